@@ -0,0 +1,68 @@
+//! Calls of the Vector pallet.
+//!
+//! The arguments are SCALE encoded by `codec`, while the pallet and call indices are read from the
+//! metadata of the node, so the calls stay valid when the pallet is moved in the runtime.
+use crate::PALLET;
+
+use avail_core::data_proof::AddressedMessage;
+use codec::Encode;
+use sp_core::H256;
+use subxt::{error::MetadataError, tx::TxPayload, Error, Metadata};
+
+/// Call of the Vector pallet named `name`, with the SCALE encoded `args`.
+#[derive(Clone, Debug)]
+pub struct VectorCall<A> {
+	pub name: &'static str,
+	pub args: A,
+}
+
+impl<A: Encode> TxPayload for VectorCall<A> {
+	fn encode_call_data_to(&self, metadata: &Metadata, out: &mut Vec<u8>) -> Result<(), Error> {
+		let pallet = metadata.pallet_by_name_err(PALLET)?;
+		let call = pallet
+			.call_variant_by_name(self.name)
+			.ok_or_else(|| MetadataError::CallNameNotFound(self.name.to_owned()))?;
+
+		pallet.index().encode_to(out);
+		call.index.encode_to(out);
+		self.args.encode_to(out);
+		Ok(())
+	}
+}
+
+/// Arguments of `Vector::execute`.
+#[derive(Clone, Debug, Encode)]
+pub struct Execute {
+	#[codec(compact)]
+	pub slot: u64,
+	pub addr_message: AddressedMessage,
+	pub account_proof: Vec<Vec<u8>>,
+	pub storage_proof: Vec<Vec<u8>>,
+}
+
+/// `Vector::fulfill_call`, the step and the rotation of the light client.
+pub fn fulfill_call(function_id: H256, inputs: Vec<u8>) -> VectorCall<(H256, Vec<u8>)> {
+	VectorCall {
+		name: "fulfill_call",
+		args: (function_id, inputs),
+	}
+}
+
+/// `Vector::execute`, the execution of a message proven against the execution state root of
+/// `slot`.
+pub fn execute(
+	slot: u64,
+	addr_message: AddressedMessage,
+	account_proof: Vec<Vec<u8>>,
+	storage_proof: Vec<Vec<u8>>,
+) -> VectorCall<Execute> {
+	VectorCall {
+		name: "execute",
+		args: Execute {
+			slot,
+			addr_message,
+			account_proof,
+			storage_proof,
+		},
+	}
+}
@@ -0,0 +1,115 @@
+//! Message proofs from `eth_getProof` responses.
+//!
+//! `execute` needs the account proof of the broadcaster, against the execution state root of the
+//! slot, and the storage proof of the message slot in its `messages` mapping. Both are the RLP
+//! encoded trie nodes returned by `eth_getProof`.
+use serde::Deserialize;
+use sp_core::{keccak_256, Bytes, H160, H256, U256};
+
+/// Response of `eth_getProof`, as defined by EIP-1186.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EthGetProofResponse {
+	pub address: H160,
+	pub account_proof: Vec<Bytes>,
+	pub storage_hash: H256,
+	pub storage_proof: Vec<EthStorageProof>,
+}
+
+/// Storage proof of a single slot of an `eth_getProof` response.
+#[derive(Clone, Debug, Deserialize)]
+pub struct EthStorageProof {
+	/// Slot as requested, which may be a quantity without the leading zeros.
+	pub key: String,
+	pub value: U256,
+	pub proof: Vec<Bytes>,
+}
+
+/// Proofs of a message, as expected by `execute`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExecuteProofs {
+	pub account_proof: Vec<Vec<u8>>,
+	pub storage_proof: Vec<Vec<u8>>,
+}
+
+impl EthGetProofResponse {
+	/// Parses the JSON `result` of an `eth_getProof` call.
+	pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+		serde_json::from_str(json)
+	}
+
+	/// Proofs of the message stored at `slot_key`, if the response proves that slot.
+	pub fn execute_proofs(&self, slot_key: H256) -> Option<ExecuteProofs> {
+		let storage = self
+			.storage_proof
+			.iter()
+			.find(|storage| parse_slot(&storage.key) == Some(slot_key))?;
+
+		Some(ExecuteProofs {
+			account_proof: self
+				.account_proof
+				.iter()
+				.map(|node| node.to_vec())
+				.collect(),
+			storage_proof: storage.proof.iter().map(|node| node.to_vec()).collect(),
+		})
+	}
+}
+
+/// Slot of message `message_id` in the broadcaster `messages` mapping, at storage index
+/// `mapping_index` of the contract. It is the slot to request with `eth_getProof`.
+pub fn message_slot_key(message_id: u64, mapping_index: u64) -> H256 {
+	let mut preimage = [0u8; 64];
+	U256::from(message_id).to_big_endian(&mut preimage[..32]);
+	U256::from(mapping_index).to_big_endian(&mut preimage[32..]);
+	H256(keccak_256(&preimage))
+}
+
+/// Parses a hex slot, left padding it to 32 bytes.
+fn parse_slot(key: &str) -> Option<H256> {
+	let digits = key.strip_prefix("0x").unwrap_or(key);
+	if digits.len() > 64 {
+		return None;
+	}
+
+	let padded = format!("{digits:0>64}");
+	let mut slot = H256::zero();
+	hex::decode_to_slice(padded, slot.as_bytes_mut()).ok()?;
+	Some(slot)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_padded_and_unpadded_slots() {
+		let slot = H256::from_low_u64_be(0xabc);
+		assert_eq!(parse_slot("0xabc"), Some(slot));
+		assert_eq!(parse_slot(&format!("{slot:?}")), Some(slot));
+		assert_eq!(parse_slot("0xzz"), None);
+	}
+
+	#[test]
+	fn selects_the_storage_proof_of_the_slot() {
+		let slot_key = message_slot_key(7, 1);
+		let json = serde_json::json!({
+			"address": "0x43f0222552e8114ad8f224dea89976d3bf41659d",
+			"accountProof": ["0x0102", "0x0304"],
+			"balance": "0x0",
+			"codeHash": format!("{:?}", H256::zero()),
+			"nonce": "0x1",
+			"storageHash": format!("{:?}", H256::zero()),
+			"storageProof": [
+				{ "key": "0x1", "value": "0x0", "proof": ["0x05"] },
+				{ "key": format!("{slot_key:?}"), "value": "0x1", "proof": ["0x0607"] },
+			],
+		});
+		let response = EthGetProofResponse::from_json(&json.to_string()).unwrap();
+
+		let proofs = response.execute_proofs(slot_key).unwrap();
+		assert_eq!(proofs.account_proof, vec![vec![1, 2], vec![3, 4]]);
+		assert_eq!(proofs.storage_proof, vec![vec![6, 7]]);
+		assert_eq!(response.execute_proofs(H256::repeat_byte(1)), None);
+	}
+}
@@ -0,0 +1,144 @@
+//! Typed client of the Vector bridge pallet.
+//!
+//! Relayers use it to submit light client updates and bridge messages, to read the bridge state
+//! and to build the message proofs from the `eth_getProof` responses of the source chain, without
+//! encoding the calls and the storage keys by hand.
+//!
+//! Calls and storage items are resolved by name, so the client does not depend on the generated
+//! `avail-subxt` API being in sync with the runtime.
+use avail_core::{data_proof::AddressedMessage, AppId};
+use avail_subxt::{avail::TxInBlock, tx, AvailClient, AvailConfig};
+use sp_core::{H256, U256};
+use subxt::{tx::Signer, Error};
+
+pub mod calls;
+pub use calls::VectorCall;
+pub mod eth_proof;
+pub use eth_proof::{message_slot_key, EthGetProofResponse, ExecuteProofs};
+pub mod rpc;
+pub use rpc::VectorRpcClient;
+pub mod storage;
+pub use storage::MessageStatus;
+
+/// Name of the bridge pallet in the runtime.
+pub const PALLET: &str = "Vector";
+
+/// Client of the Vector bridge pallet of an Avail node.
+pub struct BridgeClient {
+	client: AvailClient,
+}
+
+impl BridgeClient {
+	pub fn new(client: AvailClient) -> Self {
+		Self { client }
+	}
+
+	pub async fn from_url<U: AsRef<str>>(ws_uri: U) -> Result<Self, Error> {
+		Ok(Self::new(AvailClient::new(ws_uri).await?))
+	}
+
+	pub fn client(&self) -> &AvailClient {
+		&self.client
+	}
+
+	/// RPC methods of the node, `VectorRpcClient` adds the bridge ones.
+	pub fn rpc(&self) -> &avail_subxt::avail_client::RpcMethods {
+		self.client.rpc_methods()
+	}
+
+	/// Latest finalized slot of the source chain.
+	pub async fn head(&self) -> Result<u64, Error> {
+		self.fetch(storage::head()).await
+	}
+
+	/// Header root of the finalized `slot`, zero if the slot is unknown.
+	pub async fn header(&self, slot: u64) -> Result<H256, Error> {
+		self.fetch(storage::headers(slot)).await
+	}
+
+	/// Execution state root of the finalized `slot`, zero if the slot is unknown.
+	pub async fn execution_state_root(&self, slot: u64) -> Result<H256, Error> {
+		self.fetch(storage::execution_state_roots(slot)).await
+	}
+
+	/// Hash of the sync committee of `period`, zero if it is not stored.
+	pub async fn sync_committee_hash(&self, period: u64) -> Result<U256, Error> {
+		self.fetch(storage::sync_committee_hashes(period)).await
+	}
+
+	/// Execution status of the message with root `message_root`.
+	pub async fn message_status(&self, message_root: H256) -> Result<MessageStatus, Error> {
+		self.fetch(storage::message_status(message_root)).await
+	}
+
+	/// Step and rotate function ids, if they are set.
+	pub async fn function_ids(&self) -> Result<Option<(H256, H256)>, Error> {
+		self.fetch(storage::function_ids()).await
+	}
+
+	/// Submits a step of the light client with the CBOR encoded `inputs`, and waits until it is
+	/// finalized.
+	pub async fn step<S: Signer<AvailConfig>>(
+		&self,
+		signer: &S,
+		inputs: Vec<u8>,
+	) -> Result<TxInBlock, Error> {
+		let (step, _) = self.required_function_ids().await?;
+		self.submit(&calls::fulfill_call(step, inputs), signer)
+			.await
+	}
+
+	/// Submits a sync committee rotation with the CBOR encoded `inputs`, and waits until it is
+	/// finalized.
+	pub async fn rotate<S: Signer<AvailConfig>>(
+		&self,
+		signer: &S,
+		inputs: Vec<u8>,
+	) -> Result<TxInBlock, Error> {
+		let (_, rotate) = self.required_function_ids().await?;
+		self.submit(&calls::fulfill_call(rotate, inputs), signer)
+			.await
+	}
+
+	/// Executes `message`, proven against the execution state root of `slot`, and waits until
+	/// it is finalized.
+	pub async fn execute<S: Signer<AvailConfig>>(
+		&self,
+		signer: &S,
+		slot: u64,
+		message: AddressedMessage,
+		proofs: ExecuteProofs,
+	) -> Result<TxInBlock, Error> {
+		let call = calls::execute(slot, message, proofs.account_proof, proofs.storage_proof);
+		self.submit(&call, signer).await
+	}
+
+	/// Submits `call` of the pallet and waits until it is finalized.
+	pub async fn submit<A, S>(&self, call: &VectorCall<A>, signer: &S) -> Result<TxInBlock, Error>
+	where
+		A: codec::Encode,
+		S: Signer<AvailConfig>,
+	{
+		tx::send_then_finalized(&self.client, call, signer, AppId(0)).await
+	}
+
+	async fn required_function_ids(&self) -> Result<(H256, H256), Error> {
+		self.function_ids()
+			.await?
+			.ok_or_else(|| Error::Other("Function ids of the bridge are not set".into()))
+	}
+
+	async fn fetch<V: codec::Decode + Default>(&self, key: Vec<u8>) -> Result<V, Error> {
+		let Some(raw) = self
+			.client
+			.storage()
+			.at_latest()
+			.await?
+			.fetch_raw(key)
+			.await?
+		else {
+			return Ok(V::default());
+		};
+		Ok(V::decode(&mut raw.as_slice())?)
+	}
+}
@@ -0,0 +1,45 @@
+use avail_core::data_proof::AddressedMessage;
+use jsonrpsee::proc_macros::rpc;
+use serde::{Deserialize, Serialize};
+use sp_core::{storage::StorageKey, Bytes, H256};
+
+/// Avail state proof in the layout expected by the Ethereum verification contract.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EthReadProof {
+	pub at: H256,
+	pub block_number: u32,
+	pub state_root: H256,
+	pub proof: Vec<Bytes>,
+}
+
+/// Outcome of a dry-run of the execution of a bridge message.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageSimulation {
+	pub at: H256,
+	pub message_root: H256,
+	pub status: String,
+	pub queued: bool,
+	pub error: Option<String>,
+}
+
+#[rpc(client)]
+pub trait VectorRpc {
+	#[method(name = "state_getReadProofEth")]
+	async fn read_proof_eth(
+		&self,
+		keys: Vec<StorageKey>,
+		at: Option<H256>,
+	) -> RpcResult<EthReadProof>;
+
+	#[method(name = "succinct_simulateExecuteMessage")]
+	async fn simulate_execute_message(
+		&self,
+		slot: u64,
+		message: AddressedMessage,
+		account_proof: Vec<Bytes>,
+		storage_proof: Vec<Bytes>,
+		at: Option<H256>,
+	) -> RpcResult<MessageSimulation>;
+}
@@ -0,0 +1,55 @@
+//! Storage keys of the Vector pallet.
+//!
+//! Every map of the pallet uses the `Identity` hasher, so a key is the hashed prefix of the item
+//! followed by the SCALE encoded key.
+use crate::PALLET;
+
+use codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+use sp_core::{twox_128, H256};
+
+/// Execution status of a bridge message.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub enum MessageStatus {
+	#[default]
+	NotExecuted,
+	ExecutionSucceeded,
+	/// Execution failed and the failure was acknowledged to the sender.
+	ExecutionFailed,
+}
+
+/// Key of the storage value `item`.
+pub fn value_key(item: &str) -> Vec<u8> {
+	[twox_128(PALLET.as_bytes()), twox_128(item.as_bytes())].concat()
+}
+
+/// Key of `key` in the storage map `item`.
+pub fn map_key<K: Encode>(item: &str, key: K) -> Vec<u8> {
+	let mut storage_key = value_key(item);
+	key.encode_to(&mut storage_key);
+	storage_key
+}
+
+pub fn head() -> Vec<u8> {
+	value_key("Head")
+}
+
+pub fn headers(slot: u64) -> Vec<u8> {
+	map_key("Headers", slot)
+}
+
+pub fn execution_state_roots(slot: u64) -> Vec<u8> {
+	map_key("ExecutionStateRoots", slot)
+}
+
+pub fn sync_committee_hashes(period: u64) -> Vec<u8> {
+	map_key("SyncCommitteeHashes", period)
+}
+
+pub fn message_status(message_root: H256) -> Vec<u8> {
+	map_key("MessageStatus", message_root)
+}
+
+pub fn function_ids() -> Vec<u8> {
+	value_key("FunctionIds")
+}
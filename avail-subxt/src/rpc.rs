@@ -17,6 +17,12 @@ pub trait KateRpc {
 	#[method(name = "queryProof")]
 	async fn query_proof(&self, cells: Cells, block: H256) -> RpcResult<Vec<GDataProof>>;
 
+	#[method(name = "queryProofMulti")]
+	async fn query_proof_multi(
+		&self,
+		requests: Vec<(H256, Cells)>,
+	) -> RpcResult<Vec<Vec<GDataProof>>>;
+
 	#[method(name = "blockLength")]
 	async fn query_block_length(&self, block: H256) -> RpcResult<BlockLength>;
 
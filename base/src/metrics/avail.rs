@@ -1,7 +1,9 @@
 use core::time::Duration;
 use std::time::Instant;
 
-use substrate_prometheus_endpoint::{Histogram, PrometheusError, Registry};
+use substrate_prometheus_endpoint::{
+	register, Counter, Gauge, Histogram, PrometheusError, Registry, U64,
+};
 
 use crate::metrics::LOG_TARGET;
 
@@ -12,6 +14,7 @@ pub struct AvailMetrics {
 	pub import_block: ImportBlockMetrics,
 	pub header_extension: HeaderExtensionBuilderMetrics,
 	pub kate_rpc: KateRpcMetrics,
+	pub bridge: BridgeMetrics,
 }
 
 impl AvailMetrics {
@@ -20,6 +23,7 @@ impl AvailMetrics {
 		let import_block = ImportBlockMetrics::new(registry)?;
 		let header_extension = HeaderExtensionBuilderMetrics::new(registry)?;
 		let kate_rpc = KateRpcMetrics::new(registry)?;
+		let bridge = BridgeMetrics::new(registry)?;
 
 		log::info!(
 			target: LOG_TARGET,
@@ -30,6 +34,7 @@ impl AvailMetrics {
 			import_block,
 			header_extension,
 			kate_rpc,
+			bridge,
 		})
 	}
 }
@@ -172,6 +177,7 @@ impl HeaderExtensionBuilderMetrics {
 pub struct KateRpcMetrics {
 	pub query_rows_execution_time: Histogram,
 	pub query_proof_execution_time: Histogram,
+	pub query_proof_multi_execution_time: Histogram,
 	pub query_block_length_execution_time: Histogram,
 	pub query_data_proof_execution_time: Histogram,
 }
@@ -209,6 +215,12 @@ impl KateRpcMetrics {
 			"Kate RPC - Query Proof Time in microseconds",
 			buckets.to_vec(),
 		)?;
+		let query_proof_multi_execution_time = custom_histogram(
+			registry,
+			"avail_kate_rpc_query_proof_multi_execution_time",
+			"Kate RPC - Query Proof Multi Time in microseconds",
+			buckets.to_vec(),
+		)?;
 
 		let buckets = [
 			100.0, 200.0, 300.0, 400.0, 500.0, // 0.10ms, 0.20ms, 0.30ms, 0.40ms, 0.50ms,
@@ -237,6 +249,7 @@ impl KateRpcMetrics {
 		Ok(Self {
 			query_rows_execution_time,
 			query_proof_execution_time,
+			query_proof_multi_execution_time,
 			query_block_length_execution_time,
 			query_data_proof_execution_time,
 		})
@@ -260,6 +273,15 @@ impl KateRpcMetrics {
 		}
 	}
 
+	pub(crate) fn observe_query_proof_multi_execution_time(duration: Duration) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics
+				.kate_rpc
+				.query_proof_multi_execution_time
+				.observe(duration.as_micros() as f64);
+		}
+	}
+
 	pub(crate) fn observe_query_block_length_execution_time(duration: Duration) {
 		if let Some(metrics) = AVAIL_METRICS.get() {
 			metrics
@@ -281,6 +303,10 @@ impl KateRpcMetrics {
 
 pub struct ImportBlockMetrics {
 	pub total_execution_time: Histogram,
+	pub da_verification_time: Histogram,
+	pub da_verification_failures: Counter<U64>,
+	pub matrix_rows: Histogram,
+	pub matrix_cols: Histogram,
 }
 impl ImportBlockMetrics {
 	pub fn new(registry: &Registry) -> Result<Self, PrometheusError> {
@@ -306,9 +332,41 @@ impl ImportBlockMetrics {
 			"Import Block - Total Execution Time in microseconds",
 			buckets.to_vec(),
 		)?;
+		let da_verification_time = custom_histogram(
+			registry,
+			"avail_import_block_da_verification_time",
+			"Import Block - DA Verification Time in microseconds",
+			buckets.to_vec(),
+		)?;
+
+		let da_verification_failures = register(
+			Counter::new(
+				"avail_import_block_da_verification_failures",
+				"Import Block - Blocks failing the DA Verification",
+			)?,
+			registry,
+		)?;
+
+		let buckets = [4.0, 6.0, 8.0, 12.0, 16.0, 32.0, 64.0, 128.0, 256.0, 512.0];
+		let matrix_rows = custom_histogram(
+			registry,
+			"avail_import_block_matrix_rows",
+			"Import Block - Verified Matrix Rows",
+			buckets.to_vec(),
+		)?;
+		let matrix_cols = custom_histogram(
+			registry,
+			"avail_import_block_matrix_cols",
+			"Import Block - Verified Matrix Columns",
+			buckets.to_vec(),
+		)?;
 
 		Ok(Self {
 			total_execution_time,
+			da_verification_time,
+			da_verification_failures,
+			matrix_rows,
+			matrix_cols,
 		})
 	}
 
@@ -320,6 +378,46 @@ impl ImportBlockMetrics {
 				.observe(duration.as_micros() as f64);
 		}
 	}
+
+	/// Records the DA verification of an imported block and the size of its matrix.
+	pub fn observe_da_verification(duration: Duration, rows: usize, cols: usize, failed: bool) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			let import_block = &metrics.import_block;
+			import_block
+				.da_verification_time
+				.observe(duration.as_micros() as f64);
+			import_block.matrix_rows.observe(rows as f64);
+			import_block.matrix_cols.observe(cols as f64);
+			if failed {
+				import_block.da_verification_failures.inc();
+			}
+		}
+	}
+}
+
+pub struct BridgeMetrics {
+	pub missing_nonces: Gauge<U64>,
+}
+
+impl BridgeMetrics {
+	pub fn new(registry: &Registry) -> Result<Self, PrometheusError> {
+		let missing_nonces = register(
+			Gauge::new(
+				"avail_bridge_missing_nonces",
+				"Bridge - Message ids skipped by the relayers at the best block",
+			)?,
+			registry,
+		)?;
+
+		Ok(Self { missing_nonces })
+	}
+
+	/// Records the number of message ids missing within the nonce windows of the broadcasters.
+	pub fn observe_missing_nonces(count: u64) {
+		if let Some(metrics) = AVAIL_METRICS.get() {
+			metrics.bridge.missing_nonces.set(count);
+		}
+	}
 }
 
 pub enum ObserveKind {
@@ -327,6 +425,7 @@ pub enum ObserveKind {
 	KateQueryDataProof,
 	KateQueryBlockLength,
 	KateQueryProof,
+	KateQueryProofMulti,
 	KateQueryRows,
 	HETotalExecutionTime,
 	HEGrid,
@@ -363,6 +462,9 @@ impl Drop for MetricObserver {
 			ObserveKind::KateQueryProof => {
 				KateRpcMetrics::observe_query_proof_execution_time(duration)
 			},
+			ObserveKind::KateQueryProofMulti => {
+				KateRpcMetrics::observe_query_proof_multi_execution_time(duration)
+			},
 			ObserveKind::KateQueryRows => {
 				KateRpcMetrics::observe_query_rows_execution_time(duration)
 			},
@@ -41,3 +41,26 @@ async fn query_proof() -> anyhow::Result<()> {
 	trace!("Submitted data in block {block_hash:?} and got proof {proof:?}");
 	Ok(())
 }
+
+// Fetch the proofs of cell {0,0} of the same block twice in a single request.
+#[test(tokio::test)]
+async fn query_proof_multi() -> anyhow::Result<()> {
+	let _gc = allow_concurrency("query_proof_multi").await;
+	let client = local_connection().await?;
+	let alice = dev::alice();
+	let nonce = alice_nonce().await.fetch_add(1, Relaxed);
+
+	let tx = submit_data_with_nonce(&client, &alice, DATA, AppId(1), nonce).await?;
+	let block_hash = tx::then_in_finalized_block(tx).await?.block_hash();
+	let cells = Cells::try_from(vec![Cell::new(0, 0)]).expect("Valid bounds .qed");
+
+	let mut params = RpcParams::new();
+	params.push(vec![(block_hash, cells.clone()), (block_hash, cells)])?;
+
+	let proofs: Vec<Vec<GDataProof>> = client.rpc().request("kate_queryProofMulti", params).await?;
+
+	trace!("Got proofs {proofs:?} of block {block_hash:?}");
+	assert_eq!(proofs.len(), 2);
+	assert_eq!(proofs[0].len(), 1);
+	Ok(())
+}
@@ -0,0 +1,186 @@
+//! Regenerates `misc/bridge-dev/test-vectors.json` from a live network, so that the fixtures of
+//! the bridge tests can be reproduced instead of being copied around.
+//!
+//! The slot, the latest finalized one unless `--slot` is given, is read from the beacon API with
+//! its header root and the execution state root of its block. The messages of the current
+//! vectors, or of `--messages`, are then proven with `eth_getProof` against the broadcaster of
+//! the vectors at that block, and checked to be stored under their roots.
+//!
+//! ```bash
+//! ETH_RPC=<execution rpc> BEACON_API=<beacon api> cargo run --bin generate-fixtures -- --slot 8581263
+//! ```
+
+use anyhow::{ensure, Context, Result};
+use clap::Parser;
+use integration_tests::vectors::{TestAddressedMessage, TestMessage, TestSlot, TestVectors};
+use jsonrpsee::{core::client::ClientT, http_client::HttpClientBuilder, rpc_params};
+use serde::{de::DeserializeOwned, de::Error as _, Deserialize, Deserializer};
+use serde_json::Value;
+use sp_core::{Bytes, H256, U256};
+use std::{fs, path::PathBuf};
+
+#[derive(Parser)]
+struct Args {
+	/// Execution JSON-RPC endpoint of the source chain.
+	#[arg(long, env = "ETH_RPC")]
+	eth_rpc: String,
+	/// Beacon API endpoint of the source chain.
+	#[arg(long, env = "BEACON_API")]
+	beacon_api: String,
+	/// Slot to prove the messages at, the latest finalized slot by default.
+	#[arg(long)]
+	slot: Option<u64>,
+	/// JSON array of the messages to prove, the messages of the current vectors by default.
+	#[arg(long)]
+	messages: Option<PathBuf>,
+	/// Test vectors to update. Their broadcaster and other fields are kept.
+	#[arg(long, default_value = "../misc/bridge-dev/test-vectors.json")]
+	vectors: PathBuf,
+}
+
+#[derive(Deserialize)]
+struct BeaconResponse<T> {
+	data: T,
+}
+
+#[derive(Deserialize)]
+struct HeaderData {
+	root: H256,
+	header: SignedHeader,
+}
+
+#[derive(Deserialize)]
+struct SignedHeader {
+	message: HeaderMessage,
+}
+
+#[derive(Deserialize)]
+struct HeaderMessage {
+	#[serde(deserialize_with = "u64_from_str")]
+	slot: u64,
+}
+
+#[derive(Deserialize)]
+struct BlockData {
+	message: BlockMessage,
+}
+
+#[derive(Deserialize)]
+struct BlockMessage {
+	body: BlockBody,
+}
+
+#[derive(Deserialize)]
+struct BlockBody {
+	execution_payload: ExecutionPayload,
+}
+
+#[derive(Deserialize)]
+struct ExecutionPayload {
+	state_root: H256,
+	#[serde(deserialize_with = "u64_from_str")]
+	block_number: u64,
+}
+
+/// Response of `eth_getProof` (EIP-1186), reduced to the proofs and the values.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EthProof {
+	account_proof: Vec<Bytes>,
+	storage_proof: Vec<EthStorageProof>,
+}
+
+#[derive(Deserialize)]
+struct EthStorageProof {
+	value: U256,
+	proof: Vec<Bytes>,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+	let args = Args::parse();
+
+	let mut json: Value = serde_json::from_slice(&fs::read(&args.vectors)?)?;
+	let vectors: TestVectors = serde_json::from_value(json.clone())?;
+	let messages: Vec<TestAddressedMessage> = match &args.messages {
+		Some(path) => serde_json::from_slice(&fs::read(path)?)?,
+		None => vectors.messages.into_iter().map(|m| m.message).collect(),
+	};
+
+	let block_id = args
+		.slot
+		.map_or_else(|| "finalized".to_string(), |slot| slot.to_string());
+	let header: HeaderData = beacon_get(
+		&args.beacon_api,
+		&format!("eth/v1/beacon/headers/{block_id}"),
+	)
+	.await?;
+	let block: BlockData = beacon_get(
+		&args.beacon_api,
+		&format!("eth/v2/beacon/blocks/{:?}", header.root),
+	)
+	.await?;
+	let payload = block.message.body.execution_payload;
+	let slot = TestSlot {
+		slot: header.header.message.slot,
+		header_root: header.root,
+		execution_state_root: payload.state_root,
+	};
+
+	let eth = HttpClientBuilder::default().build(&args.eth_rpc)?;
+	let address = vectors.broadcaster.eth_address();
+	let block_number = format!("0x{:x}", payload.block_number);
+	let mut proven = Vec::with_capacity(messages.len());
+	for message in messages {
+		let proof: EthProof = eth
+			.request(
+				"eth_getProof",
+				rpc_params![address, vec![message.slot_key()], &block_number],
+			)
+			.await
+			.with_context(|| format!("eth_getProof of message {}", message.id))?;
+		let storage = proof
+			.storage_proof
+			.into_iter()
+			.next()
+			.context("eth_getProof returned no storage proof")?;
+		ensure!(
+			storage.value == U256::from_big_endian(message.root().as_bytes()),
+			"Message {} is not stored by the broadcaster at block {}",
+			message.id,
+			payload.block_number,
+		);
+
+		proven.push(TestMessage {
+			slot: slot.slot,
+			message,
+			account_proof: proof.account_proof,
+			storage_proof: storage.proof,
+		});
+	}
+
+	println!(
+		"Proved {} messages at slot {} (block {})",
+		proven.len(),
+		slot.slot,
+		payload.block_number
+	);
+	json["slots"] = serde_json::to_value(vec![slot])?;
+	json["messages"] = serde_json::to_value(proven)?;
+	fs::write(&args.vectors, serde_json::to_string_pretty(&json)? + "\n")?;
+	Ok(())
+}
+
+async fn beacon_get<T: DeserializeOwned>(api: &str, path: &str) -> Result<T> {
+	let url = format!("{}/{path}", api.trim_end_matches('/'));
+	let response = reqwest::get(&url)
+		.await?
+		.error_for_status()
+		.with_context(|| format!("GET {url}"))?;
+	Ok(response.json::<BeaconResponse<T>>().await?.data)
+}
+
+fn u64_from_str<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+	let value = String::deserialize(deserializer)?;
+	value.parse().map_err(D::Error::custom)
+}
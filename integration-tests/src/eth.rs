@@ -0,0 +1,101 @@
+//! Mock Ethereum JSON-RPC serving canned `eth_getProof` responses.
+
+use crate::vectors::TestVectors;
+
+use anyhow::Result;
+use jsonrpsee::{
+	server::{Server, ServerHandle},
+	types::ErrorObjectOwned,
+	RpcModule,
+};
+use serde::{Deserialize, Serialize};
+use sp_core::{Bytes, H160, H256};
+use std::collections::HashMap;
+use tracing::trace;
+
+/// Generic JSON-RPC error code used by Ethereum clients.
+const SERVER_ERROR: i32 = -32000;
+
+/// Response of `eth_getProof` (EIP-1186), reduced to the fields used by the relayer.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EthProof {
+	pub address: H160,
+	pub account_proof: Vec<Bytes>,
+	pub storage_proof: Vec<EthStorageProof>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EthStorageProof {
+	pub key: H256,
+	pub value: H256,
+	pub proof: Vec<Bytes>,
+}
+
+/// Running mock Ethereum node. The server is stopped on drop.
+pub struct MockEth {
+	url: String,
+	_handle: ServerHandle,
+}
+
+impl MockEth {
+	/// Serves the proofs of every message of `vectors` on a random local port.
+	pub async fn start(vectors: &TestVectors) -> Result<Self> {
+		let address = vectors.broadcaster.eth_address();
+		let proofs = vectors
+			.messages
+			.iter()
+			.map(|m| {
+				let storage_proof = EthStorageProof {
+					key: m.message.slot_key(),
+					value: m.message.root(),
+					proof: m.storage_proof.clone(),
+				};
+				(storage_proof.key, (m.account_proof.clone(), storage_proof))
+			})
+			.collect::<HashMap<_, _>>();
+
+		let mut module = RpcModule::new(proofs);
+		module.register_method("eth_getProof", move |params, proofs| {
+			let (requested, keys, block): (H160, Vec<H256>, String) = params.parse()?;
+			trace!("eth_getProof({requested:?}, {keys:?}, {block})");
+			if requested != address {
+				return Err(server_error(format!("Unknown account {requested:?}")));
+			}
+
+			let mut account_proof = Vec::new();
+			let mut storage_proof = Vec::with_capacity(keys.len());
+			for key in keys {
+				let (account, storage) = proofs
+					.get(&key)
+					.ok_or_else(|| server_error(format!("Unknown storage key {key:?}")))?;
+				account_proof = account.clone();
+				storage_proof.push(storage.clone());
+			}
+
+			Ok::<_, ErrorObjectOwned>(EthProof {
+				address,
+				account_proof,
+				storage_proof,
+			})
+		})?;
+
+		let server = Server::builder().build("127.0.0.1:0").await?;
+		let url = format!("http://{}", server.local_addr()?);
+		let handle = server.start(module);
+
+		Ok(Self {
+			url,
+			_handle: handle,
+		})
+	}
+
+	/// HTTP url of the JSON-RPC endpoint.
+	pub fn url(&self) -> &str {
+		&self.url
+	}
+}
+
+fn server_error(message: String) -> ErrorObjectOwned {
+	ErrorObjectOwned::owned(SERVER_ERROR, message, None::<()>)
+}
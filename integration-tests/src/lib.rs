@@ -0,0 +1,73 @@
+//! Harness to test the Avail node and the Vector bridge end to end.
+//!
+//! It launches the `avail-node` binary on the `bridge-dev` chain spec, a mock Ethereum JSON-RPC
+//! which serves the canned proofs of `misc/bridge-dev/test-vectors.json`, and a scripted relayer
+//! which moves the bridge state from the mock Ethereum to the node.
+//!
+//! The node binary is taken from `AVAIL_NODE_BIN`, or `../target/release/avail-node` by default.
+
+pub mod eth;
+pub mod node;
+pub mod relayer;
+pub mod vectors;
+
+use anyhow::{anyhow, Result};
+use avail_subxt::{AvailClient, AvailConfig};
+use sp_core::H256;
+use subxt::{
+	blocks::ExtrinsicEvents,
+	dynamic::{self, Value},
+	ext::scale_value::ValueDef,
+};
+
+/// Returns `true` if `events` contains the `pallet::variant` event.
+pub fn has_event(
+	events: &ExtrinsicEvents<AvailConfig>,
+	pallet: &str,
+	variant: &str,
+) -> Result<bool> {
+	for event in events.iter() {
+		let event = event?;
+		if event.pallet_name() == pallet && event.variant_name() == variant {
+			return Ok(true);
+		}
+	}
+	Ok(false)
+}
+
+/// Returns the current head of the Vector bridge.
+pub async fn bridge_head(client: &AvailClient) -> Result<u64> {
+	let query = dynamic::storage("Vector", "Head", Vec::<Value>::new());
+	let head = client
+		.storage()
+		.at_latest()
+		.await?
+		.fetch_or_default(&query)
+		.await?
+		.to_value()?;
+
+	head.as_u128()
+		.and_then(|head| u64::try_from(head).ok())
+		.ok_or_else(|| anyhow!("Unexpected bridge head {head:?}"))
+}
+
+/// Returns the name of the execution status of the message hashed to `message_root`.
+pub async fn message_status(client: &AvailClient, message_root: H256) -> Result<String> {
+	let query = dynamic::storage(
+		"Vector",
+		"MessageStatus",
+		vec![Value::from_bytes(message_root.as_bytes())],
+	);
+	let status = client
+		.storage()
+		.at_latest()
+		.await?
+		.fetch_or_default(&query)
+		.await?
+		.to_value()?;
+
+	match status.value {
+		ValueDef::Variant(variant) => Ok(variant.name),
+		other => Err(anyhow!("Unexpected message status {other:?}")),
+	}
+}
@@ -0,0 +1,73 @@
+//! Launches the Avail node binary for the duration of a test.
+
+use anyhow::{anyhow, Result};
+use avail_subxt::AvailClient;
+use std::{env, net::TcpListener, process::Stdio, time::Duration};
+use tokio::{
+	process::{Child, Command},
+	time::sleep,
+};
+use tracing::trace;
+
+/// Environment variable with the path of the node binary.
+pub const NODE_BIN_ENV: &str = "AVAIL_NODE_BIN";
+const DEFAULT_NODE_BIN: &str = "../target/release/avail-node";
+/// Seconds to wait until the RPC of the node is ready.
+const READY_TIMEOUT: u64 = 60;
+
+/// Running Avail node, authoring blocks as Alice on `bridge-dev`. The node is killed on drop.
+pub struct Node {
+	client: AvailClient,
+	_process: Child,
+}
+
+impl Node {
+	pub async fn spawn() -> Result<Self> {
+		let bin = env::var(NODE_BIN_ENV).unwrap_or_else(|_| DEFAULT_NODE_BIN.to_string());
+		let rpc_port = free_port()?;
+		let p2p_port = free_port()?;
+		let args = [
+			"--chain=bridge-dev".to_string(),
+			"--tmp".to_string(),
+			"--alice".to_string(),
+			"--validator".to_string(),
+			"--no-telemetry".to_string(),
+			"--no-prometheus".to_string(),
+			format!("--port={p2p_port}"),
+			format!("--rpc-port={rpc_port}"),
+		];
+		trace!("Spawning `{bin}` with {args:?}");
+		let process = Command::new(&bin)
+			.args(args)
+			.stdout(Stdio::null())
+			.stderr(Stdio::null())
+			.kill_on_drop(true)
+			.spawn()
+			.map_err(|e| anyhow!("Node binary `{bin}` cannot be spawned ({NODE_BIN_ENV}): {e}"))?;
+
+		let ws = format!("ws://127.0.0.1:{rpc_port}");
+		for _ in 0..READY_TIMEOUT {
+			if let Ok(client) = AvailClient::new(ws.clone()).await {
+				trace!("Node is ready at {ws}");
+				return Ok(Self {
+					client,
+					_process: process,
+				});
+			}
+			sleep(Duration::from_secs(1)).await;
+		}
+
+		Err(anyhow!(
+			"Node RPC `{ws}` is not ready after {READY_TIMEOUT} secs"
+		))
+	}
+
+	pub fn client(&self) -> &AvailClient {
+		&self.client
+	}
+}
+
+/// Asks the OS for an unused local port.
+fn free_port() -> Result<u16> {
+	Ok(TcpListener::bind("127.0.0.1:0")?.local_addr()?.port())
+}
@@ -0,0 +1,132 @@
+//! Scripted relayer submitting the Ethereum side of the bridge to the node.
+//!
+//! Calls are built with the dynamic API, so the harness follows the `Vector` pallet of the
+//! runtime under test instead of the static metadata of `avail-subxt`.
+
+use crate::{
+	eth::EthProof,
+	vectors::{TestAddressedMessage, TestMessage, TestMessageKind},
+};
+
+use anyhow::{anyhow, Result};
+use avail_core::AppId;
+use avail_subxt::{tx, AvailClient, AvailConfig};
+use jsonrpsee::{
+	core::client::ClientT,
+	http_client::{HttpClient, HttpClientBuilder},
+	rpc_params,
+};
+use sp_core::{Bytes, H160, H256};
+use subxt::{
+	blocks::ExtrinsicEvents,
+	dynamic::{self, Value},
+	tx::DynamicPayload,
+};
+use subxt_signer::sr25519::{dev, Keypair};
+use tracing::trace;
+
+pub struct Relayer<'a> {
+	client: &'a AvailClient,
+	eth: HttpClient,
+	signer: Keypair,
+}
+
+impl<'a> Relayer<'a> {
+	/// Relayer signing as Alice, the updater of `bridge-dev`.
+	pub fn new(client: &'a AvailClient, eth_url: &str) -> Result<Self> {
+		Ok(Self {
+			client,
+			eth: HttpClientBuilder::default().build(eth_url)?,
+			signer: dev::alice(),
+		})
+	}
+
+	/// Submits a light client step, encoded as CBOR `inputs`, through `Vector::fulfill_call`.
+	pub async fn step(&self, inputs: Vec<u8>) -> Result<ExtrinsicEvents<AvailConfig>> {
+		let call = dynamic::tx(
+			"Vector",
+			"fulfill_call",
+			vec![
+				Value::from_bytes(H256::zero().as_bytes()),
+				Value::from_bytes(inputs),
+			],
+		);
+		self.submit(&call).await
+	}
+
+	/// Fetches the proofs of `message` from Ethereum and executes it with `Vector::execute`.
+	pub async fn execute(
+		&self,
+		message: &TestMessage,
+		broadcaster: H160,
+	) -> Result<ExtrinsicEvents<AvailConfig>> {
+		let slot_key = message.message.slot_key();
+		let proof: EthProof = self
+			.eth
+			.request(
+				"eth_getProof",
+				rpc_params![broadcaster, vec![slot_key], "latest"],
+			)
+			.await?;
+		let storage_proof = proof
+			.storage_proof
+			.into_iter()
+			.find(|p| p.key == slot_key)
+			.ok_or_else(|| anyhow!("Missing storage proof of {slot_key:?}"))?;
+
+		let call = dynamic::tx(
+			"Vector",
+			"execute",
+			vec![
+				Value::u128(message.slot.into()),
+				addressed_message(&message.message),
+				trie_proof(proof.account_proof),
+				trie_proof(storage_proof.proof),
+			],
+		);
+		self.submit(&call).await
+	}
+
+	async fn submit(&self, call: &DynamicPayload) -> Result<ExtrinsicEvents<AvailConfig>> {
+		let progress = tx::send(self.client, call, &self.signer, AppId(0)).await?;
+		let events = tx::in_finalized(progress).await?.wait_for_success().await?;
+		trace!(
+			"{}::{} included in {:?}",
+			call.pallet_name(),
+			call.call_name(),
+			events.extrinsic_hash()
+		);
+		Ok(events)
+	}
+}
+
+fn addressed_message(m: &TestAddressedMessage) -> Value {
+	let message = match &m.message {
+		TestMessageKind::FungibleToken { asset_id, amount } => Value::named_variant(
+			"FungibleToken",
+			[
+				("asset_id", Value::from_bytes(asset_id.as_bytes())),
+				("amount", Value::u128(*amount)),
+			],
+		),
+		TestMessageKind::ArbitraryMessage(data) => {
+			Value::unnamed_variant("ArbitraryMessage", [Value::from_bytes(&data.0)])
+		},
+	};
+
+	Value::named_composite([
+		("message", message),
+		("from", Value::from_bytes(m.from.as_bytes())),
+		("to", Value::from_bytes(m.to.as_bytes())),
+		("origin_domain", Value::u128(m.origin_domain.into())),
+		(
+			"destination_domain",
+			Value::u128(m.destination_domain.into()),
+		),
+		("id", Value::u128(m.id.into())),
+	])
+}
+
+fn trie_proof(nodes: Vec<Bytes>) -> Value {
+	Value::unnamed_composite(nodes.into_iter().map(|node| Value::from_bytes(node.0)))
+}
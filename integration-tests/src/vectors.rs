@@ -0,0 +1,129 @@
+//! Test vectors of the `bridge-dev` chain spec.
+
+use anyhow::Result;
+use avail_core::data_proof::{AddressedMessage, BoundedData, Message};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+use sp_core::{keccak_256, Bytes, H160, H256};
+
+/// Storage index of the `messages` mapping in the broadcaster contract.
+const MESSAGE_MAPPING_STORAGE_INDEX: u64 = 1;
+
+const BRIDGE_DEV_VECTORS: &str = include_str!("../../misc/bridge-dev/test-vectors.json");
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestVectors {
+	pub broadcaster: TestBroadcaster,
+	pub slots: Vec<TestSlot>,
+	pub messages: Vec<TestMessage>,
+}
+
+impl TestVectors {
+	/// Test vectors the `bridge-dev` genesis was built from.
+	pub fn bridge_dev() -> Result<Self> {
+		Ok(serde_json::from_str(BRIDGE_DEV_VECTORS)?)
+	}
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestBroadcaster {
+	pub domain: u32,
+	pub address: H256,
+}
+
+impl TestBroadcaster {
+	/// Ethereum address of the broadcaster contract.
+	pub fn eth_address(&self) -> H160 {
+		H160::from_slice(&self.address[..20])
+	}
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestSlot {
+	pub slot: u64,
+	pub header_root: H256,
+	pub execution_state_root: H256,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestMessage {
+	pub slot: u64,
+	pub message: TestAddressedMessage,
+	pub account_proof: Vec<Bytes>,
+	pub storage_proof: Vec<Bytes>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestAddressedMessage {
+	pub message: TestMessageKind,
+	pub from: H256,
+	pub to: H256,
+	pub origin_domain: u32,
+	pub destination_domain: u32,
+	pub id: u64,
+}
+
+impl TestAddressedMessage {
+	/// Key of the message in the `messages` mapping of the broadcaster contract, as expected by
+	/// `eth_getProof`.
+	pub fn slot_key(&self) -> H256 {
+		let mut encoded = [0u8; 64];
+		encoded[24..32].copy_from_slice(&self.id.to_be_bytes());
+		encoded[56..64].copy_from_slice(&MESSAGE_MAPPING_STORAGE_INDEX.to_be_bytes());
+		H256(keccak_256(&encoded))
+	}
+
+	/// Hash of the message, used as key of `Vector::MessageStatus`.
+	pub fn root(&self) -> H256 {
+		H256(keccak_256(
+			&AddressedMessage::from(self.clone()).abi_encode(),
+		))
+	}
+}
+
+impl From<TestAddressedMessage> for AddressedMessage {
+	fn from(m: TestAddressedMessage) -> Self {
+		let message = match m.message {
+			TestMessageKind::FungibleToken { asset_id, amount } => {
+				Message::FungibleToken { asset_id, amount }
+			},
+			TestMessageKind::ArbitraryMessage(data) => {
+				Message::ArbitraryMessage(BoundedData::truncate_from(data.0))
+			},
+		};
+
+		AddressedMessage {
+			message,
+			from: m.from,
+			to: m.to,
+			origin_domain: m.origin_domain,
+			destination_domain: m.destination_domain,
+			id: m.id,
+		}
+	}
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TestMessageKind {
+	#[serde(rename_all = "camelCase")]
+	FungibleToken {
+		asset_id: H256,
+		#[serde(deserialize_with = "u128_from_str", serialize_with = "u128_to_str")]
+		amount: u128,
+	},
+	ArbitraryMessage(Bytes),
+}
+
+fn u128_from_str<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u128, D::Error> {
+	let amount = String::deserialize(deserializer)?;
+	amount.parse().map_err(D::Error::custom)
+}
+
+fn u128_to_str<S: Serializer>(amount: &u128, serializer: S) -> Result<S::Ok, S::Error> {
+	serializer.serialize_str(&amount.to_string())
+}
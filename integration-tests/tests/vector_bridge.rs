@@ -0,0 +1,68 @@
+use integration_tests::{
+	bridge_head, eth::MockEth, has_event, message_status, node::Node, relayer::Relayer,
+	vectors::TestVectors,
+};
+
+use anyhow::Result;
+use std::fs;
+use test_log::test;
+
+const STEP_CALL: &str = "../pallets/vector/examples/step_call.cbor";
+
+#[test(tokio::test)]
+async fn step_then_execute_message() -> Result<()> {
+	let vectors = TestVectors::bridge_dev()?;
+	let node = Node::spawn().await?;
+	let eth = MockEth::start(&vectors).await?;
+	let relayer = Relayer::new(node.client(), eth.url())?;
+	let genesis_head = bridge_head(node.client()).await?;
+
+	// Step: the light client update moves the head forward.
+	let events = relayer.step(fs::read(STEP_CALL)?).await?;
+	assert!(has_event(&events, "Vector", "HeadUpdated")?);
+	assert!(bridge_head(node.client()).await? > genesis_head);
+
+	// Execute: messages proven against the genesis slots are still executable.
+	let message = &vectors.messages[0];
+	let root = message.message.root();
+	assert_eq!(message_status(node.client(), root).await?, "NotExecuted");
+
+	let events = relayer
+		.execute(message, vectors.broadcaster.eth_address())
+		.await?;
+	assert!(has_event(&events, "Vector", "MessageExecuted")?);
+	assert_eq!(
+		message_status(node.client(), root).await?,
+		"ExecutionSucceeded"
+	);
+
+	// A message can only be executed once.
+	assert!(relayer
+		.execute(message, vectors.broadcaster.eth_address())
+		.await
+		.is_err());
+
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn execute_unknown_message_fails() -> Result<()> {
+	let vectors = TestVectors::bridge_dev()?;
+	let node = Node::spawn().await?;
+	let eth = MockEth::start(&vectors).await?;
+	let relayer = Relayer::new(node.client(), eth.url())?;
+
+	// The mock Ethereum has no proof of a message which was never sent.
+	let mut message = vectors.messages[0].clone();
+	message.message.id += 1;
+	assert!(relayer
+		.execute(&message, vectors.broadcaster.eth_address())
+		.await
+		.is_err());
+	assert_eq!(
+		message_status(node.client(), message.message.root()).await?,
+		"NotExecuted"
+	);
+
+	Ok(())
+}
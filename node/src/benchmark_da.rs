@@ -0,0 +1,239 @@
+//! # Benchmark DA
+//!
+//! `benchmark-da` subcommand, which builds the header extension of synthetic blocks of data
+//! submissions and measures the time spent on the commitments, the cell proofs and their
+//! verification. No chain or database is needed, so it can be used to size the hardware of a
+//! node and to track regressions of the commitment construction.
+use avail_core::{
+	header::HeaderExtension, AppExtrinsic, AppId, BlockLengthColumns, BlockLengthRows,
+	HeaderVersion, BLOCK_CHUNK_SIZE,
+};
+use codec::Encode;
+use da_runtime::kate::native::hosted_kate;
+use frame_system::{limits::BlockLength, native::build_extension_v2::build_extension};
+use kate::Seed;
+use kate_recovery::{
+	data::Cell as DataCell,
+	matrix::{Dimensions, Position},
+	proof::verify,
+};
+use serde::Serialize;
+use sp_core::{blake2_256, H256};
+use sp_runtime::Perbill;
+use std::time::{Duration, Instant};
+
+/// Length in bytes of a commitment or a proof.
+const G1_LENGTH: usize = 48;
+
+/// Builds synthetic blocks of data submissions and reports the DA commitment timings.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct BenchmarkDaCmd {
+	/// Sizes in bytes of the submitted data, one round of blocks per size.
+	#[arg(long, value_delimiter = ',', default_values_t = [1_024, 65_536, 524_288])]
+	pub sizes: Vec<usize>,
+
+	/// Number of data submissions per block.
+	#[arg(long, default_value_t = 8)]
+	pub submissions: usize,
+
+	/// Number of application ids the submissions are spread over.
+	#[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u32).range(1..))]
+	pub app_ids: u32,
+
+	/// Number of blocks built per size.
+	#[arg(long, default_value_t = 3, value_parser = clap::value_parser!(u32).range(1..))]
+	pub blocks: u32,
+
+	/// Number of cells proven and verified per block.
+	#[arg(long, default_value_t = 16)]
+	pub cells: u32,
+
+	/// Maximum number of rows of the block matrix.
+	#[arg(long, default_value_t = 256)]
+	pub rows: u32,
+
+	/// Maximum number of columns of the block matrix.
+	#[arg(long, default_value_t = 256)]
+	pub cols: u32,
+
+	/// Print the report as JSON.
+	#[arg(long)]
+	pub json: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SizeReport {
+	size: usize,
+	submissions: usize,
+	rows: u16,
+	cols: u16,
+	blocks: u32,
+	/// Average time to build the grid and the commitments of a block, in milliseconds.
+	commitment_ms: f64,
+	/// Slowest commitment construction, in milliseconds.
+	max_commitment_ms: f64,
+	/// Average time to prove a cell, in milliseconds.
+	proof_ms: f64,
+	/// Average time to verify a cell against its row commitment, in milliseconds.
+	verification_ms: f64,
+}
+
+impl BenchmarkDaCmd {
+	pub fn run(&self) -> sc_cli::Result<()> {
+		let block_length = BlockLength::with_normal_ratio(
+			BlockLengthRows(self.rows),
+			BlockLengthColumns(self.cols),
+			BLOCK_CHUNK_SIZE,
+			Perbill::one(),
+		)
+		.map_err(|e| format!("Invalid block dimensions: {e:?}"))?;
+		let public_params = kate::couscous::public_params();
+
+		let mut reports = Vec::with_capacity(self.sizes.len());
+		for &size in &self.sizes {
+			let mut commitment = Vec::new();
+			let mut proof = Duration::ZERO;
+			let mut verification = Duration::ZERO;
+			let mut dims = (0, 0);
+
+			for block in 0..self.blocks {
+				let submitted = self.submitted(size, block);
+				let seed = Seed::default();
+
+				let started = Instant::now();
+				let extension = build_extension(
+					submitted.clone(),
+					H256::zero(),
+					block_length.clone(),
+					block,
+					seed,
+					HeaderVersion::V3,
+				);
+				commitment.push(started.elapsed());
+
+				let HeaderExtension::V3(extension) = extension;
+				let (rows, cols) = (extension.commitment.rows, extension.commitment.cols);
+				let commitments = extension.commitment.commitment;
+				if commitments.len() != usize::from(rows) * 2 * G1_LENGTH {
+					return Err(format!(
+						"Commitments of {size} byte submissions cannot be built, the block may be too small"
+					)
+					.into());
+				}
+				dims = (rows, cols);
+
+				let cells = self.cell_positions(rows * 2, cols);
+				let started = Instant::now();
+				let proofs =
+					hosted_kate::proof(submitted, block_length.clone(), seed, cells.clone())
+						.map_err(|e| format!("Cell proofs failed: {e:?}"))?;
+				proof += started.elapsed();
+
+				let dimensions = Dimensions::new(rows, cols)
+					.ok_or_else(|| format!("Invalid block dimensions {rows}x{cols}"))?;
+				for ((row, col), (scalar, cell_proof)) in cells.into_iter().zip(proofs) {
+					let mut content = [0u8; 80];
+					content[..G1_LENGTH].copy_from_slice(&Vec::from(cell_proof));
+					scalar.to_big_endian(&mut content[G1_LENGTH..]);
+					let cell = DataCell {
+						position: Position {
+							row,
+							col: col as u16,
+						},
+						content,
+					};
+					let row_commitment: [u8; G1_LENGTH] = commitments
+						[row as usize * G1_LENGTH..(row as usize + 1) * G1_LENGTH]
+						.try_into()
+						.expect("Commitments are checked to cover the extended rows .qed");
+
+					let started = Instant::now();
+					let valid = verify(&public_params, dimensions, &row_commitment, &cell)
+						.map_err(|e| format!("Cell verification failed: {e:?}"))?;
+					verification += started.elapsed();
+					if !valid {
+						return Err(format!("Invalid proof of cell ({row}, {col})").into());
+					}
+				}
+			}
+
+			let blocks = self.blocks as f64;
+			let proven_cells = f64::from(self.cells.max(1)) * blocks;
+			reports.push(SizeReport {
+				size,
+				submissions: self.submissions,
+				rows: dims.0,
+				cols: dims.1,
+				blocks: self.blocks,
+				commitment_ms: as_ms(commitment.iter().sum()) / blocks,
+				max_commitment_ms: as_ms(commitment.into_iter().max().unwrap_or_default()),
+				proof_ms: as_ms(proof) / proven_cells,
+				verification_ms: as_ms(verification) / proven_cells,
+			});
+		}
+
+		if self.json {
+			let json = serde_json::to_string_pretty(&reports).map_err(|e| e.to_string())?;
+			println!("{json}");
+			return Ok(());
+		}
+
+		println!(
+			"{:>10} {:>6} {:>10} {:>14} {:>14} {:>10} {:>10}",
+			"size", "subs", "matrix", "commit (ms)", "max (ms)", "proof", "verify"
+		);
+		for r in reports {
+			let matrix = format!("{}x{}", r.rows, r.cols);
+			println!(
+				"{:>10} {:>6} {:>10} {:>14.2} {:>14.2} {:>10.3} {:>10.3}",
+				r.size,
+				r.submissions,
+				matrix,
+				r.commitment_ms,
+				r.max_commitment_ms,
+				r.proof_ms,
+				r.verification_ms
+			);
+		}
+		Ok(())
+	}
+
+	/// Data submissions of `size` bytes, spread over the application ids. The data of each
+	/// block is different, so no block is built from a cached grid.
+	fn submitted(&self, size: usize, block: u32) -> Vec<AppExtrinsic> {
+		(0..self.submissions)
+			.map(|index| {
+				let app_id = AppId(1 + index as u32 % self.app_ids);
+				let seed = blake2_256(&(block, index as u32).encode());
+				AppExtrinsic {
+					app_id,
+					data: synthetic_data(seed, size),
+				}
+			})
+			.collect()
+	}
+
+	/// Positions of the proven cells, spread over the extended matrix of `rows` x `cols`.
+	fn cell_positions(&self, rows: u16, cols: u16) -> Vec<(u32, u32)> {
+		(0..self.cells)
+			.map(|index| (index * 7 % u32::from(rows), index * 13 % u32::from(cols)))
+			.collect()
+	}
+}
+
+/// Pseudo random data, so the matrix is not made of a few repeated scalars.
+fn synthetic_data(seed: [u8; 32], size: usize) -> Vec<u8> {
+	let mut data = Vec::with_capacity(size + 32);
+	let mut chunk = seed;
+	while data.len() < size {
+		chunk = blake2_256(&chunk);
+		data.extend_from_slice(&chunk);
+	}
+	data.truncate(size);
+	data
+}
+
+fn as_ms(duration: Duration) -> f64 {
+	duration.as_secs_f64() * 1_000.0
+}
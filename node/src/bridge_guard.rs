@@ -0,0 +1,82 @@
+//! # Bridge Guard
+//!
+//! Wrapper of the proposer factory used by the block author. Before a block is proposed on top
+//! of a parent, the invariants of the bridge state of the parent are checked through the
+//! `VectorApi` runtime API. If they are violated, the slot is skipped instead of extending a
+//! corrupted bridge state.
+//!
+//! Runtimes without the check, and failures of the runtime API itself, do not prevent authoring.
+use da_runtime::{apis::VectorApi, NodeBlock as Block};
+use futures::future::{self, Either};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_consensus::Environment;
+use sp_runtime::traits::{Block as BlockT, Header as _};
+use std::sync::Arc;
+
+const LOG_TARGET: &str = "avail::node::bridge_guard";
+
+/// Proposer factory refusing to propose on parents violating the bridge invariants.
+pub struct BridgeGuard<E, C> {
+	inner: E,
+	client: Arc<C>,
+}
+
+impl<E, C> BridgeGuard<E, C> {
+	pub fn new(inner: E, client: Arc<C>) -> Self {
+		Self { inner, client }
+	}
+}
+
+impl<E, C> Environment<Block> for BridgeGuard<E, C>
+where
+	E: Environment<Block>,
+	E::Error: From<sp_blockchain::Error> + Send,
+	C: ProvideRuntimeApi<Block>,
+	C::Api: VectorApi<Block>,
+{
+	type Proposer = E::Proposer;
+	type CreateProposer =
+		Either<E::CreateProposer, future::Ready<Result<Self::Proposer, Self::Error>>>;
+	type Error = E::Error;
+
+	fn init(&mut self, parent_header: &<Block as BlockT>::Header) -> Self::CreateProposer {
+		let parent = parent_header.hash();
+		if let Err(violation) = check_bridge_invariants(&*self.client, parent) {
+			log::error!(
+				target: LOG_TARGET,
+				"🚨 Refusing to author on top of {parent:?}, the bridge state is corrupted: {violation}"
+			);
+			let error = sp_blockchain::Error::Application(violation.into());
+			return Either::Right(future::ready(Err(error.into())));
+		}
+
+		Either::Left(self.inner.init(parent_header))
+	}
+}
+
+/// Returns the violated bridge invariant of the state at `at`, if any.
+fn check_bridge_invariants<C>(client: &C, at: <Block as BlockT>::Hash) -> Result<(), String>
+where
+	C: ProvideRuntimeApi<Block>,
+	C::Api: VectorApi<Block>,
+{
+	let api = client.runtime_api();
+	let version = match api.api_version::<dyn VectorApi<Block>>(at) {
+		Ok(version) => version.unwrap_or_default(),
+		Err(e) => {
+			log::warn!(target: LOG_TARGET, "Vector API version at {at:?}: {e:?}");
+			return Ok(());
+		},
+	};
+	if version < 8 {
+		return Ok(());
+	}
+
+	match api.check_invariants(at) {
+		Ok(result) => result.map_err(|violation| String::from_utf8_lossy(&violation).into_owned()),
+		Err(e) => {
+			log::warn!(target: LOG_TARGET, "Bridge invariants at {at:?}: {e:?}");
+			Ok(())
+		},
+	}
+}
@@ -0,0 +1,527 @@
+//! # Bridge Index
+//!
+//! Offchain index of the bridge message events, so that explorers can list the messages of an
+//! account or a block without scanning the chain. The indexer follows the imported blocks and
+//! stores every message event of the `Vector` pallet in the offchain storage of the node, keyed
+//! by its block and position, with lists of these keys by message hash, by account and by
+//! block number. The index is served by `succinct_queryMessages`.
+//!
+//! Events of blocks on retracted forks stay in the index, they are filtered out when queried.
+//! Blocks skipped by the import notifications, e.g. during a major sync, are indexed by walking
+//! back from the next new best block, at most `MAX_CATCH_UP_BLOCKS` of them at a time.
+use codec::{Decode, Encode};
+use da_runtime::{Hash, NodeBlock as Block, Runtime, RuntimeEvent};
+use frame_support::storage::StorageValue;
+use futures::StreamExt;
+use pallet_vector::Event as VectorEvent;
+use sc_client_api::{BlockchainEvents, StorageProvider};
+use serde::{Deserialize, Serialize};
+use sp_blockchain::HeaderBackend;
+use sp_core::{offchain::OffchainStorage, storage::StorageKey, H256};
+use sp_runtime::traits::Header as _;
+use std::{marker::PhantomData, sync::Arc};
+
+const LOG_TARGET: &str = "avail::node::bridge_index";
+
+/// Prefix of the keys of the index in the offchain storage.
+const INDEX_PREFIX: &[u8] = b"avail-bridge-index";
+/// Key of the number of the latest indexed best block.
+const INDEXED_HEAD_KEY: &[u8] = b"head";
+/// Maximum number of skipped blocks indexed when a new best block is imported.
+const MAX_CATCH_UP_BLOCKS: u32 = 1024;
+/// Maximum number of messages returned by a query.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+pub(crate) type EventRecord = frame_system::EventRecord<RuntimeEvent, Hash>;
+
+/// Bridge event of an indexed message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MessageEventKind {
+	Submitted,
+	Executed,
+	ExecutionFailed,
+	Queued,
+	Routed,
+	Voided,
+	PermitUsed,
+}
+
+/// Bridge event of a message, as stored in the index.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedMessage {
+	pub block_number: u32,
+	pub block_hash: H256,
+	/// Index of the event in the events of the block.
+	pub event_index: u32,
+	pub kind: MessageEventKind,
+	/// Hash of the message, not known for the messages submitted on Avail.
+	pub message_root: Option<H256>,
+	pub message_id: Option<u64>,
+	pub from: Option<H256>,
+	pub to: Option<H256>,
+}
+
+/// Messages to query, by one of the keys of the index. The other keys, if set, filter the
+/// messages found by the first one.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageFilter {
+	pub message_root: Option<H256>,
+	/// Sender or recipient of the message.
+	pub account: Option<H256>,
+	pub block: Option<u32>,
+}
+
+/// Page of the messages matching a filter, in the order they were indexed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub struct Page {
+	pub offset: u32,
+	pub limit: u32,
+}
+
+/// Messages of a page, with the offset of the next page if there are more messages.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessagesPage {
+	pub messages: Vec<IndexedMessage>,
+	pub next_offset: Option<u32>,
+}
+
+impl IndexedMessage {
+	/// Returns the indexed message of a `Vector` event, `None` if it is not a message event.
+	fn from_event(
+		block_number: u32,
+		block_hash: H256,
+		event_index: u32,
+		event: &VectorEvent<Runtime>,
+	) -> Option<Self> {
+		let (kind, message_root, message_id, from, to) = match event {
+			VectorEvent::MessageSubmitted {
+				from,
+				to,
+				message_id,
+				..
+			} => {
+				let from = H256(from.clone().into());
+				(
+					MessageEventKind::Submitted,
+					None,
+					Some(*message_id),
+					Some(from),
+					Some(*to),
+				)
+			},
+			VectorEvent::MessageExecuted {
+				from,
+				to,
+				message_id,
+				message_root,
+			} => (
+				MessageEventKind::Executed,
+				Some(*message_root),
+				Some(*message_id),
+				Some(*from),
+				Some(*to),
+			),
+			VectorEvent::MessageExecutionFailed {
+				from,
+				to,
+				message_id,
+				message_root,
+				..
+			} => (
+				MessageEventKind::ExecutionFailed,
+				Some(*message_root),
+				Some(*message_id),
+				Some(*from),
+				Some(*to),
+			),
+			VectorEvent::MessageQueued {
+				from,
+				to,
+				message_id,
+				message_root,
+			} => (
+				MessageEventKind::Queued,
+				Some(*message_root),
+				Some(*message_id),
+				Some(*from),
+				Some(*to),
+			),
+			VectorEvent::MessageRouted {
+				from,
+				to,
+				message_id,
+				message_root,
+				..
+			} => (
+				MessageEventKind::Routed,
+				Some(*message_root),
+				Some(*message_id),
+				Some(*from),
+				Some(*to),
+			),
+			VectorEvent::MessageVoided { message_root } => (
+				MessageEventKind::Voided,
+				Some(*message_root),
+				None,
+				None,
+				None,
+			),
+			VectorEvent::ExecutionPermitUsed { message_root, .. } => (
+				MessageEventKind::PermitUsed,
+				Some(*message_root),
+				None,
+				None,
+				None,
+			),
+			_ => return None,
+		};
+
+		Some(Self {
+			block_number,
+			block_hash,
+			event_index,
+			kind,
+			message_root,
+			message_id,
+			from,
+			to,
+		})
+	}
+
+	/// Key of the message in the index, unique across forks.
+	fn key(&self) -> Vec<u8> {
+		(b"e", self.block_number, self.block_hash, self.event_index).encode()
+	}
+
+	/// Keys of the lists the message is added to.
+	fn list_keys(&self) -> Vec<Vec<u8>> {
+		let mut keys = vec![block_list_key(self.block_number)];
+		keys.extend(self.message_root.map(message_list_key));
+		keys.extend(self.from.map(account_list_key));
+		keys.extend(
+			self.to
+				.filter(|to| Some(*to) != self.from)
+				.map(account_list_key),
+		);
+		keys
+	}
+
+	fn matches(&self, filter: &MessageFilter) -> bool {
+		filter
+			.message_root
+			.map_or(true, |root| self.message_root == Some(root))
+			&& filter.account.map_or(true, |account| {
+				self.from == Some(account) || self.to == Some(account)
+			}) && filter
+			.block
+			.map_or(true, |block| self.block_number == block)
+	}
+}
+
+fn message_list_key(message_root: H256) -> Vec<u8> {
+	(b"m", message_root).encode()
+}
+
+fn account_list_key(account: H256) -> Vec<u8> {
+	(b"a", account).encode()
+}
+
+fn block_list_key(block_number: u32) -> Vec<u8> {
+	(b"b", block_number).encode()
+}
+
+/// Index of the bridge messages in the offchain storage.
+#[derive(Clone)]
+pub struct BridgeIndex<S> {
+	storage: S,
+}
+
+impl<S: OffchainStorage> BridgeIndex<S> {
+	pub fn new(storage: S) -> Self {
+		Self { storage }
+	}
+
+	fn get<V: Decode>(&self, key: &[u8]) -> Option<V> {
+		let raw = self.storage.get(INDEX_PREFIX, key)?;
+		V::decode(&mut raw.as_slice()).ok()
+	}
+
+	fn indexed_head(&self) -> Option<u32> {
+		self.get(INDEXED_HEAD_KEY)
+	}
+
+	fn set_indexed_head(&mut self, block_number: u32) {
+		self.storage
+			.set(INDEX_PREFIX, INDEXED_HEAD_KEY, &block_number.encode());
+	}
+
+	/// Adds a message to the index and to its lists.
+	fn insert(&mut self, message: &IndexedMessage) {
+		let key = message.key();
+		self.storage.set(INDEX_PREFIX, &key, &message.encode());
+		for list_key in message.list_keys() {
+			let mut list: Vec<Vec<u8>> = self.get(&list_key).unwrap_or_default();
+			if !list.contains(&key) {
+				list.push(key.clone());
+				self.storage.set(INDEX_PREFIX, &list_key, &list.encode());
+			}
+		}
+	}
+
+	/// Returns a page of the messages matching `filter` on canonical blocks, as decided by
+	/// `is_canonical`.
+	pub fn query(
+		&self,
+		filter: &MessageFilter,
+		page: Page,
+		is_canonical: impl Fn(u32, H256) -> bool,
+	) -> Result<MessagesPage, String> {
+		let list_key = match filter {
+			MessageFilter {
+				message_root: Some(message_root),
+				..
+			} => message_list_key(*message_root),
+			MessageFilter {
+				account: Some(account),
+				..
+			} => account_list_key(*account),
+			MessageFilter {
+				block: Some(block), ..
+			} => block_list_key(*block),
+			_ => return Err("The filter needs a message root, an account or a block".into()),
+		};
+		if page.limit == 0 || page.limit > MAX_PAGE_SIZE {
+			return Err(format!(
+				"The page limit must be between 1 and {MAX_PAGE_SIZE}"
+			));
+		}
+
+		let list: Vec<Vec<u8>> = self.get(&list_key).unwrap_or_default();
+		let mut matching = list
+			.iter()
+			.filter_map(|key| self.get::<IndexedMessage>(key))
+			.filter(|message| message.matches(filter))
+			.filter(|message| is_canonical(message.block_number, message.block_hash))
+			.skip(page.offset as usize);
+		let messages: Vec<IndexedMessage> = matching.by_ref().take(page.limit as usize).collect();
+		let next_offset = matching
+			.next()
+			.map(|_| page.offset.saturating_add(page.limit));
+
+		Ok(MessagesPage {
+			messages,
+			next_offset,
+		})
+	}
+}
+
+/// Indexes the bridge messages of the imported blocks.
+pub struct BridgeIndexer<C, BE, S> {
+	client: Arc<C>,
+	index: BridgeIndex<S>,
+	_backend: PhantomData<BE>,
+}
+
+impl<C, BE, S> BridgeIndexer<C, BE, S>
+where
+	C: BlockchainEvents<Block> + HeaderBackend<Block> + StorageProvider<Block, BE>,
+	BE: sc_client_api::Backend<Block>,
+	S: OffchainStorage,
+{
+	pub fn new(client: Arc<C>, storage: S) -> Self {
+		Self {
+			client,
+			index: BridgeIndex::new(storage),
+			_backend: PhantomData,
+		}
+	}
+
+	pub async fn run(mut self) {
+		let mut imports = self.client.import_notification_stream();
+		while let Some(notification) = imports.next().await {
+			let number = *notification.header.number();
+			if !notification.is_new_best {
+				self.index_block(number, notification.hash);
+				continue;
+			}
+
+			for (number, hash) in self.skipped_blocks(notification.header.parent_hash(), number) {
+				self.index_block(number, hash);
+			}
+			self.index_block(number, notification.hash);
+			self.index.set_indexed_head(number);
+		}
+	}
+
+	/// Returns the ancestors of the block after `number - 1` which were not indexed, oldest
+	/// first.
+	fn skipped_blocks(&self, parent_hash: &Hash, number: u32) -> Vec<(u32, Hash)> {
+		let Some(indexed_head) = self.index.indexed_head() else {
+			return Vec::new();
+		};
+		let first = indexed_head
+			.saturating_add(1)
+			.max(number.saturating_sub(MAX_CATCH_UP_BLOCKS));
+
+		let mut skipped = Vec::new();
+		let mut hash = *parent_hash;
+		let mut number = number.saturating_sub(1);
+		while number >= first && number > 0 {
+			skipped.push((number, hash));
+			match self.client.header(hash) {
+				Ok(Some(header)) => hash = *header.parent_hash(),
+				_ => break,
+			}
+			number -= 1;
+		}
+		skipped.reverse();
+		skipped
+	}
+
+	fn index_block(&mut self, number: u32, hash: Hash) {
+		let Some(events) = block_events::<_, BE>(&*self.client, hash) else {
+			return;
+		};
+
+		for (event_index, record) in events.iter().enumerate() {
+			let RuntimeEvent::Vector(event) = &record.event else {
+				continue;
+			};
+			if let Some(message) =
+				IndexedMessage::from_event(number, hash, event_index as u32, event)
+			{
+				self.index.insert(&message);
+			}
+		}
+	}
+}
+
+/// Returns the events of the block `hash`, `None` if they cannot be read.
+pub(crate) fn block_events<C, BE>(client: &C, hash: Hash) -> Option<Vec<EventRecord>>
+where
+	C: StorageProvider<Block, BE>,
+	BE: sc_client_api::Backend<Block>,
+{
+	let events_key = StorageKey(frame_system::Events::<Runtime>::hashed_key().to_vec());
+	let raw = match client.storage(hash, &events_key) {
+		Ok(Some(raw)) => raw,
+		Ok(None) => return None,
+		Err(e) => {
+			log::warn!(target: LOG_TARGET, "Events of block {hash:?}: {e:?}");
+			return None;
+		},
+	};
+	match Vec::<EventRecord>::decode(&mut raw.0.as_slice()) {
+		Ok(events) => Some(events),
+		Err(e) => {
+			log::warn!(target: LOG_TARGET, "Cannot decode the events of block {hash:?}: {e}");
+			None
+		},
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::offchain::storage::InMemOffchainStorage;
+
+	fn executed(block_number: u32, event_index: u32, to: H256) -> IndexedMessage {
+		IndexedMessage {
+			block_number,
+			block_hash: H256::repeat_byte(block_number as u8),
+			event_index,
+			kind: MessageEventKind::Executed,
+			message_root: Some(H256::from_low_u64_be(event_index.into())),
+			message_id: Some(event_index.into()),
+			from: Some(H256::repeat_byte(0xff)),
+			to: Some(to),
+		}
+	}
+
+	#[test]
+	fn query_pages_messages_of_account() {
+		let mut index = BridgeIndex::new(InMemOffchainStorage::default());
+		let account = H256::repeat_byte(1);
+		for event_index in 0..3 {
+			index.insert(&executed(1, event_index, account));
+		}
+		index.insert(&executed(2, 3, H256::repeat_byte(2)));
+		let filter = MessageFilter {
+			account: Some(account),
+			..Default::default()
+		};
+
+		let first = index
+			.query(
+				&filter,
+				Page {
+					offset: 0,
+					limit: 2,
+				},
+				|_, _| true,
+			)
+			.unwrap();
+		assert_eq!(
+			vec![executed(1, 0, account), executed(1, 1, account)],
+			first.messages
+		);
+		assert_eq!(Some(2), first.next_offset);
+
+		let second = index
+			.query(
+				&filter,
+				Page {
+					offset: 2,
+					limit: 2,
+				},
+				|_, _| true,
+			)
+			.unwrap();
+		assert_eq!(vec![executed(1, 2, account)], second.messages);
+		assert_eq!(None, second.next_offset);
+	}
+
+	#[test]
+	fn query_skips_retracted_blocks() {
+		let mut index = BridgeIndex::new(InMemOffchainStorage::default());
+		let message = executed(1, 0, H256::repeat_byte(1));
+		index.insert(&message);
+		let filter = MessageFilter {
+			message_root: message.message_root,
+			..Default::default()
+		};
+
+		let page = index
+			.query(
+				&filter,
+				Page {
+					offset: 0,
+					limit: 10,
+				},
+				|_, hash| hash != message.block_hash,
+			)
+			.unwrap();
+
+		assert!(page.messages.is_empty());
+	}
+
+	#[test]
+	fn query_needs_a_key() {
+		let index = BridgeIndex::new(InMemOffchainStorage::default());
+
+		let result = index.query(
+			&MessageFilter::default(),
+			Page {
+				offset: 0,
+				limit: 10,
+			},
+			|_, _| true,
+		);
+
+		assert!(result.is_err());
+	}
+}
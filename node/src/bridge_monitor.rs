@@ -0,0 +1,51 @@
+//! # Bridge Monitor
+//!
+//! Follows the best block and exports the number of bridge message ids skipped by the relayers,
+//! as reported by the `missing_nonces` runtime API, in the `avail_bridge_missing_nonces` gauge.
+//! Operators alerting on the gauge notice omitted messages without querying the chain.
+use avail_base::metrics::avail::BridgeMetrics;
+use da_runtime::{apis::VectorApi, NodeBlock as Block};
+use futures::StreamExt;
+use sc_client_api::BlockchainEvents;
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use std::sync::Arc;
+
+const LOG_TARGET: &str = "avail::node::bridge_monitor";
+
+/// Updates the missing nonces gauge on every new best block.
+pub async fn run<C>(client: Arc<C>)
+where
+	C: BlockchainEvents<Block> + ProvideRuntimeApi<Block>,
+	C::Api: VectorApi<Block>,
+{
+	let mut imports = client.import_notification_stream();
+	while let Some(notification) = imports.next().await {
+		if !notification.is_new_best {
+			continue;
+		}
+
+		let at = notification.hash;
+		let api = client.runtime_api();
+		match api.api_version::<dyn VectorApi<Block>>(at) {
+			Ok(Some(version)) if version >= 9 => {},
+			_ => continue,
+		}
+
+		match api.missing_nonces(at) {
+			Ok(missing) => {
+				for gap in missing.iter() {
+					log::warn!(
+						target: LOG_TARGET,
+						"Messages {:?} of broadcaster {:?} on domain {} are not executed at {at:?}",
+						gap.nonces,
+						gap.broadcaster,
+						gap.domain
+					);
+				}
+				let count = missing.iter().map(|gap| gap.nonces.len() as u64).sum();
+				BridgeMetrics::observe_missing_nonces(count);
+			},
+			Err(e) => log::debug!(target: LOG_TARGET, "Missing nonces at {at:?}: {e:?}"),
+		}
+	}
+}
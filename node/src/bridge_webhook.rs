@@ -0,0 +1,259 @@
+//! # Bridge Webhook
+//!
+//! Posts the bridge events of the finalized blocks as JSON to a webhook, so integrators without
+//! an indexer are notified of the head updates and of the executed or failed messages. One
+//! payload is posted per finalized block with bridge events, in the order the blocks are
+//! finalized. A payload which cannot be delivered after `MAX_ATTEMPTS` attempts is dropped.
+use da_runtime::{Hash, NodeBlock as Block, Runtime, RuntimeEvent};
+use futures::StreamExt;
+use hyper::{
+	client::HttpConnector,
+	header::{HeaderValue, AUTHORIZATION, CONTENT_TYPE},
+	Body, Client, Request, Uri,
+};
+use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
+use pallet_vector::Event as VectorEvent;
+use sc_client_api::{BlockchainEvents, StorageProvider};
+use serde::Serialize;
+use sp_blockchain::HeaderBackend;
+use sp_core::H256;
+use std::{iter, marker::PhantomData, sync::Arc, time::Duration};
+
+use crate::{bridge_index::block_events, cli::Cli};
+
+const LOG_TARGET: &str = "avail::node::bridge_webhook";
+
+/// Number of deliveries of a payload before it is dropped.
+const MAX_ATTEMPTS: u32 = 3;
+/// Time to wait for the response of the webhook.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// Delay before the second delivery of a payload, doubled for each further one.
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Webhook the bridge events are posted to.
+#[derive(Debug, Clone)]
+pub struct BridgeWebhookConfig {
+	pub url: Uri,
+	/// Value of the `Authorization` header of the requests.
+	pub auth_header: Option<HeaderValue>,
+}
+
+impl BridgeWebhookConfig {
+	/// Returns the webhook configured on the command line, if any.
+	pub fn from_cli(cli: &Cli) -> Option<Self> {
+		Some(Self {
+			url: cli.bridge_webhook_url.clone()?,
+			auth_header: cli.bridge_webhook_auth_header.clone().map(|mut value| {
+				value.set_sensitive(true);
+				value
+			}),
+		})
+	}
+}
+
+/// Bridge events of a finalized block, as posted to the webhook.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BlockPayload {
+	block_number: u32,
+	block_hash: H256,
+	events: Vec<WebhookEvent>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum WebhookEvent {
+	#[serde(rename_all = "camelCase")]
+	HeadUpdated {
+		event_index: u32,
+		slot: u64,
+		finalization_root: H256,
+		execution_state_root: H256,
+	},
+	#[serde(rename_all = "camelCase")]
+	MessageExecuted {
+		event_index: u32,
+		message_id: u64,
+		message_root: H256,
+		from: H256,
+		to: H256,
+	},
+	#[serde(rename_all = "camelCase")]
+	MessageExecutionFailed {
+		event_index: u32,
+		message_id: u64,
+		message_root: H256,
+		from: H256,
+		to: H256,
+		error: String,
+	},
+}
+
+impl WebhookEvent {
+	/// Returns the webhook event of a `Vector` event, `None` if it is not posted.
+	fn from_event(event_index: u32, event: &VectorEvent<Runtime>) -> Option<Self> {
+		let event = match event {
+			VectorEvent::HeadUpdated {
+				slot,
+				finalization_root,
+				execution_state_root,
+			} => Self::HeadUpdated {
+				event_index,
+				slot: *slot,
+				finalization_root: *finalization_root,
+				execution_state_root: *execution_state_root,
+			},
+			VectorEvent::MessageExecuted {
+				from,
+				to,
+				message_id,
+				message_root,
+			} => Self::MessageExecuted {
+				event_index,
+				message_id: *message_id,
+				message_root: *message_root,
+				from: *from,
+				to: *to,
+			},
+			VectorEvent::MessageExecutionFailed {
+				from,
+				to,
+				message_id,
+				message_root,
+				error,
+			} => Self::MessageExecutionFailed {
+				event_index,
+				message_id: *message_id,
+				message_root: *message_root,
+				from: *from,
+				to: *to,
+				error: format!("{error:?}"),
+			},
+			_ => return None,
+		};
+		Some(event)
+	}
+}
+
+/// Posts the bridge events of the finalized blocks to the webhook.
+pub struct BridgeWebhook<C, BE> {
+	client: Arc<C>,
+	config: BridgeWebhookConfig,
+	http: Client<HttpsConnector<HttpConnector>>,
+	_backend: PhantomData<BE>,
+}
+
+impl<C, BE> BridgeWebhook<C, BE>
+where
+	C: BlockchainEvents<Block> + HeaderBackend<Block> + StorageProvider<Block, BE>,
+	BE: sc_client_api::Backend<Block>,
+{
+	pub fn new(client: Arc<C>, config: BridgeWebhookConfig) -> Self {
+		let https = HttpsConnectorBuilder::new()
+			.with_webpki_roots()
+			.https_or_http()
+			.enable_http1()
+			.build();
+		Self {
+			client,
+			config,
+			http: Client::builder().build(https),
+			_backend: PhantomData,
+		}
+	}
+
+	pub async fn run(self) {
+		let mut finalized = self.client.finality_notification_stream();
+		while let Some(notification) = finalized.next().await {
+			// The route holds the blocks finalized along with the notified one, oldest first.
+			for hash in notification
+				.tree_route
+				.iter()
+				.chain(iter::once(&notification.hash))
+			{
+				if let Some(payload) = self.block_payload(*hash) {
+					self.post(&payload).await;
+				}
+			}
+		}
+	}
+
+	/// Returns the payload of the block `hash`, `None` if it has no bridge event.
+	fn block_payload(&self, hash: Hash) -> Option<BlockPayload> {
+		let block_number = self.client.number(hash).ok().flatten()?;
+		let events: Vec<_> = block_events::<_, BE>(&*self.client, hash)?
+			.iter()
+			.enumerate()
+			.filter_map(|(event_index, record)| match &record.event {
+				RuntimeEvent::Vector(event) => WebhookEvent::from_event(event_index as u32, event),
+				_ => None,
+			})
+			.collect();
+		if events.is_empty() {
+			return None;
+		}
+
+		Some(BlockPayload {
+			block_number,
+			block_hash: hash,
+			events,
+		})
+	}
+
+	async fn post(&self, payload: &BlockPayload) {
+		let body = match serde_json::to_vec(payload) {
+			Ok(body) => body,
+			Err(e) => {
+				log::error!(target: LOG_TARGET, "Cannot encode the webhook payload: {e}");
+				return;
+			},
+		};
+
+		let mut delay = RETRY_DELAY;
+		for attempt in 1..=MAX_ATTEMPTS {
+			if attempt > 1 {
+				tokio::time::sleep(delay).await;
+				delay *= 2;
+			}
+
+			let mut request =
+				Request::post(self.config.url.clone()).header(CONTENT_TYPE, "application/json");
+			if let Some(auth_header) = &self.config.auth_header {
+				request = request.header(AUTHORIZATION, auth_header.clone());
+			}
+			let request = match request.body(Body::from(body.clone())) {
+				Ok(request) => request,
+				Err(e) => {
+					log::error!(target: LOG_TARGET, "Invalid webhook request: {e}");
+					return;
+				},
+			};
+
+			match tokio::time::timeout(REQUEST_TIMEOUT, self.http.request(request)).await {
+				Ok(Ok(response)) if response.status().is_success() => return,
+				Ok(Ok(response)) => log::warn!(
+					target: LOG_TARGET,
+					"Webhook answered {} to the events of block #{}",
+					response.status(),
+					payload.block_number
+				),
+				Ok(Err(e)) => log::warn!(
+					target: LOG_TARGET,
+					"Cannot post the events of block #{}: {e}",
+					payload.block_number
+				),
+				Err(_) => log::warn!(
+					target: LOG_TARGET,
+					"Webhook did not answer within {REQUEST_TIMEOUT:?} to the events of block #{}",
+					payload.block_number
+				),
+			}
+		}
+
+		log::error!(
+			target: LOG_TARGET,
+			"Dropped the events of block #{} after {MAX_ATTEMPTS} attempts",
+			payload.block_number
+		);
+	}
+}
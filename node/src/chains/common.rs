@@ -15,6 +15,7 @@ use sc_telemetry::TelemetryEndpoints;
 use serde_json::{json, Value};
 use sp_core::crypto::AccountId32;
 use sp_core::sr25519::Public;
+use sp_core::{H256, U256};
 
 pub const PROTOCOL_ID: &str = "Avail";
 pub const TESTNET_TELEMETRY_URL: &str = "ws://telemetry.avail.tools:8001/submit";
@@ -131,22 +132,7 @@ pub fn runtime_genesis_config(
 		"treasuryCommittee": {
 			"members": treasury_committee,
 		},
-		"vector": {
-			"broadcaster": BROADCASTER,
-			"broadcasterDomain": BROADCASTER_DOMAIN,
-			"finalityThreshold": FINALITY_THRESHOLD,
-			"functionIds": (STEP_FUNCTION_ID, ROTATE_FUNCTION_ID),
-			"genesisTime": GENESIS_TIME,
-			"genesisValidatorRoot": GENESIS_VALIDATOR_ROOT,
-			"period": PERIOD,
-			"secondsPerSlot": SECONDS_PER_SLOT,
-			"slotsPerPeriod": SLOTS_PER_PERIOD,
-			"sourceChainId": SOURCE_CHAIN_ID,
-			"syncCommitteePoseidon":get_poseidon_hash_for_period(),
-			"stepVerificationKey": STEP_VK.as_bytes().to_vec(),
-			"rotateVerificationKey": ROTATE_VK.as_bytes().to_vec(),
-			"whitelistedDomains": vec![2],
-		},
+		"vector": VectorGenesis::default().build(),
 		"nominationPools": {
 			"minCreateBond": constants::nomination_pools::MIN_CREATE_BOND,
 			"minJoinBond": constants::nomination_pools::MIN_JOIN_BOND,
@@ -159,3 +145,105 @@ pub fn runtime_genesis_config(
 		},
 	})
 }
+
+/// Genesis of the Vector pallet: the circuits verifying the light client updates and the source
+/// chain they follow. Defaults to the mainnet circuits following Ethereum mainnet, networks
+/// running other circuits or following another chain override them.
+#[derive(Clone)]
+pub struct VectorGenesis {
+	step_function_id: H256,
+	rotate_function_id: H256,
+	step_verification_key: Vec<u8>,
+	rotate_verification_key: Vec<u8>,
+	period: u64,
+	sync_committee_hash: U256,
+	genesis_time: u64,
+	genesis_validator_root: H256,
+	source_chain_id: u64,
+	broadcaster: H256,
+	broadcaster_domain: u32,
+}
+
+impl Default for VectorGenesis {
+	fn default() -> Self {
+		Self {
+			step_function_id: STEP_FUNCTION_ID,
+			rotate_function_id: ROTATE_FUNCTION_ID,
+			step_verification_key: STEP_VK.as_bytes().to_vec(),
+			rotate_verification_key: ROTATE_VK.as_bytes().to_vec(),
+			period: PERIOD,
+			sync_committee_hash: get_poseidon_hash_for_period(),
+			genesis_time: GENESIS_TIME,
+			genesis_validator_root: GENESIS_VALIDATOR_ROOT,
+			source_chain_id: SOURCE_CHAIN_ID,
+			broadcaster: BROADCASTER,
+			broadcaster_domain: BROADCASTER_DOMAIN,
+		}
+	}
+}
+
+impl VectorGenesis {
+	/// Sets the step and rotate circuits, with their function ids and verification keys.
+	pub fn with_circuits(
+		mut self,
+		function_ids: (H256, H256),
+		step_verification_key: Vec<u8>,
+		rotate_verification_key: Vec<u8>,
+	) -> Self {
+		(self.step_function_id, self.rotate_function_id) = function_ids;
+		self.step_verification_key = step_verification_key;
+		self.rotate_verification_key = rotate_verification_key;
+		self
+	}
+
+	/// Sets the sync committee the light client starts from.
+	pub fn with_sync_committee(mut self, period: u64, sync_committee_hash: U256) -> Self {
+		self.period = period;
+		self.sync_committee_hash = sync_committee_hash;
+		self
+	}
+
+	/// Sets the beacon chain followed by the light client.
+	pub fn with_source_chain(
+		mut self,
+		genesis_time: u64,
+		genesis_validator_root: H256,
+		source_chain_id: u64,
+	) -> Self {
+		self.genesis_time = genesis_time;
+		self.genesis_validator_root = genesis_validator_root;
+		self.source_chain_id = source_chain_id;
+		self
+	}
+
+	/// Sets the broadcaster contract of the source chain.
+	pub fn with_broadcaster(mut self, domain: u32, broadcaster: H256) -> Self {
+		self.broadcaster_domain = domain;
+		self.broadcaster = broadcaster;
+		self
+	}
+
+	/// Returns the genesis config patch of the pallet.
+	pub fn build(self) -> Value {
+		json!({
+			"broadcaster": self.broadcaster,
+			"broadcasterDomain": self.broadcaster_domain,
+			"finalityThreshold": FINALITY_THRESHOLD,
+			"functionIds": (self.step_function_id, self.rotate_function_id),
+			"finality": {
+				"slot": {
+					"genesisTime": self.genesis_time,
+					"secondsPerSlot": SECONDS_PER_SLOT,
+				},
+			},
+			"genesisValidatorRoot": self.genesis_validator_root,
+			"period": self.period,
+			"slotsPerPeriod": SLOTS_PER_PERIOD,
+			"sourceChainId": self.source_chain_id,
+			"syncCommitteeHash": self.sync_committee_hash,
+			"stepVerificationKey": self.step_verification_key,
+			"rotateVerificationKey": self.rotate_verification_key,
+			"whitelistedDomains": vec![2],
+		})
+	}
+}
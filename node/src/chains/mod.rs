@@ -77,6 +77,225 @@ pub mod dev_tri {
 	}
 }
 
+/// Development chain with a pre-initialized Vector bridge, so that relayers and integration
+/// tests can run without a live Ethereum connection.
+///
+/// The slot roots, the broadcaster and the messages that can be executed against them are
+/// documented in `misc/bridge-dev/test-vectors.json`. Alice is the bridge updater.
+pub mod bridge_dev {
+	use super::*;
+	use da_runtime::{constants, wasm_binary_unwrap, AccountId, Runtime};
+	use sc_chain_spec::ChainType;
+	use serde::Deserialize;
+	use serde_json::json;
+	use sp_core::{Get, H256};
+	use sp_runtime::traits::AccountIdConversion;
+
+	const TEST_VECTORS: &str = include_str!("./../../../misc/bridge-dev/test-vectors.json");
+
+	#[derive(Deserialize)]
+	#[serde(rename_all = "camelCase")]
+	struct TestVectors {
+		broadcaster: TestBroadcaster,
+		slots: Vec<TestSlot>,
+	}
+
+	#[derive(Deserialize)]
+	#[serde(rename_all = "camelCase")]
+	struct TestBroadcaster {
+		domain: u32,
+		address: H256,
+	}
+
+	#[derive(Deserialize)]
+	#[serde(rename_all = "camelCase")]
+	struct TestSlot {
+		slot: u64,
+		header_root: H256,
+		execution_state_root: H256,
+	}
+
+	pub fn chain_spec() -> ChainSpec {
+		ChainSpec::builder(wasm_binary_unwrap(), Default::default())
+			.with_name("Avail Bridge Development Network")
+			.with_id("avail_bridge_development_network")
+			.with_chain_type(ChainType::Development)
+			.with_genesis_config_patch(genesis_constructor())
+			.with_protocol_id(PROTOCOL_ID)
+			.with_properties(chain_properties())
+			.with_boot_nodes(vec![])
+			.build()
+	}
+
+	pub fn genesis_constructor() -> Value {
+		let alice = AuthorityKeys::from_seed("Alice");
+		let sudo = alice.controller.clone();
+		let updater: [u8; 32] = sudo.clone().into();
+		let vectors: TestVectors =
+			serde_json::from_str(TEST_VECTORS).expect("Bridge dev test vectors are valid; qed");
+
+		let mut genesis =
+			runtime_genesis_config(sudo.clone(), vec![sudo.clone()], vec![sudo], vec![alice]);
+
+		// Fund the bridge account, so the seeded fungible token messages can be executed.
+		let bridge_account: AccountId =
+			<Runtime as pallet_vector::Config>::PalletId::get().into_account_truncating();
+		if let Some(balances) = genesis["balances"]["balances"].as_array_mut() {
+			balances.push(json!((
+				bridge_account,
+				constants::staking::MIN_VALIDATOR_BOND * 100
+			)));
+		}
+
+		let slot_roots: Vec<(u64, H256, H256)> = vectors
+			.slots
+			.iter()
+			.map(|s| (s.slot, s.header_root, s.execution_state_root))
+			.collect();
+		let vector = &mut genesis["vector"];
+		vector["updater"] = json!(H256(updater));
+		vector["broadcaster"] = json!(vectors.broadcaster.address);
+		vector["broadcasterDomain"] = json!(vectors.broadcaster.domain);
+		vector["slotRoots"] = json!(slot_roots);
+
+		genesis
+	}
+
+	#[test]
+	fn test_chain_spec_creation() {
+		chain_spec().build_storage().unwrap();
+	}
+}
+
+/// Public testnet for partners to exercise the Vector bridge against an Ethereum testnet beacon
+/// chain, without mainnet state.
+///
+/// Alice, Bob and Charlie are the initial authorities, Alice is sudo and the bridge updater and
+/// `//Faucet` holds the funds handed out to testers. The boot nodes run with the node keys
+/// `0x0101..01`, `0x0202..02` and `0x0303..03`.
+///
+/// The broadcaster and the sync committee of the starting period are not known at genesis, they
+/// are set through `set_broadcaster` and `set_poseidon_hash` once the network is up.
+pub mod turing_like_testnet {
+	use super::*;
+	use da_runtime::{constants, wasm_binary_unwrap, AccountId, Runtime};
+	use hex_literal::hex;
+	use sc_chain_spec::ChainType;
+	use sc_network::config::MultiaddrWithPeerId;
+	use serde_json::json;
+	use sp_core::{sr25519, Get, H256, U256};
+	use sp_runtime::traits::AccountIdConversion;
+
+	const BOOT_NODES: [&str; 3] = [
+		"/dns/bootnode-turing-like-001.avail.tools/tcp/30333/p2p/12D3KooWK99VoVxNE7XzyBwXEzW7xhK7Gpv85r9F3V3fyKSUKPH5",
+		"/dns/bootnode-turing-like-002.avail.tools/tcp/30333/p2p/12D3KooWJWoaqZhDaoEFshF7Rh1bpY9ohihFhzcW6d69Lr2NASuq",
+		"/dns/bootnode-turing-like-003.avail.tools/tcp/30333/p2p/12D3KooWRndVhVZPCiQwHBBBdg769GyrPUW13zxwqQyf9r3ANaba",
+	];
+
+	/// Ethereum testnet whose beacon chain the bridge follows.
+	#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+	pub enum BeaconPreset {
+		Holesky,
+		Sepolia,
+	}
+
+	impl BeaconPreset {
+		fn genesis_time(self) -> u64 {
+			match self {
+				Self::Holesky => 1695902400,
+				Self::Sepolia => 1655733600,
+			}
+		}
+
+		fn genesis_validator_root(self) -> H256 {
+			match self {
+				Self::Holesky => H256(hex!(
+					"9143aa7c615a7f7115e2b6aac319c03529df8242ae705fba9df39b79c59fa8b1"
+				)),
+				Self::Sepolia => H256(hex!(
+					"d8ea171f3c94aea21ebc42a1ed61052acf3f9209c00e4efbaaddac09ed9b8078"
+				)),
+			}
+		}
+
+		fn source_chain_id(self) -> u64 {
+			match self {
+				Self::Holesky => 17000,
+				Self::Sepolia => 11155111,
+			}
+		}
+	}
+
+	pub fn chain_spec(beacon: BeaconPreset) -> ChainSpec {
+		let boot_nodes = BOOT_NODES
+			.iter()
+			.map(|addr| {
+				addr.parse::<MultiaddrWithPeerId>()
+					.expect("Valid boot node; qed")
+			})
+			.collect();
+
+		ChainSpec::builder(wasm_binary_unwrap(), Default::default())
+			.with_name("Avail Turing-like Test Network")
+			.with_id("avail_turing_like_test_network")
+			.with_chain_type(ChainType::Live)
+			.with_genesis_config_patch(genesis_constructor(beacon))
+			.with_telemetry_endpoints(super::to_telemetry_endpoint(TESTNET_TELEMETRY_URL.into()))
+			.with_protocol_id(PROTOCOL_ID)
+			.with_properties(chain_properties())
+			.with_boot_nodes(boot_nodes)
+			.build()
+	}
+
+	pub fn genesis_constructor(beacon: BeaconPreset) -> Value {
+		let alice = AuthorityKeys::from_seed("Alice");
+		let bob = AuthorityKeys::from_seed("Bob");
+		let charlie = AuthorityKeys::from_seed("Charlie");
+		let sudo = alice.controller.clone();
+		let updater: [u8; 32] = sudo.clone().into();
+
+		let mut genesis = runtime_genesis_config(
+			sudo.clone(),
+			vec![sudo.clone()],
+			vec![sudo],
+			vec![alice, bob, charlie],
+		);
+
+		// Fund the faucet, and the bridge account so that fungible token messages can be executed.
+		let faucet = get_account_id_from_seed::<sr25519::Public>("Faucet");
+		let bridge_account: AccountId =
+			<Runtime as pallet_vector::Config>::PalletId::get().into_account_truncating();
+		if let Some(balances) = genesis["balances"]["balances"].as_array_mut() {
+			balances.push(json!((
+				faucet,
+				constants::staking::MIN_VALIDATOR_BOND * 10_000
+			)));
+			balances.push(json!((
+				bridge_account,
+				constants::staking::MIN_VALIDATOR_BOND * 100
+			)));
+		}
+
+		genesis["vector"] = VectorGenesis::default()
+			.with_source_chain(
+				beacon.genesis_time(),
+				beacon.genesis_validator_root(),
+				beacon.source_chain_id(),
+			)
+			.with_sync_committee(0, U256::zero())
+			.build();
+		genesis["vector"]["updater"] = json!(H256(updater));
+
+		genesis
+	}
+
+	#[test]
+	fn test_chain_spec_creation() {
+		chain_spec(BeaconPreset::Holesky).build_storage().unwrap();
+		chain_spec(BeaconPreset::Sepolia).build_storage().unwrap();
+	}
+}
+
 pub mod devnet0 {
 	use super::*;
 
@@ -55,15 +55,166 @@ pub struct Cli {
 
 	/// The maximum number of cells that can be requested in one go.
 	///
+	/// It also caps the cells of all blocks requested with `kate_queryProofMulti`.
 	/// Max size cannot exceed 10_000
 	#[arg(long, default_value_t = 64, value_parser=kate_max_cells_size_upper_bound)]
 	pub kate_max_cells_size: usize,
+
+	/// Enable gossiping of verified Vector step proofs and pending messages between nodes, with
+	/// `succinct_gossipStepProof` and `succinct_gossipPendingMessage` for the local relayer to
+	/// publish its proofs
+	#[clap(long = "enable-vector-gossip", default_value_t = false)]
+	pub vector_gossip_enabled: bool,
+
+	/// Include the latest Vector step proof received over the gossip in the authored blocks, so
+	/// the bridge head follows block production instead of the relayers' submissions.
+	///
+	/// Requires `--enable-vector-gossip`, over which the local relayer submits its proofs with
+	/// `succinct_gossipStepProof`.
+	#[clap(long = "enable-vector-step-inherents", default_value_t = false)]
+	pub vector_step_inherents_enabled: bool,
+
+	/// Index the bridge message events in the offchain storage and serve them with
+	/// `succinct_queryMessages`.
+	#[clap(long = "enable-bridge-index", default_value_t = false)]
+	pub bridge_index_enabled: bool,
+
+	/// URL the bridge events of the finalized blocks are posted to as JSON: the head updates and
+	/// the executed or failed messages.
+	#[arg(long, value_name = "URL")]
+	pub bridge_webhook_url: Option<hyper::Uri>,
+
+	/// Value of the `Authorization` header of the bridge webhook requests, e.g. `Bearer <TOKEN>`.
+	#[arg(long, value_name = "VALUE", requires = "bridge_webhook_url")]
+	pub bridge_webhook_auth_header: Option<hyper::header::HeaderValue>,
+
+	/// Serve the cells of finalized blocks and their proofs to light clients over libp2p.
+	///
+	/// The cells of a request are capped by `--kate-max-cells-size`.
+	#[clap(long = "enable-da-cells-protocol", default_value_t = false)]
+	pub da_cells_protocol_enabled: bool,
+
+	/// Order data submissions in authored blocks by fee-per-byte
+	#[clap(long = "enable-da-tx-priority", default_value_t = false)]
+	pub da_tx_priority_enabled: bool,
+
+	/// Maximum bytes of submitted data per application id in an authored block
+	#[arg(long, requires = "da_tx_priority_enabled")]
+	pub da_app_block_quota: Option<u32>,
+
+	/// Number of threads building the DA commitments of blocks, the logical CPUs by default.
+	///
+	/// The commitments of an authored or imported block are built on these threads while its
+	/// extrinsics execute, as its data submissions are applied.
+	#[arg(long, value_parser = clap::value_parser!(u16).range(1..))]
+	pub da_commitment_workers: Option<u16>,
+
+	/// Maximum number of calls per minute of each Kate and Vector RPC method.
+	///
+	/// The limit is shared by all the callers of a method.
+	#[arg(long)]
+	pub rpc_method_rate_limit: Option<u32>,
+
+	/// Maximum number of calls per minute of a single RPC method, as `<METHOD>=<CALLS>`.
+	///
+	/// Overrides `--rpc-method-rate-limit` for the method, can be given multiple times.
+	#[arg(long, value_parser = crate::rpc_limits::parse_method_rate_limit)]
+	pub rpc_method_rate_limit_override: Vec<(String, u32)>,
+
+	/// Maximum size in bytes of a Kate or Vector RPC response
+	#[arg(long)]
+	pub rpc_method_max_response_size: Option<usize>,
+
+	/// Maximum number of concurrent subscriptions of each Kate RPC subscription method.
+	///
+	/// The limit is shared by all the callers of a method.
+	#[arg(long, default_value_t = 1024)]
+	pub rpc_method_max_subscriptions: usize,
+
+	/// Address of the RPC server of the operator-only methods, like the verification traces.
+	///
+	/// The operator methods are then no longer served by the public RPC server, and requests to
+	/// the operator server must carry `--operator-rpc-token`.
+	#[arg(long, requires = "operator_rpc_token")]
+	pub operator_rpc_addr: Option<std::net::SocketAddr>,
+
+	/// Token the operator RPC requests must carry as `Authorization: Bearer <TOKEN>`.
+	#[arg(long, requires = "operator_rpc_addr")]
+	pub operator_rpc_token: Option<String>,
+
+	/// PEM certificate chain of the operator RPC server, which then only accepts TLS connections.
+	#[arg(long, requires_all = ["operator_rpc_addr", "operator_rpc_tls_key"])]
+	pub operator_rpc_tls_cert: Option<std::path::PathBuf>,
+
+	/// PEM PKCS#8 private key of the operator RPC server certificate.
+	#[arg(long, requires = "operator_rpc_tls_cert")]
+	pub operator_rpc_tls_key: Option<std::path::PathBuf>,
+
+	/// Milliseconds after which the DA verification of an imported block is logged as slow.
+	///
+	/// The warning lists the time of each check, the matrix size and the extrinsics of the block.
+	#[arg(long, value_name = "MS", default_value = "2000", value_parser = parse_millis)]
+	pub da_slow_verification_threshold: std::time::Duration,
+
+	/// Maximum number of imported blocks whose DA commitments are verified at the same time.
+	#[arg(
+		long,
+		value_name = "COUNT",
+		default_value_t = 4,
+		value_parser = clap::value_parser!(u32).range(1..)
+	)]
+	pub da_import_queue_size: u32,
+
+	/// Memory budget, in MiB, of the bodies of the imported blocks pending DA verification.
+	///
+	/// Blocks wait for the budget before being verified, so low-memory nodes can catch up on many
+	/// large blocks. A block larger than the whole budget is verified alone.
+	#[arg(
+		long,
+		value_name = "MIB",
+		default_value_t = 1024,
+		value_parser = clap::value_parser!(u32).range(1..)
+	)]
+	pub da_import_memory_budget: u32,
+
+	/// Number of blocks failing the DA checks after which the peer serving them is banned.
+	///
+	/// Every such block lowers the reputation of its peer twice as much as the previous one.
+	/// Offences and bans are kept in the database across restarts. `0` disables the bans.
+	#[arg(long, value_name = "COUNT", default_value_t = 3)]
+	pub da_peer_ban_threshold: u32,
+
+	/// Duration, in seconds, of the ban of a peer serving blocks which fail the DA checks.
+	#[arg(long, value_name = "SECS", default_value_t = 24 * 60 * 60)]
+	pub da_peer_ban_duration: u64,
+
+	/// URL of a remote signer holding the keys of the offchain workers, e.g. of the bridge updater.
+	///
+	/// Only the keys of the `--offchain-remote-key-type` types are signed remotely, so they never
+	/// live on this host. The other keys stay in the local keystore.
+	#[arg(long, value_name = "URL", requires = "offchain_remote_key_type")]
+	pub offchain_keystore_uri: Option<String>,
+
+	/// Key type signed by the remote signer, e.g. `imon`. Can be given multiple times.
+	#[arg(
+		long,
+		value_name = "KEY_TYPE",
+		requires = "offchain_keystore_uri",
+		value_parser = crate::remote_keystore::parse_key_type
+	)]
+	pub offchain_remote_key_type: Vec<sp_core::crypto::KeyTypeId>,
 }
 
 fn kate_max_cells_size_upper_bound(s: &str) -> Result<usize, String> {
 	clap_num::number_range(s, 0, 10_000)
 }
 
+fn parse_millis(s: &str) -> Result<std::time::Duration, String> {
+	s.parse()
+		.map(std::time::Duration::from_millis)
+		.map_err(|e| format!("Invalid milliseconds: {e}"))
+}
+
 /// Possible subcommands of the main binary.
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, clap::Subcommand)]
@@ -125,4 +276,19 @@ pub enum Subcommand {
 
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
+
+	/// Print the Vector bridge state from the local database as JSON.
+	InspectBridgeState(crate::inspect_bridge::InspectBridgeStateCmd),
+
+	/// Export the source chain headers verified by the Vector bridge as JSON or CSV.
+	ExportBridgeHeaders(crate::export_bridge_headers::ExportBridgeHeadersCmd),
+
+	/// Measure the DA commitment construction and verification of synthetic blocks.
+	BenchmarkDa(crate::benchmark_da::BenchmarkDaCmd),
+
+	/// Re-verify the DA commitments of a range of blocks from the local database.
+	VerifyDaRange(crate::verify_da_range::VerifyDaRangeCmd),
+
+	/// Compare the storage proof weights of the runtime with their cost on this hardware.
+	VerifyWeights(crate::verify_weights::VerifyWeightsCmd),
 }
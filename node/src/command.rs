@@ -31,6 +31,8 @@ use {
 
 use crate::{
 	cli::{Cli, Subcommand},
+	da_block_import::DaImportLimits,
+	rpc_limits::RpcLimitsConfig,
 	service::{self, new_partial, FullClient},
 };
 
@@ -72,6 +74,13 @@ impl SubstrateCli for Cli {
 			},
 			"dev" => Box::new(chains::dev::chain_spec()),
 			"dev.tri" => Box::new(chains::dev_tri::chain_spec()),
+			"bridge-dev" => Box::new(chains::bridge_dev::chain_spec()),
+			"turing-like-testnet" => Box::new(chains::turing_like_testnet::chain_spec(
+				chains::turing_like_testnet::BeaconPreset::Holesky,
+			)),
+			"turing-like-testnet-sepolia" => Box::new(chains::turing_like_testnet::chain_spec(
+				chains::turing_like_testnet::BeaconPreset::Sepolia,
+			)),
 			"devnet0" => Box::new(chains::devnet0::chain_spec()?),
 			"mainnet" => Box::new(chains::mainnet::chain_spec()?),
 			"turing" => Box::new(chains::turing::chain_spec()?),
@@ -89,6 +98,10 @@ pub fn run() -> Result<()> {
 
 	match &cli.subcommand {
 		None => {
+			if let Some(workers) = cli.da_commitment_workers {
+				frame_system::native::commitment_workers::init(workers.into())
+					.map_err(sc_cli::Error::Input)?;
+			}
 			let runner = cli.create_runner(&cli.run)?;
 			runner.run_node_until_exit(|config| async move {
 				service::new_full(config, cli).map_err(sc_cli::Error::Service)
@@ -208,9 +221,12 @@ pub fn run() -> Result<()> {
 				} = new_partial(
 					&config,
 					cli.unsafe_da_sync,
+					cli.da_slow_verification_threshold,
+					DaImportLimits::from(&cli),
 					cli.kate_max_cells_size,
 					cli.kate_rpc_enabled,
 					cli.kate_rpc_metrics_enabled,
+					RpcLimitsConfig::from(&cli),
 				)?;
 				Ok((cmd.run(client, import_queue), task_manager))
 			})
@@ -225,9 +241,12 @@ pub fn run() -> Result<()> {
 				} = new_partial(
 					&config,
 					cli.unsafe_da_sync,
+					cli.da_slow_verification_threshold,
+					DaImportLimits::from(&cli),
 					cli.kate_max_cells_size,
 					cli.kate_rpc_enabled,
 					cli.kate_rpc_metrics_enabled,
+					RpcLimitsConfig::from(&cli),
 				)?;
 				Ok((cmd.run(client, config.database), task_manager))
 			})
@@ -242,9 +261,12 @@ pub fn run() -> Result<()> {
 				} = new_partial(
 					&config,
 					cli.unsafe_da_sync,
+					cli.da_slow_verification_threshold,
+					DaImportLimits::from(&cli),
 					cli.kate_max_cells_size,
 					cli.kate_rpc_enabled,
 					cli.kate_rpc_metrics_enabled,
+					RpcLimitsConfig::from(&cli),
 				)?;
 				Ok((cmd.run(client, config.chain_spec), task_manager))
 			})
@@ -260,9 +282,12 @@ pub fn run() -> Result<()> {
 				} = new_partial(
 					&config,
 					cli.unsafe_da_sync,
+					cli.da_slow_verification_threshold,
+					DaImportLimits::from(&cli),
 					cli.kate_max_cells_size,
 					cli.kate_rpc_enabled,
 					cli.kate_rpc_metrics_enabled,
+					RpcLimitsConfig::from(&cli),
 				)?;
 				Ok((cmd.run(client, import_queue), task_manager))
 			})
@@ -282,9 +307,12 @@ pub fn run() -> Result<()> {
 				} = new_partial(
 					&config,
 					cli.unsafe_da_sync,
+					cli.da_slow_verification_threshold,
+					DaImportLimits::from(&cli),
 					cli.kate_max_cells_size,
 					cli.kate_rpc_enabled,
 					cli.kate_rpc_metrics_enabled,
+					RpcLimitsConfig::from(&cli),
 				)?;
 				let aux_revert = Box::new(|client: Arc<FullClient>, backend, blocks| {
 					sc_consensus_babe::revert(client.clone(), backend, blocks)?;
@@ -323,5 +351,35 @@ pub fn run() -> Result<()> {
 			let runner = cli.create_runner(cmd)?;
 			runner.sync_run(|config| cmd.run::<Block>(&config))
 		},
+		Some(Subcommand::InspectBridgeState(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.sync_run(|config| cmd.run(&config))
+		},
+		Some(Subcommand::ExportBridgeHeaders(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.sync_run(|config| cmd.run(&config))
+		},
+		Some(Subcommand::BenchmarkDa(cmd)) => cmd.run(),
+		Some(Subcommand::VerifyWeights(cmd)) => cmd.run(),
+		Some(Subcommand::VerifyDaRange(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.async_run(|config| {
+				let PartialComponents {
+					client,
+					task_manager,
+					..
+				} = new_partial(
+					&config,
+					cli.unsafe_da_sync,
+					cli.da_slow_verification_threshold,
+					DaImportLimits::from(&cli),
+					cli.kate_max_cells_size,
+					cli.kate_rpc_enabled,
+					cli.kate_rpc_metrics_enabled,
+					RpcLimitsConfig::from(&cli),
+				)?;
+				Ok((cmd.run(client), task_manager))
+			})
+		},
 	}
 }
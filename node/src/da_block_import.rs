@@ -4,17 +4,19 @@
 /// to Babe and Grandpa.
 /// It double-checks the **extension header** which contains the `Kate Commitment` and `Data
 /// Root`.
-use avail_base::metrics::avail::{MetricObserver, ObserveKind};
+use avail_base::metrics::avail::{ImportBlockMetrics, MetricObserver, ObserveKind};
 use avail_core::{
 	ensure, header::HeaderExtension, BlockLengthColumns, BlockLengthRows, OpaqueExtrinsic,
 	BLOCK_CHUNK_SIZE,
 };
+use codec::Encode;
 use da_runtime::{
 	apis::{DataAvailApi, ExtensionBuilder},
 	Header as DaHeader,
 };
 use frame_system::limits::BlockLength;
 
+use sc_client_api::AuxStore;
 use sc_consensus::{
 	block_import::{BlockCheckParams, BlockImport as BlockImportT, BlockImportParams},
 	ImportResult,
@@ -24,13 +26,57 @@ use sp_blockchain::HeaderBackend;
 use sp_consensus::{BlockOrigin, Error as ConsensusError};
 use sp_core::H256;
 use sp_runtime::traits::Block as BlockT;
-use std::{marker::PhantomData, sync::Arc};
+use std::{
+	marker::PhantomData,
+	sync::Arc,
+	time::{Duration, Instant},
+};
+use tokio::sync::Semaphore;
+
+use crate::{
+	cli::Cli,
+	da_peer_bans::{DaPeerBanConfig, DaPeerBans},
+};
+
+const LOG_TARGET: &str = "avail::node::da_block_import";
+/// Unit of the memory budget permits, so the budget of a large node fits in a `u32`.
+const MEMORY_PERMIT_SIZE: usize = 1024;
+
+/// Backpressure of the DA verification of imported blocks.
+#[derive(Debug, Clone, Copy)]
+pub struct DaImportLimits {
+	/// Maximum number of blocks verified at the same time.
+	pub queue_size: usize,
+	/// Maximum size in bytes of the bodies of the blocks pending verification.
+	pub memory_budget: usize,
+	/// Bans of the peers serving blocks which fail the DA checks.
+	pub peer_bans: DaPeerBanConfig,
+}
+
+impl From<&Cli> for DaImportLimits {
+	fn from(cli: &Cli) -> Self {
+		Self {
+			queue_size: cli.da_import_queue_size as usize,
+			memory_budget: cli.da_import_memory_budget as usize * 1024 * 1024,
+			peer_bans: DaPeerBanConfig::from(cli),
+		}
+	}
+}
 
 pub struct BlockImport<B, C, I> {
 	client: Arc<C>,
 	inner: I,
 	// If true, it skips the DA block import check during sync only.
 	unsafe_da_sync: bool,
+	// DA checks taking longer than this are logged as a warning.
+	slow_verification_threshold: Duration,
+	// Blocks whose DA checks may run at the same time, shared by the clones.
+	verification_slots: Arc<Semaphore>,
+	// Memory budget of the blocks pending verification, in `MEMORY_PERMIT_SIZE` units.
+	memory_budget: Arc<Semaphore>,
+	memory_permits: u32,
+	// Offences of the peers serving blocks which fail the DA checks, shared by the clones.
+	peer_bans: DaPeerBans<C>,
 	_block: PhantomData<B>,
 }
 
@@ -39,18 +85,87 @@ where
 	B: BlockT<Extrinsic = OpaqueExtrinsic, Header = DaHeader, Hash = H256>,
 	I: BlockImportT<B> + Clone + Send + Sync,
 	I::Error: Into<ConsensusError>,
-	C: ProvideRuntimeApi<B> + HeaderBackend<B> + Send + Sync,
+	C: ProvideRuntimeApi<B> + HeaderBackend<B> + AuxStore + Send + Sync,
 	C::Api: DataAvailApi<B> + ExtensionBuilder<B>,
 {
-	pub fn new(client: Arc<C>, inner: I, unsafe_da_sync: bool) -> Self {
+	pub fn new(
+		client: Arc<C>,
+		inner: I,
+		unsafe_da_sync: bool,
+		slow_verification_threshold: Duration,
+		limits: DaImportLimits,
+	) -> Self {
+		let memory_permits =
+			(limits.memory_budget / MEMORY_PERMIT_SIZE).clamp(1, u32::MAX as usize) as u32;
+		let peer_bans = DaPeerBans::new(client.clone(), limits.peer_bans);
 		Self {
 			client,
 			inner,
 			unsafe_da_sync,
+			slow_verification_threshold,
+			verification_slots: Arc::new(Semaphore::new(limits.queue_size.max(1))),
+			memory_budget: Arc::new(Semaphore::new(memory_permits as usize)),
+			memory_permits,
+			peer_bans,
 			_block: PhantomData,
 		}
 	}
 
+	/// Offences and bans of the peers serving blocks which fail the DA checks.
+	pub fn peer_bans(&self) -> &DaPeerBans<C> {
+		&self.peer_bans
+	}
+
+	/// Number of memory permits of `block`. A block larger than the whole budget takes all of it,
+	/// so it is verified alone instead of never.
+	fn memory_permits(&self, block: &BlockImportParams<B>) -> u32 {
+		let size = block.body.as_ref().map_or(0, |body| body.encoded_size());
+		let permits = size
+			.div_ceil(MEMORY_PERMIT_SIZE)
+			.min(self.memory_permits as usize);
+		(permits as u32).max(1)
+	}
+
+	/// Runs the DA checks of `block`, records their duration, outcome and matrix size, and warns
+	/// with the timings of each check if they are slower than `slow_verification_threshold`.
+	fn verify_da(&self, block: &BlockImportParams<B>) -> Result<(), ConsensusError> {
+		let number = block.header.number;
+		let span = sp_tracing::info_span!(
+			target: LOG_TARGET,
+			"da_verification",
+			number,
+			hash = ?block.post_hash(),
+			origin = ?block.origin,
+		);
+		let _enter = span.enter();
+
+		let start = Instant::now();
+		let result = self.ensure_last_extrinsic_is_failed_send_message_txs(block);
+		let post_inherent_time = start.elapsed();
+		let result = result.and_then(|_| self.ensure_valid_header_extension(block));
+		let duration = start.elapsed();
+
+		let rows = block.header.extension.rows() as usize;
+		let cols = block.header.extension.cols() as usize;
+		ImportBlockMetrics::observe_da_verification(duration, rows, cols, result.is_err());
+
+		if duration > self.slow_verification_threshold {
+			sp_tracing::warn!(
+				target: LOG_TARGET,
+				?duration,
+				?post_inherent_time,
+				header_extension_time = ?duration.saturating_sub(post_inherent_time),
+				extrinsics = block.body.as_ref().map_or(0, Vec::len),
+				rows,
+				cols,
+				failed = result.is_err(),
+				"Slow DA verification of block #{number}, over {:?}",
+				self.slow_verification_threshold,
+			);
+		}
+		result
+	}
+
 	fn ensure_last_extrinsic_is_failed_send_message_txs(
 		&self,
 		block: &BlockImportParams<B>,
@@ -117,7 +232,7 @@ where
 	B: BlockT<Extrinsic = OpaqueExtrinsic, Header = DaHeader, Hash = H256>,
 	I: BlockImportT<B> + Clone + Send + Sync,
 	I::Error: Into<ConsensusError>,
-	C: ProvideRuntimeApi<B> + HeaderBackend<B> + Send + Sync,
+	C: ProvideRuntimeApi<B> + HeaderBackend<B> + AuxStore + Send + Sync,
 	C::Api: DataAvailApi<B> + ExtensionBuilder<B>,
 {
 	type Error = ConsensusError;
@@ -139,8 +254,27 @@ where
 		);
 		let skip_sync = self.unsafe_da_sync && is_sync;
 		if !is_own && !skip_sync && !block.with_state() {
-			self.ensure_last_extrinsic_is_failed_send_message_txs(&block)?;
-			self.ensure_valid_header_extension(&block)?;
+			// Blocks of banned peers are rejected before their costly verification.
+			let hash = block.post_hash();
+			if self.peer_bans.is_banned_origin(&hash) {
+				return Err(banned_origin(hash));
+			}
+			// Bound the blocks verified at the same time, and the memory they hold, so a node
+			// catching up on many large blocks does not run out of memory.
+			let _slot = self
+				.verification_slots
+				.acquire()
+				.await
+				.map_err(queue_closed)?;
+			let _memory = self
+				.memory_budget
+				.acquire_many(self.memory_permits(&block))
+				.await
+				.map_err(queue_closed)?;
+			if let Err(e) = self.verify_da(&block) {
+				self.peer_bans.note_invalid_block(&hash);
+				return Err(e);
+			}
 		}
 
 		// Next import block stage & metrics
@@ -162,13 +296,18 @@ impl<B, C, I: Clone> Clone for BlockImport<B, C, I> {
 			client: self.client.clone(),
 			inner: self.inner.clone(),
 			unsafe_da_sync: self.unsafe_da_sync,
+			slow_verification_threshold: self.slow_verification_threshold,
+			verification_slots: self.verification_slots.clone(),
+			memory_budget: self.memory_budget.clone(),
+			memory_permits: self.memory_permits,
+			peer_bans: self.peer_bans.clone(),
 			_block: PhantomData,
 		}
 	}
 }
 
 /// Calculate block length from `extension`.
-fn extension_block_len(extension: &HeaderExtension) -> BlockLength {
+pub(crate) fn extension_block_len(extension: &HeaderExtension) -> BlockLength {
 	BlockLength::with_normal_ratio(
 		BlockLengthRows(extension.rows() as u32),
 		BlockLengthColumns(extension.cols() as u32),
@@ -194,6 +333,16 @@ fn build_ext_fail(e: ApiError) -> ConsensusError {
 	ConsensusError::ClientImport(msg)
 }
 
+fn queue_closed(e: tokio::sync::AcquireError) -> ConsensusError {
+	let msg = format!("DA import queue is closed: {e}");
+	ConsensusError::ClientImport(msg)
+}
+
+fn banned_origin(hash: H256) -> ConsensusError {
+	let msg = format!("Block {hash:?} was received from a peer banned for invalid DA blocks");
+	ConsensusError::ClientImport(msg)
+}
+
 fn block_doesnt_contain_post_inherent() -> ConsensusError {
 	let msg = "Block does not contain post inherent".to_string();
 	ConsensusError::ClientImport(msg)
@@ -0,0 +1,370 @@
+//! # DA Cells Protocol
+//!
+//! Request/response protocol serving the sampled cells of finalized blocks and their KZG proofs
+//! to light clients over libp2p, so that sampling does not depend on the Kate RPC of HTTP
+//! gateways.
+//!
+//! A [`CellsRequest`] lists the cells of a block, and the response is the SCALE encoded
+//! [`CellsResponse`], with the proofs in the order of the request. Like `kate_queryProof`, the
+//! number of cells of a request is capped by `--kate-max-cells-size`.
+//!
+//! Proofs are computed from the polynomial grid of the block, from which its header commitments
+//! are built. The grids of the last sampled blocks are cached, as light clients sample the same
+//! recent blocks. Every peer is rate limited, and peers sending undecodable requests are banned.
+use async_channel::Receiver;
+use avail_base::HeaderExtensionBuilderData;
+use avail_core::{
+	header::HeaderExtension, traits::ExtendedHeader, BlockLengthColumns, BlockLengthRows,
+};
+use codec::{Decode, Encode};
+use da_runtime::{
+	apis::DataAvailApi,
+	kate::{GDataProof, GProof, GRawScalar},
+	NodeBlock as Block, Runtime,
+};
+use frame_system::native::hosted_header_builder::MIN_WIDTH;
+use futures::prelude::*;
+use kate::{
+	com::Cell,
+	couscous::multiproof_params,
+	gridgen::{AsBytes as _, EvaluationGrid, PolynomialGrid},
+	pmp::m1_blst::M1NoPrecomp,
+	Seed,
+};
+use lru::LruCache;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use sc_client_api::BlockBackend;
+use sc_network::{
+	config::{IncomingRequest, OutgoingResponse, RequestResponseConfig},
+	PeerId, ProtocolName, ReputationChange,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::H256;
+use sp_runtime::{traits::Header as _, SaturatedConversion as _};
+use std::{
+	collections::HashMap,
+	num::NonZeroU16,
+	sync::{Arc, OnceLock},
+	time::{Duration, Instant},
+};
+
+const LOG_TARGET: &str = "avail::node::da_cells";
+
+/// Version of the protocol, part of its name.
+pub const PROTOCOL_VERSION: u32 = 1;
+/// Maximum size of a request, enough for the cells of `--kate-max-cells-size`.
+const MAX_REQUEST_SIZE: u64 = 128 * 1024;
+/// Maximum size of a response, enough for the proofs of `--kate-max-cells-size` cells.
+const MAX_RESPONSE_SIZE: u64 = 1024 * 1024;
+/// Time after which a request without response fails.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(20);
+/// Number of requests queued before new ones are dropped.
+const MAX_PENDING_REQUESTS: usize = 256;
+/// Maximum number of requests served to a single peer per rate limit window.
+const MAX_REQUESTS_PER_WINDOW: u32 = 64;
+/// Maximum number of cells served to a single peer per rate limit window.
+const MAX_CELLS_PER_WINDOW: u32 = 4096;
+/// Length of the rate limit window.
+const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(10);
+/// Number of tracked peers above which those with an expired window are forgotten.
+const MAX_TRACKED_PEERS: usize = 1024;
+/// Number of blocks whose polynomial grid is cached.
+const GRID_CACHE_SIZE: usize = 16;
+
+/// Reputation change for peers that sent a request which cannot be decoded.
+const INVALID_REQUEST: ReputationChange = ReputationChange::new_fatal("Invalid DA cells request");
+/// Reputation change for peers that exceed the rate limit.
+const RATE_LIMITED: ReputationChange = ReputationChange::new(-(1 << 12), "DA cells flood");
+
+/// Returns the protocol name for the chain with the given genesis hash.
+pub fn protocol_name(genesis_hash: &H256) -> ProtocolName {
+	format!("/{genesis_hash:x}/da-cells/{PROTOCOL_VERSION}").into()
+}
+
+/// Request of the proofs of some cells of a finalized block.
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq)]
+pub struct CellsRequest {
+	pub block_hash: H256,
+	/// Positions of the cells as `(row, col)` in the extended matrix.
+	pub cells: Vec<(u32, u32)>,
+}
+
+/// Reason why the cells of a request are not served.
+#[derive(Clone, Copy, Debug, Encode, Decode, PartialEq, Eq)]
+pub enum CellsError {
+	/// The block is not known, or its body was pruned.
+	UnknownBlock,
+	/// The block is not finalized yet.
+	NotFinalized,
+	/// The block has no commitments, e.g. it has no data submissions.
+	EmptyCommitments,
+	/// The request has more cells than served at once.
+	TooManyCells,
+	/// A cell is out of the matrix of the block.
+	MissingCell { row: u32, col: u32 },
+	/// The grid or a proof of the block cannot be computed.
+	Proof,
+}
+
+/// Response to a [`CellsRequest`].
+pub type CellsResponse = Result<Vec<GDataProof>, CellsError>;
+
+/// Creates the request/response protocol config and the receiver of its requests.
+pub fn request_response_config(
+	protocol_name: ProtocolName,
+) -> (RequestResponseConfig, Receiver<IncomingRequest>) {
+	let (tx, rx) = async_channel::bounded(MAX_PENDING_REQUESTS);
+	let config = RequestResponseConfig {
+		name: protocol_name,
+		fallback_names: Vec::new(),
+		max_request_size: MAX_REQUEST_SIZE,
+		max_response_size: MAX_RESPONSE_SIZE,
+		request_timeout: REQUEST_TIMEOUT,
+		inbound_queue: Some(tx),
+	};
+
+	(config, rx)
+}
+
+struct PeerState {
+	window_start: Instant,
+	requests: u32,
+	cells: u32,
+}
+
+impl PeerState {
+	fn new() -> Self {
+		Self {
+			window_start: Instant::now(),
+			requests: 0,
+			cells: 0,
+		}
+	}
+
+	fn is_expired(&self, now: Instant) -> bool {
+		now.duration_since(self.window_start) >= RATE_LIMIT_WINDOW
+	}
+
+	/// Records a request of `cells` cells, returns `false` if the peer is over its limits.
+	fn note_request(&mut self, cells: usize) -> bool {
+		let now = Instant::now();
+		if self.is_expired(now) {
+			self.window_start = now;
+			self.requests = 0;
+			self.cells = 0;
+		}
+		self.requests = self.requests.saturating_add(1);
+		self.cells = self.cells.saturating_add(cells.saturated_into());
+		self.requests <= MAX_REQUESTS_PER_WINDOW && self.cells <= MAX_CELLS_PER_WINDOW
+	}
+}
+
+/// Extended evaluation grid of a block and its polynomials.
+struct BlockGrid {
+	evaluations: EvaluationGrid,
+	polynomials: PolynomialGrid,
+}
+
+/// Handler serving the requests of the protocol.
+pub struct DaCellsRequestHandler<C> {
+	client: Arc<C>,
+	request_receiver: Receiver<IncomingRequest>,
+	max_cells: usize,
+	peers: HashMap<PeerId, PeerState>,
+	grids: LruCache<H256, Arc<BlockGrid>>,
+}
+
+impl<C> DaCellsRequestHandler<C>
+where
+	C: ProvideRuntimeApi<Block>
+		+ HeaderBackend<Block>
+		+ BlockBackend<Block>
+		+ Send
+		+ Sync
+		+ 'static,
+	C::Api: DataAvailApi<Block>,
+{
+	pub fn new(
+		client: Arc<C>,
+		request_receiver: Receiver<IncomingRequest>,
+		max_cells: usize,
+	) -> Self {
+		Self {
+			client,
+			request_receiver,
+			max_cells,
+			peers: HashMap::new(),
+			grids: LruCache::new(GRID_CACHE_SIZE),
+		}
+	}
+
+	/// Serves the requests, computing the proofs on the calling thread.
+	pub async fn run(mut self) {
+		while let Some(request) = self.request_receiver.next().await {
+			self.on_request(request);
+		}
+	}
+
+	fn on_request(&mut self, request: IncomingRequest) {
+		let IncomingRequest {
+			peer,
+			payload,
+			pending_response,
+		} = request;
+
+		let (result, reputation_changes) = match CellsRequest::decode(&mut payload.as_slice()) {
+			Ok(request) if self.note_request(peer, request.cells.len()) => {
+				let response = self.cells(&request);
+				if let Err(e) = &response {
+					log::debug!(
+						target: LOG_TARGET,
+						"Cells of {:?} not served to {peer:?}: {e:?}",
+						request.block_hash
+					);
+				}
+				(Ok(response.encode()), Vec::new())
+			},
+			Ok(_) => {
+				log::debug!(target: LOG_TARGET, "Peer {peer:?} exceeded the rate limit");
+				(Err(()), vec![RATE_LIMITED])
+			},
+			Err(_) => {
+				log::debug!(target: LOG_TARGET, "Banning {peer:?}: undecodable request");
+				(Err(()), vec![INVALID_REQUEST])
+			},
+		};
+
+		let _ = pending_response.send(OutgoingResponse {
+			result,
+			reputation_changes,
+			sent_feedback: None,
+		});
+	}
+
+	fn note_request(&mut self, peer: PeerId, cells: usize) -> bool {
+		if self.peers.len() >= MAX_TRACKED_PEERS {
+			let now = Instant::now();
+			self.peers.retain(|_, state| !state.is_expired(now));
+		}
+		self.peers
+			.entry(peer)
+			.or_insert_with(PeerState::new)
+			.note_request(cells)
+	}
+
+	fn cells(&mut self, request: &CellsRequest) -> CellsResponse {
+		if request.cells.len() > self.max_cells {
+			return Err(CellsError::TooManyCells);
+		}
+		let grid = self.grid(request.block_hash)?;
+		let srs = srs();
+
+		request
+			.cells
+			.clone()
+			.into_par_iter()
+			.map(|(row, col)| {
+				let data = grid
+					.evaluations
+					.get(row as usize, col as usize)
+					.ok_or(CellsError::MissingCell { row, col })?
+					.to_bytes()
+					.map(GRawScalar::from)
+					.map_err(|_| CellsError::Proof)?;
+				let cell = Cell::new(BlockLengthRows(row), BlockLengthColumns(col));
+				let proof = grid
+					.polynomials
+					.proof(srs, &cell)
+					.map_err(|_| CellsError::Proof)?
+					.to_bytes()
+					.map_err(|_| CellsError::Proof)?;
+				let proof = GProof::try_from(proof.to_vec()).map_err(|_| CellsError::Proof)?;
+
+				Ok((data, proof))
+			})
+			.collect()
+	}
+
+	/// Returns the grid of the finalized block `at`, from the cache if it was sampled recently.
+	fn grid(&mut self, at: H256) -> Result<Arc<BlockGrid>, CellsError> {
+		if let Some(grid) = self.grids.get(&at) {
+			return Ok(grid.clone());
+		}
+
+		let header = self
+			.client
+			.header(at)
+			.ok()
+			.flatten()
+			.ok_or(CellsError::UnknownBlock)?;
+		let number = *header.number();
+		if number > self.client.info().finalized_number {
+			return Err(CellsError::NotFinalized);
+		}
+		let HeaderExtension::V3(extension) = header.extension();
+		if extension.commitment.commitment.is_empty() {
+			return Err(CellsError::EmptyCommitments);
+		}
+		let extrinsics = self
+			.client
+			.block_body(at)
+			.ok()
+			.flatten()
+			.ok_or(CellsError::UnknownBlock)?;
+		let block_len = self
+			.client
+			.runtime_api()
+			.block_length(at)
+			.map_err(|_| CellsError::Proof)?;
+
+		type Filter = <Runtime as frame_system::Config>::HeaderExtensionDataFilter;
+		let app_extrinsics =
+			HeaderExtensionBuilderData::from_opaque_extrinsics::<Filter>(number, &extrinsics)
+				.to_app_extrinsics();
+		// Like the Kate RPC, the padding of the grid uses the default seed.
+		let evaluations = EvaluationGrid::from_extrinsics(
+			app_extrinsics,
+			MIN_WIDTH,
+			block_len.cols.0.saturated_into(),
+			block_len.rows.0.saturated_into(),
+			Seed::default(),
+		)
+		.map_err(|_| CellsError::Proof)?
+		.extend_columns(NonZeroU16::new(2).expect("2>0"))
+		.map_err(|_| CellsError::Proof)?;
+		let polynomials = evaluations
+			.make_polynomial_grid()
+			.map_err(|_| CellsError::Proof)?;
+
+		let grid = Arc::new(BlockGrid {
+			evaluations,
+			polynomials,
+		});
+		self.grids.put(at, grid.clone());
+		Ok(grid)
+	}
+}
+
+fn srs() -> &'static M1NoPrecomp {
+	static SRS: OnceLock<M1NoPrecomp> = OnceLock::new();
+	SRS.get_or_init(multiproof_params)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn peer_is_rate_limited_on_requests_and_cells() {
+		let mut state = PeerState::new();
+		assert!(state.note_request(MAX_CELLS_PER_WINDOW as usize));
+		assert!(!state.note_request(1));
+
+		let mut state = PeerState::new();
+		for _ in 0..MAX_REQUESTS_PER_WINDOW {
+			assert!(state.note_request(0));
+		}
+		assert!(!state.note_request(0));
+	}
+}
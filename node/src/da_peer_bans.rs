@@ -0,0 +1,397 @@
+//! # DA Peer Bans
+//!
+//! Peers serving blocks whose header extension does not match their extrinsics make the node
+//! spend the costly DA verification of [`crate::da_block_import::BlockImport`] for nothing.
+//!
+//! The [`DaImportQueue`] records the peer each block is received from, and every block rejected
+//! by the DA checks counts as an offence of its peer, penalized by a reputation change which
+//! doubles at each offence. A peer reaching the ban threshold is banned: it is disconnected
+//! whenever it sends blocks, and its blocks are rejected before their DA verification. Offences
+//! and bans are kept in the aux storage of the client, so they survive restarts.
+use codec::{Decode, Encode};
+use sc_client_api::AuxStore;
+use sc_consensus::import_queue::{
+	ImportQueue, ImportQueueService, IncomingBlock, Link, RuntimeOrigin,
+};
+use sc_network::{NetworkPeers, PeerId, ReputationChange};
+use sp_consensus::BlockOrigin;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{Block as BlockT, NumberFor},
+	Justifications,
+};
+use std::{
+	collections::{HashMap, VecDeque},
+	sync::{Arc, Mutex, OnceLock},
+	task::Context,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::cli::Cli;
+
+const LOG_TARGET: &str = "avail::node::da_peer_bans";
+/// Aux storage key of the offences and bans.
+const AUX_KEY: &[u8] = b"avail_da_peer_bans";
+/// Number of recently received blocks whose peer is kept.
+const MAX_KNOWN_ORIGINS: usize = 4096;
+/// Number of peers whose offences are kept.
+const MAX_OFFENDERS: usize = 1024;
+/// Reputation cost of the first offence of a peer.
+const BASE_PENALTY: i32 = 1 << 12;
+/// Number of offences after which the penalty stops doubling.
+const MAX_PENALTY_DOUBLINGS: u32 = 16;
+
+/// Reputation change for banned peers.
+const BANNED: ReputationChange = ReputationChange::new_fatal("Banned for invalid DA blocks");
+
+/// Bans of the peers serving blocks which fail the DA checks.
+#[derive(Debug, Clone, Copy)]
+pub struct DaPeerBanConfig {
+	/// Number of offences after which a peer is banned, `0` to never ban.
+	pub ban_threshold: u32,
+	/// Duration of a ban.
+	pub ban_duration: Duration,
+}
+
+impl From<&Cli> for DaPeerBanConfig {
+	fn from(cli: &Cli) -> Self {
+		Self {
+			ban_threshold: cli.da_peer_ban_threshold,
+			ban_duration: Duration::from_secs(cli.da_peer_ban_duration),
+		}
+	}
+}
+
+/// Offences of a peer, persisted in the aux storage.
+#[derive(Clone, Debug, Default, Encode, Decode, PartialEq, Eq)]
+struct PeerRecord {
+	/// Number of blocks served by the peer which failed the DA checks.
+	offences: u32,
+	/// Unix time in seconds at which the ban of the peer ends, if it was banned.
+	banned_until: Option<u64>,
+}
+
+impl PeerRecord {
+	/// Counts an offence at `now`, banning the peer once it reaches the ban threshold. Returns
+	/// whether the peer got banned.
+	fn note_offence(&mut self, now: u64, config: &DaPeerBanConfig) -> bool {
+		self.offences = self.offences.saturating_add(1);
+		if config.ban_threshold == 0 || self.offences < config.ban_threshold {
+			return false;
+		}
+		self.banned_until = Some(now.saturating_add(config.ban_duration.as_secs()));
+		true
+	}
+
+	fn is_banned(&self, now: u64) -> bool {
+		self.banned_until.is_some_and(|until| now < until)
+	}
+
+	/// Reputation change of the latest offence, twice the one of the previous offence.
+	fn penalty(&self) -> ReputationChange {
+		let doublings = self.offences.saturating_sub(1).min(MAX_PENALTY_DOUBLINGS);
+		ReputationChange::new(-(BASE_PENALTY << doublings), "Invalid DA block")
+	}
+}
+
+#[derive(Default)]
+struct BanState {
+	/// Peers the recently received blocks came from.
+	origins: HashMap<H256, PeerId>,
+	origins_order: VecDeque<H256>,
+	peers: HashMap<PeerId, PeerRecord>,
+}
+
+impl BanState {
+	fn note_origin(&mut self, hash: H256, peer: PeerId) {
+		if self.origins.insert(hash, peer).is_some() {
+			return;
+		}
+		if self.origins_order.len() >= MAX_KNOWN_ORIGINS {
+			if let Some(oldest) = self.origins_order.pop_front() {
+				self.origins.remove(&oldest);
+			}
+		}
+		self.origins_order.push_back(hash);
+	}
+
+	/// Makes room for a new offender by forgetting the peer with the fewest offences which is
+	/// not banned at `now`.
+	fn evict_offender(&mut self, now: u64) {
+		let evicted = self
+			.peers
+			.iter()
+			.filter(|(_, record)| !record.is_banned(now))
+			.min_by_key(|(_, record)| record.offences)
+			.map(|(peer, _)| *peer);
+		if let Some(peer) = evicted {
+			self.peers.remove(&peer);
+		}
+	}
+}
+
+/// Offences and bans of the peers serving blocks which fail the DA checks, shared by the import
+/// queue and the block import.
+pub struct DaPeerBans<C> {
+	client: Arc<C>,
+	config: DaPeerBanConfig,
+	state: Arc<Mutex<BanState>>,
+	network: Arc<OnceLock<Arc<dyn NetworkPeers + Send + Sync>>>,
+}
+
+impl<C> Clone for DaPeerBans<C> {
+	fn clone(&self) -> Self {
+		Self {
+			client: self.client.clone(),
+			config: self.config,
+			state: self.state.clone(),
+			network: self.network.clone(),
+		}
+	}
+}
+
+impl<C: AuxStore> DaPeerBans<C> {
+	/// Loads the offences and bans persisted in the aux storage of `client`.
+	pub fn new(client: Arc<C>, config: DaPeerBanConfig) -> Self {
+		let peers = match client.get_aux(AUX_KEY) {
+			Ok(Some(encoded)) => Vec::<(Vec<u8>, PeerRecord)>::decode(&mut encoded.as_slice())
+				.map(|records| {
+					records
+						.into_iter()
+						.filter_map(|(peer, record)| {
+							Some((PeerId::from_bytes(&peer).ok()?, record))
+						})
+						.collect()
+				})
+				.unwrap_or_else(|e| {
+					log::warn!(target: LOG_TARGET, "Discarding undecodable DA peer bans: {e}");
+					HashMap::new()
+				}),
+			Ok(None) => HashMap::new(),
+			Err(e) => {
+				log::warn!(target: LOG_TARGET, "DA peer bans cannot be loaded: {e}");
+				HashMap::new()
+			},
+		};
+		let state = BanState {
+			peers,
+			..Default::default()
+		};
+
+		Self {
+			client,
+			config,
+			state: Arc::new(Mutex::new(state)),
+			network: Arc::new(OnceLock::new()),
+		}
+	}
+
+	/// Sets the network the penalties and bans are reported to, once it is built.
+	pub fn set_network(&self, network: Arc<dyn NetworkPeers + Send + Sync>) {
+		let _ = self.network.set(network);
+	}
+
+	/// Records that block `hash` was received from `peer`, which is disconnected if it is
+	/// banned.
+	fn note_origin(&self, hash: H256, peer: PeerId) {
+		let mut state = self.state.lock().expect("Lock is never poisoned .qed");
+		state.note_origin(hash, peer);
+		let banned = state
+			.peers
+			.get(&peer)
+			.is_some_and(|record| record.is_banned(now()));
+		drop(state);
+
+		if banned {
+			log::debug!(target: LOG_TARGET, "Disconnecting banned {peer:?}");
+			self.report(peer, BANNED);
+		}
+	}
+
+	/// Returns whether block `hash` was received from a banned peer.
+	pub fn is_banned_origin(&self, hash: &H256) -> bool {
+		let state = self.state.lock().expect("Lock is never poisoned .qed");
+		state
+			.origins
+			.get(hash)
+			.and_then(|peer| state.peers.get(peer))
+			.is_some_and(|record| record.is_banned(now()))
+	}
+
+	/// Counts the failed DA checks of block `hash` as an offence of the peer it was received
+	/// from, if any.
+	pub fn note_invalid_block(&self, hash: &H256) {
+		let now = now();
+		let mut state = self.state.lock().expect("Lock is never poisoned .qed");
+		let Some(peer) = state.origins.get(hash).copied() else {
+			return;
+		};
+		if !state.peers.contains_key(&peer) && state.peers.len() >= MAX_OFFENDERS {
+			state.evict_offender(now);
+		}
+		let record = state.peers.entry(peer).or_default();
+		let banned = record.note_offence(now, &self.config);
+		let penalty = record.penalty();
+		let offences = record.offences;
+		let encoded = state
+			.peers
+			.iter()
+			.map(|(peer, record)| (peer.to_bytes(), record.clone()))
+			.collect::<Vec<_>>()
+			.encode();
+		drop(state);
+
+		if let Err(e) = self
+			.client
+			.insert_aux(&[(AUX_KEY, encoded.as_slice())], &[])
+		{
+			log::warn!(target: LOG_TARGET, "DA peer bans cannot be persisted: {e}");
+		}
+		if banned {
+			log::info!(
+				target: LOG_TARGET,
+				"Banning {peer:?} for {:?} after {offences} blocks failing the DA checks",
+				self.config.ban_duration,
+			);
+			self.report(peer, BANNED);
+		} else {
+			log::debug!(target: LOG_TARGET, "Block {hash:?} of {peer:?} failed the DA checks");
+			self.report(peer, penalty);
+		}
+	}
+
+	fn report(&self, peer: PeerId, change: ReputationChange) {
+		if let Some(network) = self.network.get() {
+			network.report_peer(peer, change);
+		}
+	}
+}
+
+/// Import queue recording the peer each block is received from, for [`DaPeerBans`].
+pub struct DaImportQueue<B: BlockT, Q, C> {
+	inner: Q,
+	service: DaImportQueueService<B, C>,
+}
+
+impl<B, Q, C> DaImportQueue<B, Q, C>
+where
+	B: BlockT,
+	Q: ImportQueue<B>,
+{
+	pub fn new(inner: Q, bans: DaPeerBans<C>) -> Self {
+		let service = DaImportQueueService {
+			inner: inner.service(),
+			bans,
+		};
+		Self { inner, service }
+	}
+}
+
+struct DaImportQueueService<B: BlockT, C> {
+	inner: Box<dyn ImportQueueService<B>>,
+	bans: DaPeerBans<C>,
+}
+
+impl<B, C> ImportQueueService<B> for DaImportQueueService<B, C>
+where
+	B: BlockT<Hash = H256>,
+	C: AuxStore + Send + Sync,
+{
+	fn import_blocks(&mut self, origin: BlockOrigin, blocks: Vec<IncomingBlock<B>>) {
+		for block in &blocks {
+			if let Some(peer) = block.origin {
+				self.bans.note_origin(block.hash, peer);
+			}
+		}
+		self.inner.import_blocks(origin, blocks)
+	}
+
+	fn import_justifications(
+		&mut self,
+		who: RuntimeOrigin,
+		hash: B::Hash,
+		number: NumberFor<B>,
+		justifications: Justifications,
+	) {
+		self.inner
+			.import_justifications(who, hash, number, justifications)
+	}
+}
+
+#[async_trait::async_trait]
+impl<B, Q, C> ImportQueue<B> for DaImportQueue<B, Q, C>
+where
+	B: BlockT<Hash = H256>,
+	Q: ImportQueue<B>,
+	C: AuxStore + Send + Sync + 'static,
+{
+	fn service(&self) -> Box<dyn ImportQueueService<B>> {
+		Box::new(DaImportQueueService {
+			inner: self.inner.service(),
+			bans: self.service.bans.clone(),
+		})
+	}
+
+	fn service_ref(&mut self) -> &mut dyn ImportQueueService<B> {
+		&mut self.service
+	}
+
+	fn poll_actions(&mut self, cx: &mut Context, link: &mut dyn Link<B>) {
+		self.inner.poll_actions(cx, link)
+	}
+
+	async fn run(self, link: Box<dyn Link<B>>) {
+		self.inner.run(link).await
+	}
+}
+
+fn now() -> u64 {
+	SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map_or(0, |elapsed| elapsed.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn config() -> DaPeerBanConfig {
+		DaPeerBanConfig {
+			ban_threshold: 3,
+			ban_duration: Duration::from_secs(60),
+		}
+	}
+
+	#[test]
+	fn peer_is_banned_at_threshold_with_doubling_penalties() {
+		let mut record = PeerRecord::default();
+
+		assert!(!record.note_offence(100, &config()));
+		assert_eq!(-BASE_PENALTY, record.penalty().value);
+		assert!(!record.note_offence(100, &config()));
+		assert_eq!(-2 * BASE_PENALTY, record.penalty().value);
+		assert!(!record.is_banned(100));
+
+		assert!(record.note_offence(100, &config()));
+		assert!(record.is_banned(159));
+		assert!(!record.is_banned(160));
+	}
+
+	#[test]
+	fn peer_is_never_banned_without_threshold() {
+		let config = DaPeerBanConfig {
+			ban_threshold: 0,
+			..config()
+		};
+		let mut record = PeerRecord::default();
+
+		for _ in 0..100 {
+			assert!(!record.note_offence(100, &config));
+		}
+		assert!(!record.is_banned(100));
+		assert_eq!(
+			-(BASE_PENALTY << MAX_PENALTY_DOUBLINGS),
+			record.penalty().value
+		);
+	}
+}
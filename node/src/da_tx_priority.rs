@@ -0,0 +1,449 @@
+//! # DA Transaction Priority
+//!
+//! Transaction pool wrapper used by the block proposer. Data submissions are ordered by
+//! fee-per-byte, and the submitted data of a single application id is capped per block, so a
+//! single rollup cannot monopolize the data matrix.
+//!
+//! Other transactions keep the order of the inner pool and are proposed before data
+//! submissions. Transactions are never proposed before the transactions they depend on.
+use avail_core::traits::GetAppId;
+use codec::{Compact, Decode, Encode};
+use da_control::Call as DACall;
+use da_runtime::{Balance, NodeBlock as Block, RuntimeCall, UncheckedExtrinsic};
+use futures::{Future, FutureExt};
+use sc_transaction_pool_api::{
+	ImportNotificationStream, InPoolTransaction, PoolFuture, PoolStatus, ReadyTransactions,
+	TransactionFor, TransactionPool, TransactionSource, TransactionStatusStreamFor, TxHash,
+};
+use sp_runtime::{
+	traits::{Block as BlockT, NumberFor},
+	OpaqueExtrinsic,
+};
+use std::{
+	cmp::Reverse,
+	collections::{BinaryHeap, HashMap},
+	pin::Pin,
+	sync::Arc,
+};
+
+/// Configuration of the data submission ordering.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DaTxPriorityConfig {
+	/// Orders data submissions by fee-per-byte if enabled.
+	pub enabled: bool,
+	/// Maximum number of submitted data bytes of a single application id per block.
+	pub max_app_bytes_per_block: Option<u32>,
+}
+
+/// Data submission details used to order a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DaSubmission {
+	pub app_id: u32,
+	pub data_len: u32,
+	/// Tip per byte of submitted data.
+	pub fee_per_byte: u128,
+}
+
+/// Returns the data submission details of `opaque`, if it is a `DataAvailability::submit_data`.
+pub fn da_submission(opaque: &OpaqueExtrinsic) -> Option<DaSubmission> {
+	let xt = UncheckedExtrinsic::try_from(opaque.clone()).ok()?;
+	let RuntimeCall::DataAvailability(DACall::submit_data { data }) = &xt.function else {
+		return None;
+	};
+	let data_len = u32::try_from(data.len()).ok()?;
+
+	// `ChargeTransactionPayment` only encodes the compact tip.
+	let (_, _, extra) = xt.signature.as_ref()?;
+	let tip = Compact::<Balance>::decode(&mut extra.7.encode().as_slice())
+		.ok()?
+		.0;
+
+	Some(DaSubmission {
+		app_id: xt.app_id().0,
+		data_len,
+		fee_per_byte: tip / u128::from(data_len.max(1)),
+	})
+}
+
+/// Transaction pool which reorders the ready transactions of `inner` for block authoring.
+pub struct DaPrioritizedPool<P> {
+	inner: Arc<P>,
+	config: DaTxPriorityConfig,
+}
+
+impl<P> DaPrioritizedPool<P> {
+	pub fn new(inner: Arc<P>, config: DaTxPriorityConfig) -> Self {
+		Self { inner, config }
+	}
+}
+
+impl DaTxPriorityConfig {
+	fn prioritize<T>(
+		self,
+		ready: Box<dyn ReadyTransactions<Item = Arc<T>> + Send>,
+	) -> Box<dyn ReadyTransactions<Item = Arc<T>> + Send>
+	where
+		T: InPoolTransaction<Transaction = OpaqueExtrinsic> + Send + Sync + 'static,
+		T::Hash: PartialEq,
+	{
+		if !self.enabled {
+			return ready;
+		}
+
+		Box::new(DaReadyTransactions::new(
+			ready.collect(),
+			|tx: &T| da_submission(tx.data()),
+			self.max_app_bytes_per_block,
+		))
+	}
+}
+
+impl<P> TransactionPool for DaPrioritizedPool<P>
+where
+	P: TransactionPool<Block = Block> + 'static,
+	P::InPoolTransaction: Send + Sync + 'static,
+	TxHash<P>: PartialEq,
+{
+	type Block = Block;
+	type Hash = TxHash<P>;
+	type InPoolTransaction = P::InPoolTransaction;
+	type Error = P::Error;
+
+	fn submit_at(
+		&self,
+		at: <Self::Block as BlockT>::Hash,
+		source: TransactionSource,
+		xts: Vec<TransactionFor<Self>>,
+	) -> PoolFuture<Vec<Result<TxHash<Self>, Self::Error>>, Self::Error> {
+		self.inner.submit_at(at, source, xts)
+	}
+
+	fn submit_one(
+		&self,
+		at: <Self::Block as BlockT>::Hash,
+		source: TransactionSource,
+		xt: TransactionFor<Self>,
+	) -> PoolFuture<TxHash<Self>, Self::Error> {
+		self.inner.submit_one(at, source, xt)
+	}
+
+	fn submit_and_watch(
+		&self,
+		at: <Self::Block as BlockT>::Hash,
+		source: TransactionSource,
+		xt: TransactionFor<Self>,
+	) -> PoolFuture<Pin<Box<TransactionStatusStreamFor<Self>>>, Self::Error> {
+		self.inner.submit_and_watch(at, source, xt)
+	}
+
+	fn ready_at(
+		&self,
+		at: NumberFor<Self::Block>,
+	) -> Pin<
+		Box<
+			dyn Future<
+					Output = Box<dyn ReadyTransactions<Item = Arc<Self::InPoolTransaction>> + Send>,
+				> + Send,
+		>,
+	> {
+		let config = self.config;
+		self.inner
+			.ready_at(at)
+			.map(move |ready| config.prioritize(ready))
+			.boxed()
+	}
+
+	fn ready(&self) -> Box<dyn ReadyTransactions<Item = Arc<Self::InPoolTransaction>> + Send> {
+		self.config.prioritize(self.inner.ready())
+	}
+
+	fn remove_invalid(&self, hashes: &[TxHash<Self>]) -> Vec<Arc<Self::InPoolTransaction>> {
+		self.inner.remove_invalid(hashes)
+	}
+
+	fn futures(&self) -> Vec<Self::InPoolTransaction> {
+		self.inner.futures()
+	}
+
+	fn status(&self) -> PoolStatus {
+		self.inner.status()
+	}
+
+	fn import_notification_stream(&self) -> ImportNotificationStream<TxHash<Self>> {
+		self.inner.import_notification_stream()
+	}
+
+	fn on_broadcasted(&self, propagations: HashMap<TxHash<Self>, Vec<String>>) {
+		self.inner.on_broadcasted(propagations)
+	}
+
+	fn hash_of(&self, xt: &TransactionFor<Self>) -> TxHash<Self> {
+		self.inner.hash_of(xt)
+	}
+
+	fn ready_transaction(&self, hash: &TxHash<Self>) -> Option<Arc<Self::InPoolTransaction>> {
+		self.inner.ready_transaction(hash)
+	}
+}
+
+/// Ordering key of a ready transaction, the greatest key is proposed first.
+/// Other transactions come before data submissions, ties keep the inner pool order.
+type OrderKey = ((bool, u128), Reverse<usize>);
+
+/// Ready transactions iterator ordering data submissions by fee-per-byte.
+///
+/// A transaction becomes available once all the transactions providing its required tags were
+/// proposed and not reported as invalid.
+struct DaReadyTransactions<T> {
+	txs: Vec<Arc<T>>,
+	submissions: Vec<Option<DaSubmission>>,
+	/// Number of required tags of each transaction provided by a not yet proposed transaction.
+	pending: Vec<usize>,
+	dependents: Vec<Vec<usize>>,
+	available: BinaryHeap<OrderKey>,
+	last: Option<usize>,
+	app_bytes: HashMap<u32, u32>,
+	max_app_bytes_per_block: Option<u32>,
+}
+
+impl<T: InPoolTransaction> DaReadyTransactions<T>
+where
+	T::Hash: PartialEq,
+{
+	fn new(
+		txs: Vec<Arc<T>>,
+		classify: impl Fn(&T) -> Option<DaSubmission>,
+		max_app_bytes_per_block: Option<u32>,
+	) -> Self {
+		let submissions: Vec<_> = txs.iter().map(|tx| classify(tx)).collect();
+
+		let mut providers = HashMap::new();
+		for (index, tx) in txs.iter().enumerate() {
+			for tag in tx.provides() {
+				providers.entry(tag.clone()).or_insert(index);
+			}
+		}
+
+		let mut pending = vec![0; txs.len()];
+		let mut dependents = vec![Vec::new(); txs.len()];
+		for (index, tx) in txs.iter().enumerate() {
+			for tag in tx.requires() {
+				if let Some(&provider) = providers.get(tag).filter(|&&p| p != index) {
+					pending[index] += 1;
+					dependents[provider].push(index);
+				}
+			}
+		}
+
+		let mut ready = Self {
+			txs,
+			submissions,
+			pending,
+			dependents,
+			available: BinaryHeap::new(),
+			last: None,
+			app_bytes: HashMap::new(),
+			max_app_bytes_per_block,
+		};
+		for index in 0..ready.txs.len() {
+			if ready.pending[index] == 0 {
+				ready.make_available(index);
+			}
+		}
+		ready
+	}
+
+	fn make_available(&mut self, index: usize) {
+		let key = match self.submissions[index] {
+			Some(submission) => (false, submission.fee_per_byte),
+			None => (true, 0),
+		};
+		self.available.push((key, Reverse(index)));
+	}
+
+	fn unlock_dependents(&mut self, index: usize) {
+		for dependent in std::mem::take(&mut self.dependents[index]) {
+			self.pending[dependent] = self.pending[dependent].saturating_sub(1);
+			if self.pending[dependent] == 0 {
+				self.make_available(dependent);
+			}
+		}
+	}
+}
+
+impl<T: InPoolTransaction> Iterator for DaReadyTransactions<T>
+where
+	T::Hash: PartialEq,
+{
+	type Item = Arc<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if let Some(last) = self.last.take() {
+			self.unlock_dependents(last);
+		}
+
+		while let Some((_, Reverse(index))) = self.available.pop() {
+			if let Some(submission) = self.submissions[index] {
+				let used = self.app_bytes.entry(submission.app_id).or_default();
+				let total = used.saturating_add(submission.data_len);
+				// Over quota, the transaction and its dependents wait for the next block.
+				if self.max_app_bytes_per_block.is_some_and(|max| total > max) {
+					continue;
+				}
+				*used = total;
+			}
+
+			self.last = Some(index);
+			return Some(self.txs[index].clone());
+		}
+
+		None
+	}
+}
+
+impl<T: InPoolTransaction> ReadyTransactions for DaReadyTransactions<T>
+where
+	T::Hash: PartialEq,
+{
+	fn report_invalid(&mut self, tx: &Self::Item) {
+		let Some(last) = self.last else {
+			return;
+		};
+		if self.txs[last].hash() != tx.hash() {
+			return;
+		}
+
+		// The transaction is not included, so its dependents are not proposed either.
+		self.last = None;
+		if let Some(submission) = self.submissions[last] {
+			if let Some(used) = self.app_bytes.get_mut(&submission.app_id) {
+				*used = used.saturating_sub(submission.data_len);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_runtime::transaction_validity::{
+		TransactionLongevity, TransactionPriority, TransactionTag,
+	};
+
+	struct TestTx {
+		hash: u64,
+		submission: Option<DaSubmission>,
+		requires: Vec<TransactionTag>,
+		provides: Vec<TransactionTag>,
+	}
+
+	impl InPoolTransaction for TestTx {
+		type Transaction = Option<DaSubmission>;
+		type Hash = u64;
+
+		fn data(&self) -> &Self::Transaction {
+			&self.submission
+		}
+
+		fn hash(&self) -> &Self::Hash {
+			&self.hash
+		}
+
+		fn priority(&self) -> &TransactionPriority {
+			&0
+		}
+
+		fn longevity(&self) -> &TransactionLongevity {
+			&64
+		}
+
+		fn requires(&self) -> &[TransactionTag] {
+			&self.requires
+		}
+
+		fn provides(&self) -> &[TransactionTag] {
+			&self.provides
+		}
+
+		fn is_propagable(&self) -> bool {
+			true
+		}
+	}
+
+	fn tx(
+		hash: u64,
+		submission: Option<(u32, u32, u128)>,
+		requires: &[u8],
+		provides: u8,
+	) -> Arc<TestTx> {
+		Arc::new(TestTx {
+			hash,
+			submission: submission.map(|(app_id, data_len, fee_per_byte)| DaSubmission {
+				app_id,
+				data_len,
+				fee_per_byte,
+			}),
+			requires: requires.iter().map(|tag| vec![*tag]).collect(),
+			provides: vec![vec![provides]],
+		})
+	}
+
+	fn order(txs: Vec<Arc<TestTx>>, max_app_bytes_per_block: Option<u32>) -> Vec<u64> {
+		DaReadyTransactions::new(txs, |tx: &TestTx| tx.submission, max_app_bytes_per_block)
+			.map(|tx| tx.hash)
+			.collect()
+	}
+
+	#[test]
+	fn data_submissions_are_ordered_by_fee_per_byte() {
+		let txs = vec![
+			tx(1, Some((1, 100, 1)), &[], 1),
+			tx(2, None, &[], 2),
+			tx(3, Some((2, 100, 5)), &[], 3),
+			tx(4, Some((3, 100, 3)), &[], 4),
+		];
+
+		assert_eq!(order(txs, None), vec![2, 3, 4, 1]);
+	}
+
+	#[test]
+	fn dependencies_are_proposed_first() {
+		// 3 depends on 1, which has the lowest fee-per-byte
+		let txs = vec![
+			tx(1, Some((1, 100, 1)), &[], 1),
+			tx(2, Some((2, 100, 5)), &[], 2),
+			tx(3, Some((1, 100, 9)), &[1], 3),
+		];
+
+		assert_eq!(order(txs, None), vec![2, 1, 3]);
+	}
+
+	#[test]
+	fn app_quota_is_enforced() {
+		let txs = vec![
+			tx(1, Some((1, 60, 9)), &[], 1),
+			tx(2, Some((1, 60, 8)), &[], 2),
+			tx(3, Some((1, 60, 7)), &[2], 3),
+			tx(4, Some((2, 60, 1)), &[], 4),
+		];
+
+		assert_eq!(order(txs, Some(100)), vec![1, 4]);
+	}
+
+	#[test]
+	fn invalid_transactions_lock_dependents_and_release_quota() {
+		let txs = vec![
+			tx(1, Some((1, 60, 9)), &[], 1),
+			tx(2, Some((1, 60, 8)), &[1], 2),
+			tx(3, Some((1, 60, 7)), &[], 3),
+		];
+		let mut ready = DaReadyTransactions::new(txs, |tx: &TestTx| tx.submission, Some(100));
+
+		let first = ready.next().unwrap();
+		assert_eq!(first.hash, 1);
+		ready.report_invalid(&first);
+
+		assert_eq!(ready.next().map(|tx| tx.hash), Some(3));
+		assert!(ready.next().is_none());
+	}
+}
@@ -0,0 +1,179 @@
+//! # Export Bridge Headers
+//!
+//! `export-bridge-headers` subcommand, which dumps the source chain headers verified by the
+//! Vector bridge and kept in the local database, with their execution state root and the sync
+//! committee participation they were finalized with, as JSON or CSV. Meant for studying the
+//! finality latency of the bridge without writing storage query scripts.
+use crate::inspect_bridge::StorageReader;
+use codec::Decode;
+use da_runtime::{NodeBlock as Block, Runtime};
+use frame_support::storage::{StorageMap, StoragePrefixedMap};
+use pallet_vector::{
+	ExecutionStateRoots, HeaderTimestamps, Headers, SlotParticipation, Timestamps,
+};
+use sc_cli::{CliConfiguration, DatabaseParams, PruningParams, SharedParams};
+use sc_client_api::{backend::Backend as _, StateBackend};
+use sc_service::Configuration as ServiceConfiguration;
+use serde::Serialize;
+use sp_blockchain::HeaderBackend;
+use sp_core::H256;
+use std::{fmt::Write as _, path::PathBuf};
+
+/// Output format of the exported headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExportFormat {
+	Json,
+	Csv,
+}
+
+/// Exports the verified source chain headers from the local database.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ExportBridgeHeadersCmd {
+	/// First slot to export.
+	#[arg(long, default_value_t = 0)]
+	pub from_slot: u64,
+
+	/// Last slot to export, the latest one by default.
+	#[arg(long)]
+	pub to_slot: Option<u64>,
+
+	/// Output format.
+	#[arg(long, value_enum, default_value_t = ExportFormat::Json)]
+	pub format: ExportFormat,
+
+	/// Write the headers to this file instead of the standard output.
+	#[arg(long)]
+	pub output: Option<PathBuf>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub pruning_params: PruningParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExportedHeader {
+	slot: u64,
+	header_root: H256,
+	execution_state_root: H256,
+	/// Sync committee participation of the step which finalized the header, unknown for the
+	/// headers stored before it was recorded.
+	participation: Option<u16>,
+	/// Timestamp of the header on the source chain, in seconds.
+	header_timestamp: Option<u64>,
+	/// Timestamp of the block of this chain which stored the header, in seconds.
+	timestamp: u64,
+}
+
+impl ExportBridgeHeadersCmd {
+	pub fn run(&self, config: &ServiceConfiguration) -> sc_cli::Result<()> {
+		let db_config = sc_client_db::DatabaseSettings {
+			trie_cache_maximum_size: config.trie_cache_maximum_size,
+			state_pruning: config.state_pruning.clone(),
+			source: config.database.clone(),
+			blocks_pruning: config.blocks_pruning,
+		};
+		let backend = sc_service::new_db_backend::<Block>(db_config)?;
+		let info = backend.blockchain().info();
+		let reader = StorageReader {
+			state: backend.state_at(info.best_hash)?,
+		};
+
+		let headers = self.headers(&reader)?;
+		let exported = match self.format {
+			ExportFormat::Json => {
+				serde_json::to_string_pretty(&headers).map_err(|e| e.to_string())?
+			},
+			ExportFormat::Csv => to_csv(&headers),
+		};
+		match &self.output {
+			Some(path) => std::fs::write(path, exported)?,
+			None => println!("{exported}"),
+		}
+		Ok(())
+	}
+
+	/// Collects the stored headers of the slots within the range, by increasing slot.
+	fn headers<S: StateBackend<sp_runtime::traits::BlakeTwo256>>(
+		&self,
+		reader: &StorageReader<S>,
+	) -> sc_cli::Result<Vec<ExportedHeader>> {
+		let prefix = Headers::<Runtime>::final_prefix();
+		let to_slot = self.to_slot.unwrap_or(u64::MAX);
+		// The slots are the `Identity` hashed suffix of the keys.
+		let mut slots = reader
+			.keys(&prefix)?
+			.into_iter()
+			.map(|key| {
+				u64::decode(&mut &key[prefix.len()..])
+					.map_err(|e| format!("Cannot decode header slot: {e}").into())
+			})
+			.collect::<sc_cli::Result<Vec<_>>>()?;
+		slots.retain(|slot| (self.from_slot..=to_slot).contains(slot));
+		slots.sort_unstable();
+
+		slots
+			.into_iter()
+			.map(|slot| {
+				Ok(ExportedHeader {
+					slot,
+					header_root: reader.get(&Headers::<Runtime>::hashed_key_for(slot))?,
+					execution_state_root: reader
+						.get(&ExecutionStateRoots::<Runtime>::hashed_key_for(slot))?,
+					participation: reader
+						.get(&SlotParticipation::<Runtime>::hashed_key_for(slot))?,
+					header_timestamp: reader
+						.get(&HeaderTimestamps::<Runtime>::hashed_key_for(slot))?,
+					timestamp: reader.get(&Timestamps::<Runtime>::hashed_key_for(slot))?,
+				})
+			})
+			.collect()
+	}
+}
+
+/// Writes `headers` as CSV, with a header row and empty fields for the unknown values.
+fn to_csv(headers: &[ExportedHeader]) -> String {
+	let mut csv = String::from(
+		"slot,header_root,execution_state_root,participation,header_timestamp,timestamp\n",
+	);
+	for header in headers {
+		let _ = writeln!(
+			csv,
+			"{},{:?},{:?},{},{},{}",
+			header.slot,
+			header.header_root,
+			header.execution_state_root,
+			csv_field(header.participation),
+			csv_field(header.header_timestamp),
+			header.timestamp
+		);
+	}
+	csv
+}
+
+/// Formats a CSV field, empty when the value is unknown.
+fn csv_field<T: ToString>(value: Option<T>) -> String {
+	value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+impl CliConfiguration for ExportBridgeHeadersCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
@@ -0,0 +1,243 @@
+//! # Inspect Bridge State
+//!
+//! `inspect-bridge-state` subcommand, which prints the Vector pallet storage of the local
+//! database as JSON. The database is only read, so it can be used to debug a stuck bridge
+//! without a running node or RPC server.
+use codec::Decode;
+use da_runtime::{NodeBlock as Block, Runtime};
+use frame_support::storage::{StorageMap, StoragePrefixedMap, StorageValue};
+use pallet_vector::{
+	archive::{self, EpochSlotList},
+	state::Configuration,
+	Broadcasters, ConfigurationStorage, EpochSlots, ExecutionStateRoots, FunctionIds, Head,
+	Headers, MessageStatus, NextArchiveEpoch, SourceChainFrozen, SyncCommitteeHashes, Timestamps,
+	Updaters, WhitelistedDomains,
+};
+use sc_cli::{CliConfiguration, DatabaseParams, PruningParams, SharedParams};
+use sc_client_api::{backend::Backend as _, StateBackend};
+use sc_service::Configuration as ServiceConfiguration;
+use serde::Serialize;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Get, H256, U256};
+use sp_state_machine::IterArgs;
+
+/// Prints the Vector bridge state from the local database.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct InspectBridgeStateCmd {
+	/// Number of latest slots to print.
+	#[arg(long, default_value_t = 10)]
+	pub slots: usize,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub pruning_params: PruningParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BridgeState {
+	block_number: u32,
+	block_hash: H256,
+	head: u64,
+	configuration: Configuration,
+	updaters: Vec<H256>,
+	function_ids: Option<(H256, H256)>,
+	latest_slots: Vec<SlotState>,
+	sync_committees: Vec<SyncCommitteeState>,
+	domains: Vec<DomainState>,
+	next_archive_epoch: Option<u64>,
+	executed_messages: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SlotState {
+	slot: u64,
+	header: H256,
+	execution_state_root: H256,
+	timestamp: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SyncCommitteeState {
+	period: u64,
+	hash: U256,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DomainState {
+	domain: u32,
+	broadcaster: H256,
+	frozen: bool,
+}
+
+pub(crate) struct StorageReader<S> {
+	pub(crate) state: S,
+}
+
+impl<S: StateBackend<sp_runtime::traits::BlakeTwo256>> StorageReader<S> {
+	pub(crate) fn get<V: Decode + Default>(&self, key: &[u8]) -> sc_cli::Result<V> {
+		let Some(raw) = self.state.storage(key).map_err(|e| format!("{e:?}"))? else {
+			return Ok(V::default());
+		};
+		V::decode(&mut raw.as_slice()).map_err(|e| format!("Cannot decode storage: {e}").into())
+	}
+
+	fn count_keys(&self, prefix: &[u8]) -> sc_cli::Result<usize> {
+		Ok(self.keys(prefix)?.len())
+	}
+
+	/// Returns the keys starting with `prefix`.
+	pub(crate) fn keys(&self, prefix: &[u8]) -> sc_cli::Result<Vec<Vec<u8>>> {
+		let args = IterArgs {
+			prefix: Some(prefix),
+			..IterArgs::default()
+		};
+		let keys = self.state.keys(args).map_err(|e| format!("{e:?}"))?;
+		keys.map(|key| key.map_err(|e| format!("{e:?}").into()))
+			.collect()
+	}
+}
+
+impl InspectBridgeStateCmd {
+	pub fn run(&self, config: &ServiceConfiguration) -> sc_cli::Result<()> {
+		let db_config = sc_client_db::DatabaseSettings {
+			trie_cache_maximum_size: config.trie_cache_maximum_size,
+			state_pruning: config.state_pruning.clone(),
+			source: config.database.clone(),
+			blocks_pruning: config.blocks_pruning,
+		};
+		let backend = sc_service::new_db_backend::<Block>(db_config)?;
+		let info = backend.blockchain().info();
+		let reader = StorageReader {
+			state: backend.state_at(info.best_hash)?,
+		};
+
+		let head: u64 = reader.get(&Head::<Runtime>::hashed_key())?;
+		let configuration: Configuration =
+			reader.get(&ConfigurationStorage::<Runtime>::hashed_key())?;
+		let next_archive_epoch: Option<u64> =
+			reader.get(&NextArchiveEpoch::<Runtime>::hashed_key())?;
+
+		let latest_slots = self.latest_slots(&reader, head, next_archive_epoch)?;
+
+		let period = head
+			.checked_div(configuration.slots_per_period)
+			.unwrap_or_default();
+		let sync_committees = [period, period + 1]
+			.into_iter()
+			.map(|period| {
+				let hash = reader.get(&SyncCommitteeHashes::<Runtime>::hashed_key_for(period))?;
+				Ok(SyncCommitteeState { period, hash })
+			})
+			.collect::<sc_cli::Result<Vec<_>>>()?;
+
+		let whitelisted: Vec<u32> = reader.get(&WhitelistedDomains::<Runtime>::hashed_key())?;
+		let domains = whitelisted
+			.into_iter()
+			.map(|domain| {
+				Ok(DomainState {
+					domain,
+					broadcaster: reader.get(&Broadcasters::<Runtime>::hashed_key_for(domain))?,
+					frozen: reader.get(&SourceChainFrozen::<Runtime>::hashed_key_for(domain))?,
+				})
+			})
+			.collect::<sc_cli::Result<Vec<_>>>()?;
+
+		let state = BridgeState {
+			block_number: info.best_number,
+			block_hash: info.best_hash,
+			head,
+			configuration,
+			updaters: Self::updaters(&reader)?,
+			function_ids: reader.get(&FunctionIds::<Runtime>::hashed_key())?,
+			latest_slots,
+			sync_committees,
+			domains,
+			next_archive_epoch,
+			executed_messages: reader.count_keys(&MessageStatus::<Runtime>::final_prefix())?,
+		};
+
+		let json = serde_json::to_string_pretty(&state).map_err(|e| e.to_string())?;
+		println!("{json}");
+		Ok(())
+	}
+
+	/// Collects the allowed updaters, the `Identity` hashed suffix of the `Updaters` keys.
+	fn updaters<S: StateBackend<sp_runtime::traits::BlakeTwo256>>(
+		reader: &StorageReader<S>,
+	) -> sc_cli::Result<Vec<H256>> {
+		let prefix = Updaters::<Runtime>::final_prefix();
+		reader
+			.keys(&prefix)?
+			.into_iter()
+			.map(|key| {
+				H256::decode(&mut &key[prefix.len()..])
+					.map_err(|e| format!("Cannot decode updater: {e}").into())
+			})
+			.collect()
+	}
+
+	/// Collects the latest `self.slots` slots, walking back over the not yet archived epochs.
+	fn latest_slots<S: StateBackend<sp_runtime::traits::BlakeTwo256>>(
+		&self,
+		reader: &StorageReader<S>,
+		head: u64,
+		next_archive_epoch: Option<u64>,
+	) -> sc_cli::Result<Vec<SlotState>> {
+		let mut slots = Vec::new();
+		let Some(oldest_epoch) = next_archive_epoch else {
+			return Ok(slots);
+		};
+		let epoch_slots = <Runtime as pallet_vector::Config>::ArchiveEpochSlots::get();
+
+		let mut epoch = archive::epoch_of(head, epoch_slots);
+		while slots.len() < self.slots && epoch >= oldest_epoch {
+			let epoch_slot_list: EpochSlotList =
+				reader.get(&EpochSlots::<Runtime>::hashed_key_for(epoch))?;
+			for slot in epoch_slot_list
+				.into_iter()
+				.rev()
+				.take(self.slots - slots.len())
+			{
+				slots.push(SlotState {
+					slot,
+					header: reader.get(&Headers::<Runtime>::hashed_key_for(slot))?,
+					execution_state_root: reader
+						.get(&ExecutionStateRoots::<Runtime>::hashed_key_for(slot))?,
+					timestamp: reader.get(&Timestamps::<Runtime>::hashed_key_for(slot))?,
+				});
+			}
+			let Some(previous) = epoch.checked_sub(1) else {
+				break;
+			};
+			epoch = previous;
+		}
+
+		Ok(slots)
+	}
+}
+
+impl CliConfiguration for InspectBridgeStateCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
@@ -1,10 +1,25 @@
+pub mod benchmark_da;
+pub mod bridge_guard;
+pub mod bridge_index;
+pub mod bridge_monitor;
+pub mod bridge_webhook;
 pub mod chains;
+pub mod da_cells;
+pub mod da_peer_bans;
 
 pub mod da_block_import;
 pub use da_block_import::BlockImport;
 
 pub mod cli;
+pub mod da_tx_priority;
+pub mod export_bridge_headers;
+pub mod inspect_bridge;
+pub mod remote_keystore;
 pub mod rpc;
+pub mod rpc_limits;
 pub mod service;
+pub mod vector_gossip;
+pub mod verify_da_range;
+pub mod verify_weights;
 
 pub const NODE_VERSION: &str = "2.1.5";
@@ -2,13 +2,28 @@
 #![warn(missing_docs)]
 #![warn(unused_extern_crates)]
 
+mod benchmark_da;
 mod benchmarking;
+mod bridge_guard;
+mod bridge_index;
+mod bridge_monitor;
+mod bridge_webhook;
 #[macro_use]
 mod service;
 mod cli;
 mod command;
 mod da_block_import;
+mod da_cells;
+mod da_peer_bans;
+mod da_tx_priority;
+mod export_bridge_headers;
+mod inspect_bridge;
+mod remote_keystore;
 mod rpc;
+mod rpc_limits;
+mod vector_gossip;
+mod verify_da_range;
+mod verify_weights;
 
 fn main() -> sc_cli::Result<()> {
 	command::run()
@@ -0,0 +1,354 @@
+//! # Remote Keystore
+//!
+//! Keystore of the offchain workers which signs with the keys of some key types through a remote
+//! signer, e.g. a host in front of an HSM, so that keys like those of the bridge updater never
+//! live on the host exposing the RPC. The keys of the other key types stay in the local keystore.
+//!
+//! The signer is called over JSON-RPC with:
+//! - `keystore_publicKeys(keyType, scheme)`, returning the public keys of the key type,
+//! - `keystore_sign(keyType, scheme, public, message)`, returning the signature of the message,
+//!   or `null` if the signer does not hold the key,
+//! - `keystore_signPrehashed(keyType, public, hash)`, the same for a prehashed ECDSA message.
+//!
+//! Key types are 4 characters strings, schemes are `sr25519`, `ed25519` or `ecdsa` and keys,
+//! messages and signatures are hex encoded. Keys of remote key types are never generated nor
+//! inserted through the node, and VRF signing is not supported for them.
+use crate::cli::Cli;
+use jsonrpsee::{
+	core::{client::ClientT, params::ArrayParams},
+	http_client::{HttpClient, HttpClientBuilder},
+	rpc_params,
+};
+use serde::de::DeserializeOwned;
+use sp_core::{
+	crypto::{ByteArray, KeyTypeId},
+	ecdsa, ed25519, sr25519, Bytes,
+};
+use sp_keystore::{Error, Keystore, KeystorePtr};
+use std::{sync::Arc, time::Duration};
+
+const LOG_TARGET: &str = "avail::node::remote_keystore";
+
+/// Timeout of a call to the remote signer.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Remote signer of the keys used by the offchain workers.
+#[derive(Debug, Clone, Default)]
+pub struct RemoteKeystoreConfig {
+	/// URL of the JSON-RPC endpoint of the signer, the local keystore is used alone without it.
+	pub uri: Option<String>,
+	/// Key types whose keys are held by the signer.
+	pub key_types: Vec<KeyTypeId>,
+}
+
+impl From<&Cli> for RemoteKeystoreConfig {
+	fn from(cli: &Cli) -> Self {
+		Self {
+			uri: cli.offchain_keystore_uri.clone(),
+			key_types: cli.offchain_remote_key_type.clone(),
+		}
+	}
+}
+
+impl RemoteKeystoreConfig {
+	/// Returns the keystore of the offchain workers, `local` itself without a remote signer.
+	///
+	/// Calls to the signer are blocking and run on `runtime`, they must not be made from it.
+	pub fn keystore(
+		&self,
+		local: KeystorePtr,
+		runtime: tokio::runtime::Handle,
+	) -> Result<KeystorePtr, String> {
+		let Some(uri) = &self.uri else {
+			return Ok(local);
+		};
+		let client = HttpClientBuilder::default()
+			.request_timeout(REQUEST_TIMEOUT)
+			.build(uri)
+			.map_err(|e| format!("Invalid remote keystore URI {uri}: {e}"))?;
+		log::info!(
+			target: LOG_TARGET,
+			"Keys of types {:?} are signed by the remote keystore {uri}",
+			self.key_types.iter().map(key_type_name).collect::<Vec<_>>()
+		);
+
+		Ok(Arc::new(RemoteKeystore {
+			local,
+			key_types: self.key_types.clone(),
+			client,
+			runtime,
+		}))
+	}
+}
+
+/// Parses a key type given on the command line, e.g. `imon`.
+pub fn parse_key_type(s: &str) -> Result<KeyTypeId, String> {
+	KeyTypeId::try_from(s).map_err(|_| format!("Key type {s} is not 4 characters long"))
+}
+
+fn key_type_name(key_type: &KeyTypeId) -> String {
+	String::from_utf8_lossy(&key_type.0).into_owned()
+}
+
+/// Keystore forwarding the keys of `key_types` to a remote signer and the others to `local`.
+pub struct RemoteKeystore {
+	local: KeystorePtr,
+	key_types: Vec<KeyTypeId>,
+	client: HttpClient,
+	runtime: tokio::runtime::Handle,
+}
+
+impl RemoteKeystore {
+	fn is_remote(&self, key_type: KeyTypeId) -> bool {
+		self.key_types.contains(&key_type)
+	}
+
+	fn call<R: DeserializeOwned>(&self, method: &str, params: ArrayParams) -> Result<R, Error> {
+		// Blocking on the runtime from one of its own threads would stall it.
+		if tokio::runtime::Handle::try_current().is_ok() {
+			return Err(Error::Other(format!(
+				"Remote keystore call {method} made from an async context"
+			)));
+		}
+		self.runtime
+			.block_on(self.client.request(method, params))
+			.map_err(|e| Error::Other(format!("Remote keystore call {method} failed: {e}")))
+	}
+
+	fn remote_public_keys(&self, key_type: KeyTypeId, scheme: &str) -> Result<Vec<Bytes>, Error> {
+		self.call(
+			"keystore_publicKeys",
+			rpc_params![key_type_name(&key_type), scheme],
+		)
+	}
+
+	fn public_keys<P: ByteArray>(&self, key_type: KeyTypeId, scheme: &str) -> Vec<P> {
+		match self.remote_public_keys(key_type, scheme) {
+			Ok(keys) => keys
+				.iter()
+				.filter_map(|key| P::from_slice(key).ok())
+				.collect(),
+			Err(e) => {
+				log::warn!(target: LOG_TARGET, "{e}");
+				Vec::new()
+			},
+		}
+	}
+
+	fn sign<S>(
+		&self,
+		key_type: KeyTypeId,
+		scheme: &str,
+		public: &[u8],
+		msg: &[u8],
+	) -> Result<Option<S>, Error>
+	where
+		S: for<'a> TryFrom<&'a [u8]>,
+	{
+		let signature: Option<Bytes> = self.call(
+			"keystore_sign",
+			rpc_params![
+				key_type_name(&key_type),
+				scheme,
+				Bytes(public.to_vec()),
+				Bytes(msg.to_vec())
+			],
+		)?;
+		signature
+			.map(|signature| {
+				S::try_from(&signature[..]).map_err(|_| {
+					Error::ValidationError(String::from("Invalid signature of the remote keystore"))
+				})
+			})
+			.transpose()
+	}
+}
+
+impl Keystore for RemoteKeystore {
+	fn sr25519_public_keys(&self, key_type: KeyTypeId) -> Vec<sr25519::Public> {
+		if self.is_remote(key_type) {
+			return self.public_keys(key_type, "sr25519");
+		}
+		self.local.sr25519_public_keys(key_type)
+	}
+
+	fn sr25519_generate_new(
+		&self,
+		key_type: KeyTypeId,
+		seed: Option<&str>,
+	) -> Result<sr25519::Public, Error> {
+		if self.is_remote(key_type) {
+			return Err(Error::Unavailable);
+		}
+		self.local.sr25519_generate_new(key_type, seed)
+	}
+
+	fn sr25519_sign(
+		&self,
+		key_type: KeyTypeId,
+		public: &sr25519::Public,
+		msg: &[u8],
+	) -> Result<Option<sr25519::Signature>, Error> {
+		if self.is_remote(key_type) {
+			return self.sign(key_type, "sr25519", public.as_slice(), msg);
+		}
+		self.local.sr25519_sign(key_type, public, msg)
+	}
+
+	fn sr25519_vrf_sign(
+		&self,
+		key_type: KeyTypeId,
+		public: &sr25519::Public,
+		data: &sr25519::vrf::VrfSignData,
+	) -> Result<Option<sr25519::vrf::VrfSignature>, Error> {
+		if self.is_remote(key_type) {
+			return Err(Error::KeyNotSupported(key_type));
+		}
+		self.local.sr25519_vrf_sign(key_type, public, data)
+	}
+
+	fn sr25519_vrf_pre_output(
+		&self,
+		key_type: KeyTypeId,
+		public: &sr25519::Public,
+		input: &sr25519::vrf::VrfInput,
+	) -> Result<Option<sr25519::vrf::VrfPreOutput>, Error> {
+		if self.is_remote(key_type) {
+			return Err(Error::KeyNotSupported(key_type));
+		}
+		self.local.sr25519_vrf_pre_output(key_type, public, input)
+	}
+
+	fn ed25519_public_keys(&self, key_type: KeyTypeId) -> Vec<ed25519::Public> {
+		if self.is_remote(key_type) {
+			return self.public_keys(key_type, "ed25519");
+		}
+		self.local.ed25519_public_keys(key_type)
+	}
+
+	fn ed25519_generate_new(
+		&self,
+		key_type: KeyTypeId,
+		seed: Option<&str>,
+	) -> Result<ed25519::Public, Error> {
+		if self.is_remote(key_type) {
+			return Err(Error::Unavailable);
+		}
+		self.local.ed25519_generate_new(key_type, seed)
+	}
+
+	fn ed25519_sign(
+		&self,
+		key_type: KeyTypeId,
+		public: &ed25519::Public,
+		msg: &[u8],
+	) -> Result<Option<ed25519::Signature>, Error> {
+		if self.is_remote(key_type) {
+			return self.sign(key_type, "ed25519", public.as_slice(), msg);
+		}
+		self.local.ed25519_sign(key_type, public, msg)
+	}
+
+	fn ecdsa_public_keys(&self, key_type: KeyTypeId) -> Vec<ecdsa::Public> {
+		if self.is_remote(key_type) {
+			return self.public_keys(key_type, "ecdsa");
+		}
+		self.local.ecdsa_public_keys(key_type)
+	}
+
+	fn ecdsa_generate_new(
+		&self,
+		key_type: KeyTypeId,
+		seed: Option<&str>,
+	) -> Result<ecdsa::Public, Error> {
+		if self.is_remote(key_type) {
+			return Err(Error::Unavailable);
+		}
+		self.local.ecdsa_generate_new(key_type, seed)
+	}
+
+	fn ecdsa_sign(
+		&self,
+		key_type: KeyTypeId,
+		public: &ecdsa::Public,
+		msg: &[u8],
+	) -> Result<Option<ecdsa::Signature>, Error> {
+		if self.is_remote(key_type) {
+			return self.sign(key_type, "ecdsa", public.as_slice(), msg);
+		}
+		self.local.ecdsa_sign(key_type, public, msg)
+	}
+
+	fn ecdsa_sign_prehashed(
+		&self,
+		key_type: KeyTypeId,
+		public: &ecdsa::Public,
+		msg: &[u8; 32],
+	) -> Result<Option<ecdsa::Signature>, Error> {
+		if !self.is_remote(key_type) {
+			return self.local.ecdsa_sign_prehashed(key_type, public, msg);
+		}
+		let signature: Option<Bytes> = self.call(
+			"keystore_signPrehashed",
+			rpc_params![
+				key_type_name(&key_type),
+				Bytes(public.to_raw_vec()),
+				Bytes(msg.to_vec())
+			],
+		)?;
+		signature
+			.map(|signature| {
+				ecdsa::Signature::try_from(&signature[..]).map_err(|_| {
+					Error::ValidationError(String::from("Invalid signature of the remote keystore"))
+				})
+			})
+			.transpose()
+	}
+
+	fn insert(&self, key_type: KeyTypeId, suri: &str, public: &[u8]) -> Result<(), ()> {
+		if self.is_remote(key_type) {
+			log::warn!(
+				target: LOG_TARGET,
+				"Keys of type {} are held by the remote keystore and cannot be inserted",
+				key_type_name(&key_type)
+			);
+			return Err(());
+		}
+		self.local.insert(key_type, suri, public)
+	}
+
+	fn keys(&self, key_type: KeyTypeId) -> Result<Vec<Vec<u8>>, Error> {
+		if !self.is_remote(key_type) {
+			return self.local.keys(key_type);
+		}
+		let mut keys = Vec::new();
+		for scheme in ["sr25519", "ed25519", "ecdsa"] {
+			let scheme_keys = self.remote_public_keys(key_type, scheme)?;
+			keys.extend(scheme_keys.into_iter().map(|key| key.0));
+		}
+		Ok(keys)
+	}
+
+	fn has_keys(&self, public_keys: &[(Vec<u8>, KeyTypeId)]) -> bool {
+		public_keys.iter().all(|(public, key_type)| {
+			if self.is_remote(*key_type) {
+				self.keys(*key_type)
+					.map_or(false, |keys| keys.contains(public))
+			} else {
+				self.local.has_keys(&[(public.clone(), *key_type)])
+			}
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse_key_type_requires_4_characters() {
+		assert_eq!(parse_key_type("imon"), Ok(KeyTypeId(*b"imon")));
+		assert!(parse_key_type("vector").is_err());
+		assert!(parse_key_type("").is_err());
+	}
+}
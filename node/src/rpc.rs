@@ -30,31 +30,84 @@
 
 //! # Data Availability Changes
 //! - Add Kate RPC extension.
+//! - Add Vector RPC extension.
 //! - Remove `sc_rpc::dev` extension.
+//! - Add `system_healthDetailed`, built once the network is started.
+//! - Add the operator RPC server, serving the operator-only methods behind a token.
+//! - Add `succinct_gossipStepProof`, `succinct_gossipPendingMessage` and
+//!   `succinct_pendingMessages`, built once the Vector gossip is started.
 
 #![warn(missing_docs)]
 
-use std::sync::Arc;
+use std::{
+	error::Error,
+	fs::File,
+	io::BufReader,
+	marker::PhantomData,
+	net::SocketAddr,
+	path::{Path, PathBuf},
+	sync::Arc,
+	task::{Context, Poll},
+	time::{SystemTime, UNIX_EPOCH},
+};
 
+use crate::{
+	bridge_index::{BridgeIndex, MessageFilter, MessagesPage, Page},
+	cli::Cli,
+	rpc_limits::RpcLimitsConfig,
+	vector_gossip::{GossipMessage, PendingMessage, VectorGossipHandle},
+};
+use avail_core::data_proof::AddressedMessage;
+use codec::Decode;
 use da_runtime::{
 	apis::{DataAvailApi, KateApi, VectorApi},
-	AccountId, Balance, BlockNumber, Hash, Index, NodeBlock as Block,
+	AccountId, Balance, BlockNumber, Hash, Index, NodeBlock as Block, Runtime,
+};
+use frame_support::storage::StorageValue;
+use futures::{future::BoxFuture, FutureExt};
+use hyper::{
+	header::{HeaderMap, AUTHORIZATION, WWW_AUTHENTICATE},
+	Body, Request as HttpRequest, Response as HttpResponse, StatusCode,
+};
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	server::ServerBuilder,
+	types::error::{ErrorObject, ErrorObjectOwned, INTERNAL_ERROR_CODE},
+	Methods, RpcModule,
+};
+use pallet_vector::{
+	artifact::{self, ProofArtifact},
+	finality::FinalityConfig,
+	Finality,
 };
-use jsonrpsee::RpcModule;
-use sc_client_api::AuxStore;
+use sc_client_api::{AuxStore, StorageProvider};
 use sc_consensus_babe::BabeWorkerHandle;
 use sc_consensus_grandpa::{
 	FinalityProofProvider, GrandpaJustificationStream, SharedAuthoritySet, SharedVoterState,
 };
+use sc_network_sync::SyncingService;
 use sc_rpc::SubscriptionTaskExecutor;
 pub use sc_rpc_api::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
+use serde::Serialize;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 use sp_consensus::SelectChain;
 use sp_consensus_babe::BabeApi;
+use sp_core::{
+	offchain::{OffchainStorage, STORAGE_PREFIX},
+	storage::StorageKey,
+	Bytes,
+};
 use sp_keystore::KeystorePtr;
+use tokio::net::{TcpListener, TcpStream};
+use tokio_rustls::{
+	rustls::{Certificate, PrivateKey, ServerConfig},
+	TlsAcceptor,
+};
+use tower::{Layer, Service};
 
 /// Extra dependencies for BABE.
 pub struct BabeDeps {
@@ -102,6 +155,8 @@ pub struct FullDeps<C, P, SC, B> {
 	///
 	/// Should not be used unless unless you know what you're doing.
 	pub kate_rpc_metrics_enabled: bool,
+	/// Limits of the Kate and Vector RPCs.
+	pub rpc_limits: RpcLimitsConfig,
 }
 
 /// Instantiate all Full RPC extensions.
@@ -112,9 +167,11 @@ pub fn create_full<C, P, SC, B>(
 where
 	C: ProvideRuntimeApi<Block>
 		+ sc_client_api::BlockBackend<Block>
+		+ sc_client_api::BlockchainEvents<Block>
 		+ HeaderBackend<Block>
 		+ AuxStore
 		+ HeaderMetadata<Block, Error = BlockChainError>
+		+ sc_client_api::ProofProvider<Block>
 		+ Sync
 		+ Send
 		+ 'static,
@@ -130,6 +187,7 @@ where
 	B::State: sc_client_api::backend::StateBackend<sp_runtime::traits::HashingFor<Block>>,
 {
 	use kate_rpc::metrics::KateApiMetricsServer;
+	use kate_rpc::subscription::{KateSubscriptionApiServer, SubscriptionLimit};
 	use kate_rpc::{Kate, KateApiServer};
 	use mmr_rpc::{Mmr, MmrApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
@@ -139,6 +197,7 @@ where
 	use sc_sync_state_rpc::{SyncState, SyncStateApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 	use substrate_state_trie_migration_rpc::{StateMigration, StateMigrationApiServer};
+	use vector_rpc::{Vector, VectorApiServer};
 
 	#[cfg(feature = "testing-environment")]
 	use testing_rpc::{TestingApiServer, TestingEnv};
@@ -155,6 +214,7 @@ where
 		kate_max_cells_size,
 		kate_rpc_enabled,
 		kate_rpc_metrics_enabled,
+		rpc_limits,
 	} = deps;
 
 	let BabeDeps {
@@ -226,18 +286,34 @@ where
 
 	io.merge(StateMigration::new(client.clone(), backend, deny_unsafe).into_rpc())?;
 
-	if is_dev_chain || kate_rpc_metrics_enabled {
-		io.merge(KateApiMetricsServer::into_rpc(Kate::<C, Block>::new(
+	io.merge(
+		rpc_limits.limit(VectorApiServer::into_rpc(Vector::<C, Block>::new(
 			client.clone(),
-			kate_max_cells_size,
-		)))?;
+			deny_unsafe,
+		)))?,
+	)?;
+
+	if is_dev_chain || kate_rpc_metrics_enabled {
+		io.merge(
+			rpc_limits.limit(KateApiMetricsServer::into_rpc(Kate::<C, Block>::new(
+				client.clone(),
+				kate_max_cells_size,
+			)))?,
+		)?;
 	}
 
 	if is_dev_chain || kate_rpc_enabled || kate_rpc_metrics_enabled {
-		io.merge(KateApiServer::into_rpc(Kate::<C, Block>::new(
-			client,
-			kate_max_cells_size,
-		)))?;
+		io.merge(
+			rpc_limits.limit(KateApiServer::into_rpc(Kate::<C, Block>::new(
+				client.clone(),
+				kate_max_cells_size,
+			)))?,
+		)?;
+		let subscription_limit = SubscriptionLimit::new(rpc_limits.max_subscriptions);
+		io.merge(KateSubscriptionApiServer::into_rpc(
+			Kate::<C, Block>::new(client, kate_max_cells_size)
+				.with_subscription_limit(subscription_limit),
+		))?;
 	}
 
 	#[cfg(feature = "testing-environment")]
@@ -245,3 +321,582 @@ where
 
 	Ok(io)
 }
+
+/// Health of the node, its DA verification and its bridge, for load balancers and monitors.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthDetailed {
+	/// The node is downloading or importing many blocks, so its state is behind the network.
+	pub is_syncing: bool,
+	/// Number of the best imported block.
+	pub best_block: BlockNumber,
+	/// Number of the latest finalized block.
+	pub finalized_block: BlockNumber,
+	/// Number of the best block announced by the peers, if any.
+	pub best_seen_block: Option<BlockNumber>,
+	/// Number of peers the node syncs from.
+	pub peers: u32,
+	/// Blocks waiting in the import queue, where their DA commitments are verified.
+	pub da_verification_backlog: u32,
+	/// Latest beacon chain slot verified by the bridge, at the best block.
+	pub bridge_head: u64,
+	/// Beacon chain slots elapsed since `bridge_head`, if the bridge knows the chain genesis.
+	pub bridge_lag_slots: Option<u64>,
+	/// Extrinsics submitted to the node and waiting in its transaction pool.
+	pub rpc_queue_depth: usize,
+}
+
+/// Detailed health check of the node.
+#[rpc(server)]
+pub trait HealthApi {
+	/// Returns the sync status, the DA verification backlog, the bridge head and lag, the
+	/// transaction queue depth and the peer count of the node in one call.
+	#[method(name = "system_healthDetailed")]
+	async fn health_detailed(&self) -> RpcResult<HealthDetailed>;
+}
+
+/// Implementation of `system_healthDetailed`, which needs the network of the node.
+pub struct Health<C, P, B> {
+	client: Arc<C>,
+	pool: Arc<P>,
+	sync_service: Arc<SyncingService<Block>>,
+	_backend: PhantomData<B>,
+}
+
+impl<C, P, B> Health<C, P, B> {
+	/// Creates the health check of the node.
+	pub fn new(client: Arc<C>, pool: Arc<P>, sync_service: Arc<SyncingService<Block>>) -> Self {
+		Self {
+			client,
+			pool,
+			sync_service,
+			_backend: PhantomData,
+		}
+	}
+}
+
+impl<C, P, B> Health<C, P, B>
+where
+	C: StorageProvider<Block, B>,
+	B: sc_client_api::Backend<Block>,
+{
+	fn storage<V: Decode + Default>(&self, at: Hash, key: Vec<u8>) -> RpcResult<V> {
+		let Some(raw) = self
+			.client
+			.storage(at, &StorageKey(key))
+			.map_err(|e| internal_err(format!("Cannot read storage: {e:?}")))?
+		else {
+			return Ok(V::default());
+		};
+		V::decode(&mut raw.0.as_slice())
+			.map_err(|e| internal_err(format!("Cannot decode storage: {e}")))
+	}
+
+	/// Source chain slots elapsed since `head`, from the finality config of the bridge.
+	fn bridge_lag_slots(&self, at: Hash, head: u64) -> RpcResult<Option<u64>> {
+		let finality: FinalityConfig =
+			self.storage(at, Finality::<Runtime>::hashed_key().to_vec())?;
+
+		let now = SystemTime::now()
+			.duration_since(UNIX_EPOCH)
+			.map_err(|e| internal_err(format!("System time is before the epoch: {e}")))?
+			.as_secs();
+		let current_slot = finality.slot_at(now);
+		Ok(current_slot.map(|slot| slot.saturating_sub(head)))
+	}
+}
+
+#[async_trait]
+impl<C, P, B> HealthApiServer for Health<C, P, B>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + StorageProvider<Block, B>,
+	C: Send + Sync + 'static,
+	C::Api: VectorApi<Block>,
+	P: TransactionPool + 'static,
+	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
+{
+	async fn health_detailed(&self) -> RpcResult<HealthDetailed> {
+		let sync = self
+			.sync_service
+			.status()
+			.await
+			.map_err(|_| internal_err("Sync service is stopped".into()))?;
+		let info = self.client.info();
+
+		let bridge_head = self
+			.client
+			.runtime_api()
+			.head(info.best_hash)
+			.map_err(|e| internal_err(format!("Cannot read the bridge head: {e:?}")))?;
+		let bridge_lag_slots = self.bridge_lag_slots(info.best_hash, bridge_head)?;
+
+		let pool = self.pool.status();
+
+		Ok(HealthDetailed {
+			is_syncing: sync.state.is_major_syncing(),
+			best_block: info.best_number,
+			finalized_block: info.finalized_number,
+			best_seen_block: sync.best_seen_block,
+			peers: sync.num_peers,
+			da_verification_backlog: sync.queued_blocks,
+			bridge_head,
+			bridge_lag_slots,
+			rpc_queue_depth: pool.ready.saturating_add(pool.future),
+		})
+	}
+}
+
+/// Queries of the offchain index of the bridge messages.
+#[rpc(server)]
+pub trait BridgeIndexApi {
+	/// Returns a page of the indexed bridge message events matching `filter` on the canonical
+	/// chain, at most `MAX_PAGE_SIZE` of them.
+	#[method(name = "succinct_queryMessages")]
+	async fn query_messages(&self, filter: MessageFilter, page: Page) -> RpcResult<MessagesPage>;
+}
+
+/// Implementation of `succinct_queryMessages`, which reads the offchain storage of the node.
+pub struct BridgeIndexQuery<C, S> {
+	client: Arc<C>,
+	index: BridgeIndex<S>,
+}
+
+impl<C, S: OffchainStorage> BridgeIndexQuery<C, S> {
+	/// Creates the queries of the index kept in `storage`.
+	pub fn new(client: Arc<C>, storage: S) -> Self {
+		Self {
+			client,
+			index: BridgeIndex::new(storage),
+		}
+	}
+}
+
+#[async_trait]
+impl<C, S> BridgeIndexApiServer for BridgeIndexQuery<C, S>
+where
+	C: HeaderBackend<Block> + Send + Sync + 'static,
+	S: OffchainStorage + 'static,
+{
+	async fn query_messages(&self, filter: MessageFilter, page: Page) -> RpcResult<MessagesPage> {
+		self.index
+			.query(&filter, page, |number, hash| {
+				self.client.hash(number).ok().flatten() == Some(hash)
+			})
+			.map_err(internal_err)
+	}
+}
+
+/// Accepted light client proof artifact, as served by `succinct_proofArtifact`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofArtifactResponse {
+	/// Block the proof was accepted in.
+	pub block_number: u32,
+	/// Function id of the `fulfill_call` submission.
+	pub function_id: Hash,
+	/// Inputs of the submission, with their proofs, as submitted.
+	pub inputs: Bytes,
+}
+
+/// Queries of the accepted light client proofs kept for audit.
+#[rpc(server)]
+pub trait ProofArtifactApi {
+	/// Returns the accepted `fulfill_call` inputs hashed to `input_hash`, if they are still within
+	/// the retention of the `Vector` pallet and indexed by this node.
+	#[method(name = "succinct_proofArtifact")]
+	fn proof_artifact(&self, input_hash: Hash) -> RpcResult<Option<ProofArtifactResponse>>;
+}
+
+/// Implementation of `succinct_proofArtifact`, which reads the offchain storage of the node.
+pub struct ProofArtifacts<S> {
+	storage: S,
+}
+
+impl<S: OffchainStorage> ProofArtifacts<S> {
+	/// Creates the queries of the proof artifacts indexed in `storage`.
+	pub fn new(storage: S) -> Self {
+		Self { storage }
+	}
+}
+
+impl<S: OffchainStorage + 'static> ProofArtifactApiServer for ProofArtifacts<S> {
+	fn proof_artifact(&self, input_hash: Hash) -> RpcResult<Option<ProofArtifactResponse>> {
+		let key = artifact::proof_artifact_key(input_hash);
+		let Some(raw) = self.storage.get(STORAGE_PREFIX, &key) else {
+			return Ok(None);
+		};
+		let artifact = ProofArtifact::decode(&mut raw.as_slice())
+			.map_err(|e| internal_err(format!("Invalid proof artifact: {e}")))?;
+		Ok(Some(ProofArtifactResponse {
+			block_number: artifact.block_number,
+			function_id: artifact.function_id,
+			inputs: artifact.inputs.into(),
+		}))
+	}
+}
+
+/// Pending message received over the Vector gossip, as served by `succinct_pendingMessages`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingMessageResponse {
+	/// Slot whose execution state root the proofs are verified against.
+	pub slot: u64,
+	/// Message sent on the source chain.
+	pub message: AddressedMessage,
+	/// Account proof of the source bridge contract.
+	pub account_proof: Vec<Bytes>,
+	/// Storage proof of the message in the source bridge contract.
+	pub storage_proof: Vec<Bytes>,
+}
+
+impl From<PendingMessage> for PendingMessageResponse {
+	fn from(pending: PendingMessage) -> Self {
+		Self {
+			slot: pending.slot,
+			message: pending.addr_message,
+			account_proof: pending.account_proof.into_iter().map(Bytes).collect(),
+			storage_proof: pending.storage_proof.into_iter().map(Bytes).collect(),
+		}
+	}
+}
+
+/// Publication of the step proofs and pending messages of a local relayer over the Vector gossip.
+#[rpc(server)]
+pub trait VectorGossipApi {
+	/// Gossips the CBOR encoded step or rotate `inputs` of `function_id`, as accepted by
+	/// `fulfill_call`, once the node verified them.
+	#[method(name = "succinct_gossipStepProof")]
+	fn gossip_step_proof(&self, function_id: Hash, inputs: Bytes) -> RpcResult<()>;
+
+	/// Gossips `message` with its proofs against the execution state root of `slot`, as accepted
+	/// by `execute`, once the node verified them.
+	#[method(name = "succinct_gossipPendingMessage")]
+	fn gossip_pending_message(
+		&self,
+		slot: u64,
+		message: AddressedMessage,
+		account_proof: Vec<Bytes>,
+		storage_proof: Vec<Bytes>,
+	) -> RpcResult<()>;
+
+	/// Returns the latest verified pending messages received over the gossip, oldest first. They
+	/// may have been executed since.
+	#[method(name = "succinct_pendingMessages")]
+	fn pending_messages(&self) -> RpcResult<Vec<PendingMessageResponse>>;
+}
+
+/// Implementation of the Vector gossip methods, which queue the proofs to the gossip worker.
+pub struct VectorGossip {
+	handle: VectorGossipHandle,
+	deny_unsafe: DenyUnsafe,
+}
+
+impl VectorGossip {
+	/// Creates the publication of proofs over the gossip worker of `handle`.
+	pub fn new(handle: VectorGossipHandle, deny_unsafe: DenyUnsafe) -> Self {
+		Self {
+			handle,
+			deny_unsafe,
+		}
+	}
+}
+
+impl VectorGossipApiServer for VectorGossip {
+	fn gossip_step_proof(&self, function_id: Hash, inputs: Bytes) -> RpcResult<()> {
+		// every proof is verified by the gossip worker
+		self.deny_unsafe.check_if_safe()?;
+		self.handle.gossip(GossipMessage::StepProof {
+			function_id,
+			inputs: inputs.0,
+		});
+		Ok(())
+	}
+
+	fn gossip_pending_message(
+		&self,
+		slot: u64,
+		message: AddressedMessage,
+		account_proof: Vec<Bytes>,
+		storage_proof: Vec<Bytes>,
+	) -> RpcResult<()> {
+		// every proof is verified by the gossip worker
+		self.deny_unsafe.check_if_safe()?;
+		self.handle
+			.gossip(GossipMessage::PendingMessage(PendingMessage {
+				slot,
+				addr_message: message,
+				account_proof: account_proof.into_iter().map(|node| node.0).collect(),
+				storage_proof: storage_proof.into_iter().map(|node| node.0).collect(),
+			}));
+		Ok(())
+	}
+
+	fn pending_messages(&self) -> RpcResult<Vec<PendingMessageResponse>> {
+		let pending = self.handle.pending_messages();
+		Ok(pending.into_iter().map(Into::into).collect())
+	}
+}
+
+/// Methods reserved to the operator of the node. With an operator RPC server, they are only
+/// served by it.
+pub const OPERATOR_METHODS: &[&str] = &[
+	"succinct_traceVerification",
+	"testing_toggleExtensionFailure",
+	"testing_toggleGridFailure",
+	"testing_toggleCommitmentFailure",
+	"testing_populateGrid",
+];
+
+const OPERATOR_LOG_TARGET: &str = "avail::node::operator_rpc";
+
+/// Configuration of the operator RPC server.
+#[derive(Debug, Clone)]
+pub struct OperatorRpcConfig {
+	/// Address the server listens on.
+	pub addr: SocketAddr,
+	/// Token the requests must carry as `Authorization: Bearer <token>`.
+	pub token: String,
+	/// Certificate chain and private key, in PEM files, if the server only accepts TLS.
+	pub tls: Option<(PathBuf, PathBuf)>,
+}
+
+impl OperatorRpcConfig {
+	/// Returns the operator RPC server configured on the command line, if any.
+	pub fn from_cli(cli: &Cli) -> Option<Self> {
+		Some(Self {
+			addr: cli.operator_rpc_addr?,
+			token: cli.operator_rpc_token.clone()?,
+			tls: cli
+				.operator_rpc_tls_cert
+				.clone()
+				.zip(cli.operator_rpc_tls_key.clone()),
+		})
+	}
+}
+
+/// Removes the operator methods from `module`, when they are served by the operator server.
+pub fn remove_operator_methods(module: &mut RpcModule<()>) {
+	for method in OPERATOR_METHODS {
+		module.remove_method(*method);
+	}
+}
+
+/// Removes all the methods but the operator ones from `module`.
+pub fn retain_operator_methods(module: &mut RpcModule<()>) {
+	let public: Vec<_> = module
+		.method_names()
+		.filter(|method| !OPERATOR_METHODS.contains(method))
+		.collect();
+	for method in public {
+		module.remove_method(method);
+	}
+}
+
+/// Serves `methods` on the operator RPC server until it stops. With TLS, the server listens on
+/// the loopback interface, behind a proxy terminating TLS on `config.addr`.
+pub async fn run_operator_server(
+	config: OperatorRpcConfig,
+	methods: impl Into<Methods>,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+	let acceptor = config
+		.tls
+		.as_ref()
+		.map(|(cert, key)| tls_acceptor(cert, key))
+		.transpose()?;
+	let listen_addr = match acceptor {
+		Some(_) => SocketAddr::from(([127, 0, 0, 1], 0)),
+		None => config.addr,
+	};
+
+	let auth = tower::ServiceBuilder::new().layer(OperatorAuthLayer::new(&config.token));
+	let server = ServerBuilder::default()
+		.set_middleware(auth)
+		.build(listen_addr)
+		.await?;
+	let server_addr = server.local_addr()?;
+	let handle = server.start(methods);
+	log::info!(
+		target: OPERATOR_LOG_TARGET,
+		"Operator RPC server listening on {} (TLS: {})",
+		config.addr,
+		acceptor.is_some()
+	);
+
+	match acceptor {
+		Some(acceptor) => {
+			let listener = TcpListener::bind(config.addr).await?;
+			proxy_tls(listener, acceptor, server_addr).await;
+		},
+		None => handle.stopped().await,
+	}
+	Ok(())
+}
+
+fn tls_acceptor(cert: &Path, key: &Path) -> Result<TlsAcceptor, Box<dyn Error + Send + Sync>> {
+	let certs = rustls_pemfile::certs(&mut BufReader::new(File::open(cert)?))?
+		.into_iter()
+		.map(Certificate)
+		.collect();
+	let key = rustls_pemfile::pkcs8_private_keys(&mut BufReader::new(File::open(key)?))?
+		.into_iter()
+		.next()
+		.map(PrivateKey)
+		.ok_or("No PKCS#8 private key in the operator RPC TLS key file")?;
+
+	let config = ServerConfig::builder()
+		.with_safe_defaults()
+		.with_no_client_auth()
+		.with_single_cert(certs, key)?;
+	Ok(TlsAcceptor::from(Arc::new(config)))
+}
+
+/// Terminates the TLS connections accepted by `listener` and forwards them to `server`.
+async fn proxy_tls(listener: TcpListener, acceptor: TlsAcceptor, server: SocketAddr) {
+	loop {
+		let stream = match listener.accept().await {
+			Ok((stream, _)) => stream,
+			Err(e) => {
+				log::warn!(target: OPERATOR_LOG_TARGET, "Cannot accept a connection: {e}");
+				continue;
+			},
+		};
+		let acceptor = acceptor.clone();
+		tokio::spawn(async move {
+			let result = async {
+				let mut tls = acceptor.accept(stream).await?;
+				let mut upstream = TcpStream::connect(server).await?;
+				tokio::io::copy_bidirectional(&mut tls, &mut upstream).await
+			}
+			.await;
+			if let Err(e) = result {
+				log::debug!(target: OPERATOR_LOG_TARGET, "Operator RPC connection closed: {e}");
+			}
+		});
+	}
+}
+
+/// HTTP middleware of the operator RPC server, rejecting the requests, and the WebSocket
+/// upgrades, which do not carry the operator token.
+#[derive(Clone)]
+pub struct OperatorAuthLayer {
+	token: Arc<str>,
+}
+
+impl OperatorAuthLayer {
+	/// Creates the middleware accepting `token`.
+	pub fn new(token: &str) -> Self {
+		Self {
+			token: token.into(),
+		}
+	}
+}
+
+impl<S> Layer<S> for OperatorAuthLayer {
+	type Service = OperatorAuth<S>;
+
+	fn layer(&self, inner: S) -> Self::Service {
+		OperatorAuth {
+			inner,
+			token: self.token.clone(),
+		}
+	}
+}
+
+/// Service of `OperatorAuthLayer`.
+#[derive(Clone)]
+pub struct OperatorAuth<S> {
+	inner: S,
+	token: Arc<str>,
+}
+
+impl<S> Service<HttpRequest<Body>> for OperatorAuth<S>
+where
+	S: Service<HttpRequest<Body>, Response = HttpResponse<Body>>,
+	S::Future: Send + 'static,
+{
+	type Response = S::Response;
+	type Error = S::Error;
+	type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+	fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+		self.inner.poll_ready(cx)
+	}
+
+	fn call(&mut self, request: HttpRequest<Body>) -> Self::Future {
+		if is_authorized(request.headers(), &self.token) {
+			return self.inner.call(request).boxed();
+		}
+
+		let response = HttpResponse::builder()
+			.status(StatusCode::UNAUTHORIZED)
+			.header(WWW_AUTHENTICATE, "Bearer")
+			.body(Body::empty())
+			.expect("Response parts are valid; qed");
+		futures::future::ready(Ok(response)).boxed()
+	}
+}
+
+/// Returns whether `headers` carry `token` as a bearer token.
+fn is_authorized(headers: &HeaderMap, token: &str) -> bool {
+	let Some(provided) = headers
+		.get(AUTHORIZATION)
+		.and_then(|value| value.to_str().ok())
+		.and_then(|value| value.strip_prefix("Bearer "))
+	else {
+		return false;
+	};
+	// Compared in constant time, so that the token is not guessed from the response times.
+	provided.len() == token.len()
+		&& provided
+			.bytes()
+			.zip(token.bytes())
+			.fold(0, |diff, (a, b)| diff | (a ^ b))
+			== 0
+}
+
+fn internal_err(message: String) -> ErrorObjectOwned {
+	ErrorObject::owned(INTERNAL_ERROR_CODE, message, None::<()>)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn operator_token_is_required() {
+		let mut headers = HeaderMap::new();
+		assert!(!is_authorized(&headers, "secret"));
+
+		headers.insert(AUTHORIZATION, "Bearer secret".parse().unwrap());
+		assert!(is_authorized(&headers, "secret"));
+		assert!(!is_authorized(&headers, "secret2"));
+		assert!(!is_authorized(&headers, "public"));
+
+		headers.insert(AUTHORIZATION, "Basic secret".parse().unwrap());
+		assert!(!is_authorized(&headers, "secret"));
+	}
+
+	#[test]
+	fn operator_methods_are_split_from_public_ones() {
+		let mut public = RpcModule::new(());
+		public
+			.register_method("succinct_traceVerification", |_, _| "trace")
+			.unwrap();
+		public
+			.register_method("succinct_headAgeSeconds", |_, _| 12)
+			.unwrap();
+		let mut operator = public.clone();
+
+		remove_operator_methods(&mut public);
+		retain_operator_methods(&mut operator);
+
+		assert_eq!(
+			vec!["succinct_headAgeSeconds"],
+			public.method_names().collect::<Vec<_>>()
+		);
+		assert_eq!(
+			vec!["succinct_traceVerification"],
+			operator.method_names().collect::<Vec<_>>()
+		);
+	}
+}
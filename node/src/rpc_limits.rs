@@ -0,0 +1,247 @@
+//! # RPC Limits
+//!
+//! Rate limits and response size caps of the expensive RPC methods of the node, like the Kate
+//! cell proofs and the Vector read proofs. The methods of a limited module are registered again
+//! in a new module, which checks the limits and forwards the calls to the original methods.
+//!
+//! The RPC server does not expose the address of the caller to the methods, so a rate limit is
+//! shared by all the callers of a method. Limits per caller are left to a reverse proxy.
+//!
+//! Subscriptions outlive the calls the rate limits apply to, so their methods cap the number of
+//! concurrent subscriptions with `max_subscriptions` instead.
+use crate::cli::Cli;
+use jsonrpsee::{
+	core::RpcResult,
+	types::{
+		error::{ErrorObject, ErrorObjectOwned, OVERSIZED_RESPONSE_CODE, SERVER_IS_BUSY_CODE},
+		Params,
+	},
+	Methods, RpcModule,
+};
+use serde::Deserialize;
+use serde_json::Value;
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+	time::Instant,
+};
+
+/// Limits of the expensive RPC methods.
+#[derive(Debug, Clone, Default)]
+pub struct RpcLimitsConfig {
+	/// Maximum number of calls per minute of each method.
+	pub calls_per_minute: Option<u32>,
+	/// Maximum number of calls per minute of single methods, overriding `calls_per_minute`.
+	pub method_calls_per_minute: HashMap<String, u32>,
+	/// Maximum size in bytes of a JSON-RPC response.
+	pub max_response_size: Option<usize>,
+	/// Maximum number of concurrent subscriptions of each subscription method.
+	pub max_subscriptions: Option<usize>,
+}
+
+impl From<&Cli> for RpcLimitsConfig {
+	fn from(cli: &Cli) -> Self {
+		Self {
+			calls_per_minute: cli.rpc_method_rate_limit,
+			method_calls_per_minute: cli.rpc_method_rate_limit_override.iter().cloned().collect(),
+			max_response_size: cli.rpc_method_max_response_size,
+			max_subscriptions: Some(cli.rpc_method_max_subscriptions),
+		}
+	}
+}
+
+impl RpcLimitsConfig {
+	fn is_unlimited(&self) -> bool {
+		self.calls_per_minute.is_none()
+			&& self.method_calls_per_minute.is_empty()
+			&& self.max_response_size.is_none()
+	}
+
+	fn calls_per_minute(&self, method: &str) -> Option<u32> {
+		self.method_calls_per_minute
+			.get(method)
+			.copied()
+			.or(self.calls_per_minute)
+	}
+
+	/// Returns the methods of `module` with the limits applied.
+	pub fn limit<Context>(
+		&self,
+		module: RpcModule<Context>,
+	) -> Result<Methods, Box<dyn std::error::Error + Send + Sync>>
+	where
+		Context: Send + Sync + 'static,
+	{
+		if self.is_unlimited() {
+			return Ok(module.into());
+		}
+
+		let inner = Arc::new(module);
+		let mut limited = RpcModule::new(());
+		for method in inner.method_names() {
+			let limit = Arc::new(MethodLimit {
+				method,
+				rate: self
+					.calls_per_minute(method)
+					.map(|calls| Mutex::new(TokenBucket::new(calls))),
+				max_response_size: self.max_response_size,
+			});
+			let inner = inner.clone();
+			limited.register_async_method(method, move |params, _| {
+				let (inner, limit) = (inner.clone(), limit.clone());
+				async move { limit.call(&inner, params).await }
+			})?;
+		}
+		Ok(limited.into())
+	}
+}
+
+/// Limits of a single method.
+struct MethodLimit {
+	method: &'static str,
+	rate: Option<Mutex<TokenBucket>>,
+	max_response_size: Option<usize>,
+}
+
+/// JSON-RPC response of a forwarded call.
+#[derive(Deserialize)]
+struct Response {
+	result: Option<Value>,
+	error: Option<ErrorObjectOwned>,
+}
+
+impl MethodLimit {
+	async fn call<Context>(
+		&self,
+		inner: &RpcModule<Context>,
+		params: Params<'static>,
+	) -> RpcResult<Value> {
+		let limited = self
+			.rate
+			.as_ref()
+			.is_some_and(|rate| !rate.lock().expect("Lock is never poisoned .qed").try_take());
+		if limited {
+			return Err(ErrorObject::owned(
+				SERVER_IS_BUSY_CODE,
+				format!("Rate limit of {} exceeded, try again later", self.method),
+				None::<()>,
+			));
+		}
+
+		let request = serde_json::json!({
+			"jsonrpc": "2.0",
+			"id": 0,
+			"method": self.method,
+			"params": params.parse::<Value>().unwrap_or(Value::Null),
+		});
+		let (response, _) = inner
+			.raw_json_request(&request.to_string(), 1)
+			.await
+			.map_err(|e| internal_err(format!("Call of {} failed: {e:?}", self.method)))?;
+
+		if let Some(max) = self.max_response_size {
+			if response.result.len() > max {
+				return Err(ErrorObject::owned(
+					OVERSIZED_RESPONSE_CODE,
+					format!(
+						"Response of {} exceeds {max} bytes, request less data per call",
+						self.method
+					),
+					None::<()>,
+				));
+			}
+		}
+
+		let response: Response = serde_json::from_str(&response.result)
+			.map_err(|e| internal_err(format!("Response of {} is invalid: {e:?}", self.method)))?;
+		match response.error {
+			Some(error) => Err(error),
+			None => Ok(response.result.unwrap_or(Value::Null)),
+		}
+	}
+}
+
+fn internal_err(message: String) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		jsonrpsee::types::error::INTERNAL_ERROR_CODE,
+		message,
+		None::<()>,
+	)
+}
+
+/// Token bucket refilled continuously with `calls_per_minute` tokens per minute.
+struct TokenBucket {
+	capacity: f64,
+	tokens: f64,
+	refilled_at: Instant,
+}
+
+impl TokenBucket {
+	fn new(calls_per_minute: u32) -> Self {
+		let capacity = f64::from(calls_per_minute);
+		Self {
+			capacity,
+			tokens: capacity,
+			refilled_at: Instant::now(),
+		}
+	}
+
+	fn try_take(&mut self) -> bool {
+		let now = Instant::now();
+		let elapsed = now.duration_since(self.refilled_at).as_secs_f64();
+		self.tokens = (self.tokens + elapsed * self.capacity / 60.0).min(self.capacity);
+		self.refilled_at = now;
+
+		if self.tokens < 1.0 {
+			return false;
+		}
+		self.tokens -= 1.0;
+		true
+	}
+}
+
+/// Parses a `<METHOD>=<CALLS_PER_MINUTE>` rate limit.
+pub fn parse_method_rate_limit(s: &str) -> Result<(String, u32), String> {
+	let (method, calls) = s
+		.split_once('=')
+		.ok_or_else(|| format!("Expected <METHOD>=<CALLS_PER_MINUTE>, got {s}"))?;
+	let calls = calls
+		.parse()
+		.map_err(|e| format!("Invalid calls per minute of {method}: {e}"))?;
+	Ok((method.to_string(), calls))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn token_bucket_limits_calls() {
+		let mut bucket = TokenBucket::new(2);
+		assert!(bucket.try_take());
+		assert!(bucket.try_take());
+		assert!(!bucket.try_take());
+	}
+
+	#[test]
+	fn method_override_takes_precedence() {
+		let config = RpcLimitsConfig {
+			calls_per_minute: Some(10),
+			method_calls_per_minute: [("kate_queryProof".to_string(), 2)].into(),
+			max_response_size: None,
+			max_subscriptions: None,
+		};
+		assert_eq!(config.calls_per_minute("kate_queryProof"), Some(2));
+		assert_eq!(config.calls_per_minute("kate_queryRows"), Some(10));
+	}
+
+	#[test]
+	fn parses_method_rate_limit() {
+		assert_eq!(
+			parse_method_rate_limit("kate_queryProof=30"),
+			Ok(("kate_queryProof".to_string(), 30))
+		);
+		assert!(parse_method_rate_limit("kate_queryProof").is_err());
+		assert!(parse_method_rate_limit("kate_queryProof=many").is_err());
+	}
+}
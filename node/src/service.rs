@@ -19,7 +19,21 @@
 //! Service and ServiceFactory implementation. Specialized wrapper over substrate service.
 #![allow(dead_code)]
 
-use crate::{cli::Cli, rpc as node_rpc};
+use crate::{
+	bridge_guard::BridgeGuard,
+	bridge_index::BridgeIndexer,
+	bridge_monitor,
+	bridge_webhook::{BridgeWebhook, BridgeWebhookConfig},
+	cli::Cli,
+	da_block_import::DaImportLimits,
+	da_cells::{self, DaCellsRequestHandler},
+	da_peer_bans::DaImportQueue,
+	da_tx_priority::{DaPrioritizedPool, DaTxPriorityConfig},
+	remote_keystore::RemoteKeystoreConfig,
+	rpc as node_rpc,
+	rpc_limits::RpcLimitsConfig,
+	vector_gossip::{self, StepInherentPool, VectorGossipWorker},
+};
 use avail_core::AppId;
 use da_runtime::{apis::RuntimeApi, NodeBlock as Block, Runtime};
 
@@ -41,7 +55,7 @@ use sc_transaction_pool_api::OffchainTransactionPoolFactory;
 use sp_api::ProvideRuntimeApi;
 use sp_core::crypto::Pair;
 use sp_runtime::{generic::Era, traits::Block as BlockT, SaturatedConversion};
-use std::{path::Path, sync::Arc};
+use std::{path::Path, sync::Arc, time::Duration};
 use substrate_prometheus_endpoint::{PrometheusError, Registry};
 
 pub const LOG_TARGET: &str = "avail::node::service";
@@ -170,15 +184,18 @@ pub fn create_extrinsic(
 pub fn new_partial(
 	config: &Configuration,
 	unsafe_da_sync: bool,
+	da_slow_verification_threshold: Duration,
+	da_import_limits: DaImportLimits,
 	kate_max_cells_size: usize,
 	kate_rpc_enabled: bool,
 	kate_rpc_metrics_enabled: bool,
+	rpc_limits: RpcLimitsConfig,
 ) -> Result<
 	sc_service::PartialComponents<
 		FullClient,
 		FullBackend,
 		FullSelectChain,
-		sc_consensus::DefaultImportQueue<Block>,
+		DaImportQueue<Block, sc_consensus::DefaultImportQueue<Block>, FullClient>,
 		sc_transaction_pool::FullPool<Block, FullClient>,
 		(
 			impl Fn(
@@ -262,7 +279,13 @@ pub fn new_partial(
 		client.clone(),
 	)?;
 
-	let da_block_import = BlockImport::new(client.clone(), block_import, unsafe_da_sync);
+	let da_block_import = BlockImport::new(
+		client.clone(),
+		block_import,
+		unsafe_da_sync,
+		da_slow_verification_threshold,
+		da_import_limits,
+	);
 
 	let slot_duration = babe_link.config().slot_duration();
 	let (import_queue, babe_worker_handle) =
@@ -288,6 +311,7 @@ pub fn new_partial(
 			telemetry: telemetry.as_ref().map(|x| x.handle()),
 			offchain_tx_pool_factory: OffchainTransactionPoolFactory::new(transaction_pool.clone()),
 		})?;
+	let import_queue = DaImportQueue::new(import_queue, da_block_import.peer_bans().clone());
 
 	let import_setup = (da_block_import, grandpa_link, babe_link);
 
@@ -332,6 +356,7 @@ pub fn new_partial(
 				kate_max_cells_size,
 				kate_rpc_enabled,
 				kate_rpc_metrics_enabled,
+				rpc_limits: rpc_limits.clone(),
 			};
 
 			node_rpc::create_full(deps, rpc_backend.clone()).map_err(Into::into)
@@ -375,9 +400,20 @@ pub fn new_full_base(
 	disable_hardware_benchmarks: bool,
 	with_startup_data: impl FnOnce(&BlockImport, &sc_consensus_babe::BabeLink<Block>),
 	unsafe_da_sync: bool,
+	da_slow_verification_threshold: Duration,
+	da_import_limits: DaImportLimits,
 	kate_max_cells_size: usize,
 	kate_rpc_enabled: bool,
 	kate_rpc_metrics_enabled: bool,
+	rpc_limits: RpcLimitsConfig,
+	operator_rpc: Option<node_rpc::OperatorRpcConfig>,
+	vector_gossip_enabled: bool,
+	vector_step_inherents_enabled: bool,
+	bridge_index_enabled: bool,
+	bridge_webhook: Option<BridgeWebhookConfig>,
+	da_cells_protocol_enabled: bool,
+	da_tx_priority: DaTxPriorityConfig,
+	remote_keystore: RemoteKeystoreConfig,
 ) -> Result<NewFullBase, ServiceError> {
 	let hwbench = if !disable_hardware_benchmarks {
 		config.database.path().map(|database_path| {
@@ -400,25 +436,45 @@ pub fn new_full_base(
 	} = new_partial(
 		&config,
 		unsafe_da_sync,
+		da_slow_verification_threshold,
+		da_import_limits,
 		kate_max_cells_size,
 		kate_rpc_enabled,
 		kate_rpc_metrics_enabled,
+		rpc_limits,
 	)?;
 
 	let shared_voter_state = rpc_setup;
 	let auth_disc_publish_non_global_ips = config.network.allow_non_globals_in_dht;
 	let mut net_config = sc_network::config::FullNetworkConfiguration::new(&config.network);
-	let grandpa_protocol_name = sc_consensus_grandpa::protocol_standard_name(
-		&client
-			.block_hash(0)
-			.ok()
-			.flatten()
-			.expect("Genesis block exists; qed"),
-		&config.chain_spec,
-	);
+	let genesis_hash = client
+		.block_hash(0)
+		.ok()
+		.flatten()
+		.expect("Genesis block exists; qed");
+	let grandpa_protocol_name =
+		sc_consensus_grandpa::protocol_standard_name(&genesis_hash, &config.chain_spec);
 	let (grandpa_protocol_config, grandpa_notification_service) =
 		sc_consensus_grandpa::grandpa_peers_set_config(grandpa_protocol_name.clone());
 	net_config.add_notification_protocol(grandpa_protocol_config);
+	let vector_gossip_notification_service = if vector_gossip_enabled {
+		let (vector_gossip_config, notification_service) = vector_gossip::peers_set_config(
+			vector_gossip::protocol_name(&genesis_hash),
+			genesis_hash,
+		);
+		net_config.add_notification_protocol(vector_gossip_config);
+		Some(notification_service)
+	} else {
+		None
+	};
+	let da_cells_request_receiver = if da_cells_protocol_enabled {
+		let (da_cells_config, request_receiver) =
+			da_cells::request_response_config(da_cells::protocol_name(&genesis_hash));
+		net_config.add_request_response_protocol(da_cells_config);
+		Some(request_receiver)
+	} else {
+		None
+	};
 	let warp_sync = Arc::new(sc_consensus_grandpa::warp_proof::NetworkProvider::new(
 		backend.clone(),
 		import_setup.1.shared_authority_set().clone(),
@@ -437,6 +493,76 @@ pub fn new_full_base(
 			warp_sync_params: Some(WarpSyncParams::WithProvider(warp_sync)),
 			block_relay: None,
 		})?;
+	import_setup.0.peer_bans().set_network(network.clone());
+
+	let bridge_index_storage = if bridge_index_enabled {
+		let storage = backend
+			.offchain_storage()
+			.ok_or("The bridge index needs the offchain storage of the backend")?;
+		Some(storage)
+	} else {
+		None
+	};
+
+	// Proof artifacts are indexed by the runtime, they are served whenever the storage exists.
+	let proof_artifact_storage = backend.offchain_storage();
+
+	let step_inherents = StepInherentPool::default();
+	let vector_gossip = vector_gossip_notification_service.map(|notification_service| {
+		let (worker, verifier, handle) = VectorGossipWorker::new(
+			client.clone(),
+			network.clone(),
+			notification_service,
+			genesis_hash,
+			step_inherents.clone(),
+		);
+		task_manager
+			.spawn_handle()
+			.spawn("vector-gossip", Some("networking"), worker.run());
+		task_manager.spawn_handle().spawn_blocking(
+			"vector-gossip-verifier",
+			Some("networking"),
+			verifier.run(),
+		);
+		handle
+	});
+
+	// The detailed health check reports the sync status, so it is added once the network is built.
+	let rpc_builder = Arc::new({
+		let client = client.clone();
+		let pool = transaction_pool.clone();
+		let sync_service = sync_service.clone();
+		let bridge_index_storage = bridge_index_storage.clone();
+		move |deny_unsafe, subscription_executor| {
+			use node_rpc::{
+				BridgeIndexApiServer, HealthApiServer, ProofArtifactApiServer,
+				VectorGossipApiServer,
+			};
+
+			let mut io = rpc_builder(deny_unsafe, subscription_executor)?;
+			let health = node_rpc::Health::<_, _, FullBackend>::new(
+				client.clone(),
+				pool.clone(),
+				sync_service.clone(),
+			);
+			io.merge(health.into_rpc())
+				.map_err(|e| ServiceError::Application(e.into()))?;
+			if let Some(storage) = bridge_index_storage.clone() {
+				let query = node_rpc::BridgeIndexQuery::new(client.clone(), storage);
+				io.merge(query.into_rpc())
+					.map_err(|e| ServiceError::Application(e.into()))?;
+			}
+			if let Some(storage) = proof_artifact_storage.clone() {
+				io.merge(node_rpc::ProofArtifacts::new(storage).into_rpc())
+					.map_err(|e| ServiceError::Application(e.into()))?;
+			}
+			if let Some(handle) = vector_gossip.clone() {
+				io.merge(node_rpc::VectorGossip::new(handle, deny_unsafe).into_rpc())
+					.map_err(|e| ServiceError::Application(e.into()))?;
+			}
+			Ok(io)
+		}
+	});
 
 	let role = config.role.clone();
 	let force_authoring = config.force_authoring;
@@ -446,8 +572,16 @@ pub fn new_full_base(
 	let enable_grandpa = !config.disable_grandpa;
 	let prometheus_registry = config.prometheus_registry().cloned();
 	let enable_offchain_worker = config.offchain_worker.enabled;
+	let offchain_keystore = remote_keystore
+		.keystore(keystore_container.keystore(), config.tokio_handle.clone())
+		.map_err(ServiceError::Other)?;
 	if let Some(reg) = prometheus_registry.as_ref() {
 		extend_metrics(reg)?;
+		task_manager.spawn_handle().spawn(
+			"bridge-monitor",
+			None,
+			bridge_monitor::run(client.clone()),
+		);
 	}
 
 	let rpc_handlers = sc_service::spawn_tasks(sc_service::SpawnTasksParams {
@@ -456,7 +590,17 @@ pub fn new_full_base(
 		client: client.clone(),
 		keystore: keystore_container.keystore(),
 		network: network.clone(),
-		rpc_builder: Box::new(rpc_builder),
+		rpc_builder: Box::new({
+			let rpc_builder = rpc_builder.clone();
+			let has_operator_server = operator_rpc.is_some();
+			move |deny_unsafe, subscription_executor| {
+				let mut io = rpc_builder(deny_unsafe, subscription_executor)?;
+				if has_operator_server {
+					node_rpc::remove_operator_methods(&mut io);
+				}
+				Ok(io)
+			}
+		}),
 		transaction_pool: transaction_pool.clone(),
 		task_manager: &mut task_manager,
 		system_rpc_tx,
@@ -486,12 +630,17 @@ pub fn new_full_base(
 		let proposer = sc_basic_authorship::ProposerFactory::new(
 			task_manager.spawn_handle(),
 			client.clone(),
-			transaction_pool.clone(),
+			Arc::new(DaPrioritizedPool::new(
+				transaction_pool.clone(),
+				da_tx_priority,
+			)),
 			prometheus_registry.as_ref(),
 			telemetry.as_ref().map(|x| x.handle()),
 		);
+		let proposer = BridgeGuard::new(proposer, client.clone());
 
 		let client_clone = client.clone();
+		let step_inherents = vector_step_inherents_enabled.then(|| step_inherents.clone());
 		let slot_duration = babe_link.config().slot_duration();
 		let babe_config = sc_consensus_babe::BabeParams {
 			keystore: keystore_container.keystore(),
@@ -503,6 +652,7 @@ pub fn new_full_base(
 			justification_sync_link: sync_service.clone(),
 			create_inherent_data_providers: move |parent, ()| {
 				let client_clone = client_clone.clone();
+				let step_inherents = step_inherents.clone();
 				async move {
 					let timestamp = sp_timestamp::InherentDataProvider::from_system_time();
 
@@ -518,7 +668,10 @@ pub fn new_full_base(
 							&parent,
 						)?;
 
-					Ok((slot, timestamp, storage_proof))
+					let step = step_inherents
+						.map(|pool| pool.inherent_data_provider(&*client_clone, parent));
+
+					Ok((slot, timestamp, storage_proof, step))
 				}
 			},
 			force_authoring,
@@ -625,7 +778,7 @@ pub fn new_full_base(
 			"offchain-work",
 			sc_offchain::OffchainWorkers::new(sc_offchain::OffchainWorkerOptions {
 				runtime_api_provider: client.clone(),
-				keystore: Some(keystore_container.keystore()),
+				keystore: Some(offchain_keystore),
 				offchain_db: backend.offchain_storage(),
 				transaction_pool: Some(OffchainTransactionPoolFactory::new(
 					transaction_pool.clone(),
@@ -640,6 +793,45 @@ pub fn new_full_base(
 		);
 	}
 
+	if let Some(operator_rpc) = operator_rpc {
+		let subscription_executor =
+			sc_rpc::SubscriptionTaskExecutor::new(task_manager.spawn_handle());
+		let mut methods = rpc_builder(node_rpc::DenyUnsafe::No, subscription_executor)?;
+		node_rpc::retain_operator_methods(&mut methods);
+		task_manager
+			.spawn_handle()
+			.spawn("operator-rpc", None, async move {
+				if let Err(e) = node_rpc::run_operator_server(operator_rpc, methods).await {
+					log::error!("Operator RPC server failed: {e}");
+				}
+			});
+	}
+
+	if let Some(storage) = bridge_index_storage {
+		let indexer = BridgeIndexer::<_, FullBackend, _>::new(client.clone(), storage);
+		task_manager
+			.spawn_handle()
+			.spawn("bridge-index", None, indexer.run());
+	}
+
+	if let Some(config) = bridge_webhook {
+		let webhook = BridgeWebhook::<_, FullBackend>::new(client.clone(), config);
+		task_manager
+			.spawn_handle()
+			.spawn("bridge-webhook", None, webhook.run());
+	}
+
+	if let Some(request_receiver) = da_cells_request_receiver {
+		let handler =
+			DaCellsRequestHandler::new(client.clone(), request_receiver, kate_max_cells_size);
+		// Proofs are computed by the handler itself, so it runs on a blocking thread.
+		task_manager.spawn_handle().spawn_blocking(
+			"da-cells-request-handler",
+			Some("networking"),
+			handler.run(),
+		);
+	}
+
 	network_starter.start_network();
 	Ok(NewFullBase {
 		task_manager,
@@ -659,9 +851,23 @@ pub fn new_full(config: Configuration, cli: Cli) -> Result<TaskManager, ServiceE
 		cli.no_hardware_benchmarks,
 		|_, _| (),
 		cli.unsafe_da_sync,
+		cli.da_slow_verification_threshold,
+		DaImportLimits::from(&cli),
 		cli.kate_max_cells_size,
 		cli.kate_rpc_enabled,
 		cli.kate_rpc_metrics_enabled,
+		RpcLimitsConfig::from(&cli),
+		node_rpc::OperatorRpcConfig::from_cli(&cli),
+		cli.vector_gossip_enabled,
+		cli.vector_step_inherents_enabled,
+		cli.bridge_index_enabled,
+		BridgeWebhookConfig::from_cli(&cli),
+		cli.da_cells_protocol_enabled,
+		DaTxPriorityConfig {
+			enabled: cli.da_tx_priority_enabled,
+			max_app_bytes_per_block: cli.da_app_block_quota,
+		},
+		RemoteKeystoreConfig::from(&cli),
 	)
 	.map(|NewFullBase { task_manager, .. }| task_manager)?;
 
@@ -0,0 +1,691 @@
+//! # Vector Gossip Protocol
+//!
+//! Notifications protocol used by nodes to share newly verified Vector step proofs and pending
+//! bridge messages, so a relayer only needs to reach a single node, e.g. with
+//! `succinct_gossipStepProof` and `succinct_gossipPendingMessage`, for its data to reach the
+//! block authors and the executors of the messages.
+//!
+//! Peers exchange a [`Handshake`] on substream opening and are rejected if they run a different
+//! protocol version or chain. Every peer is rate limited, and peers sending undecodable payloads
+//! or proofs failing the verification are banned. Messages referring to a slot or a function id
+//! unknown at the best block only cost their sender some reputation, and are verified again
+//! when they are received once more.
+//!
+//! Step proofs are verified with a dry run of `fulfill_call`, and pending messages with a dry run
+//! of `execute`, at the best block before they are propagated. The runtime calls are made by a
+//! [`VectorGossipVerifier`] on a blocking task, away from the network worker, and only a few
+//! messages of each peer are verified per rate limit window.
+//!
+//! The latest verified step proof advancing the head is kept in a [`StepInherentPool`], from
+//! which block authors include it in their blocks as an inherent. The latest verified pending
+//! messages are kept in a [`PendingMessagePool`], served to the executors over RPC.
+use avail_core::{data_proof::AddressedMessage, OpaqueExtrinsic};
+use codec::{Decode, Encode};
+use da_runtime::{
+	apis::VectorApi, NodeBlock as Block, Runtime, RuntimeCall, UncheckedExtrinsic, Vector,
+};
+use frame_support::traits::PalletInfoAccess;
+use futures::{channel::mpsc, prelude::*};
+use pallet_vector::{
+	inherent::{StepInherentData, INHERENT_IDENTIFIER},
+	state::{StageResult, StepAcceptance},
+};
+use sc_network::{
+	config::{NonDefaultSetConfig, NonReservedPeerMode, NotificationHandshake, SetConfig},
+	service::traits::{NotificationEvent, NotificationService, ValidationResult},
+	NetworkPeers, PeerId, ProtocolName, ReputationChange,
+};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::{blake2_256, H256};
+use sp_inherents::{InherentData, InherentIdentifier};
+use sp_runtime::{DispatchError, ModuleError};
+use std::{
+	collections::{HashMap, HashSet, VecDeque},
+	sync::{Arc, Mutex},
+	time::{Duration, Instant},
+};
+
+const LOG_TARGET: &str = "avail::node::vector_gossip";
+
+/// Version of the gossip protocol, exchanged in the handshake.
+pub const PROTOCOL_VERSION: u32 = 3;
+/// Maximum size of a single notification.
+const MAX_NOTIFICATION_SIZE: u64 = 4 * 1024 * 1024;
+/// Maximum number of notifications accepted from a single peer per rate limit window.
+const MAX_MESSAGES_PER_WINDOW: u32 = 32;
+/// Maximum number of new messages of a single peer verified per rate limit window, each of them
+/// costing a runtime call.
+const MAX_VERIFICATIONS_PER_WINDOW: u32 = 4;
+/// Maximum number of messages waiting for their verification. Messages received when the queue
+/// is full are dropped unverified.
+const MAX_QUEUED_VERIFICATIONS: usize = 64;
+/// Maximum number of verified pending messages kept for the executors.
+const MAX_PENDING_MESSAGES: usize = 1024;
+/// Length of the rate limit window.
+const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(10);
+/// Number of recently seen message hashes kept to avoid re-gossiping.
+const MAX_KNOWN_MESSAGES: usize = 4096;
+
+/// Reputation change for peers that sent data which cannot be decoded.
+const INVALID_MESSAGE: ReputationChange = ReputationChange::new_fatal("Invalid vector gossip");
+/// Reputation change for peers that sent a proof failing the verification.
+const INVALID_PROOF: ReputationChange = ReputationChange::new_fatal("Vector gossip invalid proof");
+/// Reputation change for peers that sent a message this node cannot verify yet, as it refers to a
+/// slot or a function id it does not know, e.g. because its best block is behind the sender.
+const UNKNOWN_STATE: ReputationChange =
+	ReputationChange::new(-(1 << 8), "Vector gossip unknown state");
+/// Reputation change for peers that exceed the rate limit.
+const RATE_LIMITED: ReputationChange = ReputationChange::new(-(1 << 12), "Vector gossip flood");
+/// Reputation change for peers that sent a new valid message.
+const GOOD_MESSAGE: ReputationChange = ReputationChange::new(1 << 4, "Vector gossip message");
+
+/// Returns the protocol name for the chain with the given genesis hash.
+pub fn protocol_name(genesis_hash: &H256) -> ProtocolName {
+	format!("/{genesis_hash:x}/vector-gossip/{PROTOCOL_VERSION}").into()
+}
+
+/// Handshake exchanged when a substream is opened.
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq)]
+pub struct Handshake {
+	pub version: u32,
+	pub genesis_hash: H256,
+}
+
+/// Messages gossiped over the protocol.
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq)]
+pub enum GossipMessage {
+	/// CBOR encoded step inputs, as accepted by `fulfill_call`.
+	StepProof { function_id: H256, inputs: Vec<u8> },
+	/// Message pending execution, with the proofs accepted by `execute`.
+	PendingMessage(PendingMessage),
+}
+
+/// Message sent on the source chain, with the proofs of its execution on Avail.
+#[derive(Clone, Debug, Encode, Decode, PartialEq, Eq)]
+pub struct PendingMessage {
+	pub slot: u64,
+	pub addr_message: AddressedMessage,
+	pub account_proof: Vec<Vec<u8>>,
+	pub storage_proof: Vec<Vec<u8>>,
+}
+
+impl GossipMessage {
+	fn hash(&self) -> H256 {
+		H256(self.using_encoded(blake2_256))
+	}
+}
+
+/// Outcome of validating a gossiped message.
+#[derive(Debug, PartialEq, Eq)]
+enum Validation {
+	/// Verified message, it should be propagated. A step proof advancing the head carries the
+	/// slot it finalizes, to be included by the block authors.
+	Valid { new_head: Option<u64> },
+	/// Already applied on chain or not acceptable at the best block, it is not propagated.
+	Stale,
+	/// The proof fails the verification, the sender should be banned.
+	Unverified,
+	/// Refers to a slot or a function id unknown at the best block. It is not propagated, and
+	/// verified again if it is received once more.
+	Unknown,
+	/// Malformed message, the sender should be banned.
+	Invalid,
+}
+
+/// Creates the notifications protocol config and its service.
+pub fn peers_set_config(
+	protocol_name: ProtocolName,
+	genesis_hash: H256,
+) -> (NonDefaultSetConfig, Box<dyn NotificationService>) {
+	let handshake = Handshake {
+		version: PROTOCOL_VERSION,
+		genesis_hash,
+	};
+
+	NonDefaultSetConfig::new(
+		protocol_name,
+		Vec::new(),
+		MAX_NOTIFICATION_SIZE,
+		Some(NotificationHandshake::new(handshake)),
+		SetConfig {
+			in_peers: 25,
+			out_peers: 25,
+			reserved_nodes: Vec::new(),
+			non_reserved_mode: NonReservedPeerMode::Accept,
+		},
+	)
+}
+
+/// Handle used to gossip locally received messages, e.g. from a relayer.
+#[derive(Clone)]
+pub struct VectorGossipHandle {
+	tx: mpsc::UnboundedSender<GossipMessage>,
+	pending_messages: PendingMessagePool,
+}
+
+impl VectorGossipHandle {
+	/// Queues a message to be gossiped to all connected peers once it is verified.
+	pub fn gossip(&self, message: GossipMessage) {
+		if self.tx.unbounded_send(message).is_err() {
+			log::warn!(target: LOG_TARGET, "Vector gossip worker is not running");
+		}
+	}
+
+	/// Returns the latest verified pending messages, oldest first.
+	pub fn pending_messages(&self) -> Vec<PendingMessage> {
+		self.pending_messages.messages()
+	}
+}
+
+/// Latest verified pending messages received by the worker, shared with the RPC.
+///
+/// The messages are not pruned once executed, the executors find it out with a dry run.
+#[derive(Clone, Default)]
+pub struct PendingMessagePool(Arc<Mutex<VecDeque<PendingMessage>>>);
+
+impl PendingMessagePool {
+	fn note(&self, message: PendingMessage) {
+		let mut messages = self.0.lock().expect("Lock is never poisoned .qed");
+		if messages.len() >= MAX_PENDING_MESSAGES {
+			messages.pop_front();
+		}
+		messages.push_back(message);
+	}
+
+	fn messages(&self) -> Vec<PendingMessage> {
+		let messages = self.0.lock().expect("Lock is never poisoned .qed");
+		messages.iter().cloned().collect()
+	}
+}
+
+/// Latest verified step proof received by the worker, shared with the block authoring.
+#[derive(Clone, Default)]
+pub struct StepInherentPool(Arc<Mutex<Option<(u64, StepInherentData)>>>);
+
+impl StepInherentPool {
+	/// Keeps the step proof of the finalized `slot` if it is the latest one.
+	fn note(&self, slot: u64, step: StepInherentData) {
+		let mut latest = self.0.lock().expect("Lock is never poisoned .qed");
+		if latest
+			.as_ref()
+			.map_or(true, |(latest_slot, _)| *latest_slot < slot)
+		{
+			*latest = Some((slot, step));
+		}
+	}
+
+	/// Returns the provider of the latest step proof, if it is ahead of the bridge head at
+	/// `parent`.
+	pub fn inherent_data_provider<C>(&self, client: &C, parent: H256) -> StepInherentDataProvider
+	where
+		C: ProvideRuntimeApi<Block>,
+		C::Api: VectorApi<Block>,
+	{
+		let head = client.runtime_api().head(parent).unwrap_or_default();
+		let latest = self.0.lock().expect("Lock is never poisoned .qed");
+		let step = latest
+			.as_ref()
+			.filter(|(slot, _)| *slot > head)
+			.map(|(_, step)| step.clone());
+		StepInherentDataProvider(step)
+	}
+}
+
+/// Provides the step proof the block author includes in its block, if any.
+pub struct StepInherentDataProvider(Option<StepInherentData>);
+
+#[async_trait::async_trait]
+impl sp_inherents::InherentDataProvider for StepInherentDataProvider {
+	async fn provide_inherent_data(
+		&self,
+		inherent_data: &mut InherentData,
+	) -> Result<(), sp_inherents::Error> {
+		match &self.0 {
+			Some(step) => inherent_data.put_data(INHERENT_IDENTIFIER, step),
+			None => Ok(()),
+		}
+	}
+
+	async fn try_handle_error(
+		&self,
+		_identifier: &InherentIdentifier,
+		_error: &[u8],
+	) -> Option<Result<(), sp_inherents::Error>> {
+		None
+	}
+}
+
+struct PeerState {
+	window_start: Instant,
+	received: u32,
+	verified: u32,
+}
+
+impl PeerState {
+	fn new() -> Self {
+		Self {
+			window_start: Instant::now(),
+			received: 0,
+			verified: 0,
+		}
+	}
+
+	/// Records a received message, returns `false` if the peer is over its limit.
+	fn note_received(&mut self) -> bool {
+		let now = Instant::now();
+		if now.duration_since(self.window_start) >= RATE_LIMIT_WINDOW {
+			self.window_start = now;
+			self.received = 0;
+			self.verified = 0;
+		}
+		self.received = self.received.saturating_add(1);
+		self.received <= MAX_MESSAGES_PER_WINDOW
+	}
+
+	/// Records a message queued for verification, returns `false` if the peer is over its limit.
+	fn note_verification(&mut self) -> bool {
+		self.verified = self.verified.saturating_add(1);
+		self.verified <= MAX_VERIFICATIONS_PER_WINDOW
+	}
+}
+
+/// Message queued for verification, with the peer it was received from, `None` if local.
+type QueuedMessage = (Option<PeerId>, GossipMessage);
+/// Verified message, with the peer it was received from and the outcome of its verification.
+type VerifiedMessage = (Option<PeerId>, GossipMessage, Validation);
+
+/// Worker driving the gossip protocol.
+pub struct VectorGossipWorker<N> {
+	network: Arc<N>,
+	notification_service: Box<dyn NotificationService>,
+	genesis_hash: H256,
+	peers: HashMap<PeerId, PeerState>,
+	known: HashSet<H256>,
+	known_order: VecDeque<H256>,
+	local_rx: mpsc::UnboundedReceiver<GossipMessage>,
+	verification_tx: mpsc::Sender<QueuedMessage>,
+	verified_rx: mpsc::UnboundedReceiver<VerifiedMessage>,
+	step_inherents: StepInherentPool,
+	pending_messages: PendingMessagePool,
+}
+
+/// Verifies the messages queued by the [`VectorGossipWorker`] with runtime calls at the best
+/// block, to be run on a blocking task.
+pub struct VectorGossipVerifier<C> {
+	client: Arc<C>,
+	verification_rx: mpsc::Receiver<QueuedMessage>,
+	verified_tx: mpsc::UnboundedSender<VerifiedMessage>,
+}
+
+impl<N> VectorGossipWorker<N>
+where
+	N: NetworkPeers + Send + Sync + 'static,
+{
+	pub fn new<C>(
+		client: Arc<C>,
+		network: Arc<N>,
+		notification_service: Box<dyn NotificationService>,
+		genesis_hash: H256,
+		step_inherents: StepInherentPool,
+	) -> (Self, VectorGossipVerifier<C>, VectorGossipHandle) {
+		let (tx, local_rx) = mpsc::unbounded();
+		let (verification_tx, verification_rx) = mpsc::channel(MAX_QUEUED_VERIFICATIONS);
+		let (verified_tx, verified_rx) = mpsc::unbounded();
+		let pending_messages = PendingMessagePool::default();
+		let worker = Self {
+			network,
+			notification_service,
+			genesis_hash,
+			peers: HashMap::new(),
+			known: HashSet::new(),
+			known_order: VecDeque::new(),
+			local_rx,
+			verification_tx,
+			verified_rx,
+			step_inherents,
+			pending_messages: pending_messages.clone(),
+		};
+		let verifier = VectorGossipVerifier {
+			client,
+			verification_rx,
+			verified_tx,
+		};
+
+		(
+			worker,
+			verifier,
+			VectorGossipHandle {
+				tx,
+				pending_messages,
+			},
+		)
+	}
+
+	pub async fn run(mut self) {
+		loop {
+			futures::select! {
+				event = self.notification_service.next_event().fuse() => match event {
+					Some(event) => self.on_event(event),
+					None => return,
+				},
+				message = self.local_rx.next() => match message {
+					Some(message) => self.on_local_message(message),
+					None => return,
+				},
+				verified = self.verified_rx.next() => match verified {
+					Some((source, message, validation)) => {
+						self.on_verified(source, message, validation)
+					},
+					None => return,
+				},
+			}
+		}
+	}
+
+	fn on_event(&mut self, event: NotificationEvent) {
+		match event {
+			NotificationEvent::ValidateInboundSubstream {
+				peer,
+				handshake,
+				result_tx,
+			} => {
+				let result = if self.is_valid_handshake(&handshake) {
+					ValidationResult::Accept
+				} else {
+					log::debug!(target: LOG_TARGET, "Rejecting {peer:?}: invalid handshake");
+					ValidationResult::Reject
+				};
+				let _ = result_tx.send(result);
+			},
+			NotificationEvent::NotificationStreamOpened {
+				peer, handshake, ..
+			} => {
+				if !self.is_valid_handshake(&handshake) {
+					self.network.report_peer(peer, INVALID_MESSAGE);
+					return;
+				}
+				self.peers.insert(peer, PeerState::new());
+			},
+			NotificationEvent::NotificationStreamClosed { peer } => {
+				self.peers.remove(&peer);
+			},
+			NotificationEvent::NotificationReceived { peer, notification } => {
+				self.on_notification(peer, notification);
+			},
+		}
+	}
+
+	fn is_valid_handshake(&self, mut handshake: &[u8]) -> bool {
+		Handshake::decode(&mut handshake).is_ok_and(|handshake| {
+			handshake.version == PROTOCOL_VERSION && handshake.genesis_hash == self.genesis_hash
+		})
+	}
+
+	fn on_notification(&mut self, peer: PeerId, notification: Vec<u8>) {
+		let Some(state) = self.peers.get_mut(&peer) else {
+			return;
+		};
+		if !state.note_received() {
+			log::debug!(target: LOG_TARGET, "Peer {peer:?} exceeded the rate limit");
+			self.network.report_peer(peer, RATE_LIMITED);
+			return;
+		}
+
+		let Ok(message) = GossipMessage::decode(&mut notification.as_slice()) else {
+			log::debug!(target: LOG_TARGET, "Banning {peer:?}: undecodable message");
+			self.network.report_peer(peer, INVALID_MESSAGE);
+			return;
+		};
+
+		if self.known.contains(&message.hash()) {
+			return;
+		}
+		if !state.note_verification() {
+			log::debug!(target: LOG_TARGET, "Peer {peer:?} exceeded the verification limit");
+			self.network.report_peer(peer, RATE_LIMITED);
+			return;
+		}
+
+		self.queue_verification(Some(peer), message);
+	}
+
+	fn on_local_message(&mut self, message: GossipMessage) {
+		if self.known.contains(&message.hash()) {
+			return;
+		}
+		self.queue_verification(None, message);
+	}
+
+	/// Hands `message` to the verifier. It is known from then on, so that it is verified once.
+	fn queue_verification(&mut self, source: Option<PeerId>, message: GossipMessage) {
+		let hash = message.hash();
+		match self.verification_tx.try_send((source, message)) {
+			Ok(()) => self.note_known(hash),
+			Err(e) if e.is_full() => {
+				log::debug!(target: LOG_TARGET, "Dropping message {hash:?}: verification queue is full");
+			},
+			Err(_) => log::warn!(target: LOG_TARGET, "Vector gossip verifier is not running"),
+		}
+	}
+
+	fn on_verified(
+		&mut self,
+		source: Option<PeerId>,
+		message: GossipMessage,
+		validation: Validation,
+	) {
+		let hash = message.hash();
+		match (validation, source) {
+			(Validation::Valid { new_head }, _) => {
+				if let Some(peer) = source {
+					self.network.report_peer(peer, GOOD_MESSAGE);
+				}
+				self.note_step_inherent(&message, new_head);
+				if let GossipMessage::PendingMessage(pending) = &message {
+					self.pending_messages.note(pending.clone());
+				}
+				self.propagate(&message, source);
+			},
+			(Validation::Stale, _) => {},
+			(Validation::Unknown, source) => {
+				log::debug!(target: LOG_TARGET, "Cannot verify {hash:?} at the best block yet");
+				if let Some(peer) = source {
+					self.network.report_peer(peer, UNKNOWN_STATE);
+				}
+				self.forget_known(hash);
+			},
+			(Validation::Unverified, Some(peer)) => {
+				log::debug!(target: LOG_TARGET, "Banning {peer:?}: unverified proof {hash:?}");
+				self.network.report_peer(peer, INVALID_PROOF);
+			},
+			(Validation::Invalid, Some(peer)) => {
+				log::debug!(target: LOG_TARGET, "Banning {peer:?}: invalid message {hash:?}");
+				self.network.report_peer(peer, INVALID_MESSAGE);
+			},
+			(Validation::Unverified | Validation::Invalid, None) => {
+				log::debug!(target: LOG_TARGET, "Dropping local message {hash:?}: not verified");
+			},
+		}
+	}
+
+	/// Keeps a verified step proof advancing the head to `new_head` for the block authors to
+	/// include as an inherent.
+	fn note_step_inherent(&self, message: &GossipMessage, new_head: Option<u64>) {
+		let (
+			GossipMessage::StepProof {
+				function_id,
+				inputs,
+			},
+			Some(slot),
+		) = (message, new_head)
+		else {
+			return;
+		};
+		self.step_inherents.note(
+			slot,
+			StepInherentData {
+				function_id: *function_id,
+				inputs: inputs.clone(),
+			},
+		);
+	}
+
+	fn note_known(&mut self, hash: H256) {
+		if self.known_order.len() >= MAX_KNOWN_MESSAGES {
+			if let Some(oldest) = self.known_order.pop_front() {
+				self.known.remove(&oldest);
+			}
+		}
+		self.known.insert(hash);
+		self.known_order.push_back(hash);
+	}
+
+	fn forget_known(&mut self, hash: H256) {
+		if self.known.remove(&hash) {
+			self.known_order.retain(|known| *known != hash);
+		}
+	}
+
+	fn propagate(&self, message: &GossipMessage, source: Option<PeerId>) {
+		let encoded = message.encode();
+		for peer in self.peers.keys().filter(|peer| Some(**peer) != source) {
+			self.notification_service
+				.send_sync_notification(peer, encoded.clone());
+		}
+	}
+}
+
+impl<C> VectorGossipVerifier<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: VectorApi<Block>,
+{
+	pub async fn run(mut self) {
+		while let Some((source, message)) = self.verification_rx.next().await {
+			let validation = self.validate(&message);
+			if self
+				.verified_tx
+				.unbounded_send((source, message, validation))
+				.is_err()
+			{
+				return;
+			}
+		}
+	}
+
+	fn validate(&self, message: &GossipMessage) -> Validation {
+		match message {
+			GossipMessage::StepProof {
+				function_id,
+				inputs,
+			} => self.validate_step_proof(function_id, inputs),
+			GossipMessage::PendingMessage(pending) => self.validate_pending_message(pending),
+		}
+	}
+
+	/// Verifies a step proof with a dry run of `fulfill_call` at the best block.
+	fn validate_step_proof(&self, function_id: &H256, inputs: &[u8]) -> Validation {
+		let call = RuntimeCall::Vector(pallet_vector::Call::fulfill_call {
+			function_id: *function_id,
+			inputs: inputs.to_vec(),
+		});
+		let encoded = UncheckedExtrinsic::new_unsigned(call).encode();
+		let Ok(uxt) = OpaqueExtrinsic::decode(&mut encoded.as_slice()) else {
+			return Validation::Invalid;
+		};
+
+		let best_hash = self.client.info().best_hash;
+		let trace = match self.client.runtime_api().trace_verification(best_hash, uxt) {
+			Ok(Some(trace)) => trace,
+			Ok(None) => return Validation::Invalid,
+			Err(e) => {
+				log::warn!(target: LOG_TARGET, "Step proof cannot be verified: {e:?}");
+				return Validation::Stale;
+			},
+		};
+
+		match trace.decode {
+			StageResult::Passed => {},
+			// compact inputs following another head than the local one
+			StageResult::Unavailable => return Validation::Unknown,
+			StageResult::Failed | StageResult::Skipped => return Validation::Invalid,
+		}
+		if trace.already_verified || trace.stale {
+			return Validation::Stale;
+		}
+		if trace.function.is_none() {
+			return Validation::Unknown;
+		}
+		let Some(output) = trace.output else {
+			return Validation::Unverified;
+		};
+		match trace.acceptance {
+			Some(Ok(StepAcceptance::NewHead)) => Validation::Valid {
+				new_head: Some(output.finalized_slot),
+			},
+			Some(Ok(_)) => Validation::Valid { new_head: None },
+			Some(Err(_)) | None => Validation::Stale,
+		}
+	}
+
+	/// Verifies a pending message with a dry run of `execute` at the best block.
+	fn validate_pending_message(&self, pending: &PendingMessage) -> Validation {
+		if pending.account_proof.is_empty() || pending.storage_proof.is_empty() {
+			return Validation::Invalid;
+		}
+
+		let best_hash = self.client.info().best_hash;
+		let api = self.client.runtime_api();
+		match api.api_version::<dyn VectorApi<Block>>(best_hash) {
+			Ok(Some(version)) if version >= 13 => {},
+			// the runtime does not report the error of the dry run
+			Ok(_) => return Validation::Stale,
+			Err(e) => {
+				log::warn!(target: LOG_TARGET, "Pending message cannot be verified: {e:?}");
+				return Validation::Stale;
+			},
+		}
+		let simulation = match api.dry_run_execute(
+			best_hash,
+			pending.slot,
+			pending.addr_message.clone(),
+			pending.account_proof.clone(),
+			pending.storage_proof.clone(),
+		) {
+			Ok(simulation) => simulation,
+			Err(e) => {
+				log::warn!(target: LOG_TARGET, "Pending message cannot be verified: {e:?}");
+				return Validation::Stale;
+			},
+		};
+
+		let Some(error) = simulation.error else {
+			return Validation::Valid { new_head: None };
+		};
+		match vector_error(&error) {
+			// the proofs do not prove the message against the execution state root of the slot
+			Some(
+				pallet_vector::Error::CannotGetStorageRoot
+				| pallet_vector::Error::CannotGetStorageValue
+				| pallet_vector::Error::InvalidMessageHash
+				| pallet_vector::Error::ProofTooDeep
+				| pallet_vector::Error::ProofTooLarge
+				| pallet_vector::Error::SourceCodeHashMismatch,
+			) => Validation::Unverified,
+			Some(pallet_vector::Error::ExecutionStateRootNotSet) => Validation::Unknown,
+			_ => Validation::Stale,
+		}
+	}
+}
+
+/// Returns the error of the Vector pallet `error` is, if it is one.
+fn vector_error(error: &DispatchError) -> Option<pallet_vector::Error<Runtime>> {
+	let DispatchError::Module(ModuleError { index, error, .. }) = error else {
+		return None;
+	};
+	if usize::from(*index) != <Vector as PalletInfoAccess>::index() {
+		return None;
+	}
+	pallet_vector::Error::<Runtime>::decode(&mut error.as_slice()).ok()
+}
@@ -0,0 +1,219 @@
+//! # Verify DA Range
+//!
+//! `verify-da-range` subcommand, which replays the DA commitments of a range of blocks of the
+//! local database. The header extension of each block is built again from its stored extrinsics,
+//! with the runtime of its parent, and compared with the imported one. It is meant to investigate
+//! suspected commitment bugs, so every mismatch is reported instead of stopping at the first one.
+use crate::{da_block_import::extension_block_len, service::FullClient};
+use avail_core::header::HeaderExtension;
+use da_runtime::apis::ExtensionBuilder;
+use sc_cli::{CliConfiguration, DatabaseParams, PruningParams, SharedParams};
+use sc_client_api::BlockBackend;
+use serde::Serialize;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::H256;
+use std::sync::Arc;
+
+/// Length in bytes of a row commitment.
+const G1_LENGTH: usize = 48;
+
+/// Re-verifies the DA commitments of a range of blocks of the local database.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct VerifyDaRangeCmd {
+	/// First block of the range. The genesis block has no commitments to replay, so it is skipped.
+	#[arg(long, default_value_t = 1)]
+	pub from: u32,
+
+	/// Last block of the range, the best block by default.
+	#[arg(long)]
+	pub to: Option<u32>,
+
+	/// Print the report as JSON.
+	#[arg(long)]
+	pub json: bool,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub pruning_params: PruningParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RangeReport {
+	from: u32,
+	to: u32,
+	verified: u32,
+	/// Blocks whose body was pruned, so their commitments cannot be replayed.
+	pruned: Vec<u32>,
+	mismatches: Vec<Mismatch>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Mismatch {
+	number: u32,
+	hash: H256,
+	/// Fields of the header extension which differ from the replayed one.
+	fields: Vec<&'static str>,
+	/// First extended row whose commitment differs, if the dimensions match.
+	first_row: Option<usize>,
+	imported: HeaderExtension,
+	replayed: HeaderExtension,
+}
+
+impl VerifyDaRangeCmd {
+	pub async fn run(&self, client: Arc<FullClient>) -> sc_cli::Result<()> {
+		let from = self.from.max(1);
+		let to = self.to.unwrap_or_else(|| client.info().best_number);
+		if from > to {
+			return Err(format!("Empty block range {from}..={to}").into());
+		}
+
+		let mut report = RangeReport {
+			from,
+			to,
+			verified: 0,
+			pruned: Vec::new(),
+			mismatches: Vec::new(),
+		};
+		for number in from..=to {
+			let hash = client
+				.hash(number)?
+				.ok_or_else(|| format!("Block #{number} is not in the database"))?;
+			let header = client
+				.header(hash)?
+				.ok_or_else(|| format!("Header of block #{number} is not in the database"))?;
+			let Some(extrinsics) = client.block_body(hash)? else {
+				report.pruned.push(number);
+				continue;
+			};
+
+			let api = client.runtime_api();
+			let data_root = api
+				.build_data_root(header.parent_hash, number, extrinsics.clone())
+				.map_err(|e| format!("Data root of block #{number} cannot be built: {e:?}"))?;
+			let replayed = api
+				.build_extension(
+					header.parent_hash,
+					extrinsics,
+					data_root,
+					extension_block_len(&header.extension),
+					number,
+				)
+				.map_err(|e| format!("Extension of block #{number} cannot be built: {e:?}"))?;
+
+			report.verified += 1;
+			if header.extension != replayed {
+				report
+					.mismatches
+					.push(mismatch(number, hash, header.extension, replayed));
+			}
+		}
+
+		if self.json {
+			let json = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+			println!("{json}");
+		} else {
+			for m in &report.mismatches {
+				let first_row = m
+					.first_row
+					.map(|row| format!(", first differing row {row}"))
+					.unwrap_or_default();
+				println!(
+					"#{} {:?}: {} differ{first_row}",
+					m.number,
+					m.hash,
+					m.fields.join(", ")
+				);
+			}
+			if !report.pruned.is_empty() {
+				println!(
+					"{} blocks skipped, their bodies are pruned",
+					report.pruned.len()
+				);
+			}
+			println!(
+				"{} blocks verified, {} mismatches",
+				report.verified,
+				report.mismatches.len()
+			);
+		}
+
+		if !report.mismatches.is_empty() {
+			return Err(format!(
+				"DA commitments of {} blocks do not match their headers",
+				report.mismatches.len()
+			)
+			.into());
+		}
+		Ok(())
+	}
+}
+
+fn mismatch(
+	number: u32,
+	hash: H256,
+	imported: HeaderExtension,
+	replayed: HeaderExtension,
+) -> Mismatch {
+	let (HeaderExtension::V3(left), HeaderExtension::V3(right)) = (&imported, &replayed);
+	let (left_commitment, right_commitment) = (&left.commitment, &right.commitment);
+
+	let mut fields = Vec::new();
+	let same_dimensions = left_commitment.rows == right_commitment.rows
+		&& left_commitment.cols == right_commitment.cols;
+	if !same_dimensions {
+		fields.push("dimensions");
+	}
+	if left_commitment.data_root != right_commitment.data_root {
+		fields.push("data root");
+	}
+	if left_commitment.commitment != right_commitment.commitment {
+		fields.push("commitments");
+	}
+	if left.app_lookup != right.app_lookup {
+		fields.push("app lookup");
+	}
+
+	let first_row = same_dimensions
+		.then(|| {
+			left_commitment
+				.commitment
+				.chunks(G1_LENGTH)
+				.zip(right_commitment.commitment.chunks(G1_LENGTH))
+				.position(|(left, right)| left != right)
+		})
+		.flatten();
+
+	Mismatch {
+		number,
+		hash,
+		fields,
+		first_row,
+		imported,
+		replayed,
+	}
+}
+
+impl CliConfiguration for VerifyDaRangeCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
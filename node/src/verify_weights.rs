@@ -0,0 +1,283 @@
+//! # Verify weights
+//!
+//! `verify-weights` subcommand, which measures the verification of synthetic source chain
+//! storage proofs on the current hardware and compares it with the `verify_proof` and
+//! `hash_proof_nodes` weights of the runtime. The measurements are kept as a JSON artifact
+//! (proof size vs verification time, trie depth vs weight), so an artifact recorded on the
+//! reference hardware can be checked again later, e.g. in CI, and the stale weight constants are
+//! flagged before they rot.
+//!
+//! The proofs are hashed by the same host functions as in the runtime, but the trie walk runs
+//! natively, so the measured times are a lower bound of the benchmarked ones.
+use amb_primitives::storage::get_storage_value;
+use da_runtime::Runtime;
+use frame_support::weights::constants::WEIGHT_REF_TIME_PER_NANOS;
+use pallet_vector::{Config, WeightInfo};
+use patricia_merkle_trie::StorageProof;
+use serde::{Deserialize, Serialize};
+use sp_core::{Get, H256};
+use std::{
+	path::PathBuf,
+	time::{Duration, Instant},
+};
+
+type Weights = <Runtime as Config>::WeightInfo;
+type Layout = <Runtime as Config>::SourceChainTrieLayout;
+type Hasher = <Runtime as Config>::SourceChainHasher;
+
+/// Version of the artifact format, bumped on incompatible changes.
+const ARTIFACT_VERSION: u32 = 1;
+
+/// Measures the proof verification and flags the weights which do not match the hardware.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct VerifyWeightsCmd {
+	/// Check the measurements of a previously written artifact instead of measuring again.
+	#[arg(long, conflicts_with = "output")]
+	pub artifact: Option<PathBuf>,
+
+	/// Write the measurements as a JSON artifact to this file.
+	#[arg(long)]
+	pub output: Option<PathBuf>,
+
+	/// Numbers of proof nodes, i.e. the depths of the proven trie paths.
+	#[arg(long, value_delimiter = ',', default_values_t = [1, 4, 8, 16, 32])]
+	pub depths: Vec<u32>,
+
+	/// Total sizes in bytes of the proofs.
+	#[arg(long, value_delimiter = ',', default_values_t = [512, 4_096, 16_384, 65_536])]
+	pub sizes: Vec<u32>,
+
+	/// Number of verifications per proof, the median is kept.
+	#[arg(long, default_value_t = 50, value_parser = clap::value_parser!(u32).range(1..))]
+	pub repeat: u32,
+
+	/// Relative difference, in percent, between a measurement and its weight above which the
+	/// weight is flagged as stale.
+	#[arg(long, default_value_t = 20)]
+	pub tolerance: u32,
+
+	/// Print the report as JSON.
+	#[arg(long)]
+	pub json: bool,
+}
+
+/// Measurements of the proof verification, as written by `--output`.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeightArtifact {
+	pub version: u32,
+	/// Runtime the weights were read from, as `spec_name/spec_version`.
+	pub runtime: String,
+	pub samples: Vec<ProofSample>,
+	/// Verification time per proof size, averaged over the depths.
+	pub proof_size_histogram: Vec<SizeBucket>,
+	/// Weight and verification time per trie depth, averaged over the proof sizes.
+	pub trie_depth_histogram: Vec<DepthBucket>,
+}
+
+/// Verification of a proof of `nodes` nodes and `bytes` bytes by the benchmark `benchmark`.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofSample {
+	pub benchmark: String,
+	pub nodes: u32,
+	pub bytes: u32,
+	/// Median verification time, in nanoseconds.
+	pub measured_ns: f64,
+	/// Reference time of the runtime weight, in nanoseconds.
+	pub weight_ns: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SizeBucket {
+	pub bytes: u32,
+	pub verification_ns: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepthBucket {
+	pub nodes: u32,
+	pub verification_ns: f64,
+	pub weight_ns: f64,
+}
+
+/// Sample whose measurement is out of the tolerance of its weight.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StaleWeight<'a> {
+	#[serde(flatten)]
+	sample: &'a ProofSample,
+	/// Measured time over the weight: above one the weight undercharges the verification.
+	ratio: f64,
+}
+
+impl VerifyWeightsCmd {
+	pub fn run(&self) -> sc_cli::Result<()> {
+		let artifact = match &self.artifact {
+			Some(path) => {
+				let file = std::fs::File::open(path)
+					.map_err(|e| format!("Cannot open {}: {e}", path.display()))?;
+				let artifact: WeightArtifact = serde_json::from_reader(file)
+					.map_err(|e| format!("Invalid artifact {}: {e}", path.display()))?;
+				if artifact.version != ARTIFACT_VERSION {
+					return Err(format!(
+						"Unsupported artifact version {}, expected {ARTIFACT_VERSION}",
+						artifact.version
+					)
+					.into());
+				}
+				artifact
+			},
+			None => self.measure(),
+		};
+
+		if let Some(path) = &self.output {
+			let json = serde_json::to_string_pretty(&artifact).map_err(|e| e.to_string())?;
+			std::fs::write(path, json)
+				.map_err(|e| format!("Cannot write {}: {e}", path.display()))?;
+		}
+
+		let tolerance = 1.0 + f64::from(self.tolerance) / 100.0;
+		let stale: Vec<_> = artifact
+			.samples
+			.iter()
+			.filter_map(|sample| {
+				let ratio = sample.measured_ns / sample.weight_ns.max(1.0);
+				(ratio > tolerance || ratio * tolerance < 1.0)
+					.then_some(StaleWeight { sample, ratio })
+			})
+			.collect();
+
+		if self.json {
+			let json = serde_json::to_string_pretty(&stale).map_err(|e| e.to_string())?;
+			println!("{json}");
+		} else {
+			println!(
+				"{:>18} {:>6} {:>8} {:>14} {:>14} {:>8}",
+				"benchmark", "nodes", "bytes", "measured (ns)", "weight (ns)", "ratio"
+			);
+			for sample in &artifact.samples {
+				let ratio = sample.measured_ns / sample.weight_ns.max(1.0);
+				println!(
+					"{:>18} {:>6} {:>8} {:>14.0} {:>14.0} {:>8.2}",
+					sample.benchmark,
+					sample.nodes,
+					sample.bytes,
+					sample.measured_ns,
+					sample.weight_ns,
+					ratio
+				);
+			}
+		}
+
+		if !stale.is_empty() {
+			return Err(format!(
+				"{} of {} weights are off by more than {}% on this hardware, the pallet should be benchmarked again",
+				stale.len(),
+				artifact.samples.len(),
+				self.tolerance
+			)
+			.into());
+		}
+		Ok(())
+	}
+
+	fn measure(&self) -> WeightArtifact {
+		let max_node_size = <Runtime as Config>::MaxProofNodeSize::get();
+		let mut samples = Vec::with_capacity(self.depths.len() * self.sizes.len() * 2);
+		for &nodes in &self.depths {
+			for &bytes in &self.sizes {
+				// Same proofs as the `verify_proof` and `hash_proof_nodes` benchmarks.
+				let node_size = (bytes / nodes.max(1)).clamp(1, max_node_size) as usize;
+				let proof: Vec<Vec<u8>> = (0..nodes).map(|i| vec![i as u8; node_size]).collect();
+
+				let measured = self.median(|| {
+					let _ = get_storage_value::<Layout>(H256::zero(), H256::zero(), proof.clone());
+				});
+				samples.push(ProofSample {
+					benchmark: "verify_proof".into(),
+					nodes,
+					bytes,
+					measured_ns: as_ns(measured),
+					weight_ns: weight_ns(Weights::verify_proof(nodes, bytes).ref_time()),
+				});
+
+				let measured = self.median(|| {
+					let _ = StorageProof::new(proof.clone()).into_memory_db::<Hasher>();
+				});
+				samples.push(ProofSample {
+					benchmark: "hash_proof_nodes".into(),
+					nodes,
+					bytes,
+					measured_ns: as_ns(measured),
+					weight_ns: weight_ns(Weights::hash_proof_nodes(nodes, bytes).ref_time()),
+				});
+			}
+		}
+
+		let verifications = || samples.iter().filter(|s| s.benchmark == "verify_proof");
+		let proof_size_histogram = self
+			.sizes
+			.iter()
+			.map(|&bytes| SizeBucket {
+				bytes,
+				verification_ns: average(verifications().filter(|s| s.bytes == bytes), |s| {
+					s.measured_ns
+				}),
+			})
+			.collect();
+		let trie_depth_histogram = self
+			.depths
+			.iter()
+			.map(|&nodes| DepthBucket {
+				nodes,
+				verification_ns: average(verifications().filter(|s| s.nodes == nodes), |s| {
+					s.measured_ns
+				}),
+				weight_ns: average(verifications().filter(|s| s.nodes == nodes), |s| {
+					s.weight_ns
+				}),
+			})
+			.collect();
+
+		let version = &da_runtime::VERSION;
+		WeightArtifact {
+			version: ARTIFACT_VERSION,
+			runtime: format!("{}/{}", version.spec_name, version.spec_version),
+			samples,
+			proof_size_histogram,
+			trie_depth_histogram,
+		}
+	}
+
+	/// Median duration of `self.repeat` runs of `f`.
+	fn median(&self, mut f: impl FnMut()) -> Duration {
+		let mut durations: Vec<Duration> = (0..self.repeat)
+			.map(|_| {
+				let started = Instant::now();
+				f();
+				started.elapsed()
+			})
+			.collect();
+		durations.sort_unstable();
+		durations[durations.len() / 2]
+	}
+}
+
+fn average<'a>(
+	samples: impl Iterator<Item = &'a ProofSample>,
+	f: impl Fn(&ProofSample) -> f64,
+) -> f64 {
+	let (sum, count) = samples.fold((0.0, 0u32), |(sum, count), s| (sum + f(s), count + 1));
+	sum / f64::from(count.max(1))
+}
+
+fn weight_ns(ref_time: u64) -> f64 {
+	ref_time as f64 / WEIGHT_REF_TIME_PER_NANOS as f64
+}
+
+fn as_ns(duration: Duration) -> f64 {
+	duration.as_secs_f64() * 1_000_000_000.0
+}
@@ -4,7 +4,7 @@ use frame_support::weights::WeightToFee;
 use frame_support::{derive_impl, weights::IdentityFee};
 use frame_system::{
 	mocking::MockUncheckedExtrinsic, native::hosted_header_builder::da::HeaderExtensionBuilder,
-	test_utils::TestRandomness,
+	test_utils::TestRandomness, EnsureRoot,
 };
 use pallet_transaction_payment::CurrencyAdapter;
 use sp_runtime::{AccountId32, BuildStorage};
@@ -77,6 +77,7 @@ impl da_control::Config for Test {}
 impl pallet_vector::Config for Test {
 	type TimeProvider = Timestamp;
 	type Currency = Balances;
+	type DegradedOrigin = EnsureRoot<AccountId32>;
 }
 
 #[derive_impl(pallet_timestamp::config_preludes::TestDefaultConfig as pallet_timestamp::DefaultConfig)]
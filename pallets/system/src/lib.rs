@@ -103,6 +103,7 @@ use avail_core::{
 	ensure,
 	header::{Header as DaHeader, HeaderExtension},
 	traits::{ExtendedBlock, ExtendedHeader, GetAppId, MaybeCaller},
+	OpaqueExtrinsic,
 };
 
 use codec::{Decode, Encode, EncodeLike, FullCodec, MaxEncodedLen};
@@ -2074,12 +2075,46 @@ impl<T: Config> Pallet<T> {
 			},
 		});
 
+		// @CUSTOM
+		// Feeds the DA commitment pipeline with the data submissions of the block.
+		Self::note_app_extrinsic();
+
 		let next_extrinsic_index = Self::extrinsic_index().unwrap_or_default() + 1u32;
 
 		storage::unhashed::put(well_known_keys::EXTRINSIC_INDEX, &next_extrinsic_index);
 		ExecutionPhase::<T>::put(Phase::ApplyExtrinsic(next_extrinsic_index));
 	}
 
+	/// Notes the current extrinsic in the DA commitment pipeline if it is a data submission, so
+	/// the commitments of the block are built while its remaining extrinsics execute.
+	fn note_app_extrinsic() {
+		let tx_index = Self::extrinsic_index().unwrap_or_default();
+		let Some(encoded_xt) = ExtrinsicData::<T>::get(tx_index) else {
+			return;
+		};
+		let Ok(opaque) = OpaqueExtrinsic::from_bytes(&encoded_xt) else {
+			return;
+		};
+		let number = <Number<T>>::get();
+		let Some(app_extrinsic) = T::HeaderExtensionDataFilter::filter(
+			&[],
+			opaque,
+			number.unique_saturated_into(),
+			tx_index as usize,
+		)
+		.and_then(|data| data.app_extrinsic) else {
+			return;
+		};
+
+		native::hosted_header_builder::da::HeaderExtensionBuilder::<T>::note_app_extrinsic(
+			app_extrinsic,
+			tx_index,
+			sp_core::H256(<ParentHash<T>>::get().into()),
+			Self::block_length(),
+			number.unique_saturated_into(),
+		);
+	}
+
 	/// To be called immediately after `note_applied_extrinsic` of the last extrinsic of the block
 	/// has been called.
 	pub fn note_finished_extrinsics() {
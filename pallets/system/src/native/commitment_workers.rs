@@ -0,0 +1,270 @@
+//! DA commitment pipeline of the header extension builder.
+//!
+//! While a block is executed, the runtime notes every data submission it applies with
+//! `note_app_extrinsic`. The noted submissions of a block are built into a header extension on
+//! the worker threads as they arrive, so the commitments are computed while the remaining
+//! extrinsics of the block execute. When `finalize` builds the extension, `join` returns the
+//! speculative build if its submissions are the ones of the block, waiting for it if it is still
+//! running, and builds the extension itself otherwise.
+//!
+//! The extension is a pure function of the block data: the worker threads only change how long
+//! it takes to build it.
+#![cfg(feature = "std")]
+
+use super::hosted_header_builder::NotedAppExtrinsic;
+use crate::{limits::BlockLength, LOG_TARGET};
+use avail_core::{header::HeaderExtension, AppExtrinsic, HeaderVersion};
+use kate::Seed;
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use sp_core::H256;
+use std::{
+	collections::VecDeque,
+	sync::{Arc, Condvar, Mutex, OnceLock},
+	vec::Vec,
+};
+
+/// Maximum number of blocks executed at once whose submissions are kept. Blocks which are never
+/// finalized, like the ones of dry runs, are dropped once this is reached.
+const MAX_PIPELINES: usize = 8;
+
+static WORKERS: OnceLock<ThreadPool> = OnceLock::new();
+static PIPELINES: OnceLock<Arc<Pipelines>> = OnceLock::new();
+
+/// Sets the number of worker threads, before the first block is executed.
+///
+/// Without it, the workers are as many as the logical CPUs.
+pub fn init(threads: usize) -> Result<(), String> {
+	let pool = ThreadPoolBuilder::new()
+		.num_threads(threads)
+		.thread_name(|i| format!("da-commitment-{i}"))
+		.build()
+		.map_err(|e| format!("DA commitment workers cannot be started: {e}"))?;
+	WORKERS
+		.set(pool)
+		.map_err(|_| String::from("DA commitment workers are already started"))
+}
+
+fn workers() -> &'static ThreadPool {
+	WORKERS.get_or_init(|| {
+		ThreadPoolBuilder::new()
+			.thread_name(|i| format!("da-commitment-{i}"))
+			.build()
+			.expect("Default thread pool is always buildable .qed")
+	})
+}
+
+fn pipelines() -> Arc<Pipelines> {
+	PIPELINES.get_or_init(Default::default).clone()
+}
+
+/// Block being executed, as seen by the runtime.
+#[derive(Clone, PartialEq)]
+struct BlockKey {
+	parent_hash: H256,
+	block_number: u32,
+	block_length: BlockLength,
+	seed: Seed,
+}
+
+/// Data submissions of a block being executed and the latest extension built from them.
+struct Pipeline {
+	key: BlockKey,
+	/// Applied submissions with their extrinsic index.
+	noted: Vec<(u32, AppExtrinsic)>,
+	/// Submissions of the latest speculative build, and the extension built without data root.
+	built: Option<(Vec<AppExtrinsic>, HeaderExtension)>,
+	building: bool,
+}
+
+impl Pipeline {
+	fn noted_are(&self, submitted: &[AppExtrinsic]) -> bool {
+		self.noted.iter().map(|(_, xt)| xt).eq(submitted.iter())
+	}
+
+	fn built_from(&self, submitted: &[AppExtrinsic]) -> bool {
+		self.built
+			.as_ref()
+			.is_some_and(|(built, _)| built.as_slice() == submitted)
+	}
+
+	fn matches(&self, block_length: &BlockLength, block_number: u32, seed: &Seed) -> bool {
+		self.key.block_number == block_number
+			&& &self.key.block_length == block_length
+			&& &self.key.seed == seed
+	}
+}
+
+#[derive(Default)]
+struct Pipelines {
+	pipelines: Mutex<VecDeque<Pipeline>>,
+	built: Condvar,
+}
+
+/// Adds a submission applied by a block being executed to the pipeline of the block, and builds
+/// the extension of the submissions noted so far if no build is running.
+///
+/// A submission noted again at an index replaces the ones from it on, as the block builder
+/// rolled them back.
+pub fn note(noted: NotedAppExtrinsic) {
+	let key = BlockKey {
+		parent_hash: noted.parent_hash,
+		block_number: noted.block_number,
+		block_length: noted.block_length,
+		seed: noted.seed,
+	};
+	let this = pipelines();
+	let mut pipelines = this
+		.pipelines
+		.lock()
+		.expect("Pipelines lock is never poisoned .qed");
+	let idx = match pipelines.iter().position(|p| p.key == key) {
+		Some(idx) => idx,
+		None => {
+			if pipelines.len() == MAX_PIPELINES {
+				pipelines.pop_front();
+			}
+			pipelines.push_back(Pipeline {
+				key: key.clone(),
+				noted: Vec::new(),
+				built: None,
+				building: false,
+			});
+			pipelines.len() - 1
+		},
+	};
+	let pipeline = &mut pipelines[idx];
+	pipeline
+		.noted
+		.retain(|(tx_index, _)| *tx_index < noted.tx_index);
+	pipeline.noted.push((noted.tx_index, noted.app_extrinsic));
+
+	if !pipeline.building {
+		pipeline.building = true;
+		let this = this.clone();
+		workers().spawn(move || this.build_noted(key));
+	}
+}
+
+impl Pipelines {
+	/// Builds the noted submissions of `key` until the latest build is the one of the noted
+	/// submissions.
+	fn build_noted(&self, key: BlockKey) {
+		loop {
+			let submitted = {
+				let mut pipelines = self
+					.pipelines
+					.lock()
+					.expect("Pipelines lock is never poisoned .qed");
+				let Some(pipeline) = pipelines.iter_mut().find(|p| p.key == key) else {
+					// Dropped or joined meanwhile.
+					return;
+				};
+				let submitted = pipeline
+					.noted
+					.iter()
+					.map(|(_, xt)| xt.clone())
+					.collect::<Vec<_>>();
+				if pipeline.built_from(&submitted) {
+					pipeline.building = false;
+					self.built.notify_all();
+					return;
+				}
+				submitted
+			};
+
+			let extension = build(
+				submitted.clone(),
+				H256::zero(),
+				key.block_length.clone(),
+				key.block_number,
+				key.seed,
+			);
+
+			let mut pipelines = self
+				.pipelines
+				.lock()
+				.expect("Pipelines lock is never poisoned .qed");
+			if let Some(pipeline) = pipelines.iter_mut().find(|p| p.key == key) {
+				pipeline.built = Some((submitted, extension));
+			}
+			self.built.notify_all();
+		}
+	}
+
+	/// Takes the extension built from `submitted`, waiting for it while the build of these
+	/// submissions is running. Returns `None` when there is no such build.
+	fn take_built(
+		&self,
+		submitted: &[AppExtrinsic],
+		block_length: &BlockLength,
+		block_number: u32,
+		seed: &Seed,
+	) -> Option<HeaderExtension> {
+		let mut pipelines = self
+			.pipelines
+			.lock()
+			.expect("Pipelines lock is never poisoned .qed");
+		loop {
+			let pos = pipelines.iter().position(|p| {
+				p.matches(block_length, block_number, seed)
+					&& (p.built_from(submitted) || (p.building && p.noted_are(submitted)))
+			})?;
+			if pipelines[pos].built_from(submitted) {
+				let pipeline = pipelines.remove(pos)?;
+				return pipeline.built.map(|(_, extension)| extension);
+			}
+			pipelines = self
+				.built
+				.wait(pipelines)
+				.expect("Pipelines lock is never poisoned .qed");
+		}
+	}
+}
+
+/// Returns the extension of the block, from its speculative build when there is one.
+pub fn join(
+	submitted: Vec<AppExtrinsic>,
+	data_root: H256,
+	block_length: BlockLength,
+	block_number: u32,
+	seed: Seed,
+) -> HeaderExtension {
+	// Blocks without submissions have empty commitments and are never noted.
+	if !submitted.is_empty() {
+		if let Some(extension) =
+			pipelines().take_built(&submitted, &block_length, block_number, &seed)
+		{
+			return with_data_root(extension, data_root);
+		}
+		log::debug!(
+			target: LOG_TARGET,
+			"No speculative extension build of block #{block_number}, building it"
+		);
+	}
+
+	workers().install(|| build(submitted, data_root, block_length, block_number, seed))
+}
+
+/// Sets the data root of an extension built before the block was finalized.
+fn with_data_root(mut extension: HeaderExtension, data_root: H256) -> HeaderExtension {
+	let HeaderExtension::V3(v3) = &mut extension;
+	v3.commitment.data_root = data_root;
+	extension
+}
+
+fn build(
+	submitted: Vec<AppExtrinsic>,
+	data_root: H256,
+	block_length: BlockLength,
+	block_number: u32,
+	seed: Seed,
+) -> HeaderExtension {
+	super::build_extension_v2::build_extension(
+		submitted,
+		data_root,
+		block_length,
+		block_number,
+		seed,
+		HeaderVersion::V3,
+	)
+}
@@ -6,6 +6,7 @@ use crate::{limits::BlockLength, Config, LOG_TARGET};
 #[cfg(feature = "std")]
 use avail_core::HeaderVersion;
 use avail_core::{header::HeaderExtension, traits::ExtendedHeader, AppExtrinsic};
+use codec::{Decode, Encode};
 pub use kate::{
 	metrics::{IgnoreMetrics, Metrics},
 	Seed,
@@ -14,7 +15,7 @@ pub use kate::{
 use frame_support::traits::Randomness;
 use sp_core::H256;
 use sp_runtime::traits::Hash;
-use sp_runtime_interface::runtime_interface;
+use sp_runtime_interface::{pass_by::PassByCodec, runtime_interface};
 use sp_std::vec::Vec;
 
 pub const MIN_WIDTH: usize = 4;
@@ -53,9 +54,39 @@ pub mod da {
 				seed,
 			)
 		}
+
+		fn note_app_extrinsic(
+			app_extrinsic: AppExtrinsic,
+			tx_index: u32,
+			parent_hash: H256,
+			block_length: BlockLength,
+			block_number: u32,
+		) {
+			let seed = Self::random_seed::<T>();
+
+			super::hosted_header_builder::note_app_extrinsic(NotedAppExtrinsic {
+				parent_hash,
+				block_number,
+				block_length,
+				seed,
+				tx_index,
+				app_extrinsic,
+			})
+		}
 	}
 }
 
+/// Data submission applied by a block being executed, noted in the DA commitment pipeline.
+#[derive(Encode, Decode, PassByCodec)]
+pub struct NotedAppExtrinsic {
+	pub parent_hash: H256,
+	pub block_number: u32,
+	pub block_length: BlockLength,
+	pub seed: Seed,
+	pub tx_index: u32,
+	pub app_extrinsic: AppExtrinsic,
+}
+
 /// Trait for header builder.
 pub trait HeaderExtensionBuilder {
 	type Header: ExtendedHeader<Extension = HeaderExtension>;
@@ -68,6 +99,16 @@ pub trait HeaderExtensionBuilder {
 		block_number: u32,
 	) -> HeaderExtension;
 
+	/// Notes the data submission applied at `tx_index`, so the commitments of the block are built
+	/// while its remaining extrinsics execute.
+	fn note_app_extrinsic(
+		app_extrinsic: AppExtrinsic,
+		tx_index: u32,
+		parent_hash: H256,
+		block_length: BlockLength,
+		block_number: u32,
+	);
+
 	/// Generates a random seed using the _epoch seed_ and the _current block_ returned by
 	/// `T::Randomness` type.
 	fn random_seed<T: Config>() -> Seed {
@@ -111,6 +152,9 @@ pub trait HostedHeaderBuilder {
 
 	/// Note: Whenever a new header version is introduced, ensure to create a corresponding version
 	/// of the `build` hosted function, while retaining the existing ones.
+	///
+	/// The extension is taken from the DA commitment pipeline when the block noted its
+	/// submissions with `note_app_extrinsic`, it is the same as the one built here otherwise.
 	#[version(2)]
 	fn build(
 		submitted: Vec<AppExtrinsic>,
@@ -119,13 +163,18 @@ pub trait HostedHeaderBuilder {
 		block_number: u32,
 		seed: Seed,
 	) -> HeaderExtension {
-		crate::native::build_extension_v2::build_extension(
+		crate::native::commitment_workers::join(
 			submitted,
 			data_root,
 			block_length,
 			block_number,
 			seed,
-			HeaderVersion::V3,
 		)
 	}
+
+	/// Feeds a data submission applied by a block being executed to the DA commitment pipeline,
+	/// which builds the commitments of the block on the worker threads while it executes.
+	fn note_app_extrinsic(noted: NotedAppExtrinsic) {
+		crate::native::commitment_workers::note(noted)
+	}
 }
@@ -2,5 +2,7 @@
 pub mod build_extension_v1;
 #[cfg(feature = "std")]
 pub mod build_extension_v2;
+#[cfg(feature = "std")]
+pub mod commitment_workers;
 
 pub mod hosted_header_builder;
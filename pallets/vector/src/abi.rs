@@ -0,0 +1,209 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{pallet_prelude::ConstU32, BoundedVec, Deserialize, Serialize};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+/// Maximum number of fields of a message schema.
+pub const MAX_SCHEMA_FIELDS: u32 = 16;
+
+/// Length in bytes of an ABI word.
+const WORD_LENGTH: usize = 32;
+
+/// ABI types supported in the message schemas.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum AbiType {
+	Bytes32,
+	Uint256,
+	Uint64,
+	Address,
+	Bool,
+	/// Dynamic `bytes`, encoded as an offset to its length-prefixed data.
+	Bytes,
+}
+
+/// Expected ABI layout of the message data, as a list of top level fields.
+pub type MessageSchema = BoundedVec<AbiType, ConstU32<MAX_SCHEMA_FIELDS>>;
+
+/// Decoded field, borrowing from the message data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AbiValue<'a> {
+	Bytes32(&'a [u8]),
+	/// Big-endian 32-byte word.
+	Uint256(&'a [u8]),
+	Uint64(u64),
+	/// 20-byte address.
+	Address(&'a [u8]),
+	Bool(bool),
+	Bytes(&'a [u8]),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AbiError {
+	/// The data ends before a field or its payload.
+	TooShort,
+	/// A padded field has non-zero padding bytes.
+	InvalidPadding,
+	/// A dynamic field points into the head or outside of the data.
+	InvalidOffset,
+}
+
+/// Decodes `data` according to `schema` without copying the field payloads.
+///
+/// Only the top level head and the length-prefixed payloads referenced by it are read, so the
+/// work is bounded by the schema length and the size of the data.
+pub fn decode<'a>(schema: &[AbiType], data: &'a [u8]) -> Result<Vec<AbiValue<'a>>, AbiError> {
+	let head_length = schema.len() * WORD_LENGTH;
+	let mut values = Vec::with_capacity(schema.len());
+
+	for (index, ty) in schema.iter().enumerate() {
+		let word = word_at(data, index * WORD_LENGTH)?;
+		let value = match ty {
+			AbiType::Bytes32 => AbiValue::Bytes32(word),
+			AbiType::Uint256 => AbiValue::Uint256(word),
+			AbiType::Uint64 => AbiValue::Uint64(word_to_u64(word)?),
+			AbiType::Address => {
+				let (padding, address) = word.split_at(WORD_LENGTH - 20);
+				ensure_zero(padding)?;
+				AbiValue::Address(address)
+			},
+			AbiType::Bool => match word_to_u64(word)? {
+				0 => AbiValue::Bool(false),
+				1 => AbiValue::Bool(true),
+				_ => return Err(AbiError::InvalidPadding),
+			},
+			AbiType::Bytes => {
+				let offset = word_to_usize(word)?;
+				if offset < head_length || offset % WORD_LENGTH != 0 {
+					return Err(AbiError::InvalidOffset);
+				}
+				let length = word_to_usize(word_at(data, offset)?)?;
+				let start = offset + WORD_LENGTH;
+				let end = start.checked_add(length).ok_or(AbiError::InvalidOffset)?;
+				AbiValue::Bytes(data.get(start..end).ok_or(AbiError::TooShort)?)
+			},
+		};
+		values.push(value);
+	}
+
+	Ok(values)
+}
+
+fn word_at(data: &[u8], offset: usize) -> Result<&[u8], AbiError> {
+	data.get(offset..offset.saturating_add(WORD_LENGTH))
+		.ok_or(AbiError::TooShort)
+}
+
+fn word_to_u64(word: &[u8]) -> Result<u64, AbiError> {
+	let (padding, value) = word.split_at(WORD_LENGTH - 8);
+	ensure_zero(padding)?;
+	let mut bytes = [0u8; 8];
+	bytes.copy_from_slice(value);
+	Ok(u64::from_be_bytes(bytes))
+}
+
+fn word_to_usize(word: &[u8]) -> Result<usize, AbiError> {
+	word_to_u64(word)
+		.ok()
+		.and_then(|value| usize::try_from(value).ok())
+		.ok_or(AbiError::InvalidOffset)
+}
+
+fn ensure_zero(padding: &[u8]) -> Result<(), AbiError> {
+	if padding.iter().all(|b| *b == 0) {
+		Ok(())
+	} else {
+		Err(AbiError::InvalidPadding)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use hex_literal::hex;
+
+	fn word(value: u64) -> [u8; 32] {
+		let mut word = [0u8; 32];
+		word[24..].copy_from_slice(&value.to_be_bytes());
+		word
+	}
+
+	#[test]
+	fn decode_static_fields() {
+		let address = hex!("681257BED628425a28B469114Dc21A7c30205cFD");
+		let mut data = [0x11u8; 32].to_vec();
+		data.extend_from_slice(&word(42));
+		data.extend_from_slice(&[0u8; 12]);
+		data.extend_from_slice(&address);
+		data.extend_from_slice(&word(1));
+
+		let schema = [
+			AbiType::Bytes32,
+			AbiType::Uint64,
+			AbiType::Address,
+			AbiType::Bool,
+		];
+		assert_eq!(
+			decode(&schema, &data),
+			Ok(vec![
+				AbiValue::Bytes32(&[0x11u8; 32]),
+				AbiValue::Uint64(42),
+				AbiValue::Address(&address),
+				AbiValue::Bool(true),
+			])
+		);
+	}
+
+	#[test]
+	fn decode_dynamic_bytes() {
+		let payload = b"Hello, World!";
+		let mut data = word(7).to_vec();
+		data.extend_from_slice(&word(64));
+		data.extend_from_slice(&word(payload.len() as u64));
+		data.extend_from_slice(payload);
+		data.resize(data.len() + 32 - payload.len(), 0);
+
+		assert_eq!(
+			decode(&[AbiType::Uint256, AbiType::Bytes], &data),
+			Ok(vec![AbiValue::Uint256(&word(7)), AbiValue::Bytes(payload)])
+		);
+	}
+
+	#[test]
+	fn decode_fails_for_short_data() {
+		assert_eq!(
+			decode(&[AbiType::Bytes32], b"Hello, World!"),
+			Err(AbiError::TooShort)
+		);
+
+		// The length of the payload points past the end of the data.
+		let mut data = word(32).to_vec();
+		data.extend_from_slice(&word(64));
+		data.extend_from_slice(&[0u8; 32]);
+		assert_eq!(decode(&[AbiType::Bytes], &data), Err(AbiError::TooShort));
+	}
+
+	#[test]
+	fn decode_fails_for_dirty_padding() {
+		let mut address = [0xffu8; 32];
+		address[12..].copy_from_slice(&[0u8; 20]);
+		assert_eq!(
+			decode(&[AbiType::Address], &address),
+			Err(AbiError::InvalidPadding)
+		);
+		assert_eq!(
+			decode(&[AbiType::Bool], &word(2)),
+			Err(AbiError::InvalidPadding)
+		);
+	}
+
+	#[test]
+	fn decode_fails_for_offset_into_head() {
+		let mut data = word(0).to_vec();
+		data.extend_from_slice(&word(0));
+		assert_eq!(
+			decode(&[AbiType::Bytes], &data),
+			Err(AbiError::InvalidOffset)
+		);
+	}
+}
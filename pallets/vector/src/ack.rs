@@ -0,0 +1,88 @@
+use crate::abi::{AbiType, MessageSchema};
+use frame_support::BoundedVec;
+use sp_core::H256;
+use sp_std::{vec, vec::Vec};
+
+/// Tag in the first word of the data of acknowledgement messages, left-aligned like a
+/// Solidity `bytes32` literal.
+pub const ACK_MESSAGE_KIND: &[u8] = b"vector:ack";
+
+/// Flag set in the ids of acknowledgement messages, so they never collide with the ids of
+/// messages sent with `send_message`.
+pub const ACK_ID_FLAG: u64 = 1 << 63;
+
+/// Maximum number of acknowledgements queued in a block.
+pub const MAX_ACKNOWLEDGEMENTS: u32 = 256;
+
+/// Result of executing an inbound message, sent back to its sender as arbitrary message data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Acknowledgement {
+	/// Id of the executed message on the source chain.
+	pub message_id: u64,
+	/// Hash of the executed message.
+	pub message_root: H256,
+	pub success: bool,
+	/// Hash of the data returned by the execution, or of the encoded error if it failed.
+	pub return_data_hash: H256,
+}
+
+impl Acknowledgement {
+	/// ABI layout of the acknowledgement data.
+	pub fn schema() -> MessageSchema {
+		BoundedVec::truncate_from(vec![
+			AbiType::Bytes32,
+			AbiType::Uint64,
+			AbiType::Bytes32,
+			AbiType::Bool,
+			AbiType::Bytes32,
+		])
+	}
+
+	/// Encodes the acknowledgement with the ABI layout of [`Self::schema`].
+	pub fn abi_encode(&self) -> Vec<u8> {
+		let mut kind = [0u8; 32];
+		kind[..ACK_MESSAGE_KIND.len()].copy_from_slice(ACK_MESSAGE_KIND);
+
+		let mut data = Vec::with_capacity(5 * 32);
+		data.extend_from_slice(&kind);
+		data.extend_from_slice(&uint_word(self.message_id));
+		data.extend_from_slice(self.message_root.as_bytes());
+		data.extend_from_slice(&uint_word(self.success.into()));
+		data.extend_from_slice(self.return_data_hash.as_bytes());
+		data
+	}
+}
+
+fn uint_word(value: u64) -> [u8; 32] {
+	let mut word = [0u8; 32];
+	word[24..].copy_from_slice(&value.to_be_bytes());
+	word
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::abi::{decode, AbiValue};
+
+	#[test]
+	fn acknowledgement_decodes_with_schema() {
+		let ack = Acknowledgement {
+			message_id: 42,
+			message_root: H256::repeat_byte(1),
+			success: true,
+			return_data_hash: H256::zero(),
+		};
+		let data = ack.abi_encode();
+
+		let values = decode(&Acknowledgement::schema(), &data).unwrap();
+		assert_eq!(values[0], AbiValue::Bytes32(&data[..32]));
+		assert!(data[..32].starts_with(ACK_MESSAGE_KIND));
+		assert_eq!(values[1], AbiValue::Uint64(42));
+		assert_eq!(
+			values[2],
+			AbiValue::Bytes32(H256::repeat_byte(1).as_bytes())
+		);
+		assert_eq!(values[3], AbiValue::Bool(true));
+		assert_eq!(values[4], AbiValue::Bytes32(H256::zero().as_bytes()));
+	}
+}
@@ -0,0 +1,190 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{Deserialize, Serialize};
+use primitive_types::{H160, H256};
+use scale_info::TypeInfo;
+
+/// Length in bytes of an EVM address.
+const EVM_ADDRESS_LENGTH: usize = 20;
+
+/// Address format used by a source chain, encoded as a single version byte.
+#[derive(Clone, Copy, Default, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum AddressVersion {
+	/// 20-byte EVM address, left-aligned in a 32-byte word.
+	#[default]
+	Evm = 0,
+	/// Native 32-byte address (Cosmos, Solana, Substrate based chains).
+	Bytes32 = 1,
+}
+
+impl AddressVersion {
+	/// Returns the version byte.
+	pub fn as_u8(self) -> u8 {
+		self as u8
+	}
+}
+
+impl TryFrom<u8> for AddressVersion {
+	type Error = AddressError;
+
+	fn try_from(value: u8) -> Result<Self, Self::Error> {
+		match value {
+			0 => Ok(AddressVersion::Evm),
+			1 => Ok(AddressVersion::Bytes32),
+			_ => Err(AddressError::UnknownVersion),
+		}
+	}
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum AddressError {
+	/// Version byte does not map to a known address format.
+	UnknownVersion,
+	/// EVM address has non-zero bytes after the first 20.
+	InvalidEvmAddress,
+}
+
+/// Chain-agnostic address of a message sender on the source chain.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum SourceAddress {
+	Evm(H160),
+	Bytes32(H256),
+}
+
+impl SourceAddress {
+	/// Decodes a 32-byte message word into an address of the given `version`.
+	pub fn decode_versioned(version: AddressVersion, raw: H256) -> Result<Self, AddressError> {
+		match version {
+			AddressVersion::Evm => {
+				if raw[EVM_ADDRESS_LENGTH..].iter().any(|b| *b != 0) {
+					return Err(AddressError::InvalidEvmAddress);
+				}
+				Ok(SourceAddress::Evm(H160::from_slice(
+					&raw[..EVM_ADDRESS_LENGTH],
+				)))
+			},
+			AddressVersion::Bytes32 => Ok(SourceAddress::Bytes32(raw)),
+		}
+	}
+
+	/// Same as `decode_versioned`, but takes the raw version byte.
+	pub fn decode_with_version_byte(version: u8, raw: H256) -> Result<Self, AddressError> {
+		Self::decode_versioned(AddressVersion::try_from(version)?, raw)
+	}
+
+	/// Raw bytes of the address, as keyed in the state trie of the source chain.
+	pub fn as_bytes(&self) -> &[u8] {
+		match self {
+			SourceAddress::Evm(address) => address.as_bytes(),
+			SourceAddress::Bytes32(address) => address.as_bytes(),
+		}
+	}
+
+	/// Version of the address format.
+	pub fn version(&self) -> AddressVersion {
+		match self {
+			SourceAddress::Evm(_) => AddressVersion::Evm,
+			SourceAddress::Bytes32(_) => AddressVersion::Bytes32,
+		}
+	}
+
+	/// Encodes the address back into a 32-byte message word.
+	pub fn to_h256(&self) -> H256 {
+		match self {
+			SourceAddress::Evm(address) => {
+				let mut raw = [0u8; 32];
+				raw[..EVM_ADDRESS_LENGTH].copy_from_slice(address.as_bytes());
+				H256(raw)
+			},
+			SourceAddress::Bytes32(address) => *address,
+		}
+	}
+
+	/// Returns the EVM address, if this is one.
+	pub fn as_evm(&self) -> Option<H160> {
+		match self {
+			SourceAddress::Evm(address) => Some(*address),
+			SourceAddress::Bytes32(_) => None,
+		}
+	}
+}
+
+impl From<H160> for SourceAddress {
+	fn from(address: H160) -> Self {
+		SourceAddress::Evm(address)
+	}
+}
+
+impl From<SourceAddress> for H256 {
+	fn from(address: SourceAddress) -> Self {
+		address.to_h256()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use frame_support::assert_err;
+	use hex_literal::hex;
+	use primitive_types::{H160, H256};
+
+	use crate::address::{AddressError, AddressVersion, SourceAddress};
+
+	#[test]
+	fn decode_evm_address() {
+		let raw = H256(hex!(
+			"f39fd6e51aad88f6f4ce6ab8827279cfffb92266000000000000000000000000"
+		));
+		let address = SourceAddress::decode_versioned(AddressVersion::Evm, raw).unwrap();
+
+		assert_eq!(
+			Some(H160(hex!("f39fd6e51aad88f6f4ce6ab8827279cfffb92266"))),
+			address.as_evm()
+		);
+		assert_eq!(AddressVersion::Evm, address.version());
+		assert_eq!(raw, address.to_h256());
+	}
+
+	#[test]
+	fn decode_evm_address_with_dirty_padding_fails() {
+		let raw = H256(hex!(
+			"f39fd6e51aad88f6f4ce6ab8827279cfffb92266000000000000000000000001"
+		));
+
+		assert_err!(
+			SourceAddress::decode_versioned(AddressVersion::Evm, raw),
+			AddressError::InvalidEvmAddress
+		);
+	}
+
+	#[test]
+	fn decode_bytes32_address() {
+		let raw = H256(hex!(
+			"d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"
+		));
+		let address = SourceAddress::decode_with_version_byte(1, raw).unwrap();
+
+		assert_eq!(SourceAddress::Bytes32(raw), address);
+		assert_eq!(None, address.as_evm());
+		assert_eq!(raw.as_bytes(), address.as_bytes());
+		assert_eq!(raw, H256::from(address));
+	}
+
+	#[test]
+	fn decode_unknown_version_fails() {
+		assert_err!(
+			SourceAddress::decode_with_version_byte(2, H256::zero()),
+			AddressError::UnknownVersion
+		);
+	}
+
+	#[test]
+	fn evm_address_roundtrip() {
+		let evm = H160(hex!("426bde66abd85741be832b824ea65a3aad70113e"));
+		let address = SourceAddress::from(evm);
+		let decoded =
+			SourceAddress::decode_with_version_byte(address.version().as_u8(), address.to_h256())
+				.unwrap();
+
+		assert_eq!(address, decoded);
+	}
+}
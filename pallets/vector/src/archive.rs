@@ -0,0 +1,161 @@
+use binary_merkle_tree::{merkle_proof, merkle_root, verify_proof, Leaf};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{pallet_prelude::ConstU32, BoundedVec};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::traits::Keccak256;
+use sp_std::vec::Vec;
+
+/// Maximum number of slots that can be stored in a single epoch.
+pub const MAX_SLOTS_PER_EPOCH: u32 = 8192;
+/// Maximum depth of an archive proof, enough for `MAX_SLOTS_PER_EPOCH` leaves.
+pub const MAX_ARCHIVE_PROOF_DEPTH: u32 = 32;
+/// Maximum number of slots archived in a single block.
+pub const MAX_ARCHIVED_SLOTS_PER_BLOCK: u32 = 256;
+
+pub type EpochSlotList = BoundedVec<u64, ConstU32<MAX_SLOTS_PER_EPOCH>>;
+/// Slot, header root, execution state root and participation of the archived slots of an epoch.
+pub type ArchivedSlotList = BoundedVec<(u64, H256, H256, u16), ConstU32<MAX_SLOTS_PER_EPOCH>>;
+
+/// Aggregated roots of an archived epoch.
+#[derive(Clone, Copy, Default, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct ArchivedEpoch {
+	/// Merkle root over the slot leaves of the epoch.
+	pub root: H256,
+	/// Number of slot leaves in the epoch.
+	pub leaves: u32,
+}
+
+/// Proof that a slot and its roots are part of an archived epoch.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct ArchiveProof {
+	pub header_root: H256,
+	pub execution_state_root: H256,
+	/// Participation of the step output which finalized the slot.
+	pub participation: u16,
+	pub leaf_index: u32,
+	pub siblings: BoundedVec<H256, ConstU32<MAX_ARCHIVE_PROOF_DEPTH>>,
+}
+
+/// Returns the epoch that a slot belongs to.
+pub fn epoch_of(slot: u64, epoch_slots: u64) -> u64 {
+	slot.checked_div(epoch_slots).unwrap_or_default()
+}
+
+/// Encodes the Merkle leaf of a slot.
+pub fn slot_leaf(
+	slot: u64,
+	header_root: H256,
+	execution_state_root: H256,
+	participation: u16,
+) -> Vec<u8> {
+	(slot, header_root, execution_state_root, participation).encode()
+}
+
+/// Computes the archived epoch from the leaves of its slots, in slot order.
+pub fn archive_leaves(leaves: &[Vec<u8>]) -> ArchivedEpoch {
+	ArchivedEpoch {
+		root: merkle_root::<Keccak256, _>(leaves),
+		leaves: leaves.len() as u32,
+	}
+}
+
+/// Builds the proof for the leaf at `leaf_index`, e.g. from the `HeadUpdated` events of an
+/// archived epoch and the `SlotParticipation` of its slots.
+pub fn archive_proof(slots: &[(u64, H256, H256, u16)], leaf_index: u32) -> Option<ArchiveProof> {
+	let (_, header_root, execution_state_root, participation) = *slots.get(leaf_index as usize)?;
+	let leaves = slots.iter().map(|(slot, header, state, participation)| {
+		slot_leaf(*slot, *header, *state, *participation)
+	});
+	let proof = merkle_proof::<Keccak256, _, _>(leaves, leaf_index as usize);
+
+	Some(ArchiveProof {
+		header_root,
+		execution_state_root,
+		participation,
+		leaf_index,
+		siblings: BoundedVec::try_from(proof.proof).ok()?,
+	})
+}
+
+/// Verifies that the slot and the roots in `proof` are part of `epoch`.
+pub fn verify_archive_proof(epoch: &ArchivedEpoch, slot: u64, proof: &ArchiveProof) -> bool {
+	if proof.leaf_index >= epoch.leaves {
+		return false;
+	}
+	let leaf = slot_leaf(
+		slot,
+		proof.header_root,
+		proof.execution_state_root,
+		proof.participation,
+	);
+
+	verify_proof::<Keccak256, _, _>(
+		&epoch.root,
+		proof.siblings.iter().copied(),
+		epoch.leaves as usize,
+		proof.leaf_index as usize,
+		Leaf::Value(leaf.as_slice()),
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use sp_core::H256;
+
+	use crate::archive::{archive_leaves, archive_proof, slot_leaf, verify_archive_proof};
+
+	fn slots() -> Vec<(u64, H256, H256, u16)> {
+		(0..5u64)
+			.map(|i| {
+				(
+					8192 + i * 32,
+					H256::repeat_byte(i as u8),
+					H256::repeat_byte(0xf0 | i as u8),
+					400 + i as u16,
+				)
+			})
+			.collect()
+	}
+
+	#[test]
+	fn archive_proof_verifies_for_every_slot() {
+		let slots = slots();
+		let leaves: Vec<_> = slots
+			.iter()
+			.map(|(s, h, e, p)| slot_leaf(*s, *h, *e, *p))
+			.collect();
+		let epoch = archive_leaves(&leaves);
+
+		for (index, (slot, ..)) in slots.iter().enumerate() {
+			let proof = archive_proof(&slots, index as u32).unwrap();
+			assert!(verify_archive_proof(&epoch, *slot, &proof));
+		}
+	}
+
+	#[test]
+	fn archive_proof_fails_for_wrong_roots() {
+		let slots = slots();
+		let leaves: Vec<_> = slots
+			.iter()
+			.map(|(s, h, e, p)| slot_leaf(*s, *h, *e, *p))
+			.collect();
+		let epoch = archive_leaves(&leaves);
+
+		let mut proof = archive_proof(&slots, 2).unwrap();
+		proof.execution_state_root = H256::zero();
+		assert!(!verify_archive_proof(&epoch, slots[2].0, &proof));
+
+		let mut proof = archive_proof(&slots, 2).unwrap();
+		proof.participation = 512;
+		assert!(!verify_archive_proof(&epoch, slots[2].0, &proof));
+
+		let proof = archive_proof(&slots, 2).unwrap();
+		assert!(!verify_archive_proof(&epoch, slots[3].0, &proof));
+	}
+
+	#[test]
+	fn archive_proof_out_of_range() {
+		assert!(archive_proof(&slots(), 5).is_none());
+	}
+}
@@ -0,0 +1,25 @@
+use codec::{Decode, Encode};
+use sp_core::H256;
+use sp_std::vec::Vec;
+
+/// Prefix of the keys of the accepted proof artifacts in the offchain storage.
+pub const PROOF_ARTIFACT_PREFIX: &[u8] = b"vector::proof_artifact::";
+/// Maximum number of proof artifacts expiring at the same block.
+pub const MAX_PROOF_ARTIFACTS_PER_BLOCK: u32 = 64;
+
+/// `fulfill_call` submission accepted by the light client, as indexed in the offchain storage.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq)]
+pub struct ProofArtifact {
+	/// Block the submission was accepted in.
+	pub block_number: u32,
+	pub function_id: H256,
+	/// Inputs of the submission, with their proofs, as submitted.
+	pub inputs: Vec<u8>,
+}
+
+/// Returns the offchain storage key of the artifact of the inputs hashed to `input_hash`.
+pub fn proof_artifact_key(input_hash: H256) -> Vec<u8> {
+	let mut key = PROOF_ARTIFACT_PREFIX.to_vec();
+	key.extend_from_slice(input_hash.as_bytes());
+	key
+}
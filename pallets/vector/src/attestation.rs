@@ -0,0 +1,129 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::{H160, H256};
+use sp_io::hashing::keccak_256;
+use sp_std::vec::Vec;
+
+/// Maximum length of the data an Ethereum account attests on Avail.
+pub const MAX_ATTESTED_DATA_LENGTH: u32 = 1024;
+/// Prefix of the messages signed with `personal_sign`, as defined by EIP-191.
+const PERSONAL_MESSAGE_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n";
+
+/// Proof that an Ethereum account signed the attested data.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo)]
+pub enum SignatureProof<AccountProof, StorageProof> {
+	/// `personal_sign` signature of an externally owned account, as `r ++ s ++ v`.
+	Ecdsa([u8; 65]),
+	/// Approval of the message hash by a contract account, as the EIP-1271 wallets record
+	/// the messages they sign: a non-zero value at the hash in the mapping at storage index
+	/// `mapping_slot`, proven against the execution state root of `slot`.
+	Contract {
+		slot: u64,
+		mapping_slot: H256,
+		account_proof: AccountProof,
+		storage_proof: StorageProof,
+	},
+}
+
+/// Kind of signature an attestation was verified with.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum SignatureKind {
+	Ecdsa,
+	Contract,
+}
+
+impl<AccountProof, StorageProof> SignatureProof<AccountProof, StorageProof> {
+	pub fn kind(&self) -> SignatureKind {
+		match self {
+			SignatureProof::Ecdsa(_) => SignatureKind::Ecdsa,
+			SignatureProof::Contract { .. } => SignatureKind::Contract,
+		}
+	}
+}
+
+/// Returns the hash signed by `personal_sign` for `data`.
+///
+/// ```
+/// use pallet_vector::attestation::message_hash;
+///
+/// // keccak256("\x19Ethereum Signed Message:\n5hello")
+/// let expected = hex_literal::hex!("50b2c43fd39106bafbba0da34fc430e1f91e3c96ea2acee2bc34119f92b37750");
+/// assert_eq!(expected, message_hash(b"hello").0);
+/// ```
+pub fn message_hash(data: &[u8]) -> H256 {
+	let mut message = PERSONAL_MESSAGE_PREFIX.to_vec();
+	message.extend_from_slice(&decimal(data.len()));
+	message.extend_from_slice(data);
+	H256(keccak_256(&message))
+}
+
+/// Returns the address of the account that signed `hash`, `None` if the signature is invalid.
+/// The recovery id `v` is either 0 or 1, or 27 or 28 as returned by `personal_sign`.
+pub fn recover_signer(hash: H256, signature: &[u8; 65]) -> Option<H160> {
+	let public = sp_io::crypto::secp256k1_ecdsa_recover(signature, &hash.0).ok()?;
+	Some(H160::from_slice(&keccak_256(&public)[12..]))
+}
+
+/// Returns the storage slot of the value at `hash` in the mapping at storage index
+/// `mapping_slot`.
+pub fn approval_slot(hash: H256, mapping_slot: H256) -> H256 {
+	let mut key = [0u8; 64];
+	key[..32].copy_from_slice(hash.as_bytes());
+	key[32..].copy_from_slice(mapping_slot.as_bytes());
+	H256(keccak_256(&key))
+}
+
+/// Returns the ASCII decimal digits of `value`.
+fn decimal(mut value: usize) -> Vec<u8> {
+	let mut digits = Vec::new();
+	loop {
+		digits.push(b'0' + (value % 10) as u8);
+		value /= 10;
+		if value == 0 {
+			break;
+		}
+	}
+	digits.reverse();
+	digits
+}
+
+#[cfg(test)]
+mod tests {
+	use hex_literal::hex;
+	use sp_core::{ecdsa, Pair, H160, H256};
+
+	use crate::attestation::{approval_slot, message_hash, recover_signer};
+
+	#[test]
+	fn ecdsa_signer_is_recovered() {
+		// the private key 1
+		let mut seed = [0u8; 32];
+		seed[31] = 1;
+		let pair = ecdsa::Pair::from_seed(&seed);
+		let hash = message_hash(b"hello");
+		let mut signature = [0u8; 65];
+		signature.copy_from_slice(pair.sign_prehashed(&hash.0).as_ref());
+
+		let signer = H160(hex!("7e5f4552091a69125d5dfcb7b8c2659029395bdf"));
+		assert_eq!(Some(signer), recover_signer(hash, &signature));
+
+		// `personal_sign` recovery ids
+		signature[64] += 27;
+		assert_eq!(Some(signer), recover_signer(hash, &signature));
+		assert_ne!(
+			Some(signer),
+			recover_signer(message_hash(b"hello!"), &signature)
+		);
+	}
+
+	#[test]
+	fn approval_slot_follows_mapping_layout() {
+		// keccak256(bytes32(0) ++ uint256(7))
+		assert_eq!(
+			H256(hex!(
+				"6d5257204ebe7d88fd91ae87941cb2dd9d8062b64ae5a2bd2d28ec40b9fbf6df"
+			)),
+			approval_slot(H256::zero(), H256::from_low_u64_be(7))
+		);
+	}
+}
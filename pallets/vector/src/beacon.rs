@@ -0,0 +1,411 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{pallet_prelude::ConstU32, BoundedVec};
+use scale_info::TypeInfo;
+use sp_core::{H160, H256};
+use ssz_rs::prelude::{is_valid_merkle_branch, Node};
+
+/// Depth of the execution state root below the beacon block header root.
+/// The header has 5 fields (depth 3) and the state root is at depth 9 in the body.
+pub const EXECUTION_STATE_ROOT_HEADER_DEPTH: u32 = 12;
+/// Generalized index of the execution state root below the beacon block header root:
+/// `body_root` (12) concatenated with the execution state root in the body (802).
+pub const EXECUTION_STATE_ROOT_HEADER_INDEX: usize = 6434;
+/// Generalized index of `body_root` in the beacon block header.
+const BODY_ROOT_INDEX: usize = 12;
+/// Depth of `body_root` in the beacon block header.
+const BODY_ROOT_DEPTH: usize = 3;
+/// Fields between the state root and the block number in the execution payload.
+const BLOCK_NUMBER_FIELD_OFFSET: usize = 4;
+/// Number of slots in a beacon chain epoch.
+pub const SLOTS_PER_EPOCH: u64 = 32;
+/// Address of the EIP-4788 beacon roots contract on the execution layer.
+pub const BEACON_ROOTS_ADDRESS: H160 = H160(hex_literal::hex!(
+	"000F3df6D732807Ef1319fB7B8bB8522d0Beac02"
+));
+/// Length of the ring buffers of the beacon roots contract, in seconds of block timestamps.
+pub const HISTORY_BUFFER_LENGTH: u64 = 8191;
+
+/// Consensus fork of the source chain, which defines where the execution state root is in the
+/// beacon block body.
+#[derive(
+	Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, PartialOrd, Ord, TypeInfo, MaxEncodedLen,
+)]
+pub enum ConsensusFork {
+	Bellatrix,
+	/// Adds the withdrawals root to the execution payload.
+	Capella,
+	/// Adds the blob gas fields to the execution payload, which no longer fits in depth 4.
+	Deneb,
+}
+
+impl ConsensusFork {
+	/// Forks from the oldest to the newest.
+	pub const ALL: [ConsensusFork; 3] = [
+		ConsensusFork::Bellatrix,
+		ConsensusFork::Capella,
+		ConsensusFork::Deneb,
+	];
+
+	/// Returns the depth and the generalized index of the execution state root in the body.
+	/// The execution payload is field 9 of the body, and the state root field 2 of the payload.
+	pub fn execution_state_root_gindex(self) -> (usize, usize) {
+		match self {
+			// The payload has at most 16 fields.
+			ConsensusFork::Bellatrix | ConsensusFork::Capella => (8, 402),
+			ConsensusFork::Deneb => (9, 802),
+		}
+	}
+
+	/// Returns the depth and the generalized index of the execution block number in the body.
+	/// The block number is field 6 of the payload.
+	pub fn execution_block_number_gindex(self) -> (usize, usize) {
+		let (depth, index) = self.execution_state_root_gindex();
+		(depth, index + BLOCK_NUMBER_FIELD_OFFSET)
+	}
+
+	/// Returns the depth and the generalized index of the execution state root in the header.
+	pub fn header_execution_state_root_gindex(self) -> (usize, usize) {
+		header_gindex(self.execution_state_root_gindex())
+	}
+
+	/// Returns the depth and the generalized index of the execution block number in the header.
+	pub fn header_execution_block_number_gindex(self) -> (usize, usize) {
+		header_gindex(self.execution_block_number_gindex())
+	}
+}
+
+/// Returns the depth and the generalized index in the header of a field of the body.
+fn header_gindex((depth, index): (usize, usize)) -> (usize, usize) {
+	(
+		BODY_ROOT_DEPTH + depth,
+		(BODY_ROOT_INDEX << depth) | (index - (1 << depth)),
+	)
+}
+
+/// Proof that an execution state root is part of a beacon block, as exposed to the
+/// execution layer by the EIP-4788 beacon roots contract.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct BeaconRootProof {
+	pub execution_state_root: H256,
+	pub branch: BoundedVec<H256, ConstU32<EXECUTION_STATE_ROOT_HEADER_DEPTH>>,
+}
+
+/// Verifies that the execution state root in `proof` is committed to by `header_root`, for a
+/// block of `fork`.
+pub fn verify_beacon_root_proof(
+	fork: ConsensusFork,
+	header_root: H256,
+	proof: &BeaconRootProof,
+) -> bool {
+	let (depth, index) = fork.header_execution_state_root_gindex();
+	verify_branch(
+		proof.execution_state_root,
+		&proof.branch,
+		depth,
+		index,
+		header_root,
+	)
+}
+
+/// Proof of the beacon block root kept by the EIP-4788 beacon roots contract for the execution
+/// block at `timestamp`: the account of the contract and, with a multiproof, its timestamp and
+/// root ring buffer entries.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo)]
+pub struct BeaconRootsProof<AccountProof, StorageProof> {
+	/// Timestamp of the execution block whose parent beacon block root is proven.
+	pub timestamp: u64,
+	pub account_proof: AccountProof,
+	pub storage_proof: StorageProof,
+}
+
+/// Returns the storage slots of the beacon roots contract keeping `timestamp` and the parent
+/// beacon block root of the execution block at `timestamp`.
+pub fn beacon_roots_slots(timestamp: u64) -> [H256; 2] {
+	let index = timestamp % HISTORY_BUFFER_LENGTH;
+	[
+		H256::from_low_u64_be(index),
+		H256::from_low_u64_be(index + HISTORY_BUFFER_LENGTH),
+	]
+}
+
+/// Returns the beacon block root of the entries read at `beacon_roots_slots(timestamp)`, `None`
+/// if they were overwritten by a block one ring buffer length later.
+pub fn kept_beacon_root(timestamp: u64, [stored_timestamp, root]: [H256; 2]) -> Option<H256> {
+	(stored_timestamp == H256::from_low_u64_be(timestamp)).then_some(root)
+}
+
+/// Proof that an execution block number is part of a beacon block.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct ExecutionBlockNumberProof {
+	pub block_number: u64,
+	pub branch: BoundedVec<H256, ConstU32<EXECUTION_STATE_ROOT_HEADER_DEPTH>>,
+}
+
+/// Verifies that the execution block number in `proof` is committed to by `header_root`, for a
+/// block of `fork`.
+pub fn verify_execution_block_number_proof(
+	fork: ConsensusFork,
+	header_root: H256,
+	proof: &ExecutionBlockNumberProof,
+) -> bool {
+	let (depth, index) = fork.header_execution_block_number_gindex();
+	verify_branch(
+		uint64_leaf(proof.block_number),
+		&proof.branch,
+		depth,
+		index,
+		header_root,
+	)
+}
+
+/// Returns the SSZ leaf of a `uint64`, little endian and zero padded.
+pub fn uint64_leaf(value: u64) -> H256 {
+	let mut leaf = [0u8; 32];
+	leaf[..8].copy_from_slice(&value.to_le_bytes());
+	H256(leaf)
+}
+
+/// Returns the root of the tree with `leaf` at the generalized `index`, proven by `branch`.
+pub fn branch_root(leaf: H256, branch: &[H256], index: usize) -> H256 {
+	let mut node = leaf;
+	for (depth, sibling) in branch.iter().enumerate() {
+		let mut pair = [0u8; 64];
+		if (index >> depth) & 1 == 1 {
+			pair[..32].copy_from_slice(sibling.as_bytes());
+			pair[32..].copy_from_slice(node.as_bytes());
+		} else {
+			pair[..32].copy_from_slice(node.as_bytes());
+			pair[32..].copy_from_slice(sibling.as_bytes());
+		}
+		node = H256(sp_io::hashing::sha2_256(&pair));
+	}
+	node
+}
+
+/// Verifies that `execution_state_root` is committed to by the `body_root` of a block of `fork`.
+pub fn verify_execution_state_root(
+	fork: ConsensusFork,
+	execution_state_root: H256,
+	branch: &[H256],
+	body_root: H256,
+) -> bool {
+	let (depth, index) = fork.execution_state_root_gindex();
+	verify_branch(execution_state_root, branch, depth, index, body_root)
+}
+
+fn verify_branch(leaf: H256, branch: &[H256], depth: usize, index: usize, root: H256) -> bool {
+	if branch.len() != depth {
+		return false;
+	}
+	let (Ok(leaf), Ok(root)) = (
+		Node::try_from(leaf.as_bytes()),
+		Node::try_from(root.as_bytes()),
+	) else {
+		return false;
+	};
+	let Ok(branch) = branch
+		.iter()
+		.map(|node| Node::try_from(node.as_bytes()))
+		.collect::<Result<sp_std::vec::Vec<_>, _>>()
+	else {
+		return false;
+	};
+
+	is_valid_merkle_branch(&leaf, branch.iter(), depth, index, &root)
+}
+
+#[cfg(test)]
+mod tests {
+	use frame_support::BoundedVec;
+	use sp_core::H256;
+
+	use crate::beacon::{
+		beacon_roots_slots, branch_root, kept_beacon_root, uint64_leaf, verify_beacon_root_proof,
+		verify_execution_block_number_proof, verify_execution_state_root, BeaconRootProof,
+		ConsensusFork, ExecutionBlockNumberProof, EXECUTION_STATE_ROOT_HEADER_DEPTH,
+		EXECUTION_STATE_ROOT_HEADER_INDEX,
+	};
+
+	/// Returns the root of a tree with `leaf` at `index` and the branch proving it.
+	fn branch_with_root(leaf: H256, depth: usize, index: usize) -> (H256, Vec<H256>) {
+		let branch: Vec<H256> = (0..depth).map(|i| H256::repeat_byte(i as u8)).collect();
+		(branch_root(leaf, &branch, index), branch)
+	}
+
+	fn proof_with_root() -> (H256, BeaconRootProof) {
+		let execution_state_root = H256::repeat_byte(0xaa);
+		let (node, branch) = branch_with_root(
+			execution_state_root,
+			EXECUTION_STATE_ROOT_HEADER_DEPTH as usize,
+			EXECUTION_STATE_ROOT_HEADER_INDEX,
+		);
+
+		let proof = BeaconRootProof {
+			execution_state_root,
+			branch: BoundedVec::truncate_from(branch),
+		};
+		(node, proof)
+	}
+
+	#[test]
+	fn beacon_root_proof_verifies() {
+		let (header_root, proof) = proof_with_root();
+		assert!(verify_beacon_root_proof(
+			ConsensusFork::Deneb,
+			header_root,
+			&proof
+		));
+	}
+
+	#[test]
+	fn beacon_root_proof_fails_for_wrong_state_root() {
+		let (header_root, mut proof) = proof_with_root();
+		proof.execution_state_root = H256::zero();
+		assert!(!verify_beacon_root_proof(
+			ConsensusFork::Deneb,
+			header_root,
+			&proof
+		));
+	}
+
+	#[test]
+	fn beacon_root_proof_fails_for_short_branch() {
+		let (header_root, mut proof) = proof_with_root();
+		proof.branch.pop();
+		assert!(!verify_beacon_root_proof(
+			ConsensusFork::Deneb,
+			header_root,
+			&proof
+		));
+	}
+
+	#[test]
+	fn beacon_root_proof_verifies_for_each_fork() {
+		for fork in ConsensusFork::ALL {
+			let (depth, index) = fork.header_execution_state_root_gindex();
+			let (header_root, branch) = branch_with_root(H256::repeat_byte(0xaa), depth, index);
+			let proof = BeaconRootProof {
+				execution_state_root: H256::repeat_byte(0xaa),
+				branch: BoundedVec::truncate_from(branch),
+			};
+
+			for other in ConsensusFork::ALL {
+				let same_layout =
+					fork.execution_state_root_gindex() == other.execution_state_root_gindex();
+				assert_eq!(
+					same_layout,
+					verify_beacon_root_proof(other, header_root, &proof)
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn execution_state_root_layout_of_each_fork() {
+		assert_eq!(
+			(8, 402),
+			ConsensusFork::Bellatrix.execution_state_root_gindex()
+		);
+		assert_eq!(
+			(8, 402),
+			ConsensusFork::Capella.execution_state_root_gindex()
+		);
+		assert_eq!((9, 802), ConsensusFork::Deneb.execution_state_root_gindex());
+		assert_eq!(
+			(11, 3218),
+			ConsensusFork::Capella.header_execution_state_root_gindex()
+		);
+		assert_eq!(
+			(
+				EXECUTION_STATE_ROOT_HEADER_DEPTH as usize,
+				EXECUTION_STATE_ROOT_HEADER_INDEX
+			),
+			ConsensusFork::Deneb.header_execution_state_root_gindex()
+		);
+	}
+
+	#[test]
+	fn execution_state_root_verifies_for_each_fork() {
+		let execution_state_root = H256::repeat_byte(0xaa);
+		let (depth, index) = ConsensusFork::Capella.execution_state_root_gindex();
+		let (body_root, branch) = branch_with_root(execution_state_root, depth, index);
+
+		assert!(verify_execution_state_root(
+			ConsensusFork::Capella,
+			execution_state_root,
+			&branch,
+			body_root
+		));
+		assert!(!verify_execution_state_root(
+			ConsensusFork::Deneb,
+			execution_state_root,
+			&branch,
+			body_root
+		));
+
+		let (depth, index) = ConsensusFork::Deneb.execution_state_root_gindex();
+		let (body_root, branch) = branch_with_root(execution_state_root, depth, index);
+		assert!(verify_execution_state_root(
+			ConsensusFork::Deneb,
+			execution_state_root,
+			&branch,
+			body_root
+		));
+		assert!(!verify_execution_state_root(
+			ConsensusFork::Bellatrix,
+			execution_state_root,
+			&branch,
+			body_root
+		));
+	}
+
+	#[test]
+	fn execution_block_number_proof_verifies_for_each_fork() {
+		assert_eq!(
+			(8, 406),
+			ConsensusFork::Capella.execution_block_number_gindex()
+		);
+		assert_eq!(
+			(9, 806),
+			ConsensusFork::Deneb.execution_block_number_gindex()
+		);
+
+		for fork in ConsensusFork::ALL {
+			let (depth, index) = fork.header_execution_block_number_gindex();
+			let (header_root, branch) = branch_with_root(uint64_leaf(19_000_000), depth, index);
+			let mut proof = ExecutionBlockNumberProof {
+				block_number: 19_000_000,
+				branch: BoundedVec::truncate_from(branch),
+			};
+			assert!(verify_execution_block_number_proof(
+				fork,
+				header_root,
+				&proof
+			));
+
+			proof.block_number += 1;
+			assert!(!verify_execution_block_number_proof(
+				fork,
+				header_root,
+				&proof
+			));
+		}
+	}
+
+	#[test]
+	fn beacon_roots_slots_wrap_around_the_ring_buffer() {
+		assert_eq!(
+			[H256::from_low_u64_be(12), H256::from_low_u64_be(8203)],
+			beacon_roots_slots(12)
+		);
+		assert_eq!(beacon_roots_slots(12), beacon_roots_slots(12 + 8191));
+	}
+
+	#[test]
+	fn kept_beacon_root_fails_for_overwritten_entry() {
+		let entries = [H256::from_low_u64_be(12), H256::repeat_byte(1)];
+
+		assert_eq!(Some(H256::repeat_byte(1)), kept_beacon_root(12, entries));
+		assert_eq!(None, kept_beacon_root(12 + 8191, entries));
+	}
+}
@@ -1,20 +1,52 @@
 use crate::{
-	state::Configuration, BalanceOf, Call, Config, ConfigurationStorage, ExecutionStateRoots,
-	FunctionIds, FunctionInput, FunctionOutput, FunctionProof, Headers, Pallet,
-	RotateVerificationKey, StepVerificationKey, Updater, ValidProof,
+	abi::{AbiType, MessageSchema, MAX_SCHEMA_FIELDS},
+	address::{AddressVersion, SourceAddress},
+	archive::{
+		self, ArchiveProof, ArchivedEpoch, ArchivedSlotList, EpochSlotList,
+		MAX_ARCHIVED_SLOTS_PER_BLOCK, MAX_ARCHIVE_PROOF_DEPTH, MAX_SLOTS_PER_EPOCH,
+	},
+	attestation::{self, SignatureProof},
+	beacon::{self, ConsensusFork, ExecutionBlockNumberProof},
+	compression,
+	finality::FinalityConfig,
+	outbound::{OutboundMessageList, MAX_OUTBOUND_MESSAGES},
+	rollup::{RollupConfig, RollupLayout},
+	state::{
+		BaseFeeOracle, BridgeStatus, Configuration, ExecutionFee, ExecutionPermit, GovernanceCall,
+		HandlerRegistration, ParticipationRequirement, ReleaseSchedule, SourceBaseFee,
+		UpdaterQuota, VerifiedAccount, VestedRelease,
+	},
+	storage_utils::get_storage_value,
+	AbsentMessages, AcknowledgedSenders, AppChainRoutes, ArchivedEpochs, ArchivedSlots,
+	Attestations, BalanceOf, BaseFeeOracles, BeaconRootDomains, Call, Config, ConfigurationStorage,
+	DestinationHandlers, DomainAliases, EpochSlots, ExecutionBlockNumbers, ExecutionFees,
+	ExecutionStateRoots, Finality, ForkActivations, ForkEpochs, ForkTransitions, FunctionIds,
+	FunctionInput, FunctionInputs, FunctionOutput, FunctionProof, GovernanceCalls,
+	GovernanceSender, Head, Headers, LightClientState, LowParticipationStreak, MessageLengthLimits,
+	MessageSchemas, NextArchiveEpoch, OrderedChannels, OutboundMessages, OutboundMessagesRoot,
+	Pallet, ParticipationRequirements, PayoutSenders, PinnedCodeHashes, ProofRetention,
+	QueuedMessages, ReleaseSchedules, ReorgRecovery, RollupConfigs, RotateVerificationKey,
+	ScheduledFunctionIds, SourceBaseFees, Status, StepVerificationKey, SyncCommitteeHashes,
+	Timestamps, Updaters, ValidProof, VerifiedAccountProofs, VerifiedInputs, VestedReleases,
+	VoidedMessages, WhitelistedDomains, MAX_BACKFILLED_PERIODS, MAX_LIGHT_CLIENT_STATE_LENGTH,
+	MAX_VERIFIED_ACCOUNTS, MAX_VERIFIED_INPUTS,
 };
 use avail_core::data_proof::BOUNDED_DATA_MAX_LENGTH;
 use avail_core::data_proof::{AddressedMessage, Message};
+use codec::Encode;
 use frame_benchmarking::{
 	impl_benchmark_test_suite, v2::benchmarks, whitelisted_caller, BenchmarkError,
 };
-use frame_support::traits::DefensiveTruncateFrom;
-use frame_support::{traits::Currency, BoundedVec};
-use frame_system::RawOrigin;
+use frame_support::traits::{DefensiveTruncateFrom, EnsureOrigin};
+use frame_support::{traits::Currency, weights::Weight, BoundedVec};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use hex_literal::hex;
-use sp_core::{Get, H256};
+use patricia_merkle_trie::StorageProof;
+use sp_core::{Get, H160, H256, U256};
+use sp_io::hashing::{blake2_256, keccak_256};
 use sp_runtime::traits::Bounded;
-use sp_std::vec;
+use sp_runtime::{MultiSignature, Perbill};
+use sp_std::{boxed::Box, vec, vec::Vec};
 
 const ACCOUNT1: [u8; 32] = [2u8; 32];
 pub const STEP_FUNCTION_ID: H256 = H256(hex!(
@@ -273,7 +305,7 @@ mod benchmarks {
 
 		Pallet::<T>::set_poseidon_hash(RawOrigin::Root.into(), 931, hash).unwrap();
 
-		Updater::<T>::set(H256(ACCOUNT1));
+		Updaters::<T>::insert(H256(ACCOUNT1), UpdaterQuota::default());
 		ConfigurationStorage::<T>::set(Configuration {
 			slots_per_period: 8192,
 			finality_threshold: 461,
@@ -314,7 +346,7 @@ mod benchmarks {
 
 		Pallet::<T>::set_poseidon_hash(RawOrigin::Root.into(), 931, hash).unwrap();
 
-		Updater::<T>::set(H256(ACCOUNT1));
+		Updaters::<T>::insert(H256(ACCOUNT1), UpdaterQuota::default());
 		ConfigurationStorage::<T>::set(Configuration {
 			slots_per_period: 8192,
 			finality_threshold: 342,
@@ -375,8 +407,8 @@ mod benchmarks {
 		T::Currency::make_free_balance_be(&account, BalanceOf::<T>::max_value() / 2u32.into());
 		T::Currency::make_free_balance_be(&pot, BalanceOf::<T>::max_value() / 2u32.into());
 
-		let account_proof = get_valid_account_proof();
-		let storage_proof = get_valid_storage_proof();
+		let account_proof = get_valid_account_proof::<T>();
+		let storage_proof = get_valid_storage_proof::<T>();
 		let message = get_valid_message();
 		let account = T::AccountId::from(ACCOUNT1);
 		let origin = RawOrigin::Signed(account.clone());
@@ -414,8 +446,8 @@ mod benchmarks {
 			BalanceOf::<T>::max_value() / 2u32.into(),
 		);
 
-		let account_proof = get_valid_amb_account_proof();
-		let storage_proof = get_valid_amb_storage_proof();
+		let account_proof = get_valid_amb_account_proof::<T>();
+		let storage_proof = get_valid_amb_storage_proof::<T>();
 		let message = get_valid_amb_message();
 		#[extrinsic_call]
 		// amount in message 1000000000000000000
@@ -435,12 +467,18 @@ mod benchmarks {
 	}
 
 	#[benchmark]
-	fn set_updater() -> Result<(), BenchmarkError> {
+	fn schedule_function_ids() -> Result<(), BenchmarkError> {
 		let origin = RawOrigin::Root;
+		let activation_block = frame_system::Pallet::<T>::block_number() + 10u32.into();
 
 		#[extrinsic_call]
-		_(origin, H256(ACCOUNT1));
+		_(
+			origin,
+			Some((STEP_FUNCTION_ID, ROTATE_FUNCTION_ID)),
+			activation_block,
+		);
 
+		assert!(ScheduledFunctionIds::<T>::get().is_some());
 		Ok(())
 	}
 
@@ -466,6 +504,879 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_source_address_version() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+
+		#[extrinsic_call]
+		_(origin, 2, AddressVersion::Bytes32);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn archive_epoch(s: Linear<0, MAX_ARCHIVED_SLOTS_PER_BLOCK>) -> Result<(), BenchmarkError> {
+		// The last slots of a full epoch are archived, with the root over all its slots.
+		let archived = MAX_SLOTS_PER_EPOCH - s;
+		let leaf = (0, H256::repeat_byte(1), H256::repeat_byte(2), 0);
+		ArchivedSlots::<T>::put(ArchivedSlotList::truncate_from(vec![
+			leaf;
+			archived as usize
+		]));
+		let slots: Vec<u64> = (archived as u64..MAX_SLOTS_PER_EPOCH as u64).collect();
+		for slot in slots.iter() {
+			Headers::<T>::insert(slot, H256::repeat_byte(1));
+			ExecutionStateRoots::<T>::insert(slot, H256::repeat_byte(2));
+			Timestamps::<T>::insert(slot, 1);
+		}
+		EpochSlots::<T>::insert(0, EpochSlotList::truncate_from(slots));
+		NextArchiveEpoch::<T>::set(Some(0));
+		Head::<T>::set(T::ArchiveEpochSlots::get() + T::SlotRetention::get());
+
+		#[block]
+		{
+			Pallet::<T>::archive_next_epoch(Weight::MAX);
+		}
+
+		assert!(ArchivedEpochs::<T>::get(0).is_some());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn verify_archive_proof(d: Linear<0, MAX_ARCHIVE_PROOF_DEPTH>) -> Result<(), BenchmarkError> {
+		// Every sibling is hashed on the path to the root, whether the proof is valid or not.
+		let epoch = ArchivedEpoch {
+			root: H256::repeat_byte(1),
+			leaves: u32::MAX,
+		};
+		let proof = ArchiveProof {
+			header_root: H256::repeat_byte(2),
+			execution_state_root: H256::repeat_byte(3),
+			participation: 0,
+			leaf_index: 0,
+			siblings: BoundedVec::truncate_from(vec![H256::repeat_byte(4); d as usize]),
+		};
+		let result;
+
+		#[block]
+		{
+			result = archive::verify_archive_proof(&epoch, 0, &proof);
+		}
+
+		assert!(!result);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn fulfill_call_cached() -> Result<(), BenchmarkError> {
+		Updaters::<T>::insert(H256(ACCOUNT1), UpdaterQuota::default());
+		let account = T::AccountId::from(ACCOUNT1);
+		let origin = RawOrigin::Signed(account);
+
+		let inputs = vec![1u8; 1024];
+		let input_hash = H256(keccak_256(inputs.as_slice()));
+		let expires_at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		VerifiedInputs::<T>::set(BoundedVec::truncate_from(vec![
+			(H256::zero(), expires_at);
+			MAX_VERIFIED_INPUTS as usize
+				- 1
+		]));
+		VerifiedInputs::<T>::append((input_hash, expires_at));
+
+		#[extrinsic_call]
+		fulfill_call(origin, H256::zero(), inputs);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_beacon_root_domain() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+
+		#[extrinsic_call]
+		_(origin, 2, true);
+
+		assert!(BeaconRootDomains::<T>::get(2));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn prove_message_absence() -> Result<(), BenchmarkError> {
+		let slot = 8581263;
+		Broadcasters::<T>::set(
+			2,
+			H256(hex!(
+				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+			)),
+		);
+		ExecutionStateRoots::<T>::set(
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+		AbsentMessages::<T>::insert(2, 1, slot - 1);
+
+		let account_proof = get_valid_account_proof::<T>();
+		let storage_proof = get_valid_storage_proof::<T>();
+		let origin = RawOrigin::Signed(T::AccountId::from(ACCOUNT1));
+
+		#[extrinsic_call]
+		// the valid storage proof is for message id 0
+		_(origin, slot, 2, 1, account_proof, storage_proof);
+
+		assert_eq!(AbsentMessages::<T>::get(2, 1), Some(slot));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn restore_bridge() -> Result<(), BenchmarkError> {
+		let origin =
+			T::DegradedOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Status::<T>::set(BridgeStatus::Degraded);
+		LowParticipationStreak::<T>::set(3);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin);
+
+		assert_eq!(Status::<T>::get(), BridgeStatus::Operational);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_updater() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let updater = H256::repeat_byte(1);
+
+		#[extrinsic_call]
+		_(origin, updater, Some(10));
+
+		assert!(Updaters::<T>::contains_key(updater));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_updater() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let updater = H256::repeat_byte(1);
+		Updaters::<T>::insert(updater, UpdaterQuota::default());
+
+		#[extrinsic_call]
+		_(origin, updater);
+
+		assert!(!Updaters::<T>::contains_key(updater));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_message_schema() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let to = H256::repeat_byte(1);
+		let schema: MessageSchema =
+			BoundedVec::truncate_from(vec![AbiType::Bytes; MAX_SCHEMA_FIELDS as usize]);
+
+		#[extrinsic_call]
+		_(origin, to, Some(schema.clone()));
+
+		assert_eq!(MessageSchemas::<T>::get(to), Some(schema));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_ordered_channel() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let from = H256::repeat_byte(1);
+
+		#[extrinsic_call]
+		_(origin, 2, from, Some(1));
+
+		assert_eq!(OrderedChannels::<T>::get(2, from), Some(1));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn skip_ordered_message() -> Result<(), BenchmarkError> {
+		let slot = 8581263;
+		Broadcasters::<T>::set(
+			2,
+			H256(hex!(
+				"DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95000000000000000000000000"
+			)),
+		);
+		ExecutionStateRoots::<T>::set(
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+		let channel_from = H256::repeat_byte(1);
+		OrderedChannels::<T>::insert(2, channel_from, 0);
+
+		let account_proof = get_valid_account_proof::<T>();
+		let storage_proof = get_valid_storage_proof::<T>();
+		let message = get_valid_message();
+		let origin = RawOrigin::Signed(T::AccountId::from(ACCOUNT1));
+
+		#[extrinsic_call]
+		_(
+			origin,
+			slot,
+			channel_from,
+			message,
+			account_proof,
+			storage_proof,
+		);
+
+		assert_eq!(OrderedChannels::<T>::get(2, channel_from), Some(1));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_acknowledged_sender() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let from = H256::repeat_byte(1);
+
+		#[extrinsic_call]
+		_(origin, 2, from, true);
+
+		assert!(AcknowledgedSenders::<T>::get(2, from));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn migrate_domain() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let message = get_valid_message();
+		let from = message.from;
+		WhitelistedDomains::<T>::put(BoundedVec::truncate_from(vec![2]));
+		Broadcasters::<T>::insert(2, H256::repeat_byte(1));
+		AbsentMessages::<T>::insert(2, 0, 1);
+		AcknowledgedSenders::<T>::insert(2, from, true);
+		OrderedChannels::<T>::insert(2, from, 0);
+		QueuedMessages::<T>::insert((2, from), 1, message);
+		DomainAliases::<T>::insert(7, 2);
+
+		#[extrinsic_call]
+		_(origin, 2, 3);
+
+		assert_eq!(Some(3), DomainAliases::<T>::get(7));
+		assert!(QueuedMessages::<T>::contains_key((3, from), 1));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn schedule_fork_transition() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let message = get_valid_message();
+		let from = message.from;
+		WhitelistedDomains::<T>::put(BoundedVec::truncate_from(vec![2]));
+		Broadcasters::<T>::insert(2, H256::repeat_byte(1));
+		AbsentMessages::<T>::insert(2, 0, 1);
+		AcknowledgedSenders::<T>::insert(2, from, true);
+		OrderedChannels::<T>::insert(2, from, 0);
+		QueuedMessages::<T>::insert((2, from), 1, message);
+		DomainAliases::<T>::insert(7, 2);
+
+		#[extrinsic_call]
+		_(origin, 2, 3, 100, H256::repeat_byte(2));
+
+		assert_eq!(Some(100), ForkActivations::<T>::get(3));
+		assert_eq!(
+			Some(H256::repeat_byte(1)),
+			ForkTransitions::<T>::get(2).map(|transition| transition.broadcaster)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn backfill_sync_committees(
+		p: Linear<0, MAX_BACKFILLED_PERIODS>,
+	) -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		ConfigurationStorage::<T>::set(Configuration {
+			slots_per_period: 8192,
+			finality_threshold: 342,
+		});
+		// the rotate proof of period 1178 rotates into the committee of the checkpoint, the
+		// proofs of the periods before it are verified before the chain is found broken
+		let checkpoint_period = 1179;
+		let checkpoint_hash = U256::from_dec_str(
+			"78004113044439342907882478475913997887515213797155324584820998418219758944903",
+		)
+		.unwrap();
+		SyncCommitteeHashes::<T>::insert(checkpoint_period, checkpoint_hash);
+		let inputs = include_bytes!("../examples/rotate_call.cbor").to_vec();
+		let proofs = BoundedVec::truncate_from(vec![inputs; p as usize]);
+
+		let result;
+		#[block]
+		{
+			result = Pallet::<T>::backfill_sync_committees(
+				origin.into(),
+				checkpoint_period,
+				checkpoint_hash,
+				proofs,
+			);
+		}
+
+		assert_eq!(p <= 1, result.is_ok());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_base_fee_oracle() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let oracle = BaseFeeOracle {
+			address: H160::repeat_byte(1),
+			slot: H256::repeat_byte(2),
+		};
+
+		#[extrinsic_call]
+		_(origin, 2, Some(oracle));
+
+		assert_eq!(BaseFeeOracles::<T>::get(2), Some(oracle));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn update_source_base_fee() -> Result<(), BenchmarkError> {
+		let slot = 8581263;
+		ExecutionStateRoots::<T>::set(
+			slot,
+			H256(hex!(
+				"c42310d65b1e953e8864480367a03179d6bd78d4ca522a5a977d2801b9b2e1d9"
+			)),
+		);
+		// the valid proofs are for the slot of message id 0 of the broadcaster
+		let mut preimage = [0u8; 64];
+		U256::from(T::MessageMappingStorageIndex::get()).to_big_endian(&mut preimage[32..]);
+		BaseFeeOracles::<T>::insert(
+			2,
+			BaseFeeOracle {
+				address: H160(hex!("DC3542b6fcC39dC0d51ecdCbc6Fbb130D5e48d95")),
+				slot: H256(keccak_256(&preimage)),
+			},
+		);
+		SourceBaseFees::<T>::insert(
+			2,
+			SourceBaseFee {
+				slot: slot - 1,
+				base_fee: U256::one(),
+			},
+		);
+
+		let account_proof = get_valid_account_proof::<T>();
+		let storage_proof = get_valid_storage_proof::<T>();
+		let origin = RawOrigin::Signed(T::AccountId::from(ACCOUNT1));
+
+		#[extrinsic_call]
+		_(origin, slot, 2, account_proof, storage_proof);
+
+		assert_eq!(SourceBaseFees::<T>::get(2).map(|fee| fee.slot), Some(slot));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_reorg_recovery() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+
+		#[extrinsic_call]
+		_(origin, true);
+
+		assert!(ReorgRecovery::<T>::get());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn rewind_head(s: Linear<0, MAX_SLOTS_PER_EPOCH>) -> Result<(), BenchmarkError> {
+		let rewound = T::ArchiveEpochSlots::get();
+		let slots: Vec<u64> = (1..=s as u64).map(|i| rewound + i).collect();
+		for slot in slots.iter() {
+			Headers::<T>::insert(slot, H256::repeat_byte(1));
+			ExecutionStateRoots::<T>::insert(slot, H256::repeat_byte(2));
+			Timestamps::<T>::insert(slot, 1);
+		}
+		EpochSlots::<T>::insert(1, EpochSlotList::truncate_from(slots));
+		Head::<T>::set(rewound + s as u64 + 1);
+		ReorgRecovery::<T>::set(true);
+		let state = vec![1u8; MAX_LIGHT_CLIENT_STATE_LENGTH as usize];
+		LightClientState::<T>::set(Some(BoundedVec::truncate_from(state)));
+		let origin = RawOrigin::Root;
+
+		#[extrinsic_call]
+		_(
+			origin,
+			rewound,
+			H256::repeat_byte(3),
+			H256::repeat_byte(4),
+			s,
+		);
+
+		assert_eq!(Head::<T>::get(), rewound);
+		assert_eq!(ExecutionStateRoots::<T>::get(rewound), H256::repeat_byte(4));
+		assert!(LightClientState::<T>::get().is_none());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_participation_requirement() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let to = H256::repeat_byte(1);
+		let requirement = ParticipationRequirement {
+			min_participation: 461,
+			min_amount: 1,
+		};
+
+		#[extrinsic_call]
+		_(origin, to, Some(requirement));
+
+		assert_eq!(ParticipationRequirements::<T>::get(to), Some(requirement));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_execution_fee() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let asset_id = H256::zero();
+		let fee = ExecutionFee {
+			rate: Perbill::from_percent(1),
+			min_fee: 1,
+			max_fee: u128::MAX,
+		};
+
+		#[extrinsic_call]
+		_(origin, asset_id, Some(fee));
+
+		assert_eq!(ExecutionFees::<T>::get(asset_id), Some(fee));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_fork_epoch() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		// The epoch is checked against the epochs of the other forks.
+		ForkEpochs::<T>::insert(ConsensusFork::Bellatrix, 144_896);
+		ForkEpochs::<T>::insert(ConsensusFork::Deneb, 269_568);
+
+		#[extrinsic_call]
+		_(origin, ConsensusFork::Capella, Some(194_048));
+
+		assert_eq!(ForkEpochs::<T>::get(ConsensusFork::Capella), Some(194_048));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn verify_execution_permit() -> Result<(), BenchmarkError> {
+		// ECDSA is the most expensive scheme to verify as the public key is recovered.
+		let recipient = H256(hex!(
+			"92069870c185d38acc5bc10768ff564e7676176ba6db19c08753fa9a16bb65f1"
+		));
+		let permit = ExecutionPermit {
+			message_root: H256::repeat_byte(1),
+			tip: 1_000,
+			deadline: u32::MAX,
+		};
+		let signature = MultiSignature::Ecdsa(sp_core::ecdsa::Signature::from_raw(hex!(
+			"ba35d6cb71fca5a44af2225fe43a97213c41c1f55da928173cf6c102acae46885835f92d444772b01fdd4e8ce7b9b0966c38de1c2fe3ab89615b5c1292f35da500"
+		)));
+		let valid;
+
+		#[block]
+		{
+			valid = permit.is_signed_by(recipient, &signature);
+		}
+
+		assert!(valid);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn verify_proof(n: Linear<1, 32>, b: Linear<32, 65_536>) -> Result<(), BenchmarkError> {
+		// Hashing and loading the nodes is the cost growing with the size of the proof. The
+		// walk of the trie stops right away, at the unknown root.
+		let node_size = (b / n).clamp(1, T::MaxProofNodeSize::get()) as usize;
+		let proof: Vec<Vec<u8>> = (0..n).map(|i| vec![i as u8; node_size]).collect();
+		let result;
+
+		#[block]
+		{
+			result =
+				get_storage_value::<T::SourceChainTrieLayout>(H256::zero(), H256::zero(), proof);
+		}
+
+		assert!(result.is_err());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn hash_proof_nodes(n: Linear<1, 32>, b: Linear<32, 65_536>) -> Result<(), BenchmarkError> {
+		// Each node is hashed by its own host call, as it is inserted in the database.
+		let node_size = (b / n).clamp(1, T::MaxProofNodeSize::get()) as usize;
+		let proof: Vec<Vec<u8>> = (0..n).map(|i| vec![i as u8; node_size]).collect();
+		let db;
+
+		#[block]
+		{
+			db = StorageProof::new(proof).into_memory_db::<T::SourceChainHasher>();
+		}
+
+		assert_eq!(db.keys().len(), n as usize);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn decompress_proof(b: Linear<32, 65_536>) -> Result<(), BenchmarkError> {
+		// Repeating data decompresses through the back references of the frame, the slowest
+		// path per decompressed byte.
+		let data: Vec<u8> = (0..b).map(|i| (i * i % 251) as u8).collect();
+		let compressed = compression::compress(&data);
+		let result;
+
+		#[block]
+		{
+			result = compression::decompress(&compressed, b as usize);
+		}
+
+		assert_eq!(result, Ok(data));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn commit_outbound_root(m: Linear<0, MAX_OUTBOUND_MESSAGES>) -> Result<(), BenchmarkError> {
+		let message_roots = (0..m).map(|i| H256::from_low_u64_be(i.into())).collect();
+		OutboundMessages::<T>::put(OutboundMessageList::truncate_from(message_roots));
+
+		#[block]
+		{
+			Pallet::<T>::commit_outbound_root();
+		}
+
+		assert_eq!(OutboundMessagesRoot::<T>::get().is_some(), m > 0);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_pinned_code_hash() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let code_hash = H256::repeat_byte(1);
+
+		#[extrinsic_call]
+		_(origin, 2, Some(code_hash));
+
+		assert_eq!(PinnedCodeHashes::<T>::get(2), Some(code_hash));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_app_chain_route() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+
+		#[extrinsic_call]
+		_(origin, 3, true);
+
+		assert!(AppChainRoutes::<T>::get(3));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn fulfill_call_stale() -> Result<(), BenchmarkError> {
+		Updaters::<T>::insert(H256(ACCOUNT1), UpdaterQuota::default());
+		let account = T::AccountId::from(ACCOUNT1);
+		let origin = RawOrigin::Signed(account);
+		ConfigurationStorage::<T>::set(Configuration {
+			slots_per_period: 8192,
+			finality_threshold: 342,
+		});
+
+		// the step is behind the head and the sync committee of its period is not stored
+		let inputs = include_bytes!("../examples/step_call.cbor").to_vec();
+		let step: FunctionInputs = serde_cbor::from_slice(&inputs).unwrap();
+		Head::<T>::set(step.finality_update.finalized_header.slot.as_u64() + 1);
+
+		let result;
+		#[block]
+		{
+			result = Pallet::<T>::fulfill_call(origin.into(), H256::zero(), inputs);
+		}
+
+		assert!(result.is_err());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_message_length_limit() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let to = H256::repeat_byte(1);
+
+		#[extrinsic_call]
+		_(origin, to, Some(1024));
+
+		assert_eq!(MessageLengthLimits::<T>::get(to), Some(1024));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn void_message() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let message_root = H256::repeat_byte(1);
+
+		#[extrinsic_call]
+		_(origin, message_root);
+
+		assert!(VoidedMessages::<T>::contains_key(message_root));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_rollup_config() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let config = RollupConfig {
+			contract: H160::repeat_byte(1),
+			layout: RollupLayout::Optimism {
+				outputs_slot: H256::from_low_u64_be(3),
+				finalization_period: 604_800,
+			},
+		};
+
+		#[extrinsic_call]
+		_(origin, 3, Some(config));
+
+		assert_eq!(RollupConfigs::<T>::get(3), Some(config));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_finality_config() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let config = FinalityConfig::Epoch {
+			genesis_time: 1_606_824_023,
+			seconds_per_slot: 12,
+			slots_per_epoch: 32,
+		};
+
+		#[extrinsic_call]
+		_(origin, config);
+
+		assert_eq!(Finality::<T>::get(), config);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_proof_retention() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let retention: BlockNumberFor<T> = 100_800u32.into();
+
+		#[extrinsic_call]
+		_(origin, Some(retention));
+
+		assert_eq!(ProofRetention::<T>::get(), Some(retention));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_release_schedule() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let asset_id = H256::zero();
+		let schedule = ReleaseSchedule {
+			threshold: 1_000_000_000_000_000_000_000,
+			duration: 100_800,
+		};
+
+		#[extrinsic_call]
+		_(origin, asset_id, Some(schedule));
+
+		assert_eq!(ReleaseSchedules::<T>::get(asset_id), Some(schedule));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn release_vested() -> Result<(), BenchmarkError> {
+		let caller = whitelisted_caller::<T::AccountId>();
+		let pot = T::AccountId::from(T::PalletId::get().into_account_truncating());
+		T::Currency::make_free_balance_be(&pot, BalanceOf::<T>::max_value() / 2u32.into());
+		let message_root = H256::repeat_byte(1);
+		// Half of the amount is released, the rest stays vested.
+		VestedReleases::<T>::insert(
+			message_root,
+			VestedRelease {
+				recipient: H256(ACCOUNT1),
+				amount: 2_000_000_000_000_000_000,
+				released: 0,
+				start: 0,
+				duration: 100,
+			},
+		);
+		frame_system::Pallet::<T>::set_block_number(50u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), message_root);
+
+		let vested = VestedReleases::<T>::get(message_root).unwrap();
+		assert_eq!(vested.released, 1_000_000_000_000_000_000);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_payout_sender() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let from = H256::repeat_byte(1);
+
+		#[extrinsic_call]
+		_(origin, 2, from, true);
+
+		assert!(PayoutSenders::<T>::get(2, from));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn attest_signature() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let origin = RawOrigin::Signed(caller);
+		// `personal_sign` of "hello" by the private key 1
+		let signer = H160(hex!("7e5f4552091a69125d5dfcb7b8c2659029395bdf"));
+		let signature = SignatureProof::Ecdsa(hex!(
+			"bb50e2d89a4ed70663d080659fe0ad4b9bc3e06c17a227433966cb59ceee020d4e9612c28c317102d1fc08e13514a54dddeeea31622a5686b3f886e305d14bfd1c"
+		));
+		let data = BoundedVec::truncate_from(b"hello".to_vec());
+
+		#[extrinsic_call]
+		_(origin, signer, data, signature);
+
+		let message_hash = attestation::message_hash(b"hello");
+		assert!(Attestations::<T>::contains_key(signer, message_hash));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn prove_execution_block_number() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let origin = RawOrigin::Signed(caller);
+		let slot = 8_000_000;
+		let block_number = 19_000_000;
+		let (depth, index) =
+			Pallet::<T>::consensus_fork(slot).header_execution_block_number_gindex();
+		let branch = vec![H256::repeat_byte(1); depth];
+		let header_root = beacon::branch_root(beacon::uint64_leaf(block_number), &branch, index);
+		Headers::<T>::insert(slot, header_root);
+		let proof = ExecutionBlockNumberProof {
+			block_number,
+			branch: BoundedVec::truncate_from(branch),
+		};
+
+		#[extrinsic_call]
+		_(origin, slot, proof);
+
+		assert_eq!(Some(block_number), ExecutionBlockNumbers::<T>::get(slot));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn register_destination_handler() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let destination = H256::repeat_byte(1);
+
+		#[extrinsic_call]
+		_(origin, destination, 1, Some(1_000));
+
+		assert_eq!(
+			Some(1),
+			DestinationHandlers::<T>::get(destination).map(|registration| registration.handler)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn deregister_destination_handler() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let destination = H256::repeat_byte(1);
+		DestinationHandlers::<T>::insert(
+			destination,
+			HandlerRegistration {
+				handler: 1,
+				code_hash: H256::repeat_byte(2),
+				expiry: None,
+			},
+		);
+
+		#[extrinsic_call]
+		_(origin, destination);
+
+		assert!(!DestinationHandlers::<T>::contains_key(destination));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_governance_sender() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let sender = Some((2, H256::repeat_byte(1)));
+
+		#[extrinsic_call]
+		_(origin, sender);
+
+		assert_eq!(sender, GovernanceSender::<T>::get());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_governance_call() -> Result<(), BenchmarkError> {
+		let origin = RawOrigin::Root;
+		let message_root = H256::repeat_byte(1);
+		let scheduled = GovernanceCall {
+			call_hash: H256::repeat_byte(2),
+			executable_at: 100,
+		};
+		GovernanceCalls::<T>::insert(message_root, scheduled);
+
+		#[extrinsic_call]
+		_(origin, message_root);
+
+		assert!(!GovernanceCalls::<T>::contains_key(message_root));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn execute_governance_call() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let origin = RawOrigin::Signed(caller);
+		let message_root = H256::repeat_byte(1);
+		// The weight of the dispatched call is added to the one of `execute_governance_call`.
+		let call = Call::<T>::set_proof_retention { retention: None };
+		let scheduled = GovernanceCall {
+			call_hash: H256(blake2_256(&call.encode())),
+			executable_at: 0,
+		};
+		GovernanceCalls::<T>::insert(message_root, scheduled);
+
+		#[extrinsic_call]
+		_(origin, message_root, Box::new(call));
+
+		assert!(!GovernanceCalls::<T>::contains_key(message_root));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn verified_account_proof() -> Result<(), BenchmarkError> {
+		// The looked up account is the last one of a full list.
+		let root = H256::repeat_byte(1);
+		let accounts = (0..MAX_VERIFIED_ACCOUNTS).map(|i| VerifiedAccount {
+			state_root: root,
+			address: H160::from_low_u64_be(i.into()).into(),
+			storage_root: H256::repeat_byte(2),
+			code_hash: H256::repeat_byte(3),
+		});
+		VerifiedAccountProofs::<T>::put(BoundedVec::truncate_from(accounts.collect()));
+		let address = SourceAddress::Evm(H160::from_low_u64_be((MAX_VERIFIED_ACCOUNTS - 1).into()));
+		let result;
+
+		#[block]
+		{
+			result = Pallet::<T>::verified_account(root, address, Vec::new());
+		}
+
+		assert_eq!(Ok(address), result.map(|account| account.address));
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
 
@@ -500,7 +1411,7 @@ fn get_valid_amb_message() -> AddressedMessage {
 	}
 }
 
-fn get_valid_account_proof() -> ValidProof {
+fn get_valid_account_proof<T: Config>() -> ValidProof<T> {
 	BoundedVec::truncate_from(vec![
         BoundedVec::truncate_from(hex!("f90211a00b9369252d91d5240966845819a5e73a8e7b4b531b9f66bd652df2ef25999e8fa00fbedaed13519e0d8971ca3e8f54fa6250b8c60ae522924ea5b7bc54d7ab049ca0e9a41917172ecd79fbbc0db3c117a990464df2767be84bc5fd749cb50763834fa0420cf2bd2e7aac8cc33b0731d7546b738315ac482524aebc60e39a7d0359ce84a007e78eae37bbc3d25305685e8321582390d9631365f5c40612d111c7c1d2a35aa0f90094ebbf4d71dc00d9ff469a149d62529289b7a88d63f69ef73c45ffadd9b8a0bb5c4d709b179a0dec8c3e298249746c5f36f1ab4c7bb0985e4c565bfa96efd2a0b44d9462647f7f99afe5396fc6c049ca3d343748d9eb27f64e118a4d55d26d2ba08119035469c78ffe7336ba8c0117906ca64c9827f16ef71b5fcd99272d983779a067a02fa9917bda0acfb3646ba7e91c7e54e56e40342c552c0b069f949a64e122a0d2b39ca49bbaf2582e8c48243c86249ef034d14f8272952975c1f01773d508c4a0cd89136cd6f4203f52553a6856ba48ee08fe3d0165b5d020b4781ec0b0195bfda0bfdc0af814301e39e11e3c2186b3969b5c6ffdcb627d99f2e1e425d48300253ea0689a72bc1f0084b35849c938c2298e0d90384a071b6ef7bb145dab1b1205efffa0614a4f66f2451debf003b8584d9c2bbb87ee1d141cb240b494a840a91d32e126a01885c00eb4d6365b341f3c21bc44754f901ff56e957399069209b612c272a97880").to_vec()),
         BoundedVec::truncate_from(hex!("f90211a0c3c1510781a6c8ba9dae550cd691248cbfe8e3071987f0da92f13d03402e68b7a0267ee923bf851b5f9776eebb0c09be120dcb22c804c9478542bb665b2d694d87a0437a44f13d49bbdcd7106f95b62b105478a4709bea251824a1058d082977ecfda03d257aafd460d893e9713d085a389be271e3162420802e7b8133e8f1f71dc38fa01c07a616c35b484e4abd791a6aecd9ece7d107a90d110754afe973b3d6b1bbf5a0e51e122b66863d3132ff63c7cacbb4d4449c788076e9f10f3d28de3ece92f5dea0521e7a7eee71a2d2855e898ba4a610b0fd6ce57bb4adf8e5d2634e033612aa5aa0c1401fa14ded62f7e1a695d505f4148beccd2be22bc25730ef75a5c94ff680cea0bda976ea47bd694d0cd116dc1aae5e56b8333b3d6b053ea8ded30e656725bfdea0e581d8d0e4b3b5ab0d441e1bec84eb205ab2f55ff06354dc47c3f53e3e91c79ba066a53d18e20d26fb3c45305e29d2cbd1ae40fe17f63eeae8ae1d2190a1d91fcda00df4111cf09b03686b76e192425627c394d7e75ac0dc96c6612366b8d68d3340a0c96d1de5da569fd8b08757d8393dca4a3000e001e674deda10d3072b1f8fff25a06ae859e5f232fd752442a5927c5433aaccd122971f59aca10c2ace8a46e5d9c5a0ad8a4773ddacac3510ee6c397f448fa9fd8f70b51d720d424313d6537f64b5eea0bc11c88bcccaf2086de82fe31e9fc2d225563c9252cd8a1b1660399627e642a980").to_vec()),
@@ -513,14 +1424,14 @@ fn get_valid_account_proof() -> ValidProof {
     ])
 }
 
-fn get_valid_storage_proof() -> ValidProof {
+fn get_valid_storage_proof<T: Config>() -> ValidProof<T> {
 	BoundedVec::truncate_from(vec![BoundedVec::truncate_from(hex!("f90131a02aa432bd6022e7da6d0188f41427645658b74a08227a036f80fa44bff9fc57af80808080a0c01b2d5e61b71c73bc7b1b73db39a4d89bea07f4594557fec2242da9c90c91a580a073768754f5d28b5a762b44f6c37233fbedbeab05779b7dfb407ede56d2812891a01f3fcf34e7a10de63f5fe300d0522504aa29e6a4a7ad39df02bfa94e958e5e458080a08f056b51124e5c81f7d86b4364a97f66324957dc1042f751bce474abfa1480e5a098b414db83b4c1efa80ad36b641c9c517b2225cb212fc236116b2f9dc69ce64fa09a0e693399670076dd7708930bdc19b9101b3f181c4bf62d25edf6e1fd6e881da079866ac4ff54c3062d8fbd4fa347961e9a905b4114a2ed9785e22a5c03f4ffb8a03a1e44d3669992d3ac3f750a1159442ba1d83432cb03e5815d7eed7d97c7e90380").to_vec()),
                                    BoundedVec::truncate_from(hex!("f851808080a08ddae10e810d2127e5d527cab77909cdc5b99e20544edfb6f0c7b46033fe17e080808080808080808080a0c43b94283974430fdacaadaea093cd1524e306af5fc8c15ae3b13528d8f332088080").to_vec()),
                                    BoundedVec::truncate_from(hex!("f843a020b5be412f275a18f6e4d622aee4ff40b21467c926224771b782d4c095d1444ba1a0efac9989593dfa1e64bac26dd75fd613470d99766ad2c954af658253a09d1ad8").to_vec()),
     ])
 }
 
-fn get_valid_amb_account_proof() -> crate::ValidProof {
+fn get_valid_amb_account_proof<T: Config>() -> crate::ValidProof<T> {
 	BoundedVec::truncate_from(vec![
         BoundedVec::truncate_from(hex!("f90211a00b9369252d91d5240966845819a5e73a8e7b4b531b9f66bd652df2ef25999e8fa00fbedaed13519e0d8971ca3e8f54fa6250b8c60ae522924ea5b7bc54d7ab049ca0e9a41917172ecd79fbbc0db3c117a990464df2767be84bc5fd749cb50763834fa0420cf2bd2e7aac8cc33b0731d7546b738315ac482524aebc60e39a7d0359ce84a007e78eae37bbc3d25305685e8321582390d9631365f5c40612d111c7c1d2a35aa0f90094ebbf4d71dc00d9ff469a149d62529289b7a88d63f69ef73c45ffadd9b8a0bb5c4d709b179a0dec8c3e298249746c5f36f1ab4c7bb0985e4c565bfa96efd2a0b44d9462647f7f99afe5396fc6c049ca3d343748d9eb27f64e118a4d55d26d2ba08119035469c78ffe7336ba8c0117906ca64c9827f16ef71b5fcd99272d983779a067a02fa9917bda0acfb3646ba7e91c7e54e56e40342c552c0b069f949a64e122a0d2b39ca49bbaf2582e8c48243c86249ef034d14f8272952975c1f01773d508c4a0cd89136cd6f4203f52553a6856ba48ee08fe3d0165b5d020b4781ec0b0195bfda0bfdc0af814301e39e11e3c2186b3969b5c6ffdcb627d99f2e1e425d48300253ea0689a72bc1f0084b35849c938c2298e0d90384a071b6ef7bb145dab1b1205efffa0614a4f66f2451debf003b8584d9c2bbb87ee1d141cb240b494a840a91d32e126a01885c00eb4d6365b341f3c21bc44754f901ff56e957399069209b612c272a97880").to_vec()),
         BoundedVec::truncate_from(hex!("f90211a0f965da070dc0173a61d34b21bea4dfde26c834257629677157ae7248b50cd81fa03a76449b49477746172ed14e2f996a8131b3e23de3315a1923f38b7ea415b979a0d074384d170d5abab0c1d9ef28cc3c0ff8f36851a7bcbaef501c6032df0943aaa0149b7581ab9bc820217566d4304c13c418893863964f1b2290390f2f4f9c07baa067bc808de655c5710908cbb592420df656ddb294a0ca123c5d1c074e977175aaa02965ae45e7ec302c8d1d24d07954074343df8247da2f6122939181ca3f187dd0a0edbf4144f7d295047f49dd10e218aa3d590cb406312310b0ef56184c1d046822a0915dbafe8ba1d369a3e24375095596455fbc9aacc468958f6928ce428ac31967a0b9e44b513ed8ca8ee7409e399c4a467dc7d260c28d1fba4685852f5e3b65f4d8a09567dcd6366635567c2e61fe0d984516a77a4b0c204bd9b2f85ff343216780afa08390c69ab6052b335f241b5b187af412395910213899dbbf6084987846204c7ba0d38a47f0e0b2b6267a7e360308cbe0ab49e22a0a8615439d3423d6c363080ff9a083e7617b1a68407db284cd4035e04b0637b727c949205cd9ed176319cb9c0e52a061503e01e7f1d1fa6ef925e561be0a4a6fd580263da8f811121b90b87708e562a09c0414ab3be0fee449c042627dac5fd0f3d228b53499a0fc181045f873e3403ca092962b27d984b35f646fa84d3ef49830c5ee8fb0d6db97a912aaf21da043187580").to_vec()),
@@ -533,7 +1444,7 @@ fn get_valid_amb_account_proof() -> crate::ValidProof {
     ])
 }
 
-fn get_valid_amb_storage_proof() -> crate::ValidProof {
+fn get_valid_amb_storage_proof<T: Config>() -> crate::ValidProof<T> {
 	BoundedVec::truncate_from(vec![
         BoundedVec::truncate_from(hex!("f90131a0358aed89bfaa22b0179d1015fa6034c7ad29702af20b05b02f3cf63f69c2811280808080a08c857f53d31e0c1b681ff481d435a48986faa0615d611b05db5d0b3f4691f6d1a0d40ba3e2c5ca0722bb231d4a661b452920758ab202dbcfbc8e505d9f436e5600a0c3d20e38630b6b8f49ea6ca919b9077081557a4ca337c374e42219e4738a8970a0d2059c3c7e4dacb02dec49822ce85568a4419849a093ec956598dc2c3269238d808080a08c675371a85fc8f524a707007b05d0b87d7ecbde37f10e953a03da38431d31f8a0079a6b40f411dbc043c20594864f4e7f3e5ef2ef8e27f230c3431ec771b2d001a079866ac4ff54c3062d8fbd4fa347961e9a905b4114a2ed9785e22a5c03f4ffb8a0fef4138a6a9993fb0418e252583a03d2586caf404c7a16f7083600f49aac8cf280").to_vec()),
         BoundedVec::truncate_from(hex!("f851808080a0aec544652aa67b55271eec87a45f5ca89f6a6ea762450ca63b014ceb073e4e9d80808080808080808080a08c06dc4d3d3e8d7fe5a8a88222594ba9f4cdb19baaa8e60919b5617770423f828080").to_vec()),
@@ -0,0 +1,194 @@
+use ruzstd::decoding::StreamingDecoder;
+use ruzstd::encoding::{compress_to_vec, CompressionLevel};
+use ruzstd::io::Read;
+use sp_std::vec::Vec;
+
+/// Size of the chunks the frames are decompressed by.
+const CHUNK_SIZE: usize = 1024;
+/// Magic number starting a zstd frame.
+const MAGIC_NUMBER: u32 = 0xFD2F_B528;
+/// Frame header descriptor of a single segment frame with an 8 byte content size.
+const SINGLE_SEGMENT_DESCRIPTOR: u8 = 0b1110_0000;
+/// Flag of the frame header descriptor set when the frame ends with a checksum.
+const CHECKSUM_FLAG: u8 = 0b0000_0100;
+
+/// Reason a zstd frame is rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecompressionError {
+	/// The data is not a valid zstd frame.
+	InvalidFrame,
+	/// The frame decompresses to more than the allowed size.
+	TooLarge,
+}
+
+/// Sizes declared by the header of a zstd frame.
+struct FrameHeader {
+	/// Length of the header, magic number included.
+	len: usize,
+	/// Size of the window the decoder keeps, the content of a single segment frame.
+	window_size: u64,
+	content_size: Option<u64>,
+	checksum: bool,
+}
+
+/// Reads the header of the zstd frame at the start of `frame`.
+fn frame_header(frame: &[u8]) -> Result<FrameHeader, DecompressionError> {
+	if frame.get(..4) != Some(&MAGIC_NUMBER.to_le_bytes()[..]) {
+		return Err(DecompressionError::InvalidFrame);
+	}
+	let descriptor = *frame.get(4).ok_or(DecompressionError::InvalidFrame)?;
+	let single_segment = descriptor & 0b0010_0000 != 0;
+	let content_size_len = match descriptor >> 6 {
+		0 => usize::from(single_segment),
+		1 => 2,
+		2 => 4,
+		_ => 8,
+	};
+	let dictionary_id_len = match descriptor & 0b0000_0011 {
+		0 => 0,
+		1 => 1,
+		2 => 2,
+		_ => 4,
+	};
+
+	let mut offset = 5;
+	let mut window_size = None;
+	if !single_segment {
+		let window_descriptor = *frame.get(offset).ok_or(DecompressionError::InvalidFrame)?;
+		let base = 1u64 << (10 + (window_descriptor >> 3));
+		window_size = Some(base + base / 8 * u64::from(window_descriptor & 0b0000_0111));
+		offset += 1;
+	}
+	offset += dictionary_id_len;
+
+	let content_size = frame
+		.get(offset..offset + content_size_len)
+		.ok_or(DecompressionError::InvalidFrame)?;
+	let mut bytes = [0u8; 8];
+	bytes[..content_size_len].copy_from_slice(content_size);
+	let content_size = match content_size_len {
+		0 => None,
+		// 2 byte content sizes start at 256, smaller ones fit in 1 byte.
+		2 => Some(u64::from_le_bytes(bytes) + 256),
+		_ => Some(u64::from_le_bytes(bytes)),
+	};
+
+	Ok(FrameHeader {
+		len: offset + content_size_len,
+		window_size: window_size.or(content_size).unwrap_or_default(),
+		content_size,
+		checksum: descriptor & CHECKSUM_FLAG != 0,
+	})
+}
+
+/// Decompresses the zstd frame at the start of `compressed`, failing as soon as it decompresses
+/// to more than `max_size` bytes, so a small frame cannot expand without bound.
+///
+/// Frames whose header declares a window or a content larger than `max_size` are rejected
+/// before decoding, as the decoder may allocate the declared window upfront. Frames of
+/// `compress` declare their content as window.
+pub fn decompress(compressed: &[u8], max_size: usize) -> Result<Vec<u8>, DecompressionError> {
+	let header = frame_header(compressed)?;
+	let max_size_u64 = max_size as u64;
+	if header.window_size > max_size_u64
+		|| header.content_size.is_some_and(|size| size > max_size_u64)
+	{
+		return Err(DecompressionError::TooLarge);
+	}
+
+	let mut decoder =
+		StreamingDecoder::new(compressed).map_err(|_| DecompressionError::InvalidFrame)?;
+	let mut decompressed = Vec::new();
+	let mut chunk = [0u8; CHUNK_SIZE];
+	loop {
+		let read = decoder
+			.read(&mut chunk)
+			.map_err(|_| DecompressionError::InvalidFrame)?;
+		if read == 0 {
+			return Ok(decompressed);
+		}
+		if decompressed.len() + read > max_size {
+			return Err(DecompressionError::TooLarge);
+		}
+		decompressed.extend_from_slice(&chunk[..read]);
+	}
+}
+
+/// Compresses `data` into a single segment zstd frame, which declares `data` as its content and
+/// window, so `decompress` accepts it up to a `max_size` of the length of `data`.
+pub fn compress(data: &[u8]) -> Vec<u8> {
+	let frame = compress_to_vec(data, CompressionLevel::Fastest);
+	let header = frame_header(&frame).expect("The encoder writes valid frame headers; qed");
+
+	let mut descriptor = SINGLE_SEGMENT_DESCRIPTOR;
+	if header.checksum {
+		descriptor |= CHECKSUM_FLAG;
+	}
+	// The blocks never refer further back than the content decoded before them.
+	let mut single_segment = MAGIC_NUMBER.to_le_bytes().to_vec();
+	single_segment.push(descriptor);
+	single_segment.extend_from_slice(&(data.len() as u64).to_le_bytes());
+	single_segment.extend_from_slice(&frame[header.len..]);
+	single_segment
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::compression::{compress, decompress, frame_header, DecompressionError};
+
+	#[test]
+	fn decompress_restores_data() {
+		let data: Vec<u8> = (0..4096u32).map(|i| (i % 7) as u8).collect();
+		let compressed = compress(&data);
+		assert!(compressed.len() < data.len());
+
+		assert_eq!(Ok(data), decompress(&compressed, 4096));
+	}
+
+	#[test]
+	fn decompress_rejects_data_above_max_size() {
+		let data = vec![0u8; 4097];
+		let compressed = compress(&data);
+
+		assert_eq!(
+			Err(DecompressionError::TooLarge),
+			decompress(&compressed, 4096)
+		);
+	}
+
+	#[test]
+	fn decompress_rejects_declared_window_above_max_size() {
+		let data = vec![0u8; 64];
+		let compressed = compress(&data);
+		let header = frame_header(&compressed).unwrap();
+
+		// The same blocks in a frame declaring a 1 MiB window and no content size.
+		let mut large_window = compressed[..4].to_vec();
+		large_window.extend_from_slice(&[compressed[4] & 0b0000_0100, 10 << 3]);
+		large_window.extend_from_slice(&compressed[header.len..]);
+		assert_eq!(1 << 20, frame_header(&large_window).unwrap().window_size);
+
+		assert_eq!(
+			Err(DecompressionError::TooLarge),
+			decompress(&large_window, 4096)
+		);
+		assert_eq!(Ok(data), decompress(&compressed, 4096));
+	}
+
+	#[test]
+	fn compress_declares_content_as_window() {
+		let data = vec![1u8; 300];
+		let header = frame_header(&compress(&data)).unwrap();
+
+		assert_eq!(300, header.window_size);
+		assert_eq!(Some(300), header.content_size);
+	}
+
+	#[test]
+	fn decompress_rejects_invalid_frame() {
+		assert_eq!(
+			Err(DecompressionError::InvalidFrame),
+			decompress(&[1, 2, 3, 4], 4096)
+		);
+	}
+}
@@ -0,0 +1,350 @@
+/// Conformance of the light client rules of Vector with the light client sync tests of the
+/// Ethereum consensus specs.
+///
+/// The tests run against the vectors of the `v1.4.0` release of `consensus-spec-tests`, extracted
+/// in the directory given by `CONSENSUS_SPEC_TESTS_DIR`. They are ignored by default, fail when
+/// the vectors are missing, and run in the `consensus_spec_conformance` job of the unit tests
+/// workflow:
+///
+/// ```text
+/// curl -L https://github.com/ethereum/consensus-spec-tests/releases/download/v1.4.0/minimal.tar.gz | tar xz
+/// curl -L https://github.com/ethereum/consensus-spec-tests/releases/download/v1.4.0/mainnet.tar.gz | tar xz
+/// CONSENSUS_SPEC_TESTS_DIR=$PWD/tests cargo test -p pallet-vector conformance -- --ignored
+/// ```
+///
+/// Every `process_update` step of the `light_client/sync` cases of each fork of a preset is
+/// checked against the period math of `Configuration::slots_per_period`, the participation
+/// `finality_threshold` a step needs, and the header chain rules: the head only moves forward,
+/// and sync committees are rotated in one period at a time.
+use std::{
+	env, fs,
+	path::{Path, PathBuf},
+};
+
+use crate::state::{check_rotation, Configuration};
+
+const TESTS_DIR_ENV: &str = "CONSENSUS_SPEC_TESTS_DIR";
+
+const BEACON_BLOCK_HEADER_SIZE: usize = 112;
+const BLS_PUBKEY_SIZE: usize = 48;
+const BLS_SIGNATURE_SIZE: usize = 96;
+const OFFSET_SIZE: usize = 4;
+
+/// Constants of a preset of the consensus specs.
+struct Preset {
+	name: &'static str,
+	slots_per_epoch: u64,
+	epochs_per_sync_committee_period: u64,
+	sync_committee_size: usize,
+}
+
+const MINIMAL: Preset = Preset {
+	name: "minimal",
+	slots_per_epoch: 8,
+	epochs_per_sync_committee_period: 8,
+	sync_committee_size: 32,
+};
+
+const MAINNET: Preset = Preset {
+	name: "mainnet",
+	slots_per_epoch: 32,
+	epochs_per_sync_committee_period: 256,
+	sync_committee_size: 512,
+};
+
+impl Preset {
+	/// Configuration of Vector following a chain of the preset.
+	fn configuration(&self) -> Configuration {
+		Configuration {
+			slots_per_period: self.slots_per_epoch * self.epochs_per_sync_committee_period,
+			// Supermajority of `process_light_client_update`: 3 * participants >= 2 * size.
+			finality_threshold: (2 * self.sync_committee_size).div_ceil(3) as u16,
+		}
+	}
+
+	/// `compute_sync_committee_period_at_slot` of the specs.
+	fn period_at_slot(&self, slot: u64) -> u64 {
+		slot / self.slots_per_epoch / self.epochs_per_sync_committee_period
+	}
+
+	fn sync_committee_size_bytes(&self) -> usize {
+		(self.sync_committee_size + 1) * BLS_PUBKEY_SIZE
+	}
+
+	fn sync_aggregate_size_bytes(&self) -> usize {
+		self.sync_committee_size / 8 + BLS_SIGNATURE_SIZE
+	}
+}
+
+/// SSZ layout of the light client containers, which changed over the forks.
+#[derive(Clone, Copy, Debug)]
+struct Layout {
+	/// Headers are `BeaconBlockHeader`s inlined in the containers until Capella, and variable
+	/// size `LightClientHeader`s after.
+	inline_headers: bool,
+	/// Depth of the sync committee branches.
+	sync_committee_depth: usize,
+	/// Depth of the finality branch.
+	finality_depth: usize,
+}
+
+const LAYOUTS: [Layout; 3] = [
+	// Altair and Bellatrix.
+	Layout {
+		inline_headers: true,
+		sync_committee_depth: 5,
+		finality_depth: 6,
+	},
+	// Capella and Deneb.
+	Layout {
+		inline_headers: false,
+		sync_committee_depth: 5,
+		finality_depth: 6,
+	},
+	// Electra.
+	Layout {
+		inline_headers: false,
+		sync_committee_depth: 6,
+		finality_depth: 7,
+	},
+];
+
+impl Layout {
+	fn header_size(&self) -> usize {
+		if self.inline_headers {
+			BEACON_BLOCK_HEADER_SIZE
+		} else {
+			OFFSET_SIZE
+		}
+	}
+}
+
+/// Fields of a `LightClientUpdate` checked against Vector.
+#[derive(Debug)]
+struct Update {
+	attested_slot: u64,
+	finalized_slot: u64,
+	signature_slot: u64,
+	participation: u16,
+	/// The update proves a finalized header.
+	is_finality_update: bool,
+}
+
+fn read_u64(bytes: &[u8], at: usize) -> Option<u64> {
+	let le = bytes.get(at..at.checked_add(8)?)?;
+	Some(u64::from_le_bytes(le.try_into().ok()?))
+}
+
+fn read_offset(bytes: &[u8], at: usize) -> Option<usize> {
+	let le = bytes.get(at..at.checked_add(OFFSET_SIZE)?)?;
+	Some(u32::from_le_bytes(le.try_into().ok()?) as usize)
+}
+
+/// Returns the slot of the header at `at` of `container` with `layout`, `fixed_size` being the
+/// size of the fixed part of the container.
+fn header_slot(container: &[u8], layout: Layout, at: usize, fixed_size: usize) -> Option<u64> {
+	if layout.inline_headers {
+		return read_u64(container, at);
+	}
+	// The beacon block header is the first field of a `LightClientHeader`.
+	let offset = read_offset(container, at)?;
+	if offset < fixed_size {
+		return None;
+	}
+	read_u64(container, offset)
+}
+
+/// Decodes an SSZ `LightClientUpdate` with `layout`, `None` if it does not have this layout.
+fn decode_update(preset: &Preset, layout: Layout, bytes: &[u8]) -> Option<Update> {
+	let attested_at = 0;
+	let next_sync_committee_branch_at =
+		attested_at + layout.header_size() + preset.sync_committee_size_bytes();
+	let finalized_at = next_sync_committee_branch_at + layout.sync_committee_depth * 32;
+	let finality_branch_at = finalized_at + layout.header_size();
+	let sync_aggregate_at = finality_branch_at + layout.finality_depth * 32;
+	let signature_slot_at = sync_aggregate_at + preset.sync_aggregate_size_bytes();
+	let fixed_size = signature_slot_at + 8;
+
+	let matches = if layout.inline_headers {
+		bytes.len() == fixed_size
+	} else {
+		bytes.len() >= fixed_size && read_offset(bytes, attested_at) == Some(fixed_size)
+	};
+	if !matches {
+		return None;
+	}
+
+	let is_zero = |from: usize, len: usize| bytes[from..from + len].iter().all(|byte| *byte == 0);
+	let bits = &bytes[sync_aggregate_at..sync_aggregate_at + preset.sync_committee_size / 8];
+
+	Some(Update {
+		attested_slot: header_slot(bytes, layout, attested_at, fixed_size)?,
+		finalized_slot: header_slot(bytes, layout, finalized_at, fixed_size)?,
+		signature_slot: read_u64(bytes, signature_slot_at)?,
+		participation: bits.iter().map(|byte| byte.count_ones() as u16).sum(),
+		is_finality_update: !is_zero(finality_branch_at, layout.finality_depth * 32),
+	})
+}
+
+/// Decodes the slot of the header of an SSZ `LightClientBootstrap` with `layout`, `None` if it
+/// does not have this layout.
+fn decode_bootstrap_slot(preset: &Preset, layout: Layout, bytes: &[u8]) -> Option<u64> {
+	let fixed_size = layout.header_size()
+		+ preset.sync_committee_size_bytes()
+		+ layout.sync_committee_depth * 32;
+	let matches = if layout.inline_headers {
+		bytes.len() == fixed_size
+	} else {
+		bytes.len() >= fixed_size && read_offset(bytes, 0) == Some(fixed_size)
+	};
+	if !matches {
+		return None;
+	}
+	header_slot(bytes, layout, 0, fixed_size)
+}
+
+/// Reads a snappy compressed SSZ file of a test case.
+fn read_ssz_snappy(path: &Path) -> Vec<u8> {
+	let compressed = fs::read(path).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+	snap::raw::Decoder::new()
+		.decompress_vec(&compressed)
+		.unwrap_or_else(|e| panic!("{}: {e}", path.display()))
+}
+
+/// Returns the `light_client/sync` test cases of `preset`, panicking if the vectors are not
+/// available.
+fn sync_test_cases(preset: &Preset) -> Vec<PathBuf> {
+	let tests_dir = env::var_os(TESTS_DIR_ENV)
+		.unwrap_or_else(|| panic!("{TESTS_DIR_ENV} must be set to the consensus spec tests"));
+	let preset_dir = Path::new(&tests_dir).join(preset.name);
+	let forks =
+		fs::read_dir(&preset_dir).unwrap_or_else(|e| panic!("{}: {e}", preset_dir.display()));
+	let mut cases = Vec::new();
+	for fork in forks.flatten() {
+		let sync_dir = fork.path().join("light_client/sync/pyspec_tests");
+		let Ok(fork_cases) = fs::read_dir(&sync_dir) else {
+			continue;
+		};
+		cases.extend(fork_cases.flatten().map(|case| case.path()));
+	}
+	cases.sort();
+	assert!(
+		!cases.is_empty(),
+		"No light client sync test in {}",
+		preset_dir.display()
+	);
+	cases
+}
+
+/// Runs the steps of a `light_client/sync` test case, checking each update applied by the
+/// specs against the rules of Vector.
+fn check_sync_test_case(preset: &Preset, case: &Path) {
+	let cfg = preset.configuration();
+	let bootstrap = read_ssz_snappy(&case.join("bootstrap.ssz_snappy"));
+	let mut finalized_slot = LAYOUTS
+		.iter()
+		.find_map(|layout| decode_bootstrap_slot(preset, *layout, &bootstrap))
+		.unwrap_or_else(|| panic!("{}: undecodable bootstrap", case.display()));
+
+	let steps = fs::read(case.join("steps.yaml")).expect("Test case has steps");
+	let steps: Vec<serde_yaml::Value> = serde_yaml::from_slice(&steps).expect("Valid steps");
+	for step in steps {
+		let checked_slot = |step: &serde_yaml::Value| {
+			step["checks"]["finalized_header"]["slot"]
+				.as_u64()
+				.unwrap_or_else(|| panic!("{}: step without checks", case.display()))
+		};
+
+		// Forced and store upgrade steps follow rules of the specs Vector has no counterpart of.
+		let Some(process_update) = step.get("process_update") else {
+			if let Some(slot) = step
+				.as_mapping()
+				.and_then(|step| step.values().next())
+				.map(checked_slot)
+			{
+				finalized_slot = slot;
+			}
+			continue;
+		};
+
+		let name = process_update["update"].as_str().expect("Update file name");
+		let bytes = read_ssz_snappy(&case.join(format!("{name}.ssz_snappy")));
+		let update = LAYOUTS
+			.iter()
+			.find_map(|layout| decode_update(preset, *layout, &bytes))
+			.unwrap_or_else(|| panic!("{}: undecodable {name}", case.display()));
+		let next_finalized_slot = checked_slot(process_update);
+		let context = format!("{}: {name} {update:?}", case.display());
+
+		// Period math.
+		for slot in [
+			update.attested_slot,
+			update.finalized_slot,
+			update.signature_slot,
+		] {
+			assert_eq!(
+				Some(preset.period_at_slot(slot)),
+				slot.checked_div(cfg.slots_per_period),
+				"{context}"
+			);
+		}
+
+		// The finalized header of the specs never moves back, as the head of Vector.
+		assert!(next_finalized_slot >= finalized_slot, "{context}");
+
+		if update.participation < cfg.finality_threshold {
+			// Vector rejects steps without enough participation, the specs do not apply them.
+			assert_eq!(finalized_slot, next_finalized_slot, "{context}");
+		} else if next_finalized_slot > finalized_slot {
+			assert!(update.is_finality_update, "{context}");
+			assert_eq!(update.finalized_slot, next_finalized_slot, "{context}");
+
+			// The sync committees between both headers are rotated in one period at a time,
+			// from the previous header, verified by a step as the head of Vector.
+			let period = preset.period_at_slot(finalized_slot);
+			let next_period = preset.period_at_slot(next_finalized_slot);
+			assert!(next_period <= period + 1, "{context}");
+			if next_period == period + 1 {
+				assert_eq!(
+					Ok(()),
+					check_rotation(
+						&cfg,
+						finalized_slot,
+						Some(cfg.finality_threshold),
+						next_period
+					),
+					"{context}"
+				);
+			}
+		}
+
+		finalized_slot = next_finalized_slot;
+	}
+}
+
+#[test]
+fn preset_configurations_follow_specs() {
+	let minimal = MINIMAL.configuration();
+	assert_eq!(64, minimal.slots_per_period);
+	assert_eq!(22, minimal.finality_threshold);
+
+	let mainnet = MAINNET.configuration();
+	assert_eq!(crate::constants::SLOTS_PER_PERIOD, mainnet.slots_per_period);
+	assert_eq!(342, mainnet.finality_threshold);
+}
+
+#[test]
+#[ignore = "needs the consensus spec tests in CONSENSUS_SPEC_TESTS_DIR"]
+fn light_client_sync_conforms_to_minimal_preset() {
+	for case in sync_test_cases(&MINIMAL) {
+		check_sync_test_case(&MINIMAL, &case);
+	}
+}
+
+#[test]
+#[ignore = "needs the consensus spec tests in CONSENSUS_SPEC_TESTS_DIR"]
+fn light_client_sync_conforms_to_mainnet_preset() {
+	for case in sync_test_cases(&MAINNET) {
+		check_sync_test_case(&MAINNET, &case);
+	}
+}
@@ -0,0 +1,65 @@
+use sp_core::H256;
+use sp_runtime::Perbill;
+use sp_staking::{
+	offence::{Kind, Offence},
+	SessionIndex,
+};
+use sp_std::{vec, vec::Vec};
+
+/// Fraction of the stake slashed for an updater equivocation.
+///
+/// Finalizing a conflicting header is never an honest mistake, so the fraction does not depend
+/// on the number of offenders.
+const EQUIVOCATION_SLASH_FRACTION: Perbill = Perbill::from_percent(10);
+
+/// Reports updaters which submitted a step conflicting with an accepted one.
+pub trait ReportUpdaterEquivocation {
+	/// Records an offence against `updater` for the source chain `slot`.
+	///
+	/// Returns `false` if no offence could be recorded, e.g. as the updater is not staked.
+	fn report(updater: H256, slot: u64) -> bool;
+}
+
+impl ReportUpdaterEquivocation for () {
+	fn report(_updater: H256, _slot: u64) -> bool {
+		false
+	}
+}
+
+/// Offence of an updater finalizing two different header roots for the same source chain slot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpdaterEquivocationOffence<Offender> {
+	/// Session in which the equivocation was detected.
+	pub session_index: SessionIndex,
+	/// Size of the validator set in `session_index`.
+	pub validator_set_count: u32,
+	/// Identification of the updater.
+	pub offender: Offender,
+	/// Source chain slot of the conflicting headers.
+	pub slot: u64,
+}
+
+impl<Offender: Clone> Offence<Offender> for UpdaterEquivocationOffence<Offender> {
+	const ID: Kind = *b"vector:equivocat";
+	type TimeSlot = u64;
+
+	fn offenders(&self) -> Vec<Offender> {
+		vec![self.offender.clone()]
+	}
+
+	fn session_index(&self) -> SessionIndex {
+		self.session_index
+	}
+
+	fn validator_set_count(&self) -> u32 {
+		self.validator_set_count
+	}
+
+	fn time_slot(&self) -> Self::TimeSlot {
+		self.slot
+	}
+
+	fn slash_fraction(&self, _offenders_count: u32) -> Perbill {
+		EQUIVOCATION_SLASH_FRACTION
+	}
+}
@@ -0,0 +1,64 @@
+use frame_support::weights::Weight;
+use sp_core::{H160, H256};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+/// Executes the messages addressed to the contracts of an EVM pallet of the runtime, e.g.
+/// Frontier's `pallet-evm`.
+///
+/// A message is addressed to a contract if its recipient is an `H160` address, left-padded with
+/// zeros, at which the EVM holds code. The amount of a fungible token message is transferred to
+/// the account backing the EVM balance of the contract, and the data of an arbitrary message is
+/// the input of a call to the contract.
+pub trait EvmExecutor {
+	/// Returns whether `address` holds the code of an EVM contract.
+	fn is_contract(address: H160) -> bool;
+
+	/// Returns the account holding the EVM balance of `address`, as mapped by the EVM pallet.
+	fn account_of(address: H160) -> H256;
+
+	/// Calls `target` from `source` with `input`, spending at most `gas_limit` gas. A revert of
+	/// the contract, or running out of gas, is returned as an error.
+	fn call(
+		source: H160,
+		target: H160,
+		input: Vec<u8>,
+		gas_limit: u64,
+	) -> Result<(), DispatchError>;
+
+	/// Returns the weight of spending `gas` gas.
+	fn gas_weight(gas: u64) -> Weight;
+}
+
+/// Runtimes without an EVM pallet, none of the messages is addressed to a contract.
+impl EvmExecutor for () {
+	fn is_contract(_address: H160) -> bool {
+		false
+	}
+
+	fn account_of(address: H160) -> H256 {
+		H256::from(address)
+	}
+
+	fn call(
+		_source: H160,
+		_target: H160,
+		_input: Vec<u8>,
+		_gas_limit: u64,
+	) -> Result<(), DispatchError> {
+		Err(DispatchError::Other("No EVM pallet"))
+	}
+
+	fn gas_weight(_gas: u64) -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Returns the `H160` address of `to`, if it is one left-padded with zeros.
+pub fn evm_address(to: H256) -> Option<H160> {
+	let (padding, address) = to.as_bytes().split_at(12);
+	padding
+		.iter()
+		.all(|byte| *byte == 0)
+		.then(|| H160::from_slice(address))
+}
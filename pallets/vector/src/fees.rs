@@ -0,0 +1,114 @@
+use frame_support::weights::Weight;
+use sp_runtime::{
+	traits::{One, Saturating},
+	FixedPointNumber, FixedU128,
+};
+
+/// Multiplier of the weight fee of bridge proof submissions.
+pub type Multiplier = FixedU128;
+
+/// Returns the multiplier of the bridge fee lane for the next block.
+///
+/// It follows the rule of `TargetedFeeAdjustment` for the bridge proof submissions alone: it
+/// increases when they used more than `target` in the block and decreases otherwise, by
+/// `adjustment` times the difference relative to `target`. It is kept between one, the price of
+/// an idle lane, and `maximum`.
+pub fn next_multiplier(
+	previous: Multiplier,
+	used: Weight,
+	target: Weight,
+	adjustment: Multiplier,
+	maximum: Multiplier,
+) -> Multiplier {
+	if target.ref_time() == 0 {
+		return previous;
+	}
+	let (used, target) = (used.ref_time(), target.ref_time());
+	let positive = used >= target;
+	let diff = Multiplier::saturating_from_rational(used.abs_diff(target), target);
+
+	let first_term = adjustment.saturating_mul(diff);
+	let second_term =
+		first_term.saturating_mul(first_term) / Multiplier::saturating_from_integer(2);
+	let next = if positive {
+		let excess = first_term.saturating_add(second_term);
+		previous.saturating_add(previous.saturating_mul(excess))
+	} else {
+		let negative = first_term.saturating_sub(second_term);
+		previous.saturating_sub(previous.saturating_mul(negative))
+	};
+
+	next.clamp(Multiplier::one(), maximum)
+}
+
+/// Gas used by the destination contract to verify the proof of an outbound message against the
+/// Avail state root and to deliver it, besides its payload.
+pub const DESTINATION_BASE_GAS: u64 = 150_000;
+
+/// Gas of a non-zero byte of calldata.
+pub const CALLDATA_GAS_PER_BYTE: u64 = 16;
+
+/// Returns the gas used on the destination chain by an outbound message of `payload_size`
+/// bytes, as a proxy of the cost of its delivery.
+pub fn destination_gas(payload_size: u32) -> u64 {
+	DESTINATION_BASE_GAS.saturating_add(CALLDATA_GAS_PER_BYTE.saturating_mul(payload_size.into()))
+}
+
+#[cfg(test)]
+mod tests {
+	use frame_support::weights::Weight;
+	use sp_runtime::{traits::One, FixedPointNumber};
+
+	use crate::fees::{destination_gas, next_multiplier, Multiplier, DESTINATION_BASE_GAS};
+
+	fn adjustment() -> Multiplier {
+		Multiplier::saturating_from_rational(1, 100)
+	}
+
+	fn target() -> Weight {
+		Weight::from_parts(1_000_000, 0)
+	}
+
+	#[test]
+	fn next_multiplier_follows_usage_against_target() {
+		let previous = Multiplier::saturating_from_integer(2);
+		let maximum = Multiplier::saturating_from_integer(100);
+
+		let at_target = next_multiplier(previous, target(), target(), adjustment(), maximum);
+		assert_eq!(previous, at_target);
+
+		let double = Weight::from_parts(2_000_000, 0);
+		let surge = next_multiplier(previous, double, target(), adjustment(), maximum);
+		assert!(surge > previous);
+
+		let idle = next_multiplier(previous, Weight::zero(), target(), adjustment(), maximum);
+		assert!(idle < previous);
+	}
+
+	#[test]
+	fn next_multiplier_is_bounded() {
+		let maximum = Multiplier::saturating_from_integer(2);
+		let surge = Weight::from_parts(u64::MAX, 0);
+
+		assert_eq!(
+			maximum,
+			next_multiplier(maximum, surge, target(), adjustment(), maximum)
+		);
+		assert_eq!(
+			Multiplier::one(),
+			next_multiplier(
+				Multiplier::one(),
+				Weight::zero(),
+				target(),
+				adjustment(),
+				maximum
+			)
+		);
+	}
+
+	#[test]
+	fn destination_gas_grows_with_payload() {
+		assert_eq!(DESTINATION_BASE_GAS, destination_gas(0));
+		assert_eq!(DESTINATION_BASE_GAS + 16 * 100, destination_gas(100));
+	}
+}
@@ -0,0 +1,190 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{Deserialize, Serialize};
+use scale_info::TypeInfo;
+
+/// How the source chain finalizes its headers, and when its slots start.
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	Debug,
+	PartialEq,
+	Eq,
+	TypeInfo,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum FinalityConfig {
+	/// Beacon chain finality: the sync committee finalizes any slot, and slots last
+	/// `seconds_per_slot` from `genesis_time`.
+	#[serde(rename_all = "camelCase")]
+	Slot {
+		genesis_time: u64,
+		seconds_per_slot: u64,
+	},
+	/// Checkpoint finality: only the first slot of each epoch of `slots_per_epoch` slots is
+	/// finalized.
+	#[serde(rename_all = "camelCase")]
+	Epoch {
+		genesis_time: u64,
+		seconds_per_slot: u64,
+		slots_per_epoch: u64,
+	},
+	/// Every block is final once produced, so the finalized slot is the attested one. Slots are
+	/// block numbers, produced every `seconds_per_block`.
+	#[serde(rename_all = "camelCase")]
+	Instant {
+		genesis_time: u64,
+		seconds_per_block: u64,
+	},
+}
+
+/// Reason a finalized slot is rejected by `check_finalized_slot`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FinalityError {
+	/// The slot is not the first slot of an epoch.
+	NotEpochBoundary,
+	/// The finalized slot of an instant finality chain is not the attested slot.
+	NotAttestedSlot,
+}
+
+impl Default for FinalityConfig {
+	fn default() -> Self {
+		FinalityConfig::Slot {
+			genesis_time: 0,
+			seconds_per_slot: 0,
+		}
+	}
+}
+
+impl FinalityConfig {
+	pub fn genesis_time(&self) -> u64 {
+		match self {
+			FinalityConfig::Slot { genesis_time, .. }
+			| FinalityConfig::Epoch { genesis_time, .. }
+			| FinalityConfig::Instant { genesis_time, .. } => *genesis_time,
+		}
+	}
+
+	/// Returns the duration of a slot, or of a block for instant finality chains.
+	pub fn seconds_per_slot(&self) -> u64 {
+		match self {
+			FinalityConfig::Slot {
+				seconds_per_slot, ..
+			}
+			| FinalityConfig::Epoch {
+				seconds_per_slot, ..
+			} => *seconds_per_slot,
+			FinalityConfig::Instant {
+				seconds_per_block, ..
+			} => *seconds_per_block,
+		}
+	}
+
+	/// Returns the timestamp of `slot` on the source chain, in seconds.
+	pub fn slot_timestamp(&self, slot: u64) -> u64 {
+		self.genesis_time()
+			.saturating_add(slot.saturating_mul(self.seconds_per_slot()))
+	}
+
+	/// Returns the slot of the source chain at `timestamp`, `None` if the timing is not set.
+	pub fn slot_at(&self, timestamp: u64) -> Option<u64> {
+		if self.genesis_time() == 0 {
+			return None;
+		}
+		timestamp
+			.saturating_sub(self.genesis_time())
+			.checked_div(self.seconds_per_slot())
+	}
+
+	/// Checks that `finalized_slot` can be finalized by the source chain, from a header attested
+	/// at `attested_slot` if it is known.
+	///
+	/// ```
+	/// use pallet_vector::finality::{FinalityConfig, FinalityError};
+	///
+	/// let epochs = FinalityConfig::Epoch { genesis_time: 1, seconds_per_slot: 12, slots_per_epoch: 32 };
+	/// assert_eq!(Ok(()), epochs.check_finalized_slot(Some(8256), 8192));
+	/// assert_eq!(Err(FinalityError::NotEpochBoundary), epochs.check_finalized_slot(None, 8193));
+	///
+	/// let instant = FinalityConfig::Instant { genesis_time: 1, seconds_per_block: 2 };
+	/// assert_eq!(Ok(()), instant.check_finalized_slot(Some(10), 10));
+	/// assert_eq!(Err(FinalityError::NotAttestedSlot), instant.check_finalized_slot(Some(10), 9));
+	/// ```
+	pub fn check_finalized_slot(
+		&self,
+		attested_slot: Option<u64>,
+		finalized_slot: u64,
+	) -> Result<(), FinalityError> {
+		match self {
+			FinalityConfig::Slot { .. } => Ok(()),
+			FinalityConfig::Epoch {
+				slots_per_epoch, ..
+			} => match finalized_slot.checked_rem(*slots_per_epoch) {
+				Some(0) | None => Ok(()),
+				Some(_) => Err(FinalityError::NotEpochBoundary),
+			},
+			FinalityConfig::Instant { .. } => match attested_slot {
+				Some(attested) if attested != finalized_slot => Err(FinalityError::NotAttestedSlot),
+				_ => Ok(()),
+			},
+		}
+	}
+}
+
+/// Storage of runtimes before `FinalityConfig`, which only followed beacon chains.
+pub(crate) mod v0 {
+	use frame_support::{pallet_prelude::OptionQuery, storage_alias};
+
+	use crate::{Config, Pallet};
+
+	#[storage_alias]
+	pub type GenesisTimestamp<T: Config> = StorageValue<Pallet<T>, u64, OptionQuery>;
+
+	#[storage_alias]
+	pub type SecondsPerSlot<T: Config> = StorageValue<Pallet<T>, u64, OptionQuery>;
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::finality::FinalityConfig;
+
+	#[test]
+	fn slot_timing_follows_config() {
+		let beacon = FinalityConfig::Slot {
+			genesis_time: 100,
+			seconds_per_slot: 12,
+		};
+		assert_eq!(100 + 8224 * 12, beacon.slot_timestamp(8224));
+		assert_eq!(Some(8224), beacon.slot_at(100 + 8224 * 12 + 11));
+
+		let instant = FinalityConfig::Instant {
+			genesis_time: 100,
+			seconds_per_block: 2,
+		};
+		assert_eq!(120, instant.slot_timestamp(10));
+		assert_eq!(Some(10), instant.slot_at(121));
+
+		assert_eq!(None, FinalityConfig::default().slot_at(1_000));
+	}
+
+	#[test]
+	fn genesis_config_json_is_camel_case() {
+		let config: FinalityConfig = serde_json::from_str(
+			r#"{"epoch":{"genesisTime":100,"secondsPerSlot":12,"slotsPerEpoch":32}}"#,
+		)
+		.unwrap();
+
+		assert_eq!(
+			FinalityConfig::Epoch {
+				genesis_time: 100,
+				seconds_per_slot: 12,
+				slots_per_epoch: 32,
+			},
+			config
+		);
+	}
+}
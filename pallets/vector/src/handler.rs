@@ -0,0 +1,44 @@
+use avail_core::data_proof::AddressedMessage;
+use frame_support::weights::Weight;
+use sp_core::H256;
+use sp_runtime::DispatchResult;
+
+/// Handlers of the runtime pallets the arbitrary messages to a destination registered with
+/// `register_destination_handler` are handed to, identified by the id of their registration.
+/// Lets new applications consume the messages of the bridge without changing the pallet.
+///
+/// Implemented for tuples, the message is handed to the first handler with the id.
+pub trait DestinationHandler {
+	/// Handles `addr_message` with root `message_root` if `handler` is the id of this handler,
+	/// returns `None` otherwise. An error fails the execution of the message.
+	fn handle(
+		handler: u32,
+		addr_message: &AddressedMessage,
+		message_root: H256,
+	) -> Option<DispatchResult>;
+
+	/// Returns the maximum weight of handling a message, charged with every message.
+	fn max_weight() -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl DestinationHandler for Tuple {
+	fn handle(
+		handler: u32,
+		addr_message: &AddressedMessage,
+		message_root: H256,
+	) -> Option<DispatchResult> {
+		for_tuples!( #(
+			if let Some(result) = Tuple::handle(handler, addr_message, message_root) {
+				return Some(result);
+			}
+		)* );
+		None
+	}
+
+	fn max_weight() -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight = weight.max(Tuple::max_weight()); )* );
+		weight
+	}
+}
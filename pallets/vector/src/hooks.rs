@@ -0,0 +1,59 @@
+use avail_core::data_proof::AddressedMessage;
+use frame_support::weights::Weight;
+use sp_core::H256;
+
+/// Called when the proof of a message is verified, before it is executed or queued behind the
+/// earlier messages of its ordered channel. Lets the pallets of the runtime react to the inbound
+/// messages of the bridge, e.g. to credit points or update an oracle.
+///
+/// Implemented for tuples, the hooks of which are called in order.
+pub trait OnMessageReceived {
+	/// Reacts to the message `addr_message` with root `message_root`.
+	fn on_message_received(addr_message: &AddressedMessage, message_root: H256);
+
+	/// Returns the maximum weight of `on_message_received`, charged with every message.
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl OnMessageReceived for Tuple {
+	fn on_message_received(addr_message: &AddressedMessage, message_root: H256) {
+		for_tuples!( #( Tuple::on_message_received(addr_message, message_root); )* );
+	}
+
+	fn weight() -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight = weight.saturating_add(Tuple::weight()); )* );
+		weight
+	}
+}
+
+/// Called when a message is executed, or routed to its destination domain. A message whose
+/// failure is recorded instead of reverting, as one of an acknowledged sender, is reported with
+/// `success` unset.
+///
+/// Implemented for tuples, the hooks of which are called in order.
+pub trait OnMessageExecuted {
+	/// Reacts to the execution of the message `addr_message` with root `message_root`.
+	fn on_message_executed(addr_message: &AddressedMessage, message_root: H256, success: bool);
+
+	/// Returns the maximum weight of `on_message_executed`, charged with every message.
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl OnMessageExecuted for Tuple {
+	fn on_message_executed(addr_message: &AddressedMessage, message_root: H256, success: bool) {
+		for_tuples!( #( Tuple::on_message_executed(addr_message, message_root, success); )* );
+	}
+
+	fn weight() -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight = weight.saturating_add(Tuple::weight()); )* );
+		weight
+	}
+}
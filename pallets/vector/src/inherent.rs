@@ -0,0 +1,16 @@
+use codec::{Decode, Encode};
+use frame_support::inherent::InherentIdentifier;
+use sp_core::H256;
+use sp_std::vec::Vec;
+
+/// Identifier of the step proof a block author includes in its block.
+pub const INHERENT_IDENTIFIER: InherentIdentifier = *b"vectstep";
+
+/// Step or rotate inputs a block author includes in its block, as accepted by
+/// `fulfill_call_inherent`.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq)]
+pub struct StepInherentData {
+	pub function_id: H256,
+	/// CBOR encoded `CompactFunctionInputs`, the light client store is taken from the chain.
+	pub inputs: Vec<u8>,
+}
@@ -1,31 +1,58 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "512"]
 
-use crate::{storage_utils::MessageStatusEnum, verifier::Verifier};
+use crate::{attestation::SignatureProof, storage_utils::MessageStatusEnum, verifier::Verifier};
 use avail_base::{MemoryTemporaryStorage, ProvidePostInherent};
-use avail_core::data_proof::{tx_uid, AddressedMessage, Message, MessageType};
+use avail_core::data_proof::{
+	tx_uid, AddressedMessage, Message, MessageType, BOUNDED_DATA_MAX_LENGTH,
+};
 use helios_common::config::types::Forks;
 use helios_consensus_core::{apply_finality_update, apply_update, verify_finality_update, verify_update, types::{Bytes32, ByteVector, LightClientStore, Update, FinalityUpdate}};
 use alloy_primitives::B256;
 use ssz_rs::prelude::*;
 
-use codec::Compact;
+use codec::{Compact, DecodeAll, DecodeLimit};
 use frame_support::{
 	pallet_prelude::*,
+	storage::{with_storage_layer, with_transaction, TransactionOutcome},
 	traits::{Currency, ExistenceRequirement, UnixTime},
 	PalletId,
 };
 use sp_core::H256;
-use sp_runtime::SaturatedConversion;
+use sp_runtime::{DigestItem, MultiSignature, SaturatedConversion};
 use sp_std::{vec, vec::Vec};
 
+pub mod abi;
+pub mod ack;
+pub mod address;
+pub mod archive;
+pub mod artifact;
+pub mod attestation;
+pub mod beacon;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod compression;
+#[cfg(test)]
+mod conformance_tests;
 pub mod constants;
+pub mod equivocation;
+pub mod evm;
+pub mod fees;
+pub mod finality;
+pub mod handler;
+pub mod hooks;
+pub mod inherent;
 #[cfg(test)]
 mod mock;
-mod state;
+pub mod nonce;
+pub mod outbound;
+pub mod payout;
+pub mod receipt;
+pub mod rollup;
+pub mod state;
 mod storage_utils;
+#[cfg(test)]
+mod test_utils;
 
 // Use new tests made with light client inputs
 // #[cfg(test)]
@@ -40,7 +67,27 @@ pub use pallet::*;
 pub type FunctionInput = BoundedVec<u8, ConstU32<256>>;
 pub type FunctionOutput = BoundedVec<u8, ConstU32<512>>;
 pub type FunctionProof = BoundedVec<u8, ConstU32<1048>>;
-pub type ValidProof = BoundedVec<BoundedVec<u8, ConstU32<2048>>, ConstU32<32>>;
+/// Merkle-Patricia trie proof, bounded by `Config::MaxProofNodes` nodes of at most
+/// `Config::MaxProofNodeSize` bytes each.
+pub type ValidProof<T> =
+	BoundedVec<BoundedVec<u8, <T as Config>::MaxProofNodeSize>, <T as Config>::MaxProofNodes>;
+/// zstd frame of the SCALE encoded `ValidProof`, bounded by `Config::MaxCompressedProofSize`
+/// bytes.
+pub type CompressedProof<T> = BoundedVec<u8, <T as Config>::MaxCompressedProofSize>;
+/// Merkle-Patricia trie multiproof, the deduplicated nodes of the proofs of many keys, bounded by
+/// `Config::MaxMultiProofNodes` nodes of at most `Config::MaxProofNodeSize` bytes each.
+pub type MultiProof<T> =
+	BoundedVec<BoundedVec<u8, <T as Config>::MaxProofNodeSize>, <T as Config>::MaxMultiProofNodes>;
+/// Messages executed together by `execute_messages`.
+pub type MessageBatch<T> = BoundedVec<AddressedMessage, <T as Config>::MaxBatchMessages>;
+/// Proof of the state of a rollup against the execution state root, with a storage multiproof
+/// of the commitment and its finality.
+pub type ValidRollupProof<T> = RollupProof<ValidProof<T>, MultiProof<T>>;
+/// Proof of a beacon block root kept by the EIP-4788 beacon roots contract, with a storage
+/// multiproof of its ring buffer entries.
+pub type ValidBeaconRootsProof<T> = beacon::BeaconRootsProof<ValidProof<T>, MultiProof<T>>;
+/// Proof that an Ethereum account signed the data attested by `attest_signature`.
+pub type ValidSignatureProof<T> = SignatureProof<ValidProof<T>, ValidProof<T>>;
 
 // Avail asset is supported for now
 pub const SUPPORTED_ASSET_ID: H256 = H256::zero();
@@ -48,6 +95,25 @@ pub const FAILED_SEND_MSG_ID: &[u8] = b"vector:failed_send_msg_txs";
 pub const LOG_TARGET: &str = "runtime::vector";
 pub const ROTATE_POSEIDON_OUTPUT_LENGTH: u32 = 32;
 pub const STEP_OUTPUT_LENGTH: u32 = 74;
+/// Maximum number of verified input hashes kept in `VerifiedInputs`.
+pub const MAX_VERIFIED_INPUTS: u32 = 64;
+/// Maximum number of accounts kept in `VerifiedAccountProofs`.
+pub const MAX_VERIFIED_ACCOUNTS: u32 = 16;
+/// Maximum number of accepted headers kept to detect updater equivocations.
+pub const MAX_ACCEPTED_HEADERS: u32 = 64;
+/// Maximum number of queued messages executed when the gap of an ordered channel is filled.
+pub const MAX_DRAINED_MESSAGES: u32 = 4;
+/// Maximum number of ordered channels whose queued messages are executed in `on_idle`.
+pub const MAX_IDLE_CHANNELS: u32 = 16;
+/// Maximum number of sync committee periods backfilled by one call, each of them verified with
+/// a rotate proof.
+pub const MAX_BACKFILLED_PERIODS: u32 = 32;
+/// Number of sync committee periods, up to the one of the head, whose participation is kept.
+pub const MAX_PARTICIPATION_PERIODS: u64 = 128;
+/// Maximum nesting of the calls decoded from governance messages.
+pub const MAX_GOVERNANCE_CALL_DEPTH: u32 = 8;
+/// Maximum length of the CBOR encoded `LightClientContext` kept for compact inputs.
+pub const MAX_LIGHT_CLIENT_STATE_LENGTH: u32 = 256 * 1024;
 
 pub type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -56,23 +122,61 @@ pub type BalanceOf<T> =
 pub mod pallet {
 	use helios_consensus_core::get_bits;
 	use ethabi::Token;
-	use ethabi::Token::Uint;
-	use frame_support::dispatch::GetDispatchInfo;
-	use frame_support::traits::{LockableCurrency, UnfilteredDispatchable};
+	use frame_support::dispatch::{GetDispatchInfo, Pays, PostDispatchInfo, WithPostDispatchInfo};
+	use frame_support::traits::{
+		GetStorageVersion, LockableCurrency, StorageVersion, UnfilteredDispatchable,
+	};
 	use frame_support::{pallet_prelude::ValueQuery, DefaultNoBound};
 	use frame_system::pallet_prelude::*;
-	use primitive_types::H160;
-	use primitive_types::{H256, U256};
+	use primitive_types::{H160, H256, U256};
+	use sp_io::hashing::blake2_256;
 	use sp_io::hashing::keccak_256;
 	use sp_io::hashing::sha2_256;
-	use sp_runtime::traits::AccountIdConversion;
+	use sp_runtime::traits::{AccountIdConversion, One};
+	use sp_runtime::Perbill;
+	use sp_std::boxed::Box;
+	pub use amb_primitives::{EIP1186Layout, KeccakHasher, PlainKeyLayout, ProofLayout};
+	pub use patricia_merkle_trie::{blake2_256::Blake2Hasher, sha256::Sha256Hasher};
 	pub use weights::WeightInfo;
 
-	use crate::state::Configuration;
+	use crate::abi::{self, MessageSchema};
+	use crate::ack::{Acknowledgement, ACK_ID_FLAG, MAX_ACKNOWLEDGEMENTS};
+	use crate::address::{AddressVersion, SourceAddress};
+	use crate::archive::{
+		self, ArchiveProof, ArchivedEpoch, ArchivedSlotList, EpochSlotList,
+		MAX_ARCHIVED_SLOTS_PER_BLOCK,
+	};
+	use crate::artifact::{self, ProofArtifact, MAX_PROOF_ARTIFACTS_PER_BLOCK};
+	use crate::attestation::{self, SignatureKind, MAX_ATTESTED_DATA_LENGTH};
+	use crate::beacon::{self, BeaconRootProof, ConsensusFork, ExecutionBlockNumberProof};
+	use crate::compression::{self, DecompressionError};
+	use crate::equivocation::ReportUpdaterEquivocation;
+	use crate::evm::{self, EvmExecutor};
+	use crate::fees::{self, Multiplier};
+	use crate::finality::{self, FinalityConfig, FinalityError};
+	use crate::handler::DestinationHandler;
+	use crate::hooks::{OnMessageExecuted, OnMessageReceived};
+	use crate::inherent::{StepInherentData, INHERENT_IDENTIFIER};
+	use crate::nonce::{MissingNonces, NonceWindow};
+	use crate::outbound::{self, OutboundMessageList, OutboundRoot, OUTBOUND_ROOT_LOG_PREFIX};
+	use crate::payout;
+	use crate::rollup::{RollupConfig, RollupProof};
+	use crate::state::{
+		AcceptedHeader, BaseFeeOracle, BridgeStatus, Configuration, DegradedReason,
+		ExecutionFailure, ExecutionFee, ExecutionPermit, ExecutionSimulation, FailureCode,
+		ForkTransition, GovernanceCall, HandlerRegistration, MessageDirection, MessageHeader,
+		ParticipationRequirement, PeriodParticipation, ProofTier, ReleaseSchedule, SourceBaseFee,
+		StageResult, StepAcceptance, UpdaterQuota, VerificationTrace, VerifiedAccount,
+		VerifiedFunction, VestedRelease,
+	};
 	use crate::state::{
-		parse_rotate_output, parse_step_output, VerifiedRotate, VerifiedStep, VerifiedStepOutput,
+		check_rotation, parse_rotate_output, parse_step_output, RotationError, VerifiedRotate,
+		VerifiedStep, VerifiedStepOutput,
+	};
+	use crate::storage_utils::{
+		get_account, get_account_of, get_storage_root, get_storage_value, get_storage_values,
+		verify_storage_absence, StorageError,
 	};
-	use crate::storage_utils::{get_storage_root, get_storage_value};
 	use crate::verifier::encode_packed;
 
 	use super::*;
@@ -122,6 +226,168 @@ pub mod pallet {
 		CannotParseOutputData,
 		/// Cannot get current message id
 		CurrentMessageIdNotFound,
+		/// Address cannot be decoded with the source chain address version
+		InvalidSourceAddress,
+		/// Epoch of the slot is not archived
+		EpochNotArchived,
+		/// Slot roots are not part of the archived epoch
+		InvalidArchiveProof,
+		/// Epoch cannot hold more slots
+		TooManySlotsInEpoch,
+		/// Updater is not in the allowlist
+		UpdaterNotFound,
+		/// Updater has used all its submissions for the current session
+		UpdaterQuotaExceeded,
+		/// Source chain does not expose beacon block roots
+		BeaconRootNotSupported,
+		/// Execution state root is not part of the beacon block
+		InvalidBeaconRootProof,
+		/// Beacon roots contract no longer keeps the root of the block at the proven timestamp
+		BeaconRootExpired,
+		/// High-value messages need an elevated origin while the bridge is degraded
+		BridgeDegraded,
+		/// Message is stored in the source chain broadcaster
+		MessageSent,
+		/// Message absence is already proven for the same or a later slot
+		MessageAbsenceAlreadyProven,
+		/// Message data does not match the schema registered for the recipient
+		InvalidMessageData,
+		/// Message id is lower than the next id of its ordered channel
+		MessageOutOfOrder,
+		/// Message is already queued in its ordered channel
+		MessageAlreadyQueued,
+		/// Sender has no ordered channel on the source chain
+		OrderedChannelNotFound,
+		/// Message is not the next message of the ordered channel or belongs to it
+		MessageNotSkippable,
+		/// Message data is longer than the limit of its recipient or `MaxMessageLength`
+		MessageTooLong,
+		/// No more acknowledgements can be queued in this block
+		AcknowledgementQueueFull,
+		/// Domain is whitelisted or is an alias of another domain
+		DomainAlreadyUsed,
+		/// Backfilled sync committees do not end at the stored checkpoint
+		InvalidSyncCommitteeCheckpoint,
+		/// Epoch of the slot is already archived
+		EpochAlreadyArchived,
+		/// Source chain has no base fee oracle
+		BaseFeeOracleNotSet,
+		/// Base fee is already proven for the same or a later slot
+		BaseFeeAlreadyProven,
+		/// The head can only be rewound in source chain reorg recovery mode
+		ReorgRecoveryNotEnabled,
+		/// Rewound slot must be behind the head
+		SlotNotBehindHead,
+		/// More slots would be invalidated than declared
+		TooManyInvalidatedSlots,
+		/// Participation of the step output of the slot is below the one required by the recipient
+		InsufficientParticipation,
+		/// Minimum execution fee is above the maximum one
+		InvalidExecutionFee,
+		/// Fork epoch is before the one of a previous fork or after the one of a later fork
+		InvalidForkEpoch,
+		/// Batch of messages is empty
+		EmptyMessageBatch,
+		/// Messages of a batch are from different source chains
+		MixedSourceChains,
+		/// Execution permit is for another message
+		PermitMessageMismatch,
+		/// Execution permit of the message was already used
+		PermitAlreadyUsed,
+		/// Execution permit is past its deadline
+		PermitExpired,
+		/// Tip of the execution permit is above the bridged amount
+		PermitTipTooHigh,
+		/// Execution permit is not signed by the recipient of the message
+		InvalidPermitSignature,
+		/// Message of the execution permit was queued or failed instead of being executed
+		PermitMessageNotExecuted,
+		/// Account or storage proof has more nodes than `Config::MaxProofDepth`
+		ProofTooDeep,
+		/// No more messages can be sent in this block
+		OutboundQueueFull,
+		/// Code hash of the broadcaster contract differs from the pinned one
+		SourceCodeHashMismatch,
+		/// Rotated sync committee is not the one of the period after the finalized header
+		RotateNotNextPeriod,
+		/// Finalized header of the rotation is not verified by a step
+		RotateHeaderNotVerified,
+		/// Step of the finalized header of the rotation had too little participation
+		RotateParticipationTooLow,
+		/// Inputs of `fulfill_call` cannot be decoded
+		InvalidInputs,
+		/// Compact inputs cannot be completed without the light client state of the head
+		LightClientStateNotSet,
+		/// Slot delta of compact inputs does not lead from the head to the finalized header
+		SlotDeltaMismatch,
+		/// Messages to the Avail domain are executed, they cannot be routed
+		InvalidAppChainDomain,
+		/// Fungible tokens cannot be routed to an app-chain
+		FungibleTokenNotRoutable,
+		/// Message is voided by governance and cannot be executed
+		MessageVoided,
+		/// Source domain is not a rollup anchored on Ethereum
+		RollupNotSupported,
+		/// State commitment is not of the kind of the rollup or its state root cannot be decoded
+		InvalidStateCommitment,
+		/// State commitment does not match the one stored in the rollup contract
+		StateCommitmentMismatch,
+		/// State commitment is not final on Ethereum yet
+		StateCommitmentNotFinal,
+		/// Finalized slot is not the first slot of an epoch of the source chain
+		SlotNotEpochBoundary,
+		/// Finalized slot of an instant finality source chain is not the attested slot
+		FinalizedSlotNotAttested,
+		/// Release schedule does not release over at least one block
+		InvalidReleaseSchedule,
+		/// No amount of the message is vested
+		VestedReleaseNotFound,
+		/// Vested amount is already released
+		NothingToRelease,
+		/// Message of a payout sender is not a valid payout batch
+		InvalidPayoutBatch,
+		/// Chain id the source chain takes at a fork is proven before the fork
+		ForkNotActivated,
+		/// Function ids are scheduled to be activated at a past or the current block
+		ActivationBlockPassed,
+		/// Signature of the attested data is not signed by the Ethereum account
+		InvalidEthereumSignature,
+		/// Contract account did not approve the hash of the attested data
+		SignatureNotApproved,
+		/// Execution block number is not committed to by the header root of the slot
+		InvalidExecutionBlockNumberProof,
+		/// Execution block number of the slot is already proven
+		ExecutionBlockNumberAlreadyProven,
+		/// Call of the EVM contract the message is addressed to reverted
+		EvmCallReverted,
+		/// No destination handler of the runtime has the registered id
+		UnknownDestinationHandler,
+		/// Registration of the destination handler is expired
+		DestinationHandlerExpired,
+		/// Runtime code changed since the destination handler was registered
+		DestinationHandlerCodeChanged,
+		/// No destination handler is registered for the destination
+		DestinationHandlerNotRegistered,
+		/// Expiry of the destination handler is in the past
+		InvalidHandlerExpiry,
+		/// Governance message is not a whitelisted call of the pallet
+		InvalidGovernanceCall,
+		/// No governance call is scheduled for the message
+		GovernanceCallNotFound,
+		/// Delay of the governance call has not elapsed
+		GovernanceCallNotExecutable,
+		/// Call does not match the scheduled governance call
+		GovernanceCallMismatch,
+		/// Compressed proof is not a zstd frame of an encoded proof
+		InvalidCompressedProof,
+		/// Compressed proof decompresses to more than the maximum encoded length of a proof
+		DecompressedProofTooLarge,
+		/// Backfilled sync committee does not rotate into the committee of the next period
+		SyncCommitteeChainMismatch,
+		/// Proof has more or larger nodes than the bridge calls accept
+		ProofTooLarge,
+		/// No execution state root is stored for the slot
+		ExecutionStateRootNotSet,
 	}
 
 	#[pallet::event]
@@ -171,8 +437,274 @@ pub mod pallet {
 		RotateVerificationKeyUpdated {
 			value: Option<BoundedVec<u8, ConstU32<10_000>>>,
 		},
-		/// Emit new updater.
+		/// Emit when the allowlist is replaced by a single updater. `old` is the replaced updater,
+		/// zero if the allowlist did not hold exactly one.
 		NewUpdater { old: H256, new: H256 },
+		/// Emit when source chain address version is updated.
+		SourceAddressVersionUpdated {
+			domain: u32,
+			version: AddressVersion,
+		},
+		/// Emit when per-slot roots of an epoch are archived.
+		EpochArchived { epoch: u64, root: H256, slots: u32 },
+		/// Emit when an updater is added to the allowlist.
+		UpdaterAdded {
+			updater: H256,
+			max_submissions: Option<u32>,
+		},
+		/// Emit when an updater is removed from the allowlist.
+		UpdaterRemoved { updater: H256 },
+		/// Emit when submitted inputs were already verified and the call is refunded.
+		InputsAlreadyVerified { input_hash: H256 },
+		/// Emit when beacon root anchored messages are enabled or disabled for a domain.
+		BeaconRootDomainUpdated { domain: u32, enabled: bool },
+		/// Emit when an anomaly moves the bridge into the degraded status.
+		BridgeDegraded { reason: DegradedReason },
+		/// Emit when the bridge is restored to the operational status.
+		BridgeRestored,
+		/// Emit when it is proven that a message was not sent as of the slot.
+		MessageAbsenceProven {
+			domain: u32,
+			message_id: u64,
+			slot: u64,
+		},
+		/// Emit when the message schema of a recipient is set or removed.
+		MessageSchemaUpdated {
+			to: H256,
+			schema: Option<MessageSchema>,
+		},
+		/// Emit when an updater submits a step conflicting with an accepted header root.
+		/// `reported` is false if no offence could be reported, the updater is then removed from
+		/// the allowlist instead.
+		UpdaterEquivocated {
+			updater: H256,
+			slot: u64,
+			accepted_root: H256,
+			conflicting_root: H256,
+			reported: bool,
+		},
+		/// Emit when an ordered channel is enabled, moved or disabled.
+		OrderedChannelUpdated {
+			domain: u32,
+			from: H256,
+			next_id: Option<u64>,
+		},
+		/// Emit when a message is queued until the previous messages of its ordered channel are executed.
+		MessageQueued {
+			from: H256,
+			to: H256,
+			message_id: u64,
+			message_root: H256,
+		},
+		/// Emit when the next id of an ordered channel is skipped because it belongs to another channel.
+		OrderedMessageSkipped {
+			domain: u32,
+			from: H256,
+			message_id: u64,
+		},
+		/// Emit when acknowledgements are enabled or disabled for a sender.
+		AcknowledgedSenderUpdated {
+			domain: u32,
+			from: H256,
+			enabled: bool,
+		},
+		/// Emit when an acknowledged message fails to execute.
+		MessageExecutionFailed {
+			from: H256,
+			to: H256,
+			message_id: u64,
+			message_root: H256,
+			error: DispatchError,
+		},
+		/// Emit when an acknowledgement is queued for the sender of an executed message.
+		AcknowledgementQueued {
+			to: H256,
+			destination_domain: u32,
+			message_id: u64,
+			success: bool,
+		},
+		/// Emit when the state of a source domain is migrated to a new domain.
+		DomainMigrated { old: u32, new: u32 },
+		/// Emit when the sync committees of the periods before a checkpoint are backfilled.
+		SyncCommitteesBackfilled {
+			first_period: u64,
+			checkpoint_period: u64,
+		},
+		/// Emit when a finalized header below the head is imported.
+		HistoricalHeaderImported {
+			slot: u64,
+			finalization_root: H256,
+			execution_state_root: H256,
+		},
+		/// Emit when the base fee oracle of a domain is set or removed.
+		BaseFeeOracleUpdated {
+			domain: u32,
+			oracle: Option<BaseFeeOracle>,
+		},
+		/// Emit when the base fee of a source chain is proven.
+		SourceBaseFeeUpdated {
+			domain: u32,
+			slot: u64,
+			base_fee: U256,
+		},
+		/// Emit when source chain reorg recovery mode is entered or left.
+		ReorgRecoveryUpdated { enabled: bool },
+		/// Emit when the roots of a slot are removed by a rewind of the head.
+		ExecutionStateRootInvalidated {
+			slot: u64,
+			execution_state_root: H256,
+		},
+		/// Emit when the head is rewound to a lower slot.
+		HeadRewound {
+			from: u64,
+			to: u64,
+			invalidated: u32,
+		},
+		/// Emit when the participation required by a recipient is set or removed.
+		ParticipationRequirementUpdated {
+			to: H256,
+			requirement: Option<ParticipationRequirement>,
+		},
+		/// Emit when the execution fee of an asset is set or removed.
+		ExecutionFeeUpdated {
+			asset_id: H256,
+			fee: Option<ExecutionFee>,
+		},
+		/// Emit when a relayer takes its execution fee out of a bridged amount.
+		ExecutionFeePaid {
+			message_root: H256,
+			relayer: T::AccountId,
+			fee: u128,
+		},
+		/// Emit when the activation epoch of a source chain consensus fork is set or removed.
+		ForkEpochUpdated {
+			fork: ConsensusFork,
+			epoch: Option<u64>,
+		},
+		/// Emit when a message is executed with the execution permit of its recipient.
+		ExecutionPermitUsed {
+			message_root: H256,
+			executor: Option<T::AccountId>,
+			tip: u128,
+		},
+		/// Emit when the messages sent in the block are committed to an outbound root.
+		OutboundRootCommitted { root: H256, leaves: u32 },
+		/// Emit when the code hash of the broadcaster of a domain is pinned or unpinned.
+		PinnedCodeHashUpdated {
+			domain: u32,
+			code_hash: Option<H256>,
+		},
+		/// Emit when the routing of messages to an app-chain is enabled or disabled.
+		AppChainRouteUpdated { domain: u32, enabled: bool },
+		/// Emit when the data length limit of the messages to a recipient is set or removed.
+		MessageLengthLimitUpdated { to: H256, limit: Option<u32> },
+		/// Emit when a message is routed to an app-chain through the outbound root.
+		MessageRouted {
+			from: H256,
+			to: H256,
+			message_id: u64,
+			message_root: H256,
+			destination_domain: u32,
+		},
+		/// Emit when a message is voided by governance.
+		MessageVoided { message_root: H256 },
+		/// Emit when the rollup configuration of a domain is set or removed.
+		RollupConfigUpdated {
+			domain: u32,
+			config: Option<RollupConfig>,
+		},
+		/// Emit when the finality and slot timing of the source chain are updated.
+		FinalityConfigUpdated { config: FinalityConfig },
+		/// Emit when the retention of the accepted proof artifacts is updated.
+		ProofRetentionUpdated {
+			retention: Option<BlockNumberFor<T>>,
+		},
+		/// Emit when the release schedule of an asset is set or removed.
+		ReleaseScheduleUpdated {
+			asset_id: H256,
+			schedule: Option<ReleaseSchedule>,
+		},
+		/// Emit when the amount of an executed message is vested to its recipient instead of
+		/// being transferred.
+		ReleaseScheduled {
+			message_root: H256,
+			recipient: H256,
+			amount: u128,
+			duration: u32,
+		},
+		/// Emit when a vested amount is transferred to its recipient.
+		VestedReleased {
+			message_root: H256,
+			recipient: H256,
+			amount: u128,
+		},
+		/// Emit when payout batches are enabled or disabled for a sender.
+		PayoutSenderUpdated {
+			domain: u32,
+			from: H256,
+			enabled: bool,
+		},
+		/// Emit when the payouts of a batch are transferred to their recipients.
+		PayoutsExecuted {
+			message_root: H256,
+			asset_id: H256,
+			count: u32,
+			total: u128,
+		},
+		/// Emit the decoded header of a message when it is executed or its execution fails.
+		MessageHeaderRecorded {
+			message_root: H256,
+			header: MessageHeader,
+		},
+		/// Emit when the chain id of a source domain changes at a fork.
+		ForkTransitionScheduled {
+			old: u32,
+			new: u32,
+			activation_slot: u64,
+			broadcaster: H256,
+		},
+		/// Emit function ids scheduled to replace the current ones at `activation_block`.
+		FunctionIdsScheduled {
+			value: (H256, H256),
+			activation_block: BlockNumberFor<T>,
+		},
+		/// Emit scheduled function ids once they replaced the current ones.
+		FunctionIdsActivated { value: (H256, H256) },
+		/// Emit when an Ethereum account is proven to have signed the data of `message_hash`.
+		SignatureAttested {
+			signer: H160,
+			message_hash: H256,
+			kind: SignatureKind,
+		},
+		/// Emit when the execution block number of a finalized slot is proven.
+		ExecutionBlockNumberProven { slot: u64, block_number: u64 },
+		/// Emit when a runtime handler is registered for the messages to `destination`.
+		DestinationHandlerRegistered {
+			destination: H256,
+			handler: u32,
+			code_hash: H256,
+			expiry: Option<u32>,
+		},
+		/// Emit when the runtime handler of `destination` is deregistered.
+		DestinationHandlerDeregistered { destination: H256 },
+		/// Emit when the governance of the source chain is set or removed.
+		GovernanceSenderUpdated { sender: Option<(u32, H256)> },
+		/// Emit when a governance message schedules a call of the pallet.
+		GovernanceCallScheduled {
+			message_root: H256,
+			call_hash: H256,
+			executable_at: u32,
+		},
+		/// Emit when a scheduled governance call is cancelled.
+		GovernanceCallCancelled { message_root: H256 },
+		/// Emit when a scheduled governance call is executed, with its result.
+		GovernanceCallExecuted {
+			message_root: H256,
+			result: DispatchResult,
+		},
+		/// Emit when a rewind of the head to `slot` clears the light client state. Compact inputs
+		/// are rejected until full inputs are accepted.
+		LightClientStateCleared { slot: u64 },
 	}
 
 	/// Storage for a head updates.
@@ -189,10 +721,37 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Timestamps<T> = StorageMap<_, Identity, u64, u64, ValueQuery>;
 
+	/// Maps from a slot to the timestamp of its finalized header on the source chain, in seconds.
+	#[pallet::storage]
+	#[pallet::getter(fn header_timestamps)]
+	pub type HeaderTimestamps<T> = StorageMap<_, Identity, u64, u64, OptionQuery>;
+
 	/// Maps from a slot to the current finalized ethereum execution state root.
 	#[pallet::storage]
 	pub type ExecutionStateRoots<T> = StorageMap<_, Identity, u64, H256, ValueQuery>;
 
+	/// Maps from a slot to the number of its finalized execution block, once it is proven.
+	#[pallet::storage]
+	#[pallet::getter(fn block_number_for_slot)]
+	pub type ExecutionBlockNumbers<T> = StorageMap<_, Identity, u64, u64, OptionQuery>;
+
+	/// Maps from an epoch to the slots with roots which are not archived yet.
+	#[pallet::storage]
+	pub type EpochSlots<T> = StorageMap<_, Identity, u64, EpochSlotList, ValueQuery>;
+
+	/// Maps from an epoch to the Merkle root over its archived slot roots.
+	#[pallet::storage]
+	#[pallet::getter(fn archived_epochs)]
+	pub type ArchivedEpochs<T> = StorageMap<_, Identity, u64, ArchivedEpoch, OptionQuery>;
+
+	/// Next epoch to be archived once it leaves the retention window.
+	#[pallet::storage]
+	pub type NextArchiveEpoch<T> = StorageValue<_, Option<u64>, ValueQuery>;
+
+	/// Slots of `NextArchiveEpoch` already archived, kept until the root of the epoch is stored.
+	#[pallet::storage]
+	pub type ArchivedSlots<T> = StorageValue<_, ArchivedSlotList, ValueQuery>;
+
 	/// DEPRECATED: Use SyncCommitteeHashes instead.
 	/// Maps from a period to the poseidon commitment for the sync committee.
 	#[pallet::storage]
@@ -216,10 +775,228 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Broadcasters<T> = StorageMap<_, Identity, u32, H256, ValueQuery>;
 
+	/// Mapping between source chainId and the address format used on that chain.
+	#[pallet::storage]
+	pub type SourceAddressVersions<T> =
+		StorageMap<_, Identity, u32, AddressVersion, ValueQuery>;
+
 	/// Flags source chain to be frozen.
 	#[pallet::storage]
 	pub type SourceChainFrozen<T> = StorageMap<_, Identity, u32, bool, ValueQuery>;
 
+	/// Domains that expose the beacon block root (EIP-4788), so their messages can be proven
+	/// against the beacon block header root.
+	#[pallet::storage]
+	pub type BeaconRootDomains<T> = StorageMap<_, Identity, u32, bool, ValueQuery>;
+
+	/// Maps from a source domain and message id to the latest slot as of which the message
+	/// is proven to not be sent.
+	#[pallet::storage]
+	pub type AbsentMessages<T> =
+		StorageDoubleMap<_, Identity, u32, Identity, u64, u64, OptionQuery>;
+
+	/// Maps from a recipient to the ABI schema its arbitrary messages are decoded with.
+	/// Messages to recipients without a schema are executed with opaque data.
+	#[pallet::storage]
+	pub type MessageSchemas<T> = StorageMap<_, Identity, H256, MessageSchema, OptionQuery>;
+
+	/// Maps from a recipient to the data length limit of its arbitrary messages, overriding
+	/// `MaxMessageLength`.
+	#[pallet::storage]
+	pub type MessageLengthLimits<T> = StorageMap<_, Identity, H256, u32, OptionQuery>;
+
+	/// Maps from a source domain and sender to the id of the next message of its ordered channel.
+	/// Messages of senders without an ordered channel are executed in any order.
+	#[pallet::storage]
+	pub type OrderedChannels<T> =
+		StorageDoubleMap<_, Identity, u32, Identity, H256, u64, OptionQuery>;
+
+	/// Proven messages of ordered channels, keyed by (domain, sender) and message id, waiting for
+	/// the messages before them to be executed.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub type QueuedMessages<T> =
+		StorageDoubleMap<_, Identity, (u32, H256), Identity, u64, AddressedMessage, OptionQuery>;
+
+	/// Ordered channel after which `on_idle` resumes executing queued messages, so that every
+	/// channel gets its turn across blocks.
+	#[pallet::storage]
+	pub type IdleExecutionCursor<T> = StorageValue<_, (u32, H256), OptionQuery>;
+
+	/// Senders, by source domain, whose messages are acknowledged back to them once executed.
+	#[pallet::storage]
+	pub type AcknowledgedSenders<T> =
+		StorageDoubleMap<_, Identity, u32, Identity, H256, bool, ValueQuery>;
+
+	/// Senders, by source domain, whose messages are batches of payouts of the bridged assets,
+	/// e.g. the contract batching deposits on the source chain.
+	#[pallet::storage]
+	pub type PayoutSenders<T> =
+		StorageDoubleMap<_, Identity, u32, Identity, H256, bool, ValueQuery>;
+
+	/// Maps from a migrated source domain to the domain its state was moved to. Messages still
+	/// carrying the old domain are executed with the state of the new one.
+	#[pallet::storage]
+	pub type DomainAliases<T> = StorageMap<_, Identity, u32, u32, OptionQuery>;
+
+	/// Maps from the chain id of a source domain before a fork to the transition to the chain id
+	/// it took at the fork. The messages of the old chain id are proven against the broadcaster
+	/// they were sent with before the fork.
+	#[pallet::storage]
+	pub type ForkTransitions<T> = StorageMap<_, Identity, u32, ForkTransition, OptionQuery>;
+
+	/// Maps from the chain id a source domain took at a fork to the first slot of the fork. Its
+	/// messages are not valid at earlier slots.
+	#[pallet::storage]
+	pub type ForkActivations<T> = StorageMap<_, Identity, u32, u64, OptionQuery>;
+
+	/// Outbound acknowledgement messages queued in the current block, to be relayed to the
+	/// source chains. Cleared at the start of every block.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub type OutboundAcknowledgements<T> =
+		StorageValue<_, BoundedVec<AddressedMessage, ConstU32<MAX_ACKNOWLEDGEMENTS>>, ValueQuery>;
+
+	/// Roots of the messages sent from Avail in the current block, acknowledgements included,
+	/// in sending order. Cleared at the start of every block.
+	#[pallet::storage]
+	pub type OutboundMessages<T> = StorageValue<_, OutboundMessageList, ValueQuery>;
+
+	/// Merkle root over the `OutboundMessages` of the block, committed at its end. Destination
+	/// chains verify the root of a block once and each message with a Merkle branch against it.
+	/// Cleared at the start of every block.
+	#[pallet::storage]
+	pub type OutboundMessagesRoot<T> = StorageValue<_, OutboundRoot, OptionQuery>;
+
+	/// Accounts proven by the account proofs of the current block. The messages of a broadcaster
+	/// share its account proof, which is only hashed and walked for the first of them. Cleared at
+	/// the end of every block.
+	#[pallet::storage]
+	pub type VerifiedAccountProofs<T> =
+		StorageValue<_, BoundedVec<VerifiedAccount, ConstU32<MAX_VERIFIED_ACCOUNTS>>, ValueQuery>;
+
+	#[pallet::type_value]
+	pub fn DefaultBridgeFeeMultiplier() -> Multiplier {
+		Multiplier::one()
+	}
+
+	/// Multiplier of the weight fee of bridge proof submissions, applied instead of the
+	/// multiplier of the other transactions so that a surge of data submissions does not price
+	/// out the updates of the light client.
+	#[pallet::storage]
+	#[pallet::getter(fn bridge_fee_multiplier)]
+	pub type BridgeFeeMultiplier<T> =
+		StorageValue<_, Multiplier, ValueQuery, DefaultBridgeFeeMultiplier>;
+
+	/// Weight used by the bridge proof submissions of the current block.
+	#[pallet::storage]
+	pub type BridgeFeeUsage<T> = StorageValue<_, Weight, ValueQuery>;
+
+	/// Maps from a source domain to the contract keeping its base fee.
+	#[pallet::storage]
+	pub type BaseFeeOracles<T> = StorageMap<_, Identity, u32, BaseFeeOracle, OptionQuery>;
+
+	/// Maps from a source domain to its latest proven base fee, used to quote the fees of
+	/// outbound messages.
+	#[pallet::storage]
+	#[pallet::getter(fn source_base_fee)]
+	pub type SourceBaseFees<T> = StorageMap<_, Identity, u32, SourceBaseFee, OptionQuery>;
+
+	/// Whether the source chain reorg recovery mode is on, in which the head can be rewound.
+	#[pallet::storage]
+	pub type ReorgRecovery<T> = StorageValue<_, bool, ValueQuery>;
+
+	/// Maps from a slot to the participation of the step output which finalized it. It is
+	/// archived with the slot roots, and proven with them by `execute_archived`.
+	#[pallet::storage]
+	pub type SlotParticipation<T> = StorageMap<_, Identity, u64, u16, OptionQuery>;
+
+	/// Maps from a sync committee period to the participation of the step outputs accepted
+	/// during it. Only the last `MAX_PARTICIPATION_PERIODS` periods are kept.
+	#[pallet::storage]
+	pub type ParticipationHistory<T> =
+		StorageMap<_, Identity, u64, PeriodParticipation, OptionQuery>;
+
+	/// Maps from a recipient to the participation it requires to execute its messages.
+	#[pallet::storage]
+	pub type ParticipationRequirements<T> =
+		StorageMap<_, Identity, H256, ParticipationRequirement, OptionQuery>;
+
+	/// Maps from an asset to the fee relayers take out of its bridged amounts.
+	#[pallet::storage]
+	#[pallet::getter(fn execution_fee)]
+	pub type ExecutionFees<T> = StorageMap<_, Identity, H256, ExecutionFee, OptionQuery>;
+
+	/// Maps from an asset to the schedule its large bridged amounts are released with.
+	#[pallet::storage]
+	#[pallet::getter(fn release_schedule)]
+	pub type ReleaseSchedules<T> = StorageMap<_, Identity, H256, ReleaseSchedule, OptionQuery>;
+
+	/// Maps from a message root to the amount of the message vested to its recipient, until it
+	/// is fully released.
+	#[pallet::storage]
+	#[pallet::getter(fn vested_release)]
+	pub type VestedReleases<T> = StorageMap<_, Identity, H256, VestedRelease, OptionQuery>;
+
+	/// Maps from a source chain consensus fork to its activation epoch. A fork without an
+	/// epoch is active from genesis.
+	#[pallet::storage]
+	pub type ForkEpochs<T> = StorageMap<_, Identity, ConsensusFork, u64, OptionQuery>;
+
+	/// Maps from a message root to the block its execution permit was used at.
+	#[pallet::storage]
+	pub type UsedPermits<T: Config> = StorageMap<_, Identity, H256, BlockNumberFor<T>, OptionQuery>;
+
+	/// Maps from a message root to the failure of its execution, for messages with the
+	/// `ExecutionFailed` status.
+	#[pallet::storage]
+	#[pallet::getter(fn message_failure)]
+	pub type MessageFailures<T> = StorageMap<_, Identity, H256, ExecutionFailure, OptionQuery>;
+
+	/// Maps from a message root to the decoded header of the message, once it is executed or its
+	/// execution failed, so that explorers can show who sent what without decoding the calls.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	#[pallet::getter(fn message_header)]
+	pub type MessageHeaders<T> = StorageMap<_, Identity, H256, MessageHeader, OptionQuery>;
+
+	/// Maps from a message root to the block in which governance voided the message, e.g. as the
+	/// result of an exploit of the source chain. Voided messages are never executed.
+	#[pallet::storage]
+	pub type VoidedMessages<T: Config> =
+		StorageMap<_, Identity, H256, BlockNumberFor<T>, OptionQuery>;
+
+	/// Maps from a source domain and broadcaster to the message ids executed within
+	/// `NONCE_WINDOW` of the highest one, to detect messages skipped by the relayers.
+	#[pallet::storage]
+	pub type NonceWindows<T> =
+		StorageDoubleMap<_, Identity, u32, Identity, H256, NonceWindow, ValueQuery>;
+
+	/// Maps from the domain of a rollup to the contract on Ethereum committing to its states.
+	/// Messages of these domains are proven against the rollup state, itself proven against the
+	/// execution state root.
+	#[pallet::storage]
+	pub type RollupConfigs<T> = StorageMap<_, Identity, u32, RollupConfig, OptionQuery>;
+
+	/// Maps from a domain to the code hash its broadcaster contract must have. Messages are not
+	/// accepted from a broadcaster whose code changed until the new code hash is pinned.
+	#[pallet::storage]
+	pub type PinnedCodeHashes<T> = StorageMap<_, Identity, u32, H256, OptionQuery>;
+
+	/// Domains of the app-chains connected to Avail. Proven messages to these domains are not
+	/// executed on Avail but added to the outbound root, for the app-chain to execute them.
+	#[pallet::storage]
+	pub type AppChainRoutes<T> = StorageMap<_, Identity, u32, bool, ValueQuery>;
+
+	/// Operational status of the bridge.
+	#[pallet::storage]
+	#[pallet::getter(fn status)]
+	pub type Status<T> = StorageValue<_, BridgeStatus, ValueQuery>;
+
+	/// Number of consecutive updates accepted with participation just above the finality threshold.
+	#[pallet::storage]
+	pub type LowParticipationStreak<T> = StorageValue<_, u32, ValueQuery>;
+
 	/// List of permitted domains.
 	#[pallet::storage]
 	pub type WhitelistedDomains<T> = StorageValue<_, BoundedVec<u32, ConstU32<10_000>>, ValueQuery>;
@@ -233,6 +1010,20 @@ pub mod pallet {
 	#[pallet::getter(fn function_ids)]
 	pub type FunctionIds<T: Config> = StorageValue<_, Option<(H256, H256)>, ValueQuery>;
 
+	/// Step and rotate function ids replacing `FunctionIds` at the start of the block they are
+	/// scheduled at, so that the updaters can switch to redeployed circuits at a known block.
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_function_ids)]
+	pub type ScheduledFunctionIds<T: Config> =
+		StorageValue<_, (BlockNumberFor<T>, (H256, H256)), OptionQuery>;
+
+	/// Maps from an Ethereum account and the `personal_sign` hash of data it signed to the
+	/// block its signature was attested at.
+	#[pallet::storage]
+	#[pallet::getter(fn attestation)]
+	pub type Attestations<T: Config> =
+		StorageDoubleMap<_, Identity, H160, Identity, H256, BlockNumberFor<T>, OptionQuery>;
+
 	/// Step verification key storage.
 	#[pallet::storage]
 	#[pallet::getter(fn step_verification_key)]
@@ -250,35 +1041,97 @@ pub mod pallet {
 	#[pallet::getter(fn genesis_validator_root)]
 	pub type GenesisValidatorRoot<T: Config> = StorageValue<_, H256, ValueQuery>;
 
-	/// Genesis timestamp, used to check initialization.
-	#[pallet::storage]
-	#[pallet::getter(fn genesis_timestamp)]
-	pub type GenesisTimestamp<T: Config> = StorageValue<_, u64, ValueQuery>;
-
-	/// Seconds per slot, used to check initialization.
+	/// Finality and slot timing of the source chain, used to check initialization.
 	#[pallet::storage]
-	#[pallet::getter(fn seconds_per_slot)]
-	pub type SecondsPerSlot<T: Config> = StorageValue<_, u64, ValueQuery>;
+	#[pallet::getter(fn finality)]
+	pub type Finality<T: Config> = StorageValue<_, FinalityConfig, ValueQuery>;
 
 	/// Source chain id, used to check initialization.
 	#[pallet::storage]
 	#[pallet::getter(fn source_chain_id)]
 	pub type SourceChainId<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+	/// DEPRECATED: Use Updaters instead, the updater is migrated into the allowlist.
 	/// Updater that can submit updates
 	#[pallet::storage]
 	#[pallet::getter(fn updater)]
-	pub type Updater<T: Config> = StorageValue<_, H256, ValueQuery>;
+	pub type Updater<T: Config> = StorageValue<_, H256, OptionQuery>;
+
+	/// Allowlist of updaters with their per-session submission quota.
+	#[pallet::storage]
+	#[pallet::getter(fn updaters)]
+	pub type Updaters<T: Config> = StorageMap<_, Identity, H256, UpdaterQuota, OptionQuery>;
+
+	/// Light client state of the head, the CBOR encoded `LightClientContext` after the last
+	/// accepted `fulfill_call`, which completes compact inputs.
+	#[pallet::storage]
+	pub type LightClientState<T> =
+		StorageValue<_, BoundedVec<u8, ConstU32<MAX_LIGHT_CLIENT_STATE_LENGTH>>, OptionQuery>;
+
+	/// Hashes of recently verified `fulfill_call` inputs with the block at which they expire,
+	/// in insertion order.
+	#[pallet::storage]
+	#[pallet::getter(fn verified_inputs)]
+	pub type VerifiedInputs<T: Config> = StorageValue<
+		_,
+		BoundedVec<(H256, BlockNumberFor<T>), ConstU32<MAX_VERIFIED_INPUTS>>,
+		ValueQuery,
+	>;
+
+	/// Recently accepted header roots with their updaters, in insertion order.
+	#[pallet::storage]
+	pub type AcceptedHeaders<T> =
+		StorageValue<_, BoundedVec<AcceptedHeader, ConstU32<MAX_ACCEPTED_HEADERS>>, ValueQuery>;
+
+	/// Number of blocks the accepted `fulfill_call` inputs are kept in the offchain storage for
+	/// audit, they are not kept if not set.
+	#[pallet::storage]
+	#[pallet::getter(fn proof_retention)]
+	pub type ProofRetention<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// Hashes of the accepted `fulfill_call` inputs kept in the offchain storage, by the block at
+	/// which they are removed from it.
+	#[pallet::storage]
+	pub type ProofArtifactExpiries<T: Config> = StorageMap<
+		_,
+		Identity,
+		BlockNumberFor<T>,
+		BoundedVec<H256, ConstU32<MAX_PROOF_ARTIFACTS_PER_BLOCK>>,
+		ValueQuery,
+	>;
+
+	/// Maps from a destination address to the runtime handler its arbitrary messages are handed
+	/// to.
+	#[pallet::storage]
+	pub type DestinationHandlers<T> =
+		StorageMap<_, Identity, H256, HandlerRegistration, OptionQuery>;
+
+	/// Hash of the runtime code, updated on runtime upgrades.
+	#[pallet::storage]
+	pub type RuntimeCodeHash<T> = StorageValue<_, H256, ValueQuery>;
+
+	/// Domain and address of the governance of the source chain, whose messages schedule calls
+	/// of the pallet.
+	#[pallet::storage]
+	pub type GovernanceSender<T> = StorageValue<_, (u32, H256), OptionQuery>;
+
+	/// Maps from the root of a governance message to the call it scheduled.
+	#[pallet::storage]
+	pub type GovernanceCalls<T> = StorageMap<_, Identity, H256, GovernanceCall, OptionQuery>;
 
 	/// Default implementations of [`DefaultConfig`], which can be used to implement [`Config`].
 	pub mod config_preludes {
 		use super::*;
 		use frame_support::derive_impl;
 		use frame_support::parameter_types;
-		use frame_support::traits::ConstU64;
+		use frame_support::traits::{ConstBool, ConstU128, ConstU16, ConstU64};
+		use sp_runtime::FixedPointNumber;
 
 		parameter_types! {
 			pub const BridgePalletId: PalletId = PalletId(*b"avl/brdg");
+			pub BridgeFeeTarget: Weight = Weight::from_parts(1_000_000_000, 0);
+			pub BridgeFeeAdjustment: Multiplier = Multiplier::saturating_from_rational(1, 100);
+			pub MaximumBridgeFeeMultiplier: Multiplier = Multiplier::saturating_from_integer(100);
 		}
 
 		/// Provides a viable default config that can be used with
@@ -299,6 +1152,33 @@ pub mod pallet {
 			#[inject_runtime_type]
 			type RuntimeCall = ();
 			type PalletId = BridgePalletId;
+			type SlotRetention = ConstU64<262_144>;
+			type ArchiveEpochSlots = ConstU64<8192>;
+			type CurrentSession = ConstU32<0>;
+			type VerifiedInputsExpiry = ConstU32<10>;
+			type SourceChainHasher = KeccakHasher;
+			type SourceChainTrieLayout = EIP1186Layout<KeccakHasher>;
+			type LowParticipationMargin = ConstU16<10>;
+			type MaxLowParticipationUpdates = ConstU32<3>;
+			type HighValueAmount = ConstU128<100_000_000_000_000_000_000>;
+			type EquivocationReporter = ();
+			type MaxProofNodes = ConstU32<32>;
+			type MaxProofNodeSize = ConstU32<2048>;
+			type MaxProofDepth = ConstU32<16>;
+			type MaxCompressedProofSize = ConstU32<{ 16 * 1024 }>;
+			type MaxMessageLength = ConstU32<BOUNDED_DATA_MAX_LENGTH>;
+			type MaxBatchMessages = ConstU32<16>;
+			type MaxMultiProofNodes = ConstU32<256>;
+			type DepositOutboundRootLog = ConstBool<false>;
+			type BridgeFeeTarget = BridgeFeeTarget;
+			type BridgeFeeAdjustment = BridgeFeeAdjustment;
+			type MaximumBridgeFeeMultiplier = MaximumBridgeFeeMultiplier;
+			type EvmExecutor = ();
+			type EvmGasLimit = ConstU64<1_000_000>;
+			type OnMessageReceived = ();
+			type OnMessageExecuted = ();
+			type DestinationHandlers = ();
+			type GovernanceDelay = ConstU32<100>;
 		}
 	}
 
@@ -330,6 +1210,92 @@ pub mod pallet {
 		/// Unique value associated with Avail Network. Used to distinguish messages between Avail and non-Avail networks.
 		#[pallet::constant]
 		type AvailDomain: Get<u32>;
+		/// Number of slots behind the head for which per-slot roots are kept in storage.
+		#[pallet::constant]
+		type SlotRetention: Get<u64>;
+		/// Number of slots aggregated into a single archived epoch root.
+		/// Must not exceed `archive::MAX_SLOTS_PER_EPOCH`.
+		#[pallet::constant]
+		type ArchiveEpochSlots: Get<u64>;
+		/// Current session index, used to reset updater submission quotas.
+		type CurrentSession: Get<u32>;
+		/// Number of blocks for which verified inputs are cached.
+		#[pallet::constant]
+		type VerifiedInputsExpiry: Get<u32>;
+		/// Hasher of the source chain state trie, e.g. keccak for EVM chains.
+		type SourceChainHasher: trie_db::Hasher<Out = H256>;
+		/// Layout of the source chain state trie, e.g. `EIP1186Layout` for Ethereum. It defines
+		/// how accounts and storage slots are keyed in the proven tries.
+		type SourceChainTrieLayout: ProofLayout<Hash = Self::SourceChainHasher>;
+		/// Participation below `finality_threshold + LowParticipationMargin` counts as low.
+		#[pallet::constant]
+		type LowParticipationMargin: Get<u16>;
+		/// Number of consecutive low participation updates after which the bridge is degraded.
+		#[pallet::constant]
+		type MaxLowParticipationUpdates: Get<u32>;
+		/// Fungible token amount from which a message needs `DegradedOrigin` while degraded.
+		#[pallet::constant]
+		type HighValueAmount: Get<u128>;
+		/// Origin that executes high-value messages while degraded and restores the bridge.
+		#[pallet::no_default]
+		type DegradedOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Reports updaters finalizing conflicting header roots, e.g. to the offences pallet.
+		type EquivocationReporter: ReportUpdaterEquivocation;
+		/// Maximum number of nodes of an account or storage proof.
+		#[pallet::constant]
+		type MaxProofNodes: Get<u32>;
+		/// Maximum size in bytes of a proof node.
+		#[pallet::constant]
+		type MaxProofNodeSize: Get<u32>;
+		/// Maximum number of nodes of an account or storage proof accepted for verification,
+		/// the depth of the proven key in the trie. Must not exceed `MaxProofNodes`.
+		#[pallet::constant]
+		type MaxProofDepth: Get<u32>;
+		/// Maximum size in bytes of a zstd compressed account or storage proof. Proofs decompress
+		/// to at most the maximum encoded length of `ValidProof`.
+		#[pallet::constant]
+		type MaxCompressedProofSize: Get<u32>;
+		/// Maximum length in bytes of the data of an arbitrary message, sent or executed.
+		/// Must not exceed `BOUNDED_DATA_MAX_LENGTH`.
+		#[pallet::constant]
+		type MaxMessageLength: Get<u32>;
+		/// Maximum number of messages executed by a single `execute_messages`.
+		#[pallet::constant]
+		type MaxBatchMessages: Get<u32>;
+		/// Maximum number of nodes of a storage multiproof.
+		#[pallet::constant]
+		type MaxMultiProofNodes: Get<u32>;
+		/// Whether the outbound root of a block is also deposited in its header digest, for
+		/// destination chains following the headers rather than the state.
+		#[pallet::constant]
+		type DepositOutboundRootLog: Get<bool>;
+		/// Weight of bridge proof submissions per block above which their fee multiplier
+		/// increases, and below which it decreases.
+		#[pallet::constant]
+		type BridgeFeeTarget: Get<Weight>;
+		/// How fast the fee multiplier of bridge proof submissions follows their usage.
+		#[pallet::constant]
+		type BridgeFeeAdjustment: Get<Multiplier>;
+		/// Upper bound of the fee multiplier of bridge proof submissions.
+		#[pallet::constant]
+		type MaximumBridgeFeeMultiplier: Get<Multiplier>;
+		/// Executes the messages addressed to EVM contracts, `()` for runtimes without an EVM
+		/// pallet.
+		type EvmExecutor: EvmExecutor;
+		/// Maximum gas spent by the call of the EVM contract a message is addressed to.
+		#[pallet::constant]
+		type EvmGasLimit: Get<u64>;
+		/// Hooks of the runtime pallets reacting to the messages whose proof is verified.
+		type OnMessageReceived: OnMessageReceived;
+		/// Hooks of the runtime pallets reacting to the executed messages.
+		type OnMessageExecuted: OnMessageExecuted;
+		/// Handlers of the runtime pallets the messages to the registered destinations are handed
+		/// to.
+		type DestinationHandlers: DestinationHandler;
+		/// Number of blocks between the execution of a governance message and the earliest
+		/// execution of the call it scheduled, during which the call can be cancelled.
+		#[pallet::constant]
+		type GovernanceDelay: Get<u32>;
 	}
 
 	#[pallet::genesis_config]
@@ -346,9 +1312,11 @@ pub mod pallet {
 		pub rotate_verification_key: Vec<u8>,
 		pub whitelisted_domains: Vec<u32>,
 		pub genesis_validator_root: H256,
-		pub genesis_time: u64,
-		pub seconds_per_slot: u64,
+		pub finality: FinalityConfig,
 		pub source_chain_id: u64,
+		pub updater: H256,
+		/// Pre-initialized `(slot, header root, execution state root)` entries, for dev chains.
+		pub slot_roots: Vec<(u64, H256, H256)>,
 		pub _phantom: PhantomData<T>,
 	}
 
@@ -387,29 +1355,137 @@ pub mod pallet {
 
 			GenesisValidatorRoot::<T>::set(self.genesis_validator_root);
 
-			GenesisTimestamp::<T>::set(self.genesis_time);
-
-			SecondsPerSlot::<T>::set(self.seconds_per_slot);
+			Finality::<T>::set(self.finality);
 
 			SourceChainId::<T>::set(self.source_chain_id);
+
+			Updaters::<T>::insert(self.updater, UpdaterQuota::default());
+
+			// Slots are kept in order, as expected by the epoch archive.
+			let mut slot_roots = self.slot_roots.clone();
+			slot_roots.sort_by_key(|(slot, _, _)| *slot);
+			for (slot, header_root, execution_state_root) in slot_roots {
+				let epoch = archive::epoch_of(slot, T::ArchiveEpochSlots::get());
+				EpochSlots::<T>::try_mutate(epoch, |slots| slots.try_push(slot))
+					.expect("Too many genesis slots in a single epoch.");
+				if NextArchiveEpoch::<T>::get().is_none() {
+					NextArchiveEpoch::<T>::set(Some(epoch));
+				}
+
+				Head::<T>::set(slot);
+				Headers::<T>::insert(slot, header_root);
+				ExecutionStateRoots::<T>::insert(slot, execution_state_root);
+				let timestamp = self.finality.slot_timestamp(slot);
+				Timestamps::<T>::insert(slot, timestamp);
+				HeaderTimestamps::<T>::insert(slot, timestamp);
+			}
+
+			// The runtime code is set by the system pallet, built before.
+			Pallet::<T>::note_runtime_code();
 		}
 	}
 
+	/// The in-code storage version, bumped when the slot timing and the updater were migrated into
+	/// `Finality` and `Updaters`.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	#[pallet::inherent]
+	impl<T: Config> ProvideInherent for Pallet<T>
+	where
+		[u8; 32]: From<T::AccountId>,
+	{
+		type Call = Call<T>;
+		type Error = frame_support::inherent::MakeFatalError<()>;
+		const INHERENT_IDENTIFIER: InherentIdentifier = INHERENT_IDENTIFIER;
+
+		fn create_inherent(data: &InherentData) -> Option<Self::Call> {
+			let step = data
+				.get_data::<StepInherentData>(&INHERENT_IDENTIFIER)
+				.ok()
+				.flatten()?;
+			// the inputs were already submitted by an updater
+			let input_hash = H256(keccak_256(step.inputs.as_slice()));
+			if Self::is_input_verified(input_hash) {
+				return None;
+			}
+			// only compact inputs, completed with the light client state of the chain, are included
+			Self::decode_compact_inputs(&step.inputs).ok()?;
+
+			Some(Call::fulfill_call_inherent {
+				function_id: step.function_id,
+				inputs: step.inputs,
+			})
+		}
+
+		fn is_inherent(call: &Self::Call) -> bool {
+			matches!(call, Call::fulfill_call_inherent { .. })
+		}
+
+		fn check_inherent(call: &Self::Call, _: &InherentData) -> Result<(), Self::Error> {
+			let Call::fulfill_call_inherent { inputs, .. } = call else {
+				return Ok(());
+			};
+			Self::decode_compact_inputs(inputs)
+				.map(|_| ())
+				.map_err(|_| ().into())
+		}
+	}
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
 			if let Some(failed_txs) =
 				MemoryTemporaryStorage::take::<Vec<Compact<u32>>>(FAILED_SEND_MSG_ID)
 			{
 				log::trace!(target: LOG_TARGET, "Failed Txs cleaned: {failed_txs:?}");
 			}
+			OutboundAcknowledgements::<T>::kill();
+			OutboundMessages::<T>::kill();
+			OutboundMessagesRoot::<T>::kill();
+			BridgeFeeUsage::<T>::kill();
+			Self::expire_proof_artifacts(n);
+			Self::activate_function_ids(n);
+
+			// The fee multiplier of bridge proof submissions is updated on finalize.
+			T::DbWeight::get()
+				.reads_writes(4, 9)
+				.saturating_add(T::WeightInfo::commit_outbound_root(0))
+		}
 
-			Weight::zero()
+		fn on_runtime_upgrade() -> Weight {
+			let mut weight = Self::note_runtime_code().saturating_add(T::DbWeight::get().reads(1));
+			if Pallet::<T>::on_chain_storage_version() < STORAGE_VERSION {
+				weight = weight
+					.saturating_add(Self::migrate_finality_config())
+					.saturating_add(Self::migrate_updater())
+					.saturating_add(T::DbWeight::get().writes(1));
+				STORAGE_VERSION.put::<Pallet<T>>();
+			}
+			weight
 		}
-	}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let executed = Self::execute_queued_messages(remaining_weight);
+			executed.saturating_add(Self::archive_next_epoch(
+				remaining_weight.saturating_sub(executed),
+			))
+		}
+
+		fn on_finalize(_n: BlockNumberFor<T>) {
+			Self::commit_outbound_root();
+			Self::update_bridge_fee_multiplier();
+			VerifiedAccountProofs::<T>::kill();
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::check_invariants().map_err(Into::into)
+		}
+	}
 	// TODO: Rename to FunctionInput
 	#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
 	pub struct FunctionInputs {
@@ -422,6 +1498,26 @@ pub mod pallet {
 		pub execution_state_proof: ExecutionStateProof,
 	}
 
+	/// Inputs of `fulfill_call` without the light client state, which is completed from the
+	/// `LightClientState` of the head. They are enough for an updater submitting every step.
+	#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+	pub struct CompactFunctionInputs {
+		/// Slots from the head to the finalized header of `finality_update`.
+		pub slot_delta: u64,
+		pub updates: Vec<Update>,
+		pub finality_update: FinalityUpdate,
+		pub expected_current_slot: u64,
+		pub execution_state_proof: ExecutionStateProof,
+	}
+
+	/// Part of `FunctionInputs` kept in `LightClientState` between steps.
+	#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+	pub struct LightClientContext {
+		pub store: LightClientStore,
+		pub genesis_root: Bytes32,
+		pub forks: Forks,
+	}
+
 	#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
 	pub struct ExecutionStateProof {
 		#[serde(rename = "executionStateRoot")]
@@ -430,9 +1526,21 @@ pub mod pallet {
 		pub execution_state_branch: Vec<B256>,
 		pub gindex: String,
 	}
-	/// Merkle branch index & depth for the execution state root proof.
-	pub const MERKLE_BRANCH_INDEX: usize = 802;
-	pub const MERKLE_BRANCH_DEPTH: usize = 9;
+
+	/// Outcome of the light client stages of the verification of `FunctionInputs`, shared by
+	/// `fulfill_call` and `trace_verification`.
+	pub(crate) struct LightClientVerification {
+		/// Verification of the sync committee updates, in order.
+		pub updates: Vec<StageResult>,
+		pub finality_update: StageResult,
+		pub execution_state_root: StageResult,
+		/// Store with the verified updates applied.
+		pub store: LightClientStore,
+		pub genesis_root: Bytes32,
+		pub forks: Forks,
+		/// Output of the verification, set if every stage passed.
+		pub output: Option<VerifiedStepOutput>,
+	}
 	#[pallet::call]
 	impl<T: Config> Pallet<T>
 	where
@@ -440,7 +1548,7 @@ pub mod pallet {
 	{
 		/// The entrypoint for fulfilling a call.
 		/// function_id Function identifier.
-		/// inputs Function input.
+		/// inputs Function input, CBOR encoded `FunctionInputs` or `CompactFunctionInputs`.
 		#[pallet::call_index(0)]
 		// Unused parameter that's hard to remove, would mess up traits
 		#[pallet::weight(weight_helper::fulfill_call::<T>(* function_id))]
@@ -449,235 +1557,42 @@ pub mod pallet {
 			function_id: H256,
 			inputs: Vec<u8>, // TODO: Convert to fixed bytes
 		) -> DispatchResultWithPostInfo {
-			let config = ConfigurationStorage::<T>::get();
-			let FunctionInputs {
-				updates,
-				finality_update,
-				expected_current_slot,
-				mut store,
-				genesis_root,
-				forks,
-				execution_state_proof,
-			} = serde_cbor::from_slice(&inputs).unwrap();
-
-			let mut is_valid = true;
-			let prev_head = store.finalized_header.slot;
-
-			// 1. Apply sync committee updates, if any
-			for (index, update) in updates.iter().enumerate() {
-				is_valid = is_valid
-					&& verify_update(
-					update,
-					expected_current_slot,
-					&store,
-					genesis_root.clone(),
-					&forks,
-				)
-					.is_ok();
-
-				apply_update(&mut store, update);
-			}
-
-			// 2. Apply finality update
-			is_valid = is_valid
-				&& verify_finality_update(
-				&finality_update,
-				expected_current_slot,
-				&store,
-				genesis_root.clone(),
-				&forks,
-			)
-				.is_ok();
-			apply_finality_update(&mut store, &finality_update);
-
-			// 3. Verify execution state root proof
-			let execution_state_branch_nodes: Vec<Node> = execution_state_proof
-				.execution_state_branch
-				.iter()
-				.map(|b| Node::try_from(b.as_ref()).unwrap())
-				.collect();
-
-			is_valid = is_valid
-				&& is_valid_merkle_branch(
-				&Node::try_from(execution_state_proof.execution_state_root.as_ref()).unwrap(),
-				execution_state_branch_nodes.iter(),
-				MERKLE_BRANCH_DEPTH,
-				MERKLE_BRANCH_INDEX,
-				&Node::try_from(store.finalized_header.body_root.as_ref()).unwrap(),
-			);
-
-			let finalized_header_root: [u8; 32] = store
-				.finalized_header
-				.hash_tree_root()
-				.unwrap().as_ref().try_into().unwrap();
-			let execution_state_root: [u8; 32] = execution_state_proof.execution_state_root.as_slice().try_into().unwrap();
-
-			let head = store.finalized_header.slot;
 			let sender: [u8; 32] = ensure_signed(origin)?.into();
-			let updater = Updater::<T>::get();
 
 			// ensure sender is preconfigured
-			ensure!(H256(sender) == updater, Error::<T>::UpdaterMisMatch);
-			ensure!(is_valid, Error::<T>::VerificationFailed);
-
-			let mut function_called = false;
-
-			// 4. Store step if needed
-			if prev_head != head {
-				let verified_output = VerifiedStepOutput {
-					finalized_header_root: H256::from(finalized_header_root),
-					execution_state_root: H256::from(execution_state_root),
-					finalized_slot: store.finalized_header.slot.as_u64(),
-					participation: store.current_max_active_participants.try_into().unwrap(),
-				};
-
-				let head = Head::<T>::get();
-				ensure!(verified_output.finalized_slot > head, Error::<T>::SlotBehindHead);
-
-				if Self::set_slot_roots(verified_output)? {
-					Self::deposit_event(Event::HeadUpdated {
-						slot: verified_output.finalized_slot,
-						finalization_root: verified_output.finalized_header_root,
-						execution_state_root: verified_output.execution_state_root,
-					});
-					function_called = true;
-				}
-			}
-
-			// 5. Store rotate if needed
-			// a) Store current sync committee if stored one is empty (i.e. first time or after a range of updates)
-			let period = head.as_u64()
-				.checked_div(config.slots_per_period)
-				.ok_or(Error::<T>::ConfigurationNotSet)?;
-			let stored_current_sync_committee = SyncCommitteeHashes::<T>::get(period);
-			if stored_current_sync_committee.is_zero() {
-				let current_sync_committee_hash: U256 = store
-					.current_sync_committee
-					.hash_tree_root()
-					.unwrap()
-					.as_ref()
-					.try_into()
-					.unwrap();
-				Self::deposit_event(Event::SyncCommitteeUpdated {
-					period,
-					root: current_sync_committee_hash,
-				});
-				function_called = true;
-
-				Self::set_sync_committee_hash(period, current_sync_committee_hash)?;
-			}
-
-			// b) Store next sync committee if available
-			if let Some(mut next_sync_committee) = store.next_sync_committee {
-				let next_period = period + 1;
-				let stored_next_sync_committee_hash = SyncCommitteeHashes::<T>::get(next_period);
-				let next_sync_committee_hash: [u8; 32] = next_sync_committee
-					.hash_tree_root()
-					.unwrap()
-					.as_ref()
-					.try_into()
-					.unwrap();
-				let next_sync_committee_hash = U256::from(next_sync_committee_hash);
-
-				// If the next sync committee is already correct, we don't need to update it.
-				if stored_next_sync_committee_hash != next_sync_committee_hash.into() {
-					Self::deposit_event(Event::SyncCommitteeUpdated {
-						period: next_period,
-						root: next_sync_committee_hash,
-					});
-					function_called = true;
-
-					Self::set_sync_committee_hash(next_period, next_sync_committee_hash)?;
-				}
-			}
-
-			if !function_called {
-				return Err(Error::<T>::FunctionIdNotKnown.into());
-			}
+			let sender = H256(sender);
+			ensure!(
+				Updaters::<T>::contains_key(sender),
+				Error::<T>::UpdaterMisMatch
+			);
 
-			Ok(().into())
+			Self::do_fulfill_call(Some(sender), function_id, inputs)
 		}
 
 		/// Executes message if a valid proofs are provided for the supported message type, assets and domains.
+		/// The verification of the account proof is refunded if the account was already proven
+		/// in the block.
 		#[pallet::call_index(1)]
-		#[pallet::weight({
-			match addr_message.message {
-				Message::ArbitraryMessage(ref data) => T::WeightInfo::execute_arbitrary_message(data.len() as u32),
-				Message::FungibleToken {..} => T::WeightInfo::execute_fungible_token(),
-			}
-		})]
+		#[pallet::weight(weight_helper::execute::<T>(addr_message, account_proof, storage_proof))]
 		pub fn execute(
 			origin: OriginFor<T>,
 			#[pallet::compact] slot: u64,
 			addr_message: AddressedMessage,
-			account_proof: ValidProof,
-			storage_proof: ValidProof,
+			account_proof: ValidProof<T>,
+			storage_proof: ValidProof<T>,
 		) -> DispatchResultWithPostInfo {
-			ensure_signed(origin)?;
-			let encoded_data = addr_message.clone().abi_encode();
-			let message_root = H256(keccak_256(encoded_data.as_slice()));
-
-			Self::check_preconditions(&addr_message, message_root)?;
-
-			ensure!(
-				!SourceChainFrozen::<T>::get(addr_message.origin_domain),
-				Error::<T>::SourceChainFrozen
-			);
+			Self::ensure_message_length(&addr_message.message, addr_message.to)?;
+			let relayer = Self::ensure_can_execute(origin, &addr_message)?;
+			Self::ensure_participation(slot, &addr_message)?;
+			Self::ensure_fork_activated(slot, &addr_message)?;
 			let root = ExecutionStateRoots::<T>::get(slot);
-			let broadcaster = Broadcasters::<T>::get(addr_message.origin_domain);
-
-			// extract contract address
-			let contract_broadcaster_address = H160::from_slice(broadcaster[..20].as_ref());
-			let account_proof_vec = account_proof
-				.iter()
-				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
-				.collect();
-
-			let storage_root =
-				get_storage_root(account_proof_vec, contract_broadcaster_address, root)
-					.map_err(|_| Error::<T>::CannotGetStorageRoot)?;
-
-			let message_id = Uint(U256::from(addr_message.id));
-			let mm_idx = Uint(U256::from(T::MessageMappingStorageIndex::get()));
-			let slot_key = H256(keccak_256(ethabi::encode(&[message_id, mm_idx]).as_slice()));
-
-			let storage_proof_vec = storage_proof
-				.iter()
-				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
-				.collect();
-
-			let slot_value = get_storage_value(slot_key, storage_root, storage_proof_vec)
-				.map_err(|_| Error::<T>::CannotGetStorageValue)?;
-
-			ensure!(slot_value == message_root, Error::<T>::InvalidMessageHash);
-
-			if let Message::FungibleToken { asset_id, amount } = &addr_message.message {
-				ensure!(
-					SUPPORTED_ASSET_ID == *asset_id,
-					Error::<T>::AssetNotSupported
-				);
-
-				let destination_account_id =
-					T::AccountId::decode(&mut &addr_message.to.encode()[..])
-						.map_err(|_| Error::<T>::CannotDecodeDestinationAccountId)?;
-
-				T::Currency::transfer(
-					&Self::account_id(),
-					&destination_account_id,
-					(*amount).saturated_into(),
-					ExistenceRequirement::AllowDeath,
-				)?;
-			}
+			let weight = weight_helper::execute::<T>(&addr_message, &account_proof, &storage_proof);
+			let reused = Self::account_proof_verified(root, &addr_message)
+				.then(|| weight_helper::reused_account_proof::<T>(&account_proof));
 
-			MessageStatus::<T>::set(message_root, MessageStatusEnum::ExecutionSucceeded);
-			Self::deposit_event(Event::<T>::MessageExecuted {
-				from: addr_message.from,
-				to: addr_message.to,
-				message_id: addr_message.id,
-				message_root,
-			});
+			Self::do_execute(root, addr_message, account_proof, storage_proof, relayer, 0)?;
 
-			Ok(().into())
+			Ok(reused.map(|saved| weight.saturating_sub(saved)).into())
 		}
 
 		/// source_chain_froze froze source chain and prevent messages to be executed.
@@ -714,6 +1629,8 @@ pub mod pallet {
 				Message::ArbitraryMessage(ref data) => T::WeightInfo::send_message_arbitrary_message(data.len() as u32),
 				Message::FungibleToken{..} => T::WeightInfo::send_message_fungible_token(),
 			}
+			.saturating_add(weight_helper::outbound_message::<T>())
+			.saturating_add(weight_helper::message_length_limit::<T>())
 		})]
 		pub fn send_message(
 			origin: OriginFor<T>,
@@ -901,110 +1818,3825 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// set_updater replaces the allowlist with `updater`, without a quota. `add_updater` adds
+		/// an updater next to the others.
+		//
+		// Test names: update_updater(), update_updater_non_root()
 		#[pallet::call_index(12)]
-		#[pallet::weight(T::WeightInfo::set_updater())]
+		#[pallet::weight(T::WeightInfo::add_updater().saturating_add(T::WeightInfo::remove_updater()))]
 		pub fn set_updater(origin: OriginFor<T>, updater: H256) -> DispatchResult {
 			ensure_root(origin)?;
-			let old = Updater::<T>::get();
-			Updater::<T>::set(updater);
+			let mut replaced = Updaters::<T>::iter_keys();
+			let old = match (replaced.next(), replaced.next()) {
+				(Some(old), None) => old,
+				_ => H256::zero(),
+			};
+			let _ = Updaters::<T>::clear(u32::MAX, None);
+			Updaters::<T>::insert(
+				updater,
+				UpdaterQuota {
+					max_submissions: None,
+					session: T::CurrentSession::get(),
+					submissions: 0,
+				},
+			);
 
 			Self::deposit_event(Event::<T>::NewUpdater { old, new: updater });
 			Ok(())
 		}
-	}
-
-	impl<T: Config> Pallet<T> {
-		fn do_send_message(
-			who: T::AccountId,
-			message: Message,
-			to: H256,
-			domain: u32,
-		) -> DispatchResultWithPostInfo {
-			// Ensure the domain is currently supported
-			ensure!(
-				Self::is_domain_valid(domain),
-				Error::<T>::DomainNotSupported
-			);
-			// Check MessageType and enforce the rules
-			let message_type = message.r#type();
-			match message {
-				Message::FungibleToken { asset_id, amount } => {
-					ensure!(
-						SUPPORTED_ASSET_ID == asset_id,
-						Error::<T>::AssetNotSupported
-					);
-					ensure!(
-						amount.saturated_into::<u128>() > 0,
-						Error::<T>::InvalidBridgeInputs
-					);
-					T::Currency::transfer(
-						&who,
-						&Self::account_id(),
-						amount.saturated_into(),
-						ExistenceRequirement::KeepAlive,
-					)?;
-				},
-				Message::ArbitraryMessage(data) => {
-					ensure!(!data.is_empty(), Error::<T>::InvalidBridgeInputs)
-				},
-			};
-
-			let message_id = Self::fetch_curr_message_id().map_err(|e| e)?;
 
-			Self::deposit_event(Event::MessageSubmitted {
-				from: who,
-				to,
-				message_type,
-				destination_domain: domain,
-				message_id,
-			});
+		/// set_source_address_version sets the address format used by the source chain. The
+		/// senders of its messages and its broadcaster contracts are decoded with it.
+		//
+		// Test names:
+		//	set_source_address_version_works_with_root(), set_source_address_version_does_not_work_with_non_root(),
+		//	execute_fails_for_source_address_version_mismatch()
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::set_source_address_version())]
+		pub fn set_source_address_version(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			version: AddressVersion,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			SourceAddressVersions::<T>::set(domain, version);
 
-			Ok(().into())
+			Self::deposit_event(Event::<T>::SourceAddressVersionUpdated { domain, version });
+			Ok(())
 		}
 
-		fn fetch_curr_message_id() -> Result<u64, DispatchError> {
-			let number = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
-			let tx_index_option = <frame_system::Pallet<T>>::extrinsic_index();
-
-			match tx_index_option {
-				Some(tx_index) => Ok(tx_uid(number, tx_index)),
-				None => Err(Error::<T>::CurrentMessageIdNotFound.into()),
-			}
+		/// add_updater adds an updater to the allowlist, with an optional per-session quota.
+		//
+		// Test names: add_updater_works_with_root(), add_updater_does_not_work_with_non_root()
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::add_updater())]
+		pub fn add_updater(
+			origin: OriginFor<T>,
+			updater: H256,
+			max_submissions: Option<u32>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::do_add_updater(updater, max_submissions);
+			Ok(())
 		}
 
-		fn check_preconditions(
-			message: &AddressedMessage,
-			message_root: H256,
-		) -> Result<(), DispatchError> {
-			let message_status = MessageStatus::<T>::get(message_root);
-			// Message must not be executed
+		/// remove_updater removes an updater from the allowlist.
+		//
+		// Test names: remove_updater_works_with_root(), remove_updater_does_not_work_with_non_root()
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::remove_updater())]
+		pub fn remove_updater(origin: OriginFor<T>, updater: H256) -> DispatchResult {
+			ensure_root(origin)?;
 			ensure!(
-				message_status == MessageStatusEnum::NotExecuted,
-				Error::<T>::MessageAlreadyExecuted
+				Updaters::<T>::contains_key(updater),
+				Error::<T>::UpdaterNotFound
 			);
+			Updaters::<T>::remove(updater);
 
-			ensure!(
-				message.destination_domain == T::AvailDomain::get(),
+			Self::deposit_event(Event::<T>::UpdaterRemoved { updater });
+			Ok(())
+		}
+
+		/// set_beacon_root_domain enables proofs anchored to the beacon block root for a domain.
+		//
+		// Test names: set_beacon_root_domain_works_with_root(), set_beacon_root_domain_does_not_work_with_non_root()
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::set_beacon_root_domain())]
+		pub fn set_beacon_root_domain(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			enabled: bool,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			BeaconRootDomains::<T>::set(domain, enabled);
+			Self::deposit_event(Event::<T>::BeaconRootDomainUpdated { domain, enabled });
+
+			Ok(())
+		}
+
+		/// Executes message proven against the execution state root of an earlier beacon block,
+		/// whose root the EIP-4788 beacon roots contract keeps in the execution state of `slot`.
+		/// beacon_roots_proof proves that beacon block root against the stored execution state
+		/// root of `slot`, and beacon_proof the execution state root against the beacon block root.
+		//
+		// Test names:
+		//	execute_beacon_anchored_fails_for_unsupported_domain(),
+		//	execute_beacon_anchored_fails_for_invalid_proof()
+		#[pallet::call_index(18)]
+		#[pallet::weight(
+			weight_helper::execute_beacon_anchored::<T>(addr_message)
+				.saturating_add(weight_helper::proof::<T>(&beacon_roots_proof.account_proof))
+				.saturating_add(weight_helper::proofs::<T>(account_proof, storage_proof))
+		)]
+		pub fn execute_beacon_anchored(
+			origin: OriginFor<T>,
+			#[pallet::compact] slot: u64,
+			addr_message: AddressedMessage,
+			account_proof: ValidProof<T>,
+			storage_proof: ValidProof<T>,
+			beacon_roots_proof: ValidBeaconRootsProof<T>,
+			beacon_proof: BeaconRootProof,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_message_length(&addr_message.message, addr_message.to)?;
+			let relayer = Self::ensure_can_execute(origin, &addr_message)?;
+			Self::ensure_participation(slot, &addr_message)?;
+			Self::ensure_fork_activated(slot, &addr_message)?;
+			ensure!(
+				BeaconRootDomains::<T>::get(Self::source_domain(&addr_message)),
+				Error::<T>::BeaconRootNotSupported
+			);
+			let (fork, beacon_root) = Self::verify_beacon_roots(slot, beacon_roots_proof)?;
+			ensure!(
+				beacon::verify_beacon_root_proof(fork, beacon_root, &beacon_proof),
+				Error::<T>::InvalidBeaconRootProof
+			);
+
+			Self::do_execute(
+				beacon_proof.execution_state_root,
+				addr_message,
+				account_proof,
+				storage_proof,
+				relayer,
+				0,
+			)
+		}
+
+		/// Executes message from a slot whose roots are already archived.
+		/// archive_proof proves the slot roots and participation against the archived epoch root.
+		//
+		// Test names: execute_archived_fails_with_insufficient_archived_participation()
+		#[pallet::call_index(14)]
+		#[pallet::weight(
+			weight_helper::execute_archived::<T>(addr_message, archive_proof)
+				.saturating_add(weight_helper::proofs::<T>(account_proof, storage_proof))
+		)]
+		pub fn execute_archived(
+			origin: OriginFor<T>,
+			#[pallet::compact] slot: u64,
+			addr_message: AddressedMessage,
+			account_proof: ValidProof<T>,
+			storage_proof: ValidProof<T>,
+			archive_proof: ArchiveProof,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_message_length(&addr_message.message, addr_message.to)?;
+			let relayer = Self::ensure_can_execute(origin, &addr_message)?;
+			Self::ensure_fork_activated(slot, &addr_message)?;
+			let epoch = archive::epoch_of(slot, T::ArchiveEpochSlots::get());
+			let archived = ArchivedEpochs::<T>::get(epoch).ok_or(Error::<T>::EpochNotArchived)?;
+			ensure!(
+				archive::verify_archive_proof(&archived, slot, &archive_proof),
+				Error::<T>::InvalidArchiveProof
+			);
+			Self::ensure_min_participation(archive_proof.participation, &addr_message)?;
+
+			Self::do_execute(
+				archive_proof.execution_state_root,
+				addr_message,
+				account_proof,
+				storage_proof,
+				relayer,
+				0,
+			)
+		}
+
+		/// restore_bridge moves a degraded bridge back to the operational status.
+		//
+		// Test names: restore_bridge_works_with_degraded_origin(), restore_bridge_does_not_work_with_signed()
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::restore_bridge())]
+		pub fn restore_bridge(origin: OriginFor<T>) -> DispatchResult {
+			T::DegradedOrigin::ensure_origin(origin)?;
+			Status::<T>::set(BridgeStatus::Operational);
+			LowParticipationStreak::<T>::kill();
+
+			Self::deposit_event(Event::<T>::BridgeRestored);
+			Ok(())
+		}
+
+		/// Records that no message with `message_id` was sent from `domain` as of `slot`.
+		/// storage_proof proves that the message slot of the broadcaster is empty.
+		//
+		// Test names:
+		//	prove_message_absence_works(), prove_message_absence_fails_for_sent_message(),
+		//	prove_message_absence_fails_for_unsupported_domain(), prove_message_absence_fails_for_proven_slot()
+		#[pallet::call_index(20)]
+		#[pallet::weight(
+			T::WeightInfo::prove_message_absence()
+				.saturating_add(weight_helper::proofs::<T>(account_proof, storage_proof))
+				.saturating_add(weight_helper::verified_account_proof::<T>())
+		)]
+		pub fn prove_message_absence(
+			origin: OriginFor<T>,
+			#[pallet::compact] slot: u64,
+			domain: u32,
+			message_id: u64,
+			account_proof: ValidProof<T>,
+			storage_proof: ValidProof<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(
+				WhitelistedDomains::<T>::get().contains(&domain),
+				Error::<T>::UnsupportedOriginChain
+			);
+			ensure!(
+				!SourceChainFrozen::<T>::get(domain),
+				Error::<T>::SourceChainFrozen
+			);
+			if let Some(proven_slot) = AbsentMessages::<T>::get(domain, message_id) {
+				ensure!(slot > proven_slot, Error::<T>::MessageAbsenceAlreadyProven);
+			}
+
+			let root = ExecutionStateRoots::<T>::get(slot);
+			let broadcaster = Broadcasters::<T>::get(domain);
+			let storage_root =
+				Self::broadcaster_storage_root(domain, broadcaster, root, account_proof)?;
+			let storage_proof_vec = Self::proof_nodes(storage_proof)?;
+
+			verify_storage_absence::<T::SourceChainTrieLayout>(
+				Self::message_slot_key(message_id),
+				storage_root,
+				storage_proof_vec,
+			)
+			.map_err(|e| match e {
+				StorageError::StorageValueExists => Error::<T>::MessageSent,
+				_ => Error::<T>::CannotGetStorageValue,
+			})?;
+
+			AbsentMessages::<T>::insert(domain, message_id, slot);
+			Self::deposit_event(Event::<T>::MessageAbsenceProven {
+				domain,
+				message_id,
+				slot,
+			});
+			Ok(())
+		}
+
+		/// set_message_schema sets the ABI schema the arbitrary messages to `to` must match,
+		/// or removes it if `schema` is `None`.
+		//
+		// Test names: set_message_schema_works_with_root(), set_message_schema_does_not_work_with_non_root()
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::set_message_schema())]
+		pub fn set_message_schema(
+			origin: OriginFor<T>,
+			to: H256,
+			schema: Option<MessageSchema>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			MessageSchemas::<T>::set(to, schema.clone());
+
+			Self::deposit_event(Event::<T>::MessageSchemaUpdated { to, schema });
+			Ok(())
+		}
+
+		/// set_ordered_channel executes the messages of `from` on `domain` in id order, starting
+		/// with `next_id`, or disables the ordered channel and drops its queue if `next_id` is `None`.
+		//
+		// Test names: set_ordered_channel_works_with_root(), set_ordered_channel_does_not_work_with_non_root()
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::set_ordered_channel())]
+		pub fn set_ordered_channel(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			from: H256,
+			next_id: Option<u64>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			match next_id {
+				Some(next_id) => OrderedChannels::<T>::insert(domain, from, next_id),
+				None => {
+					OrderedChannels::<T>::remove(domain, from);
+					let _ = QueuedMessages::<T>::clear_prefix((domain, from), u32::MAX, None);
+				},
+			}
+
+			Self::deposit_event(Event::<T>::OrderedChannelUpdated {
+				domain,
+				from,
+				next_id,
+			});
+			Ok(())
+		}
+
+		/// Skips the next id of the ordered channel of `channel_from`, given that the message
+		/// with that id was sent by another sender or to another chain, or was voided, and
+		/// executes the queued messages after it.
+		//
+		// Test names:
+		//	skip_ordered_message_works(), skip_ordered_message_fails_for_channel_message(),
+		//	skip_ordered_message_fails_for_wrong_id()
+		#[pallet::call_index(23)]
+		#[pallet::weight(
+			weight_helper::skip_ordered_message::<T>()
+				.saturating_add(weight_helper::proofs::<T>(account_proof, storage_proof))
+		)]
+		pub fn skip_ordered_message(
+			origin: OriginFor<T>,
+			#[pallet::compact] slot: u64,
+			channel_from: H256,
+			addr_message: AddressedMessage,
+			account_proof: ValidProof<T>,
+			storage_proof: ValidProof<T>,
+		) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+			let domain = Self::source_domain(&addr_message);
+			ensure!(
+				WhitelistedDomains::<T>::get().contains(&domain),
+				Error::<T>::UnsupportedOriginChain
+			);
+			let next_id = OrderedChannels::<T>::get(domain, channel_from)
+				.ok_or(Error::<T>::OrderedChannelNotFound)?;
+			let message_root = Self::message_root(&addr_message);
+			ensure!(
+				addr_message.id == next_id
+					&& (addr_message.from != channel_from
+						|| !Self::is_destination_valid(addr_message.destination_domain)
+						|| VoidedMessages::<T>::contains_key(message_root)),
+				Error::<T>::MessageNotSkippable
+			);
+
+			let root = ExecutionStateRoots::<T>::get(slot);
+			Self::verify_message(
+				root,
+				&addr_message,
+				message_root,
+				account_proof,
+				storage_proof,
+			)?;
+
+			Self::deposit_event(Event::<T>::OrderedMessageSkipped {
+				domain,
+				from: channel_from,
+				message_id: next_id,
+			});
+			Self::drain_ordered_channel(
+				domain,
+				channel_from,
+				next_id.saturating_add(1),
+				Some(&relayer),
+			);
+			Ok(())
+		}
+
+		/// set_acknowledged_sender enables or disables acknowledgements of the executed messages
+		/// of `from` on `domain`. Acknowledged messages that fail are marked as failed instead of
+		/// being retried.
+		//
+		// Test names: set_acknowledged_sender_works_with_root(), set_acknowledged_sender_does_not_work_with_non_root()
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::set_acknowledged_sender())]
+		pub fn set_acknowledged_sender(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			from: H256,
+			enabled: bool,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			AcknowledgedSenders::<T>::set(domain, from, enabled);
+
+			Self::deposit_event(Event::<T>::AcknowledgedSenderUpdated {
+				domain,
+				from,
+				enabled,
+			});
+			Ok(())
+		}
+
+		/// migrate_domain moves the state of the source domain `old` to `new`, e.g. after a
+		/// redeploy or a hard fork changing the chain id. `old` becomes an alias of `new`, so
+		/// the executed messages of `old` stay executed and its pending messages can still be
+		/// executed with the state of `new`.
+		//
+		// Test names:
+		//	migrate_domain_works_with_root(), migrate_domain_does_not_work_with_non_root(),
+		//	migrate_domain_fails_for_used_domain(), execute_works_for_migrated_domain()
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::migrate_domain())]
+		pub fn migrate_domain(
+			origin: OriginFor<T>,
+			#[pallet::compact] old: u32,
+			#[pallet::compact] new: u32,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			Self::do_migrate_domain(old, new)?;
+
+			Self::deposit_event(Event::<T>::DomainMigrated { old, new });
+			Ok(())
+		}
+
+		/// Backfills the sync committee hashes of the periods before `checkpoint_period`, so that
+		/// headers of these periods can be imported after a fresh deployment. `proofs` are the
+		/// CBOR encoded rotate `FunctionInputs` of each period, ordered by period and ending at
+		/// `checkpoint_period - 1`.
+		///
+		/// The stored hash of the checkpoint must be `checkpoint_hash`. The proof of each period
+		/// must verify a finalized header of the period whose next sync committee is the one of
+		/// the following period, so the backfilled periods chain to the checkpoint. Every link is
+		/// verified before any period is stored, and stored periods are never overwritten.
+		//
+		// Test names:
+		//	backfill_sync_committees_works_with_root(), backfill_sync_committees_does_not_work_with_non_root(),
+		//	backfill_sync_committees_fails_for_wrong_checkpoint(), backfill_sync_committees_fails_for_broken_chain(),
+		//	backfill_sync_committees_does_not_overwrite_periods(), fulfill_call_imports_historical_header()
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::backfill_sync_committees(proofs.len() as u32))]
+		pub fn backfill_sync_committees(
+			origin: OriginFor<T>,
+			#[pallet::compact] checkpoint_period: u64,
+			checkpoint_hash: U256,
+			proofs: BoundedVec<Vec<u8>, ConstU32<MAX_BACKFILLED_PERIODS>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let stored_checkpoint = SyncCommitteeHashes::<T>::get(checkpoint_period);
+			ensure!(
+				!stored_checkpoint.is_zero() && stored_checkpoint == checkpoint_hash,
+				Error::<T>::InvalidSyncCommitteeCheckpoint
+			);
+			let first_period = checkpoint_period
+				.checked_sub(proofs.len() as u64)
+				.ok_or(Error::<T>::InvalidSyncCommitteeCheckpoint)?;
+
+			// every proof is verified before the links are checked, so the call costs the same
+			// whichever link is broken
+			let config = ConfigurationStorage::<T>::get();
+			let rotations = proofs
+				.iter()
+				.map(|inputs| Self::verify_backfilled_rotation(inputs, &config))
+				.collect::<Result<Vec<_>, _>>()?;
+
+			// walk back from the checkpoint, each period rotating into the next one
+			let mut next_hash = checkpoint_hash;
+			for (period, (slot, hash, rotated_hash)) in
+				(first_period..checkpoint_period).zip(rotations.iter()).rev()
+			{
+				ensure!(
+					slot.checked_div(config.slots_per_period) == Some(period)
+						&& *rotated_hash == next_hash
+						&& !hash.is_zero(),
+					Error::<T>::SyncCommitteeChainMismatch
+				);
+				next_hash = *hash;
+			}
+
+			for (period, (_, hash, _)) in (first_period..checkpoint_period).zip(rotations) {
+				Self::set_sync_committee_hash(period, hash)?;
+			}
+
+			Self::deposit_event(Event::<T>::SyncCommitteesBackfilled {
+				first_period,
+				checkpoint_period,
+			});
+			Ok(())
+		}
+
+		/// set_base_fee_oracle sets the contract keeping the base fee of a source chain, or
+		/// removes it if `oracle` is `None`.
+		//
+		// Test names: set_base_fee_oracle_works_with_root(), set_base_fee_oracle_does_not_work_with_non_root()
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::set_base_fee_oracle())]
+		pub fn set_base_fee_oracle(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			oracle: Option<BaseFeeOracle>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			BaseFeeOracles::<T>::set(domain, oracle);
+			Self::deposit_event(Event::<T>::BaseFeeOracleUpdated { domain, oracle });
+
+			Ok(())
+		}
+
+		/// Records the base fee of a source chain, read from the storage of its base fee oracle.
+		/// account_proof proves the oracle account against the execution state root of `slot`.
+		/// storage_proof proves the base fee slot of the oracle.
+		//
+		// Test names:
+		//	update_source_base_fee_works(), update_source_base_fee_fails_without_oracle(),
+		//	update_source_base_fee_fails_for_proven_slot(), update_source_base_fee_fails_for_invalid_proof()
+		#[pallet::call_index(28)]
+		#[pallet::weight(
+			T::WeightInfo::update_source_base_fee()
+				.saturating_add(weight_helper::proofs::<T>(account_proof, storage_proof))
+		)]
+		pub fn update_source_base_fee(
+			origin: OriginFor<T>,
+			#[pallet::compact] slot: u64,
+			#[pallet::compact] domain: u32,
+			account_proof: ValidProof<T>,
+			storage_proof: ValidProof<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let oracle = BaseFeeOracles::<T>::get(domain).ok_or(Error::<T>::BaseFeeOracleNotSet)?;
+			ensure!(
+				!SourceChainFrozen::<T>::get(domain),
+				Error::<T>::SourceChainFrozen
+			);
+			if let Some(proven) = SourceBaseFees::<T>::get(domain) {
+				ensure!(slot > proven.slot, Error::<T>::BaseFeeAlreadyProven);
+			}
+
+			let root = ExecutionStateRoots::<T>::get(slot);
+			let account_proof_vec = Self::proof_nodes(account_proof)?;
+			let storage_root = get_storage_root::<T::SourceChainTrieLayout>(
+				account_proof_vec,
+				oracle.address,
+				root,
+			)
+			.map_err(|_| Error::<T>::CannotGetStorageRoot)?;
+
+			let storage_proof_vec = Self::proof_nodes(storage_proof)?;
+			let value = get_storage_value::<T::SourceChainTrieLayout>(
+				oracle.slot,
+				storage_root,
+				storage_proof_vec,
+			)
+			.map_err(|_| Error::<T>::CannotGetStorageValue)?;
+			let base_fee = U256::from_big_endian(value.as_bytes());
+
+			SourceBaseFees::<T>::insert(domain, SourceBaseFee { slot, base_fee });
+			Self::deposit_event(Event::<T>::SourceBaseFeeUpdated {
+				domain,
+				slot,
+				base_fee,
+			});
+			Ok(())
+		}
+
+		/// set_reorg_recovery enters or leaves the source chain reorg recovery mode. Steps never
+		/// move the head backwards, the mode is the only way to accept a lower slot.
+		//
+		// Test names: set_reorg_recovery_works_with_root(), set_reorg_recovery_does_not_work_with_non_root()
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::set_reorg_recovery())]
+		pub fn set_reorg_recovery(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ReorgRecovery::<T>::set(enabled);
+			Self::deposit_event(Event::<T>::ReorgRecoveryUpdated { enabled });
+
+			Ok(())
+		}
+
+		/// rewind_head moves the head back to `slot` after a reorg of the source chain, with
+		/// the given roots. The roots of `slot` and of every later slot are invalidated first.
+		/// max_invalidated is the maximum number of slots to invalidate.
+		///
+		/// The light client state of the head and the cache of verified inputs are cleared, as they
+		/// reflect the abandoned fork. Messages already executed against the invalidated roots stay
+		/// executed.
+		//
+		// Test names:
+		//	rewind_head_works_in_reorg_recovery(), rewind_head_fails_without_reorg_recovery(),
+		//	rewind_head_fails_for_slot_ahead_of_head(), rewind_head_fails_with_too_many_invalidated_slots(),
+		//	rewind_head_then_step_requires_full_inputs()
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::rewind_head(*max_invalidated))]
+		pub fn rewind_head(
+			origin: OriginFor<T>,
+			#[pallet::compact] slot: u64,
+			finalization_root: H256,
+			execution_state_root: H256,
+			max_invalidated: u32,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				ReorgRecovery::<T>::get(),
+				Error::<T>::ReorgRecoveryNotEnabled
+			);
+			let head = Head::<T>::get();
+			ensure!(slot < head, Error::<T>::SlotNotBehindHead);
+
+			let epoch_slots = T::ArchiveEpochSlots::get();
+			let first_epoch = archive::epoch_of(slot, epoch_slots);
+			ensure!(
+				!ArchivedEpochs::<T>::contains_key(first_epoch),
+				Error::<T>::EpochAlreadyArchived
+			);
+
+			let mut invalidated = Vec::new();
+			for epoch in first_epoch..=archive::epoch_of(head, epoch_slots) {
+				EpochSlots::<T>::mutate(epoch, |slots| {
+					slots.retain(|stored| {
+						let keep = *stored < slot;
+						if !keep {
+							invalidated.push(*stored);
+						}
+						keep
+					})
+				});
+			}
+			ensure!(
+				invalidated.len() <= max_invalidated as usize,
+				Error::<T>::TooManyInvalidatedSlots
+			);
+
+			for invalidated_slot in invalidated.iter() {
+				Headers::<T>::remove(invalidated_slot);
+				Timestamps::<T>::remove(invalidated_slot);
+				HeaderTimestamps::<T>::remove(invalidated_slot);
+				SlotParticipation::<T>::remove(invalidated_slot);
+				ExecutionBlockNumbers::<T>::remove(invalidated_slot);
+				let execution_state_root = ExecutionStateRoots::<T>::take(invalidated_slot);
+				Self::deposit_event(Event::<T>::ExecutionStateRootInvalidated {
+					slot: *invalidated_slot,
+					execution_state_root,
+				});
+			}
+			AcceptedHeaders::<T>::mutate(|accepted| accepted.retain(|header| header.slot < slot));
+
+			EpochSlots::<T>::try_mutate(first_epoch, |slots| slots.try_push(slot))
+				.map_err(|_| Error::<T>::TooManySlotsInEpoch)?;
+			if NextArchiveEpoch::<T>::get().map_or(true, |next| first_epoch < next) {
+				NextArchiveEpoch::<T>::set(Some(first_epoch));
+			}
+			Head::<T>::set(slot);
+			Headers::<T>::insert(slot, finalization_root);
+			ExecutionStateRoots::<T>::insert(slot, execution_state_root);
+			Timestamps::<T>::insert(slot, T::TimeProvider::now().as_secs());
+			HeaderTimestamps::<T>::insert(slot, Self::slot_timestamp(slot));
+			VerifiedInputs::<T>::kill();
+			if LightClientState::<T>::take().is_some() {
+				Self::deposit_event(Event::<T>::LightClientStateCleared { slot });
+			}
+
+			Self::deposit_event(Event::<T>::HeadRewound {
+				from: head,
+				to: slot,
+				invalidated: invalidated.len() as u32,
+			});
+			Ok(())
+		}
+
+		/// set_participation_requirement sets the sync committee participation the messages to
+		/// `to` require, or removes it if `requirement` is `None`. It lets high-value recipients
+		/// demand a stronger finality than `finality_threshold`.
+		//
+		// Test names:
+		//	set_participation_requirement_works_with_root(), set_participation_requirement_does_not_work_with_non_root(),
+		//	execute_fails_with_insufficient_participation(), execute_works_below_required_amount()
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::set_participation_requirement())]
+		pub fn set_participation_requirement(
+			origin: OriginFor<T>,
+			to: H256,
+			requirement: Option<ParticipationRequirement>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ParticipationRequirements::<T>::set(to, requirement);
+			Self::deposit_event(Event::<T>::ParticipationRequirementUpdated { to, requirement });
+
+			Ok(())
+		}
+
+		/// set_execution_fee sets the fee a relayer takes out of the bridged amount of `asset_id`
+		/// when it executes a message of another recipient, or removes it if `fee` is `None`.
+		/// It lets recipients without balance receive funds executed by a relayer.
+		//
+		// Test names:
+		//	set_execution_fee_works_with_root(), set_execution_fee_does_not_work_with_non_root(),
+		//	set_execution_fee_fails_with_invalid_bounds(), execute_pays_execution_fee_to_relayer(),
+		//	execute_by_recipient_pays_no_execution_fee()
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::set_execution_fee())]
+		pub fn set_execution_fee(
+			origin: OriginFor<T>,
+			asset_id: H256,
+			fee: Option<ExecutionFee>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			if let Some(fee) = fee {
+				ensure!(fee.min_fee <= fee.max_fee, Error::<T>::InvalidExecutionFee);
+			}
+
+			ExecutionFees::<T>::set(asset_id, fee);
+			Self::deposit_event(Event::<T>::ExecutionFeeUpdated { asset_id, fee });
+
+			Ok(())
+		}
+
+		/// set_fork_epoch sets the activation epoch of a source chain consensus fork, or removes
+		/// it if `epoch` is `None`. The fork of a slot selects where the execution state root is
+		/// proven in its beacon block.
+		//
+		// Test names:
+		//	set_fork_epoch_works_with_root(), set_fork_epoch_does_not_work_with_non_root(),
+		//	set_fork_epoch_fails_for_unordered_epoch(), consensus_fork_follows_fork_epochs()
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::set_fork_epoch())]
+		pub fn set_fork_epoch(
+			origin: OriginFor<T>,
+			fork: ConsensusFork,
+			epoch: Option<u64>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			if let Some(epoch) = epoch {
+				for other in ConsensusFork::ALL {
+					let Some(other_epoch) = ForkEpochs::<T>::get(other) else {
+						continue;
+					};
+					let ordered = match other.cmp(&fork) {
+						sp_std::cmp::Ordering::Less => other_epoch <= epoch,
+						sp_std::cmp::Ordering::Equal => true,
+						sp_std::cmp::Ordering::Greater => other_epoch >= epoch,
+					};
+					ensure!(ordered, Error::<T>::InvalidForkEpoch);
+				}
+			}
+
+			ForkEpochs::<T>::set(fork, epoch);
+			Self::deposit_event(Event::<T>::ForkEpochUpdated { fork, epoch });
+
+			Ok(())
+		}
+
+		/// Executes messages of the same source chain proven against the execution state root of
+		/// `slot`. storage_proof is a multiproof covering the slots of all the messages, so the
+		/// nodes shared by their proofs are submitted once. The batch fails if any message fails.
+		//
+		// Test names:
+		//	execute_messages_works_with_multiproof(), execute_messages_fails_for_empty_batch(),
+		//	execute_messages_fails_for_mixed_source_chains(),
+		//	execute_messages_fails_if_a_message_is_not_proven(),
+		//	execute_messages_fails_for_duplicate_message()
+		#[pallet::call_index(34)]
+		#[pallet::weight(
+			weight_helper::execute_messages::<T>(messages)
+				.saturating_add(weight_helper::proof::<T>(account_proof))
+				.saturating_add(weight_helper::verified_account_proof::<T>())
+		)]
+		pub fn execute_messages(
+			origin: OriginFor<T>,
+			#[pallet::compact] slot: u64,
+			messages: MessageBatch<T>,
+			account_proof: ValidProof<T>,
+			storage_proof: MultiProof<T>,
+		) -> DispatchResultWithPostInfo {
+			let first = messages.first().ok_or(Error::<T>::EmptyMessageBatch)?;
+			let domain = Self::source_domain(first);
+			ensure!(
+				messages.iter().all(|m| Self::source_domain(m) == domain),
+				Error::<T>::MixedSourceChains
+			);
+
+			let mut relayer = None;
+			for addr_message in messages.iter() {
+				Self::ensure_message_length(&addr_message.message, addr_message.to)?;
+				relayer = Self::ensure_can_execute(origin.clone(), addr_message)?;
+				Self::ensure_participation(slot, addr_message)?;
+				Self::ensure_fork_activated(slot, addr_message)?;
+			}
+			let root = ExecutionStateRoots::<T>::get(slot);
+
+			Self::do_execute_batch(
+				root,
+				domain,
+				messages.into_inner(),
+				account_proof,
+				storage_proof,
+				relayer,
+			)
+		}
+
+		/// Executes a message on behalf of its recipient, who signed `permit` to let anyone
+		/// execute it. The executor is paid the tip of the permit out of the bridged amount, on
+		/// top of the execution fee of the asset.
+		//
+		// Test names:
+		//	verify_permit_accepts_recipient_signature(),
+		//	verify_permit_fails_for_tip_above_amount_after_fee(),
+		//	execute_with_permit_fails_for_invalid_signature(),
+		//	execute_with_permit_fails_for_other_message(), execute_with_permit_fails_for_expired_permit(),
+		//	execute_with_permit_fails_for_tip_above_amount(), execute_with_permit_fails_for_used_permit()
+		#[pallet::call_index(35)]
+		#[pallet::weight(
+			weight_helper::execute_with_permit::<T>(addr_message)
+				.saturating_add(weight_helper::proofs::<T>(account_proof, storage_proof))
+		)]
+		pub fn execute_with_permit(
+			origin: OriginFor<T>,
+			#[pallet::compact] slot: u64,
+			addr_message: AddressedMessage,
+			account_proof: ValidProof<T>,
+			storage_proof: ValidProof<T>,
+			permit: ExecutionPermit,
+			signature: MultiSignature,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_message_length(&addr_message.message, addr_message.to)?;
+			let executor = Self::ensure_can_execute(origin, &addr_message)?;
+			Self::ensure_participation(slot, &addr_message)?;
+			Self::ensure_fork_activated(slot, &addr_message)?;
+			let recipient = Self::verify_permit(&addr_message, &permit, &signature)?;
+			let root = ExecutionStateRoots::<T>::get(slot);
+
+			let message_root = permit.message_root;
+			let tip = match &executor {
+				Some(executor) if *executor != recipient => permit.tip,
+				_ => 0,
+			};
+			Self::do_execute(
+				root,
+				addr_message,
+				account_proof,
+				storage_proof,
+				executor.clone(),
+				tip,
+			)?;
+			ensure!(
+				MessageStatus::<T>::get(message_root) == MessageStatusEnum::ExecutionSucceeded,
+				Error::<T>::PermitMessageNotExecuted
+			);
+			UsedPermits::<T>::insert(message_root, <frame_system::Pallet<T>>::block_number());
+
+			Self::deposit_event(Event::<T>::ExecutionPermitUsed {
+				message_root,
+				executor,
+				tip,
+			});
+
+			Ok(().into())
+		}
+
+		/// Pins the code hash of the broadcaster contract of `domain`, or unpins it if
+		/// `code_hash` is `None`. An upgrade of a pinned broadcaster, e.g. of the implementation
+		/// behind a proxy, has to be acknowledged by pinning its new code hash.
+		//
+		// Test names:
+		//	set_pinned_code_hash_works_with_root(), set_pinned_code_hash_does_not_work_with_non_root(),
+		//	execute_fails_for_unpinned_code_hash()
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::set_pinned_code_hash())]
+		pub fn set_pinned_code_hash(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			code_hash: Option<H256>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			PinnedCodeHashes::<T>::set(domain, code_hash);
+			Self::deposit_event(Event::<T>::PinnedCodeHashUpdated { domain, code_hash });
+
+			Ok(())
+		}
+
+		/// Enables or disables the routing of proven messages to the app-chain of `domain`.
+		///
+		/// Routed messages are added to the outbound root of the block instead of being executed.
+		//
+		// Test names:
+		//	set_app_chain_route_works_with_root(), set_app_chain_route_does_not_work_with_non_root(),
+		//	execute_routes_message_to_app_chain()
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::set_app_chain_route())]
+		pub fn set_app_chain_route(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			enabled: bool,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				domain != T::AvailDomain::get(),
+				Error::<T>::InvalidAppChainDomain
+			);
+			AppChainRoutes::<T>::set(domain, enabled);
+			Self::deposit_event(Event::<T>::AppChainRouteUpdated { domain, enabled });
+
+			Ok(())
+		}
+
+		/// Sets the data length limit of the arbitrary messages to and from `to`, overriding
+		/// `MaxMessageLength`, or removes it if `limit` is `None`.
+		//
+		// Test names: set_message_length_limit_works_with_root(), set_message_length_limit_does_not_work_with_non_root()
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::set_message_length_limit())]
+		pub fn set_message_length_limit(
+			origin: OriginFor<T>,
+			to: H256,
+			limit: Option<u32>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			MessageLengthLimits::<T>::set(to, limit);
+
+			Self::deposit_event(Event::<T>::MessageLengthLimitUpdated { to, limit });
+			Ok(())
+		}
+
+		/// Voids the message with the root `message_root`, so that it is never executed. Meant
+		/// for messages proven to result from an exploit of the source chain, before a relayer
+		/// executes them. A voided message blocking its ordered channel can be skipped.
+		//
+		// Test names: void_message_works_with_root(), void_message_does_not_work_with_non_root()
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::void_message())]
+		pub fn void_message(origin: OriginFor<T>, message_root: H256) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				MessageStatus::<T>::get(message_root) == MessageStatusEnum::NotExecuted,
+				Error::<T>::MessageAlreadyExecuted
+			);
+			VoidedMessages::<T>::insert(message_root, <frame_system::Pallet<T>>::block_number());
+
+			Self::deposit_event(Event::<T>::MessageVoided { message_root });
+			Ok(())
+		}
+
+		/// Sets the contract on Ethereum committing to the states of the rollup of `domain`, or
+		/// removes it if `config` is `None`.
+		//
+		// Test names: set_rollup_config_works_with_root(), set_rollup_config_does_not_work_with_non_root()
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::set_rollup_config())]
+		pub fn set_rollup_config(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			config: Option<RollupConfig>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			RollupConfigs::<T>::set(domain, config);
+
+			Self::deposit_event(Event::<T>::RollupConfigUpdated { domain, config });
+			Ok(())
+		}
+
+		/// Executes a message of a rollup, proven against the rollup state at `rollup_proof.index`.
+		/// rollup_proof proves the final commitment to that state in the rollup contract against
+		/// the execution state root of `slot`.
+		//
+		// Test names:
+		//	execute_rollup_anchored_fails_for_unsupported_domain(),
+		//	execute_rollup_anchored_fails_for_commitment_of_other_rollup()
+		#[pallet::call_index(41)]
+		#[pallet::weight(
+			weight_helper::execute_rollup_anchored::<T>(addr_message)
+				.saturating_add(weight_helper::proof::<T>(&rollup_proof.account_proof))
+				.saturating_add(weight_helper::proofs::<T>(account_proof, storage_proof))
+		)]
+		pub fn execute_rollup_anchored(
+			origin: OriginFor<T>,
+			#[pallet::compact] slot: u64,
+			addr_message: AddressedMessage,
+			rollup_proof: ValidRollupProof<T>,
+			account_proof: ValidProof<T>,
+			storage_proof: ValidProof<T>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_message_length(&addr_message.message, addr_message.to)?;
+			let relayer = Self::ensure_can_execute(origin, &addr_message)?;
+			Self::ensure_participation(slot, &addr_message)?;
+			Self::ensure_fork_activated(slot, &addr_message)?;
+			let config = RollupConfigs::<T>::get(Self::source_domain(&addr_message))
+				.ok_or(Error::<T>::RollupNotSupported)?;
+			let state_root = Self::verify_rollup_state(slot, config, rollup_proof)?;
+
+			Self::do_execute(
+				state_root,
+				addr_message,
+				account_proof,
+				storage_proof,
+				relayer,
+				0,
+			)
+		}
+
+		/// Sets how the source chain finalizes its headers and the timing of its slots, so that
+		/// chains other than the beacon chain can be followed.
+		//
+		// Test names: set_finality_config_works_with_root(), set_finality_config_does_not_work_with_non_root()
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::set_finality_config())]
+		pub fn set_finality_config(origin: OriginFor<T>, config: FinalityConfig) -> DispatchResult {
+			ensure_root(origin)?;
+			Finality::<T>::put(config);
+
+			Self::deposit_event(Event::<T>::FinalityConfigUpdated { config });
+			Ok(())
+		}
+
+		/// Sets the number of blocks the accepted `fulfill_call` inputs are kept in the offchain
+		/// storage, served by `succinct_proofArtifact`, or stops keeping them if `None`. The
+		/// inputs are only written by nodes with offchain indexing enabled.
+		//
+		// Test names: set_proof_retention_works_with_root(), set_proof_retention_does_not_work_with_non_root()
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::set_proof_retention())]
+		pub fn set_proof_retention(
+			origin: OriginFor<T>,
+			retention: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ProofRetention::<T>::set(retention);
+
+			Self::deposit_event(Event::<T>::ProofRetentionUpdated { retention });
+			Ok(())
+		}
+
+		/// Sets or removes the schedule the bridged amounts of an asset from its threshold are
+		/// released with, so that large withdrawals are vested instead of transferred at once.
+		//
+		// Test names:
+		//	set_release_schedule_works_with_root(), set_release_schedule_does_not_work_with_non_root(),
+		//	set_release_schedule_fails_without_duration(), execute_vests_amount_from_release_threshold()
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::set_release_schedule())]
+		pub fn set_release_schedule(
+			origin: OriginFor<T>,
+			asset_id: H256,
+			schedule: Option<ReleaseSchedule>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			if let Some(schedule) = schedule {
+				ensure!(schedule.duration > 0, Error::<T>::InvalidReleaseSchedule);
+			}
+
+			ReleaseSchedules::<T>::set(asset_id, schedule);
+			Self::deposit_event(Event::<T>::ReleaseScheduleUpdated { asset_id, schedule });
+
+			Ok(())
+		}
+
+		/// Transfers the part of the vested amount of a message released so far to its
+		/// recipient. Anyone can release it.
+		//
+		// Test names: release_vested_transfers_released_part(), release_vested_fails_when_nothing_is_released()
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::release_vested())]
+		pub fn release_vested(origin: OriginFor<T>, message_root: H256) -> DispatchResult {
+			ensure_signed(origin)?;
+			let mut vested =
+				VestedReleases::<T>::get(message_root).ok_or(Error::<T>::VestedReleaseNotFound)?;
+			let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+			let amount = vested.releasable(now);
+			ensure!(amount > 0, Error::<T>::NothingToRelease);
+
+			let recipient = T::AccountId::decode(&mut &vested.recipient.encode()[..])
+				.map_err(|_| Error::<T>::CannotDecodeDestinationAccountId)?;
+			T::Currency::transfer(
+				&Self::account_id(),
+				&recipient,
+				amount.saturated_into(),
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			vested.released = vested.released.saturating_add(amount);
+			if vested.released < vested.amount {
+				VestedReleases::<T>::insert(message_root, vested);
+			} else {
+				VestedReleases::<T>::remove(message_root);
+			}
+			Self::deposit_event(Event::<T>::VestedReleased {
+				message_root,
+				recipient: vested.recipient,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Enables or disables payout batches for the messages of `from` on `domain`. The data of
+		/// these messages must then decode as a batch, whose payouts are all transferred when the
+		/// message is executed, or none of them.
+		//
+		// Test names:
+		//	set_payout_sender_works_with_root(), set_payout_sender_does_not_work_with_non_root(),
+		//	execute_transfers_payout_batch(), execute_fails_for_invalid_payout_batch()
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::set_payout_sender())]
+		pub fn set_payout_sender(
+			origin: OriginFor<T>,
+			#[pallet::compact] domain: u32,
+			from: H256,
+			enabled: bool,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			PayoutSenders::<T>::set(domain, from, enabled);
+
+			Self::deposit_event(Event::<T>::PayoutSenderUpdated {
+				domain,
+				from,
+				enabled,
+			});
+			Ok(())
+		}
+
+		/// schedule_fork_transition moves the source domain `old` to the chain id `new` it takes
+		/// at a fork of the source chain starting at `activation_slot`, as `migrate_domain` does.
+		/// The messages of `new` are then proven against `broadcaster` from `activation_slot`,
+		/// while the messages of `old` are still proven against the broadcaster of `old`, so the
+		/// messages sent before the fork can be executed after it.
+		//
+		// Test names:
+		//	schedule_fork_transition_works_with_root(), schedule_fork_transition_does_not_work_with_non_root(),
+		//	execute_fails_for_new_chain_id_before_fork(), execute_works_for_old_chain_id_after_fork()
+		#[pallet::call_index(47)]
+		#[pallet::weight(T::WeightInfo::schedule_fork_transition())]
+		pub fn schedule_fork_transition(
+			origin: OriginFor<T>,
+			#[pallet::compact] old: u32,
+			#[pallet::compact] new: u32,
+			#[pallet::compact] activation_slot: u64,
+			broadcaster: H256,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let old_broadcaster = Broadcasters::<T>::get(old);
+			Self::do_migrate_domain(old, new)?;
+
+			Broadcasters::<T>::insert(new, broadcaster);
+			ForkTransitions::<T>::insert(
+				old,
+				ForkTransition {
+					new_domain: new,
+					activation_slot,
+					broadcaster: old_broadcaster,
+				},
+			);
+			ForkActivations::<T>::insert(new, activation_slot);
+
+			Self::deposit_event(Event::<T>::ForkTransitionScheduled {
+				old,
+				new,
+				activation_slot,
+				broadcaster,
+			});
+			Ok(())
+		}
+
+		/// schedule_function_ids schedules the step and rotate function ids to replace the
+		/// current ones at the start of `activation_block`, replacing the ones scheduled before.
+		/// Scheduling `None` cancels the scheduled function ids.
+		//
+		// Test names:
+		//	schedule_function_ids_works_with_root(), schedule_function_ids_does_not_work_with_non_root(),
+		//	schedule_function_ids_fails_for_passed_block(), scheduled_function_ids_are_activated()
+		#[pallet::call_index(48)]
+		#[pallet::weight(T::WeightInfo::schedule_function_ids())]
+		pub fn schedule_function_ids(
+			origin: OriginFor<T>,
+			value: Option<(H256, H256)>,
+			activation_block: BlockNumberFor<T>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let Some(value) = value else {
+				ScheduledFunctionIds::<T>::kill();
+				return Ok(());
+			};
+			ensure!(
+				activation_block > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::ActivationBlockPassed
+			);
+
+			ScheduledFunctionIds::<T>::put((activation_block, value));
+
+			Self::deposit_event(Event::FunctionIdsScheduled {
+				value,
+				activation_block,
+			});
+			Ok(())
+		}
+
+		/// Fulfills a step or rotate call included as an inherent by the block author, from the
+		/// proofs its relayer component or the vector gossip made available to it. The step is
+		/// not attributed to an updater.
+		///
+		/// Only `CompactFunctionInputs` are accepted: the light client store is taken from
+		/// `LightClientState`, as the block author is not trusted with it.
+		//
+		// Test names:
+		//	fulfill_call_inherent_updates_head(), fulfill_call_inherent_is_created_from_inherent_data(),
+		//	fulfill_call_inherent_rejects_forged_store()
+		#[pallet::call_index(49)]
+		#[pallet::weight(weight_helper::fulfill_call::<T>(*function_id))]
+		pub fn fulfill_call_inherent(
+			origin: OriginFor<T>,
+			function_id: H256,
+			inputs: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+			Self::do_fulfill_call(None, function_id, inputs)
+		}
+
+		/// attest_signature records that the Ethereum account `signer` signed `data`, for
+		/// cross-chain authentication on Avail. An externally owned account proves it with its
+		/// `personal_sign` signature of `data`, a contract account with the approval of its hash
+		/// in the contract storage.
+		//
+		// Test names:
+		//	attest_signature_works_with_ecdsa(), attest_signature_fails_for_other_signer(),
+		//	attest_signature_fails_for_contract_with_invalid_proof()
+		#[pallet::call_index(50)]
+		#[pallet::weight(
+			T::WeightInfo::attest_signature()
+				.saturating_add(weight_helper::signature_proof::<T>(proof))
+		)]
+		pub fn attest_signature(
+			origin: OriginFor<T>,
+			signer: H160,
+			data: BoundedVec<u8, ConstU32<MAX_ATTESTED_DATA_LENGTH>>,
+			proof: ValidSignatureProof<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let message_hash = attestation::message_hash(&data);
+			let kind = proof.kind();
+
+			match proof {
+				SignatureProof::Ecdsa(signature) => ensure!(
+					attestation::recover_signer(message_hash, &signature) == Some(signer),
+					Error::<T>::InvalidEthereumSignature
+				),
+				SignatureProof::Contract {
+					slot,
+					mapping_slot,
+					account_proof,
+					storage_proof,
+				} => {
+					let root = ExecutionStateRoots::<T>::get(slot);
+					let account_proof_vec = Self::proof_nodes(account_proof)?;
+					let storage_root = get_storage_root::<T::SourceChainTrieLayout>(
+						account_proof_vec,
+						signer,
+						root,
+					)
+					.map_err(|_| Error::<T>::CannotGetStorageRoot)?;
+
+					let storage_proof_vec = Self::proof_nodes(storage_proof)?;
+					let approval = get_storage_value::<T::SourceChainTrieLayout>(
+						attestation::approval_slot(message_hash, mapping_slot),
+						storage_root,
+						storage_proof_vec,
+					)
+					.map_err(|_| Error::<T>::CannotGetStorageValue)?;
+					ensure!(!approval.is_zero(), Error::<T>::SignatureNotApproved);
+				},
+			}
+
+			Attestations::<T>::insert(
+				signer,
+				message_hash,
+				<frame_system::Pallet<T>>::block_number(),
+			);
+			Self::deposit_event(Event::SignatureAttested {
+				signer,
+				message_hash,
+				kind,
+			});
+			Ok(())
+		}
+
+		/// prove_execution_block_number records the number of the execution block finalized at
+		/// `slot`, proven against the stored header root of the slot, so downstream proofs can
+		/// reference execution blocks by number without trusting an offchain mapping.
+		//
+		// Test names:
+		//	prove_execution_block_number_works(), prove_execution_block_number_fails_for_invalid_proof(),
+		//	prove_execution_block_number_fails_when_already_proven()
+		#[pallet::call_index(51)]
+		#[pallet::weight(T::WeightInfo::prove_execution_block_number())]
+		pub fn prove_execution_block_number(
+			origin: OriginFor<T>,
+			#[pallet::compact] slot: u64,
+			proof: ExecutionBlockNumberProof,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(
+				!ExecutionBlockNumbers::<T>::contains_key(slot),
+				Error::<T>::ExecutionBlockNumberAlreadyProven
+			);
+			let header_root = Headers::<T>::get(slot);
+			ensure!(!header_root.is_zero(), Error::<T>::HeaderRootNotSet);
+			ensure!(
+				beacon::verify_execution_block_number_proof(
+					Self::consensus_fork(slot),
+					header_root,
+					&proof
+				),
+				Error::<T>::InvalidExecutionBlockNumberProof
+			);
+
+			ExecutionBlockNumbers::<T>::insert(slot, proof.block_number);
+			Self::deposit_event(Event::ExecutionBlockNumberProven {
+				slot,
+				block_number: proof.block_number,
+			});
+			Ok(())
+		}
+
+		/// register_destination_handler hands the arbitrary messages to `destination` to the
+		/// runtime handler with id `handler` until block `expiry`, if any. The registration is
+		/// bound to the current runtime code, and must be renewed after a runtime upgrade.
+		//
+		// Test names:
+		//	register_destination_handler_works_with_root(), register_destination_handler_does_not_work_with_non_root(),
+		//	execute_hands_message_to_destination_handler(), execute_fails_for_expired_destination_handler(),
+		//	execute_fails_for_destination_handler_of_other_code()
+		#[pallet::call_index(54)]
+		#[pallet::weight(T::WeightInfo::register_destination_handler())]
+		pub fn register_destination_handler(
+			origin: OriginFor<T>,
+			destination: H256,
+			handler: u32,
+			expiry: Option<u32>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+			let registration = HandlerRegistration {
+				handler,
+				code_hash: RuntimeCodeHash::<T>::get(),
+				expiry,
+			};
+			ensure!(
+				!registration.is_expired(now),
+				Error::<T>::InvalidHandlerExpiry
+			);
+
+			DestinationHandlers::<T>::insert(destination, registration);
+			Self::deposit_event(Event::DestinationHandlerRegistered {
+				destination,
+				handler,
+				code_hash: registration.code_hash,
+				expiry,
+			});
+			Ok(())
+		}
+
+		/// deregister_destination_handler stops handing the messages to `destination` to its
+		/// runtime handler.
+		//
+		// Test names:
+		//	deregister_destination_handler_works_with_root(), deregister_destination_handler_fails_when_not_registered()
+		#[pallet::call_index(55)]
+		#[pallet::weight(T::WeightInfo::deregister_destination_handler())]
+		pub fn deregister_destination_handler(
+			origin: OriginFor<T>,
+			destination: H256,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			DestinationHandlers::<T>::take(destination)
+				.ok_or(Error::<T>::DestinationHandlerNotRegistered)?;
+
+			Self::deposit_event(Event::DestinationHandlerDeregistered { destination });
+			Ok(())
+		}
+
+		/// set_governance_sender sets the domain and address of the governance of the source
+		/// chain, or removes it if `sender` is `None`. The arbitrary messages it sends are SCALE
+		/// encoded calls of the pallet, scheduled for execution after `Config::GovernanceDelay`
+		/// blocks if they are whitelisted by `is_governance_call`.
+		//
+		// Test names:
+		//	set_governance_sender_works_with_root(), set_governance_sender_does_not_work_with_non_root(),
+		//	execute_schedules_governance_call(), execute_fails_for_governance_message_not_whitelisted(),
+		//	execute_fails_for_governance_message_changing_trust_root()
+		#[pallet::call_index(56)]
+		#[pallet::weight(T::WeightInfo::set_governance_sender())]
+		pub fn set_governance_sender(
+			origin: OriginFor<T>,
+			sender: Option<(u32, H256)>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			GovernanceSender::<T>::set(sender);
+
+			Self::deposit_event(Event::GovernanceSenderUpdated { sender });
+			Ok(())
+		}
+
+		/// cancel_governance_call cancels the call scheduled by the governance message with root
+		/// `message_root`, until it is executed.
+		//
+		// Test names:
+		//	cancel_governance_call_works_with_root(), cancel_governance_call_does_not_work_with_non_root()
+		#[pallet::call_index(57)]
+		#[pallet::weight(T::WeightInfo::cancel_governance_call())]
+		pub fn cancel_governance_call(origin: OriginFor<T>, message_root: H256) -> DispatchResult {
+			ensure_root(origin)?;
+			GovernanceCalls::<T>::take(message_root).ok_or(Error::<T>::GovernanceCallNotFound)?;
+
+			Self::deposit_event(Event::GovernanceCallCancelled { message_root });
+			Ok(())
+		}
+
+		/// execute_governance_call executes with the root origin the `call` scheduled by the
+		/// governance message with root `message_root`, once its delay has elapsed. Anyone can
+		/// execute it.
+		//
+		// Test names:
+		//	execute_governance_call_works_after_delay(), execute_governance_call_fails_before_delay(),
+		//	execute_governance_call_fails_for_other_call()
+		#[pallet::call_index(58)]
+		#[pallet::weight(
+			T::WeightInfo::execute_governance_call().saturating_add(call.get_dispatch_info().weight)
+		)]
+		pub fn execute_governance_call(
+			origin: OriginFor<T>,
+			message_root: H256,
+			call: Box<Call<T>>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let scheduled = GovernanceCalls::<T>::get(message_root)
+				.ok_or(Error::<T>::GovernanceCallNotFound)?;
+			ensure!(
+				scheduled.call_hash == H256(blake2_256(&call.encode())),
+				Error::<T>::GovernanceCallMismatch
+			);
+			let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+			ensure!(
+				now >= scheduled.executable_at,
+				Error::<T>::GovernanceCallNotExecutable
+			);
+
+			GovernanceCalls::<T>::remove(message_root);
+			let result = call
+				.dispatch_bypass_filter(frame_system::RawOrigin::Root.into())
+				.map(|_| ())
+				.map_err(|error| error.error);
+			Self::deposit_event(Event::GovernanceCallExecuted {
+				message_root,
+				result,
+			});
+			Ok(())
+		}
+
+		/// Executes message like `execute`, with zstd compressed account and storage proofs. The
+		/// proofs are decompressed before their verification, which is charged by decompressed
+		/// byte. The weight of the largest proofs is charged upfront and the excess is refunded.
+		/// Frames declaring a window or a content above the largest proof are rejected, see
+		/// `compression::compress`.
+		//
+		// Test names:
+		//	execute_compressed_works(), execute_compressed_fails_for_invalid_proof(),
+		//	execute_compressed_fails_for_proof_above_max_size()
+		#[pallet::call_index(59)]
+		#[pallet::weight(weight_helper::execute_compressed::<T>(addr_message))]
+		pub fn execute_compressed(
+			origin: OriginFor<T>,
+			#[pallet::compact] slot: u64,
+			addr_message: AddressedMessage,
+			account_proof: CompressedProof<T>,
+			storage_proof: CompressedProof<T>,
+		) -> DispatchResultWithPostInfo {
+			let account_proof = Self::decompress_proof(&account_proof)?;
+			let storage_proof = Self::decompress_proof(&storage_proof)?;
+			let decompression =
+				weight_helper::decompressed_proofs::<T>(&account_proof, &storage_proof);
+			let weight = weight_helper::execute::<T>(&addr_message, &account_proof, &storage_proof);
+
+			let post_info =
+				Self::execute(origin, slot, addr_message, account_proof, storage_proof)?;
+
+			let executed = post_info.actual_weight.unwrap_or(weight);
+			Ok(Some(executed.saturating_add(decompression)).into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Verifies and applies the step or rotate inputs submitted by `sender`, `None` for the
+		/// inputs included by the block author.
+		fn do_fulfill_call(
+			sender: Option<H256>,
+			function_id: H256,
+			inputs: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			// the same inputs were already verified by another updater, skip the verification
+			let input_hash = H256(keccak_256(inputs.as_slice()));
+			if Self::is_input_verified(input_hash) {
+				Self::deposit_event(Event::InputsAlreadyVerified { input_hash });
+				return Ok(PostDispatchInfo {
+					actual_weight: Some(T::WeightInfo::fulfill_call_cached()),
+					pays_fee: Pays::No,
+				});
+			}
+			if let Some(sender) = sender {
+				Self::note_updater_submission(sender)?;
+			}
+
+			let config = ConfigurationStorage::<T>::get();
+			// the light client store of the inputs of a block author, who is not an updater, is
+			// not trusted, it is taken from the chain
+			let function_inputs = match sender {
+				Some(_) => Self::decode_inputs(&inputs)?,
+				None => Self::decode_compact_inputs(&inputs)?,
+			};
+
+			// a step racing a newer one is rejected before its proofs are verified
+			if Self::is_stale_step(&function_inputs, &config) {
+				return Err(
+					Error::<T>::SlotBehindHead.with_weight(T::WeightInfo::fulfill_call_stale())
+				);
+			}
+
+			let prev_head = function_inputs.store.finalized_header.slot.as_u64();
+			let LightClientVerification {
+				mut store,
+				genesis_root,
+				forks,
+				output,
+				..
+			} = Self::verify_light_client(function_inputs);
+			let verified_output = output.ok_or(Error::<T>::VerificationFailed)?;
+			let head = verified_output.finalized_slot;
+
+			let mut function_called = false;
+
+			// 4. Store step if needed
+			match Self::step_acceptance(prev_head, &verified_output, &mut store, &config)? {
+				StepAcceptance::Unchanged => {},
+				StepAcceptance::Equivocation => {
+					Self::note_equivocation(sender, &verified_output);
+					Self::degrade(DegradedReason::FinalityRegression {
+						slot: verified_output.finalized_slot,
+					});
+					Self::note_verified_input(input_hash);
+					Self::note_proof_artifact(input_hash, function_id, inputs);
+					return Ok(().into());
+				},
+				StepAcceptance::Historical => {
+					Self::import_historical_header(verified_output)?;
+					Self::note_verified_input(input_hash);
+					Self::note_proof_artifact(input_hash, function_id, inputs);
+					return Ok(().into());
+				},
+				StepAcceptance::NewHead => {
+					if Self::set_slot_roots(verified_output)? {
+						Self::note_accepted_header(sender, &verified_output);
+						Self::note_participation(
+							verified_output.finalized_slot,
+							verified_output.participation,
+							&config,
+						);
+						Self::deposit_event(Event::HeadUpdated {
+							slot: verified_output.finalized_slot,
+							finalization_root: verified_output.finalized_header_root,
+							execution_state_root: verified_output.execution_state_root,
+						});
+						function_called = true;
+					}
+				},
+			}
+
+			Self::note_light_client_state(&store, &genesis_root, &forks);
+
+			// 5. Store rotate if needed
+			// a) Store current sync committee if stored one is empty (i.e. first time or after a range of updates)
+			let period = head
+				.checked_div(config.slots_per_period)
+				.ok_or(Error::<T>::ConfigurationNotSet)?;
+			let stored_current_sync_committee = SyncCommitteeHashes::<T>::get(period);
+			if stored_current_sync_committee.is_zero() {
+				let current_sync_committee_hash: U256 = store
+					.current_sync_committee
+					.hash_tree_root()
+					.unwrap()
+					.as_ref()
+					.try_into()
+					.unwrap();
+				Self::deposit_event(Event::SyncCommitteeUpdated {
+					period,
+					root: current_sync_committee_hash,
+				});
+				function_called = true;
+
+				Self::set_sync_committee_hash(period, current_sync_committee_hash)?;
+			}
+
+			// b) Store next sync committee if available
+			if let Some(mut next_sync_committee) = store.next_sync_committee {
+				let next_period = period + 1;
+				let stored_next_sync_committee_hash = SyncCommitteeHashes::<T>::get(next_period);
+				let next_sync_committee_hash: [u8; 32] = next_sync_committee
+					.hash_tree_root()
+					.unwrap()
+					.as_ref()
+					.try_into()
+					.unwrap();
+				let next_sync_committee_hash = U256::from(next_sync_committee_hash);
+
+				// If the next sync committee is already correct, we don't need to update it.
+				if stored_next_sync_committee_hash != next_sync_committee_hash.into() {
+					Self::ensure_rotation(head, next_period, &config)?;
+					Self::deposit_event(Event::SyncCommitteeUpdated {
+						period: next_period,
+						root: next_sync_committee_hash,
+					});
+					function_called = true;
+
+					Self::set_sync_committee_hash(next_period, next_sync_committee_hash)?;
+				}
+			}
+
+			if !function_called {
+				return Err(Error::<T>::FunctionIdNotKnown.into());
+			}
+
+			Self::note_verified_input(input_hash);
+			Self::note_proof_artifact(input_hash, function_id, inputs);
+
+			Ok(().into())
+		}
+
+		/// Verifies the sync committee updates, the finality update and the execution state root
+		/// of `inputs` in turn, applying them to the store. The stages after a failed one are
+		/// skipped.
+		pub(crate) fn verify_light_client(inputs: FunctionInputs) -> LightClientVerification {
+			let FunctionInputs {
+				updates,
+				finality_update,
+				expected_current_slot,
+				mut store,
+				genesis_root,
+				forks,
+				execution_state_proof,
+			} = inputs;
+
+			// 1. Apply sync committee updates, if any
+			let mut is_valid = true;
+			let mut update_results = Vec::with_capacity(updates.len());
+			for update in updates.iter() {
+				let result = if is_valid {
+					is_valid = verify_update(
+						update,
+						expected_current_slot,
+						&store,
+						genesis_root.clone(),
+						&forks,
+					)
+					.is_ok();
+					is_valid.into()
+				} else {
+					StageResult::Skipped
+				};
+				update_results.push(result);
+				if is_valid {
+					apply_update(&mut store, update);
+				}
+			}
+
+			// 2. Apply finality update
+			let finality_result = if is_valid {
+				is_valid = verify_finality_update(
+					&finality_update,
+					expected_current_slot,
+					&store,
+					genesis_root.clone(),
+					&forks,
+				)
+				.is_ok();
+				if is_valid {
+					apply_finality_update(&mut store, &finality_update);
+				}
+				is_valid.into()
+			} else {
+				StageResult::Skipped
+			};
+
+			// 3. Verify execution state root proof, laid out by the fork of the finalized slot
+			let execution_state_root =
+				H256::from_slice(execution_state_proof.execution_state_root.as_slice());
+			let finalized_slot = store.finalized_header.slot.as_u64();
+			let root_result = if is_valid {
+				let execution_state_branch: Vec<H256> = execution_state_proof
+					.execution_state_branch
+					.iter()
+					.map(|b| H256::from_slice(b.as_slice()))
+					.collect();
+				is_valid = beacon::verify_execution_state_root(
+					Self::consensus_fork(finalized_slot),
+					execution_state_root,
+					&execution_state_branch,
+					H256::from_slice(store.finalized_header.body_root.as_ref()),
+				);
+				is_valid.into()
+			} else {
+				StageResult::Skipped
+			};
+
+			let output = if is_valid {
+				let participation = store
+					.current_max_active_participants
+					.try_into()
+					.unwrap_or(u16::MAX);
+				store
+					.finalized_header
+					.hash_tree_root()
+					.ok()
+					.map(|root| VerifiedStepOutput {
+						finalized_header_root: H256::from_slice(root.as_ref()),
+						execution_state_root,
+						finalized_slot,
+						participation,
+					})
+			} else {
+				None
+			};
+
+			LightClientVerification {
+				updates: update_results,
+				finality_update: finality_result,
+				execution_state_root: root_result,
+				store,
+				genesis_root,
+				forks,
+				output,
+			}
+		}
+
+		/// Returns how the verified `output` is applied against the head, `prev_head` being the
+		/// finalized slot of the store before the step, or the error rejecting it.
+		pub(crate) fn step_acceptance(
+			prev_head: u64,
+			output: &VerifiedStepOutput,
+			store: &mut LightClientStore,
+			config: &Configuration,
+		) -> Result<StepAcceptance, DispatchError> {
+			if prev_head == output.finalized_slot {
+				return Ok(StepAcceptance::Unchanged);
+			}
+			Self::ensure_finalizable(None, output.finalized_slot)?;
+
+			let head = Head::<T>::get();
+			if output.finalized_slot <= head && Self::is_conflicting_header(output) {
+				return Ok(StepAcceptance::Equivocation);
+			}
+			if output.finalized_slot < head {
+				let committee_hash: U256 = store
+					.current_sync_committee
+					.hash_tree_root()
+					.unwrap()
+					.as_ref()
+					.try_into()
+					.unwrap();
+				if Self::is_stored_committee(output.finalized_slot, committee_hash, config) {
+					return Ok(StepAcceptance::Historical);
+				}
+			}
+			ensure!(output.finalized_slot > head, Error::<T>::SlotBehindHead);
+
+			Ok(StepAcceptance::NewHead)
+		}
+
+		fn do_execute(
+			root: H256,
+			addr_message: AddressedMessage,
+			account_proof: ValidProof<T>,
+			storage_proof: ValidProof<T>,
+			relayer: Option<T::AccountId>,
+			tip: u128,
+		) -> DispatchResultWithPostInfo {
+			let message_root = Self::message_root(&addr_message);
+			Self::check_preconditions(&addr_message, message_root)?;
+			Self::verify_message(
+				root,
+				&addr_message,
+				message_root,
+				account_proof,
+				storage_proof,
+			)?;
+
+			Self::dispatch_message(addr_message, message_root, relayer.as_ref(), tip)?;
+
+			Ok(().into())
+		}
+
+		/// Verifies the messages of `domain` against the execution state root with a single
+		/// storage multiproof, then executes them in order.
+		fn do_execute_batch(
+			root: H256,
+			domain: u32,
+			messages: Vec<AddressedMessage>,
+			account_proof: ValidProof<T>,
+			storage_proof: MultiProof<T>,
+			relayer: Option<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			let broadcaster = messages
+				.first()
+				.map(|addr_message| Self::message_broadcaster(addr_message, domain))
+				.unwrap_or_else(|| Broadcasters::<T>::get(domain));
+			for addr_message in messages.iter() {
+				Self::ensure_source_address(domain, addr_message)?;
+				// messages of chain ids from before and after a fork are stored apart
+				ensure!(
+					Self::message_broadcaster(addr_message, domain) == broadcaster,
+					Error::<T>::MixedSourceChains
+				);
+			}
+			let storage_root =
+				Self::broadcaster_storage_root(domain, broadcaster, root, account_proof)?;
+			let slot_keys: Vec<H256> = messages
+				.iter()
+				.map(|addr_message| Self::message_slot_key(addr_message.id))
+				.collect();
+
+			let storage_proof_vec = storage_proof
+				.iter()
+				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
+				.collect();
+
+			let slot_values = get_storage_values::<T::SourceChainTrieLayout>(
+				&slot_keys,
+				storage_root,
+				storage_proof_vec,
+			)
+			.map_err(|_| Error::<T>::CannotGetStorageValue)?;
+
+			for (addr_message, slot_value) in messages.into_iter().zip(slot_values) {
+				let message_root = Self::message_root(&addr_message);
+				ensure!(slot_value == message_root, Error::<T>::InvalidMessageHash);
+				// Checked after the previous messages are executed, so a message is not executed
+				// twice by the same batch.
+				Self::check_preconditions(&addr_message, message_root)?;
+				Self::dispatch_message(addr_message, message_root, relayer.as_ref(), 0)?;
+			}
+
+			Ok(().into())
+		}
+
+		/// Executes a proven message, or queues it if its ordered channel is waiting for earlier
+		/// messages. The `tip` of an execution permit is only paid if the message is executed.
+		fn dispatch_message(
+			addr_message: AddressedMessage,
+			message_root: H256,
+			relayer: Option<&T::AccountId>,
+			tip: u128,
+		) -> DispatchResult {
+			T::OnMessageReceived::on_message_received(&addr_message, message_root);
+			let (domain, from) = (Self::source_domain(&addr_message), addr_message.from);
+			match OrderedChannels::<T>::get(domain, from) {
+				Some(next_id) if addr_message.id > next_id => {
+					ensure!(
+						!QueuedMessages::<T>::contains_key((domain, from), addr_message.id),
+						Error::<T>::MessageAlreadyQueued
+					);
+					Self::deposit_event(Event::<T>::MessageQueued {
+						from,
+						to: addr_message.to,
+						message_id: addr_message.id,
+						message_root,
+					});
+					QueuedMessages::<T>::insert((domain, from), addr_message.id, addr_message);
+				},
+				Some(next_id) => {
+					ensure!(addr_message.id == next_id, Error::<T>::MessageOutOfOrder);
+					Self::deliver_message(addr_message, message_root, relayer, tip)?;
+					QueuedMessages::<T>::remove((domain, from), next_id);
+					Self::drain_ordered_channel(domain, from, next_id.saturating_add(1), relayer);
+				},
+				None => Self::deliver_message(addr_message, message_root, relayer, tip)?,
+			}
+
+			Ok(())
+		}
+
+		/// Returns the hash of the message, as stored in the source chain broadcaster.
+		fn message_root(addr_message: &AddressedMessage) -> H256 {
+			amb_primitives::message_root(addr_message)
+		}
+
+		/// Verifies that the message is stored in the broadcaster of its source chain, proven
+		/// against the execution state root.
+		fn verify_message(
+			root: H256,
+			addr_message: &AddressedMessage,
+			message_root: H256,
+			account_proof: ValidProof<T>,
+			storage_proof: ValidProof<T>,
+		) -> DispatchResult {
+			let domain = Self::source_domain(addr_message);
+			Self::ensure_source_address(domain, addr_message)?;
+
+			let broadcaster = Self::message_broadcaster(addr_message, domain);
+			let storage_root =
+				Self::broadcaster_storage_root(domain, broadcaster, root, account_proof)?;
+			let slot_key = Self::message_slot_key(addr_message.id);
+
+			let storage_proof_vec = Self::proof_nodes(storage_proof)?;
+
+			let slot_value = get_storage_value::<T::SourceChainTrieLayout>(
+				slot_key,
+				storage_root,
+				storage_proof_vec,
+			)
+			.map_err(|_| Error::<T>::CannotGetStorageValue)?;
+
+			ensure!(slot_value == message_root, Error::<T>::InvalidMessageHash);
+
+			Ok(())
+		}
+
+		/// Executes a proven message and, if its sender is acknowledged, queues the result for
+		/// the sender. A failing acknowledged message is marked as failed instead of reverting.
+		fn deliver_message(
+			addr_message: AddressedMessage,
+			message_root: H256,
+			relayer: Option<&T::AccountId>,
+			tip: u128,
+		) -> DispatchResult {
+			let domain = Self::source_domain(&addr_message);
+			// Reverted along with the message if it is not delivered.
+			let broadcaster = Self::message_broadcaster(&addr_message, domain);
+			NonceWindows::<T>::mutate(domain, broadcaster, |window| window.note(addr_message.id));
+			let acknowledged = AcknowledgedSenders::<T>::get(domain, addr_message.from);
+			// Messages to EVM contracts are marked as failed when the contract reverts too.
+			if !acknowledged && Self::evm_contract(&addr_message).is_none() {
+				Self::do_deliver_message(&addr_message, message_root, relayer, tip)?;
+				T::OnMessageExecuted::on_message_executed(&addr_message, message_root, true);
+				return Ok(());
+			}
+
+			let result = with_storage_layer(|| {
+				Self::do_deliver_message(&addr_message, message_root, relayer, tip)
+			});
+			let return_data_hash = match result {
+				Ok(()) => H256::zero(),
+				Err(error) => {
+					MessageStatus::<T>::set(message_root, MessageStatusEnum::ExecutionFailed);
+					MessageFailures::<T>::insert(message_root, Self::execution_failure(error));
+					Self::record_message_header(&addr_message, message_root);
+					Self::deposit_event(Event::<T>::MessageExecutionFailed {
+						from: addr_message.from,
+						to: addr_message.to,
+						message_id: addr_message.id,
+						message_root,
+						error,
+					});
+					H256(keccak_256(&error.encode()))
+				},
+			};
+			T::OnMessageExecuted::on_message_executed(&addr_message, message_root, result.is_ok());
+			if !acknowledged {
+				return Ok(());
+			}
+
+			Self::queue_acknowledgement(
+				&addr_message,
+				Acknowledgement {
+					message_id: addr_message.id,
+					message_root,
+					success: result.is_ok(),
+					return_data_hash,
+				},
+			)
+		}
+
+		/// Classifies the error a message execution failed with.
+		pub(crate) fn execution_failure(error: DispatchError) -> ExecutionFailure {
+			let decode_errors = [
+				Error::<T>::InvalidMessageData.into(),
+				Error::<T>::CannotDecodeDestinationAccountId.into(),
+			];
+			let code = match error {
+				_ if decode_errors.contains(&error) => FailureCode::DecodeFailure,
+				DispatchError::Token(_) | DispatchError::Arithmetic(_) => FailureCode::CapExceeded,
+				DispatchError::Module(_) => FailureCode::HandlerReverted,
+				_ => FailureCode::Other,
+			};
+			let module_error = match error {
+				DispatchError::Module(module_error) => {
+					Some((module_error.index, module_error.error[0]))
+				},
+				_ => None,
+			};
+
+			ExecutionFailure { code, module_error }
+		}
+
+		/// Returns whether `call` submits a light client update or a message proof, charged with
+		/// the fee multiplier of the bridge.
+		pub fn is_proof_submission(call: &Call<T>) -> bool {
+			matches!(
+				call,
+				Call::fulfill_call { .. }
+					| Call::execute { .. }
+					| Call::execute_beacon_anchored { .. }
+					| Call::execute_archived { .. }
+					| Call::execute_messages { .. }
+					| Call::execute_with_permit { .. }
+					| Call::execute_rollup_anchored { .. }
+					| Call::execute_compressed { .. }
+			)
+		}
+
+		/// Returns whether `call` updates a parameter of the bridge, i.e. a fee, a limit, a
+		/// retention or a schedule, which the governance of the source chain can schedule.
+		///
+		/// Calls changing what the bridge trusts, e.g. the broadcasters, the whitelisted domains
+		/// or the finality, stay reserved to root.
+		pub fn is_governance_call(call: &Call<T>) -> bool {
+			matches!(
+				call,
+				Call::set_participation_requirement { .. }
+					| Call::set_execution_fee { .. }
+					| Call::set_message_length_limit { .. }
+					| Call::set_proof_retention { .. }
+					| Call::set_release_schedule { .. }
+			)
+		}
+
+		/// Adds the weight used by a bridge proof submission to the usage of the block.
+		pub fn note_proof_submission(weight: Weight) {
+			BridgeFeeUsage::<T>::mutate(|usage| *usage = usage.saturating_add(weight));
+		}
+
+		/// Moves the fee multiplier of bridge proof submissions towards the usage of the block.
+		fn update_bridge_fee_multiplier() {
+			let next = fees::next_multiplier(
+				BridgeFeeMultiplier::<T>::get(),
+				BridgeFeeUsage::<T>::get(),
+				T::BridgeFeeTarget::get(),
+				T::BridgeFeeAdjustment::get(),
+				T::MaximumBridgeFeeMultiplier::get(),
+			);
+			BridgeFeeMultiplier::<T>::put(next);
+		}
+
+		/// Returns the weight of the extrinsic bridging a message of `payload_size` bytes in
+		/// `direction`, an arbitrary message or, without payload, a fungible token transfer.
+		pub fn quote_message_weight(direction: MessageDirection, payload_size: u32) -> Weight {
+			match direction {
+				MessageDirection::Inbound => weight_helper::quoted_execute::<T>(payload_size),
+				MessageDirection::Outbound => {
+					let send = match payload_size {
+						0 => T::WeightInfo::send_message_fungible_token(),
+						size => T::WeightInfo::send_message_arbitrary_message(size),
+					};
+					send.saturating_add(weight_helper::outbound_message::<T>())
+						.saturating_add(weight_helper::message_length_limit::<T>())
+				},
+			}
+		}
+
+		/// Returns the gas used on the destination chain by the delivery of a message of
+		/// `payload_size` bytes sent to `domain` in `direction`, and its cost at the base fee
+		/// proven for `domain`. The delivery of an inbound message uses no gas.
+		pub fn quote_destination_fee(
+			direction: MessageDirection,
+			payload_size: u32,
+			domain: u32,
+		) -> (u64, Option<U256>) {
+			if direction == MessageDirection::Inbound {
+				return (0, None);
+			}
+			let gas = fees::destination_gas(payload_size);
+			let fee = SourceBaseFees::<T>::get(domain)
+				.map(|proven| proven.base_fee.saturating_mul(gas.into()));
+			(gas, fee)
+		}
+
+		/// Adds the root of a message sent from Avail to the outbound root of the block.
+		fn queue_outbound_message(addr_message: &AddressedMessage) -> DispatchResult {
+			let message_root = Self::message_root(addr_message);
+			OutboundMessages::<T>::try_mutate(|roots| roots.try_push(message_root))
+				.map_err(|_| Error::<T>::OutboundQueueFull.into())
+		}
+
+		/// Commits the messages sent in the block to their outbound root, deposited in the header
+		/// digest too if `Config::DepositOutboundRootLog` is set.
+		pub(crate) fn commit_outbound_root() {
+			let message_roots = OutboundMessages::<T>::get();
+			if message_roots.is_empty() {
+				return;
+			}
+
+			let outbound = outbound::outbound_root(&message_roots);
+			OutboundMessagesRoot::<T>::put(outbound);
+			if T::DepositOutboundRootLog::get() {
+				<frame_system::Pallet<T>>::deposit_log(DigestItem::Other(
+					(OUTBOUND_ROOT_LOG_PREFIX, outbound).encode(),
+				));
+			}
+			Self::deposit_event(Event::<T>::OutboundRootCommitted {
+				root: outbound.root,
+				leaves: outbound.leaves,
+			});
+		}
+
+		/// Queues `ack` as an outbound message from the recipient of `addr_message` to its sender.
+		fn queue_acknowledgement(
+			addr_message: &AddressedMessage,
+			ack: Acknowledgement,
+		) -> DispatchResult {
+			let destination_domain = Self::source_domain(addr_message);
+			OutboundAcknowledgements::<T>::try_mutate(|acks| {
+				let block = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+				let id = ACK_ID_FLAG | tx_uid(block, acks.len() as u32);
+				let message = AddressedMessage {
+					message: Message::ArbitraryMessage(BoundedVec::truncate_from(ack.abi_encode())),
+					from: addr_message.to,
+					to: addr_message.from,
+					origin_domain: T::AvailDomain::get(),
+					destination_domain,
+					id,
+				};
+				Self::queue_outbound_message(&message)?;
+				acks.try_push(message)
+					.map_err(|_| Error::<T>::AcknowledgementQueueFull)?;
+
+				Self::deposit_event(Event::<T>::AcknowledgementQueued {
+					to: addr_message.from,
+					destination_domain,
+					message_id: id,
+					success: ack.success,
+				});
+				Ok(())
+			})
+		}
+
+		/// Executes a proven message and marks it as executed. A relayer executing a fungible
+		/// token for another recipient takes the execution fee of the asset and the `tip` of the
+		/// execution permit, if any, out of the amount.
+		fn do_deliver_message(
+			addr_message: &AddressedMessage,
+			message_root: H256,
+			relayer: Option<&T::AccountId>,
+			tip: u128,
+		) -> DispatchResult {
+			if addr_message.destination_domain != T::AvailDomain::get() {
+				return Self::route_message(addr_message, message_root);
+			}
+			if let Some(contract) = Self::evm_contract(addr_message) {
+				Self::deliver_to_evm(addr_message, contract)?;
+				return Self::record_delivery(addr_message, message_root);
+			}
+
+			if let Message::ArbitraryMessage(data) = &addr_message.message {
+				Self::check_message_data(addr_message.to, data)?;
+				if PayoutSenders::<T>::get(Self::source_domain(addr_message), addr_message.from) {
+					Self::execute_payouts(data, message_root)?;
+				}
+				if Self::is_governance_sender(addr_message) {
+					Self::schedule_governance_call(data, message_root)?;
+				}
+				Self::hand_to_destination_handler(addr_message, message_root)?;
+			}
+
+			if let Message::FungibleToken { asset_id, amount } = &addr_message.message {
+				ensure!(
+					SUPPORTED_ASSET_ID == *asset_id,
+					Error::<T>::AssetNotSupported
+				);
+
+				let destination_account_id =
+					T::AccountId::decode(&mut &addr_message.to.encode()[..])
+						.map_err(|_| Error::<T>::CannotDecodeDestinationAccountId)?;
+
+				let fee = match (relayer, ExecutionFees::<T>::get(asset_id)) {
+					(Some(relayer), Some(fee)) if *relayer != destination_account_id => {
+						let fee = fee.fee_of(*amount);
+						T::Currency::transfer(
+							&Self::account_id(),
+							relayer,
+							fee.saturated_into(),
+							ExistenceRequirement::AllowDeath,
+						)?;
+						Self::deposit_event(Event::<T>::ExecutionFeePaid {
+							message_root,
+							relayer: relayer.clone(),
+							fee,
+						});
+						fee
+					},
+					_ => 0,
+				};
+
+				let amount = amount.saturating_sub(fee);
+				let tip = match relayer {
+					Some(relayer) if tip > 0 && *relayer != destination_account_id => {
+						ensure!(tip <= amount, Error::<T>::PermitTipTooHigh);
+						T::Currency::transfer(
+							&Self::account_id(),
+							relayer,
+							tip.saturated_into(),
+							ExistenceRequirement::AllowDeath,
+						)?;
+						tip
+					},
+					_ => 0,
+				};
+
+				let amount = amount.saturating_sub(tip);
+				match ReleaseSchedules::<T>::get(asset_id) {
+					Some(schedule) if amount >= schedule.threshold => {
+						Self::vest(addr_message.to, message_root, amount, schedule)
+					},
+					_ => T::Currency::transfer(
+						&Self::account_id(),
+						&destination_account_id,
+						amount.saturated_into(),
+						ExistenceRequirement::AllowDeath,
+					)?,
+				}
+			}
+
+			Self::record_delivery(addr_message, message_root)
+		}
+
+		/// Marks a delivered message as executed.
+		fn record_delivery(addr_message: &AddressedMessage, message_root: H256) -> DispatchResult {
+			MessageStatus::<T>::set(message_root, MessageStatusEnum::ExecutionSucceeded);
+			Self::record_message_header(addr_message, message_root);
+			Self::deposit_event(Event::<T>::MessageExecuted {
+				from: addr_message.from,
+				to: addr_message.to,
+				message_id: addr_message.id,
+				message_root,
+			});
+
+			Ok(())
+		}
+
+		/// Returns whether `addr_message` is sent by the governance of the source chain.
+		fn is_governance_sender(addr_message: &AddressedMessage) -> bool {
+			GovernanceSender::<T>::get()
+				== Some((Self::source_domain(addr_message), addr_message.from))
+		}
+
+		/// Schedules the call of the pallet encoded in the governance message `data`, which must
+		/// be whitelisted by `is_governance_call`.
+		fn schedule_governance_call(data: &[u8], message_root: H256) -> DispatchResult {
+			let call =
+				Call::<T>::decode_all_with_depth_limit(MAX_GOVERNANCE_CALL_DEPTH, &mut &data[..])
+					.map_err(|_| Error::<T>::InvalidGovernanceCall)?;
+			ensure!(
+				Self::is_governance_call(&call),
+				Error::<T>::InvalidGovernanceCall
+			);
+
+			let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+			let scheduled = GovernanceCall {
+				call_hash: H256(blake2_256(&call.encode())),
+				executable_at: now.saturating_add(T::GovernanceDelay::get()),
+			};
+			GovernanceCalls::<T>::insert(message_root, scheduled);
+			Self::deposit_event(Event::<T>::GovernanceCallScheduled {
+				message_root,
+				call_hash: scheduled.call_hash,
+				executable_at: scheduled.executable_at,
+			});
+			Ok(())
+		}
+
+		/// Hands a message to the runtime handler registered for its recipient, if any.
+		fn hand_to_destination_handler(
+			addr_message: &AddressedMessage,
+			message_root: H256,
+		) -> DispatchResult {
+			let Some(registration) = DestinationHandlers::<T>::get(addr_message.to) else {
+				return Ok(());
+			};
+			let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+			ensure!(
+				!registration.is_expired(now),
+				Error::<T>::DestinationHandlerExpired
+			);
+			ensure!(
+				registration.code_hash == RuntimeCodeHash::<T>::get(),
+				Error::<T>::DestinationHandlerCodeChanged
+			);
+
+			T::DestinationHandlers::handle(registration.handler, addr_message, message_root)
+				.unwrap_or_else(|| Err(Error::<T>::UnknownDestinationHandler.into()))
+		}
+
+		/// Returns the EVM contract `addr_message` is addressed to, if any.
+		fn evm_contract(addr_message: &AddressedMessage) -> Option<H160> {
+			if addr_message.destination_domain != T::AvailDomain::get() {
+				return None;
+			}
+			evm::evm_address(addr_message.to)
+				.filter(|address| T::EvmExecutor::is_contract(*address))
+		}
+
+		/// Delivers a message to the EVM `contract`: the amount of a fungible token message is
+		/// added to the EVM balance of the contract, and an arbitrary message calls the contract
+		/// with its data as input, with at most `Config::EvmGasLimit` gas.
+		fn deliver_to_evm(addr_message: &AddressedMessage, contract: H160) -> DispatchResult {
+			match &addr_message.message {
+				Message::FungibleToken { asset_id, amount } => {
+					ensure!(
+						SUPPORTED_ASSET_ID == *asset_id,
+						Error::<T>::AssetNotSupported
+					);
+					let account = T::EvmExecutor::account_of(contract);
+					let account_id = T::AccountId::decode(&mut &account.encode()[..])
+						.map_err(|_| Error::<T>::CannotDecodeDestinationAccountId)?;
+					T::Currency::transfer(
+						&Self::account_id(),
+						&account_id,
+						(*amount).saturated_into(),
+						ExistenceRequirement::AllowDeath,
+					)
+				},
+				Message::ArbitraryMessage(data) => {
+					// The sender is the source chain address, right-padded like in the broadcaster.
+					let source = H160::from_slice(&addr_message.from.as_bytes()[..20]);
+					T::EvmExecutor::call(source, contract, data.to_vec(), T::EvmGasLimit::get())
+						.map_err(|_| Error::<T>::EvmCallReverted.into())
+				},
+			}
+		}
+
+		/// Records the header of the message with root `message_root`, for explorers.
+		fn record_message_header(addr_message: &AddressedMessage, message_root: H256) {
+			let header = MessageHeader::of(addr_message);
+			MessageHeaders::<T>::insert(message_root, header.clone());
+			Self::deposit_event(Event::<T>::MessageHeaderRecorded {
+				message_root,
+				header,
+			});
+		}
+
+		/// Keeps `amount` in the bridge account, to be released to `recipient` over the duration
+		/// of `schedule` from now.
+		fn vest(recipient: H256, message_root: H256, amount: u128, schedule: ReleaseSchedule) {
+			let vested = VestedRelease {
+				recipient,
+				amount,
+				released: 0,
+				start: <frame_system::Pallet<T>>::block_number().saturated_into(),
+				duration: schedule.duration,
+			};
+			VestedReleases::<T>::insert(message_root, vested);
+			Self::deposit_event(Event::<T>::ReleaseScheduled {
+				message_root,
+				recipient,
+				amount,
+				duration: schedule.duration,
+			});
+		}
+
+		/// Transfers the payouts of the batch encoded in `data` from the bridge account.
+		fn execute_payouts(data: &[u8], message_root: H256) -> DispatchResult {
+			let batch =
+				payout::decode_message_data_v2(data).map_err(|_| Error::<T>::InvalidPayoutBatch)?;
+			ensure!(
+				SUPPORTED_ASSET_ID == batch.asset_id,
+				Error::<T>::AssetNotSupported
+			);
+
+			let mut total = 0u128;
+			for payout in batch.payouts.iter() {
+				let recipient = T::AccountId::decode(&mut &payout.recipient.encode()[..])
+					.map_err(|_| Error::<T>::CannotDecodeDestinationAccountId)?;
+				T::Currency::transfer(
+					&Self::account_id(),
+					&recipient,
+					payout.amount.saturated_into(),
+					ExistenceRequirement::AllowDeath,
+				)?;
+				total = total.saturating_add(payout.amount);
+			}
+
+			Self::deposit_event(Event::<T>::PayoutsExecuted {
+				message_root,
+				asset_id: batch.asset_id,
+				count: batch.payouts.len() as u32,
+				total,
+			});
+			Ok(())
+		}
+
+		/// Adds a proven message to an app-chain to the outbound root of the block, for the
+		/// app-chain to execute it, and marks it as executed.
+		fn route_message(addr_message: &AddressedMessage, message_root: H256) -> DispatchResult {
+			ensure!(
+				matches!(addr_message.message, Message::ArbitraryMessage(_)),
+				Error::<T>::FungibleTokenNotRoutable
+			);
+			Self::queue_outbound_message(addr_message)?;
+
+			MessageStatus::<T>::set(message_root, MessageStatusEnum::ExecutionSucceeded);
+			Self::record_message_header(addr_message, message_root);
+			Self::deposit_event(Event::<T>::MessageRouted {
+				from: addr_message.from,
+				to: addr_message.to,
+				message_id: addr_message.id,
+				message_root,
+				destination_domain: addr_message.destination_domain,
+			});
+
+			Ok(())
+		}
+
+		/// Executes the queued messages of the ordered channel starting at `next_id` and moves
+		/// the channel past them. At most `MAX_DRAINED_MESSAGES` messages are executed, the rest
+		/// stay queued until the next message of the channel is executed again, or until a block
+		/// has weight left for them in `on_idle`. A queued message that fails stays queued and
+		/// can be retried with its proofs.
+		fn drain_ordered_channel(
+			domain: u32,
+			from: H256,
+			next_id: u64,
+			relayer: Option<&T::AccountId>,
+		) {
+			Self::drain_queued_messages(domain, from, next_id, relayer, MAX_DRAINED_MESSAGES);
+		}
+
+		/// Executes at most `max_messages` queued messages of the ordered channel starting at
+		/// `next_id`, and returns the number of messages executed, skipped or failed.
+		fn drain_queued_messages(
+			domain: u32,
+			from: H256,
+			mut next_id: u64,
+			relayer: Option<&T::AccountId>,
+			max_messages: u32,
+		) -> u32 {
+			let mut drained = 0;
+			while drained < max_messages {
+				let Some(addr_message) = QueuedMessages::<T>::get((domain, from), next_id) else {
+					break;
+				};
+				drained += 1;
+				let message_root = Self::message_root(&addr_message);
+				if VoidedMessages::<T>::contains_key(message_root) {
+					// Voided after it was queued, skipped rather than blocking the channel.
+					Self::deposit_event(Event::<T>::OrderedMessageSkipped {
+						domain,
+						from,
+						message_id: next_id,
+					});
+				} else if with_storage_layer(|| {
+					Self::deliver_message(addr_message, message_root, relayer, 0)
+				})
+				.is_err()
+				{
+					break;
+				}
+				QueuedMessages::<T>::remove((domain, from), next_id);
+				next_id = next_id.saturating_add(1);
+			}
+			OrderedChannels::<T>::insert(domain, from, next_id);
+			drained
+		}
+
+		/// Executes the queued messages ready in the ordered channels after the one of
+		/// `IdleExecutionCursor`, within `limit`, so that relayers only need to prove messages.
+		/// Nothing is executed while the bridge is degraded, as high-value messages then need an
+		/// elevated origin.
+		fn execute_queued_messages(limit: Weight) -> Weight {
+			// Status and cursor lookups, cursor update.
+			let mut used = T::DbWeight::get().reads_writes(2, 1);
+			// Channel iteration and queued message lookup.
+			let per_channel = T::DbWeight::get().reads(2);
+			let per_message = weight_helper::queued_message::<T>();
+			let has_room = |used: Weight| {
+				limit.all_gte(used.saturating_add(per_channel).saturating_add(per_message))
+			};
+			if !has_room(used) {
+				return Weight::zero();
+			}
+			if Status::<T>::get() == BridgeStatus::Degraded {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let mut cursor = IdleExecutionCursor::<T>::get();
+			let mut channels = match cursor {
+				Some((domain, from)) => OrderedChannels::<T>::iter_from(
+					OrderedChannels::<T>::hashed_key_for(domain, from),
+				),
+				None => OrderedChannels::<T>::iter(),
+			};
+			for _ in 0..MAX_IDLE_CHANNELS {
+				if !has_room(used) {
+					break;
+				}
+				used.saturating_accrue(per_channel);
+				let Some((domain, from, next_id)) = channels.next() else {
+					// Every channel had its turn, the next block starts over.
+					cursor = None;
+					break;
+				};
+				cursor = Some((domain, from));
+				if !QueuedMessages::<T>::contains_key((domain, from), next_id) {
+					continue;
+				}
+
+				let max_messages =
+					weight_helper::queued_messages_within::<T>(limit.saturating_sub(used));
+				let drained =
+					Self::drain_queued_messages(domain, from, next_id, None, max_messages);
+				used.saturating_accrue(per_message.saturating_mul(drained.into()));
+			}
+			IdleExecutionCursor::<T>::set(cursor);
+
+			used
+		}
+
+		/// Checks that `data` decodes with the message schema of the recipient, if any.
+		pub(crate) fn check_message_data(to: H256, data: &[u8]) -> DispatchResult {
+			if let Some(schema) = MessageSchemas::<T>::get(to) {
+				abi::decode(&schema, data).map_err(|_| Error::<T>::InvalidMessageData)?;
+			}
+			Ok(())
+		}
+
+		/// Adds `updater` to the allowlist with a fresh quota, replacing its quota if it is there.
+		fn do_add_updater(updater: H256, max_submissions: Option<u32>) {
+			Updaters::<T>::insert(
+				updater,
+				UpdaterQuota {
+					max_submissions,
+					session: T::CurrentSession::get(),
+					submissions: 0,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::UpdaterAdded {
+				updater,
+				max_submissions,
+			});
+		}
+
+		/// Counts a submission against the updater quota, if the updater is in the allowlist.
+		pub(crate) fn note_updater_submission(updater: H256) -> DispatchResult {
+			Updaters::<T>::try_mutate_exists(updater, |maybe_quota| {
+				let Some(quota) = maybe_quota.as_mut() else {
+					return Ok(());
+				};
+
+				let session = T::CurrentSession::get();
+				if quota.session != session {
+					quota.session = session;
+					quota.submissions = 0;
+				}
+				if let Some(max_submissions) = quota.max_submissions {
+					ensure!(
+						quota.submissions < max_submissions,
+						Error::<T>::UpdaterQuotaExceeded
+					);
+				}
+				quota.submissions = quota.submissions.saturating_add(1);
+
+				Ok(())
+			})
+		}
+
+		/// Returns the nodes of an account or storage proof, rejecting proofs deeper than
+		/// `Config::MaxProofDepth`.
+		fn proof_nodes(proof: ValidProof<T>) -> Result<Vec<Vec<u8>>, DispatchError> {
+			ensure!(
+				proof.len() as u32 <= T::MaxProofDepth::get(),
+				Error::<T>::ProofTooDeep
+			);
+			Ok(proof.into_iter().map(BoundedVec::into_inner).collect())
+		}
+
+		/// Decompresses `proof`, a zstd frame of the SCALE encoded proof, rejecting frames which
+		/// decompress to more than the maximum encoded length of a proof.
+		fn decompress_proof(proof: &CompressedProof<T>) -> Result<ValidProof<T>, DispatchError> {
+			let encoded = compression::decompress(proof, ValidProof::<T>::max_encoded_len())
+				.map_err(|error| match error {
+					DecompressionError::InvalidFrame => Error::<T>::InvalidCompressedProof,
+					DecompressionError::TooLarge => Error::<T>::DecompressedProofTooLarge,
+				})?;
+			let proof = ValidProof::<T>::decode_all(&mut &encoded[..])
+				.map_err(|_| Error::<T>::InvalidCompressedProof)?;
+			Ok(proof)
+		}
+
+		/// Returns the storage root of `broadcaster`, a broadcaster contract of `domain`, proven
+		/// against the execution state root. Fails if the code hash of the contracts of the domain
+		/// is pinned to another one.
+		fn broadcaster_storage_root(
+			domain: u32,
+			broadcaster: H256,
+			root: H256,
+			account_proof: ValidProof<T>,
+		) -> Result<H256, DispatchError> {
+			let contract_broadcaster_address = Self::broadcaster_address(domain, broadcaster)?;
+			let account_proof_vec = Self::proof_nodes(account_proof)?;
+
+			let account =
+				Self::verified_account(root, contract_broadcaster_address, account_proof_vec)?;
+			if let Some(code_hash) = PinnedCodeHashes::<T>::get(domain) {
+				ensure!(
+					account.code_hash == code_hash,
+					Error::<T>::SourceCodeHashMismatch
+				);
+			}
+
+			Ok(account.storage_root)
+		}
+
+		/// Returns the address of `broadcaster`, a broadcaster contract of `domain`, in the address
+		/// format of the domain.
+		fn broadcaster_address(
+			domain: u32,
+			broadcaster: H256,
+		) -> Result<SourceAddress, DispatchError> {
+			let address_version = SourceAddressVersions::<T>::get(domain);
+
+			SourceAddress::decode_versioned(address_version, broadcaster)
+				.map_err(|_| Error::<T>::InvalidSourceAddress.into())
+		}
+
+		/// Returns the account `address` proven against the execution state root `root`. The
+		/// proof is only verified if the account was not already proven in the block, the proven
+		/// accounts are recorded in `VerifiedAccountProofs` until it is full.
+		pub(crate) fn verified_account(
+			root: H256,
+			address: SourceAddress,
+			account_proof: Vec<Vec<u8>>,
+		) -> Result<VerifiedAccount, DispatchError> {
+			let mut verified = VerifiedAccountProofs::<T>::get();
+			if let Some(account) = verified
+				.iter()
+				.find(|account| account.state_root == root && account.address == address)
+			{
+				return Ok(*account);
+			}
+
+			let account =
+				get_account_of::<T::SourceChainTrieLayout>(account_proof, address.as_bytes(), root)
+					.map_err(|_| Error::<T>::CannotGetStorageRoot)?;
+			let account = VerifiedAccount {
+				state_root: root,
+				address,
+				storage_root: account.storage_root,
+				code_hash: account.code_hash,
+			};
+			if verified.try_push(account).is_ok() {
+				VerifiedAccountProofs::<T>::put(verified);
+			}
+
+			Ok(account)
+		}
+
+		/// Returns whether the account of the broadcaster of `addr_message` was already proven
+		/// against `root` in the block.
+		fn account_proof_verified(root: H256, addr_message: &AddressedMessage) -> bool {
+			let domain = Self::source_domain(addr_message);
+			let broadcaster = Self::message_broadcaster(addr_message, domain);
+			let Ok(address) = Self::broadcaster_address(domain, broadcaster) else {
+				return false;
+			};
+
+			VerifiedAccountProofs::<T>::get()
+				.iter()
+				.any(|account| account.state_root == root && account.address == address)
+		}
+
+		/// Verifies the final commitment of the rollup contract to a rollup state against the
+		/// execution state root of `slot`, and returns the state root of the rollup.
+		fn verify_rollup_state(
+			slot: u64,
+			config: RollupConfig,
+			proof: ValidRollupProof<T>,
+		) -> Result<H256, DispatchError> {
+			let RollupProof {
+				index,
+				commitment,
+				account_proof,
+				storage_proof,
+			} = proof;
+			ensure!(
+				commitment.matches(&config.layout),
+				Error::<T>::InvalidStateCommitment
+			);
+			let state_root = commitment
+				.state_root()
+				.ok_or(Error::<T>::InvalidStateCommitment)?;
+
+			let root = ExecutionStateRoots::<T>::get(slot);
+			let account_proof_vec = Self::proof_nodes(account_proof)?;
+			let account =
+				get_account::<T::SourceChainTrieLayout>(account_proof_vec, config.contract, root)
+					.map_err(|_| Error::<T>::CannotGetStorageRoot)?;
+
+			let storage_proof_vec = storage_proof
+				.iter()
+				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
+				.collect();
+			let slot_keys = [
+				config.layout.commitment_slot(index),
+				config.layout.finality_slot(index),
+			];
+			let values = get_storage_values::<T::SourceChainTrieLayout>(
+				&slot_keys,
+				account.storage_root,
+				storage_proof_vec,
+			)
+			.map_err(|_| Error::<T>::CannotGetStorageValue)?;
+			let (stored_commitment, finality_value) = (values[0], values[1]);
+
+			ensure!(
+				stored_commitment == commitment.root(),
+				Error::<T>::StateCommitmentMismatch
+			);
+			let timestamp = HeaderTimestamps::<T>::get(slot).unwrap_or_default();
+			ensure!(
+				config.layout.is_final(index, finality_value, timestamp),
+				Error::<T>::StateCommitmentNotFinal
+			);
+
+			Ok(state_root)
+		}
+
+		/// Verifies the beacon block root kept by the EIP-4788 beacon roots contract in the
+		/// execution state of `slot`, and returns it with the fork of its block.
+		fn verify_beacon_roots(
+			slot: u64,
+			proof: ValidBeaconRootsProof<T>,
+		) -> Result<(ConsensusFork, H256), DispatchError> {
+			let beacon::BeaconRootsProof {
+				timestamp,
+				account_proof,
+				storage_proof,
+			} = proof;
+
+			let root = ExecutionStateRoots::<T>::get(slot);
+			let account_proof_vec = Self::proof_nodes(account_proof)?;
+			let account = get_account::<T::SourceChainTrieLayout>(
+				account_proof_vec,
+				beacon::BEACON_ROOTS_ADDRESS,
+				root,
+			)
+			.map_err(|_| Error::<T>::CannotGetStorageRoot)?;
+
+			let storage_proof_vec = storage_proof
+				.iter()
+				.map(|inner_bounded_vec| inner_bounded_vec.iter().copied().collect())
+				.collect();
+			let values = get_storage_values::<T::SourceChainTrieLayout>(
+				&beacon::beacon_roots_slots(timestamp),
+				account.storage_root,
+				storage_proof_vec,
+			)
+			.map_err(|_| Error::<T>::CannotGetStorageValue)?;
+			let entries = values
+				.try_into()
+				.map_err(|_| Error::<T>::CannotGetStorageValue)?;
+			let beacon_root = beacon::kept_beacon_root(timestamp, entries)
+				.ok_or(Error::<T>::BeaconRootExpired)?;
+
+			// The root is of the parent beacon block of the execution block at `timestamp`,
+			// before the slot at that time. Without the slot timing, the ring buffer spans about
+			// a day before `slot`.
+			let parent_slot = Finality::<T>::get()
+				.slot_at(timestamp)
+				.map_or(slot, |at| at.saturating_sub(1));
+			Ok((Self::consensus_fork(parent_slot), beacon_root))
+		}
+
+		/// Verifies that `permit` is unused, not expired and signed by the recipient of the
+		/// message for this message, and returns the recipient.
+		pub(crate) fn verify_permit(
+			addr_message: &AddressedMessage,
+			permit: &ExecutionPermit,
+			signature: &MultiSignature,
+		) -> Result<T::AccountId, DispatchError> {
+			ensure!(
+				permit.message_root == Self::message_root(addr_message),
+				Error::<T>::PermitMessageMismatch
+			);
+			ensure!(
+				!UsedPermits::<T>::contains_key(permit.message_root),
+				Error::<T>::PermitAlreadyUsed
+			);
+			let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+			ensure!(now <= permit.deadline, Error::<T>::PermitExpired);
+
+			// the tip is paid out of the bridged amount left after the execution fee
+			let amount = match addr_message.message {
+				Message::FungibleToken { asset_id, amount } => {
+					let fee = ExecutionFees::<T>::get(asset_id).map_or(0, |fee| fee.fee_of(amount));
+					amount.saturating_sub(fee)
+				},
+				Message::ArbitraryMessage(_) => 0,
+			};
+			ensure!(permit.tip <= amount, Error::<T>::PermitTipTooHigh);
+			ensure!(
+				permit.is_signed_by(addr_message.to, signature),
+				Error::<T>::InvalidPermitSignature
+			);
+
+			T::AccountId::decode(&mut &addr_message.to.encode()[..])
+				.map_err(|_| Error::<T>::CannotDecodeDestinationAccountId.into())
+		}
+
+		/// Ensures that the source chain is not frozen and that the sender is well-formed for it,
+		/// in the address version of the source chain.
+		fn ensure_source_address(domain: u32, addr_message: &AddressedMessage) -> DispatchResult {
+			ensure!(
+				!SourceChainFrozen::<T>::get(domain),
+				Error::<T>::SourceChainFrozen
+			);
+			SourceAddress::decode_versioned(
+				SourceAddressVersions::<T>::get(domain),
+				addr_message.from,
+			)
+			.map_err(|_| Error::<T>::InvalidSourceAddress)?;
+
+			Ok(())
+		}
+
+		/// Returns the storage slot of the message in the broadcaster `messages` mapping.
+		fn message_slot_key(message_id: u64) -> H256 {
+			amb_primitives::message_slot_key(message_id, T::MessageMappingStorageIndex::get())
+		}
+
+		/// Ensures that the data of an arbitrary message to `to` is at most as long as the limit
+		/// of `to`, `MaxMessageLength` bytes if it has none.
+		fn ensure_message_length(message: &Message, to: H256) -> DispatchResult {
+			if let Message::ArbitraryMessage(data) = message {
+				let limit = MessageLengthLimits::<T>::get(to).unwrap_or(T::MaxMessageLength::get());
+				ensure!(data.len() as u32 <= limit, Error::<T>::MessageTooLong);
+			}
+			Ok(())
+		}
+
+		/// Ensures that the origin can execute the message and returns the relayer, if signed.
+		/// While the bridge is degraded, high-value messages can only be executed by
+		/// `DegradedOrigin`.
+		fn ensure_can_execute(
+			origin: OriginFor<T>,
+			addr_message: &AddressedMessage,
+		) -> Result<Option<T::AccountId>, DispatchError> {
+			let Err(origin) = T::DegradedOrigin::try_origin(origin) else {
+				return Ok(None);
+			};
+			let relayer = ensure_signed(origin)?;
+
+			if Status::<T>::get() == BridgeStatus::Degraded {
+				if let Message::FungibleToken { amount, .. } = addr_message.message {
+					ensure!(
+						amount < T::HighValueAmount::get(),
+						Error::<T>::BridgeDegraded
+					);
+				}
+			}
+
+			Ok(Some(relayer))
+		}
+
+		/// Ensures that the step output of `slot` has the participation required by the recipient
+		/// of the message, if any.
+		fn ensure_participation(slot: u64, addr_message: &AddressedMessage) -> DispatchResult {
+			let participation = SlotParticipation::<T>::get(slot).unwrap_or_default();
+			Self::ensure_min_participation(participation, addr_message)
+		}
+
+		/// Checks that `participation` meets the requirement of the recipient of the message.
+		fn ensure_min_participation(
+			participation: u16,
+			addr_message: &AddressedMessage,
+		) -> DispatchResult {
+			let Some(requirement) = ParticipationRequirements::<T>::get(addr_message.to) else {
+				return Ok(());
+			};
+			if let Message::FungibleToken { amount, .. } = addr_message.message {
+				if amount < requirement.min_amount {
+					return Ok(());
+				}
+			}
+
+			ensure!(
+				participation >= requirement.min_participation,
+				Error::<T>::InsufficientParticipation
+			);
+			Ok(())
+		}
+
+		/// Returns true if a different header root is already stored for the verified slot.
+		fn is_conflicting_header(step_output: &VerifiedStepOutput) -> bool {
+			let mut header = Headers::<T>::get(step_output.finalized_slot);
+			if header.is_zero() {
+				// The header may be archived already, fall back to the recent history.
+				header = Self::accepted_header(step_output.finalized_slot)
+					.map(|accepted| accepted.header_root)
+					.unwrap_or_default();
+			}
+			!header.is_zero() && header != step_output.finalized_header_root
+		}
+
+		/// Returns whether the step of `inputs` finalizes a header which is of no use anymore:
+		/// the same header is stored already, or it is behind the head and cannot be imported as
+		/// the sync committee of its period is not stored. Rotates, steps moving the head and
+		/// steps conflicting with a stored header are always verified.
+		fn is_stale_step(inputs: &FunctionInputs, config: &Configuration) -> bool {
+			let slot = inputs.finality_update.finalized_header.slot.as_u64();
+			if slot == inputs.store.finalized_header.slot.as_u64() || slot > Head::<T>::get() {
+				return false;
+			}
+
+			let mut header = Headers::<T>::get(slot);
+			if header.is_zero() {
+				header = Self::accepted_header(slot)
+					.map(|accepted| accepted.header_root)
+					.unwrap_or_default();
+			}
+			if header.is_zero() {
+				return slot
+					.checked_div(config.slots_per_period)
+					.map_or(true, |period| {
+						SyncCommitteeHashes::<T>::get(period).is_zero()
+					});
+			}
+
+			let mut finalized_header = inputs.finality_update.finalized_header.clone();
+			finalized_header
+				.hash_tree_root()
+				.is_ok_and(|root| H256::from_slice(root.as_ref()) == header)
+		}
+
+		/// Returns the newest consensus fork active at `slot`.
+		pub fn consensus_fork(slot: u64) -> ConsensusFork {
+			let epoch = slot / beacon::SLOTS_PER_EPOCH;
+			ConsensusFork::ALL
+				.into_iter()
+				.rev()
+				.find(|fork| ForkEpochs::<T>::get(fork).map_or(true, |start| start <= epoch))
+				.unwrap_or(ConsensusFork::Bellatrix)
+		}
+
+		fn accepted_header(slot: u64) -> Option<AcceptedHeader> {
+			AcceptedHeaders::<T>::get()
+				.into_iter()
+				.find(|accepted| accepted.slot == slot)
+		}
+
+		/// Adds the header of a stored step to the recent history, dropping the oldest one
+		/// if the history is full.
+		fn note_accepted_header(updater: Option<H256>, step_output: &VerifiedStepOutput) {
+			AcceptedHeaders::<T>::mutate(|accepted| {
+				if accepted.is_full() {
+					accepted.remove(0);
+				}
+				let _ = accepted.try_push(AcceptedHeader {
+					slot: step_output.finalized_slot,
+					header_root: step_output.finalized_header_root,
+					updater,
+				});
+			});
+		}
+
+		/// Reports `updater` if its step conflicts with a header root in the recent history. Steps
+		/// included by the block author have no updater to report.
+		///
+		/// An updater whose offence cannot be reported, as it is not staked, is removed from the
+		/// allowlist so that it cannot equivocate again.
+		fn note_equivocation(updater: Option<H256>, step_output: &VerifiedStepOutput) {
+			let Some(updater) = updater else {
+				return;
+			};
+			let Some(accepted) = Self::accepted_header(step_output.finalized_slot) else {
+				return;
+			};
+			if accepted.header_root == step_output.finalized_header_root {
+				return;
+			}
+
+			let reported = T::EquivocationReporter::report(updater, step_output.finalized_slot);
+			Self::deposit_event(Event::<T>::UpdaterEquivocated {
+				updater,
+				slot: step_output.finalized_slot,
+				accepted_root: accepted.header_root,
+				conflicting_root: step_output.finalized_header_root,
+				reported,
+			});
+			if !reported && Updaters::<T>::take(updater).is_some() {
+				Self::deposit_event(Event::<T>::UpdaterRemoved { updater });
+			}
+		}
+
+		/// Tracks consecutive updates with participation just above the finality threshold
+		/// and degrades the bridge once there are too many of them.
+		fn note_participation(slot: u64, participation: u16, config: &Configuration) {
+			let low = config
+				.finality_threshold
+				.saturating_add(T::LowParticipationMargin::get());
+			if let Some(period) = slot.checked_div(config.slots_per_period) {
+				ParticipationHistory::<T>::mutate(period, |history| {
+					history
+						.get_or_insert_with(Default::default)
+						.note(participation, participation < low)
+				});
+				if let Some(expired) = period.checked_sub(MAX_PARTICIPATION_PERIODS) {
+					ParticipationHistory::<T>::remove(expired);
+				}
+			}
+
+			if participation >= low {
+				LowParticipationStreak::<T>::kill();
+				return;
+			}
+
+			let updates = LowParticipationStreak::<T>::mutate(|streak| {
+				*streak = streak.saturating_add(1);
+				*streak
+			});
+			if updates >= T::MaxLowParticipationUpdates::get() {
+				Self::degrade(DegradedReason::LowParticipation { updates });
+			}
+		}
+
+		/// Moves the bridge into the degraded status, if it is not degraded already.
+		fn degrade(reason: DegradedReason) {
+			if Status::<T>::get() == BridgeStatus::Degraded {
+				return;
+			}
+			Status::<T>::set(BridgeStatus::Degraded);
+			Self::deposit_event(Event::<T>::BridgeDegraded { reason });
+		}
+
+		/// Returns true if the inputs with `input_hash` were verified and did not expire yet.
+		pub(crate) fn is_input_verified(input_hash: H256) -> bool {
+			let now = frame_system::Pallet::<T>::block_number();
+			VerifiedInputs::<T>::get()
+				.iter()
+				.any(|(hash, expires_at)| *hash == input_hash && *expires_at > now)
+		}
+
+		/// Decodes the inputs of `fulfill_call`, completing compact inputs with the light client
+		/// state of the head.
+		fn decode_inputs(inputs: &[u8]) -> Result<FunctionInputs, DispatchError> {
+			if let Ok(function_inputs) = serde_cbor::from_slice::<FunctionInputs>(inputs) {
+				return Ok(function_inputs);
+			}
+			Self::decode_compact_inputs(inputs)
+		}
+
+		/// Decodes `CompactFunctionInputs`, completed with the light client state of the head.
+		fn decode_compact_inputs(inputs: &[u8]) -> Result<FunctionInputs, DispatchError> {
+			let compact: CompactFunctionInputs =
+				serde_cbor::from_slice(inputs).map_err(|_| Error::<T>::InvalidInputs)?;
+
+			let LightClientContext {
+				store,
+				genesis_root,
+				forks,
+			} = LightClientState::<T>::get()
+				.and_then(|state| serde_cbor::from_slice(&state).ok())
+				.ok_or(Error::<T>::LightClientStateNotSet)?;
+			let head = store.finalized_header.slot.as_u64();
+			ensure!(head == Head::<T>::get(), Error::<T>::LightClientStateNotSet);
+			ensure!(
+				head.checked_add(compact.slot_delta)
+					== Some(compact.finality_update.finalized_header.slot.as_u64()),
+				Error::<T>::SlotDeltaMismatch
+			);
+
+			Ok(FunctionInputs {
+				updates: compact.updates,
+				finality_update: compact.finality_update,
+				expected_current_slot: compact.expected_current_slot,
+				store,
+				genesis_root,
+				forks,
+				execution_state_proof: compact.execution_state_proof,
+			})
+		}
+
+		/// Keeps the light client state of the head for the next compact inputs. It is dropped
+		/// if it does not fit in `LightClientState`, compact inputs are then rejected.
+		fn note_light_client_state(
+			store: &LightClientStore,
+			genesis_root: &Bytes32,
+			forks: &Forks,
+		) {
+			let context = LightClientContext {
+				store: store.clone(),
+				genesis_root: genesis_root.clone(),
+				forks: forks.clone(),
+			};
+			let state = serde_cbor::to_vec(&context)
+				.ok()
+				.and_then(|state| BoundedVec::try_from(state).ok());
+			LightClientState::<T>::set(state);
+		}
+
+		/// Caches the hash of verified inputs, dropping expired entries and, if the cache is
+		/// still full, the oldest one.
+		fn note_verified_input(input_hash: H256) {
+			let now = frame_system::Pallet::<T>::block_number();
+			let expires_at = now.saturating_add(T::VerifiedInputsExpiry::get().into());
+			VerifiedInputs::<T>::mutate(|verified| {
+				verified.retain(|(_, expires_at)| *expires_at > now);
+				if verified.is_full() {
+					verified.remove(0);
+				}
+				// cannot fail, an entry was removed above if the cache was full
+				let _ = verified.try_push((input_hash, expires_at));
+			});
+		}
+
+		/// Indexes the accepted `inputs` in the offchain storage until `ProofRetention` blocks
+		/// from now, if it is set. The inputs are not kept if too many already expire at the same
+		/// block.
+		fn note_proof_artifact(input_hash: H256, function_id: H256, inputs: Vec<u8>) {
+			let Some(retention) = ProofRetention::<T>::get() else {
+				return;
+			};
+			let now = frame_system::Pallet::<T>::block_number();
+			let expires_at = now.saturating_add(retention);
+			if ProofArtifactExpiries::<T>::try_append(expires_at, input_hash).is_err() {
+				log::warn!(target: LOG_TARGET, "Proof artifact {input_hash:?} is not kept");
+				return;
+			}
+			let artifact = ProofArtifact {
+				block_number: now.saturated_into(),
+				function_id,
+				inputs,
+			};
+			let key = artifact::proof_artifact_key(input_hash);
+			sp_io::offchain_index::set(&key, &artifact.encode());
+		}
+
+		/// Removes the proof artifacts expiring at block `n` from the offchain storage.
+		fn expire_proof_artifacts(n: BlockNumberFor<T>) {
+			for input_hash in ProofArtifactExpiries::<T>::take(n) {
+				sp_io::offchain_index::clear(&artifact::proof_artifact_key(input_hash));
+			}
+		}
+
+		/// Replaces the function ids with the scheduled ones once block `n` reaches their
+		/// activation block.
+		fn activate_function_ids(n: BlockNumberFor<T>) {
+			let Some((activation_block, value)) = ScheduledFunctionIds::<T>::get() else {
+				return;
+			};
+			if n < activation_block {
+				return;
+			}
+
+			ScheduledFunctionIds::<T>::kill();
+			FunctionIds::<T>::put(Some(value));
+			Self::deposit_event(Event::FunctionIdsActivated { value });
+		}
+
+		/// Archives the slots of the oldest epoch whose slots are all outside of the retention
+		/// window, at most `MAX_ARCHIVED_SLOTS_PER_BLOCK` of them and as many as `limit` allows.
+		/// The archived slots are kept in `ArchivedSlots` until the last slots of the epoch are
+		/// archived, which stores the root of the epoch.
+		pub(crate) fn archive_next_epoch(limit: Weight) -> Weight {
+			let idle = T::DbWeight::get().reads(2);
+			if !limit.all_gte(idle.saturating_add(T::WeightInfo::archive_epoch(1))) {
+				return Weight::zero();
+			}
+			let Some(epoch) = NextArchiveEpoch::<T>::get() else {
+				return idle;
+			};
+			let epoch_end = epoch
+				.saturating_add(1)
+				.saturating_mul(T::ArchiveEpochSlots::get());
+			if epoch_end.saturating_add(T::SlotRetention::get()) > Head::<T>::get() {
+				return idle;
+			}
+
+			let mut count = MAX_ARCHIVED_SLOTS_PER_BLOCK;
+			while count > 1 && !limit.all_gte(T::WeightInfo::archive_epoch(count)) {
+				count /= 2;
+			}
+			let mut slots = EpochSlots::<T>::take(epoch).into_inner();
+			let remaining = slots.split_off(slots.len().min(count as usize));
+			for slot in slots.iter() {
+				let header_root = Headers::<T>::take(slot);
+				let execution_state_root = ExecutionStateRoots::<T>::take(slot);
+				Timestamps::<T>::remove(slot);
+				HeaderTimestamps::<T>::remove(slot);
+				let participation = SlotParticipation::<T>::take(slot).unwrap_or_default();
+				ExecutionBlockNumbers::<T>::remove(slot);
+				// bounded by the slots of the epoch
+				let _ = ArchivedSlots::<T>::try_append((
+					*slot,
+					header_root,
+					execution_state_root,
+					participation,
+				));
+			}
+			let weight = T::WeightInfo::archive_epoch(slots.len() as u32);
+			if !remaining.is_empty() {
+				EpochSlots::<T>::insert(epoch, EpochSlotList::truncate_from(remaining));
+				return weight;
+			}
+
+			let leaves: Vec<Vec<u8>> = ArchivedSlots::<T>::take()
+				.iter()
+				.map(|(slot, header_root, execution_state_root, participation)| {
+					archive::slot_leaf(*slot, *header_root, *execution_state_root, *participation)
+				})
+				.collect();
+			if !leaves.is_empty() {
+				let archived = archive::archive_leaves(&leaves);
+				ArchivedEpochs::<T>::insert(epoch, archived);
+				Self::deposit_event(Event::<T>::EpochArchived {
+					epoch,
+					root: archived.root,
+					slots: archived.leaves,
+				});
+			}
+			NextArchiveEpoch::<T>::set(Some(epoch.saturating_add(1)));
+
+			weight
+		}
+
+		fn do_send_message(
+			who: T::AccountId,
+			message: Message,
+			to: H256,
+			domain: u32,
+		) -> DispatchResultWithPostInfo {
+			// Ensure the domain is currently supported
+			ensure!(
+				Self::is_domain_valid(domain),
+				Error::<T>::DomainNotSupported
+			);
+			Self::ensure_message_length(&message, to)?;
+			// Check MessageType and enforce the rules
+			let message_type = message.r#type();
+			match &message {
+				Message::FungibleToken { asset_id, amount } => {
+					ensure!(
+						SUPPORTED_ASSET_ID == *asset_id,
+						Error::<T>::AssetNotSupported
+					);
+					ensure!(
+						amount.saturated_into::<u128>() > 0,
+						Error::<T>::InvalidBridgeInputs
+					);
+					T::Currency::transfer(
+						&who,
+						&Self::account_id(),
+						(*amount).saturated_into(),
+						ExistenceRequirement::KeepAlive,
+					)?;
+				},
+				Message::ArbitraryMessage(data) => {
+					ensure!(!data.is_empty(), Error::<T>::InvalidBridgeInputs)
+				},
+			};
+
+			let message_id = Self::fetch_curr_message_id().map_err(|e| e)?;
+			let from = H256::decode(&mut &who.encode()[..])
+				.map_err(|_| Error::<T>::InvalidBridgeInputs)?;
+			Self::queue_outbound_message(&AddressedMessage {
+				message,
+				from,
+				to,
+				origin_domain: T::AvailDomain::get(),
+				destination_domain: domain,
+				id: message_id,
+			})?;
+
+			Self::deposit_event(Event::MessageSubmitted {
+				from: who,
+				to,
+				message_type,
+				destination_domain: domain,
+				message_id,
+			});
+
+			Ok(().into())
+		}
+
+		fn fetch_curr_message_id() -> Result<u64, DispatchError> {
+			let number = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+			let tx_index_option = <frame_system::Pallet<T>>::extrinsic_index();
+
+			match tx_index_option {
+				Some(tx_index) => Ok(tx_uid(number, tx_index)),
+				None => Err(Error::<T>::CurrentMessageIdNotFound.into()),
+			}
+		}
+
+		fn check_preconditions(
+			message: &AddressedMessage,
+			message_root: H256,
+		) -> Result<(), DispatchError> {
+			let message_status = MessageStatus::<T>::get(message_root);
+			// Message must not be executed
+			ensure!(
+				message_status == MessageStatusEnum::NotExecuted,
+				Error::<T>::MessageAlreadyExecuted
+			);
+			ensure!(
+				!VoidedMessages::<T>::contains_key(message_root),
+				Error::<T>::MessageVoided
+			);
+
+			ensure!(
+				Self::is_destination_valid(message.destination_domain),
 				Error::<T>::WrongDestinationChain
 			);
 
+			let domain = Self::source_domain(message);
+			ensure!(
+				WhitelistedDomains::<T>::get().contains(&domain),
+				Error::<T>::UnsupportedOriginChain
+			);
+
+			let source_chain = Broadcasters::<T>::get(domain);
+			ensure!(
+				source_chain != H256::zero(),
+				Error::<T>::BroadcasterSourceChainNotSet
+			);
+
+			Ok(())
+		}
+
+		/// The account ID of the bridge's pot.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// Timestamp of the header of `slot` on the source chain, in seconds.
+		fn slot_timestamp(slot: u64) -> u64 {
+			Finality::<T>::get().slot_timestamp(slot)
+		}
+
+		/// Ensures that the source chain can finalize `finalized_slot`, from a header attested at
+		/// `attested_slot` if it is known.
+		fn ensure_finalizable(attested_slot: Option<u64>, finalized_slot: u64) -> DispatchResult {
+			Finality::<T>::get()
+				.check_finalized_slot(attested_slot, finalized_slot)
+				.map_err(|e| {
+					match e {
+						FinalityError::NotEpochBoundary => Error::<T>::SlotNotEpochBoundary,
+						FinalityError::NotAttestedSlot => Error::<T>::FinalizedSlotNotAttested,
+					}
+					.into()
+				})
+		}
+
+		/// Moves the slot timing of runtimes which only followed beacon chains into `Finality`.
+		fn migrate_finality_config() -> Weight {
+			let genesis_time = finality::v0::GenesisTimestamp::<T>::take();
+			let seconds_per_slot = finality::v0::SecondsPerSlot::<T>::take();
+			if let (Some(genesis_time), Some(seconds_per_slot)) = (genesis_time, seconds_per_slot) {
+				Finality::<T>::put(FinalityConfig::Slot {
+					genesis_time,
+					seconds_per_slot,
+				});
+				log::info!(target: LOG_TARGET, "Migrated the slot timing to the finality config");
+			}
+			T::DbWeight::get().reads_writes(2, 3)
+		}
+
+		/// Moves the updater of runtimes before the allowlist into `Updaters`, without a quota.
+		fn migrate_updater() -> Weight {
+			let Some(updater) = Updater::<T>::take() else {
+				return T::DbWeight::get().reads(1);
+			};
+			if !Updaters::<T>::contains_key(updater) {
+				Self::do_add_updater(updater, None);
+			}
+			log::info!(target: LOG_TARGET, "Migrated the updater to the allowlist");
+			T::DbWeight::get().reads_writes(3, 3)
+		}
+
+		/// Records the hash of the runtime code, which the destination handlers are bound to.
+		pub(crate) fn note_runtime_code() -> Weight {
+			if let Some(code) = sp_io::storage::get(sp_core::storage::well_known_keys::CODE) {
+				RuntimeCodeHash::<T>::put(H256(blake2_256(&code)));
+			}
+			T::DbWeight::get().reads_writes(1, 1)
+		}
+
+		/// Seconds elapsed since the timestamp of the head on the source chain, `None` if the
+		/// timestamp of the head is not known.
+		///
+		/// Contracts and dApps use it to check the freshness of the bridge.
+		pub fn head_age_seconds() -> Option<u64> {
+			let timestamp = HeaderTimestamps::<T>::get(Head::<T>::get())?;
+			Some(T::TimeProvider::now().as_secs().saturating_sub(timestamp))
+		}
+
+		/// Checks the invariants of the bridge state at the end of a block, returning the first
+		/// violated one:
+		/// - the roots of the head are stored, unless its epoch is archived,
+		/// - no accepted header is ahead of the head,
+		/// - the outbound root is committed over the messages sent in the block.
+		pub fn check_invariants() -> Result<(), &'static str> {
+			let head = Head::<T>::get();
+			let epoch = archive::epoch_of(head, T::ArchiveEpochSlots::get());
+			if head != 0 && !ArchivedEpochs::<T>::contains_key(epoch) {
+				ensure!(
+					!Headers::<T>::get(head).is_zero(),
+					"Header root of the head is not stored"
+				);
+				ensure!(
+					!ExecutionStateRoots::<T>::get(head).is_zero(),
+					"Execution state root of the head is not stored"
+				);
+			}
+
+			ensure!(
+				AcceptedHeaders::<T>::get()
+					.iter()
+					.all(|accepted| accepted.slot <= head),
+				"Accepted header is ahead of the head"
+			);
+
+			let message_roots = OutboundMessages::<T>::get();
+			if !message_roots.is_empty() {
+				ensure!(
+					OutboundMessagesRoot::<T>::get()
+						== Some(outbound::outbound_root(&message_roots)),
+					"Outbound root does not commit to the sent messages"
+				);
+			}
+			Ok(())
+		}
+
+		/// Message ids of each source broadcaster which are not executed although later ones
+		/// are, within `NONCE_WINDOW` of the highest executed id. Persistent gaps point at
+		/// messages omitted by the relayers.
+		pub fn missing_nonces() -> Vec<MissingNonces> {
+			NonceWindows::<T>::iter()
+				.filter_map(|(domain, broadcaster, window)| {
+					let nonces = window.missing();
+					(!nonces.is_empty()).then_some(MissingNonces {
+						domain,
+						broadcaster,
+						nonces,
+					})
+				})
+				.collect()
+		}
+
+		/// Participation of the periods kept in `ParticipationHistory`, from the period of the
+		/// head back to `periods` periods before it, in increasing order. Periods without
+		/// accepted step outputs are left out.
+		pub fn participation_history(periods: u32) -> Vec<(u64, PeriodParticipation)> {
+			let Some(current) = Self::current_period() else {
+				return Vec::new();
+			};
+			let periods = u64::from(periods).min(MAX_PARTICIPATION_PERIODS);
+			let first = current.saturating_add(1).saturating_sub(periods);
+			(first..=current)
+				.filter_map(|period| {
+					ParticipationHistory::<T>::get(period).map(|history| (period, history))
+				})
+				.collect()
+		}
+
+		/// Share of the step outputs accepted over the last `periods` periods without low
+		/// participation, `None` if there is none.
+		pub fn liveness_score(periods: u32) -> Option<Perbill> {
+			let mut total = PeriodParticipation::default();
+			for (_, history) in Self::participation_history(periods) {
+				total.updates = total.updates.saturating_add(history.updates);
+				total.low_updates = total.low_updates.saturating_add(history.low_updates);
+			}
+			total.liveness()
+		}
+
+		/// Sync committee period of the head, `None` if the configuration is not set.
+		pub fn current_period() -> Option<u64> {
+			Head::<T>::get().checked_div(ConfigurationStorage::<T>::get().slots_per_period)
+		}
+
+		/// First slot of `period`, `None` if the configuration is not set.
+		pub fn period_start_slot(period: u64) -> Option<u64> {
+			let slots_per_period = ConfigurationStorage::<T>::get().slots_per_period;
+			if slots_per_period == 0 {
+				return None;
+			}
+			period.checked_mul(slots_per_period)
+		}
+
+		/// Slot by which the sync committee of the next period must be rotated in, which is the
+		/// first slot of that period. `None` if it is already stored or the configuration is
+		/// not set.
+		pub fn rotate_due_by_slot() -> Option<u64> {
+			let next_period = Self::current_period()?.checked_add(1)?;
+			if !SyncCommitteeHashes::<T>::get(next_period).is_zero() {
+				return None;
+			}
+			Self::period_start_slot(next_period)
+		}
+
+		/// Dry-runs `execute` of `addr_message` against the execution state root of `slot`,
+		/// verifying the proofs and delivering the message, then reverts every change.
+		///
+		/// The origin checks of `execute` are skipped, so the outcome is the one of a relayer
+		/// allowed to execute the message.
+		pub fn simulate_execute(
+			slot: u64,
+			addr_message: AddressedMessage,
+			account_proof: Vec<Vec<u8>>,
+			storage_proof: Vec<Vec<u8>>,
+		) -> ExecutionSimulation {
+			let message_root = Self::message_root(&addr_message);
+			let channel = (Self::source_domain(&addr_message), addr_message.from);
+			let message_id = addr_message.id;
+
+			let outcome = with_transaction(|| {
+				let outcome =
+					Self::simulate_do_execute(slot, addr_message, account_proof, storage_proof)
+						.map(|_| {
+							(
+								MessageStatus::<T>::get(message_root),
+								QueuedMessages::<T>::contains_key(channel, message_id),
+							)
+						});
+				TransactionOutcome::Rollback(outcome)
+			});
+
+			match outcome {
+				Ok((status, queued)) => ExecutionSimulation {
+					message_root,
+					status,
+					queued,
+					error: None,
+				},
+				Err(error) => ExecutionSimulation {
+					message_root,
+					status: MessageStatus::<T>::get(message_root),
+					queued: false,
+					error: Some(error),
+				},
+			}
+		}
+
+		/// Runs the verification stages of `fulfill_call` on `inputs` without changing the state
+		/// and reports the outcome of each of them, so that an updater can find why its
+		/// submission is rejected. The stages are the ones of `fulfill_call`, up to how the
+		/// output is applied against the head, and the stages after a failed one are not run.
+		pub fn trace_verification(function_id: H256, inputs: Vec<u8>) -> VerificationTrace {
+			let input_hash = H256(keccak_256(inputs.as_slice()));
+			let function = FunctionIds::<T>::get().and_then(|(step, rotate)| {
+				if function_id == step {
+					Some(VerifiedFunction::Step)
+				} else if function_id == rotate {
+					Some(VerifiedFunction::Rotate)
+				} else {
+					None
+				}
+			});
+			let mut trace = VerificationTrace {
+				input_hash,
+				already_verified: Self::is_input_verified(input_hash),
+				function,
+				decode: StageResult::Skipped,
+				stale: false,
+				updates: Vec::new(),
+				finality_update: StageResult::Skipped,
+				execution_state_root: StageResult::Skipped,
+				output: None,
+				acceptance: None,
+			};
+
+			let function_inputs = Self::decode_inputs(&inputs);
+			trace.decode = match &function_inputs {
+				Ok(_) => StageResult::Passed,
+				Err(e)
+					if *e == Error::<T>::LightClientStateNotSet.into()
+						|| *e == Error::<T>::SlotDeltaMismatch.into() =>
+				{
+					StageResult::Unavailable
+				},
+				Err(_) => StageResult::Failed,
+			};
+			log::debug!(target: LOG_TARGET, "Trace of {input_hash:?}, decode: {:?}", trace.decode);
+			let Ok(function_inputs) = function_inputs else {
+				return trace;
+			};
+			let config = ConfigurationStorage::<T>::get();
+			trace.stale = Self::is_stale_step(&function_inputs, &config);
+
+			let prev_head = function_inputs.store.finalized_header.slot.as_u64();
+			let mut verification = Self::verify_light_client(function_inputs);
+			trace.updates = verification.updates;
+			trace.finality_update = verification.finality_update;
+			trace.execution_state_root = verification.execution_state_root;
+			trace.output = verification.output;
+			trace.acceptance = verification.output.map(|output| {
+				Self::step_acceptance(prev_head, &output, &mut verification.store, &config)
+			});
+			log::debug!(target: LOG_TARGET, "Trace of {input_hash:?}: {trace:?}");
+
+			trace
+		}
+
+		fn simulate_do_execute(
+			slot: u64,
+			addr_message: AddressedMessage,
+			account_proof: Vec<Vec<u8>>,
+			storage_proof: Vec<Vec<u8>>,
+		) -> DispatchResult {
+			Self::ensure_message_length(&addr_message.message, addr_message.to)?;
+			Self::ensure_participation(slot, &addr_message)?;
+			Self::ensure_fork_activated(slot, &addr_message)?;
+			let account_proof = Self::bounded_proof(account_proof)?;
+			let storage_proof = Self::bounded_proof(storage_proof)?;
+			let root = ExecutionStateRoots::<T>::get(slot);
+			ensure!(!root.is_zero(), Error::<T>::ExecutionStateRootNotSet);
+
+			Self::do_execute(root, addr_message, account_proof, storage_proof, None, 0)
+				.map(|_| ())
+				.map_err(|e| e.error)
+		}
+
+		fn bounded_proof(proof: Vec<Vec<u8>>) -> Result<ValidProof<T>, DispatchError> {
+			let nodes = proof
+				.into_iter()
+				.map(BoundedVec::try_from)
+				.collect::<Result<Vec<_>, _>>()
+				.map_err(|_| Error::<T>::ProofTooLarge)?;
+			Ok(BoundedVec::try_from(nodes).map_err(|_| Error::<T>::ProofTooLarge)?)
+		}
+
+		/// Cheap structural checks of a bridge call, run by the transaction pool before the
+		/// signed extensions so that relayer calls which cannot succeed never enter the pool.
+		///
+		/// No proof is verified here. Calls referring to a slot which is not finalized yet are
+		/// `Future`, calls referring to an executed message or a pruned slot are `Stale`. Steps
+		/// moving the head are tagged so that the pool only keeps the newest of them.
+		pub fn pre_validate(call: &Call<T>) -> TransactionValidity {
+			match call {
+				Call::fulfill_call {
+					function_id,
+					inputs,
+				} => Self::pre_validate_step(*function_id, inputs),
+				call => Self::pre_validate_relay(call).map(|()| ValidTransaction::default()),
+			}
+		}
+
+		/// Rejects steps which `fulfill_call` would reject before verifying their proofs, see
+		/// `is_stale_step`. A step moving the head provides the tag of its function id, with its
+		/// finalized slot as priority, so that a newer step replaces it in the pool.
+		fn pre_validate_step(function_id: H256, inputs: &[u8]) -> TransactionValidity {
+			let inputs = Self::decode_inputs(inputs).map_err(|_| InvalidTransaction::Call)?;
 			ensure!(
-				WhitelistedDomains::<T>::get().contains(&message.origin_domain),
-				Error::<T>::UnsupportedOriginChain
+				!Self::is_stale_step(&inputs, &ConfigurationStorage::<T>::get()),
+				InvalidTransaction::Stale
 			);
 
-			let source_chain = Broadcasters::<T>::get(message.origin_domain);
+			let slot = inputs.finality_update.finalized_header.slot.as_u64();
+			if slot == inputs.store.finalized_header.slot.as_u64() || slot <= Head::<T>::get() {
+				return Ok(ValidTransaction::default());
+			}
+			ValidTransaction::with_tag_prefix("VectorStep")
+				.priority(slot)
+				.and_provides(function_id)
+				.build()
+		}
+
+		fn pre_validate_relay(call: &Call<T>) -> Result<(), TransactionValidityError> {
+			match call {
+				Call::execute {
+					slot, addr_message, ..
+				}
+				| Call::execute_compressed {
+					slot, addr_message, ..
+				} => {
+					Self::pre_validate_message(addr_message)?;
+					Self::pre_validate_slot(*slot, ExecutionStateRoots::<T>::get(slot))
+				},
+				Call::execute_messages { slot, messages, .. } => {
+					ensure!(!messages.is_empty(), InvalidTransaction::Call);
+					for addr_message in messages.iter() {
+						Self::pre_validate_message(addr_message)?;
+					}
+					Self::pre_validate_slot(*slot, ExecutionStateRoots::<T>::get(slot))
+				},
+				Call::execute_with_permit {
+					slot,
+					addr_message,
+					permit,
+					..
+				} => {
+					Self::pre_validate_message(addr_message)?;
+					ensure!(
+						!UsedPermits::<T>::contains_key(permit.message_root),
+						InvalidTransaction::Stale
+					);
+					Self::pre_validate_slot(*slot, ExecutionStateRoots::<T>::get(slot))
+				},
+				Call::execute_beacon_anchored {
+					slot, addr_message, ..
+				} => {
+					Self::pre_validate_message(addr_message)?;
+					Self::pre_validate_slot(*slot, ExecutionStateRoots::<T>::get(slot))
+				},
+				Call::execute_rollup_anchored {
+					slot, addr_message, ..
+				} => {
+					Self::pre_validate_message(addr_message)?;
+					ensure!(
+						RollupConfigs::<T>::contains_key(Self::source_domain(addr_message)),
+						InvalidTransaction::Call
+					);
+					Self::pre_validate_slot(*slot, ExecutionStateRoots::<T>::get(slot))
+				},
+				Call::execute_archived {
+					slot, addr_message, ..
+				} => {
+					Self::pre_validate_message(addr_message)?;
+					let epoch = archive::epoch_of(*slot, T::ArchiveEpochSlots::get());
+					ensure!(
+						ArchivedEpochs::<T>::contains_key(epoch),
+						InvalidTransaction::Future
+					);
+					Ok(())
+				},
+				Call::prove_message_absence { slot, domain, .. } => {
+					ensure!(
+						Self::is_domain_valid(*domain) && !SourceChainFrozen::<T>::get(domain),
+						InvalidTransaction::Call
+					);
+					Self::pre_validate_slot(*slot, ExecutionStateRoots::<T>::get(slot))
+				},
+				Call::skip_ordered_message {
+					slot,
+					channel_from,
+					addr_message,
+					..
+				} => {
+					let next_id =
+						OrderedChannels::<T>::get(Self::source_domain(addr_message), channel_from)
+							.ok_or(InvalidTransaction::Call)?;
+					ensure!(addr_message.id >= next_id, InvalidTransaction::Stale);
+					ensure!(addr_message.id == next_id, InvalidTransaction::Future);
+					Self::pre_validate_slot(*slot, ExecutionStateRoots::<T>::get(slot))
+				},
+				_ => Ok(()),
+			}
+		}
+
+		/// Rejects messages which can never be executed, whatever proofs they come with.
+		fn pre_validate_message(
+			addr_message: &AddressedMessage,
+		) -> Result<(), TransactionValidityError> {
+			let domain = Self::source_domain(addr_message);
 			ensure!(
-				source_chain != H256::zero(),
-				Error::<T>::BroadcasterSourceChainNotSet
+				Self::ensure_message_length(&addr_message.message, addr_message.to).is_ok()
+					&& Self::is_destination_valid(addr_message.destination_domain)
+					&& Self::is_domain_valid(domain)
+					&& !SourceChainFrozen::<T>::get(domain),
+				InvalidTransaction::Call
+			);
+			let message_root = Self::message_root(addr_message);
+			ensure!(
+				MessageStatus::<T>::get(message_root) == MessageStatusEnum::NotExecuted
+					&& !VoidedMessages::<T>::contains_key(message_root),
+				InvalidTransaction::Stale
 			);
+			Ok(())
+		}
 
+		/// Rejects proofs against a `root` which is not stored for `slot`, either because the
+		/// slot is not finalized yet or because it was pruned.
+		fn pre_validate_slot(slot: u64, root: H256) -> Result<(), TransactionValidityError> {
+			if root.is_zero() {
+				let error = if slot > Head::<T>::get() {
+					InvalidTransaction::Future
+				} else {
+					InvalidTransaction::Stale
+				};
+				return Err(error.into());
+			}
 			Ok(())
 		}
 
-		/// The account ID of the bridge's pot.
-		pub fn account_id() -> T::AccountId {
-			T::PalletId::get().into_account_truncating()
+		/// Verifies the CBOR encoded rotate `inputs` of a backfilled period. Returns the finalized
+		/// slot, the hash of the sync committee of its period and the hash of the next sync
+		/// committee.
+		fn verify_backfilled_rotation(
+			inputs: &[u8],
+			cfg: &Configuration,
+		) -> Result<(u64, U256, U256), DispatchError> {
+			let function_inputs: FunctionInputs =
+				serde_cbor::from_slice(inputs).map_err(|_| Error::<T>::InvalidInputs)?;
+			let LightClientVerification { mut store, output, .. } =
+				Self::verify_light_client(function_inputs);
+			let output = output.ok_or(Error::<T>::VerificationFailed)?;
+			ensure!(
+				output.participation >= cfg.finality_threshold,
+				Error::<T>::RotateParticipationTooLow
+			);
+
+			let mut next_sync_committee = store
+				.next_sync_committee
+				.ok_or(Error::<T>::SyncCommitteeChainMismatch)?;
+			let hash: [u8; 32] = store
+				.current_sync_committee
+				.hash_tree_root()
+				.map_err(|_| Error::<T>::VerificationFailed)?
+				.as_ref()
+				.try_into()
+				.map_err(|_| Error::<T>::VerificationFailed)?;
+			let next_hash: [u8; 32] = next_sync_committee
+				.hash_tree_root()
+				.map_err(|_| Error::<T>::VerificationFailed)?
+				.as_ref()
+				.try_into()
+				.map_err(|_| Error::<T>::VerificationFailed)?;
+
+			Ok((output.finalized_slot, U256::from(hash), U256::from(next_hash)))
+		}
+
+		/// Ensures the sync committee of `next_period` can be rotated in from the finalized header
+		/// of `slot`, see `state::check_rotation`.
+		fn ensure_rotation(slot: u64, next_period: u64, cfg: &Configuration) -> DispatchResult {
+			let participation = SlotParticipation::<T>::get(slot);
+			check_rotation(cfg, slot, participation, next_period).map_err(|e| {
+				match e {
+					RotationError::ConfigurationNotSet => Error::<T>::ConfigurationNotSet,
+					RotationError::NotNextPeriod => Error::<T>::RotateNotNextPeriod,
+					RotationError::HeaderNotVerified => Error::<T>::RotateHeaderNotVerified,
+					RotationError::ParticipationTooLow => Error::<T>::RotateParticipationTooLow,
+				}
+				.into()
+			})
 		}
 
 		fn rotate_into(
@@ -1027,6 +5659,7 @@ pub mod pallet {
 				.checked_div(cfg.slots_per_period)
 				.ok_or(Error::<T>::ConfigurationNotSet)?;
 			let next_period = period + 1;
+			Self::ensure_rotation(finalized_slot, next_period, cfg)?;
 
 			Self::set_sync_committee_hash(next_period, sync_committee_hash)?;
 
@@ -1052,6 +5685,7 @@ pub mod pallet {
 				result.participation >= cfg.finality_threshold,
 				Error::<T>::NotEnoughParticipants
 			);
+			Self::ensure_finalizable(Some(attested_slot), result.finalized_slot)?;
 
 			let head = Head::<T>::get();
 			ensure!(result.finalized_slot > head, Error::<T>::SlotBehindHead);
@@ -1074,6 +5708,13 @@ pub mod pallet {
 				Error::<T>::StateRootAlreadySet
 			);
 
+			let epoch = archive::epoch_of(step_output.finalized_slot, T::ArchiveEpochSlots::get());
+			EpochSlots::<T>::try_mutate(epoch, |slots| slots.try_push(step_output.finalized_slot))
+				.map_err(|_| Error::<T>::TooManySlotsInEpoch)?;
+			if NextArchiveEpoch::<T>::get().is_none() {
+				NextArchiveEpoch::<T>::set(Some(epoch));
+			}
+
 			Head::<T>::set(step_output.finalized_slot);
 			Headers::<T>::insert(
 				step_output.finalized_slot,
@@ -1085,6 +5726,11 @@ pub mod pallet {
 			);
 
 			Timestamps::<T>::insert(step_output.finalized_slot, T::TimeProvider::now().as_secs());
+			HeaderTimestamps::<T>::insert(
+				step_output.finalized_slot,
+				Self::slot_timestamp(step_output.finalized_slot),
+			);
+			SlotParticipation::<T>::insert(step_output.finalized_slot, step_output.participation);
 
 			Ok(true)
 		}
@@ -1102,6 +5748,51 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Returns whether `committee_hash` is the stored sync committee of the period of `slot`.
+		fn is_stored_committee(slot: u64, committee_hash: U256, config: &Configuration) -> bool {
+			slot.checked_div(config.slots_per_period)
+				.map(SyncCommitteeHashes::<T>::get)
+				.is_some_and(|stored| !stored.is_zero() && stored == committee_hash)
+		}
+
+		/// Imports the roots of a finalized header below the head without moving the head, so
+		/// that messages of older slots can be executed.
+		fn import_historical_header(step_output: VerifiedStepOutput) -> DispatchResult {
+			let slot = step_output.finalized_slot;
+			ensure!(
+				Headers::<T>::get(slot).is_zero(),
+				Error::<T>::HeaderRootAlreadySet
+			);
+			ensure!(
+				ExecutionStateRoots::<T>::get(slot).is_zero(),
+				Error::<T>::StateRootAlreadySet
+			);
+
+			let epoch = archive::epoch_of(slot, T::ArchiveEpochSlots::get());
+			ensure!(
+				!ArchivedEpochs::<T>::contains_key(epoch),
+				Error::<T>::EpochAlreadyArchived
+			);
+			EpochSlots::<T>::try_mutate(epoch, |slots| slots.try_push(slot))
+				.map_err(|_| Error::<T>::TooManySlotsInEpoch)?;
+			if NextArchiveEpoch::<T>::get().map_or(true, |next| epoch < next) {
+				NextArchiveEpoch::<T>::set(Some(epoch));
+			}
+
+			Headers::<T>::insert(slot, step_output.finalized_header_root);
+			ExecutionStateRoots::<T>::insert(slot, step_output.execution_state_root);
+			Timestamps::<T>::insert(slot, T::TimeProvider::now().as_secs());
+			HeaderTimestamps::<T>::insert(slot, Self::slot_timestamp(slot));
+			SlotParticipation::<T>::insert(slot, step_output.participation);
+
+			Self::deposit_event(Event::<T>::HistoricalHeaderImported {
+				slot,
+				finalization_root: step_output.finalized_header_root,
+				execution_state_root: step_output.execution_state_root,
+			});
+			Ok(())
+		}
+
 		/// Sets the sync committee hash for a given period.
 		fn set_sync_committee_hash(period: u64, hash: U256) -> Result<(), DispatchError> {
 			let sync_committee_hashes = SyncCommitteeHashes::<T>::get(period);
@@ -1183,6 +5874,88 @@ pub mod pallet {
 			}
 		}
 
+		/// Moves the state of the source domain `old` to `new` and makes `old` an alias of `new`.
+		fn do_migrate_domain(old: u32, new: u32) -> DispatchResult {
+			ensure!(
+				Self::is_domain_valid(old),
+				Error::<T>::UnsupportedOriginChain
+			);
+			ensure!(
+				old != new
+					&& new != T::AvailDomain::get()
+					&& !Self::is_domain_valid(new)
+					&& !DomainAliases::<T>::contains_key(new),
+				Error::<T>::DomainAlreadyUsed
+			);
+
+			WhitelistedDomains::<T>::mutate(|domains| {
+				for domain in domains.iter_mut().filter(|domain| **domain == old) {
+					*domain = new;
+				}
+			});
+			Broadcasters::<T>::insert(new, Broadcasters::<T>::take(old));
+			SourceAddressVersions::<T>::insert(new, SourceAddressVersions::<T>::take(old));
+			SourceChainFrozen::<T>::insert(new, SourceChainFrozen::<T>::take(old));
+			BeaconRootDomains::<T>::insert(new, BeaconRootDomains::<T>::take(old));
+			for (message_id, slot) in AbsentMessages::<T>::drain_prefix(old) {
+				AbsentMessages::<T>::insert(new, message_id, slot);
+			}
+			for (from, enabled) in AcknowledgedSenders::<T>::drain_prefix(old) {
+				AcknowledgedSenders::<T>::insert(new, from, enabled);
+			}
+			for (from, enabled) in PayoutSenders::<T>::drain_prefix(old) {
+				PayoutSenders::<T>::insert(new, from, enabled);
+			}
+			for (from, next_id) in OrderedChannels::<T>::drain_prefix(old) {
+				OrderedChannels::<T>::insert(new, from, next_id);
+				// queued messages keep their original domain, their roots are already proven
+				for (message_id, message) in QueuedMessages::<T>::drain_prefix((old, from)) {
+					QueuedMessages::<T>::insert((new, from), message_id, message);
+				}
+			}
+
+			// earlier aliases of `old` follow it to `new`
+			let aliases: Vec<u32> = DomainAliases::<T>::iter()
+				.filter_map(|(alias, domain)| (domain == old).then_some(alias))
+				.collect();
+			for alias in aliases {
+				DomainAliases::<T>::insert(alias, new);
+			}
+			DomainAliases::<T>::insert(old, new);
+
+			Ok(())
+		}
+
+		/// Returns the domain whose state applies to `addr_message`, following the alias of a
+		/// migrated origin domain.
+		pub(crate) fn source_domain(addr_message: &AddressedMessage) -> u32 {
+			DomainAliases::<T>::get(addr_message.origin_domain)
+				.unwrap_or(addr_message.origin_domain)
+		}
+
+		/// Returns the broadcaster storing `addr_message`: the broadcaster of its chain id from
+		/// before a fork, if the chain id changed at a fork, or else the broadcaster of `domain`.
+		pub(crate) fn message_broadcaster(addr_message: &AddressedMessage, domain: u32) -> H256 {
+			ForkTransitions::<T>::get(addr_message.origin_domain)
+				.map(|transition| transition.broadcaster)
+				.unwrap_or_else(|| Broadcasters::<T>::get(domain))
+		}
+
+		/// Ensures that the chain id of `addr_message` is in use at `slot`, the chain id taken at
+		/// a fork only being valid from the first slot of the fork.
+		fn ensure_fork_activated(slot: u64, addr_message: &AddressedMessage) -> DispatchResult {
+			if let Some(activation_slot) = ForkActivations::<T>::get(addr_message.origin_domain) {
+				ensure!(slot >= activation_slot, Error::<T>::ForkNotActivated);
+			}
+			Ok(())
+		}
+
+		/// Returns whether messages to `domain` are accepted, either executed on Avail or routed
+		/// to an app-chain.
+		fn is_destination_valid(domain: u32) -> bool {
+			domain == T::AvailDomain::get() || AppChainRoutes::<T>::get(domain)
+		}
+
 		/// Check if the given domain is supported or not
 		fn is_domain_valid(domain: u32) -> bool {
 			WhitelistedDomains::<T>::get().contains(&domain)
@@ -1230,14 +6003,493 @@ where
 
 pub mod weight_helper {
 	use super::*;
+	use crate::archive::ArchiveProof;
+	use crate::evm::EvmExecutor;
+	use crate::handler::DestinationHandler;
+	use crate::hooks::{OnMessageExecuted, OnMessageReceived};
 
 	/// Weight for `dataAvailability::submit_data`.
 	pub fn fulfill_call<T: Config>(function_id: H256) -> (Weight, DispatchClass) {
+		// Light client state read by compact inputs and written for the next ones, the
+		// retention and expiries of the proof artifact, and the participation history.
+		let light_client_state = T::DbWeight::get().reads_writes(4, 4);
 		if let Some((step_function_id, _)) = FunctionIds::<T>::get() {
 			if step_function_id == function_id {
-				return (T::WeightInfo::fulfill_call_step(), DispatchClass::Normal);
+				return (
+					T::WeightInfo::fulfill_call_step().saturating_add(light_client_state),
+					DispatchClass::Normal,
+				);
 			}
 		}
-		(T::WeightInfo::fulfill_call_rotate(), DispatchClass::Normal)
+		(
+			T::WeightInfo::fulfill_call_rotate().saturating_add(light_client_state),
+			DispatchClass::Normal,
+		)
+	}
+
+	/// Weight for `vector::execute`.
+	pub fn execute<T: Config>(
+		addr_message: &AddressedMessage,
+		account_proof: &ValidProof<T>,
+		storage_proof: &ValidProof<T>,
+	) -> Weight {
+		let execute = match addr_message.message {
+			Message::ArbitraryMessage(ref data) => {
+				T::WeightInfo::execute_arbitrary_message(data.len() as u32)
+			},
+			Message::FungibleToken { .. } => T::WeightInfo::execute_fungible_token(),
+		};
+		execute
+			.saturating_add(ordered_channel::<T>(addr_message))
+			.saturating_add(acknowledgement::<T>())
+			.saturating_add(participation_requirement::<T>())
+			.saturating_add(message_length_limit::<T>())
+			.saturating_add(execution_fee::<T>())
+			.saturating_add(release_schedule::<T>())
+			.saturating_add(message_header::<T>())
+			.saturating_add(fork_transition::<T>())
+			.saturating_add(payout_batch::<T>(addr_message))
+			.saturating_add(nonce_window::<T>())
+			.saturating_add(message_hooks::<T>())
+			.saturating_add(destination_handler::<T>())
+			.saturating_add(governance_message::<T>())
+			.saturating_add(evm_call::<T>(addr_message))
+			.saturating_add(verified_account_proof::<T>())
+			.saturating_add(proofs::<T>(account_proof, storage_proof))
+	}
+
+	/// Weight for `vector::execute_compressed`, charged for proofs of the largest tier which
+	/// decompress to the maximum encoded length of a proof.
+	pub fn execute_compressed<T: Config>(addr_message: &AddressedMessage) -> Weight {
+		let proof = max_proof::<T>().saturating_add(decompressed_proof::<T>(
+			ValidProof::<T>::max_encoded_len() as u32,
+		));
+		// The verification of the proofs is charged on top, at the largest tier.
+		let empty = ValidProof::<T>::default();
+		execute::<T>(addr_message, &empty, &empty).saturating_add(proof.saturating_mul(2))
+	}
+
+	/// Additional weight of decompressing an account and a storage proof.
+	pub fn decompressed_proofs<T: Config>(
+		account_proof: &ValidProof<T>,
+		storage_proof: &ValidProof<T>,
+	) -> Weight {
+		decompressed_proof::<T>(account_proof.encoded_size() as u32)
+			.saturating_add(decompressed_proof::<T>(storage_proof.encoded_size() as u32))
+	}
+
+	/// Weight of decompressing a proof of `bytes` decompressed bytes.
+	pub fn decompressed_proof<T: Config>(bytes: u32) -> Weight {
+		T::WeightInfo::decompress_proof(bytes)
+	}
+
+	/// Weight for `vector::execute` of a message of `payload_size` bytes, an arbitrary message
+	/// or, without payload, a fungible token transfer, with proofs of the medium tier. Quoted
+	/// before the message and its proofs are known.
+	pub fn quoted_execute<T: Config>(payload_size: u32) -> Weight {
+		let execute = match payload_size {
+			0 => T::WeightInfo::execute_fungible_token(),
+			size => T::WeightInfo::execute_arbitrary_message(size),
+		};
+		let proof = T::WeightInfo::verify_proof(ProofTier::MEDIUM_NODES, ProofTier::MEDIUM_BYTES);
+		// Domain alias and ordered channel lookups.
+		execute
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(acknowledgement::<T>())
+			.saturating_add(participation_requirement::<T>())
+			.saturating_add(message_length_limit::<T>())
+			.saturating_add(execution_fee::<T>())
+			.saturating_add(release_schedule::<T>())
+			.saturating_add(message_header::<T>())
+			.saturating_add(fork_transition::<T>())
+			.saturating_add(payouts::<T>(0))
+			.saturating_add(nonce_window::<T>())
+			.saturating_add(message_hooks::<T>())
+			.saturating_add(destination_handler::<T>())
+			.saturating_add(governance_message::<T>())
+			.saturating_add(verified_account_proof::<T>())
+			.saturating_add(proof.saturating_mul(2))
+	}
+
+	/// Weight for `vector::execute_beacon_anchored`.
+	pub fn execute_beacon_anchored<T: Config>(addr_message: &AddressedMessage) -> Weight {
+		let execute = match addr_message.message {
+			Message::ArbitraryMessage(ref data) => {
+				T::WeightInfo::execute_arbitrary_message(data.len() as u32)
+			},
+			Message::FungibleToken { .. } => T::WeightInfo::execute_fungible_token(),
+		};
+		// Beacon root domain, finality config and fork epoch lookups, and verification of the
+		// beacon roots contract state.
+		execute
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(beacon::ConsensusFork::ALL.len() as u64))
+			.saturating_add(T::WeightInfo::verify_proof(
+				ProofTier::MEDIUM_NODES,
+				ProofTier::MEDIUM_BYTES,
+			))
+			.saturating_add(ordered_channel::<T>(addr_message))
+			.saturating_add(acknowledgement::<T>())
+			.saturating_add(participation_requirement::<T>())
+			.saturating_add(message_length_limit::<T>())
+			.saturating_add(execution_fee::<T>())
+			.saturating_add(release_schedule::<T>())
+			.saturating_add(message_header::<T>())
+			.saturating_add(fork_transition::<T>())
+			.saturating_add(payout_batch::<T>(addr_message))
+			.saturating_add(nonce_window::<T>())
+			.saturating_add(message_hooks::<T>())
+			.saturating_add(destination_handler::<T>())
+			.saturating_add(governance_message::<T>())
+			.saturating_add(evm_call::<T>(addr_message))
+			.saturating_add(verified_account_proof::<T>())
+	}
+
+	/// Weight for `vector::execute_rollup_anchored`.
+	pub fn execute_rollup_anchored<T: Config>(addr_message: &AddressedMessage) -> Weight {
+		let execute = match addr_message.message {
+			Message::ArbitraryMessage(ref data) => {
+				T::WeightInfo::execute_arbitrary_message(data.len() as u32)
+			},
+			Message::FungibleToken { .. } => T::WeightInfo::execute_fungible_token(),
+		};
+		// Rollup config and header timestamp lookups, and verification of the rollup state.
+		execute
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::WeightInfo::verify_proof(
+				ProofTier::MEDIUM_NODES,
+				ProofTier::MEDIUM_BYTES,
+			))
+			.saturating_add(ordered_channel::<T>(addr_message))
+			.saturating_add(acknowledgement::<T>())
+			.saturating_add(participation_requirement::<T>())
+			.saturating_add(message_length_limit::<T>())
+			.saturating_add(execution_fee::<T>())
+			.saturating_add(release_schedule::<T>())
+			.saturating_add(message_header::<T>())
+			.saturating_add(fork_transition::<T>())
+			.saturating_add(payout_batch::<T>(addr_message))
+			.saturating_add(nonce_window::<T>())
+			.saturating_add(message_hooks::<T>())
+			.saturating_add(destination_handler::<T>())
+			.saturating_add(governance_message::<T>())
+			.saturating_add(evm_call::<T>(addr_message))
+			.saturating_add(verified_account_proof::<T>())
+	}
+
+	/// Weight for `vector::execute_archived`.
+	pub fn execute_archived<T: Config>(
+		addr_message: &AddressedMessage,
+		archive_proof: &ArchiveProof,
+	) -> Weight {
+		let execute = match addr_message.message {
+			Message::ArbitraryMessage(ref data) => {
+				T::WeightInfo::execute_arbitrary_message(data.len() as u32)
+			},
+			Message::FungibleToken { .. } => T::WeightInfo::execute_fungible_token(),
+		};
+		// Archived epoch lookup and verification of the archive proof.
+		execute
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::WeightInfo::verify_archive_proof(
+				archive_proof.siblings.len() as u32,
+			))
+			.saturating_add(ordered_channel::<T>(addr_message))
+			.saturating_add(acknowledgement::<T>())
+			.saturating_add(participation_requirement::<T>())
+			.saturating_add(message_length_limit::<T>())
+			.saturating_add(execution_fee::<T>())
+			.saturating_add(release_schedule::<T>())
+			.saturating_add(message_header::<T>())
+			.saturating_add(fork_transition::<T>())
+			.saturating_add(payout_batch::<T>(addr_message))
+			.saturating_add(nonce_window::<T>())
+			.saturating_add(message_hooks::<T>())
+			.saturating_add(destination_handler::<T>())
+			.saturating_add(governance_message::<T>())
+			.saturating_add(evm_call::<T>(addr_message))
+			.saturating_add(verified_account_proof::<T>())
+	}
+
+	/// Weight for `vector::execute_messages`, the weight of executing each message with its own
+	/// proofs.
+	pub fn execute_messages<T: Config>(messages: &[AddressedMessage]) -> Weight {
+		messages
+			.iter()
+			.fold(Weight::zero(), |weight, addr_message| {
+				let execute = match addr_message.message {
+					Message::ArbitraryMessage(ref data) => {
+						T::WeightInfo::execute_arbitrary_message(data.len() as u32)
+					},
+					Message::FungibleToken { .. } => T::WeightInfo::execute_fungible_token(),
+				};
+				weight
+					.saturating_add(execute)
+					.saturating_add(ordered_channel::<T>(addr_message))
+					.saturating_add(acknowledgement::<T>())
+					.saturating_add(participation_requirement::<T>())
+					.saturating_add(message_length_limit::<T>())
+					.saturating_add(execution_fee::<T>())
+					.saturating_add(release_schedule::<T>())
+					.saturating_add(message_header::<T>())
+					.saturating_add(fork_transition::<T>())
+					.saturating_add(payout_batch::<T>(addr_message))
+					.saturating_add(nonce_window::<T>())
+					.saturating_add(message_hooks::<T>())
+					.saturating_add(destination_handler::<T>())
+					.saturating_add(governance_message::<T>())
+					.saturating_add(evm_call::<T>(addr_message))
+			})
+	}
+
+	/// Weight for `vector::execute_with_permit`.
+	pub fn execute_with_permit<T: Config>(addr_message: &AddressedMessage) -> Weight {
+		let execute = match addr_message.message {
+			Message::ArbitraryMessage(ref data) => {
+				T::WeightInfo::execute_arbitrary_message(data.len() as u32)
+			},
+			Message::FungibleToken { .. } => T::WeightInfo::execute_fungible_token(),
+		};
+		// Used permit lookup and insertion, and tip transfer.
+		execute
+			.saturating_add(T::WeightInfo::verify_execution_permit())
+			.saturating_add(T::DbWeight::get().reads_writes(3, 3))
+			.saturating_add(ordered_channel::<T>(addr_message))
+			.saturating_add(acknowledgement::<T>())
+			.saturating_add(participation_requirement::<T>())
+			.saturating_add(message_length_limit::<T>())
+			.saturating_add(execution_fee::<T>())
+			.saturating_add(release_schedule::<T>())
+			.saturating_add(message_header::<T>())
+			.saturating_add(fork_transition::<T>())
+			.saturating_add(payout_batch::<T>(addr_message))
+			.saturating_add(nonce_window::<T>())
+			.saturating_add(message_hooks::<T>())
+			.saturating_add(destination_handler::<T>())
+			.saturating_add(governance_message::<T>())
+			.saturating_add(evm_call::<T>(addr_message))
+			.saturating_add(verified_account_proof::<T>())
+	}
+
+	/// Additional weight of verifying the approval of a contract signer, read like a base fee
+	/// from the contract storage. ECDSA signatures are covered by `attest_signature`.
+	pub fn signature_proof<T: Config>(proof: &ValidSignatureProof<T>) -> Weight {
+		match proof {
+			SignatureProof::Ecdsa(_) => Weight::zero(),
+			SignatureProof::Contract {
+				account_proof,
+				storage_proof,
+				..
+			} => T::WeightInfo::update_source_base_fee()
+				.saturating_add(proofs::<T>(account_proof, storage_proof)),
+		}
+	}
+
+	/// Additional weight of verifying an account and a storage proof.
+	pub fn proofs<T: Config>(
+		account_proof: &ValidProof<T>,
+		storage_proof: &ValidProof<T>,
+	) -> Weight {
+		proof::<T>(account_proof).saturating_add(proof::<T>(storage_proof))
+	}
+
+	/// Additional weight of verifying `proof`, charged at the upper bound of its tier. Small
+	/// proofs are covered by the benchmarks of the calls verifying them.
+	pub fn proof<T: Config>(proof: &ValidProof<T>) -> Weight {
+		let nodes = proof.len() as u32;
+		let bytes = proof.iter().map(|node| node.len() as u32).sum();
+		match ProofTier::classify(nodes, bytes) {
+			ProofTier::Small => Weight::zero(),
+			ProofTier::Medium => {
+				T::WeightInfo::verify_proof(ProofTier::MEDIUM_NODES, ProofTier::MEDIUM_BYTES)
+			},
+			ProofTier::Large => max_proof::<T>(),
+		}
+	}
+
+	/// Additional weight of verifying a proof of the largest tier.
+	pub fn max_proof<T: Config>() -> Weight {
+		let depth = T::MaxProofDepth::get();
+		T::WeightInfo::verify_proof(depth, depth.saturating_mul(T::MaxProofNodeSize::get()))
+	}
+
+	/// Additional weight of looking up the account of a broadcaster among the accounts proven
+	/// in the block, and of recording it.
+	pub fn verified_account_proof<T: Config>() -> Weight {
+		T::WeightInfo::verified_account_proof()
+	}
+
+	/// Weight of verifying `account_proof`, refunded when its account was already proven in
+	/// the block.
+	pub fn reused_account_proof<T: Config>(account_proof: &ValidProof<T>) -> Weight {
+		let nodes = account_proof.len() as u32;
+		let bytes = account_proof.iter().map(|node| node.len() as u32).sum();
+		T::WeightInfo::verify_proof(nodes, bytes)
+	}
+
+	/// Weight for `vector::skip_ordered_message`.
+	pub fn skip_ordered_message<T: Config>() -> Weight {
+		T::WeightInfo::skip_ordered_message().saturating_add(drain_ordered_channel::<T>())
+	}
+
+	/// Additional weight of executing a message of an ordered channel, which may execute
+	/// the queued messages after it.
+	pub fn ordered_channel<T: Config>(addr_message: &AddressedMessage) -> Weight {
+		// Domain alias and ordered channel lookups.
+		let lookup = T::DbWeight::get().reads(2);
+		let domain = Pallet::<T>::source_domain(addr_message);
+		if OrderedChannels::<T>::contains_key(domain, addr_message.from) {
+			lookup.saturating_add(drain_ordered_channel::<T>())
+		} else {
+			lookup
+		}
+	}
+
+	/// Weight of executing up to `MAX_DRAINED_MESSAGES` queued messages.
+	fn drain_ordered_channel<T: Config>() -> Weight {
+		queued_message::<T>().saturating_mul(MAX_DRAINED_MESSAGES.into())
+	}
+
+	/// Weight of executing a queued message.
+	pub fn queued_message<T: Config>() -> Weight {
+		let execute = T::WeightInfo::execute_fungible_token().max(
+			T::WeightInfo::execute_arbitrary_message(BOUNDED_DATA_MAX_LENGTH),
+		);
+		// Queued message lookup, void lookup and removal.
+		execute
+			.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+			.saturating_add(acknowledgement::<T>())
+			.saturating_add(execution_fee::<T>())
+			.saturating_add(release_schedule::<T>())
+			.saturating_add(message_header::<T>())
+			.saturating_add(fork_transition::<T>())
+			.saturating_add(payouts::<T>(payout::MAX_PAYOUTS))
+			.saturating_add(nonce_window::<T>())
+			.saturating_add(message_hooks::<T>())
+			.saturating_add(destination_handler::<T>())
+			.saturating_add(governance_message::<T>())
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::EvmExecutor::gas_weight(T::EvmGasLimit::get()))
+	}
+
+	/// Number of queued messages which can be executed within `weight`, at most
+	/// `MAX_DRAINED_MESSAGES`.
+	pub fn queued_messages_within<T: Config>(weight: Weight) -> u32 {
+		let message = queued_message::<T>();
+		let by_ref_time = weight.ref_time() / message.ref_time().max(1);
+		let by_proof_size = weight.proof_size() / message.proof_size().max(1);
+		by_ref_time
+			.min(by_proof_size)
+			.min(MAX_DRAINED_MESSAGES.into()) as u32
+	}
+
+	/// Additional weight of looking up whether the sender is acknowledged, storing the failure of
+	/// a failed message and queueing the acknowledgement.
+	pub fn acknowledgement<T: Config>() -> Weight {
+		T::DbWeight::get()
+			.reads_writes(2, 3)
+			.saturating_add(outbound_message::<T>())
+	}
+
+	/// Additional weight of adding a sent message to the outbound root of the block, and of its
+	/// share of the commitment at the end of the block.
+	pub fn outbound_message<T: Config>() -> Weight {
+		let commit = T::WeightInfo::commit_outbound_root(1)
+			.saturating_sub(T::WeightInfo::commit_outbound_root(0));
+		T::DbWeight::get().reads_writes(1, 1).saturating_add(commit)
+	}
+
+	/// Additional weight of looking up the participation required by the recipient and the
+	/// participation of the slot.
+	pub fn participation_requirement<T: Config>() -> Weight {
+		T::DbWeight::get().reads(2)
+	}
+
+	/// Additional weight of looking up the execution fee of the asset and paying it to the
+	/// relayer.
+	pub fn execution_fee<T: Config>() -> Weight {
+		T::DbWeight::get().reads_writes(2, 1)
+	}
+
+	/// Additional weight of looking up the release schedule of the asset and vesting the
+	/// amount.
+	pub fn release_schedule<T: Config>() -> Weight {
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+
+	/// Additional weight of recording the header of the message.
+	pub fn message_header<T: Config>() -> Weight {
+		T::DbWeight::get().writes(1)
+	}
+
+	/// Additional weight of looking up the fork activation of the chain id of the message and
+	/// the broadcaster of its chain id from before a fork.
+	pub fn fork_transition<T: Config>() -> Weight {
+		T::DbWeight::get().reads(2)
+	}
+
+	/// Additional weight of looking up whether the sender sends payout batches and transferring
+	/// the payouts of the message, if it is one.
+	pub fn payout_batch<T: Config>(addr_message: &AddressedMessage) -> Weight {
+		let count = match addr_message.message {
+			Message::ArbitraryMessage(ref data) => payout::payout_count(data).unwrap_or(0),
+			Message::FungibleToken { .. } => 0,
+		};
+		payouts::<T>(count)
+	}
+
+	/// Additional weight of looking up whether the sender sends payout batches and transferring
+	/// `count` payouts.
+	pub fn payouts<T: Config>(count: u32) -> Weight {
+		// Sender lookup, and both accounts of every transfer.
+		let transfers = T::DbWeight::get()
+			.reads_writes(2, 2)
+			.saturating_mul(count.into());
+		T::DbWeight::get().reads(1).saturating_add(transfers)
+	}
+
+	/// Additional weight of looking up the data length limit of the recipient.
+	pub fn message_length_limit<T: Config>() -> Weight {
+		T::DbWeight::get().reads(1)
+	}
+
+	/// Additional weight of calling the EVM contract `addr_message` may be addressed to, at most
+	/// `Config::EvmGasLimit` gas, after looking up whether it is one.
+	pub fn evm_call<T: Config>(addr_message: &AddressedMessage) -> Weight {
+		if evm::evm_address(addr_message.to).is_none() {
+			return Weight::zero();
+		}
+		let call = match addr_message.message {
+			Message::ArbitraryMessage(_) => T::EvmExecutor::gas_weight(T::EvmGasLimit::get()),
+			Message::FungibleToken { .. } => Weight::zero(),
+		};
+		T::DbWeight::get().reads(1).saturating_add(call)
+	}
+
+	/// Additional weight of the hooks of the runtime pallets called with a received and executed
+	/// message.
+	pub fn message_hooks<T: Config>() -> Weight {
+		<T::OnMessageReceived as OnMessageReceived>::weight()
+			.saturating_add(<T::OnMessageExecuted as OnMessageExecuted>::weight())
+	}
+
+	/// Additional weight of looking up whether the sender is the governance of the source chain
+	/// and scheduling the call of its message.
+	pub fn governance_message<T: Config>() -> Weight {
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+
+	/// Additional weight of handing a message to the runtime handler registered for its
+	/// recipient, after looking up the registration and the runtime code hash.
+	pub fn destination_handler<T: Config>() -> Weight {
+		T::DbWeight::get()
+			.reads(2)
+			.saturating_add(<T::DestinationHandlers as DestinationHandler>::max_weight())
+	}
+
+	/// Additional weight of recording the id of the executed message in the nonce window of its
+	/// broadcaster.
+	pub fn nonce_window<T: Config>() -> Weight {
+		T::DbWeight::get().reads_writes(1, 1)
 	}
 }
@@ -1,19 +1,27 @@
-use frame_support::{derive_impl, parameter_types, traits::ConstU64, PalletId};
-use frame_system::{native::hosted_header_builder::da, test_utils::TestRandomness};
+use avail_core::data_proof::{AddressedMessage, BOUNDED_DATA_MAX_LENGTH};
+use frame_support::{derive_impl, parameter_types, traits::ConstU64, weights::Weight, PalletId};
+use frame_system::{native::hosted_header_builder::da, test_utils::TestRandomness, EnsureRoot};
 use hex_literal::hex;
-use primitive_types::H256;
+use primitive_types::{H160, H256};
 use sp_runtime::{
 	traits::{Block as BlockT, IdentityLookup},
-	AccountId32, BuildStorage,
+	AccountId32, BuildStorage, DispatchError, DispatchResult,
 };
 
 use crate as vector_bridge;
+use crate::equivocation::ReportUpdaterEquivocation;
+use crate::evm::EvmExecutor;
+use crate::handler::DestinationHandler;
+use crate::hooks::{OnMessageExecuted, OnMessageReceived};
 
 type Balance = u128;
 type Extrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockDaBlock<Test>;
 type Header = <Block as BlockT>::Header;
 
+pub const TEST_HANDLER_ID: u32 = 7;
+pub const FAILING_HANDLER_ID: u32 = 8;
+
 pub const STEP_FUNCTION_ID: H256 = H256(hex!(
 	"af44af6890508b3b7f6910d4a4570a0d524769a23ce340b2c7400e140ad168ab"
 ));
@@ -210,39 +218,171 @@ impl pallet_timestamp::Config for Test {
 
 parameter_types! {
 	pub const BridgePalletId: PalletId = PalletId(*b"avl/brdg");
+	pub static CurrentSession: u32 = 0;
+	pub static ReportedEquivocations: Vec<(H256, u64)> = vec![];
+	pub static UnstakedUpdaters: Vec<H256> = vec![];
+	pub static MaxMessageLength: u32 = BOUNDED_DATA_MAX_LENGTH;
+	pub static EvmContracts: Vec<H160> = vec![];
+	pub static EvmCalls: Vec<(H160, H160, Vec<u8>, u64)> = vec![];
+	pub static ReceivedMessages: Vec<H256> = vec![];
+	pub static ExecutedMessages: Vec<(H256, bool)> = vec![];
+	pub static HandledMessages: Vec<(u32, H256)> = vec![];
+}
+
+/// EVM holding code at the `EvmContracts` addresses, whose calls are recorded in `EvmCalls`. The
+/// calls with the input `revert` revert.
+pub struct TestEvmExecutor;
+impl EvmExecutor for TestEvmExecutor {
+	fn is_contract(address: H160) -> bool {
+		EvmContracts::get().contains(&address)
+	}
+
+	fn account_of(address: H160) -> H256 {
+		H256::from(address)
+	}
+
+	fn call(
+		source: H160,
+		target: H160,
+		input: Vec<u8>,
+		gas_limit: u64,
+	) -> Result<(), DispatchError> {
+		if input == b"revert" {
+			return Err(DispatchError::Other("Reverted"));
+		}
+		EvmCalls::mutate(|calls| calls.push((source, target, input, gas_limit)));
+		Ok(())
+	}
+
+	fn gas_weight(gas: u64) -> Weight {
+		Weight::from_parts(gas, 0)
+	}
+}
+
+/// Records the roots of the received messages in `ReceivedMessages` and of the executed ones in
+/// `ExecutedMessages`.
+pub struct TestMessageHooks;
+impl OnMessageReceived for TestMessageHooks {
+	fn on_message_received(_addr_message: &AddressedMessage, message_root: H256) {
+		ReceivedMessages::mutate(|received| received.push(message_root));
+	}
+
+	fn weight() -> Weight {
+		Weight::from_parts(1_000, 0)
+	}
+}
+
+impl OnMessageExecuted for TestMessageHooks {
+	fn on_message_executed(_addr_message: &AddressedMessage, message_root: H256, success: bool) {
+		ExecutedMessages::mutate(|executed| executed.push((message_root, success)));
+	}
+
+	fn weight() -> Weight {
+		Weight::from_parts(2_000, 0)
+	}
+}
+
+/// Destination handlers with the ids `TEST_HANDLER_ID` and `FAILING_HANDLER_ID`, recording the
+/// handled messages in `HandledMessages`, the latter failing them.
+pub struct TestDestinationHandlers;
+impl DestinationHandler for TestDestinationHandlers {
+	fn handle(
+		handler: u32,
+		_addr_message: &AddressedMessage,
+		message_root: H256,
+	) -> Option<DispatchResult> {
+		match handler {
+			TEST_HANDLER_ID => {
+				HandledMessages::mutate(|handled| handled.push((handler, message_root)));
+				Some(Ok(()))
+			},
+			FAILING_HANDLER_ID => Some(Err(DispatchError::Other("Handler failed"))),
+			_ => None,
+		}
+	}
+
+	fn max_weight() -> Weight {
+		Weight::from_parts(5_000, 0)
+	}
+}
+
+/// Records the reported updater equivocations in `ReportedEquivocations`, except for the
+/// `UnstakedUpdaters`.
+pub struct TestEquivocationReporter;
+impl ReportUpdaterEquivocation for TestEquivocationReporter {
+	fn report(updater: H256, slot: u64) -> bool {
+		if UnstakedUpdaters::get().contains(&updater) {
+			return false;
+		}
+		ReportedEquivocations::mutate(|reported| reported.push((updater, slot)));
+		true
+	}
 }
 
 #[derive_impl(crate::config_preludes::TestDefaultConfig as crate::DefaultConfig)]
 impl vector_bridge::Config for Test {
 	type TimeProvider = Timestamp;
 	type Currency = Balances;
+	type CurrentSession = CurrentSession;
+	type DegradedOrigin = EnsureRoot<AccountId32>;
+	type EquivocationReporter = TestEquivocationReporter;
+	type MaxMessageLength = MaxMessageLength;
+	type EvmExecutor = TestEvmExecutor;
+	type OnMessageReceived = TestMessageHooks;
+	type OnMessageExecuted = TestMessageHooks;
+	type DestinationHandlers = TestDestinationHandlers;
 }
 
-/// Create new externalities for `Vector` module tests.
-pub fn new_test_ext() -> sp_io::TestExternalities {
-	let mut t = RuntimeGenesisConfig::default()
-		.system
-		.build_storage()
-		.expect("Genesis build should work");
+/// Builder of the externalities of `Vector` module tests, with a configurable finality threshold.
+pub struct ExtBuilder {
+	finality_threshold: u16,
+}
 
-	pallet_balances::GenesisConfig::<Test> {
-		balances: vec![(Bridge::account_id(), 2_000 * 1000000000000000000)],
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			finality_threshold: 461,
+		}
 	}
-	.assimilate_storage(&mut t)
-	.unwrap();
-
-	vector_bridge::GenesisConfig::<Test> {
-		finality_threshold: 461,
-		function_ids: (STEP_FUNCTION_ID, ROTATE_FUNCTION_ID),
-		slots_per_period: 8192,
-		step_verification_key: STEP_VK.as_bytes().to_vec(),
-		rotate_verification_key: ROTATE_VK.as_bytes().to_vec(),
-		whitelisted_domains: vec![2],
-		..Default::default()
+}
+
+impl ExtBuilder {
+	/// Sets the minimum participation of the sync committee for a header to be finalized.
+	pub fn finality_threshold(mut self, finality_threshold: u16) -> Self {
+		self.finality_threshold = finality_threshold;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = RuntimeGenesisConfig::default()
+			.system
+			.build_storage()
+			.expect("Genesis build should work");
+
+		pallet_balances::GenesisConfig::<Test> {
+			balances: vec![(Bridge::account_id(), 2_000 * 1000000000000000000)],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		vector_bridge::GenesisConfig::<Test> {
+			finality_threshold: self.finality_threshold,
+			function_ids: (STEP_FUNCTION_ID, ROTATE_FUNCTION_ID),
+			slots_per_period: 8192,
+			step_verification_key: STEP_VK.as_bytes().to_vec(),
+			rotate_verification_key: ROTATE_VK.as_bytes().to_vec(),
+			whitelisted_domains: vec![2],
+			..Default::default()
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
 	}
-	.assimilate_storage(&mut t)
-	.unwrap();
-	let mut ext = sp_io::TestExternalities::new(t);
-	ext.execute_with(|| System::set_block_number(1));
-	ext
+}
+
+/// Create new externalities for `Vector` module tests.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	ExtBuilder::default().build()
 }
@@ -0,0 +1,106 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_std::vec::Vec;
+
+/// Number of message ids, up to the highest executed one, checked for gaps.
+pub const NONCE_WINDOW: u64 = 128;
+
+/// Message ids of a source broadcaster executed within `NONCE_WINDOW` of the highest one.
+#[derive(Clone, Copy, Default, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct NonceWindow {
+	/// Highest executed message id.
+	pub highest: u64,
+	/// Lowest executed message id, ids before it are not expected to be executed.
+	pub lowest: u64,
+	/// Bit `i` is set if the id `highest - i` is executed.
+	pub executed: u128,
+}
+
+/// Message ids of a source broadcaster within the window which are not executed.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo)]
+pub struct MissingNonces {
+	pub domain: u32,
+	pub broadcaster: H256,
+	/// Missing ids, in increasing order.
+	pub nonces: Vec<u64>,
+}
+
+impl NonceWindow {
+	/// Records the execution of the message with id `nonce`.
+	pub fn note(&mut self, nonce: u64) {
+		if self.executed == 0 {
+			*self = Self {
+				highest: nonce,
+				lowest: nonce,
+				executed: 1,
+			};
+			return;
+		}
+
+		if nonce > self.highest {
+			let shift = nonce - self.highest;
+			self.executed = if shift < NONCE_WINDOW {
+				self.executed << shift
+			} else {
+				0
+			};
+			self.executed |= 1;
+			self.highest = nonce;
+		} else if self.highest - nonce < NONCE_WINDOW {
+			self.executed |= 1 << (self.highest - nonce);
+		}
+		self.lowest = self.lowest.min(nonce);
+	}
+
+	/// Returns the ids within the window which are not executed, in increasing order.
+	pub fn missing(&self) -> Vec<u64> {
+		if self.executed == 0 {
+			return Vec::new();
+		}
+		let first = self
+			.highest
+			.saturating_sub(NONCE_WINDOW - 1)
+			.max(self.lowest);
+		(first..self.highest)
+			.filter(|nonce| self.executed & (1 << (self.highest - nonce)) == 0)
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::nonce::{NonceWindow, NONCE_WINDOW};
+
+	#[test]
+	fn missing_lists_gaps_since_lowest() {
+		let mut window = NonceWindow::default();
+		assert!(window.missing().is_empty());
+
+		window.note(10);
+		window.note(13);
+		window.note(11);
+		assert_eq!(vec![12], window.missing());
+
+		window.note(12);
+		assert!(window.missing().is_empty());
+
+		window.note(5);
+		assert_eq!(vec![6, 7, 8, 9], window.missing());
+	}
+
+	#[test]
+	fn missing_is_bounded_by_window() {
+		let mut window = NonceWindow::default();
+		window.note(0);
+		window.note(1000);
+
+		let missing = window.missing();
+		assert_eq!(NONCE_WINDOW as usize - 1, missing.len());
+		assert_eq!(Some(&(1000 - NONCE_WINDOW + 1)), missing.first());
+
+		// too old to be recorded
+		window.note(1);
+		assert_eq!(missing, window.missing());
+	}
+}
@@ -0,0 +1,108 @@
+use binary_merkle_tree::{merkle_proof, merkle_root, verify_proof, Leaf};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{pallet_prelude::ConstU32, BoundedVec};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::traits::Keccak256;
+
+/// Maximum number of messages sent from Avail in a single block.
+pub const MAX_OUTBOUND_MESSAGES: u32 = 8192;
+/// Maximum depth of an outbound proof, enough for `MAX_OUTBOUND_MESSAGES` leaves.
+pub const MAX_OUTBOUND_PROOF_DEPTH: u32 = 32;
+/// Prefix of the header digest item carrying the outbound root of a block.
+pub const OUTBOUND_ROOT_LOG_PREFIX: [u8; 4] = *b"vobr";
+
+pub type OutboundMessageList = BoundedVec<H256, ConstU32<MAX_OUTBOUND_MESSAGES>>;
+
+/// Aggregated roots of the messages sent from Avail in a block.
+#[derive(Clone, Copy, Default, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct OutboundRoot {
+	/// Merkle root over the message roots, in sending order.
+	pub root: H256,
+	/// Number of messages in the block.
+	pub leaves: u32,
+}
+
+/// Proof that a message is part of the outbound root of its block.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct OutboundProof {
+	pub leaf_index: u32,
+	pub siblings: BoundedVec<H256, ConstU32<MAX_OUTBOUND_PROOF_DEPTH>>,
+}
+
+/// Computes the outbound root from the roots of the messages of a block, in sending order.
+pub fn outbound_root(message_roots: &[H256]) -> OutboundRoot {
+	OutboundRoot {
+		root: merkle_root::<Keccak256, _>(message_roots),
+		leaves: message_roots.len() as u32,
+	}
+}
+
+/// Builds the proof for the message at `leaf_index`, e.g. from the `OutboundMessages` of the
+/// block.
+pub fn outbound_proof(message_roots: &[H256], leaf_index: u32) -> Option<OutboundProof> {
+	if leaf_index as usize >= message_roots.len() {
+		return None;
+	}
+	let proof = merkle_proof::<Keccak256, _, _>(message_roots, leaf_index as usize);
+
+	Some(OutboundProof {
+		leaf_index,
+		siblings: BoundedVec::try_from(proof.proof).ok()?,
+	})
+}
+
+/// Verifies that the message with root `message_root` is part of `outbound`.
+pub fn verify_outbound_proof(
+	outbound: &OutboundRoot,
+	message_root: H256,
+	proof: &OutboundProof,
+) -> bool {
+	if proof.leaf_index >= outbound.leaves {
+		return false;
+	}
+
+	verify_proof::<Keccak256, _, _>(
+		&outbound.root,
+		proof.siblings.iter().copied(),
+		outbound.leaves as usize,
+		proof.leaf_index as usize,
+		Leaf::Value(message_root.as_bytes()),
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use sp_core::H256;
+
+	use crate::outbound::{outbound_proof, outbound_root, verify_outbound_proof};
+
+	fn message_roots() -> Vec<H256> {
+		(0..5u8).map(H256::repeat_byte).collect()
+	}
+
+	#[test]
+	fn outbound_proof_verifies_for_every_message() {
+		let message_roots = message_roots();
+		let outbound = outbound_root(&message_roots);
+
+		for (index, message_root) in message_roots.iter().enumerate() {
+			let proof = outbound_proof(&message_roots, index as u32).unwrap();
+			assert!(verify_outbound_proof(&outbound, *message_root, &proof));
+		}
+	}
+
+	#[test]
+	fn outbound_proof_fails_for_other_message() {
+		let message_roots = message_roots();
+		let outbound = outbound_root(&message_roots);
+
+		let proof = outbound_proof(&message_roots, 2).unwrap();
+		assert!(!verify_outbound_proof(&outbound, message_roots[3], &proof));
+	}
+
+	#[test]
+	fn outbound_proof_out_of_range() {
+		assert!(outbound_proof(&message_roots(), 5).is_none());
+	}
+}
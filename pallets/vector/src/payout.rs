@@ -0,0 +1,160 @@
+use sp_core::{H256, U256};
+use sp_std::vec::Vec;
+
+/// Version tag of the message data of a payout batch, its first byte.
+pub const PAYOUT_BATCH_VERSION: u8 = 2;
+/// Maximum number of payouts in a batch.
+pub const MAX_PAYOUTS: u32 = 64;
+
+/// Length of the version tag and the asset id.
+const HEADER_LENGTH: usize = 33;
+/// Length of a recipient and its `uint256` amount.
+const PAYOUT_LENGTH: usize = 64;
+
+/// Amount of an asset paid to a recipient.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Payout {
+	pub recipient: H256,
+	pub amount: u128,
+}
+
+/// Payouts of an asset batched in a single message, executed all at once.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PayoutBatch {
+	pub asset_id: H256,
+	pub payouts: Vec<Payout>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PayoutError {
+	/// The data is not tagged with `PAYOUT_BATCH_VERSION`.
+	UnknownVersion,
+	/// The data is not a header followed by whole payouts, or has no payout.
+	InvalidLength,
+	/// The batch has more than `MAX_PAYOUTS` payouts.
+	TooManyPayouts,
+	/// An amount does not fit in a `u128`.
+	AmountOverflow,
+}
+
+/// Returns the number of payouts in `data` if it is tagged as a payout batch, without decoding
+/// them.
+pub fn payout_count(data: &[u8]) -> Option<u32> {
+	if data.first() != Some(&PAYOUT_BATCH_VERSION) {
+		return None;
+	}
+	let count = data.len().saturating_sub(HEADER_LENGTH) / PAYOUT_LENGTH;
+	Some(count.min(MAX_PAYOUTS as usize) as u32)
+}
+
+/// Decodes the data of a payout batch, packed as
+/// `abi.encodePacked(uint8(2), bytes32 assetId, (bytes32 recipient, uint256 amount)[])`.
+///
+/// ```
+/// use pallet_vector::payout::{decode_message_data_v2, PayoutError};
+///
+/// let mut data = vec![2u8];
+/// data.extend_from_slice(&[0u8; 32]);
+/// data.extend_from_slice(&[1u8; 32]);
+/// data.extend_from_slice(&[0u8; 31]);
+/// data.push(100);
+///
+/// let batch = decode_message_data_v2(&data).unwrap();
+/// assert_eq!(100, batch.payouts[0].amount);
+/// assert_eq!(Err(PayoutError::InvalidLength), decode_message_data_v2(&data[..96]));
+/// ```
+pub fn decode_message_data_v2(data: &[u8]) -> Result<PayoutBatch, PayoutError> {
+	if data.first() != Some(&PAYOUT_BATCH_VERSION) {
+		return Err(PayoutError::UnknownVersion);
+	}
+	let payouts = data
+		.get(HEADER_LENGTH..)
+		.ok_or(PayoutError::InvalidLength)?;
+	if payouts.is_empty() || payouts.len() % PAYOUT_LENGTH != 0 {
+		return Err(PayoutError::InvalidLength);
+	}
+	if payouts.len() / PAYOUT_LENGTH > MAX_PAYOUTS as usize {
+		return Err(PayoutError::TooManyPayouts);
+	}
+
+	let payouts = payouts
+		.chunks_exact(PAYOUT_LENGTH)
+		.map(|payout| {
+			let (recipient, amount) = payout.split_at(32);
+			let amount = U256::from_big_endian(amount);
+			if amount > U256::from(u128::MAX) {
+				return Err(PayoutError::AmountOverflow);
+			}
+			Ok(Payout {
+				recipient: H256::from_slice(recipient),
+				amount: amount.low_u128(),
+			})
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+
+	Ok(PayoutBatch {
+		asset_id: H256::from_slice(&data[1..HEADER_LENGTH]),
+		payouts,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use sp_core::H256;
+
+	use crate::payout::{decode_message_data_v2, payout_count, PayoutError, MAX_PAYOUTS};
+
+	fn batch_data(payouts: usize) -> Vec<u8> {
+		let mut data = vec![2u8];
+		data.extend_from_slice(&[7u8; 32]);
+		for index in 0..payouts {
+			data.extend_from_slice(&[index as u8 + 1; 32]);
+			let mut amount = [0u8; 32];
+			amount[31] = index as u8 + 1;
+			data.extend_from_slice(&amount);
+		}
+		data
+	}
+
+	#[test]
+	fn payout_batch_decodes_pairs() {
+		let data = batch_data(3);
+
+		let batch = decode_message_data_v2(&data).unwrap();
+
+		assert_eq!(H256::repeat_byte(7), batch.asset_id);
+		assert_eq!(3, batch.payouts.len());
+		assert_eq!(H256::repeat_byte(3), batch.payouts[2].recipient);
+		assert_eq!(3, batch.payouts[2].amount);
+		assert_eq!(Some(3), payout_count(&data));
+	}
+
+	#[test]
+	fn payout_batch_is_rejected_when_malformed() {
+		let data = batch_data(1);
+		let mut overflow = batch_data(1);
+		overflow[33 + 32] = 1;
+
+		assert_eq!(
+			Err(PayoutError::UnknownVersion),
+			decode_message_data_v2(&[1u8; 97])
+		);
+		assert_eq!(
+			Err(PayoutError::InvalidLength),
+			decode_message_data_v2(&batch_data(0))
+		);
+		assert_eq!(
+			Err(PayoutError::InvalidLength),
+			decode_message_data_v2(&data[..96])
+		);
+		assert_eq!(
+			Err(PayoutError::TooManyPayouts),
+			decode_message_data_v2(&batch_data(MAX_PAYOUTS as usize + 1))
+		);
+		assert_eq!(
+			Err(PayoutError::AmountOverflow),
+			decode_message_data_v2(&overflow)
+		);
+		assert_eq!(None, payout_count(&[1u8; 97]));
+	}
+}
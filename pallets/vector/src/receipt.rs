@@ -0,0 +1,364 @@
+use primitive_types::{H160, H256};
+use rlp::Rlp;
+use sp_std::vec::Vec;
+
+/// Length in bytes of the logs bloom filter of a receipt.
+const BLOOM_LENGTH: usize = 256;
+
+/// EIP-2718 transaction type of a receipt.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TxType {
+	/// Untyped receipt, encoded as a bare RLP list.
+	Legacy,
+	/// EIP-2930 access list transaction.
+	AccessList,
+	/// EIP-1559 dynamic fee transaction.
+	DynamicFee,
+	/// EIP-4844 blob transaction.
+	Blob,
+}
+
+impl TxType {
+	fn from_type_byte(byte: u8) -> Result<Self, ReceiptError> {
+		match byte {
+			0x01 => Ok(Self::AccessList),
+			0x02 => Ok(Self::DynamicFee),
+			0x03 => Ok(Self::Blob),
+			_ => Err(ReceiptError::UnsupportedType(byte)),
+		}
+	}
+}
+
+/// Outcome of the transaction, as committed in the receipt.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReceiptOutcome {
+	/// Post-transaction state root of pre-Byzantium receipts.
+	StateRoot(H256),
+	/// EIP-658 status code, `true` if the transaction succeeded.
+	Status(bool),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Log {
+	pub address: H160,
+	pub topics: Vec<H256>,
+	pub data: Vec<u8>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Receipt {
+	pub tx_type: TxType,
+	pub outcome: ReceiptOutcome,
+	pub cumulative_gas_used: u64,
+	pub logs: Vec<Log>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReceiptError {
+	/// The receipt is empty.
+	Empty,
+	/// The type byte is not a supported EIP-2718 receipt type.
+	UnsupportedType(u8),
+	/// The payload is not a well-formed RLP list of receipt fields.
+	InvalidRlp,
+	/// The status is neither a status code nor a state root.
+	InvalidOutcome,
+	/// The logs bloom is not 256 bytes long.
+	InvalidBloom,
+	/// A log is not a list of an address, topics and data.
+	InvalidLog,
+}
+
+impl From<rlp::DecoderError> for ReceiptError {
+	fn from(_: rlp::DecoderError) -> Self {
+		Self::InvalidRlp
+	}
+}
+
+impl Receipt {
+	/// Decodes a receipt as stored in the receipts trie: either a legacy RLP list, or a
+	/// transaction type byte followed by the RLP list (EIP-2718).
+	pub fn decode(bytes: &[u8]) -> Result<Self, ReceiptError> {
+		let (tx_type, payload) = match bytes.first() {
+			None => return Err(ReceiptError::Empty),
+			// RLP lists start at 0xc0, type bytes are in [0x00, 0x7f].
+			Some(byte) if *byte >= 0xc0 => (TxType::Legacy, bytes),
+			Some(byte) => (TxType::from_type_byte(*byte)?, &bytes[1..]),
+		};
+
+		let rlp = Rlp::new(payload);
+		let info = rlp.payload_info()?;
+		if !rlp.is_list() || info.header_len + info.value_len != payload.len() {
+			return Err(ReceiptError::InvalidRlp);
+		}
+		if rlp.item_count()? != 4 {
+			return Err(ReceiptError::InvalidRlp);
+		}
+
+		let outcome = decode_outcome(rlp.at(0)?.data()?)?;
+		let cumulative_gas_used = rlp.val_at::<u64>(1)?;
+		if rlp.at(2)?.data()?.len() != BLOOM_LENGTH {
+			return Err(ReceiptError::InvalidBloom);
+		}
+		let logs = rlp.at(3)?;
+		if !logs.is_list() {
+			return Err(ReceiptError::InvalidRlp);
+		}
+		let logs = logs
+			.iter()
+			.map(|log| decode_log(&log))
+			.collect::<Result<Vec<_>, _>>()?;
+
+		Ok(Self {
+			tx_type,
+			outcome,
+			cumulative_gas_used,
+			logs,
+		})
+	}
+
+	/// Returns `true` if the transaction of the receipt succeeded. Pre-Byzantium receipts
+	/// do not commit to the result and are never considered successful.
+	pub fn is_success(&self) -> bool {
+		self.outcome == ReceiptOutcome::Status(true)
+	}
+
+	/// Returns the logs emitted by `address` whose leading topics match `topics`, where `None`
+	/// matches any topic, like the topic filters of `eth_getLogs`.
+	pub fn filter_logs<'a>(
+		&'a self,
+		address: H160,
+		topics: &'a [Option<H256>],
+	) -> impl Iterator<Item = &'a Log> + 'a {
+		self.logs.iter().filter(move |log| {
+			log.address == address
+				&& log.topics.len() >= topics.len()
+				&& topics
+					.iter()
+					.zip(&log.topics)
+					.all(|(filter, topic)| filter.map_or(true, |filter| filter == *topic))
+		})
+	}
+}
+
+fn decode_outcome(value: &[u8]) -> Result<ReceiptOutcome, ReceiptError> {
+	match value {
+		[] => Ok(ReceiptOutcome::Status(false)),
+		[1] => Ok(ReceiptOutcome::Status(true)),
+		root if root.len() == 32 => Ok(ReceiptOutcome::StateRoot(H256::from_slice(root))),
+		_ => Err(ReceiptError::InvalidOutcome),
+	}
+}
+
+fn decode_log(rlp: &Rlp) -> Result<Log, ReceiptError> {
+	if !rlp.is_list() || rlp.item_count()? != 3 {
+		return Err(ReceiptError::InvalidLog);
+	}
+
+	let address = rlp.at(0)?.data()?;
+	if address.len() != 20 {
+		return Err(ReceiptError::InvalidLog);
+	}
+	let topics = rlp.at(1)?;
+	if !topics.is_list() {
+		return Err(ReceiptError::InvalidLog);
+	}
+	let topics = topics
+		.iter()
+		.map(|topic| match topic.data() {
+			Ok(topic) if topic.len() == 32 => Ok(H256::from_slice(topic)),
+			_ => Err(ReceiptError::InvalidLog),
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+
+	Ok(Log {
+		address: H160::from_slice(address),
+		topics,
+		data: rlp.at(2)?.data()?.to_vec(),
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rlp::RlpStream;
+
+	const ADDRESS: H160 = H160([0x11; 20]);
+	const OTHER_ADDRESS: H160 = H160([0x22; 20]);
+	const TOPIC: H256 = H256([0xaa; 32]);
+	const OTHER_TOPIC: H256 = H256([0xbb; 32]);
+
+	fn log(address: H160, topics: &[H256], data: &[u8]) -> Log {
+		Log {
+			address,
+			topics: topics.to_vec(),
+			data: data.to_vec(),
+		}
+	}
+
+	fn encode_receipt(
+		tx_type: Option<u8>,
+		outcome: &[u8],
+		bloom_len: usize,
+		logs: &[Log],
+	) -> Vec<u8> {
+		let mut stream = RlpStream::new_list(4);
+		stream.append(&outcome.to_vec());
+		stream.append(&21_000u64);
+		stream.append(&vec![0u8; bloom_len]);
+		stream.begin_list(logs.len());
+		for log in logs {
+			stream.begin_list(3);
+			stream.append(&log.address.as_bytes().to_vec());
+			stream.begin_list(log.topics.len());
+			for topic in &log.topics {
+				stream.append(&topic.as_bytes().to_vec());
+			}
+			stream.append(&log.data);
+		}
+
+		let mut bytes = tx_type.map(|t| vec![t]).unwrap_or_default();
+		bytes.extend_from_slice(&stream.out());
+		bytes
+	}
+
+	#[test]
+	fn decode_legacy_receipt_with_status() {
+		let logs = [log(ADDRESS, &[TOPIC], b"data")];
+		let receipt = Receipt::decode(&encode_receipt(None, &[1], 256, &logs)).unwrap();
+
+		assert_eq!(TxType::Legacy, receipt.tx_type);
+		assert_eq!(ReceiptOutcome::Status(true), receipt.outcome);
+		assert_eq!(21_000, receipt.cumulative_gas_used);
+		assert_eq!(logs.to_vec(), receipt.logs);
+		assert!(receipt.is_success());
+	}
+
+	#[test]
+	fn decode_legacy_receipt_with_state_root() {
+		let root = H256::repeat_byte(0x33);
+		let receipt = Receipt::decode(&encode_receipt(None, root.as_bytes(), 256, &[])).unwrap();
+
+		assert_eq!(ReceiptOutcome::StateRoot(root), receipt.outcome);
+		assert!(!receipt.is_success());
+	}
+
+	#[test]
+	fn decode_typed_receipts() {
+		let logs = [log(ADDRESS, &[TOPIC, OTHER_TOPIC], &[])];
+		for (type_byte, tx_type) in [
+			(0x01, TxType::AccessList),
+			(0x02, TxType::DynamicFee),
+			(0x03, TxType::Blob),
+		] {
+			let receipt =
+				Receipt::decode(&encode_receipt(Some(type_byte), &[1], 256, &logs)).unwrap();
+
+			assert_eq!(tx_type, receipt.tx_type);
+			assert_eq!(logs.to_vec(), receipt.logs);
+		}
+	}
+
+	#[test]
+	fn decode_failed_receipt() {
+		let receipt = Receipt::decode(&encode_receipt(Some(0x02), &[], 256, &[])).unwrap();
+
+		assert_eq!(ReceiptOutcome::Status(false), receipt.outcome);
+		assert!(!receipt.is_success());
+	}
+
+	#[test]
+	fn decode_fails_for_unsupported_type() {
+		assert_eq!(
+			Err(ReceiptError::UnsupportedType(0x7e)),
+			Receipt::decode(&encode_receipt(Some(0x7e), &[1], 256, &[]))
+		);
+		assert_eq!(
+			Err(ReceiptError::UnsupportedType(0x00)),
+			Receipt::decode(&encode_receipt(Some(0x00), &[1], 256, &[]))
+		);
+	}
+
+	#[test]
+	fn decode_fails_for_malformed_receipts() {
+		assert_eq!(Err(ReceiptError::Empty), Receipt::decode(&[]));
+		assert_eq!(Err(ReceiptError::InvalidRlp), Receipt::decode(&[0x02]));
+
+		let mut trailing = encode_receipt(Some(0x02), &[1], 256, &[]);
+		trailing.push(0);
+		assert_eq!(Err(ReceiptError::InvalidRlp), Receipt::decode(&trailing));
+
+		let mut truncated = encode_receipt(None, &[1], 256, &[]);
+		truncated.pop();
+		assert!(Receipt::decode(&truncated).is_err());
+
+		let mut stream = RlpStream::new_list(3);
+		stream
+			.append(&vec![1u8])
+			.append(&21_000u64)
+			.append(&vec![0u8; 256]);
+		assert_eq!(
+			Err(ReceiptError::InvalidRlp),
+			Receipt::decode(&stream.out())
+		);
+
+		assert_eq!(
+			Err(ReceiptError::InvalidOutcome),
+			Receipt::decode(&encode_receipt(None, &[2], 256, &[]))
+		);
+		assert_eq!(
+			Err(ReceiptError::InvalidBloom),
+			Receipt::decode(&encode_receipt(None, &[1], 255, &[]))
+		);
+	}
+
+	#[test]
+	fn decode_fails_for_malformed_log() {
+		let mut stream = RlpStream::new_list(4);
+		stream
+			.append(&vec![1u8])
+			.append(&21_000u64)
+			.append(&vec![0u8; 256]);
+		stream.begin_list(1).begin_list(3);
+		stream.append(&vec![0x11u8; 19]);
+		stream.begin_list(0);
+		stream.append(&Vec::<u8>::new());
+
+		assert_eq!(
+			Err(ReceiptError::InvalidLog),
+			Receipt::decode(&stream.out())
+		);
+	}
+
+	#[test]
+	fn filter_logs_by_address_and_topics() {
+		let logs = [
+			log(ADDRESS, &[TOPIC, OTHER_TOPIC], b"first"),
+			log(OTHER_ADDRESS, &[TOPIC], b"other address"),
+			log(ADDRESS, &[OTHER_TOPIC], b"other topic"),
+			log(ADDRESS, &[], b"anonymous"),
+		];
+		let receipt = Receipt::decode(&encode_receipt(Some(0x02), &[1], 256, &logs)).unwrap();
+		let data = |topics: &[Option<H256>]| {
+			receipt
+				.filter_logs(ADDRESS, topics)
+				.map(|log| log.data.clone())
+				.collect::<Vec<_>>()
+		};
+
+		assert_eq!(
+			vec![
+				b"first".to_vec(),
+				b"other topic".to_vec(),
+				b"anonymous".to_vec()
+			],
+			data(&[])
+		);
+		assert_eq!(vec![b"first".to_vec()], data(&[Some(TOPIC)]));
+		assert_eq!(vec![b"first".to_vec()], data(&[None, Some(OTHER_TOPIC)]));
+		assert_eq!(
+			vec![b"first".to_vec(), b"other topic".to_vec()],
+			data(&[None])
+		);
+	}
+}
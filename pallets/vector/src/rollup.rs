@@ -0,0 +1,264 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{pallet_prelude::ConstU32, BoundedVec};
+use primitive_types::U256;
+use rlp::Rlp;
+use scale_info::TypeInfo;
+use sp_core::{H160, H256};
+use sp_io::hashing::keccak_256;
+
+/// Maximum length of the RLP encoded header of an Arbitrum block.
+pub const MAX_L2_HEADER_LENGTH: u32 = 1024;
+/// Index of the state root in the RLP encoded header of an EVM block.
+const HEADER_STATE_ROOT_INDEX: usize = 3;
+
+/// Layout of the contract on Ethereum committing to the states of a rollup.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum RollupLayout {
+	/// OP Stack `L2OutputOracle`, with the `l2Outputs` array of output proposals at storage
+	/// index `outputs_slot`. An output is final once `finalization_period` seconds passed since
+	/// it was proposed.
+	Optimism {
+		outputs_slot: H256,
+		finalization_period: u64,
+	},
+	/// Arbitrum Nitro `RollupCore`, with the `_nodes` mapping at storage index `nodes_slot`,
+	/// the `confirmData` of a node `confirm_data_offset` slots after its start, and
+	/// `_latestConfirmed` in the lowest bytes of `latest_confirmed_slot`.
+	Arbitrum {
+		nodes_slot: H256,
+		confirm_data_offset: u32,
+		latest_confirmed_slot: H256,
+	},
+}
+
+/// Rollup whose messages are proven against its state committed on Ethereum.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct RollupConfig {
+	/// Address of the commitment contract on Ethereum.
+	pub contract: H160,
+	pub layout: RollupLayout,
+}
+
+/// Preimage of the commitment to a state of a rollup, as stored on Ethereum.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo)]
+pub enum StateCommitment {
+	/// Fields of a version 0 output root.
+	Optimism {
+		state_root: H256,
+		message_passer_storage_root: H256,
+		block_hash: H256,
+	},
+	/// Header of the confirmed block and the send root confirmed with it.
+	Arbitrum {
+		header: BoundedVec<u8, ConstU32<MAX_L2_HEADER_LENGTH>>,
+		send_root: H256,
+	},
+}
+
+/// Proof of the state of a rollup against the execution state root of Ethereum: the account of
+/// the commitment contract and, with a multiproof, its commitment to the state at `index` and
+/// the finality of that commitment.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo)]
+pub struct RollupProof<AccountProof, StorageProof> {
+	/// Index of the output proposal or node committing to the state.
+	pub index: u64,
+	pub commitment: StateCommitment,
+	pub account_proof: AccountProof,
+	pub storage_proof: StorageProof,
+}
+
+impl RollupLayout {
+	/// Returns the storage slot of the commitment at `index`.
+	pub fn commitment_slot(&self, index: u64) -> H256 {
+		match self {
+			RollupLayout::Optimism { outputs_slot, .. } => {
+				// Output proposals take two slots, the output root first.
+				let start = U256::from_big_endian(&keccak_256(outputs_slot.as_bytes()));
+				slot_at(start, U256::from(index).saturating_mul(2.into()))
+			},
+			RollupLayout::Arbitrum {
+				nodes_slot,
+				confirm_data_offset,
+				..
+			} => {
+				let mut key = [0u8; 64];
+				key[24..32].copy_from_slice(&index.to_be_bytes());
+				key[32..].copy_from_slice(nodes_slot.as_bytes());
+				let start = U256::from_big_endian(&keccak_256(&key));
+				slot_at(start, (*confirm_data_offset).into())
+			},
+		}
+	}
+
+	/// Returns the storage slot of the value the finality of the commitment at `index` is
+	/// checked with.
+	pub fn finality_slot(&self, index: u64) -> H256 {
+		match self {
+			RollupLayout::Optimism { .. } => {
+				// `timestamp` is packed with `l2BlockNumber` after the output root.
+				let commitment = U256::from_big_endian(self.commitment_slot(index).as_bytes());
+				slot_at(commitment, U256::one())
+			},
+			RollupLayout::Arbitrum {
+				latest_confirmed_slot,
+				..
+			} => *latest_confirmed_slot,
+		}
+	}
+
+	/// Returns whether the commitment at `index` is final, given the value of its finality slot
+	/// and the timestamp of the Ethereum block it is proven in.
+	pub fn is_final(&self, index: u64, finality_value: H256, timestamp: u64) -> bool {
+		match self {
+			RollupLayout::Optimism {
+				finalization_period,
+				..
+			} => {
+				let proposed = low_u64(finality_value);
+				proposed.saturating_add(*finalization_period) <= timestamp
+			},
+			RollupLayout::Arbitrum { .. } => index <= low_u64(finality_value),
+		}
+	}
+}
+
+impl StateCommitment {
+	/// Returns whether the commitment is of the kind of rollup of `layout`.
+	pub fn matches(&self, layout: &RollupLayout) -> bool {
+		matches!(
+			(self, layout),
+			(
+				StateCommitment::Optimism { .. },
+				RollupLayout::Optimism { .. }
+			) | (
+				StateCommitment::Arbitrum { .. },
+				RollupLayout::Arbitrum { .. }
+			)
+		)
+	}
+
+	/// Returns the commitment stored on Ethereum: the output root for an OP Stack chain and the
+	/// confirm data for Arbitrum.
+	pub fn root(&self) -> H256 {
+		match self {
+			StateCommitment::Optimism {
+				state_root,
+				message_passer_storage_root,
+				block_hash,
+			} => {
+				let mut preimage = [0u8; 128];
+				preimage[32..64].copy_from_slice(state_root.as_bytes());
+				preimage[64..96].copy_from_slice(message_passer_storage_root.as_bytes());
+				preimage[96..].copy_from_slice(block_hash.as_bytes());
+				H256(keccak_256(&preimage))
+			},
+			StateCommitment::Arbitrum { header, send_root } => {
+				let mut preimage = [0u8; 64];
+				preimage[..32].copy_from_slice(&keccak_256(header));
+				preimage[32..].copy_from_slice(send_root.as_bytes());
+				H256(keccak_256(&preimage))
+			},
+		}
+	}
+
+	/// Returns the state root of the rollup, `None` if the header cannot be decoded.
+	pub fn state_root(&self) -> Option<H256> {
+		match self {
+			StateCommitment::Optimism { state_root, .. } => Some(*state_root),
+			StateCommitment::Arbitrum { header, .. } => {
+				let state_root = Rlp::new(header).at(HEADER_STATE_ROOT_INDEX).ok()?;
+				let state_root = state_root.data().ok()?;
+				(state_root.len() == 32).then(|| H256::from_slice(state_root))
+			},
+		}
+	}
+}
+
+fn slot_at(start: U256, offset: U256) -> H256 {
+	let mut slot = [0u8; 32];
+	start.overflowing_add(offset).0.to_big_endian(&mut slot);
+	H256(slot)
+}
+
+fn low_u64(value: H256) -> u64 {
+	let mut bytes = [0u8; 8];
+	bytes.copy_from_slice(&value.as_bytes()[24..]);
+	u64::from_be_bytes(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+	use hex_literal::hex;
+	use sp_core::H256;
+
+	use crate::rollup::{RollupLayout, StateCommitment};
+
+	fn optimism() -> RollupLayout {
+		RollupLayout::Optimism {
+			outputs_slot: H256::from_low_u64_be(3),
+			finalization_period: 604_800,
+		}
+	}
+
+	#[test]
+	fn optimism_output_slots_follow_array_layout() {
+		let layout = optimism();
+
+		// keccak256(uint256(3))
+		let start = H256(hex!(
+			"c2575a0e9e593c00f959f8c92f12db2869c3395a3b0502d05e2516446f71f85b"
+		));
+		assert_eq!(start, layout.commitment_slot(0));
+		assert_eq!(
+			H256(hex!(
+				"c2575a0e9e593c00f959f8c92f12db2869c3395a3b0502d05e2516446f71f85d"
+			)),
+			layout.commitment_slot(1)
+		);
+		assert_eq!(
+			H256(hex!(
+				"c2575a0e9e593c00f959f8c92f12db2869c3395a3b0502d05e2516446f71f85e"
+			)),
+			layout.finality_slot(1)
+		);
+	}
+
+	#[test]
+	fn optimism_output_is_final_after_finalization_period() {
+		let layout = optimism();
+		let proposed = H256::from_low_u64_be(1_000);
+
+		assert!(!layout.is_final(0, proposed, 1_000 + 604_799));
+		assert!(layout.is_final(0, proposed, 1_000 + 604_800));
+	}
+
+	#[test]
+	fn arbitrum_node_is_final_once_confirmed() {
+		let layout = RollupLayout::Arbitrum {
+			nodes_slot: H256::from_low_u64_be(117),
+			confirm_data_offset: 2,
+			latest_confirmed_slot: H256::from_low_u64_be(114),
+		};
+		let latest_confirmed = H256::from_low_u64_be(7);
+
+		assert!(layout.is_final(7, latest_confirmed, 0));
+		assert!(!layout.is_final(8, latest_confirmed, 0));
+	}
+
+	#[test]
+	fn commitment_must_match_layout() {
+		let commitment = StateCommitment::Optimism {
+			state_root: H256::repeat_byte(1),
+			message_passer_storage_root: H256::repeat_byte(2),
+			block_hash: H256::repeat_byte(3),
+		};
+
+		assert!(commitment.matches(&optimism()));
+		assert_eq!(Some(H256::repeat_byte(1)), commitment.state_root());
+		assert!(!StateCommitment::Arbitrum {
+			header: Default::default(),
+			send_root: H256::zero(),
+		}
+		.matches(&optimism()));
+	}
+}
@@ -6,12 +6,17 @@ use ark_groth16::Proof;
 use ark_std::str::FromStr;
 use ark_std::string::String;
 use ark_std::string::ToString;
+use avail_core::data_proof::{AddressedMessage, Message, MessageType};
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{Deserialize, Serialize};
+use frame_support::{weights::Weight, Deserialize, Serialize};
 use scale_info::TypeInfo;
-use sp_core::{H256, U256};
+use sp_core::{H160, H256, U256};
+use sp_io::hashing::keccak_256;
+use sp_runtime::{traits::Verify, AccountId32, DispatchError, MultiSignature, Perbill};
 use sp_std::prelude::*;
 
+use crate::address::SourceAddress;
+use crate::storage_utils::MessageStatusEnum;
 use crate::verifier::{str_to_fq, VerificationError};
 
 #[derive(Debug, PartialEq, Clone)]
@@ -91,6 +96,565 @@ pub struct Configuration {
 	pub finality_threshold: u16,
 }
 
+/// Reason a sync committee rotation is rejected by `check_rotation`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RotationError {
+	ConfigurationNotSet,
+	/// The rotated committee is not the one of the period after the period of the header.
+	NotNextPeriod,
+	/// The header the rotation is proven from was not verified by a step.
+	HeaderNotVerified,
+	/// The step which verified the header had less participation than the finality threshold.
+	ParticipationTooLow,
+}
+
+/// Checks that the sync committee of `next_period` can be rotated in from the finalized header of
+/// `slot`, verified by a step with `participation`, `None` if no step verified it.
+///
+/// The light client moves from one period to the next as follows:
+/// 1. the sync committee of period `N` is stored, so steps into period `N` can be verified,
+/// 2. a step verifies the header of a slot of period `N` with enough participation,
+/// 3. the sync committee of period `N + 1` is rotated in from that header, so steps into period
+///    `N + 1` can be verified.
+///
+/// ```
+/// use pallet_vector::state::{check_rotation, Configuration, RotationError};
+///
+/// let cfg = Configuration { slots_per_period: 8192, finality_threshold: 342 };
+/// // A step verified slot 8191, the last slot of period 0, so period 1 can be rotated in.
+/// assert_eq!(Ok(()), check_rotation(&cfg, 8191, Some(400), 1));
+/// // Periods cannot be skipped, and slot 8192 is already in period 1.
+/// assert_eq!(Err(RotationError::NotNextPeriod), check_rotation(&cfg, 8191, Some(400), 2));
+/// assert_eq!(Err(RotationError::NotNextPeriod), check_rotation(&cfg, 8192, Some(400), 1));
+/// // The header must be verified by a step with at least the finality threshold participation.
+/// assert_eq!(Err(RotationError::HeaderNotVerified), check_rotation(&cfg, 8191, None, 1));
+/// assert_eq!(
+/// 	Err(RotationError::ParticipationTooLow),
+/// 	check_rotation(&cfg, 8191, Some(341), 1)
+/// );
+/// ```
+pub fn check_rotation(
+	cfg: &Configuration,
+	slot: u64,
+	participation: Option<u16>,
+	next_period: u64,
+) -> Result<(), RotationError> {
+	let period = slot
+		.checked_div(cfg.slots_per_period)
+		.ok_or(RotationError::ConfigurationNotSet)?;
+	if period.checked_add(1) != Some(next_period) {
+		return Err(RotationError::NotNextPeriod);
+	}
+	let participation = participation.ok_or(RotationError::HeaderNotVerified)?;
+	if participation < cfg.finality_threshold {
+		return Err(RotationError::ParticipationTooLow);
+	}
+	Ok(())
+}
+
+/// Submission quota of an allowed updater.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen, Default)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct UpdaterQuota {
+	/// Maximum number of submissions per session, unlimited if `None`.
+	pub max_submissions: Option<u32>,
+	/// Session in which `submissions` were counted.
+	pub session: u32,
+	/// Number of submissions in `session`.
+	pub submissions: u32,
+}
+
+/// Header root accepted from a step, kept in a short history to detect equivocations.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AcceptedHeader {
+	pub slot: u64,
+	pub header_root: H256,
+	/// Updater that submitted the step, `None` for a step included by the block author, which
+	/// cannot be attributed to the relayer that proved it.
+	pub updater: Option<H256>,
+}
+
+/// Contract of a source chain keeping its latest base fee in a storage slot.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct BaseFeeOracle {
+	pub address: H160,
+	/// Storage slot of the base fee in the contract.
+	pub slot: H256,
+}
+
+/// Base fee of a source chain, proven against the execution state root of `slot`.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct SourceBaseFee {
+	pub slot: u64,
+	pub base_fee: U256,
+}
+
+/// Sync committee participation a recipient requires from the step output of the slot its
+/// messages are proven against.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ParticipationRequirement {
+	/// Minimum number of participants, like `Configuration::finality_threshold`.
+	pub min_participation: u16,
+	/// Fungible token amount from which the requirement applies. Arbitrary messages are
+	/// always subject to it.
+	pub min_amount: u128,
+}
+
+/// Sync committee participation of the step outputs accepted during a period.
+#[derive(Clone, Copy, Default, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct PeriodParticipation {
+	/// Number of accepted step outputs.
+	pub updates: u32,
+	/// Number of them with low participation, below the finality threshold and its margin.
+	pub low_updates: u32,
+	pub min: u16,
+	pub max: u16,
+	/// Sum of the participations, for their average.
+	pub sum: u64,
+}
+
+impl PeriodParticipation {
+	/// Records the `participation` of an accepted step output.
+	pub fn note(&mut self, participation: u16, low: bool) {
+		self.min = match self.updates {
+			0 => participation,
+			_ => self.min.min(participation),
+		};
+		self.max = self.max.max(participation);
+		self.updates = self.updates.saturating_add(1);
+		self.low_updates = self.low_updates.saturating_add(low.into());
+		self.sum = self.sum.saturating_add(participation.into());
+	}
+
+	/// Returns the average participation, zero without updates.
+	pub fn average(&self) -> u16 {
+		self.sum
+			.checked_div(self.updates.into())
+			.unwrap_or_default() as u16
+	}
+
+	/// Returns the share of updates without low participation, `None` without updates.
+	pub fn liveness(&self) -> Option<Perbill> {
+		(self.updates > 0).then(|| {
+			Perbill::from_rational(self.updates.saturating_sub(self.low_updates), self.updates)
+		})
+	}
+}
+
+/// Fee a relayer takes out of the bridged amount of an asset when it executes a message on
+/// behalf of its recipient.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ExecutionFee {
+	/// Share of the bridged amount.
+	pub rate: Perbill,
+	pub min_fee: u128,
+	pub max_fee: u128,
+}
+
+impl ExecutionFee {
+	/// Returns the fee of bridging `amount`, bounded by `min_fee` and `max_fee` and never
+	/// above `amount` itself.
+	pub fn fee_of(&self, amount: u128) -> u128 {
+		(self.rate * amount)
+			.clamp(self.min_fee, self.max_fee)
+			.min(amount)
+	}
+}
+
+/// Release of the large bridged amounts of an asset: an amount from `threshold`, after the
+/// execution fee, is vested linearly over `duration` blocks instead of being transferred when
+/// its message is executed.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ReleaseSchedule {
+	pub threshold: u128,
+	/// Number of blocks over which the amount is released.
+	pub duration: u32,
+}
+
+/// Change of the chain id of a source domain at a fork of the source chain.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ForkTransition {
+	/// Chain id of the domain from `activation_slot`.
+	pub new_domain: u32,
+	/// First slot of the fork.
+	pub activation_slot: u64,
+	/// Broadcaster the messages of the old chain id were sent with.
+	pub broadcaster: H256,
+}
+
+/// Amount of an executed message vested to its recipient.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct VestedRelease {
+	pub recipient: H256,
+	pub amount: u128,
+	/// Part of the amount already transferred to the recipient.
+	pub released: u128,
+	/// Block the vesting starts at.
+	pub start: u32,
+	pub duration: u32,
+}
+
+impl VestedRelease {
+	/// Returns the part of the amount vested at block `now`.
+	pub fn vested_at(&self, now: u32) -> u128 {
+		let elapsed = now.saturating_sub(self.start);
+		if elapsed >= self.duration {
+			return self.amount;
+		}
+		Perbill::from_rational(elapsed, self.duration) * self.amount
+	}
+
+	/// Returns the vested part of the amount which is not released yet at block `now`.
+	pub fn releasable(&self, now: u32) -> u128 {
+		self.vested_at(now).saturating_sub(self.released)
+	}
+}
+
+/// Runtime handler the arbitrary messages to a destination are handed to, bound to the runtime
+/// code it was registered with.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct HandlerRegistration {
+	/// Id of the handler among the `Config::DestinationHandlers`.
+	pub handler: u32,
+	/// Hash of the runtime code at registration. The handler must be registered again after a
+	/// runtime upgrade, as the id may no longer designate the same handler.
+	pub code_hash: H256,
+	/// Last block at which messages are handed to the handler.
+	pub expiry: Option<u32>,
+}
+
+impl HandlerRegistration {
+	/// Returns whether the registration is expired at block `now`.
+	pub fn is_expired(&self, now: u32) -> bool {
+		self.expiry.map_or(false, |expiry| now > expiry)
+	}
+}
+
+/// Call of this pallet sent by the governance of the source chain, executable once its delay
+/// has elapsed.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct GovernanceCall {
+	/// Blake2-256 hash of the SCALE encoded call.
+	pub call_hash: H256,
+	/// First block at which the call can be executed.
+	pub executable_at: u32,
+}
+
+/// Header of an executed inbound message, as decoded from its ABI encoding, with the hash of
+/// its payload.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo)]
+pub struct MessageHeader {
+	pub message_type: MessageType,
+	pub from: H256,
+	pub to: H256,
+	pub origin_domain: u32,
+	pub destination_domain: u32,
+	/// Nonce of the message on its origin domain.
+	pub id: u64,
+	/// Keccak-256 hash of the data of an arbitrary message, or of the ABI encoded asset id and
+	/// amount of a fungible token.
+	pub payload_hash: H256,
+}
+
+impl MessageHeader {
+	pub fn of(addr_message: &AddressedMessage) -> Self {
+		let payload_hash = match &addr_message.message {
+			Message::ArbitraryMessage(data) => keccak_256(data),
+			Message::FungibleToken { asset_id, amount } => {
+				let mut payload = [0u8; 64];
+				payload[..32].copy_from_slice(asset_id.as_bytes());
+				U256::from(*amount).to_big_endian(&mut payload[32..]);
+				keccak_256(&payload)
+			},
+		};
+
+		MessageHeader {
+			message_type: addr_message.message.r#type(),
+			from: addr_message.from,
+			to: addr_message.to,
+			origin_domain: addr_message.origin_domain,
+			destination_domain: addr_message.destination_domain,
+			id: addr_message.id,
+			payload_hash: H256(payload_hash),
+		}
+	}
+}
+
+/// Authorization by the recipient of a message for anyone to execute it, in exchange for a tip
+/// taken out of the bridged amount.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ExecutionPermit {
+	/// Hash of the authorized message.
+	pub message_root: H256,
+	/// Amount paid to the executor.
+	pub tip: u128,
+	/// Last block at which the permit can be used.
+	pub deadline: u32,
+}
+
+impl ExecutionPermit {
+	/// Prefix of the signed payload, so the signature is never valid for another kind of payload.
+	pub const SIGNING_CONTEXT: &'static [u8] = b"avail:vector:execution-permit";
+
+	/// Returns the payload the recipient signs.
+	pub fn signing_payload(&self) -> Vec<u8> {
+		(Self::SIGNING_CONTEXT, self).encode()
+	}
+
+	/// Returns true if `signature` is the signature of the permit by `recipient`.
+	pub fn is_signed_by(&self, recipient: H256, signature: &MultiSignature) -> bool {
+		signature.verify(
+			self.signing_payload().as_slice(),
+			&AccountId32::new(recipient.0),
+		)
+	}
+}
+
+/// Weight class of an account or storage proof, by its number of nodes and total size in bytes.
+/// A proof is charged at the upper bound of its tier.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProofTier {
+	/// Proof of a usual depth and size, covered by the weight of the execution itself.
+	Small,
+	/// Proof of up to `MEDIUM_NODES` nodes and `MEDIUM_BYTES` bytes.
+	Medium,
+	/// Proof of up to `Config::MaxProofDepth` nodes of `Config::MaxProofNodeSize` bytes.
+	Large,
+}
+
+impl ProofTier {
+	pub const SMALL_NODES: u32 = 10;
+	pub const SMALL_BYTES: u32 = 8 * 1024;
+	pub const MEDIUM_NODES: u32 = 16;
+	pub const MEDIUM_BYTES: u32 = 16 * 1024;
+
+	/// Returns the smallest tier containing a proof of `nodes` nodes and `bytes` bytes.
+	pub fn classify(nodes: u32, bytes: u32) -> Self {
+		if nodes <= Self::SMALL_NODES && bytes <= Self::SMALL_BYTES {
+			ProofTier::Small
+		} else if nodes <= Self::MEDIUM_NODES && bytes <= Self::MEDIUM_BYTES {
+			ProofTier::Medium
+		} else {
+			ProofTier::Large
+		}
+	}
+}
+
+/// Operational status of the bridge.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen, Default)]
+pub enum BridgeStatus {
+	#[default]
+	Operational,
+	/// High-value messages can only be executed by an elevated origin.
+	Degraded,
+}
+
+/// Anomaly that moved the bridge into the degraded status.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo)]
+pub enum DegradedReason {
+	/// Consecutive updates were accepted with participation just above the finality threshold.
+	LowParticipation { updates: u32 },
+	/// A finalized header conflicting with the stored one was proven for a slot behind the head.
+	FinalityRegression { slot: u64 },
+}
+
+/// Outcome of a dry-run of `execute`, returned by `dry_run_execute` of the `VectorApi` runtime
+/// API.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo)]
+pub struct ExecutionSimulation {
+	/// Hash of the message, the key of its status.
+	pub message_root: H256,
+	/// Status the message would have after the execution.
+	pub status: MessageStatusEnum,
+	/// The message would wait for the previous messages of its ordered channel.
+	pub queued: bool,
+	/// Error the execution would fail with.
+	pub error: Option<DispatchError>,
+}
+
+/// Outcome of a dry-run of `execute` with the name of the error, returned by `simulate_execute`
+/// of the `VectorApi` runtime API. The name is lost once the error is encoded.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo)]
+pub struct NamedExecutionSimulation {
+	pub message_root: H256,
+	pub status: MessageStatusEnum,
+	pub queued: bool,
+	/// Name of the error the execution would fail with.
+	pub error: Option<Vec<u8>>,
+}
+
+impl From<ExecutionSimulation> for NamedExecutionSimulation {
+	fn from(simulation: ExecutionSimulation) -> Self {
+		NamedExecutionSimulation {
+			message_root: simulation.message_root,
+			status: simulation.status,
+			queued: simulation.queued,
+			error: simulation
+				.error
+				.map(|error| <&'static str>::from(error).as_bytes().to_vec()),
+		}
+	}
+}
+
+/// Direction of a bridge message, relative to Avail.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum MessageDirection {
+	/// Sent on a source chain and executed on Avail by a relayer.
+	Inbound,
+	/// Sent on Avail and executed on the destination chain by a relayer.
+	Outbound,
+}
+
+/// Priority of a bridge message, by which the tip of its relayer is suggested.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum FeePriority {
+	Low,
+	Medium,
+	High,
+}
+
+impl FeePriority {
+	/// Returns the share of the execution fee suggested as a tip to the relayer.
+	pub fn tip_rate(&self) -> Perbill {
+		match self {
+			FeePriority::Low => Perbill::zero(),
+			FeePriority::Medium => Perbill::from_percent(10),
+			FeePriority::High => Perbill::from_percent(25),
+		}
+	}
+}
+
+/// Estimate of the cost of bridging a message, returned by the `VectorApi` runtime API.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo)]
+pub struct MessageFeeQuote {
+	/// Weight of the extrinsic carrying the message on Avail, `execute` for an inbound message
+	/// and `send_message` for an outbound one.
+	pub weight: Weight,
+	/// Fee of `weight`, under the fee multiplier the extrinsic is charged with.
+	pub execution_fee: u128,
+	/// Tip suggested to the relayer, a share of `execution_fee` by priority.
+	pub relayer_tip: u128,
+	/// Gas used by the delivery of an outbound message on the destination chain, zero for an
+	/// inbound message.
+	pub destination_gas: u64,
+	/// Cost of `destination_gas` at the latest base fee proven for the destination domain, in
+	/// the native unit of the destination chain. `None` without a proven base fee.
+	pub destination_fee: Option<U256>,
+}
+
+/// Account of a source chain contract proven against an execution state root in the current
+/// block, reused by the next proofs of the same account.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct VerifiedAccount {
+	pub state_root: H256,
+	pub address: SourceAddress,
+	pub storage_root: H256,
+	pub code_hash: H256,
+}
+
+/// Function a `fulfill_call` submission is made for, selected by its function id.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo)]
+pub enum VerifiedFunction {
+	Step,
+	Rotate,
+}
+
+/// Outcome of a stage of the verification of a `fulfill_call` submission.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo)]
+pub enum StageResult {
+	Passed,
+	Failed,
+	/// The stage was not run because a previous one failed.
+	Skipped,
+	/// The stage cannot be run against the local state, like compact inputs following another
+	/// head than the one of the light client state.
+	Unavailable,
+}
+
+impl From<bool> for StageResult {
+	fn from(passed: bool) -> Self {
+		if passed {
+			StageResult::Passed
+		} else {
+			StageResult::Failed
+		}
+	}
+}
+
+/// Outcome of each stage of the verification of a `fulfill_call` submission, returned by the
+/// `VectorApi` runtime API to find why a submission is rejected.
+#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq, TypeInfo)]
+pub struct VerificationTrace {
+	/// Keccak hash of the inputs, the key of the verified inputs.
+	pub input_hash: H256,
+	/// The same inputs were verified already, their verification would be skipped.
+	pub already_verified: bool,
+	/// Function of the function id of the submission, `None` if the id is not registered.
+	pub function: Option<VerifiedFunction>,
+	/// Decoding of the CBOR inputs.
+	pub decode: StageResult,
+	/// The step is older than the head and of no use, it would be rejected unverified.
+	pub stale: bool,
+	/// Verification of the sync committee updates, in order.
+	pub updates: Vec<StageResult>,
+	/// Verification of the finality update, including the signature of the sync committee.
+	pub finality_update: StageResult,
+	/// Verification of the execution state root against the finalized header.
+	pub execution_state_root: StageResult,
+	/// Output of the verification, set if every stage passed.
+	pub output: Option<VerifiedStepOutput>,
+	/// How the verified output would be applied, or the error rejecting it, set with `output`.
+	pub acceptance: Option<Result<StepAcceptance, DispatchError>>,
+}
+
+/// How a verified step output is applied against the head.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo)]
+pub enum StepAcceptance {
+	/// The step finalizes no new header, only its sync committees are stored.
+	Unchanged,
+	/// The finalized header conflicts with the stored one of its slot, the bridge is degraded.
+	Equivocation,
+	/// The finalized header is imported behind the head with its stored sync committee.
+	Historical,
+	/// The finalized header becomes the head.
+	NewHead,
+}
+
+/// Cause of the failure of a message execution.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum FailureCode {
+	/// The message data or its recipient could not be decoded.
+	DecodeFailure,
+	/// The execution reverted with an error of a module.
+	HandlerReverted,
+	/// The transfer exceeded the available funds or a balance limit.
+	CapExceeded,
+	/// Any other error.
+	Other,
+}
+
+/// Failure of a message with the `ExecutionFailed` status.
+#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct ExecutionFailure {
+	pub code: FailureCode,
+	/// Pallet index and error index of the module error the execution failed with, if any.
+	pub module_error: Option<(u8, u8)>,
+}
+
 /// VerifiedStep struct that holds verified params from a step call.
 #[derive(Default, Debug)]
 pub struct VerifiedStep {
@@ -186,9 +750,13 @@ mod tests {
 	use frame_support::{assert_err, assert_ok};
 	use hex_literal::hex;
 	use sp_core::H256;
+	use sp_runtime::Perbill;
 
 	use crate::state::ParseError::ParsingError;
-	use crate::state::{parse_rotate_output, parse_step_output};
+	use crate::state::{
+		check_rotation, parse_rotate_output, parse_step_output, Configuration, PeriodParticipation,
+		RotationError, VestedRelease,
+	};
 
 	#[test]
 	fn test_step_input() {
@@ -247,4 +815,63 @@ mod tests {
 		let result = parse_rotate_output(input_more_then_expected.to_vec());
 		assert_err!(result, ParsingError);
 	}
+
+	#[test]
+	fn check_rotation_requires_verified_header_of_previous_period() {
+		let cfg = Configuration {
+			slots_per_period: 8192,
+			finality_threshold: 342,
+		};
+
+		assert_eq!(Ok(()), check_rotation(&cfg, 8192, Some(342), 2));
+		assert_eq!(
+			Err(RotationError::NotNextPeriod),
+			check_rotation(&cfg, 8192, Some(342), 1)
+		);
+		assert_eq!(
+			Err(RotationError::HeaderNotVerified),
+			check_rotation(&cfg, 8192, None, 2)
+		);
+		assert_eq!(
+			Err(RotationError::ParticipationTooLow),
+			check_rotation(&cfg, 8192, Some(341), 2)
+		);
+		assert_eq!(
+			Err(RotationError::ConfigurationNotSet),
+			check_rotation(&Configuration::default(), 8192, Some(342), 2)
+		);
+	}
+
+	#[test]
+	fn vested_release_is_linear_over_duration() {
+		let vested = VestedRelease {
+			recipient: H256::zero(),
+			amount: 1_000,
+			released: 250,
+			start: 10,
+			duration: 100,
+		};
+
+		assert_eq!(0, vested.vested_at(5));
+		assert_eq!(500, vested.vested_at(60));
+		assert_eq!(250, vested.releasable(60));
+		assert_eq!(1_000, vested.vested_at(110));
+		assert_eq!(750, vested.releasable(1_000));
+	}
+
+	#[test]
+	fn period_participation_tracks_low_updates() {
+		let mut participation = PeriodParticipation::default();
+		assert_eq!(None, participation.liveness());
+
+		participation.note(400, false);
+		participation.note(300, true);
+		participation.note(500, false);
+		participation.note(480, false);
+
+		assert_eq!(300, participation.min);
+		assert_eq!(500, participation.max);
+		assert_eq!(420, participation.average());
+		assert_eq!(Some(Perbill::from_percent(75)), participation.liveness());
+	}
 }
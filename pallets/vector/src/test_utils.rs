@@ -0,0 +1,95 @@
+//! Helpers of the `Vector` module tests: submissions built from the step and rotate fixtures of
+//! `examples` and assertions on the events of the pallet.
+use std::fs;
+
+use frame_support::dispatch::DispatchResultWithPostInfo;
+use hex_literal::hex;
+use primitive_types::H256;
+use sp_runtime::AccountId32;
+
+use crate::mock::{Bridge, RuntimeEvent, RuntimeOrigin, System, Test};
+use crate::state::UpdaterQuota;
+use crate::{ConfigurationStorage, Event, FunctionInputs, SlotParticipation, Updaters};
+
+/// Updater submitting the fixtures.
+pub const TEST_SENDER_VEC: [u8; 32] =
+	hex!("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d");
+pub const TEST_SENDER_ACCOUNT: AccountId32 = AccountId32::new(TEST_SENDER_VEC);
+
+/// Finality threshold met by the participation of the rotate fixture.
+pub const ROTATE_FINALITY_THRESHOLD: u16 = 342;
+
+/// CBOR encoded inputs of `fulfill_call`, as passed in by the operator.
+pub struct Submission {
+	pub inputs: Vec<u8>,
+	pub parsed: FunctionInputs,
+}
+
+impl Submission {
+	/// Step of the `step_call.cbor` fixture.
+	pub fn step() -> Self {
+		Self::from_fixture("./examples/step_call.cbor")
+	}
+
+	/// Rotate of the `rotate_call.cbor` fixture, storing the sync committee of period 1179.
+	pub fn rotate() -> Self {
+		Self::from_fixture("./examples/rotate_call.cbor")
+	}
+
+	fn from_fixture(path: &str) -> Self {
+		let inputs = fs::read(path).unwrap();
+		let parsed = serde_cbor::from_slice(&inputs).unwrap();
+		Self { inputs, parsed }
+	}
+
+	/// Slot of the header finalized by a step.
+	pub fn step_slot(&self) -> u64 {
+		self.parsed.finality_update.finalized_header.slot.as_u64()
+	}
+
+	/// Slot of the header a rotate is proven against.
+	pub fn rotate_slot(&self) -> u64 {
+		self.parsed.store.finalized_header.slot.as_u64()
+	}
+
+	/// Sets the fixture updater and submits the inputs with `fulfill_call`.
+	pub fn submit(&self) -> DispatchResultWithPostInfo {
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
+		Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256::zero(),
+			self.inputs.clone(),
+		)
+	}
+}
+
+/// Sets the finality threshold of the configuration.
+pub fn set_finality_threshold(finality_threshold: u16) {
+	ConfigurationStorage::<Test>::mutate(|config| config.finality_threshold = finality_threshold);
+}
+
+/// Makes the header a rotate is proven against verified by a step, as required to accept it.
+pub fn prepare_rotate(rotate: &Submission) {
+	set_finality_threshold(ROTATE_FINALITY_THRESHOLD);
+	SlotParticipation::<Test>::insert(rotate.rotate_slot(), ROTATE_FINALITY_THRESHOLD);
+}
+
+/// Events deposited by the pallet, in order.
+pub fn bridge_events() -> Vec<Event<Test>> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::Bridge(event) => Some(event),
+			_ => None,
+		})
+		.collect()
+}
+
+/// Asserts that the pallet deposited `event`.
+pub fn assert_bridge_event(event: Event<Test>) {
+	let events = bridge_events();
+	assert!(
+		events.contains(&event),
+		"{event:?} was not deposited, events: {events:?}"
+	);
+}
@@ -3,12 +3,12 @@ use crate::{
 		new_test_ext, Balances, Bridge, RuntimeEvent, RuntimeOrigin, System, Test,
 		ROTATE_FUNCTION_ID, ROTATE_VK, STEP_FUNCTION_ID, STEP_VK,
 	},
-	state::Configuration,
+	state::{Configuration, UpdaterQuota},
 	storage_utils::MessageStatusEnum,
 	Broadcasters, ConfigurationStorage, Error, Event, ExecutionStateRoots, FunctionIds,
 	FunctionInput, FunctionOutput, FunctionProof, Head, Headers, MessageStatus,
-	RotateVerificationKey, SourceChainFrozen, StepVerificationKey, SyncCommitteePoseidons, Updater,
-	ValidProof, WhitelistedDomains,
+	RotateVerificationKey, SourceChainFrozen, StepVerificationKey, SyncCommitteePoseidons,
+	Updaters, ValidProof, WhitelistedDomains,
 };
 use avail_core::data_proof::Message::FungibleToken;
 use avail_core::data_proof::{tx_uid, AddressedMessage, Message};
@@ -63,7 +63,7 @@ fn get_invalid_proof() -> FunctionProof {
 	BoundedVec::truncate_from(hex!("1b496d04c0e12206bc846edd2077a20b8b55f65fc0e40bb8cf617d9b79ce39e508281ad49432300b3b7c8a95a0a63544f93f553fcfdeba38c82460888f4030ed1f67a1be666c12ee00658109c802042c58f645474fcee7d128277a4e35c1dd1504d33cb652ec23407cd3580eda0196dd97054eb5c2a817163d6997832d9abd422729b3e85a15941722baeb5ca8a42567a91c6a0b0cd64ac15431fde05071e90e0d30c12013d5803336cc2f433c16eaa5434e30b89ce7395c3c3cda29dde3be062281095f143d728486c71203b24fa6068e69aabf29d457ffadc6d682d51a4f08179d3240bc561ae7e2c005bb772a4d4c5ba6644986052fad554f042ab0074a8f").to_vec())
 }
 
-fn get_valid_account_proof() -> ValidProof {
+fn get_valid_account_proof() -> ValidProof<Test> {
 	BoundedVec::truncate_from(vec![
         BoundedVec::truncate_from(hex!("f90211a00b9369252d91d5240966845819a5e73a8e7b4b531b9f66bd652df2ef25999e8fa00fbedaed13519e0d8971ca3e8f54fa6250b8c60ae522924ea5b7bc54d7ab049ca0e9a41917172ecd79fbbc0db3c117a990464df2767be84bc5fd749cb50763834fa0420cf2bd2e7aac8cc33b0731d7546b738315ac482524aebc60e39a7d0359ce84a007e78eae37bbc3d25305685e8321582390d9631365f5c40612d111c7c1d2a35aa0f90094ebbf4d71dc00d9ff469a149d62529289b7a88d63f69ef73c45ffadd9b8a0bb5c4d709b179a0dec8c3e298249746c5f36f1ab4c7bb0985e4c565bfa96efd2a0b44d9462647f7f99afe5396fc6c049ca3d343748d9eb27f64e118a4d55d26d2ba08119035469c78ffe7336ba8c0117906ca64c9827f16ef71b5fcd99272d983779a067a02fa9917bda0acfb3646ba7e91c7e54e56e40342c552c0b069f949a64e122a0d2b39ca49bbaf2582e8c48243c86249ef034d14f8272952975c1f01773d508c4a0cd89136cd6f4203f52553a6856ba48ee08fe3d0165b5d020b4781ec0b0195bfda0bfdc0af814301e39e11e3c2186b3969b5c6ffdcb627d99f2e1e425d48300253ea0689a72bc1f0084b35849c938c2298e0d90384a071b6ef7bb145dab1b1205efffa0614a4f66f2451debf003b8584d9c2bbb87ee1d141cb240b494a840a91d32e126a01885c00eb4d6365b341f3c21bc44754f901ff56e957399069209b612c272a97880").to_vec()),
         BoundedVec::truncate_from(hex!("f90211a0c3c1510781a6c8ba9dae550cd691248cbfe8e3071987f0da92f13d03402e68b7a0267ee923bf851b5f9776eebb0c09be120dcb22c804c9478542bb665b2d694d87a0437a44f13d49bbdcd7106f95b62b105478a4709bea251824a1058d082977ecfda03d257aafd460d893e9713d085a389be271e3162420802e7b8133e8f1f71dc38fa01c07a616c35b484e4abd791a6aecd9ece7d107a90d110754afe973b3d6b1bbf5a0e51e122b66863d3132ff63c7cacbb4d4449c788076e9f10f3d28de3ece92f5dea0521e7a7eee71a2d2855e898ba4a610b0fd6ce57bb4adf8e5d2634e033612aa5aa0c1401fa14ded62f7e1a695d505f4148beccd2be22bc25730ef75a5c94ff680cea0bda976ea47bd694d0cd116dc1aae5e56b8333b3d6b053ea8ded30e656725bfdea0e581d8d0e4b3b5ab0d441e1bec84eb205ab2f55ff06354dc47c3f53e3e91c79ba066a53d18e20d26fb3c45305e29d2cbd1ae40fe17f63eeae8ae1d2190a1d91fcda00df4111cf09b03686b76e192425627c394d7e75ac0dc96c6612366b8d68d3340a0c96d1de5da569fd8b08757d8393dca4a3000e001e674deda10d3072b1f8fff25a06ae859e5f232fd752442a5927c5433aaccd122971f59aca10c2ace8a46e5d9c5a0ad8a4773ddacac3510ee6c397f448fa9fd8f70b51d720d424313d6537f64b5eea0bc11c88bcccaf2086de82fe31e9fc2d225563c9252cd8a1b1660399627e642a980").to_vec()),
@@ -76,7 +76,7 @@ fn get_valid_account_proof() -> ValidProof {
     ])
 }
 
-fn get_valid_trimmed_account_proof() -> ValidProof {
+fn get_valid_trimmed_account_proof() -> ValidProof<Test> {
 	BoundedVec::truncate_from(vec![
 		BoundedVec::truncate_from(hex!("f90211a03f67cc4693a599249b84bfeff3a5d12e853bf772ae066a77221736d723559d1aa06d2b1cc23f415ff8ad546eee0b17411ad19b57701b85f96e27e6ba5724f9c69ca02e682d0fcb1bc2e30cb30db360871870f20766f38878970c52077474b4bb302ea0f1fd41727a86c80436ab4338b4a1f189f02591b7faf7487ee424c71e80bd77a7a0195c17c74a608f2848526a9ef4b81d1eab7f1d9906e74f706bb2d99cd557e9f9a0fc31be6f33b1f4655add8ca1f92fc6ac823db5b0e5d384545f639c199f9b1412a0bc16f1b8369608a15f4e425fb72d5d919b625016ea2765efd937b81b2fb95f7ca0300ab398a0037c592cf9a6d21c5d1bbe480b73f88c8b1c6f67796cf6af06d15ca0ce1ac3af168abe59b356c182c4823f869f26658f0e40d140ada95c804b066f70a0f6db7ed6b431338769f86f050543d6dfef570b52ab2ac677be95e789b9fa6cdfa036a2de51d9b52bcfe611a9b6e60e6fb66da89e7f199dcdb94ad3c8ff231d991aa042846d291fc4e1028d3c984ff8c0dc0602183b9ac72e8f1a7e47655acd4d5b8aa0b6875189e1e5fce8d06ecb5de139af5cddc9bf6cb945a165fc68b7311e2b692ea076ed2532d8a35f6a813e66585380f1368288e64512b2f970edd5905855493feaa06f26c65a9131dcd09e52ceceec1c91dfe04ec2e055ede6f6ceb24c1d297d46a4a0ce8e3c86b6d650cffb18b64325117513fb823c8872fbdcb3979435ba37cba25e80").to_vec()),
 		BoundedVec::truncate_from(hex!("f90211a0bd2e3689d300a30100a2329f8402e73dd245a1c669bb160aab470a284abbe982a0b25da6bb9e7831fbd9e48fbf4874de13785c60e60f7fd2425da0a89d0f790578a06d8564eb4a5870a4b99321423ef15918e64293489c05fe87780a547f723c7d16a0d6ab8cfdeb467341f0d1f2576f8e574cf6535bbc29b589e7676f6be1561820d6a006f5e3dc5ccb84eb9c7ab28b62118a33218829543f69f0ed3f88984a8cd3de82a0caa12557af64ae8c5b84a63d0f7300a33a7536c85ad39b87bd12ff0c7b4c1530a06cfcee7f8123c7914f4920bcd21d1fdde4a5f912b4d2c03224bec6e0f32af487a0ee943defe13e21b3ffcd1d8e70c324dd796df825edc27d27f342add5cfe0bf21a00825681a49714a705942453e89ac474023716f7e94b72ea8212f991d4e3ea8e2a0a793299ac09099832c69cbf8abeb8fd270fc3bd156063c49a39134d44905c047a08eaa7057951e3b83488d09f5fcaabcf4f3bb20974049d1a4a3531d0540ea5ab2a02edfe21f3ab3a99cf37dbc28d22ca51c4e047294f45cf444277ffba450638e18a0330a8cb44a696e8d530641831f5be9e7cefbdee0720c6cb7ecf65d5062a67f14a0c7a27629e9d3d0fdbcdad8098b318eec1b124c0bfce2e72d5d793359c5985ea8a0e5f686ba4b1a9505ae8b9585fe7859f25a03c771b3de08b73395acb3fa0737a2a0c2f391630cbed30ed21eb8f19dc978836795fbeb659f3cb33ca984986c65859980").to_vec()),
@@ -89,7 +89,7 @@ fn get_valid_trimmed_account_proof() -> ValidProof {
 	])
 }
 
-fn get_valid_trimmed_storage_proof() -> ValidProof {
+fn get_valid_trimmed_storage_proof() -> ValidProof<Test> {
 	BoundedVec::truncate_from(vec![
 		BoundedVec::truncate_from(hex!("f90211a00b32abb8354c7486f39ed2ac1152624ef001bffb0ce0eb7c31ae063df789f994a080bb0ddb0ad0557859ba0c2ef1e2d67e4461b379043ec91ecebbefab53d569b3a06734318e802e53ba9f909ab03ede43bbf05c0aa1dee2affea86e533c49aaefdba072a71576a3efe0a39495f056c24ed4f0bcd11355d850e737887a456d7fb0bca9a0c379788ccd4ceb4a915d30c03258c61725cf57132f00c4653db0214a39cd04d0a01d5aa540d5728ae4900a82aca7f240ce98538d196cd2dc92a70c3f7dfee8c5aaa07e2e97a62072a02c650aec15bc75f36a8dde008e1f6bd2592129ba6c41493f43a091fbeea8e97fd76afdea2d1a92b7f3140b5165923403b9917f527e74359dcabea08dbd10c6ed681079481c92ac3687049653d391c8269c25d83538081fa8a8bc76a05e0f867c10c0fe18ed170d68546b82a1171326368e7b7405a20b252b15498e1ea056b131482acba838f00027ca6ea75079d1b1485894a19ec4512ba6b1887e8148a0fb123da682ae4a2214681e7793801c3e5167591d97b072e851f5f9112c79f6bfa0a15859022edd732924fc5192e0fd93c758f463e97d0f765a0b3ff3c0cd5d3a60a0cbf4df8376c85940b02f98c851db0182b25302f5658e43871e9c6511f3e88a25a020f58bc2c7cafb6460830aaaa5c41a9567ede0b636a20fa04593004fe43ecf7fa0059c2b1df5bcf7ae85f61f51182275c3b6bff4fe482c714e7c3f50516a933b3780").to_vec()),
 		BoundedVec::truncate_from(hex!("f90211a09900b767b77271c9af3fc54de094d91dc7b08650aa756ada2800ab79148eefbfa008fb501eeef4ce577c036c664f9d9275309d155c8aa7e96572819f389405ce5da05a9206d832ec4df61192e2ff2c5eb9efebe77e29a54a3dd4bd7fe6212110c18fa0e318d4044bde6cb11dd358ad9dbee4d763ec5ca946192ba924049bf15b23329ea06b60d1fbd395bcccdcb6f22f0fd19e6233043710743a1bf030fcc5921d10ac12a04073234ea2baa8e1eda5ee3abefb70d575786de6565267d90c659e0917010e19a0ebfe792b17dcdcbc456259f76d9af665eb34ef5fa464f51959886136543ea8d6a055ca87cc8ffa31c826b991fc30419bcd1067194eab1b1fb2023688656006782fa07db930f6a48aa842be5608d2dd099b7fed997058763b33e6419062e65cf92353a07be0780df1edede41c7795e9747d20173e12c3c4c06521edf6b4354a871ac0f5a01fb962cc28068fa0cb59e4ee2912e7890e301efadd34d389c80404f80c87c486a0597f19d7d15226fa7410817e8be23adc3cadfa01550f28b62b094d7fda48a7fba06a4b5abfae8b92f163863f644cc3f0c1cc2f75cbbe31ac80c7f8ad71f277b8f5a05d1213906174d263996d60b4d136e3d24387226166abcca5798f46c8bd1a2214a08258fd3c37c7ef5649c3f1591540aca89dfd39345a17d98775da9208e8d8a19ca0000fb606926d0ece17aa61bea6e4c7e09c14d85564cc0de1c9ae98d41d6985e780").to_vec()),
@@ -99,7 +99,7 @@ fn get_valid_trimmed_storage_proof() -> ValidProof {
 	])
 }
 
-fn get_invalid_account_proof() -> ValidProof {
+fn get_invalid_account_proof() -> ValidProof<Test> {
 	BoundedVec::truncate_from(vec![
         BoundedVec::truncate_from(hex!("f90211a050da92c339db0b71cd6a8ac7893a6b8689ec5a3a46a0231b3ee2bd1baee75e1da045a3d973eb74a02b762d8b1ba683f39bca3965806276c8ceffe2d2ebc6cce233a0e88ad29ca98fa08f59f2a7f0110d63505d99a173628643290df869c4d1fa312ba00bb4cc9dc0b1de6ae0d80424b1fa992efb400a07a0e84615c91762fe734b2d0ca0a07e495d39bf2b779405790c6c7e7eb1cc3c803a88db36d1ec600fb0e555b5bba09a1c776e89c8be75d0a9ea022c05fd2ff095869d549e74a8fff7f2fb2deaf738a073b874e49e77dfd9312d7b1afd1ac10e02021a1ba2ab7c97ecaeaa0e26a34027a07e3424405c13aa33a2eb9ec6d8640aa1f67fdd8c8e9e4276334515b1cf1df65ca0246b93b2e3cc625a5e75b40165c6cb95ae8ffb9406563d34092d6359c7616aeea04d2fd8fdb1ab7d8f8fc6079400396fec828914230fade3794f13dc5ae7f6bbb8a04811b9efbfa8d495c5be91be78372b4a29140bd1e092e793db50ed9c495a6d54a02e1b3a417e8341dc8e1ade6ca527778192d33c7c827cfa63a366d007f2884e24a0845f4f33a4993d85766a14222cde1d124bd0f15523d239572883258a7bbcccd9a0ed2021cc2206fcfd9f80d592890b1b4eb615fae4f11d4e4a66d54a6767908901a07d46bf6e9dc9599eb7ca036aa976ef9cc63f02e9097252799f5d3a8792c49620a00b58d1d2cc72401c7cb978d34e15f74038ac63355e415d53b894179b8938dbb780").to_vec()),
         BoundedVec::truncate_from(hex!("f90211a0f7c14d7714348be36359dd28afd64e2fb72679a7ae09a27027fc95e335bcde1ca0824329840722c728e0f19ae424caad4581ac42015a4ab8e9d3ea550c857da804a040d48c9df564c00b11d304e2a2597a35b17b25429c3850c4e3fe4e9a278bec88a0a497297590785cfaa8491579745c077b1095348912d4e3288d8f00857ed9db5da0b0ea3abfcdab8c6cf03152cc7a57f602f85d86f4bdb3d1ca2242a5e737561bbda06bbe0e0416b59f1c4cba36afdee766ea4689f1c1ac8e2245f45c2631e2478119a0222dec72b36685a0ca89e49ce87262957f7f891e695ea8ec52e25fbc3a328589a00b3cac878feb2bcd5fc3d49fe5f607eabf75f014df74a268d4aaa1d25654d030a000deffa5e2879748ef9a634a3573484b4dd259c0d4c10453a7e1e3504b56322ea05c356b24b3b36089583f650cb954f884b05275b09b7715a2eb3cf6fa9175738ea093abf2b2cb15649c192d0d79f62627ce634843f84ec98eee99267c1354b5135aa059e9c60388154b3b810ffd41f81ed9128c8091a12e0c53062d9e7430fedf5939a06855c9a5622a40b5bce572522e4774986c7061557d2f1b8f7070d8d397888b4ea04d220a5fb22e38d64cdf4b46a42898b9f1ce9f316f1d332eebebd32c0cc59000a09004930139d4ae94070b29245230d5b28b25ac59c11339928a2eb547f0828341a00f37af44fb487a5ed675e12f0566a54e59cc025466e91cf56dcf348ff4049ed980").to_vec()),
@@ -112,14 +112,14 @@ fn get_invalid_account_proof() -> ValidProof {
     ])
 }
 
-fn get_valid_storage_proof() -> ValidProof {
+fn get_valid_storage_proof() -> ValidProof<Test> {
 	BoundedVec::truncate_from(vec![BoundedVec::truncate_from(hex!("f90131a02aa432bd6022e7da6d0188f41427645658b74a08227a036f80fa44bff9fc57af80808080a0c01b2d5e61b71c73bc7b1b73db39a4d89bea07f4594557fec2242da9c90c91a580a073768754f5d28b5a762b44f6c37233fbedbeab05779b7dfb407ede56d2812891a01f3fcf34e7a10de63f5fe300d0522504aa29e6a4a7ad39df02bfa94e958e5e458080a08f056b51124e5c81f7d86b4364a97f66324957dc1042f751bce474abfa1480e5a098b414db83b4c1efa80ad36b641c9c517b2225cb212fc236116b2f9dc69ce64fa09a0e693399670076dd7708930bdc19b9101b3f181c4bf62d25edf6e1fd6e881da079866ac4ff54c3062d8fbd4fa347961e9a905b4114a2ed9785e22a5c03f4ffb8a03a1e44d3669992d3ac3f750a1159442ba1d83432cb03e5815d7eed7d97c7e90380").to_vec()),
                                    BoundedVec::truncate_from(hex!("f851808080a08ddae10e810d2127e5d527cab77909cdc5b99e20544edfb6f0c7b46033fe17e080808080808080808080a0c43b94283974430fdacaadaea093cd1524e306af5fc8c15ae3b13528d8f332088080").to_vec()),
                                    BoundedVec::truncate_from(hex!("f843a020b5be412f275a18f6e4d622aee4ff40b21467c926224771b782d4c095d1444ba1a0efac9989593dfa1e64bac26dd75fd613470d99766ad2c954af658253a09d1ad8").to_vec()),
     ])
 }
 
-fn get_invalid_storage_proof() -> ValidProof {
+fn get_invalid_storage_proof() -> ValidProof<Test> {
 	BoundedVec::truncate_from(vec![BoundedVec::truncate_from(hex!("f90211a0f0a16ee9b11528f3da8796229dad134b9085ed9428d868e6988f9b2473b59d6fa0f8175015d0a3df8fc451d2bd3d64a34e0836f3203129ac567e869f1157b488dfa0f9d56e943c6962cf8e2ca51b94b54307eb45424ebb84ed079b417cf03a85e298a0408af9f1c5f64ed6c517b1dbf661b75a705ef7d78bcae67b9a54c1e8052b56b2a02157d476a9a077cfc9eb00ead5ab65dcbfe363a71e993c3602a66c0fccf13e4aa00772697ebf25f2e83830918bd52bbb9600c077ae289e740ae76c7bdfd34b7ebea0a1dd0da76aacf7c82629c55e4b956b2e9ef77d7fdcee1adeb23d022f0950d554a0695cb723c857d98ad1c96a372f7983bf771556f4608674266a0698531543217ba05c0fb347305720b81c7d39be6fd5b2083af607654098a0f1418ec111a846510aa0ecd30808bffcb164a258c332a29f3050e9e85d28e988305b7f643dcad4f32c8fa0ec5ee93a7ede0a9c641dcd7515c1408ab48f86b5295cd26b3d738e8d8ac7829fa01434a5f6054456bbce0a59ba1c182eeee8e64fd6762ff365e550ca7cd8cedad0a0b4fefcb325f044a6663c9441ec9f025718d0f2d7fc1c29ec819f4a366cafbb6fa0cc26bfb18151569b0f765335474fa3840f9093385816bd14a4a3c553fae62949a06a28c02f7b649bad24b39d9a4e9fc4c8e93b1ae2b043af4f5bbcb8238e193eaba011ef889094bf6ca740810423041169453b7daea3df98b3018523f86e96bf033580").to_vec()),
                                    BoundedVec::truncate_from(hex!("e219a0053d037613f1c22bb588aaa70237b3798774d2b20413c686e2263daef21ec226").to_vec()),
                                    BoundedVec::truncate_from(hex!("f851a0c45dca792d516550b57f7f31e33c67f0e6debfe0bdb3076fe0078c65c5afbf8280808080a022e43fa2c06d3d498253aadec7a7db94183eec2aabbdf2afc67a45107d19932b8080808080808080808080").to_vec()),
@@ -127,7 +127,7 @@ fn get_invalid_storage_proof() -> ValidProof {
     ])
 }
 
-fn get_valid_amb_account_proof() -> ValidProof {
+fn get_valid_amb_account_proof() -> ValidProof<Test> {
 	BoundedVec::truncate_from(vec![
         BoundedVec::truncate_from(hex!("f90211a00b9369252d91d5240966845819a5e73a8e7b4b531b9f66bd652df2ef25999e8fa00fbedaed13519e0d8971ca3e8f54fa6250b8c60ae522924ea5b7bc54d7ab049ca0e9a41917172ecd79fbbc0db3c117a990464df2767be84bc5fd749cb50763834fa0420cf2bd2e7aac8cc33b0731d7546b738315ac482524aebc60e39a7d0359ce84a007e78eae37bbc3d25305685e8321582390d9631365f5c40612d111c7c1d2a35aa0f90094ebbf4d71dc00d9ff469a149d62529289b7a88d63f69ef73c45ffadd9b8a0bb5c4d709b179a0dec8c3e298249746c5f36f1ab4c7bb0985e4c565bfa96efd2a0b44d9462647f7f99afe5396fc6c049ca3d343748d9eb27f64e118a4d55d26d2ba08119035469c78ffe7336ba8c0117906ca64c9827f16ef71b5fcd99272d983779a067a02fa9917bda0acfb3646ba7e91c7e54e56e40342c552c0b069f949a64e122a0d2b39ca49bbaf2582e8c48243c86249ef034d14f8272952975c1f01773d508c4a0cd89136cd6f4203f52553a6856ba48ee08fe3d0165b5d020b4781ec0b0195bfda0bfdc0af814301e39e11e3c2186b3969b5c6ffdcb627d99f2e1e425d48300253ea0689a72bc1f0084b35849c938c2298e0d90384a071b6ef7bb145dab1b1205efffa0614a4f66f2451debf003b8584d9c2bbb87ee1d141cb240b494a840a91d32e126a01885c00eb4d6365b341f3c21bc44754f901ff56e957399069209b612c272a97880").to_vec()),
         BoundedVec::truncate_from(hex!("f90211a0f965da070dc0173a61d34b21bea4dfde26c834257629677157ae7248b50cd81fa03a76449b49477746172ed14e2f996a8131b3e23de3315a1923f38b7ea415b979a0d074384d170d5abab0c1d9ef28cc3c0ff8f36851a7bcbaef501c6032df0943aaa0149b7581ab9bc820217566d4304c13c418893863964f1b2290390f2f4f9c07baa067bc808de655c5710908cbb592420df656ddb294a0ca123c5d1c074e977175aaa02965ae45e7ec302c8d1d24d07954074343df8247da2f6122939181ca3f187dd0a0edbf4144f7d295047f49dd10e218aa3d590cb406312310b0ef56184c1d046822a0915dbafe8ba1d369a3e24375095596455fbc9aacc468958f6928ce428ac31967a0b9e44b513ed8ca8ee7409e399c4a467dc7d260c28d1fba4685852f5e3b65f4d8a09567dcd6366635567c2e61fe0d984516a77a4b0c204bd9b2f85ff343216780afa08390c69ab6052b335f241b5b187af412395910213899dbbf6084987846204c7ba0d38a47f0e0b2b6267a7e360308cbe0ab49e22a0a8615439d3423d6c363080ff9a083e7617b1a68407db284cd4035e04b0637b727c949205cd9ed176319cb9c0e52a061503e01e7f1d1fa6ef925e561be0a4a6fd580263da8f811121b90b87708e562a09c0414ab3be0fee449c042627dac5fd0f3d228b53499a0fc181045f873e3403ca092962b27d984b35f646fa84d3ef49830c5ee8fb0d6db97a912aaf21da043187580").to_vec()),
@@ -140,7 +140,7 @@ fn get_valid_amb_account_proof() -> ValidProof {
     ])
 }
 
-fn get_valid_amb_storage_proof() -> ValidProof {
+fn get_valid_amb_storage_proof() -> ValidProof<Test> {
 	BoundedVec::truncate_from(vec![
         BoundedVec::truncate_from(hex!("f90131a0358aed89bfaa22b0179d1015fa6034c7ad29702af20b05b02f3cf63f69c2811280808080a08c857f53d31e0c1b681ff481d435a48986faa0615d611b05db5d0b3f4691f6d1a0d40ba3e2c5ca0722bb231d4a661b452920758ab202dbcfbc8e505d9f436e5600a0c3d20e38630b6b8f49ea6ca919b9077081557a4ca337c374e42219e4738a8970a0d2059c3c7e4dacb02dec49822ce85568a4419849a093ec956598dc2c3269238d808080a08c675371a85fc8f524a707007b05d0b87d7ecbde37f10e953a03da38431d31f8a0079a6b40f411dbc043c20594864f4e7f3e5ef2ef8e27f230c3431ec771b2d001a079866ac4ff54c3062d8fbd4fa347961e9a905b4114a2ed9785e22a5c03f4ffb8a0fef4138a6a9993fb0418e252583a03d2586caf404c7a16f7083600f49aac8cf280").to_vec()),
         BoundedVec::truncate_from(hex!("f851808080a0aec544652aa67b55271eec87a45f5ca89f6a6ea762450ca63b014ceb073e4e9d80808080808080808080a08c06dc4d3d3e8d7fe5a8a88222594ba9f4cdb19baaa8e60919b5617770423f828080").to_vec()),
@@ -183,7 +183,7 @@ fn get_valid_amb_message() -> AddressedMessage {
 fn test_fulfill_step_call_proof_not_valid() {
 	new_test_ext().execute_with(|| {
 		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
 		ConfigurationStorage::<Test>::set(Configuration {
 			slots_per_period: 8192,
 			finality_threshold: 461,
@@ -206,7 +206,7 @@ fn test_fulfill_step_call_proof_not_valid() {
 fn test_fulfill_step_call_not_valid_function_id() {
 	new_test_ext().execute_with(|| {
 		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
 		ConfigurationStorage::<Test>::set(Configuration {
 			slots_per_period: 8192,
 			finality_threshold: 461,
@@ -231,7 +231,7 @@ fn test_fulfill_step_call_not_valid_function_id() {
 fn test_fulfill_step_call_finality_not_met() {
 	new_test_ext().execute_with(|| {
 		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
 		SyncCommitteePoseidons::<Test>::insert(
 			931,
 			U256::from(hex!(
@@ -260,7 +260,7 @@ fn test_fulfill_step_call_finality_not_met() {
 fn test_fulfill_step_call_wrong_updater_address() {
 	new_test_ext().execute_with(|| {
 		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
 		ConfigurationStorage::<Test>::set(Configuration {
 			slots_per_period: 8192,
 			finality_threshold: 461,
@@ -605,7 +605,7 @@ fn test_execute_message_with_unsupported_domain() {
 fn test_fulfill_step_call() {
 	new_test_ext().execute_with(|| {
 		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
 
 		SyncCommitteePoseidons::<Test>::insert(
 			931,
@@ -668,7 +668,7 @@ fn test_fulfill_step_call() {
 fn test_fulfill_step_call_wrong_poseidon() {
 	new_test_ext().execute_with(|| {
 		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
 
 		// current poseidon is not the same as the one in the valid proof
 		SyncCommitteePoseidons::<Test>::insert(
@@ -700,7 +700,7 @@ fn test_fulfill_step_call_wrong_poseidon() {
 fn test_fulfill_step_call_slot_behind_head() {
 	new_test_ext().execute_with(|| {
 		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
 		SyncCommitteePoseidons::<Test>::insert(
 			931,
 			U256::from(hex!(
@@ -733,7 +733,7 @@ fn test_fulfill_step_call_slot_behind_head() {
 fn test_fulfill_rotate_call() {
 	new_test_ext().execute_with(|| {
 		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
 
 		ConfigurationStorage::<Test>::set(Configuration {
 			slots_per_period: 8192,
@@ -780,7 +780,7 @@ fn test_fulfill_rotate_call() {
 fn test_fulfill_rotate_call_wrong_header() {
 	new_test_ext().execute_with(|| {
 		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
 
 		ConfigurationStorage::<Test>::set(Configuration {
 			slots_per_period: 8192,
@@ -810,7 +810,7 @@ fn test_fulfill_rotate_call_wrong_header() {
 #[test]
 fn test_fulfill_call_function_ids_not_set() {
 	new_test_ext().execute_with(|| {
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
 
 		Bridge::set_function_ids(RawOrigin::Root.into(), None).unwrap();
 		let slot = 7634942;
@@ -830,7 +830,7 @@ fn test_fulfill_call_function_ids_not_set() {
 fn test_fulfill_step_call_verification_key_is_not_set() {
 	new_test_ext().execute_with(|| {
 		Bridge::set_step_verification_key(RawOrigin::Root.into(), None).unwrap();
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
 
 		let slot = 7634942;
 
@@ -863,7 +863,7 @@ fn test_fulfill_rotate_call_verification_key_is_not_set() {
 	new_test_ext().execute_with(|| {
 		Bridge::set_rotate_verification_key(RawOrigin::Root.into(), None).unwrap();
 		let slot = 7634942;
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
 
 		ConfigurationStorage::<Test>::set(Configuration {
 			slots_per_period: 8192,
@@ -1345,12 +1345,17 @@ fn set_rotate_verification_key_does_not_work_with_non_root() {
 fn update_updater() {
 	new_test_ext().execute_with(|| {
 		let old_updater = H256(TEST_SENDER_VEC);
-		Updater::<Test>::set(old_updater);
+		Updaters::<Test>::insert(old_updater, UpdaterQuota::default());
 
 		let new_updater = H256([2u8; 32]);
 		let ok = Bridge::set_updater(RawOrigin::Root.into(), new_updater);
 		assert_ok!(ok);
 
+		assert_eq!(
+			Some(UpdaterQuota::default()),
+			Updaters::<Test>::get(new_updater)
+		);
+		assert!(!Updaters::<Test>::contains_key(old_updater));
 		let expected_event = RuntimeEvent::Bridge(Event::NewUpdater {
 			old: old_updater,
 			new: new_updater,
@@ -1363,12 +1368,10 @@ fn update_updater() {
 fn update_updater_non_root() {
 	new_test_ext().execute_with(|| {
 		let origin = RuntimeOrigin::signed(TEST_SENDER_VEC.into());
-		let old_updater = H256(TEST_SENDER_VEC);
-		Updater::<Test>::set(old_updater);
 		let new_updater = H256([2u8; 32]);
 
 		let err = Bridge::set_updater(origin, new_updater);
 		assert_err!(err, BadOrigin);
-		assert_eq!(old_updater, Updater::<Test>::get());
+		assert!(!Updaters::<Test>::contains_key(new_updater));
 	});
 }
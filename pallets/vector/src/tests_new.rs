@@ -1,21 +1,37 @@
 /// Tests for Vector that use CBOR encoded Ethereum light client inputs instead of ZKProofs.
 /// Adapted from corresponding tests in src/tests.rs.
 use std::fs;
-use frame_support::{assert_err, assert_ok};
+use codec::{Encode, MaxEncodedLen};
+use frame_support::{assert_err, assert_ok, dispatch::{GetDispatchInfo, Pays}, inherent::{InherentData, ProvideInherent}, traits::{Currency, GetStorageVersion, Hooks, StorageVersion}, weights::Weight, BoundedVec};
 use hex_literal::hex;
-use primitive_types::{H256, U256};
-use sp_core::crypto::AccountId32;
-use crate::mock::{new_test_ext, Bridge,RuntimeEvent, RuntimeOrigin, System, Test, ROTATE_FUNCTION_ID, STEP_FUNCTION_ID};
-use crate::{ConfigurationStorage,Error, Event, ExecutionStateRoots, FunctionInputs, Head, Headers, SyncCommitteeHashes, Updater};
-use crate::state::Configuration;
-const TEST_SENDER_VEC: [u8; 32] =
-	hex!("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d");
-const TEST_SENDER_ACCOUNT: AccountId32 = AccountId32::new(TEST_SENDER_VEC);
+use primitive_types::{H160, H256, U256};
+use sp_core::{crypto::AccountId32, Pair};
+use sp_io::hashing::{blake2_256, keccak_256};
+use sp_runtime::{traits::{BadOrigin, One}, transaction_validity::InvalidTransaction, BuildStorage, DispatchError, MultiSignature, Perbill};
+use crate::mock::{new_test_ext, Balances, ExtBuilder, Bridge, CurrentSession, EvmCalls, EvmContracts, ExecutedMessages, HandledMessages, MaxMessageLength, ReceivedMessages, ReportedEquivocations, RuntimeEvent, RuntimeOrigin, System, Test, UnstakedUpdaters, FAILING_HANDLER_ID, ROTATE_FUNCTION_ID, STEP_FUNCTION_ID, TEST_HANDLER_ID};
+use crate::abi::{AbiType, MessageSchema};
+use crate::ack::{Acknowledgement, ACK_ID_FLAG};
+use crate::address::AddressVersion;
+use crate::archive::{archive_proof, verify_archive_proof};
+use crate::attestation::{self, SignatureKind, SignatureProof};
+use crate::fees::{self, Multiplier};
+use crate::finality::{self, FinalityConfig};
+use crate::inherent::{StepInherentData, INHERENT_IDENTIFIER};
+use crate::nonce::MissingNonces;
+use crate::payout;
+use crate::rollup::{RollupConfig, RollupLayout, RollupProof, StateCommitment};
+use crate::beacon::{self, BeaconRootProof, BeaconRootsProof, ConsensusFork, ExecutionBlockNumberProof};
+use crate::outbound::{outbound_proof, outbound_root, verify_outbound_proof};
+use avail_core::data_proof::{tx_uid, AddressedMessage, Message, MessageType};
+use crate::{weight_helper, AbsentMessages, AcceptedHeaders, AcknowledgedSenders, AppChainRoutes, ArchivedEpochs, ArchivedSlots, Attestations, BaseFeeOracles, BeaconRootDomains, Broadcasters, Call, CompactFunctionInputs, CompressedProof, ConfigurationStorage, DestinationHandlers, DomainAliases, EpochSlots, ExecutionBlockNumbers, Error, Event, ExecutionFees, ExecutionStateRoots, Finality, ForkActivations, ForkEpochs, ForkTransitions, FunctionIds, FunctionInputs, GenesisConfig, GovernanceCalls, GovernanceSender, Head, HeaderTimestamps, Headers, IdleExecutionCursor, LightClientContext, LightClientState, LowParticipationStreak, MessageBatch, MessageFailures, MessageHeaders, MessageLengthLimits, MessageSchemas, MessageStatus, MultiProof, NextArchiveEpoch, NonceWindows, OrderedChannels, OutboundAcknowledgements, OutboundMessages, OutboundMessagesRoot, ParticipationHistory, ParticipationRequirements, PayoutSenders, PinnedCodeHashes, ProofArtifactExpiries, ProofRetention, QueuedMessages, ReleaseSchedules, ReorgRecovery, RollupConfigs, RuntimeCodeHash, ScheduledFunctionIds, SourceAddressVersions, SourceBaseFees, Status, SlotParticipation, SyncCommitteeHashes, Timestamps, Updater, Updaters, UsedPermits, ValidBeaconRootsProof, ValidProof, ValidRollupProof, VerifiedAccountProofs, VerifiedInputs, VestedReleases, VoidedMessages, WhitelistedDomains, MAX_PARTICIPATION_PERIODS, SUPPORTED_ASSET_ID};
+use crate::storage_utils::MessageStatusEnum;
+use crate::state::{AcceptedHeader, BaseFeeOracle, BridgeStatus, Configuration, DegradedReason, ExecutionFailure, ExecutionFee, ExecutionPermit, ExecutionSimulation, FailureCode, ForkTransition, GovernanceCall, HandlerRegistration, MessageDirection, MessageHeader, ParticipationRequirement, PeriodParticipation, ProofTier, ReleaseSchedule, SourceBaseFee, StageResult, StepAcceptance, UpdaterQuota, VerifiedFunction};
+use crate::test_utils::{assert_bridge_event, bridge_events, prepare_rotate, Submission, TEST_SENDER_ACCOUNT, TEST_SENDER_VEC};
 
 #[test]
 fn test_fulfill_step_call() {
 	new_test_ext().execute_with(|| {
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
 
 		// These inputs, encoded in CBOR format, would be passed in via the operator
 		let inputs: Vec<u8> = fs::read("./examples/step_call.cbor").unwrap();
@@ -71,7 +87,7 @@ fn test_fulfill_step_call() {
 #[test]
 fn test_fulfill_step_call_slot_behind_head() {
 	new_test_ext().execute_with(|| {
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
 		let inputs: Vec<u8> = fs::read("./examples/step_call.cbor").unwrap();
 
 		// move head forward
@@ -92,10 +108,72 @@ fn test_fulfill_step_call_slot_behind_head() {
 	});
 }
 
+#[test]
+fn test_fulfill_step_call_already_verified_is_refunded() {
+	new_test_ext().execute_with(|| {
+		let other_updater = AccountId32::new([2u8; 32]);
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
+		Updaters::<Test>::insert(H256([2u8; 32]), UpdaterQuota::default());
+		let inputs: Vec<u8> = fs::read("./examples/step_call.cbor").unwrap();
+
+		ConfigurationStorage::<Test>::set(Configuration {
+			slots_per_period: 8192,
+			finality_threshold: 461,
+		});
+
+		// both updaters race with the same proof
+		let first = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256::zero(),
+			inputs.clone(),
+		);
+		assert_ok!(first);
+		let head = Head::<Test>::get();
+		let input_hash = H256(keccak_256(inputs.as_slice()));
+		assert_eq!(input_hash, VerifiedInputs::<Test>::get()[0].0);
+
+		let second = Bridge::fulfill_call(
+			RuntimeOrigin::signed(other_updater.clone()),
+			H256::zero(),
+			inputs.clone(),
+		);
+		assert_eq!(Pays::No, second.unwrap().pays_fee);
+		assert_eq!(head, Head::<Test>::get());
+		let expected_event = RuntimeEvent::Bridge(Event::InputsAlreadyVerified { input_hash });
+		assert_eq!(expected_event, System::events().last().unwrap().event);
+
+		// once expired, the inputs are verified again
+		System::set_block_number(11);
+		let third = Bridge::fulfill_call(
+			RuntimeOrigin::signed(other_updater),
+			H256::zero(),
+			inputs,
+		);
+		assert_err!(third, Error::<Test>::SlotBehindHead);
+	});
+}
+
+#[test]
+fn test_fulfill_step_call_already_verified_requires_updater() {
+	new_test_ext().execute_with(|| {
+		let inputs: Vec<u8> = fs::read("./examples/step_call.cbor").unwrap();
+		let input_hash = H256(keccak_256(inputs.as_slice()));
+		VerifiedInputs::<Test>::set(BoundedVec::truncate_from(vec![(input_hash, 11)]));
+
+		let result = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256::zero(),
+			inputs,
+		);
+
+		assert_err!(result, Error::<Test>::UpdaterMisMatch);
+	});
+}
+
 #[test]
 fn test_fulfill_rotate_call() {
 	new_test_ext().execute_with(|| {
-		Updater::<Test>::set(H256(TEST_SENDER_VEC));
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
 
 		// These inputs, encoded in CBOR format, would be passed in via the operator
 		let inputs: Vec<u8> = fs::read("./examples/rotate_call.cbor").unwrap();
@@ -104,6 +182,10 @@ fn test_fulfill_rotate_call() {
 			slots_per_period: 8192,
 			finality_threshold: 342,
 		});
+		// the next sync committee is rotated in from the finalized header, verified by a step
+		let parsed_inputs: FunctionInputs = serde_cbor::from_slice(&inputs).unwrap();
+		let finalized_slot = parsed_inputs.store.finalized_header.slot.as_u64();
+		SlotParticipation::<Test>::insert(finalized_slot, 342);
 
 		let result = Bridge::fulfill_call(
 			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
@@ -129,4 +211,5328 @@ fn test_fulfill_rotate_call() {
 		assert_eq!(expected_event, System::events()[1].event);
 		assert_eq!(poseidon, expected_hash);
 	});
-}
\ No newline at end of file
+}
+
+#[test]
+fn set_source_address_version_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let domain = 2;
+		assert_eq!(AddressVersion::Evm, SourceAddressVersions::<Test>::get(domain));
+
+		let result =
+			Bridge::set_source_address_version(RuntimeOrigin::root(), domain, AddressVersion::Bytes32);
+
+		assert_ok!(result);
+		assert_eq!(AddressVersion::Bytes32, SourceAddressVersions::<Test>::get(domain));
+
+		let expected_event = RuntimeEvent::Bridge(Event::SourceAddressVersionUpdated {
+			domain,
+			version: AddressVersion::Bytes32,
+		});
+		assert_eq!(expected_event, System::events()[0].event);
+	});
+}
+
+#[test]
+fn set_source_address_version_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::set_source_address_version(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			AddressVersion::Bytes32,
+		);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn archive_epoch_prunes_slot_roots() {
+	new_test_ext().execute_with(|| {
+		let header_root = H256::repeat_byte(1);
+		let execution_state_root = H256::repeat_byte(2);
+		let slots = [8192u64, 8224, 8256];
+		for slot in slots {
+			Headers::<Test>::insert(slot, header_root);
+			ExecutionStateRoots::<Test>::insert(slot, execution_state_root);
+		}
+		EpochSlots::<Test>::insert(1, BoundedVec::truncate_from(slots.to_vec()));
+		NextArchiveEpoch::<Test>::set(Some(1));
+
+		// epoch 1 ends at slot 16384, still within the retention window
+		Head::<Test>::set(16384 + 262_144 - 1);
+		Bridge::on_idle(1, Weight::MAX);
+		assert_eq!(None, ArchivedEpochs::<Test>::get(1));
+		assert_eq!(header_root, Headers::<Test>::get(8192));
+
+		Head::<Test>::set(16384 + 262_144);
+		Bridge::on_idle(2, Weight::MAX);
+
+		let archived = ArchivedEpochs::<Test>::get(1).unwrap();
+		assert_eq!(3, archived.leaves);
+		assert_eq!(Some(2), NextArchiveEpoch::<Test>::get());
+		for slot in slots {
+			assert_eq!(H256::zero(), Headers::<Test>::get(slot));
+			assert_eq!(H256::zero(), ExecutionStateRoots::<Test>::get(slot));
+		}
+
+		let expected_event = RuntimeEvent::Bridge(Event::EpochArchived {
+			epoch: 1,
+			root: archived.root,
+			slots: 3,
+		});
+		assert_eq!(expected_event, System::events()[0].event);
+
+		let leaves = slots.map(|slot| (slot, header_root, execution_state_root, 0));
+		let proof = archive_proof(&leaves, 1).unwrap();
+		assert!(verify_archive_proof(&archived, 8224, &proof));
+	});
+}
+
+#[test]
+fn archive_epoch_is_split_across_blocks() {
+	new_test_ext().execute_with(|| {
+		let slots: Vec<u64> = (8192..8192 + 300).collect();
+		for slot in slots.iter() {
+			Headers::<Test>::insert(slot, H256::repeat_byte(1));
+		}
+		EpochSlots::<Test>::insert(1, BoundedVec::truncate_from(slots.clone()));
+		NextArchiveEpoch::<Test>::set(Some(1));
+		Head::<Test>::set(16384 + 262_144);
+
+		// without a budget nothing is archived
+		assert_eq!(Weight::zero(), Bridge::on_idle(1, Weight::zero()));
+		assert_eq!(300, EpochSlots::<Test>::get(1).len());
+
+		Bridge::on_idle(1, Weight::MAX);
+		assert_eq!(None, ArchivedEpochs::<Test>::get(1));
+		assert_eq!(44, EpochSlots::<Test>::get(1).len());
+		assert_eq!(256, ArchivedSlots::<Test>::get().len());
+		assert_eq!(H256::zero(), Headers::<Test>::get(8192));
+		assert_eq!(H256::repeat_byte(1), Headers::<Test>::get(8192 + 256));
+
+		Bridge::on_idle(2, Weight::MAX);
+		assert_eq!(300, ArchivedEpochs::<Test>::get(1).unwrap().leaves);
+		assert_eq!(Some(2), NextArchiveEpoch::<Test>::get());
+		assert!(EpochSlots::<Test>::get(1).is_empty());
+		assert!(ArchivedSlots::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn add_updater_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let updater = H256(TEST_SENDER_VEC);
+		let result = Bridge::add_updater(RuntimeOrigin::root(), updater, Some(2));
+
+		assert_ok!(result);
+		assert_eq!(
+			Some(UpdaterQuota {
+				max_submissions: Some(2),
+				session: 0,
+				submissions: 0,
+			}),
+			Updaters::<Test>::get(updater)
+		);
+		let expected_event = RuntimeEvent::Bridge(Event::UpdaterAdded {
+			updater,
+			max_submissions: Some(2),
+		});
+		assert_eq!(expected_event, System::events()[0].event);
+	});
+}
+
+#[test]
+fn add_updater_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::add_updater(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256(TEST_SENDER_VEC),
+			None,
+		);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn remove_updater_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let updater = H256(TEST_SENDER_VEC);
+		Updaters::<Test>::insert(updater, UpdaterQuota::default());
+
+		let result = Bridge::remove_updater(RuntimeOrigin::root(), updater);
+
+		assert_ok!(result);
+		assert!(!Updaters::<Test>::contains_key(updater));
+		let expected_event = RuntimeEvent::Bridge(Event::UpdaterRemoved { updater });
+		assert_eq!(expected_event, System::events()[0].event);
+	});
+}
+
+#[test]
+fn remove_updater_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::remove_updater(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256(TEST_SENDER_VEC),
+		);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn remove_updater_fails_for_unknown_updater() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::remove_updater(RuntimeOrigin::root(), H256(TEST_SENDER_VEC));
+
+		assert_err!(result, Error::<Test>::UpdaterNotFound);
+	});
+}
+
+#[test]
+fn updater_quota_resets_every_session() {
+	new_test_ext().execute_with(|| {
+		let updater = H256(TEST_SENDER_VEC);
+		assert_ok!(Bridge::add_updater(RuntimeOrigin::root(), updater, Some(2)));
+
+		assert_ok!(Bridge::note_updater_submission(updater));
+		assert_ok!(Bridge::note_updater_submission(updater));
+		assert_err!(
+			Bridge::note_updater_submission(updater),
+			Error::<Test>::UpdaterQuotaExceeded
+		);
+
+		CurrentSession::set(1);
+		assert_ok!(Bridge::note_updater_submission(updater));
+		assert_eq!(
+			Some(UpdaterQuota {
+				max_submissions: Some(2),
+				session: 1,
+				submissions: 1,
+			}),
+			Updaters::<Test>::get(updater)
+		);
+	});
+}
+
+#[test]
+fn runtime_upgrade_migrates_updater_to_allowlist() {
+	new_test_ext().execute_with(|| {
+		let updater = H256(TEST_SENDER_VEC);
+		Updater::<Test>::put(updater);
+		StorageVersion::new(0).put::<Bridge>();
+
+		Bridge::on_runtime_upgrade();
+
+		assert_eq!(None, Updater::<Test>::get());
+		assert_eq!(Some(UpdaterQuota::default()), Updaters::<Test>::get(updater));
+		assert_bridge_event(Event::UpdaterAdded {
+			updater,
+			max_submissions: None,
+		});
+		assert_eq!(StorageVersion::new(1), Bridge::on_chain_storage_version());
+	});
+}
+
+#[test]
+fn runtime_upgrade_does_not_migrate_twice() {
+	new_test_ext().execute_with(|| {
+		let updater = H256(TEST_SENDER_VEC);
+		Updater::<Test>::put(updater);
+		finality::v0::GenesisTimestamp::<Test>::put(100);
+		finality::v0::SecondsPerSlot::<Test>::put(12);
+		let finality = Finality::<Test>::get();
+		StorageVersion::new(1).put::<Bridge>();
+
+		Bridge::on_runtime_upgrade();
+
+		assert_eq!(Some(updater), Updater::<Test>::get());
+		assert!(!Updaters::<Test>::contains_key(updater));
+		assert_eq!(finality, Finality::<Test>::get());
+		assert!(finality::v0::GenesisTimestamp::<Test>::exists());
+	});
+}
+
+#[test]
+fn runtime_upgrade_keeps_quota_of_allowed_updater() {
+	new_test_ext().execute_with(|| {
+		let updater = H256(TEST_SENDER_VEC);
+		assert_ok!(Bridge::add_updater(RuntimeOrigin::root(), updater, Some(2)));
+		Updater::<Test>::put(updater);
+		StorageVersion::new(0).put::<Bridge>();
+		let events = System::events().len();
+
+		Bridge::on_runtime_upgrade();
+
+		assert_eq!(None, Updater::<Test>::get());
+		assert_eq!(Some(2), Updaters::<Test>::get(updater).unwrap().max_submissions);
+		assert_eq!(events, System::events().len());
+	});
+}
+
+fn beacon_anchored_message() -> AddressedMessage {
+	AddressedMessage {
+		message: Message::FungibleToken {
+			asset_id: H256::zero(),
+			amount: 1_000_000_000_000_000_000u128,
+		},
+		from: H256(hex!(
+			"681257BED628425a28B469114Dc21A7c30205cFD000000000000000000000000"
+		)),
+		to: H256(TEST_SENDER_VEC),
+		origin_domain: 2,
+		destination_domain: 1,
+		id: 0,
+	}
+}
+
+#[test]
+fn set_beacon_root_domain_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::set_beacon_root_domain(RuntimeOrigin::root(), 2, true);
+
+		assert_ok!(result);
+		assert!(BeaconRootDomains::<Test>::get(2));
+		let expected_event = RuntimeEvent::Bridge(Event::BeaconRootDomainUpdated {
+			domain: 2,
+			enabled: true,
+		});
+		assert_eq!(expected_event, System::events()[0].event);
+	});
+}
+
+#[test]
+fn set_beacon_root_domain_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result =
+			Bridge::set_beacon_root_domain(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT), 2, true);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+fn beacon_roots_proof() -> ValidBeaconRootsProof<Test> {
+	BeaconRootsProof {
+		timestamp: 1_700_000_000,
+		account_proof: BoundedVec::new(),
+		storage_proof: BoundedVec::new(),
+	}
+}
+
+#[test]
+fn execute_beacon_anchored_fails_for_unsupported_domain() {
+	new_test_ext().execute_with(|| {
+		ExecutionStateRoots::<Test>::insert(8192, H256::repeat_byte(1));
+
+		let result = Bridge::execute_beacon_anchored(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			8192,
+			beacon_anchored_message(),
+			BoundedVec::new(),
+			BoundedVec::new(),
+			beacon_roots_proof(),
+			BeaconRootProof {
+				execution_state_root: H256::repeat_byte(2),
+				branch: BoundedVec::new(),
+			},
+		);
+
+		assert_err!(result, Error::<Test>::BeaconRootNotSupported);
+	});
+}
+
+#[test]
+fn execute_beacon_anchored_fails_for_invalid_proof() {
+	new_test_ext().execute_with(|| {
+		BeaconRootDomains::<Test>::set(2, true);
+		ExecutionStateRoots::<Test>::insert(8192, H256::repeat_byte(1));
+		let beacon_proof = BeaconRootProof {
+			execution_state_root: H256::repeat_byte(2),
+			branch: BoundedVec::truncate_from(vec![H256::repeat_byte(3); 12]),
+		};
+
+		// The beacon roots contract is not part of the execution state of the slot.
+		let result = Bridge::execute_beacon_anchored(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			8192,
+			beacon_anchored_message(),
+			BoundedVec::new(),
+			BoundedVec::new(),
+			beacon_roots_proof(),
+			beacon_proof,
+		);
+		assert_err!(result, Error::<Test>::CannotGetStorageRoot);
+	});
+}
+
+#[test]
+fn genesis_stores_slot_roots() {
+	let storage = GenesisConfig::<Test> {
+		finality: FinalityConfig::Slot {
+			genesis_time: 100,
+			seconds_per_slot: 12,
+		},
+		updater: H256(TEST_SENDER_VEC),
+		slot_roots: vec![
+			(8224, H256::repeat_byte(3), H256::repeat_byte(4)),
+			(8192, H256::repeat_byte(1), H256::repeat_byte(2)),
+		],
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap();
+
+	sp_io::TestExternalities::new(storage).execute_with(|| {
+		assert_eq!(8224, Head::<Test>::get());
+		assert_eq!(Some(UpdaterQuota::default()), Updaters::<Test>::get(H256(TEST_SENDER_VEC)));
+		assert_eq!(H256::repeat_byte(1), Headers::<Test>::get(8192));
+		assert_eq!(H256::repeat_byte(4), ExecutionStateRoots::<Test>::get(8224));
+		assert_eq!(100 + 8224 * 12, Timestamps::<Test>::get(8224));
+		assert_eq!(vec![8192, 8224], EpochSlots::<Test>::get(1).into_inner());
+		assert_eq!(Some(1), NextArchiveEpoch::<Test>::get());
+	});
+}
+
+#[test]
+fn fulfill_call_degrades_after_low_participation_updates() {
+	new_test_ext().execute_with(|| {
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
+		let inputs: Vec<u8> = fs::read("./examples/step_call.cbor").unwrap();
+		LowParticipationStreak::<Test>::set(2);
+
+		// every participation is just above this threshold
+		ConfigurationStorage::<Test>::set(Configuration {
+			slots_per_period: 8192,
+			finality_threshold: u16::MAX,
+		});
+
+		let result = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256::zero(),
+			inputs,
+		);
+
+		assert_ok!(result);
+		assert_eq!(BridgeStatus::Degraded, Status::<Test>::get());
+		let expected_event = RuntimeEvent::Bridge(Event::BridgeDegraded {
+			reason: DegradedReason::LowParticipation { updates: 3 },
+		});
+		assert!(System::events().iter().any(|e| e.event == expected_event));
+	});
+}
+
+#[test]
+fn fulfill_call_resets_low_participation_streak() {
+	new_test_ext().execute_with(|| {
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
+		let inputs: Vec<u8> = fs::read("./examples/step_call.cbor").unwrap();
+		LowParticipationStreak::<Test>::set(2);
+
+		ConfigurationStorage::<Test>::set(Configuration {
+			slots_per_period: 8192,
+			finality_threshold: 0,
+		});
+
+		let result = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256::zero(),
+			inputs,
+		);
+
+		assert_ok!(result);
+		assert_eq!(0, LowParticipationStreak::<Test>::get());
+		assert_eq!(BridgeStatus::Operational, Status::<Test>::get());
+	});
+}
+
+#[test]
+fn fulfill_call_degrades_on_conflicting_header() {
+	new_test_ext().execute_with(|| {
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
+		let inputs: Vec<u8> = fs::read("./examples/step_call.cbor").unwrap();
+		let parsed_inputs: FunctionInputs = serde_cbor::from_slice(&inputs).unwrap();
+		let finalized_slot = parsed_inputs.finality_update.finalized_header.slot.as_u64();
+
+		// a different header is already stored for the proven slot
+		Head::<Test>::set(9678877);
+		Headers::<Test>::insert(finalized_slot, H256::repeat_byte(1));
+
+		ConfigurationStorage::<Test>::set(Configuration {
+			slots_per_period: 8192,
+			finality_threshold: 461,
+		});
+
+		let result = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256::zero(),
+			inputs,
+		);
+
+		assert_ok!(result);
+		assert_eq!(9678877, Head::<Test>::get());
+		assert_eq!(H256::repeat_byte(1), Headers::<Test>::get(finalized_slot));
+		assert_eq!(BridgeStatus::Degraded, Status::<Test>::get());
+		let expected_event = RuntimeEvent::Bridge(Event::BridgeDegraded {
+			reason: DegradedReason::FinalityRegression {
+				slot: finalized_slot,
+			},
+		});
+		assert_eq!(expected_event, System::events().last().unwrap().event);
+	});
+}
+
+#[test]
+fn fulfill_call_records_accepted_header() {
+	new_test_ext().execute_with(|| {
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
+		let inputs: Vec<u8> = fs::read("./examples/step_call.cbor").unwrap();
+		let parsed_inputs: FunctionInputs = serde_cbor::from_slice(&inputs).unwrap();
+		let finalized_slot = parsed_inputs.finality_update.finalized_header.slot.as_u64();
+
+		ConfigurationStorage::<Test>::set(Configuration {
+			slots_per_period: 8192,
+			finality_threshold: 461,
+		});
+
+		let result = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256::zero(),
+			inputs,
+		);
+
+		assert_ok!(result);
+		assert_eq!(
+			vec![AcceptedHeader {
+				slot: finalized_slot,
+				header_root: Headers::<Test>::get(finalized_slot),
+				updater: Some(H256(TEST_SENDER_VEC)),
+			}],
+			AcceptedHeaders::<Test>::get().into_inner()
+		);
+	});
+}
+
+#[test]
+fn fulfill_call_reports_equivocating_updater() {
+	new_test_ext().execute_with(|| {
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
+		let inputs: Vec<u8> = fs::read("./examples/step_call.cbor").unwrap();
+		let parsed_inputs: FunctionInputs = serde_cbor::from_slice(&inputs).unwrap();
+		let finalized_slot = parsed_inputs.finality_update.finalized_header.slot.as_u64();
+
+		// a different header was accepted for the proven slot and archived since
+		Head::<Test>::set(9678877);
+		AcceptedHeaders::<Test>::set(BoundedVec::truncate_from(vec![AcceptedHeader {
+			slot: finalized_slot,
+			header_root: H256::repeat_byte(1),
+			updater: Some(H256::repeat_byte(2)),
+		}]));
+
+		ConfigurationStorage::<Test>::set(Configuration {
+			slots_per_period: 8192,
+			finality_threshold: 461,
+		});
+
+		let result = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256::zero(),
+			inputs,
+		);
+
+		assert_ok!(result);
+		assert_eq!(
+			vec![(H256(TEST_SENDER_VEC), finalized_slot)],
+			ReportedEquivocations::get()
+		);
+		assert_eq!(BridgeStatus::Degraded, Status::<Test>::get());
+		let expected_event = RuntimeEvent::Bridge(Event::UpdaterEquivocated {
+			updater: H256(TEST_SENDER_VEC),
+			slot: finalized_slot,
+			accepted_root: H256::repeat_byte(1),
+			conflicting_root: H256(hex!(
+				"a6e3468985f31ca58e34fe0a40a72f4bbc08d4d00a0933d28b07ddb95d1faf95"
+			)),
+			reported: true,
+		});
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == expected_event));
+		assert!(Updaters::<Test>::contains_key(H256(TEST_SENDER_VEC)));
+	});
+}
+
+#[test]
+fn fulfill_call_removes_unstaked_equivocating_updater() {
+	new_test_ext().execute_with(|| {
+		let updater = H256(TEST_SENDER_VEC);
+		Updaters::<Test>::insert(updater, UpdaterQuota::default());
+		UnstakedUpdaters::set(vec![updater]);
+		let inputs: Vec<u8> = fs::read("./examples/step_call.cbor").unwrap();
+		let parsed_inputs: FunctionInputs = serde_cbor::from_slice(&inputs).unwrap();
+		let finalized_slot = parsed_inputs.finality_update.finalized_header.slot.as_u64();
+
+		Head::<Test>::set(9678877);
+		AcceptedHeaders::<Test>::set(BoundedVec::truncate_from(vec![AcceptedHeader {
+			slot: finalized_slot,
+			header_root: H256::repeat_byte(1),
+			updater: Some(H256::repeat_byte(2)),
+		}]));
+		ConfigurationStorage::<Test>::set(Configuration {
+			slots_per_period: 8192,
+			finality_threshold: 461,
+		});
+
+		let result = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256::zero(),
+			inputs.clone(),
+		);
+
+		assert_ok!(result);
+		assert!(ReportedEquivocations::get().is_empty());
+		assert_bridge_event(Event::UpdaterEquivocated {
+			updater,
+			slot: finalized_slot,
+			accepted_root: H256::repeat_byte(1),
+			conflicting_root: H256(hex!(
+				"a6e3468985f31ca58e34fe0a40a72f4bbc08d4d00a0933d28b07ddb95d1faf95"
+			)),
+			reported: false,
+		});
+		assert_bridge_event(Event::UpdaterRemoved { updater });
+
+		// the removed updater cannot submit again
+		assert!(!Updaters::<Test>::contains_key(updater));
+		let result = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256::zero(),
+			inputs,
+		);
+		assert_err!(result, Error::<Test>::UpdaterMisMatch);
+	});
+}
+
+#[test]
+fn execute_high_value_message_needs_degraded_origin() {
+	new_test_ext().execute_with(|| {
+		Status::<Test>::set(BridgeStatus::Degraded);
+		let mut message = beacon_anchored_message();
+		message.message = Message::FungibleToken {
+			asset_id: H256::zero(),
+			amount: 100_000_000_000_000_000_000u128,
+		};
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			8192,
+			message.clone(),
+			BoundedVec::new(),
+			BoundedVec::new(),
+		);
+		assert_err!(result, Error::<Test>::BridgeDegraded);
+
+		// the elevated origin passes the check and fails on the missing broadcaster
+		let result = Bridge::execute(
+			RuntimeOrigin::root(),
+			8192,
+			message,
+			BoundedVec::new(),
+			BoundedVec::new(),
+		);
+		assert_err!(result, Error::<Test>::BroadcasterSourceChainNotSet);
+
+		// low-value messages are not restricted
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			8192,
+			beacon_anchored_message(),
+			BoundedVec::new(),
+			BoundedVec::new(),
+		);
+		assert_err!(result, Error::<Test>::BroadcasterSourceChainNotSet);
+	});
+}
+
+#[test]
+fn restore_bridge_works_with_degraded_origin() {
+	new_test_ext().execute_with(|| {
+		Status::<Test>::set(BridgeStatus::Degraded);
+		LowParticipationStreak::<Test>::set(3);
+
+		let result = Bridge::restore_bridge(RuntimeOrigin::root());
+
+		assert_ok!(result);
+		assert_eq!(BridgeStatus::Operational, Status::<Test>::get());
+		assert_eq!(0, LowParticipationStreak::<Test>::get());
+		let expected_event = RuntimeEvent::Bridge(Event::BridgeRestored);
+		assert_eq!(expected_event, System::events()[0].event);
+	});
+}
+
+#[test]
+fn restore_bridge_does_not_work_with_signed() {
+	new_test_ext().execute_with(|| {
+		Status::<Test>::set(BridgeStatus::Degraded);
+
+		let result = Bridge::restore_bridge(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT));
+
+		assert_err!(result, BadOrigin);
+		assert_eq!(BridgeStatus::Degraded, Status::<Test>::get());
+	});
+}
+
+/// Slot of the bridge-dev test vectors, as pinned when they are regenerated by
+/// `generate-fixtures`.
+const BRIDGE_DEV_SLOT: u64 = 8581263;
+
+/// Sets up the broadcaster and slot roots of the bridge-dev test vectors and returns the
+/// account and storage proofs of its message with id 0.
+fn setup_bridge_dev_message() -> (ValidProof<Test>, ValidProof<Test>) {
+	let vectors: serde_json::Value =
+		serde_json::from_slice(&fs::read("../../misc/bridge-dev/test-vectors.json").unwrap())
+			.unwrap();
+	let h256 = |value: &serde_json::Value| -> H256 {
+		H256::from_slice(&sp_core::bytes::from_hex(value.as_str().unwrap()).unwrap())
+	};
+	assert_eq!(Some(BRIDGE_DEV_SLOT), vectors["slots"][0]["slot"].as_u64());
+	Broadcasters::<Test>::set(2, h256(&vectors["broadcaster"]["address"]));
+	ExecutionStateRoots::<Test>::set(
+		BRIDGE_DEV_SLOT,
+		h256(&vectors["slots"][0]["executionStateRoot"]),
+	);
+
+	let proof = |key: &str| -> ValidProof<Test> {
+		let nodes = vectors["messages"][0][key].as_array().unwrap();
+		let nodes = nodes
+			.iter()
+			.map(|node| sp_core::bytes::from_hex(node.as_str().unwrap()).unwrap())
+			.map(BoundedVec::truncate_from)
+			.collect::<Vec<_>>();
+		BoundedVec::truncate_from(nodes)
+	};
+	(proof("accountProof"), proof("storageProof"))
+}
+
+#[test]
+fn prove_message_absence_works() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+
+		// the message with id 1 branches off an empty child of the storage root node
+		let result = Bridge::prove_message_absence(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			2,
+			1,
+			account_proof,
+			storage_proof,
+		);
+
+		assert_ok!(result);
+		assert_eq!(Some(BRIDGE_DEV_SLOT), AbsentMessages::<Test>::get(2, 1));
+		let expected_event = RuntimeEvent::Bridge(Event::MessageAbsenceProven {
+			domain: 2,
+			message_id: 1,
+			slot: BRIDGE_DEV_SLOT,
+		});
+		assert_eq!(expected_event, System::events()[0].event);
+	});
+}
+
+#[test]
+fn prove_message_absence_fails_for_sent_message() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+
+		let result = Bridge::prove_message_absence(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			2,
+			0,
+			account_proof,
+			storage_proof,
+		);
+
+		assert_err!(result, Error::<Test>::MessageSent);
+		assert_eq!(None, AbsentMessages::<Test>::get(2, 0));
+	});
+}
+
+#[test]
+fn prove_message_absence_fails_for_unsupported_domain() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+
+		let result = Bridge::prove_message_absence(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			3,
+			1,
+			account_proof,
+			storage_proof,
+		);
+
+		assert_err!(result, Error::<Test>::UnsupportedOriginChain);
+	});
+}
+
+#[test]
+fn prove_message_absence_fails_for_proven_slot() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		AbsentMessages::<Test>::insert(2, 1, BRIDGE_DEV_SLOT);
+
+		let result = Bridge::prove_message_absence(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			2,
+			1,
+			account_proof,
+			storage_proof,
+		);
+
+		assert_err!(result, Error::<Test>::MessageAbsenceAlreadyProven);
+	});
+}
+
+#[test]
+fn set_message_schema_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let to = H256(TEST_SENDER_VEC);
+		let schema: MessageSchema =
+			BoundedVec::truncate_from(vec![AbiType::Address, AbiType::Uint256]);
+
+		let result = Bridge::set_message_schema(RuntimeOrigin::root(), to, Some(schema.clone()));
+
+		assert_ok!(result);
+		assert_eq!(MessageSchemas::<Test>::get(to), Some(schema.clone()));
+		let expected_event = RuntimeEvent::Bridge(Event::MessageSchemaUpdated {
+			to,
+			schema: Some(schema),
+		});
+		assert_eq!(expected_event, System::events()[0].event);
+
+		assert_ok!(Bridge::set_message_schema(RuntimeOrigin::root(), to, None));
+		assert!(!MessageSchemas::<Test>::contains_key(to));
+	});
+}
+
+#[test]
+fn set_message_schema_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::set_message_schema(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256(TEST_SENDER_VEC),
+			None,
+		);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn check_message_data_decodes_with_recipient_schema() {
+	new_test_ext().execute_with(|| {
+		let to = H256(TEST_SENDER_VEC);
+		let mut data = [0u8; 12].to_vec();
+		data.extend_from_slice(&hex!("681257BED628425a28B469114Dc21A7c30205cFD"));
+
+		// Without a schema the data is opaque.
+		assert_ok!(Bridge::check_message_data(to, b"Hello, World!"));
+
+		MessageSchemas::<Test>::insert(to, MessageSchema::truncate_from(vec![AbiType::Address]));
+		assert_ok!(Bridge::check_message_data(to, &data));
+		assert_err!(
+			Bridge::check_message_data(to, b"Hello, World!"),
+			Error::<Test>::InvalidMessageData
+		);
+	});
+}
+
+/// Message with id 0 of the bridge-dev test vectors.
+fn bridge_dev_message() -> AddressedMessage {
+	AddressedMessage {
+		message: Message::FungibleToken {
+			asset_id: H256::zero(),
+			amount: 1_000_000_000_000_000_000u128,
+		},
+		from: H256(hex!(
+			"681257bed628425a28b469114dc21a7c30205cfd000000000000000000000000"
+		)),
+		to: H256(hex!(
+			"0000000000000000000000000000000000000000000000000000000000000001"
+		)),
+		origin_domain: 2,
+		destination_domain: 1,
+		id: 0,
+	}
+}
+
+/// Queues a message following `message` in its ordered channel, as if it was proven earlier.
+fn queue_next_message(message: &AddressedMessage) -> AddressedMessage {
+	let mut next = message.clone();
+	next.message = Message::ArbitraryMessage(BoundedVec::truncate_from(b"Hello, World!".to_vec()));
+	next.to = H256(TEST_SENDER_VEC);
+	next.id = message.id + 1;
+	QueuedMessages::<Test>::insert((next.origin_domain, next.from), next.id, next.clone());
+	next
+}
+
+#[test]
+fn set_ordered_channel_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let message = bridge_dev_message();
+
+		let result = Bridge::set_ordered_channel(RuntimeOrigin::root(), 2, message.from, Some(0));
+
+		assert_ok!(result);
+		assert_eq!(Some(0), OrderedChannels::<Test>::get(2, message.from));
+		let expected_event = RuntimeEvent::Bridge(Event::OrderedChannelUpdated {
+			domain: 2,
+			from: message.from,
+			next_id: Some(0),
+		});
+		assert_eq!(expected_event, System::events()[0].event);
+
+		// disabling the channel drops its queue
+		queue_next_message(&message);
+		assert_ok!(Bridge::set_ordered_channel(
+			RuntimeOrigin::root(),
+			2,
+			message.from,
+			None
+		));
+		assert_eq!(None, OrderedChannels::<Test>::get(2, message.from));
+		assert!(!QueuedMessages::<Test>::contains_key((2, message.from), 1));
+	});
+}
+
+#[test]
+fn set_ordered_channel_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::set_ordered_channel(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			H256(TEST_SENDER_VEC),
+			Some(0),
+		);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn execute_drains_ordered_channel() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		OrderedChannels::<Test>::insert(2, message.from, 0);
+		let next = queue_next_message(&message);
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message,
+			account_proof,
+			storage_proof,
+		);
+
+		assert_ok!(result);
+		assert_eq!(Some(2), OrderedChannels::<Test>::get(2, next.from));
+		assert!(!QueuedMessages::<Test>::contains_key((2, next.from), 1));
+		let expected_event = RuntimeEvent::Bridge(Event::MessageExecuted {
+			from: next.from,
+			to: next.to,
+			message_id: 1,
+			message_root: H256(keccak_256(&next.clone().abi_encode())),
+		});
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == expected_event));
+	});
+}
+
+#[test]
+fn execute_fails_for_message_before_ordered_channel() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		OrderedChannels::<Test>::insert(2, message.from, 1);
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message.clone(),
+			account_proof,
+			storage_proof,
+		);
+
+		assert_err!(result, Error::<Test>::MessageOutOfOrder);
+		assert_eq!(Some(1), OrderedChannels::<Test>::get(2, message.from));
+	});
+}
+
+#[test]
+fn skip_ordered_message_works() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let mut channel_message = bridge_dev_message();
+		channel_message.from = H256(TEST_SENDER_VEC);
+		OrderedChannels::<Test>::insert(2, channel_message.from, 0);
+		let next = queue_next_message(&channel_message);
+
+		let result = Bridge::skip_ordered_message(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			channel_message.from,
+			bridge_dev_message(),
+			account_proof,
+			storage_proof,
+		);
+
+		assert_ok!(result);
+		assert_eq!(Some(2), OrderedChannels::<Test>::get(2, next.from));
+		let expected_event = RuntimeEvent::Bridge(Event::OrderedMessageSkipped {
+			domain: 2,
+			from: channel_message.from,
+			message_id: 0,
+		});
+		assert_eq!(expected_event, System::events()[0].event);
+	});
+}
+
+#[test]
+fn skip_ordered_message_fails_for_channel_message() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		OrderedChannels::<Test>::insert(2, message.from, 0);
+
+		let result = Bridge::skip_ordered_message(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message.from,
+			message.clone(),
+			account_proof,
+			storage_proof,
+		);
+
+		assert_err!(result, Error::<Test>::MessageNotSkippable);
+		assert_eq!(Some(0), OrderedChannels::<Test>::get(2, message.from));
+	});
+}
+
+#[test]
+fn skip_ordered_message_fails_for_wrong_id() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let channel_from = H256(TEST_SENDER_VEC);
+		OrderedChannels::<Test>::insert(2, channel_from, 1);
+
+		let result = Bridge::skip_ordered_message(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			channel_from,
+			bridge_dev_message(),
+			account_proof,
+			storage_proof,
+		);
+
+		assert_err!(result, Error::<Test>::MessageNotSkippable);
+		assert_eq!(Some(1), OrderedChannels::<Test>::get(2, channel_from));
+	});
+}
+
+#[test]
+fn send_message_fails_for_too_long_data() {
+	new_test_ext().execute_with(|| {
+		MaxMessageLength::set(4);
+		let message =
+			Message::ArbitraryMessage(BoundedVec::truncate_from(b"Hello, World!".to_vec()));
+
+		let result = Bridge::send_message(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			message,
+			H256(TEST_SENDER_VEC),
+			2,
+		);
+
+		assert_err!(result, Error::<Test>::MessageTooLong);
+	});
+}
+
+#[test]
+fn execute_fails_for_too_long_data() {
+	new_test_ext().execute_with(|| {
+		MaxMessageLength::set(4);
+		let mut message = bridge_dev_message();
+		message.message =
+			Message::ArbitraryMessage(BoundedVec::truncate_from(b"Hello, World!".to_vec()));
+
+		// the length is checked before the proofs
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message,
+			BoundedVec::new(),
+			BoundedVec::new(),
+		);
+
+		assert_err!(result, Error::<Test>::MessageTooLong);
+	});
+}
+
+#[test]
+fn set_acknowledged_sender_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let from = bridge_dev_message().from;
+
+		let result = Bridge::set_acknowledged_sender(RuntimeOrigin::root(), 2, from, true);
+
+		assert_ok!(result);
+		assert!(AcknowledgedSenders::<Test>::get(2, from));
+		let expected_event = RuntimeEvent::Bridge(Event::AcknowledgedSenderUpdated {
+			domain: 2,
+			from,
+			enabled: true,
+		});
+		assert_eq!(expected_event, System::events()[0].event);
+	});
+}
+
+#[test]
+fn set_acknowledged_sender_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::set_acknowledged_sender(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			H256(TEST_SENDER_VEC),
+			true,
+		);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn execute_queues_acknowledgement() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		let message_root = H256(keccak_256(&message.clone().abi_encode()));
+		AcknowledgedSenders::<Test>::insert(2, message.from, true);
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message.clone(),
+			account_proof,
+			storage_proof,
+		);
+
+		assert_ok!(result);
+		let ack = Acknowledgement {
+			message_id: 0,
+			message_root,
+			success: true,
+			return_data_hash: H256::zero(),
+		};
+		let acks = OutboundAcknowledgements::<Test>::get();
+		assert_eq!(1, acks.len());
+		assert_eq!(
+			Message::ArbitraryMessage(BoundedVec::truncate_from(ack.abi_encode())),
+			acks[0].message
+		);
+		assert_eq!((message.to, message.from), (acks[0].from, acks[0].to));
+		assert_eq!((1, 2), (acks[0].origin_domain, acks[0].destination_domain));
+		assert_ne!(0, acks[0].id & ACK_ID_FLAG);
+
+		// the queue only holds the acknowledgements of the current block
+		Bridge::on_initialize(2);
+		assert!(OutboundAcknowledgements::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn execute_acknowledges_failed_message() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		let message_root = H256(keccak_256(&message.clone().abi_encode()));
+		AcknowledgedSenders::<Test>::insert(2, message.from, true);
+		// the bridge pot cannot pay the transfer
+		Balances::make_free_balance_be(&Bridge::account_id(), 0);
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message,
+			account_proof,
+			storage_proof,
+		);
+
+		assert_ok!(result);
+		assert_eq!(
+			MessageStatusEnum::ExecutionFailed,
+			MessageStatus::<Test>::get(message_root)
+		);
+		assert_eq!(
+			Some(FailureCode::CapExceeded),
+			MessageFailures::<Test>::get(message_root).map(|failure| failure.code)
+		);
+		let acks = OutboundAcknowledgements::<Test>::get();
+		assert_eq!(1, acks.len());
+		let expected_event = RuntimeEvent::Bridge(Event::AcknowledgementQueued {
+			to: acks[0].to,
+			destination_domain: 2,
+			message_id: acks[0].id,
+			success: false,
+		});
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == expected_event));
+	});
+}
+
+fn execute_call(slot: u64, message: AddressedMessage) -> Call<Test> {
+	Call::execute {
+		slot,
+		addr_message: message,
+		account_proof: BoundedVec::new(),
+		storage_proof: BoundedVec::new(),
+	}
+}
+
+#[test]
+fn pre_validate_accepts_executable_message() {
+	new_test_ext().execute_with(|| {
+		setup_bridge_dev_message();
+		Head::<Test>::set(BRIDGE_DEV_SLOT);
+
+		// the proofs are not verified before the dispatch
+		let result = Bridge::pre_validate(&execute_call(BRIDGE_DEV_SLOT, bridge_dev_message()));
+
+		assert_ok!(result);
+	});
+}
+
+#[test]
+fn pre_validate_rejects_executed_message() {
+	new_test_ext().execute_with(|| {
+		setup_bridge_dev_message();
+		Head::<Test>::set(BRIDGE_DEV_SLOT);
+		let message = bridge_dev_message();
+		let message_root = H256(keccak_256(&message.clone().abi_encode()));
+		MessageStatus::<Test>::set(message_root, MessageStatusEnum::ExecutionSucceeded);
+
+		let result = Bridge::pre_validate(&execute_call(BRIDGE_DEV_SLOT, message));
+
+		assert_eq!(result, Err(InvalidTransaction::Stale.into()));
+	});
+}
+
+#[test]
+fn pre_validate_rejects_unknown_slot() {
+	new_test_ext().execute_with(|| {
+		setup_bridge_dev_message();
+		Head::<Test>::set(BRIDGE_DEV_SLOT);
+
+		let future = Bridge::pre_validate(&execute_call(BRIDGE_DEV_SLOT + 1, bridge_dev_message()));
+		let pruned = Bridge::pre_validate(&execute_call(BRIDGE_DEV_SLOT - 1, bridge_dev_message()));
+
+		assert_eq!(future, Err(InvalidTransaction::Future.into()));
+		assert_eq!(pruned, Err(InvalidTransaction::Stale.into()));
+	});
+}
+
+#[test]
+fn pre_validate_rejects_unexecutable_message() {
+	new_test_ext().execute_with(|| {
+		setup_bridge_dev_message();
+		Head::<Test>::set(BRIDGE_DEV_SLOT);
+		let mut wrong_destination = bridge_dev_message();
+		wrong_destination.destination_domain = 3;
+		MaxMessageLength::set(4);
+		let mut too_long = bridge_dev_message();
+		too_long.message =
+			Message::ArbitraryMessage(BoundedVec::truncate_from(b"Hello, World!".to_vec()));
+
+		for message in [wrong_destination, too_long] {
+			let result = Bridge::pre_validate(&execute_call(BRIDGE_DEV_SLOT, message));
+			assert_eq!(result, Err(InvalidTransaction::Call.into()));
+		}
+	});
+}
+
+#[test]
+fn migrate_domain_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let message = bridge_dev_message();
+		setup_bridge_dev_message();
+		AbsentMessages::<Test>::insert(2, 7, BRIDGE_DEV_SLOT);
+		OrderedChannels::<Test>::insert(2, message.from, 0);
+		let next = queue_next_message(&message);
+
+		let result = Bridge::migrate_domain(RuntimeOrigin::root(), 2, 5);
+
+		assert_ok!(result);
+		assert_eq!(vec![5], WhitelistedDomains::<Test>::get().to_vec());
+		assert_eq!(Some(5), DomainAliases::<Test>::get(2));
+		assert!(Broadcasters::<Test>::get(2).is_zero());
+		assert!(!Broadcasters::<Test>::get(5).is_zero());
+		assert_eq!(Some(BRIDGE_DEV_SLOT), AbsentMessages::<Test>::get(5, 7));
+		assert_eq!(None, OrderedChannels::<Test>::get(2, message.from));
+		assert_eq!(Some(0), OrderedChannels::<Test>::get(5, message.from));
+		// queued messages keep the domain they were proven with
+		assert_eq!(
+			Some(next),
+			QueuedMessages::<Test>::get((5, message.from), 1)
+		);
+		let expected_event = RuntimeEvent::Bridge(Event::DomainMigrated { old: 2, new: 5 });
+		assert_eq!(expected_event, System::events()[0].event);
+	});
+}
+
+#[test]
+fn migrate_domain_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::migrate_domain(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT), 2, 5);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn migrate_domain_fails_for_used_domain() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Bridge::migrate_domain(RuntimeOrigin::root(), 2, 5));
+
+		// 2 is an alias of 5 now
+		let result = Bridge::migrate_domain(RuntimeOrigin::root(), 5, 2);
+		assert_err!(result, Error::<Test>::DomainAlreadyUsed);
+
+		let result = Bridge::migrate_domain(RuntimeOrigin::root(), 5, 1);
+		assert_err!(result, Error::<Test>::DomainAlreadyUsed);
+
+		let result = Bridge::migrate_domain(RuntimeOrigin::root(), 2, 6);
+		assert_err!(result, Error::<Test>::UnsupportedOriginChain);
+	});
+}
+
+#[test]
+fn execute_works_for_migrated_domain() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		let message_root = H256(keccak_256(&message.clone().abi_encode()));
+		assert_ok!(Bridge::migrate_domain(RuntimeOrigin::root(), 2, 5));
+
+		// the message was sent with the old domain
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message,
+			account_proof,
+			storage_proof,
+		);
+
+		assert_ok!(result);
+		assert_eq!(
+			MessageStatusEnum::ExecutionSucceeded,
+			MessageStatus::<Test>::get(message_root)
+		);
+	});
+}
+
+fn simulation_proofs() -> (Vec<Vec<u8>>, Vec<Vec<u8>>) {
+	let (account_proof, storage_proof) = setup_bridge_dev_message();
+	let nodes = |proof: ValidProof<Test>| proof.into_iter().map(|node| node.into_inner()).collect();
+	(nodes(account_proof), nodes(storage_proof))
+}
+
+#[test]
+fn simulate_execute_does_not_change_state() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = simulation_proofs();
+		let message = bridge_dev_message();
+		let message_root = H256(keccak_256(&message.clone().abi_encode()));
+		let events = System::events().len();
+
+		let simulation =
+			Bridge::simulate_execute(BRIDGE_DEV_SLOT, message, account_proof, storage_proof);
+
+		assert_eq!(
+			simulation,
+			ExecutionSimulation {
+				message_root,
+				status: MessageStatusEnum::ExecutionSucceeded,
+				queued: false,
+				error: None,
+			}
+		);
+		assert_eq!(
+			MessageStatus::<Test>::get(message_root),
+			MessageStatusEnum::NotExecuted
+		);
+		assert_eq!(System::events().len(), events);
+	});
+}
+
+#[test]
+fn simulate_execute_reports_failure_reason() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = simulation_proofs();
+		let message = bridge_dev_message();
+
+		let wrong_slot = Bridge::simulate_execute(
+			BRIDGE_DEV_SLOT + 1,
+			message.clone(),
+			account_proof.clone(),
+			storage_proof.clone(),
+		);
+		let too_large = Bridge::simulate_execute(
+			BRIDGE_DEV_SLOT,
+			message,
+			vec![vec![0; 1 << 20]],
+			storage_proof,
+		);
+
+		assert_eq!(
+			wrong_slot.error,
+			Some(Error::<Test>::ExecutionStateRootNotSet.into())
+		);
+		assert_eq!(wrong_slot.status, MessageStatusEnum::NotExecuted);
+		assert_eq!(too_large.error, Some(Error::<Test>::ProofTooLarge.into()));
+	});
+}
+
+/// Hash of the sync committee of period 1179, rotated in by `rotate_call.cbor`.
+const ROTATED_SYNC_COMMITTEE_HASH: &str =
+	"78004113044439342907882478475913997887515213797155324584820998418219758944903";
+
+/// Stores the sync committee of period 1179 as the backfill checkpoint and returns the rotate
+/// proof of period 1178.
+fn setup_backfill_checkpoint() -> Vec<u8> {
+	ConfigurationStorage::<Test>::set(Configuration {
+		slots_per_period: 8192,
+		finality_threshold: 342,
+	});
+	let checkpoint_hash = U256::from_dec_str(ROTATED_SYNC_COMMITTEE_HASH).unwrap();
+	SyncCommitteeHashes::<Test>::insert(1179, checkpoint_hash);
+	fs::read("./examples/rotate_call.cbor").unwrap()
+}
+
+#[test]
+fn backfill_sync_committees_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let inputs = setup_backfill_checkpoint();
+		let checkpoint_hash = U256::from_dec_str(ROTATED_SYNC_COMMITTEE_HASH).unwrap();
+
+		let result = Bridge::backfill_sync_committees(
+			RuntimeOrigin::root(),
+			1179,
+			checkpoint_hash,
+			BoundedVec::truncate_from(vec![inputs]),
+		);
+
+		assert_ok!(result);
+		assert!(!SyncCommitteeHashes::<Test>::get(1178).is_zero());
+		assert!(SyncCommitteeHashes::<Test>::get(1177).is_zero());
+		let expected_event = RuntimeEvent::Bridge(Event::SyncCommitteesBackfilled {
+			first_period: 1178,
+			checkpoint_period: 1179,
+		});
+		assert_eq!(expected_event, System::events().last().unwrap().event);
+	});
+}
+
+#[test]
+fn backfill_sync_committees_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let inputs = setup_backfill_checkpoint();
+
+		let result = Bridge::backfill_sync_committees(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			1179,
+			U256::from_dec_str(ROTATED_SYNC_COMMITTEE_HASH).unwrap(),
+			BoundedVec::truncate_from(vec![inputs]),
+		);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn backfill_sync_committees_fails_for_wrong_checkpoint() {
+	new_test_ext().execute_with(|| {
+		SyncCommitteeHashes::<Test>::insert(1, U256::from(7));
+		let backfill = |checkpoint_period: u64, checkpoint_hash: u64, periods: usize| {
+			Bridge::backfill_sync_committees(
+				RuntimeOrigin::root(),
+				checkpoint_period,
+				U256::from(checkpoint_hash),
+				BoundedVec::truncate_from(vec![vec![1u8]; periods]),
+			)
+		};
+
+		// unknown checkpoint, other checkpoint hash, periods before genesis
+		for result in [backfill(2, 0, 1), backfill(1, 8, 1), backfill(1, 7, 2)] {
+			assert_err!(result, Error::<Test>::InvalidSyncCommitteeCheckpoint);
+		}
+	});
+}
+
+#[test]
+fn backfill_sync_committees_fails_for_broken_chain() {
+	new_test_ext().execute_with(|| {
+		let inputs = setup_backfill_checkpoint();
+		let checkpoint_hash = U256::from_dec_str(ROTATED_SYNC_COMMITTEE_HASH).unwrap();
+		SyncCommitteeHashes::<Test>::insert(1180, U256::from(7));
+		let backfill = |checkpoint_period: u64, checkpoint_hash: U256, proofs: Vec<Vec<u8>>| {
+			Bridge::backfill_sync_committees(
+				RuntimeOrigin::root(),
+				checkpoint_period,
+				checkpoint_hash,
+				BoundedVec::truncate_from(proofs),
+			)
+		};
+
+		// the proof of period 1178 does not rotate into another committee, is not a proof of
+		// period 1179, nor of the period before it
+		for result in [
+			backfill(1180, U256::from(7), vec![inputs.clone()]),
+			backfill(1179, checkpoint_hash, vec![inputs.clone(), inputs.clone()]),
+		] {
+			assert_err!(result, Error::<Test>::SyncCommitteeChainMismatch);
+		}
+		assert_err!(
+			backfill(1179, checkpoint_hash, vec![vec![1u8]]),
+			Error::<Test>::InvalidInputs
+		);
+		assert!(SyncCommitteeHashes::<Test>::get(1178).is_zero());
+		assert!(SyncCommitteeHashes::<Test>::get(1177).is_zero());
+	});
+}
+
+#[test]
+fn backfill_sync_committees_does_not_overwrite_periods() {
+	new_test_ext().execute_with(|| {
+		let inputs = setup_backfill_checkpoint();
+		SyncCommitteeHashes::<Test>::insert(1178, U256::from(6));
+
+		let result = Bridge::backfill_sync_committees(
+			RuntimeOrigin::root(),
+			1179,
+			U256::from_dec_str(ROTATED_SYNC_COMMITTEE_HASH).unwrap(),
+			BoundedVec::truncate_from(vec![inputs]),
+		);
+
+		assert_err!(result, Error::<Test>::SyncCommitteeAlreadySet);
+		assert_eq!(U256::from(6), SyncCommitteeHashes::<Test>::get(1178));
+	});
+}
+
+#[test]
+fn fulfill_call_imports_historical_header() {
+	new_test_ext().execute_with(|| {
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
+		let inputs: Vec<u8> = fs::read("./examples/step_call.cbor").unwrap();
+		ConfigurationStorage::<Test>::set(Configuration {
+			slots_per_period: 8192,
+			finality_threshold: 461,
+		});
+		// the first call stores the sync committee of the period of the finalized slot
+		assert_ok!(Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256::zero(),
+			inputs.clone(),
+		));
+		let slot = Head::<Test>::get();
+		let header = Headers::<Test>::take(slot);
+		let execution_state_root = ExecutionStateRoots::<Test>::take(slot);
+		let _ = EpochSlots::<Test>::clear(u32::MAX, None);
+		VerifiedInputs::<Test>::kill();
+		Head::<Test>::set(slot + 100);
+
+		let result = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256::zero(),
+			inputs,
+		);
+
+		assert_ok!(result);
+		assert_eq!(slot + 100, Head::<Test>::get());
+		assert_eq!(header, Headers::<Test>::get(slot));
+		assert_eq!(execution_state_root, ExecutionStateRoots::<Test>::get(slot));
+		let expected_event = RuntimeEvent::Bridge(Event::HistoricalHeaderImported {
+			slot,
+			finalization_root: header,
+			execution_state_root,
+		});
+		assert_eq!(expected_event, System::events().last().unwrap().event);
+	});
+}
+
+/// Base fee oracle reading the slot of the message with id `message_id` of the bridge-dev
+/// broadcaster, so the test vector proofs can stand in for the proofs of an oracle.
+fn bridge_dev_base_fee_oracle(message_id: u64) -> BaseFeeOracle {
+	let mut preimage = [0u8; 64];
+	U256::from(message_id).to_big_endian(&mut preimage[..32]);
+	U256::from(1).to_big_endian(&mut preimage[32..]);
+	BaseFeeOracle {
+		address: H160(hex!("dc3542b6fcc39dc0d51ecdcbc6fbb130d5e48d95")),
+		slot: H256(keccak_256(&preimage)),
+	}
+}
+
+#[test]
+fn set_base_fee_oracle_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let oracle = bridge_dev_base_fee_oracle(0);
+
+		let result = Bridge::set_base_fee_oracle(RuntimeOrigin::root(), 2, Some(oracle));
+
+		assert_ok!(result);
+		assert_eq!(Some(oracle), BaseFeeOracles::<Test>::get(2));
+		let expected_event = RuntimeEvent::Bridge(Event::BaseFeeOracleUpdated {
+			domain: 2,
+			oracle: Some(oracle),
+		});
+		assert_eq!(expected_event, System::events()[0].event);
+	});
+}
+
+#[test]
+fn set_base_fee_oracle_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::set_base_fee_oracle(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			Some(bridge_dev_base_fee_oracle(0)),
+		);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn update_source_base_fee_works() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		BaseFeeOracles::<Test>::insert(2, bridge_dev_base_fee_oracle(0));
+
+		let result = Bridge::update_source_base_fee(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			2,
+			account_proof,
+			storage_proof,
+		);
+
+		assert_ok!(result);
+		// the proven slot holds the root of the message
+		let base_fee = U256::from_big_endian(&keccak_256(&bridge_dev_message().abi_encode()));
+		assert_eq!(
+			Some(SourceBaseFee {
+				slot: BRIDGE_DEV_SLOT,
+				base_fee,
+			}),
+			Bridge::source_base_fee(2)
+		);
+		let expected_event = RuntimeEvent::Bridge(Event::SourceBaseFeeUpdated {
+			domain: 2,
+			slot: BRIDGE_DEV_SLOT,
+			base_fee,
+		});
+		assert_eq!(expected_event, System::events()[0].event);
+	});
+}
+
+#[test]
+fn update_source_base_fee_fails_without_oracle() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+
+		let result = Bridge::update_source_base_fee(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			2,
+			account_proof,
+			storage_proof,
+		);
+
+		assert_err!(result, Error::<Test>::BaseFeeOracleNotSet);
+	});
+}
+
+#[test]
+fn update_source_base_fee_fails_for_proven_slot() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		BaseFeeOracles::<Test>::insert(2, bridge_dev_base_fee_oracle(0));
+		let proven = SourceBaseFee {
+			slot: BRIDGE_DEV_SLOT,
+			base_fee: U256::from(7),
+		};
+		SourceBaseFees::<Test>::insert(2, proven);
+
+		let result = Bridge::update_source_base_fee(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			2,
+			account_proof,
+			storage_proof,
+		);
+
+		assert_err!(result, Error::<Test>::BaseFeeAlreadyProven);
+		assert_eq!(Some(proven), SourceBaseFees::<Test>::get(2));
+	});
+}
+
+#[test]
+fn update_source_base_fee_fails_for_invalid_proof() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		// the storage proof does not cover the slot of message 1
+		BaseFeeOracles::<Test>::insert(2, bridge_dev_base_fee_oracle(1));
+
+		let result = Bridge::update_source_base_fee(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			2,
+			account_proof,
+			storage_proof,
+		);
+
+		assert_err!(result, Error::<Test>::CannotGetStorageValue);
+		assert_eq!(None, SourceBaseFees::<Test>::get(2));
+	});
+}
+
+#[test]
+fn period_boundaries_follow_head() {
+	new_test_ext().execute_with(|| {
+		Head::<Test>::set(8192 * 3 + 5);
+
+		assert_eq!(Some(3), Bridge::current_period());
+		assert_eq!(Some(8192 * 3), Bridge::period_start_slot(3));
+		assert_eq!(Some(8192 * 4), Bridge::rotate_due_by_slot());
+	});
+}
+
+#[test]
+fn rotate_is_not_due_when_next_committee_is_stored() {
+	new_test_ext().execute_with(|| {
+		Head::<Test>::set(8192 * 3 + 5);
+		SyncCommitteeHashes::<Test>::insert(4, U256::one());
+
+		assert_eq!(None, Bridge::rotate_due_by_slot());
+	});
+}
+
+#[test]
+fn period_boundaries_without_configuration() {
+	new_test_ext().execute_with(|| {
+		ConfigurationStorage::<Test>::set(Configuration {
+			slots_per_period: 0,
+			finality_threshold: 461,
+		});
+
+		assert_eq!(None, Bridge::current_period());
+		assert_eq!(None, Bridge::period_start_slot(1));
+		assert_eq!(None, Bridge::rotate_due_by_slot());
+	});
+}
+
+#[test]
+fn set_reorg_recovery_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::set_reorg_recovery(RuntimeOrigin::root(), true);
+
+		assert_ok!(result);
+		assert!(ReorgRecovery::<Test>::get());
+		let expected_event = RuntimeEvent::Bridge(Event::ReorgRecoveryUpdated { enabled: true });
+		assert_eq!(expected_event, System::events()[0].event);
+	});
+}
+
+#[test]
+fn set_reorg_recovery_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::set_reorg_recovery(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT), true);
+
+		assert_err!(result, BadOrigin);
+		assert!(!ReorgRecovery::<Test>::get());
+	});
+}
+
+/// Stores the roots of `slots` of epoch 1, the last one being the head.
+fn store_epoch_slots(slots: &[u64]) {
+	for slot in slots {
+		Headers::<Test>::insert(slot, H256::repeat_byte(1));
+		ExecutionStateRoots::<Test>::insert(slot, H256::repeat_byte(2));
+	}
+	EpochSlots::<Test>::insert(1, BoundedVec::truncate_from(slots.to_vec()));
+	Head::<Test>::set(*slots.last().unwrap());
+}
+
+#[test]
+fn rewind_head_works_in_reorg_recovery() {
+	new_test_ext().execute_with(|| {
+		store_epoch_slots(&[8192, 8224, 8256]);
+		ReorgRecovery::<Test>::set(true);
+
+		let result = Bridge::rewind_head(
+			RuntimeOrigin::root(),
+			8200,
+			H256::repeat_byte(3),
+			H256::repeat_byte(4),
+			2,
+		);
+
+		assert_ok!(result);
+		assert_eq!(8200, Head::<Test>::get());
+		assert_eq!(vec![8192, 8200], EpochSlots::<Test>::get(1).to_vec());
+		assert_eq!(H256::repeat_byte(2), ExecutionStateRoots::<Test>::get(8192));
+		assert_eq!(H256::repeat_byte(3), Headers::<Test>::get(8200));
+		assert_eq!(H256::repeat_byte(4), ExecutionStateRoots::<Test>::get(8200));
+		for slot in [8224, 8256] {
+			assert_eq!(H256::zero(), Headers::<Test>::get(slot));
+			assert_eq!(H256::zero(), ExecutionStateRoots::<Test>::get(slot));
+		}
+
+		let events: Vec<_> = System::events().into_iter().map(|r| r.event).collect();
+		assert_eq!(
+			vec![
+				RuntimeEvent::Bridge(Event::ExecutionStateRootInvalidated {
+					slot: 8224,
+					execution_state_root: H256::repeat_byte(2),
+				}),
+				RuntimeEvent::Bridge(Event::ExecutionStateRootInvalidated {
+					slot: 8256,
+					execution_state_root: H256::repeat_byte(2),
+				}),
+				RuntimeEvent::Bridge(Event::HeadRewound {
+					from: 8256,
+					to: 8200,
+					invalidated: 2,
+				}),
+			],
+			events
+		);
+	});
+}
+
+#[test]
+fn rewind_head_fails_without_reorg_recovery() {
+	new_test_ext().execute_with(|| {
+		store_epoch_slots(&[8192, 8224, 8256]);
+
+		let result =
+			Bridge::rewind_head(RuntimeOrigin::root(), 8200, H256::zero(), H256::zero(), 2);
+
+		assert_err!(result, Error::<Test>::ReorgRecoveryNotEnabled);
+		assert_eq!(8256, Head::<Test>::get());
+	});
+}
+
+#[test]
+fn rewind_head_fails_for_slot_ahead_of_head() {
+	new_test_ext().execute_with(|| {
+		store_epoch_slots(&[8192, 8224, 8256]);
+		ReorgRecovery::<Test>::set(true);
+
+		let result =
+			Bridge::rewind_head(RuntimeOrigin::root(), 8256, H256::zero(), H256::zero(), 2);
+
+		assert_err!(result, Error::<Test>::SlotNotBehindHead);
+	});
+}
+
+#[test]
+fn rewind_head_fails_with_too_many_invalidated_slots() {
+	new_test_ext().execute_with(|| {
+		store_epoch_slots(&[8192, 8224, 8256]);
+		ReorgRecovery::<Test>::set(true);
+
+		let result =
+			Bridge::rewind_head(RuntimeOrigin::root(), 8200, H256::zero(), H256::zero(), 1);
+
+		assert_err!(result, Error::<Test>::TooManyInvalidatedSlots);
+		assert_eq!(H256::repeat_byte(2), ExecutionStateRoots::<Test>::get(8256));
+	});
+}
+
+#[test]
+fn rewind_head_then_step_requires_full_inputs() {
+	new_test_ext().execute_with(|| {
+		let step = Submission::step();
+		assert_ok!(step.submit());
+		assert!(LightClientState::<Test>::get().is_some());
+		ReorgRecovery::<Test>::set(true);
+
+		let rewound = step.step_slot() - 1;
+		let result = Bridge::rewind_head(
+			RuntimeOrigin::root(),
+			rewound,
+			H256::repeat_byte(3),
+			H256::repeat_byte(4),
+			1,
+		);
+
+		assert_ok!(result);
+		assert_eq!(None, LightClientState::<Test>::get());
+		assert!(VerifiedInputs::<Test>::get().is_empty());
+		assert_bridge_event(Event::LightClientStateCleared { slot: rewound });
+
+		// compact inputs would be completed with the store of the abandoned fork
+		let parsed = step.parsed.clone();
+		let compact = serde_cbor::to_vec(&CompactFunctionInputs {
+			slot_delta: step.step_slot() - rewound,
+			updates: parsed.updates,
+			finality_update: parsed.finality_update,
+			expected_current_slot: parsed.expected_current_slot,
+			execution_state_proof: parsed.execution_state_proof,
+		})
+		.unwrap();
+		let compact = Submission {
+			inputs: compact,
+			parsed: step.parsed.clone(),
+		};
+		assert_err!(compact.submit(), Error::<Test>::LightClientStateNotSet);
+
+		// full inputs step the head again
+		assert_ok!(step.submit());
+		assert_eq!(step.step_slot(), Head::<Test>::get());
+		assert!(LightClientState::<Test>::get().is_some());
+	});
+}
+
+#[test]
+fn set_participation_requirement_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let to = H256(TEST_SENDER_VEC);
+		let requirement = ParticipationRequirement {
+			min_participation: 480,
+			min_amount: 1_000,
+		};
+
+		let result =
+			Bridge::set_participation_requirement(RuntimeOrigin::root(), to, Some(requirement));
+
+		assert_ok!(result);
+		assert_eq!(
+			Some(requirement),
+			ParticipationRequirements::<Test>::get(to)
+		);
+		let expected_event = RuntimeEvent::Bridge(Event::ParticipationRequirementUpdated {
+			to,
+			requirement: Some(requirement),
+		});
+		assert_eq!(expected_event, System::events()[0].event);
+	});
+}
+
+#[test]
+fn set_participation_requirement_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::set_participation_requirement(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256(TEST_SENDER_VEC),
+			None,
+		);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn execute_fails_with_insufficient_participation() {
+	new_test_ext().execute_with(|| {
+		let message = beacon_anchored_message();
+		ParticipationRequirements::<Test>::insert(
+			message.to,
+			ParticipationRequirement {
+				min_participation: 480,
+				min_amount: 0,
+			},
+		);
+		SlotParticipation::<Test>::insert(8192, 470);
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			8192,
+			message.clone(),
+			BoundedVec::new(),
+			BoundedVec::new(),
+		);
+		assert_err!(result, Error::<Test>::InsufficientParticipation);
+
+		// enough participation passes the check and fails on the missing broadcaster
+		SlotParticipation::<Test>::insert(8192, 480);
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			8192,
+			message,
+			BoundedVec::new(),
+			BoundedVec::new(),
+		);
+		assert_err!(result, Error::<Test>::BroadcasterSourceChainNotSet);
+	});
+}
+
+#[test]
+fn execute_archived_fails_with_insufficient_archived_participation() {
+	new_test_ext().execute_with(|| {
+		let message = beacon_anchored_message();
+		ParticipationRequirements::<Test>::insert(
+			message.to,
+			ParticipationRequirement {
+				min_participation: 480,
+				min_amount: 0,
+			},
+		);
+		let (header_root, execution_state_root) = (H256::repeat_byte(1), H256::repeat_byte(2));
+		let slots = [8192u64, 8224];
+		for slot in slots {
+			Headers::<Test>::insert(slot, header_root);
+			ExecutionStateRoots::<Test>::insert(slot, execution_state_root);
+		}
+		SlotParticipation::<Test>::insert(8192, 470);
+		SlotParticipation::<Test>::insert(8224, 480);
+		EpochSlots::<Test>::insert(1, BoundedVec::truncate_from(slots.to_vec()));
+		NextArchiveEpoch::<Test>::set(Some(1));
+		Head::<Test>::set(16384 + 262_144);
+		Bridge::on_idle(1, Weight::MAX);
+		assert_eq!(None, SlotParticipation::<Test>::get(8192));
+
+		let leaves = [
+			(8192, header_root, execution_state_root, 470),
+			(8224, header_root, execution_state_root, 480),
+		];
+		let result = Bridge::execute_archived(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			8192,
+			message.clone(),
+			BoundedVec::new(),
+			BoundedVec::new(),
+			archive_proof(&leaves, 0).unwrap(),
+		);
+		assert_err!(result, Error::<Test>::InsufficientParticipation);
+
+		// the archived participation of the other slot passes the check and fails on the
+		// missing broadcaster
+		let result = Bridge::execute_archived(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			8224,
+			message,
+			BoundedVec::new(),
+			BoundedVec::new(),
+			archive_proof(&leaves, 1).unwrap(),
+		);
+		assert_err!(result, Error::<Test>::BroadcasterSourceChainNotSet);
+	});
+}
+
+#[test]
+fn execute_works_below_required_amount() {
+	new_test_ext().execute_with(|| {
+		let message = beacon_anchored_message();
+		ParticipationRequirements::<Test>::insert(
+			message.to,
+			ParticipationRequirement {
+				min_participation: 480,
+				min_amount: 10_000_000_000_000_000_000u128,
+			},
+		);
+
+		// the slot has no recorded participation, but the amount is below the requirement
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			8192,
+			message,
+			BoundedVec::new(),
+			BoundedVec::new(),
+		);
+		assert_err!(result, Error::<Test>::BroadcasterSourceChainNotSet);
+	});
+}
+
+fn relayer_fee() -> ExecutionFee {
+	ExecutionFee {
+		rate: Perbill::from_percent(1),
+		min_fee: 1_000,
+		max_fee: 100_000_000_000_000_000u128,
+	}
+}
+
+#[test]
+fn set_execution_fee_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let asset_id = H256::zero();
+
+		let result =
+			Bridge::set_execution_fee(RuntimeOrigin::root(), asset_id, Some(relayer_fee()));
+
+		assert_ok!(result);
+		assert_eq!(Some(relayer_fee()), ExecutionFees::<Test>::get(asset_id));
+		let expected_event = RuntimeEvent::Bridge(Event::ExecutionFeeUpdated {
+			asset_id,
+			fee: Some(relayer_fee()),
+		});
+		assert_eq!(expected_event, System::events()[0].event);
+	});
+}
+
+#[test]
+fn set_execution_fee_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::set_execution_fee(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256::zero(),
+			Some(relayer_fee()),
+		);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn set_execution_fee_fails_with_invalid_bounds() {
+	new_test_ext().execute_with(|| {
+		let fee = ExecutionFee {
+			min_fee: 2_000,
+			max_fee: 1_000,
+			..relayer_fee()
+		};
+
+		let result = Bridge::set_execution_fee(RuntimeOrigin::root(), H256::zero(), Some(fee));
+
+		assert_err!(result, Error::<Test>::InvalidExecutionFee);
+	});
+}
+
+#[test]
+fn execute_pays_execution_fee_to_relayer() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		let recipient = AccountId32::new(message.to.0);
+		ExecutionFees::<Test>::insert(H256::zero(), relayer_fee());
+		let relayer_balance = Balances::free_balance(&TEST_SENDER_ACCOUNT);
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message.clone(),
+			account_proof,
+			storage_proof,
+		);
+
+		assert_ok!(result);
+		// 1% of the amount
+		let fee = 10_000_000_000_000_000u128;
+		assert_eq!(
+			relayer_balance + fee,
+			Balances::free_balance(&TEST_SENDER_ACCOUNT)
+		);
+		assert_eq!(
+			1_000_000_000_000_000_000u128 - fee,
+			Balances::free_balance(&recipient)
+		);
+		let expected_event = RuntimeEvent::Bridge(Event::ExecutionFeePaid {
+			message_root: H256(keccak_256(&message.clone().abi_encode())),
+			relayer: TEST_SENDER_ACCOUNT,
+			fee,
+		});
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == expected_event));
+	});
+}
+
+#[test]
+fn execute_by_recipient_pays_no_execution_fee() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		let recipient = AccountId32::new(message.to.0);
+		ExecutionFees::<Test>::insert(H256::zero(), relayer_fee());
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(recipient.clone()),
+			BRIDGE_DEV_SLOT,
+			message,
+			account_proof,
+			storage_proof,
+		);
+
+		assert_ok!(result);
+		assert_eq!(
+			1_000_000_000_000_000_000u128,
+			Balances::free_balance(&recipient)
+		);
+	});
+}
+
+#[test]
+fn set_fork_epoch_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let result =
+			Bridge::set_fork_epoch(RuntimeOrigin::root(), ConsensusFork::Deneb, Some(269_568));
+
+		assert_ok!(result);
+		assert_eq!(Some(269_568), ForkEpochs::<Test>::get(ConsensusFork::Deneb));
+		let expected_event = RuntimeEvent::Bridge(Event::ForkEpochUpdated {
+			fork: ConsensusFork::Deneb,
+			epoch: Some(269_568),
+		});
+		assert_eq!(expected_event, System::events()[0].event);
+
+		assert_ok!(Bridge::set_fork_epoch(
+			RuntimeOrigin::root(),
+			ConsensusFork::Deneb,
+			None
+		));
+		assert_eq!(None, ForkEpochs::<Test>::get(ConsensusFork::Deneb));
+	});
+}
+
+#[test]
+fn set_fork_epoch_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::set_fork_epoch(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			ConsensusFork::Deneb,
+			Some(269_568),
+		);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn set_fork_epoch_fails_for_unordered_epoch() {
+	new_test_ext().execute_with(|| {
+		ForkEpochs::<Test>::insert(ConsensusFork::Capella, 194_048);
+
+		let before_capella =
+			Bridge::set_fork_epoch(RuntimeOrigin::root(), ConsensusFork::Deneb, Some(194_047));
+		assert_err!(before_capella, Error::<Test>::InvalidForkEpoch);
+
+		let after_capella = Bridge::set_fork_epoch(
+			RuntimeOrigin::root(),
+			ConsensusFork::Bellatrix,
+			Some(194_049),
+		);
+		assert_err!(after_capella, Error::<Test>::InvalidForkEpoch);
+	});
+}
+
+#[test]
+fn consensus_fork_follows_fork_epochs() {
+	new_test_ext().execute_with(|| {
+		// without a schedule, every slot is laid out as in the newest fork
+		assert_eq!(ConsensusFork::Deneb, Bridge::consensus_fork(0));
+
+		ForkEpochs::<Test>::insert(ConsensusFork::Capella, 194_048);
+		ForkEpochs::<Test>::insert(ConsensusFork::Deneb, 269_568);
+
+		assert_eq!(
+			ConsensusFork::Bellatrix,
+			Bridge::consensus_fork(194_048 * 32 - 1)
+		);
+		assert_eq!(ConsensusFork::Capella, Bridge::consensus_fork(194_048 * 32));
+		assert_eq!(
+			ConsensusFork::Capella,
+			Bridge::consensus_fork(269_568 * 32 - 1)
+		);
+		assert_eq!(ConsensusFork::Deneb, Bridge::consensus_fork(269_568 * 32));
+	});
+}
+
+fn multiproof(proof: ValidProof<Test>) -> MultiProof<Test> {
+	BoundedVec::truncate_from(proof.into_inner())
+}
+
+#[test]
+fn execute_messages_works_with_multiproof() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		let message_root = H256(keccak_256(&message.clone().abi_encode()));
+
+		let result = Bridge::execute_messages(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			MessageBatch::<Test>::truncate_from(vec![message.clone()]),
+			account_proof,
+			multiproof(storage_proof),
+		);
+
+		assert_ok!(result);
+		assert_eq!(
+			MessageStatusEnum::ExecutionSucceeded,
+			MessageStatus::<Test>::get(message_root)
+		);
+		let expected_event = RuntimeEvent::Bridge(Event::MessageExecuted {
+			from: message.from,
+			to: message.to,
+			message_id: message.id,
+			message_root,
+		});
+		assert!(System::events()
+			.iter()
+			.any(|record| record.event == expected_event));
+	});
+}
+
+#[test]
+fn execute_messages_fails_for_empty_batch() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let call = Call::<Test>::execute_messages {
+			slot: BRIDGE_DEV_SLOT,
+			messages: MessageBatch::<Test>::default(),
+			account_proof: account_proof.clone(),
+			storage_proof: multiproof(storage_proof.clone()),
+		};
+
+		let result = Bridge::execute_messages(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			MessageBatch::<Test>::default(),
+			account_proof,
+			multiproof(storage_proof),
+		);
+
+		assert_err!(result, Error::<Test>::EmptyMessageBatch);
+		assert_eq!(
+			Err(InvalidTransaction::Call.into()),
+			Bridge::pre_validate(&call)
+		);
+	});
+}
+
+#[test]
+fn execute_messages_fails_for_mixed_source_chains() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		let mut other = message.clone();
+		other.origin_domain = 3;
+
+		let result = Bridge::execute_messages(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			MessageBatch::<Test>::truncate_from(vec![message, other]),
+			account_proof,
+			multiproof(storage_proof),
+		);
+
+		assert_err!(result, Error::<Test>::MixedSourceChains);
+	});
+}
+
+#[test]
+fn execute_messages_fails_if_a_message_is_not_proven() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		let mut unproven = message.clone();
+		unproven.id = 1;
+
+		let result = Bridge::execute_messages(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			MessageBatch::<Test>::truncate_from(vec![message.clone(), unproven]),
+			account_proof,
+			multiproof(storage_proof),
+		);
+
+		assert_err!(result, Error::<Test>::CannotGetStorageValue);
+		assert_eq!(
+			MessageStatusEnum::NotExecuted,
+			MessageStatus::<Test>::get(H256(keccak_256(&message.clone().abi_encode())))
+		);
+	});
+}
+
+#[test]
+fn execute_messages_fails_for_duplicate_message() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+
+		let result = Bridge::execute_messages(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			MessageBatch::<Test>::truncate_from(vec![message.clone(), message]),
+			account_proof,
+			multiproof(storage_proof),
+		);
+
+		assert_err!(result, Error::<Test>::MessageAlreadyExecuted);
+	});
+}
+
+fn permit_signer() -> sp_core::sr25519::Pair {
+	sp_core::sr25519::Pair::from_seed(&[7u8; 32])
+}
+
+fn sign_permit(permit: &ExecutionPermit) -> MultiSignature {
+	MultiSignature::from(permit_signer().sign(&permit.signing_payload()))
+}
+
+fn bridge_dev_permit() -> ExecutionPermit {
+	ExecutionPermit {
+		message_root: H256(keccak_256(&bridge_dev_message().abi_encode())),
+		tip: 1_000,
+		deadline: 100,
+	}
+}
+
+#[test]
+fn verify_permit_accepts_recipient_signature() {
+	new_test_ext().execute_with(|| {
+		let mut message = bridge_dev_message();
+		message.to = H256(permit_signer().public().0);
+		let permit = ExecutionPermit {
+			message_root: H256(keccak_256(&message.clone().abi_encode())),
+			tip: 1_000,
+			deadline: 100,
+		};
+
+		let recipient = Bridge::verify_permit(&message, &permit, &sign_permit(&permit));
+
+		assert_eq!(recipient, Ok(AccountId32::new(permit_signer().public().0)));
+	});
+}
+
+#[test]
+fn execute_with_permit_fails_for_invalid_signature() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let permit = bridge_dev_permit();
+
+		// The bridge-dev message is not sent to the signer of the permit.
+		let result = Bridge::execute_with_permit(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			bridge_dev_message(),
+			account_proof,
+			storage_proof,
+			permit,
+			sign_permit(&permit),
+		);
+
+		assert_err!(result, Error::<Test>::InvalidPermitSignature);
+	});
+}
+
+#[test]
+fn execute_with_permit_fails_for_other_message() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let mut permit = bridge_dev_permit();
+		permit.message_root = H256::repeat_byte(1);
+
+		let result = Bridge::execute_with_permit(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			bridge_dev_message(),
+			account_proof,
+			storage_proof,
+			permit,
+			sign_permit(&permit),
+		);
+
+		assert_err!(result, Error::<Test>::PermitMessageMismatch);
+	});
+}
+
+#[test]
+fn execute_with_permit_fails_for_expired_permit() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let permit = bridge_dev_permit();
+		System::set_block_number((permit.deadline + 1).into());
+
+		let result = Bridge::execute_with_permit(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			bridge_dev_message(),
+			account_proof,
+			storage_proof,
+			permit,
+			sign_permit(&permit),
+		);
+
+		assert_err!(result, Error::<Test>::PermitExpired);
+	});
+}
+
+#[test]
+fn execute_with_permit_fails_for_tip_above_amount() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let mut permit = bridge_dev_permit();
+		permit.tip = 1_000_000_000_000_000_001;
+
+		let result = Bridge::execute_with_permit(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			bridge_dev_message(),
+			account_proof,
+			storage_proof,
+			permit,
+			sign_permit(&permit),
+		);
+
+		assert_err!(result, Error::<Test>::PermitTipTooHigh);
+	});
+}
+
+#[test]
+fn verify_permit_fails_for_tip_above_amount_after_fee() {
+	new_test_ext().execute_with(|| {
+		ExecutionFees::<Test>::insert(H256::zero(), relayer_fee());
+		let mut message = bridge_dev_message();
+		message.to = H256(permit_signer().public().0);
+		// the fee takes 1% of the amount
+		let permit = ExecutionPermit {
+			message_root: H256(keccak_256(&message.clone().abi_encode())),
+			tip: 990_000_000_000_000_001,
+			deadline: 100,
+		};
+
+		let result = Bridge::verify_permit(&message, &permit, &sign_permit(&permit));
+
+		assert_err!(result, Error::<Test>::PermitTipTooHigh);
+	});
+}
+
+#[test]
+fn execute_with_permit_fails_for_used_permit() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let permit = bridge_dev_permit();
+		UsedPermits::<Test>::insert(permit.message_root, 1);
+
+		let result = Bridge::execute_with_permit(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			bridge_dev_message(),
+			account_proof,
+			storage_proof,
+			permit,
+			sign_permit(&permit),
+		);
+
+		assert_err!(result, Error::<Test>::PermitAlreadyUsed);
+	});
+}
+
+#[test]
+fn proof_tier_classifies_by_nodes_and_bytes() {
+	assert_eq!(ProofTier::classify(8, 3_315), ProofTier::Small);
+	assert_eq!(ProofTier::classify(12, 3_315), ProofTier::Medium);
+	assert_eq!(ProofTier::classify(8, 12 * 1024), ProofTier::Medium);
+	assert_eq!(ProofTier::classify(17, 3_315), ProofTier::Large);
+	assert_eq!(ProofTier::classify(8, 32 * 1024), ProofTier::Large);
+}
+
+/// Pads `proof` with nodes up to `nodes` nodes.
+fn padded_proof(proof: &ValidProof<Test>, nodes: usize) -> ValidProof<Test> {
+	let mut padded = proof.clone().into_inner();
+	padded.resize(nodes, BoundedVec::truncate_from(vec![0u8; 532]));
+	BoundedVec::truncate_from(padded)
+}
+
+#[test]
+fn execute_charges_proofs_by_tier() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let weight = |storage_proof: ValidProof<Test>| {
+			Call::<Test>::execute {
+				slot: BRIDGE_DEV_SLOT,
+				addr_message: bridge_dev_message(),
+				account_proof: account_proof.clone(),
+				storage_proof,
+			}
+			.get_dispatch_info()
+			.weight
+		};
+
+		let small = weight(storage_proof.clone());
+		let medium = weight(padded_proof(&storage_proof, 12));
+		let large = weight(padded_proof(&storage_proof, 17));
+
+		assert!(small.ref_time() < medium.ref_time());
+		assert!(medium.ref_time() < large.ref_time());
+	});
+}
+
+#[test]
+fn execute_fails_for_too_deep_proof() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			bridge_dev_message(),
+			account_proof,
+			padded_proof(&storage_proof, 17),
+		);
+
+		assert_err!(result, Error::<Test>::ProofTooDeep);
+	});
+}
+
+#[test]
+fn send_message_commits_outbound_root() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let message =
+			Message::ArbitraryMessage(BoundedVec::truncate_from(b"Hello, World!".to_vec()));
+		let mut message_roots = Vec::new();
+		for tx_index in 0..3 {
+			System::set_extrinsic_index(tx_index);
+			assert_ok!(Bridge::send_message(
+				RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+				message.clone(),
+				H256(TEST_SENDER_VEC),
+				2,
+			));
+			let sent = AddressedMessage {
+				message: message.clone(),
+				from: H256(TEST_SENDER_VEC),
+				to: H256(TEST_SENDER_VEC),
+				origin_domain: 1,
+				destination_domain: 2,
+				id: tx_uid(1, tx_index),
+			};
+			message_roots.push(H256(keccak_256(&sent.abi_encode())));
+		}
+		assert_eq!(OutboundMessages::<Test>::get().into_inner(), message_roots);
+
+		Bridge::on_finalize(1);
+
+		let outbound = OutboundMessagesRoot::<Test>::get().unwrap();
+		assert_eq!(outbound, outbound_root(&message_roots));
+		let proof = outbound_proof(&message_roots, 1).unwrap();
+		assert!(verify_outbound_proof(&outbound, message_roots[1], &proof));
+		let expected_event = RuntimeEvent::Bridge(Event::OutboundRootCommitted {
+			root: outbound.root,
+			leaves: 3,
+		});
+		assert_eq!(expected_event, System::events().last().unwrap().event);
+
+		Bridge::on_initialize(2);
+
+		assert!(OutboundMessages::<Test>::get().is_empty());
+		assert_eq!(None, OutboundMessagesRoot::<Test>::get());
+	});
+}
+
+#[test]
+fn outbound_root_is_not_committed_without_messages() {
+	new_test_ext().execute_with(|| {
+		Bridge::on_finalize(1);
+
+		assert_eq!(None, OutboundMessagesRoot::<Test>::get());
+	});
+}
+
+#[test]
+fn execution_failure_classifies_errors() {
+	new_test_ext().execute_with(|| {
+		let decode = Bridge::execution_failure(Error::<Test>::InvalidMessageData.into());
+		assert_eq!(FailureCode::DecodeFailure, decode.code);
+		assert!(decode.module_error.is_some());
+
+		let reverted = Bridge::execution_failure(Error::<Test>::AssetNotSupported.into());
+		assert_eq!(FailureCode::HandlerReverted, reverted.code);
+		assert_ne!(decode.module_error, reverted.module_error);
+
+		let cap = Bridge::execution_failure(sp_runtime::TokenError::FundsUnavailable.into());
+		assert_eq!(
+			ExecutionFailure {
+				code: FailureCode::CapExceeded,
+				module_error: None
+			},
+			cap
+		);
+
+		let other = Bridge::execution_failure(BadOrigin.into());
+		assert_eq!(FailureCode::Other, other.code);
+	});
+}
+
+#[test]
+fn set_pinned_code_hash_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let code_hash = H256::repeat_byte(1);
+
+		let result = Bridge::set_pinned_code_hash(RuntimeOrigin::root(), 2, Some(code_hash));
+
+		assert_ok!(result);
+		assert_eq!(Some(code_hash), PinnedCodeHashes::<Test>::get(2));
+		let expected_event = RuntimeEvent::Bridge(Event::PinnedCodeHashUpdated {
+			domain: 2,
+			code_hash: Some(code_hash),
+		});
+		assert_eq!(expected_event, System::events()[0].event);
+
+		assert_ok!(Bridge::set_pinned_code_hash(RuntimeOrigin::root(), 2, None));
+		assert_eq!(None, PinnedCodeHashes::<Test>::get(2));
+	});
+}
+
+#[test]
+fn set_pinned_code_hash_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::set_pinned_code_hash(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			Some(H256::repeat_byte(1)),
+		);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn execute_decodes_addresses_with_source_address_version() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		// the broadcaster is read as a 32-byte address, the proof is of its EVM account
+		SourceAddressVersions::<Test>::insert(2, AddressVersion::Bytes32);
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message.clone(),
+			account_proof.clone(),
+			storage_proof.clone(),
+		);
+		assert_err!(result, Error::<Test>::CannotGetStorageRoot);
+
+		// an EVM sender cannot have bytes after its 20 bytes
+		SourceAddressVersions::<Test>::remove(2);
+		let mut from = message.from;
+		from[31] = 1;
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			AddressedMessage { from, ..message },
+			account_proof,
+			storage_proof,
+		);
+		assert_err!(result, Error::<Test>::InvalidSourceAddress);
+	});
+}
+
+#[test]
+fn execute_fails_for_unpinned_code_hash() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		// the broadcaster was upgraded to code that is not pinned yet
+		PinnedCodeHashes::<Test>::insert(2, H256::repeat_byte(1));
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message.clone(),
+			account_proof.clone(),
+			storage_proof.clone(),
+		);
+		assert_err!(result, Error::<Test>::SourceCodeHashMismatch);
+
+		PinnedCodeHashes::<Test>::insert(
+			2,
+			H256(hex!(
+				"54ccfa149cf4cf471e53bc5dfbfe8ae5c27fa96b0599f2747ffe6e1fb8bd4396"
+			)),
+		);
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message,
+			account_proof,
+			storage_proof,
+		);
+		assert_ok!(result);
+	});
+}
+
+#[test]
+fn fulfill_rotate_call_requires_step_of_previous_period() {
+	new_test_ext().execute_with(|| {
+		Updaters::<Test>::insert(H256(TEST_SENDER_VEC), UpdaterQuota::default());
+		let inputs: Vec<u8> = fs::read("./examples/rotate_call.cbor").unwrap();
+		ConfigurationStorage::<Test>::set(Configuration {
+			slots_per_period: 8192,
+			finality_threshold: 342,
+		});
+		let parsed_inputs: FunctionInputs = serde_cbor::from_slice(&inputs).unwrap();
+		let finalized_slot = parsed_inputs.store.finalized_header.slot.as_u64();
+
+		let result = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256::zero(),
+			inputs.clone(),
+		);
+		assert_err!(result, Error::<Test>::RotateHeaderNotVerified);
+
+		SlotParticipation::<Test>::insert(finalized_slot, 341);
+		let result = Bridge::fulfill_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256::zero(),
+			inputs,
+		);
+		assert_err!(result, Error::<Test>::RotateParticipationTooLow);
+		assert!(SyncCommitteeHashes::<Test>::get(1179).is_zero());
+	});
+}
+
+#[test]
+fn bridge_fee_multiplier_follows_proof_submissions() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Multiplier::one(), Bridge::bridge_fee_multiplier());
+
+		Bridge::on_initialize(1);
+		Bridge::note_proof_submission(Weight::from_parts(2_000_000_000, 0));
+		Bridge::on_finalize(1);
+		let surged = Bridge::bridge_fee_multiplier();
+		assert!(surged > Multiplier::one());
+
+		// The usage of the previous block is not carried over.
+		Bridge::on_initialize(2);
+		Bridge::on_finalize(2);
+		assert!(Bridge::bridge_fee_multiplier() < surged);
+	});
+}
+
+#[test]
+fn bridge_fee_multiplier_stays_at_one_when_idle() {
+	new_test_ext().execute_with(|| {
+		Bridge::on_initialize(1);
+		Bridge::on_finalize(1);
+
+		assert_eq!(Multiplier::one(), Bridge::bridge_fee_multiplier());
+	});
+}
+
+#[test]
+fn proof_submissions_are_classified() {
+	new_test_ext().execute_with(|| {
+		let execute = execute_call(BRIDGE_DEV_SLOT, bridge_dev_message());
+		let fulfill = Call::<Test>::fulfill_call {
+			function_id: H256::zero(),
+			inputs: Vec::new(),
+		};
+		let set_updater = Call::<Test>::set_updater {
+			updater: H256::zero(),
+		};
+
+		assert!(Bridge::is_proof_submission(&execute));
+		assert!(Bridge::is_proof_submission(&fulfill));
+		assert!(!Bridge::is_proof_submission(&set_updater));
+	});
+}
+
+#[test]
+fn head_age_seconds_follows_source_chain_time() {
+	let storage = GenesisConfig::<Test> {
+		finality: FinalityConfig::Slot {
+			genesis_time: 100,
+			seconds_per_slot: 12,
+		},
+		slot_roots: vec![(8224, H256::repeat_byte(1), H256::repeat_byte(2))],
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap();
+
+	sp_io::TestExternalities::new(storage).execute_with(|| {
+		let head_timestamp = 100 + 8224 * 12;
+		assert_eq!(Some(head_timestamp), HeaderTimestamps::<Test>::get(8224));
+
+		pallet_timestamp::Pallet::<Test>::set_timestamp((head_timestamp + 30) * 1000);
+		assert_eq!(Some(30), Bridge::head_age_seconds());
+	});
+}
+
+#[test]
+fn head_age_seconds_is_unknown_without_head_timestamp() {
+	new_test_ext().execute_with(|| {
+		Head::<Test>::set(8224);
+
+		assert_eq!(None, Bridge::head_age_seconds());
+	});
+}
+
+#[test]
+fn step_submission_updates_head() {
+	new_test_ext().execute_with(|| {
+		let step = Submission::step();
+
+		assert_ok!(step.submit());
+
+		let slot = step.step_slot();
+		assert_eq!(slot, Head::<Test>::get());
+		assert_eq!(
+			Event::HeadUpdated {
+				slot,
+				finalization_root: Headers::<Test>::get(slot),
+				execution_state_root: ExecutionStateRoots::<Test>::get(slot),
+			},
+			bridge_events()[0]
+		);
+	});
+}
+
+#[test]
+fn step_submission_below_configured_threshold_is_low_participation() {
+	ExtBuilder::default()
+		.finality_threshold(u16::MAX)
+		.build()
+		.execute_with(|| {
+			let step = Submission::step();
+
+			assert_ok!(step.submit());
+			assert_eq!(1, LowParticipationStreak::<Test>::get());
+			assert_eq!(step.step_slot(), Head::<Test>::get());
+		});
+}
+
+#[test]
+fn rotate_submission_updates_sync_committee() {
+	new_test_ext().execute_with(|| {
+		let rotate = Submission::rotate();
+		prepare_rotate(&rotate);
+
+		assert_ok!(rotate.submit());
+
+		let root = SyncCommitteeHashes::<Test>::get(1179);
+		assert!(!root.is_zero());
+		assert_bridge_event(Event::SyncCommitteeUpdated { period: 1179, root });
+	});
+}
+
+#[test]
+fn set_app_chain_route_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::set_app_chain_route(RuntimeOrigin::root(), 5, true);
+
+		assert_ok!(result);
+		assert!(AppChainRoutes::<Test>::get(5));
+		assert_bridge_event(Event::AppChainRouteUpdated {
+			domain: 5,
+			enabled: true,
+		});
+
+		// the Avail domain executes its messages
+		let result = Bridge::set_app_chain_route(RuntimeOrigin::root(), 1, true);
+		assert_err!(result, Error::<Test>::InvalidAppChainDomain);
+	});
+}
+
+#[test]
+fn set_app_chain_route_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result =
+			Bridge::set_app_chain_route(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT), 5, true);
+
+		assert_err!(result, BadOrigin);
+		assert!(!AppChainRoutes::<Test>::get(5));
+	});
+}
+
+#[test]
+fn pre_validate_accepts_message_to_app_chain() {
+	new_test_ext().execute_with(|| {
+		setup_bridge_dev_message();
+		Head::<Test>::set(BRIDGE_DEV_SLOT);
+		let mut message = bridge_dev_message();
+		message.destination_domain = 5;
+		AppChainRoutes::<Test>::insert(5, true);
+
+		let result = Bridge::pre_validate(&execute_call(BRIDGE_DEV_SLOT, message));
+
+		assert_ok!(result);
+	});
+}
+
+#[test]
+fn execute_routes_message_to_app_chain() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		OrderedChannels::<Test>::insert(2, message.from, 0);
+		AppChainRoutes::<Test>::insert(5, true);
+		let mut next = message.clone();
+		next.message =
+			Message::ArbitraryMessage(BoundedVec::truncate_from(b"Hello, World!".to_vec()));
+		next.destination_domain = 5;
+		next.id = 1;
+		QueuedMessages::<Test>::insert((2, next.from), 1, next.clone());
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message,
+			account_proof,
+			storage_proof,
+		);
+
+		assert_ok!(result);
+		let message_root = H256(keccak_256(&next.clone().abi_encode()));
+		assert_eq!(
+			MessageStatusEnum::ExecutionSucceeded,
+			MessageStatus::<Test>::get(message_root)
+		);
+		assert!(OutboundMessages::<Test>::get().contains(&message_root));
+		assert_bridge_event(Event::MessageRouted {
+			from: next.from,
+			to: next.to,
+			message_id: 1,
+			message_root,
+			destination_domain: 5,
+		});
+	});
+}
+
+#[test]
+fn step_submission_of_stored_header_is_rejected_before_verification() {
+	new_test_ext().execute_with(|| {
+		let step = Submission::step();
+		assert_ok!(step.submit());
+		// submitted again by an updater who did not see the first one
+		VerifiedInputs::<Test>::kill();
+
+		let error = step.submit().unwrap_err();
+
+		assert_eq!(
+			DispatchError::from(Error::<Test>::SlotBehindHead),
+			error.error
+		);
+		assert!(error.post_info.actual_weight.is_some());
+	});
+}
+
+#[test]
+fn step_submission_behind_head_without_committee_is_rejected_before_verification() {
+	new_test_ext().execute_with(|| {
+		let step = Submission::step();
+		Head::<Test>::set(step.step_slot() + 1);
+		let period = step.step_slot() / ConfigurationStorage::<Test>::get().slots_per_period;
+		SyncCommitteeHashes::<Test>::remove(period);
+
+		let error = step.submit().unwrap_err();
+
+		assert_eq!(
+			DispatchError::from(Error::<Test>::SlotBehindHead),
+			error.error
+		);
+		assert!(error.post_info.actual_weight.is_some());
+	});
+}
+
+#[test]
+fn pre_validate_tags_step_moving_head() {
+	new_test_ext().execute_with(|| {
+		let step = Submission::step();
+		let call = Call::fulfill_call {
+			function_id: STEP_FUNCTION_ID,
+			inputs: step.inputs.clone(),
+		};
+
+		let validity = Bridge::pre_validate(&call).unwrap();
+
+		assert_eq!(step.step_slot(), validity.priority);
+		assert_eq!(1, validity.provides.len());
+		assert!(validity.requires.is_empty());
+
+		// once the step is applied, the same step is stale
+		assert_ok!(step.submit());
+		assert_eq!(
+			Bridge::pre_validate(&call),
+			Err(InvalidTransaction::Stale.into())
+		);
+	});
+}
+
+#[test]
+fn trace_verification_reports_every_stage() {
+	new_test_ext().execute_with(|| {
+		let step = Submission::step();
+
+		let trace = Bridge::trace_verification(STEP_FUNCTION_ID, step.inputs.clone());
+
+		assert_eq!(H256(keccak_256(&step.inputs)), trace.input_hash);
+		assert_eq!(Some(VerifiedFunction::Step), trace.function);
+		assert!(!trace.already_verified && !trace.stale);
+		assert_eq!(StageResult::Passed, trace.decode);
+		assert!(trace
+			.updates
+			.iter()
+			.all(|update| *update == StageResult::Passed));
+		assert_eq!(StageResult::Passed, trace.finality_update);
+		assert_eq!(StageResult::Passed, trace.execution_state_root);
+		assert_eq!(Some(Ok(StepAcceptance::NewHead)), trace.acceptance);
+		// the trace does not change the state
+		assert_eq!(0, Head::<Test>::get());
+
+		assert_ok!(step.submit());
+		let output = trace.output.unwrap();
+		assert_eq!(Head::<Test>::get(), output.finalized_slot);
+		assert_eq!(
+			Headers::<Test>::get(output.finalized_slot),
+			output.finalized_header_root
+		);
+	});
+}
+
+#[test]
+fn trace_verification_stops_at_failed_stage() {
+	new_test_ext().execute_with(|| {
+		let trace = Bridge::trace_verification(H256::zero(), vec![1, 2, 3]);
+
+		assert_eq!(None, trace.function);
+		assert_eq!(StageResult::Failed, trace.decode);
+		assert!(trace.updates.is_empty());
+		assert_eq!(StageResult::Skipped, trace.finality_update);
+		assert_eq!(StageResult::Skipped, trace.execution_state_root);
+		assert_eq!(None, trace.output);
+		assert_eq!(None, trace.acceptance);
+	});
+}
+
+#[test]
+fn trace_verification_reports_step_behind_head() {
+	new_test_ext().execute_with(|| {
+		let step = Submission::step();
+		assert_ok!(step.submit());
+
+		let trace = Bridge::trace_verification(STEP_FUNCTION_ID, step.inputs.clone());
+
+		assert!(trace.already_verified);
+		assert_eq!(Head::<Test>::get(), trace.output.unwrap().finalized_slot);
+		assert_eq!(
+			Some(Err(Error::<Test>::SlotBehindHead.into())),
+			trace.acceptance
+		);
+	});
+}
+
+#[test]
+fn set_message_length_limit_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let to = H256(TEST_SENDER_VEC);
+
+		let result = Bridge::set_message_length_limit(RuntimeOrigin::root(), to, Some(4));
+
+		assert_ok!(result);
+		assert_eq!(Some(4), MessageLengthLimits::<Test>::get(to));
+		assert_bridge_event(Event::MessageLengthLimitUpdated { to, limit: Some(4) });
+
+		assert_ok!(Bridge::set_message_length_limit(
+			RuntimeOrigin::root(),
+			to,
+			None
+		));
+		assert_eq!(None, MessageLengthLimits::<Test>::get(to));
+	});
+}
+
+#[test]
+fn set_message_length_limit_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::set_message_length_limit(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256(TEST_SENDER_VEC),
+			Some(4),
+		);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn execute_fails_for_data_longer_than_recipient_limit() {
+	new_test_ext().execute_with(|| {
+		let mut message = bridge_dev_message();
+		message.message =
+			Message::ArbitraryMessage(BoundedVec::truncate_from(b"Hello, World!".to_vec()));
+		MessageLengthLimits::<Test>::insert(message.to, 4);
+
+		// the length is checked before the proofs
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message,
+			BoundedVec::new(),
+			BoundedVec::new(),
+		);
+
+		assert_err!(result, Error::<Test>::MessageTooLong);
+	});
+}
+
+#[test]
+fn recipient_limit_overrides_max_message_length() {
+	new_test_ext().execute_with(|| {
+		MaxMessageLength::set(4);
+		let to = H256(TEST_SENDER_VEC);
+		MessageLengthLimits::<Test>::insert(to, 16);
+		let message =
+			Message::ArbitraryMessage(BoundedVec::truncate_from(b"Hello, World!".to_vec()));
+
+		let result =
+			Bridge::send_message(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT), message, to, 2);
+
+		assert_ok!(result);
+	});
+}
+
+/// Compact inputs of the step fixture, with the light client state of the fixture stored for
+/// the head.
+fn compact_step(step: &Submission, slot_delta: u64) -> Vec<u8> {
+	let FunctionInputs {
+		updates,
+		finality_update,
+		expected_current_slot,
+		store,
+		genesis_root,
+		forks,
+		execution_state_proof,
+	} = step.parsed.clone();
+	let state = LightClientContext {
+		store,
+		genesis_root,
+		forks,
+	};
+	Head::<Test>::set(state.store.finalized_header.slot.as_u64());
+	LightClientState::<Test>::set(Some(BoundedVec::truncate_from(
+		serde_cbor::to_vec(&state).unwrap(),
+	)));
+
+	serde_cbor::to_vec(&CompactFunctionInputs {
+		slot_delta,
+		updates,
+		finality_update,
+		expected_current_slot,
+		execution_state_proof,
+	})
+	.unwrap()
+}
+
+#[test]
+fn step_submission_stores_light_client_state() {
+	new_test_ext().execute_with(|| {
+		let step = Submission::step();
+
+		assert_ok!(step.submit());
+
+		let state: LightClientContext =
+			serde_cbor::from_slice(&LightClientState::<Test>::get().unwrap()).unwrap();
+		assert_eq!(
+			Head::<Test>::get(),
+			state.store.finalized_header.slot.as_u64()
+		);
+	});
+}
+
+#[test]
+fn compact_step_submission_updates_head() {
+	new_test_ext().execute_with(|| {
+		let step = Submission::step();
+		let head = step.parsed.store.finalized_header.slot.as_u64();
+		let compact = Submission {
+			inputs: compact_step(&step, step.step_slot() - head),
+			parsed: step.parsed.clone(),
+		};
+
+		assert_ok!(compact.submit());
+
+		assert_eq!(step.step_slot(), Head::<Test>::get());
+		assert!(bridge_events().contains(&Event::HeadUpdated {
+			slot: step.step_slot(),
+			finalization_root: Headers::<Test>::get(step.step_slot()),
+			execution_state_root: ExecutionStateRoots::<Test>::get(step.step_slot()),
+		}));
+	});
+}
+
+#[test]
+fn compact_step_submission_fails_for_wrong_slot_delta() {
+	new_test_ext().execute_with(|| {
+		let step = Submission::step();
+		let compact = Submission {
+			inputs: compact_step(&step, 1),
+			parsed: step.parsed.clone(),
+		};
+
+		assert_err!(compact.submit(), Error::<Test>::SlotDeltaMismatch);
+	});
+}
+
+#[test]
+fn compact_step_submission_fails_without_light_client_state() {
+	new_test_ext().execute_with(|| {
+		let step = Submission::step();
+		let head = step.parsed.store.finalized_header.slot.as_u64();
+		let compact = Submission {
+			inputs: compact_step(&step, step.step_slot() - head),
+			parsed: step.parsed.clone(),
+		};
+		LightClientState::<Test>::kill();
+
+		assert_err!(compact.submit(), Error::<Test>::LightClientStateNotSet);
+	});
+}
+
+#[test]
+fn trace_verification_reports_compact_inputs_of_another_head() {
+	new_test_ext().execute_with(|| {
+		let step = Submission::step();
+		let inputs = compact_step(&step, 1);
+
+		let trace = Bridge::trace_verification(STEP_FUNCTION_ID, inputs.clone());
+		assert_eq!(StageResult::Unavailable, trace.decode);
+		assert_eq!(None, trace.output);
+
+		LightClientState::<Test>::kill();
+		let trace = Bridge::trace_verification(STEP_FUNCTION_ID, inputs);
+		assert_eq!(StageResult::Unavailable, trace.decode);
+	});
+}
+
+#[test]
+fn check_invariants_holds_after_step() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Bridge::check_invariants());
+
+		assert_ok!(Submission::step().submit());
+
+		assert_ok!(Bridge::check_invariants());
+	});
+}
+
+#[test]
+fn check_invariants_fails_for_missing_head_roots() {
+	new_test_ext().execute_with(|| {
+		let step = Submission::step();
+		assert_ok!(step.submit());
+
+		Headers::<Test>::remove(step.step_slot());
+
+		assert_eq!(
+			Err("Header root of the head is not stored"),
+			Bridge::check_invariants()
+		);
+	});
+}
+
+#[test]
+fn check_invariants_fails_for_uncommitted_outbound_messages() {
+	new_test_ext().execute_with(|| {
+		OutboundMessages::<Test>::set(BoundedVec::truncate_from(vec![H256::repeat_byte(1)]));
+
+		assert!(Bridge::check_invariants().is_err());
+
+		Bridge::on_finalize(1);
+
+		assert_ok!(Bridge::check_invariants());
+	});
+}
+
+#[test]
+fn void_message_works_with_root() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(3);
+		let message_root = H256(keccak_256(&bridge_dev_message().abi_encode()));
+
+		let result = Bridge::void_message(RuntimeOrigin::root(), message_root);
+
+		assert_ok!(result);
+		assert_eq!(Some(3), VoidedMessages::<Test>::get(message_root));
+		assert_bridge_event(Event::MessageVoided { message_root });
+	});
+}
+
+#[test]
+fn void_message_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::void_message(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT), H256::zero());
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn void_message_fails_for_executed_message() {
+	new_test_ext().execute_with(|| {
+		let message_root = H256(keccak_256(&bridge_dev_message().abi_encode()));
+		MessageStatus::<Test>::insert(message_root, MessageStatusEnum::ExecutionSucceeded);
+
+		let result = Bridge::void_message(RuntimeOrigin::root(), message_root);
+
+		assert_err!(result, Error::<Test>::MessageAlreadyExecuted);
+		assert!(!VoidedMessages::<Test>::contains_key(message_root));
+	});
+}
+
+#[test]
+fn execute_fails_for_voided_message() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		let message_root = H256(keccak_256(&message.clone().abi_encode()));
+		assert_ok!(Bridge::void_message(RuntimeOrigin::root(), message_root));
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message,
+			account_proof,
+			storage_proof,
+		);
+
+		assert_err!(result, Error::<Test>::MessageVoided);
+		assert_eq!(
+			MessageStatusEnum::NotExecuted,
+			MessageStatus::<Test>::get(message_root)
+		);
+	});
+}
+
+#[test]
+fn execute_skips_voided_queued_message() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		OrderedChannels::<Test>::insert(2, message.from, 0);
+		let next = queue_next_message(&message);
+		let next_root = H256(keccak_256(&next.clone().abi_encode()));
+		assert_ok!(Bridge::void_message(RuntimeOrigin::root(), next_root));
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message.clone(),
+			account_proof,
+			storage_proof,
+		);
+
+		assert_ok!(result);
+		assert_eq!(Some(2), OrderedChannels::<Test>::get(2, message.from));
+		assert_eq!(None, QueuedMessages::<Test>::get((2, message.from), 1));
+		assert_eq!(
+			MessageStatusEnum::NotExecuted,
+			MessageStatus::<Test>::get(next_root)
+		);
+		assert_bridge_event(Event::OrderedMessageSkipped {
+			domain: 2,
+			from: message.from,
+			message_id: 1,
+		});
+	});
+}
+
+#[test]
+fn execute_records_nonce_of_broadcaster() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message.clone(),
+			account_proof,
+			storage_proof,
+		);
+
+		assert_ok!(result);
+		let window = NonceWindows::<Test>::get(2, Broadcasters::<Test>::get(2));
+		assert_eq!(message.id, window.highest);
+		assert!(Bridge::missing_nonces().is_empty());
+	});
+}
+
+#[test]
+fn missing_nonces_lists_skipped_ids() {
+	new_test_ext().execute_with(|| {
+		let broadcaster = H256::repeat_byte(1);
+		NonceWindows::<Test>::mutate(2, broadcaster, |window| {
+			window.note(1);
+			window.note(4);
+		});
+
+		assert_eq!(
+			vec![MissingNonces {
+				domain: 2,
+				broadcaster,
+				nonces: vec![2, 3]
+			}],
+			Bridge::missing_nonces()
+		);
+	});
+}
+
+fn optimism_rollup() -> RollupConfig {
+	RollupConfig {
+		contract: H160::repeat_byte(1),
+		layout: RollupLayout::Optimism {
+			outputs_slot: H256::from_low_u64_be(3),
+			finalization_period: 604_800,
+		},
+	}
+}
+
+fn rollup_proof(commitment: StateCommitment) -> ValidRollupProof<Test> {
+	RollupProof {
+		index: 0,
+		commitment,
+		account_proof: BoundedVec::new(),
+		storage_proof: BoundedVec::new(),
+	}
+}
+
+#[test]
+fn set_rollup_config_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let config = optimism_rollup();
+
+		let result = Bridge::set_rollup_config(RuntimeOrigin::root(), 3, Some(config));
+
+		assert_ok!(result);
+		assert_eq!(Some(config), RollupConfigs::<Test>::get(3));
+		assert_bridge_event(Event::RollupConfigUpdated {
+			domain: 3,
+			config: Some(config),
+		});
+
+		assert_ok!(Bridge::set_rollup_config(RuntimeOrigin::root(), 3, None));
+		assert_eq!(None, RollupConfigs::<Test>::get(3));
+	});
+}
+
+#[test]
+fn set_rollup_config_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::set_rollup_config(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			3,
+			Some(optimism_rollup()),
+		);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn execute_rollup_anchored_fails_for_unsupported_domain() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let commitment = StateCommitment::Optimism {
+			state_root: H256::repeat_byte(1),
+			message_passer_storage_root: H256::zero(),
+			block_hash: H256::zero(),
+		};
+
+		let result = Bridge::execute_rollup_anchored(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			bridge_dev_message(),
+			rollup_proof(commitment),
+			account_proof,
+			storage_proof,
+		);
+
+		assert_err!(result, Error::<Test>::RollupNotSupported);
+	});
+}
+
+#[test]
+fn execute_rollup_anchored_fails_for_commitment_of_other_rollup() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		RollupConfigs::<Test>::insert(2, optimism_rollup());
+		let commitment = StateCommitment::Arbitrum {
+			header: BoundedVec::new(),
+			send_root: H256::zero(),
+		};
+
+		let result = Bridge::execute_rollup_anchored(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			bridge_dev_message(),
+			rollup_proof(commitment),
+			account_proof,
+			storage_proof,
+		);
+
+		assert_err!(result, Error::<Test>::InvalidStateCommitment);
+	});
+}
+
+#[test]
+fn set_finality_config_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let config = FinalityConfig::Instant {
+			genesis_time: 100,
+			seconds_per_block: 2,
+		};
+
+		assert_ok!(Bridge::set_finality_config(RuntimeOrigin::root(), config));
+
+		assert_eq!(config, Finality::<Test>::get());
+		assert_bridge_event(Event::FinalityConfigUpdated { config });
+	});
+}
+
+#[test]
+fn set_finality_config_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::set_finality_config(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			FinalityConfig::default(),
+		);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn step_submission_fails_for_slot_not_finalized_by_epoch_chain() {
+	new_test_ext().execute_with(|| {
+		let step = Submission::step();
+		Finality::<Test>::set(FinalityConfig::Epoch {
+			genesis_time: 100,
+			seconds_per_slot: 12,
+			slots_per_epoch: step.step_slot() + 1,
+		});
+
+		assert_err!(step.submit(), Error::<Test>::SlotNotEpochBoundary);
+	});
+}
+
+#[test]
+fn runtime_upgrade_moves_slot_timing_into_finality_config() {
+	new_test_ext().execute_with(|| {
+		finality::v0::GenesisTimestamp::<Test>::put(100);
+		finality::v0::SecondsPerSlot::<Test>::put(12);
+		StorageVersion::new(0).put::<Bridge>();
+
+		Bridge::on_runtime_upgrade();
+
+		assert_eq!(
+			FinalityConfig::Slot {
+				genesis_time: 100,
+				seconds_per_slot: 12
+			},
+			Finality::<Test>::get()
+		);
+		assert!(!finality::v0::GenesisTimestamp::<Test>::exists());
+		assert!(!finality::v0::SecondsPerSlot::<Test>::exists());
+	});
+}
+
+#[test]
+fn on_idle_executes_queued_messages() {
+	new_test_ext().execute_with(|| {
+		setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		OrderedChannels::<Test>::insert(2, message.from, 1);
+		let next = queue_next_message(&message);
+
+		Bridge::on_idle(1, Weight::MAX);
+
+		assert_eq!(Some(2), OrderedChannels::<Test>::get(2, next.from));
+		assert!(!QueuedMessages::<Test>::contains_key((2, next.from), 1));
+		assert_bridge_event(Event::MessageExecuted {
+			from: next.from,
+			to: next.to,
+			message_id: next.id,
+			message_root: H256(keccak_256(&next.clone().abi_encode())),
+		});
+		// every channel had its turn
+		assert_eq!(None, IdleExecutionCursor::<Test>::get());
+	});
+}
+
+#[test]
+fn on_idle_does_not_execute_without_weight() {
+	new_test_ext().execute_with(|| {
+		setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		OrderedChannels::<Test>::insert(2, message.from, 1);
+		let next = queue_next_message(&message);
+
+		assert_eq!(Weight::zero(), Bridge::on_idle(1, Weight::zero()));
+
+		assert_eq!(Some(1), OrderedChannels::<Test>::get(2, next.from));
+		assert!(QueuedMessages::<Test>::contains_key((2, next.from), 1));
+	});
+}
+
+#[test]
+fn on_idle_does_not_execute_while_degraded() {
+	new_test_ext().execute_with(|| {
+		setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		OrderedChannels::<Test>::insert(2, message.from, 1);
+		let next = queue_next_message(&message);
+		Status::<Test>::set(BridgeStatus::Degraded);
+
+		Bridge::on_idle(1, Weight::MAX);
+
+		assert_eq!(Some(1), OrderedChannels::<Test>::get(2, next.from));
+		assert!(QueuedMessages::<Test>::contains_key((2, next.from), 1));
+	});
+}
+
+#[test]
+fn set_proof_retention_works_with_root() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Bridge::set_proof_retention(
+			RuntimeOrigin::root(),
+			Some(100)
+		));
+
+		assert_eq!(Some(100), ProofRetention::<Test>::get());
+		assert_bridge_event(Event::ProofRetentionUpdated {
+			retention: Some(100),
+		});
+	});
+}
+
+#[test]
+fn set_proof_retention_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result =
+			Bridge::set_proof_retention(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT), Some(100));
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn accepted_step_proof_is_kept_for_retention() {
+	new_test_ext().execute_with(|| {
+		let step = Submission::step();
+		ProofRetention::<Test>::set(Some(100));
+		let now = System::block_number();
+
+		assert_ok!(step.submit());
+
+		let input_hash = H256(keccak_256(step.inputs.as_slice()));
+		assert_eq!(
+			vec![input_hash],
+			ProofArtifactExpiries::<Test>::get(now + 100).into_inner()
+		);
+
+		Bridge::on_initialize(now + 100);
+		assert!(ProofArtifactExpiries::<Test>::get(now + 100).is_empty());
+	});
+}
+
+#[test]
+fn accepted_step_proof_is_not_kept_without_retention() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Submission::step().submit());
+
+		assert_eq!(0, ProofArtifactExpiries::<Test>::iter().count());
+	});
+}
+
+fn release_schedule() -> ReleaseSchedule {
+	ReleaseSchedule {
+		threshold: 1_000_000_000_000_000_000,
+		duration: 100,
+	}
+}
+
+/// Executes the bridge dev message, of 1 token, with the release schedule of the asset set.
+fn execute_vested_message() -> (H256, AccountId32) {
+	let (account_proof, storage_proof) = setup_bridge_dev_message();
+	let message = bridge_dev_message();
+	let recipient = AccountId32::new(message.to.0);
+	ReleaseSchedules::<Test>::insert(H256::zero(), release_schedule());
+
+	assert_ok!(Bridge::execute(
+		RuntimeOrigin::signed(recipient.clone()),
+		BRIDGE_DEV_SLOT,
+		message.clone(),
+		account_proof,
+		storage_proof,
+	));
+	(H256(keccak_256(&message.clone().abi_encode())), recipient)
+}
+
+#[test]
+fn set_release_schedule_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let schedule = Some(release_schedule());
+
+		assert_ok!(Bridge::set_release_schedule(
+			RuntimeOrigin::root(),
+			H256::zero(),
+			schedule
+		));
+
+		assert_eq!(schedule, ReleaseSchedules::<Test>::get(H256::zero()));
+		assert_bridge_event(Event::ReleaseScheduleUpdated {
+			asset_id: H256::zero(),
+			schedule,
+		});
+	});
+}
+
+#[test]
+fn set_release_schedule_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::set_release_schedule(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256::zero(),
+			Some(release_schedule()),
+		);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn set_release_schedule_fails_without_duration() {
+	new_test_ext().execute_with(|| {
+		let schedule = ReleaseSchedule {
+			duration: 0,
+			..release_schedule()
+		};
+
+		let result =
+			Bridge::set_release_schedule(RuntimeOrigin::root(), H256::zero(), Some(schedule));
+
+		assert_err!(result, Error::<Test>::InvalidReleaseSchedule);
+	});
+}
+
+#[test]
+fn execute_vests_amount_from_release_threshold() {
+	new_test_ext().execute_with(|| {
+		let (message_root, recipient) = execute_vested_message();
+
+		assert_eq!(0, Balances::free_balance(&recipient));
+		let vested = VestedReleases::<Test>::get(message_root).unwrap();
+		assert_eq!(1_000_000_000_000_000_000, vested.amount);
+		assert_eq!(0, vested.released);
+		assert_eq!(
+			MessageStatusEnum::ExecutionSucceeded,
+			MessageStatus::<Test>::get(message_root)
+		);
+		assert_bridge_event(Event::ReleaseScheduled {
+			message_root,
+			recipient: H256(recipient.into()),
+			amount: 1_000_000_000_000_000_000,
+			duration: 100,
+		});
+	});
+}
+
+#[test]
+fn execute_below_release_threshold_transfers_amount() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		let recipient = AccountId32::new(message.to.0);
+		let schedule = ReleaseSchedule {
+			threshold: u128::MAX,
+			..release_schedule()
+		};
+		ReleaseSchedules::<Test>::insert(H256::zero(), schedule);
+
+		assert_ok!(Bridge::execute(
+			RuntimeOrigin::signed(recipient.clone()),
+			BRIDGE_DEV_SLOT,
+			message,
+			account_proof,
+			storage_proof,
+		));
+
+		assert_eq!(
+			1_000_000_000_000_000_000u128,
+			Balances::free_balance(&recipient)
+		);
+		assert_eq!(0, VestedReleases::<Test>::iter().count());
+	});
+}
+
+#[test]
+fn release_vested_transfers_released_part() {
+	new_test_ext().execute_with(|| {
+		let (message_root, recipient) = execute_vested_message();
+		let start = System::block_number();
+
+		System::set_block_number(start + 50);
+		assert_ok!(Bridge::release_vested(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			message_root
+		));
+		assert_eq!(
+			500_000_000_000_000_000u128,
+			Balances::free_balance(&recipient)
+		);
+		assert_bridge_event(Event::VestedReleased {
+			message_root,
+			recipient: H256(recipient.clone().into()),
+			amount: 500_000_000_000_000_000,
+		});
+
+		System::set_block_number(start + 150);
+		assert_ok!(Bridge::release_vested(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			message_root
+		));
+		assert_eq!(
+			1_000_000_000_000_000_000u128,
+			Balances::free_balance(&recipient)
+		);
+		assert_eq!(None, VestedReleases::<Test>::get(message_root));
+	});
+}
+
+#[test]
+fn release_vested_fails_when_nothing_is_released() {
+	new_test_ext().execute_with(|| {
+		let (message_root, _) = execute_vested_message();
+
+		let result =
+			Bridge::release_vested(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT), message_root);
+		assert_err!(result, Error::<Test>::NothingToRelease);
+
+		let result =
+			Bridge::release_vested(RuntimeOrigin::signed(TEST_SENDER_ACCOUNT), H256::zero());
+		assert_err!(result, Error::<Test>::VestedReleaseNotFound);
+	});
+}
+
+/// Data of a payout batch of the supported asset.
+fn payout_batch_data(payouts: &[(H256, u128)]) -> Vec<u8> {
+	let mut data = vec![payout::PAYOUT_BATCH_VERSION];
+	data.extend_from_slice(SUPPORTED_ASSET_ID.as_bytes());
+	for (recipient, amount) in payouts {
+		data.extend_from_slice(recipient.as_bytes());
+		let mut word = [0u8; 32];
+		U256::from(*amount).to_big_endian(&mut word);
+		data.extend_from_slice(&word);
+	}
+	data
+}
+
+/// Queues a payout batch following the bridge dev message in its ordered channel.
+fn queue_payout_batch(data: Vec<u8>) -> AddressedMessage {
+	setup_bridge_dev_message();
+	let message = bridge_dev_message();
+	OrderedChannels::<Test>::insert(2, message.from, 1);
+	PayoutSenders::<Test>::insert(2, message.from, true);
+	let mut next = queue_next_message(&message);
+	next.message = Message::ArbitraryMessage(BoundedVec::truncate_from(data));
+	QueuedMessages::<Test>::insert((2, next.from), next.id, next.clone());
+	next
+}
+
+#[test]
+fn set_payout_sender_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let from = H256::repeat_byte(1);
+
+		assert_ok!(Bridge::set_payout_sender(
+			RuntimeOrigin::root(),
+			2,
+			from,
+			true
+		));
+
+		assert!(PayoutSenders::<Test>::get(2, from));
+		assert_bridge_event(Event::PayoutSenderUpdated {
+			domain: 2,
+			from,
+			enabled: true,
+		});
+	});
+}
+
+#[test]
+fn set_payout_sender_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::set_payout_sender(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			H256::repeat_byte(1),
+			true,
+		);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn execute_transfers_payout_batch() {
+	new_test_ext().execute_with(|| {
+		let first = H256::repeat_byte(0xaa);
+		let second = H256::repeat_byte(0xbb);
+		let data = payout_batch_data(&[
+			(first, 100_000_000_000_000_000),
+			(second, 200_000_000_000_000_000),
+		]);
+		let next = queue_payout_batch(data);
+
+		Bridge::on_idle(1, Weight::MAX);
+
+		assert!(!QueuedMessages::<Test>::contains_key(
+			(2, next.from),
+			next.id
+		));
+		assert_eq!(
+			100_000_000_000_000_000u128,
+			Balances::free_balance(AccountId32::new(first.0))
+		);
+		assert_eq!(
+			200_000_000_000_000_000u128,
+			Balances::free_balance(AccountId32::new(second.0))
+		);
+		assert_bridge_event(Event::PayoutsExecuted {
+			message_root: H256(keccak_256(&next.clone().abi_encode())),
+			asset_id: SUPPORTED_ASSET_ID,
+			count: 2,
+			total: 300_000_000_000_000_000,
+		});
+	});
+}
+
+#[test]
+fn execute_fails_for_invalid_payout_batch() {
+	new_test_ext().execute_with(|| {
+		let recipient = H256::repeat_byte(0xaa);
+		let mut data = payout_batch_data(&[(recipient, 100_000_000_000_000_000)]);
+		data.pop();
+		let next = queue_payout_batch(data);
+
+		Bridge::on_idle(1, Weight::MAX);
+
+		// the message stays queued, nothing is paid out
+		assert!(QueuedMessages::<Test>::contains_key(
+			(2, next.from),
+			next.id
+		));
+		assert_eq!(0, Balances::free_balance(AccountId32::new(recipient.0)));
+	});
+}
+
+#[test]
+fn step_submission_records_period_participation() {
+	new_test_ext().execute_with(|| {
+		let step = Submission::step();
+		let period = step.step_slot() / ConfigurationStorage::<Test>::get().slots_per_period;
+		let expired = period - MAX_PARTICIPATION_PERIODS;
+		ParticipationHistory::<Test>::insert(expired, PeriodParticipation::default());
+
+		assert_ok!(step.submit());
+
+		let history = ParticipationHistory::<Test>::get(period).unwrap();
+		assert_eq!(1, history.updates);
+		assert_eq!(history.min, history.max);
+		assert_eq!(None, ParticipationHistory::<Test>::get(expired));
+		assert_eq!(vec![(period, history)], Bridge::participation_history(4));
+	});
+}
+
+#[test]
+fn liveness_score_counts_low_participation_updates() {
+	ExtBuilder::default()
+		.finality_threshold(u16::MAX)
+		.build()
+		.execute_with(|| {
+			let step = Submission::step();
+			let period = step.step_slot() / ConfigurationStorage::<Test>::get().slots_per_period;
+			let mut earlier = PeriodParticipation::default();
+			earlier.note(500, false);
+			ParticipationHistory::<Test>::insert(period - 1, earlier);
+
+			assert_ok!(step.submit());
+
+			assert_eq!(
+				1,
+				ParticipationHistory::<Test>::get(period)
+					.unwrap()
+					.low_updates
+			);
+			assert_eq!(Some(Perbill::from_percent(50)), Bridge::liveness_score(2));
+			assert_eq!(Some(Perbill::zero()), Bridge::liveness_score(1));
+			assert_eq!(None, Bridge::liveness_score(0));
+		});
+}
+
+#[test]
+fn execute_records_message_header() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		let message_root = H256(keccak_256(&message.clone().abi_encode()));
+
+		assert_ok!(Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message.clone(),
+			account_proof,
+			storage_proof,
+		));
+
+		let mut payload = [0u8; 64];
+		U256::from(1_000_000_000_000_000_000u128).to_big_endian(&mut payload[32..]);
+		let header = MessageHeader {
+			message_type: MessageType::FungibleToken,
+			from: message.from,
+			to: message.to,
+			origin_domain: 2,
+			destination_domain: 1,
+			id: 0,
+			payload_hash: H256(keccak_256(&payload)),
+		};
+		assert_eq!(
+			Some(header.clone()),
+			MessageHeaders::<Test>::get(message_root)
+		);
+		assert_bridge_event(Event::MessageHeaderRecorded {
+			message_root,
+			header,
+		});
+	});
+}
+
+#[test]
+fn queued_message_execution_records_message_header() {
+	new_test_ext().execute_with(|| {
+		setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		OrderedChannels::<Test>::insert(2, message.from, 1);
+		let next = queue_next_message(&message);
+		let message_root = H256(keccak_256(&next.clone().abi_encode()));
+
+		Bridge::on_idle(1, Weight::MAX);
+
+		let header = MessageHeaders::<Test>::get(message_root).unwrap();
+		assert_eq!(MessageType::ArbitraryMessage, header.message_type);
+		assert_eq!(H256(TEST_SENDER_VEC), header.to);
+		assert_eq!(1, header.id);
+		assert_eq!(H256(keccak_256(b"Hello, World!")), header.payload_hash);
+	});
+}
+
+#[test]
+fn schedule_fork_transition_works_with_root() {
+	new_test_ext().execute_with(|| {
+		setup_bridge_dev_message();
+		let old_broadcaster = Broadcasters::<Test>::get(2);
+		let new_broadcaster = H256::repeat_byte(3);
+
+		let result = Bridge::schedule_fork_transition(
+			RuntimeOrigin::root(),
+			2,
+			3,
+			BRIDGE_DEV_SLOT + 1,
+			new_broadcaster,
+		);
+
+		assert_ok!(result);
+		assert_eq!(vec![3], WhitelistedDomains::<Test>::get().to_vec());
+		assert_eq!(Some(3), DomainAliases::<Test>::get(2));
+		assert_eq!(new_broadcaster, Broadcasters::<Test>::get(3));
+		assert_eq!(
+			Some(ForkTransition {
+				new_domain: 3,
+				activation_slot: BRIDGE_DEV_SLOT + 1,
+				broadcaster: old_broadcaster,
+			}),
+			ForkTransitions::<Test>::get(2)
+		);
+		assert_eq!(Some(BRIDGE_DEV_SLOT + 1), ForkActivations::<Test>::get(3));
+		assert_bridge_event(Event::ForkTransitionScheduled {
+			old: 2,
+			new: 3,
+			activation_slot: BRIDGE_DEV_SLOT + 1,
+			broadcaster: new_broadcaster,
+		});
+	});
+}
+
+#[test]
+fn schedule_fork_transition_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::schedule_fork_transition(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			2,
+			3,
+			BRIDGE_DEV_SLOT + 1,
+			H256::repeat_byte(3),
+		);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn execute_fails_for_new_chain_id_before_fork() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		assert_ok!(Bridge::schedule_fork_transition(
+			RuntimeOrigin::root(),
+			2,
+			3,
+			BRIDGE_DEV_SLOT + 1,
+			H256::repeat_byte(3),
+		));
+		let mut message = bridge_dev_message();
+		message.origin_domain = 3;
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message,
+			account_proof,
+			storage_proof,
+		);
+
+		assert_err!(result, Error::<Test>::ForkNotActivated);
+	});
+}
+
+#[test]
+fn execute_works_for_old_chain_id_after_fork() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		assert_ok!(Bridge::schedule_fork_transition(
+			RuntimeOrigin::root(),
+			2,
+			3,
+			BRIDGE_DEV_SLOT,
+			H256::repeat_byte(3),
+		));
+
+		// proven against the broadcaster the message was sent with before the fork
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			bridge_dev_message(),
+			account_proof,
+			storage_proof,
+		);
+
+		assert_ok!(result);
+	});
+}
+
+#[test]
+fn schedule_function_ids_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let value = (H256::repeat_byte(1), H256::repeat_byte(2));
+
+		let result = Bridge::schedule_function_ids(RuntimeOrigin::root(), Some(value), 10);
+
+		assert_ok!(result);
+		assert_eq!(Some((10, value)), ScheduledFunctionIds::<Test>::get());
+		assert_ne!(Some(value), FunctionIds::<Test>::get());
+		assert_bridge_event(Event::FunctionIdsScheduled {
+			value,
+			activation_block: 10,
+		});
+
+		// scheduling none cancels the scheduled function ids
+		assert_ok!(Bridge::schedule_function_ids(
+			RuntimeOrigin::root(),
+			None,
+			0
+		));
+		assert_eq!(None, ScheduledFunctionIds::<Test>::get());
+	});
+}
+
+#[test]
+fn schedule_function_ids_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::schedule_function_ids(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			Some((H256::repeat_byte(1), H256::repeat_byte(2))),
+			10,
+		);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn schedule_function_ids_fails_for_passed_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+
+		let result = Bridge::schedule_function_ids(
+			RuntimeOrigin::root(),
+			Some((H256::repeat_byte(1), H256::repeat_byte(2))),
+			10,
+		);
+
+		assert_err!(result, Error::<Test>::ActivationBlockPassed);
+	});
+}
+
+#[test]
+fn scheduled_function_ids_are_activated() {
+	new_test_ext().execute_with(|| {
+		let current = FunctionIds::<Test>::get();
+		let value = (H256::repeat_byte(1), H256::repeat_byte(2));
+		assert_ok!(Bridge::schedule_function_ids(
+			RuntimeOrigin::root(),
+			Some(value),
+			10
+		));
+
+		Bridge::on_initialize(9);
+		assert_eq!(current, FunctionIds::<Test>::get());
+
+		Bridge::on_initialize(10);
+		assert_eq!(Some(value), FunctionIds::<Test>::get());
+		assert_eq!(None, ScheduledFunctionIds::<Test>::get());
+		assert_bridge_event(Event::FunctionIdsActivated { value });
+	});
+}
+
+#[test]
+fn fulfill_call_inherent_updates_head() {
+	new_test_ext().execute_with(|| {
+		let step = Submission::step();
+		let head = step.parsed.store.finalized_header.slot.as_u64();
+		let inputs = compact_step(&step, step.step_slot() - head);
+
+		let signed = Bridge::fulfill_call_inherent(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256::zero(),
+			inputs.clone(),
+		);
+		assert_err!(signed, BadOrigin);
+
+		// no updater is needed, the block author includes the proof
+		let result =
+			Bridge::fulfill_call_inherent(RuntimeOrigin::none(), H256::zero(), inputs.clone());
+
+		assert_ok!(result);
+		assert_eq!(step.step_slot(), Head::<Test>::get());
+		// the step is not attributed to an updater
+		assert_eq!(None, AcceptedHeaders::<Test>::get()[0].updater);
+	});
+}
+
+#[test]
+fn fulfill_call_inherent_is_created_from_inherent_data() {
+	new_test_ext().execute_with(|| {
+		let step = Submission::step();
+		let head = step.parsed.store.finalized_header.slot.as_u64();
+		let inputs = compact_step(&step, step.step_slot() - head);
+		let mut data = InherentData::new();
+		assert_eq!(None, Bridge::create_inherent(&data));
+
+		let step = StepInherentData {
+			function_id: H256::zero(),
+			inputs: inputs.clone(),
+		};
+		data.put_data(INHERENT_IDENTIFIER, &step).unwrap();
+		let call = Bridge::create_inherent(&data).unwrap();
+
+		assert!(Bridge::is_inherent(&call));
+		assert!(Bridge::check_inherent(&call, &data).is_ok());
+		assert_eq!(
+			Call::fulfill_call_inherent {
+				function_id: H256::zero(),
+				inputs: inputs.clone(),
+			},
+			call
+		);
+
+		// inputs already submitted by an updater are not included again
+		let input_hash = H256(keccak_256(inputs.as_slice()));
+		VerifiedInputs::<Test>::set(BoundedVec::truncate_from(vec![(input_hash, 11)]));
+		assert_eq!(None, Bridge::create_inherent(&data));
+	});
+}
+
+#[test]
+fn fulfill_call_inherent_rejects_forged_store() {
+	new_test_ext().execute_with(|| {
+		// full inputs carrying a light client store of the block author's choosing
+		let mut forged = Submission::step().parsed;
+		forged.store = Submission::rotate().parsed.store;
+		let inputs = serde_cbor::to_vec(&forged).unwrap();
+		let head = Head::<Test>::get();
+
+		let mut data = InherentData::new();
+		let step = StepInherentData {
+			function_id: H256::zero(),
+			inputs: inputs.clone(),
+		};
+		data.put_data(INHERENT_IDENTIFIER, &step).unwrap();
+		assert_eq!(None, Bridge::create_inherent(&data));
+
+		let call = Call::fulfill_call_inherent {
+			function_id: H256::zero(),
+			inputs: inputs.clone(),
+		};
+		assert!(Bridge::check_inherent(&call, &data).is_err());
+
+		let result = Bridge::fulfill_call_inherent(RuntimeOrigin::none(), H256::zero(), inputs);
+		assert_err!(result, Error::<Test>::InvalidInputs);
+		assert_eq!(head, Head::<Test>::get());
+	});
+}
+
+// `personal_sign` of "hello" by the private key 1
+const HELLO_SIGNER: H160 = H160(hex!("7e5f4552091a69125d5dfcb7b8c2659029395bdf"));
+const HELLO_SIGNATURE: [u8; 65] = hex!("bb50e2d89a4ed70663d080659fe0ad4b9bc3e06c17a227433966cb59ceee020d4e9612c28c317102d1fc08e13514a54dddeeea31622a5686b3f886e305d14bfd1c");
+
+#[test]
+fn attest_signature_works_with_ecdsa() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::attest_signature(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			HELLO_SIGNER,
+			BoundedVec::truncate_from(b"hello".to_vec()),
+			SignatureProof::Ecdsa(HELLO_SIGNATURE),
+		);
+
+		assert_ok!(result);
+		let message_hash = attestation::message_hash(b"hello");
+		assert_eq!(
+			Some(1),
+			Attestations::<Test>::get(HELLO_SIGNER, message_hash)
+		);
+		assert_bridge_event(Event::SignatureAttested {
+			signer: HELLO_SIGNER,
+			message_hash,
+			kind: SignatureKind::Ecdsa,
+		});
+	});
+}
+
+#[test]
+fn attest_signature_fails_for_other_signer() {
+	new_test_ext().execute_with(|| {
+		let other_data = Bridge::attest_signature(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			HELLO_SIGNER,
+			BoundedVec::truncate_from(b"hello!".to_vec()),
+			SignatureProof::Ecdsa(HELLO_SIGNATURE),
+		);
+		assert_err!(other_data, Error::<Test>::InvalidEthereumSignature);
+
+		let other_signer = Bridge::attest_signature(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H160::repeat_byte(1),
+			BoundedVec::truncate_from(b"hello".to_vec()),
+			SignatureProof::Ecdsa(HELLO_SIGNATURE),
+		);
+		assert_err!(other_signer, Error::<Test>::InvalidEthereumSignature);
+	});
+}
+
+#[test]
+fn attest_signature_fails_for_contract_with_invalid_proof() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::attest_signature(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			HELLO_SIGNER,
+			BoundedVec::truncate_from(b"hello".to_vec()),
+			SignatureProof::Contract {
+				slot: BRIDGE_DEV_SLOT,
+				mapping_slot: H256::from_low_u64_be(7),
+				account_proof: BoundedVec::new(),
+				storage_proof: BoundedVec::new(),
+			},
+		);
+
+		assert_err!(result, Error::<Test>::CannotGetStorageRoot);
+		assert_eq!(0, Attestations::<Test>::iter().count());
+	});
+}
+
+/// Stores a header root for `slot` committing to `block_number` and returns its proof.
+fn execution_block_number_proof(slot: u64, block_number: u64) -> ExecutionBlockNumberProof {
+	let (depth, index) = Bridge::consensus_fork(slot).header_execution_block_number_gindex();
+	let branch = vec![H256::repeat_byte(1); depth];
+	let header_root = beacon::branch_root(beacon::uint64_leaf(block_number), &branch, index);
+	Headers::<Test>::insert(slot, header_root);
+	ExecutionBlockNumberProof {
+		block_number,
+		branch: BoundedVec::truncate_from(branch),
+	}
+}
+
+#[test]
+fn prove_execution_block_number_works() {
+	new_test_ext().execute_with(|| {
+		let proof = execution_block_number_proof(8_000_000, 19_000_000);
+
+		let result = Bridge::prove_execution_block_number(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			8_000_000,
+			proof,
+		);
+
+		assert_ok!(result);
+		assert_eq!(Some(19_000_000), Bridge::block_number_for_slot(8_000_000));
+		assert_eq!(None, Bridge::block_number_for_slot(8_000_001));
+		assert_bridge_event(Event::ExecutionBlockNumberProven {
+			slot: 8_000_000,
+			block_number: 19_000_000,
+		});
+	});
+}
+
+#[test]
+fn prove_execution_block_number_fails_for_invalid_proof() {
+	new_test_ext().execute_with(|| {
+		let mut proof = execution_block_number_proof(8_000_000, 19_000_000);
+		proof.block_number = 19_000_001;
+
+		let wrong_number = Bridge::prove_execution_block_number(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			8_000_000,
+			proof.clone(),
+		);
+		assert_err!(
+			wrong_number,
+			Error::<Test>::InvalidExecutionBlockNumberProof
+		);
+
+		let unknown_slot = Bridge::prove_execution_block_number(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			8_000_001,
+			proof,
+		);
+		assert_err!(unknown_slot, Error::<Test>::HeaderRootNotSet);
+		assert_eq!(None, Bridge::block_number_for_slot(8_000_000));
+	});
+}
+
+#[test]
+fn prove_execution_block_number_fails_when_already_proven() {
+	new_test_ext().execute_with(|| {
+		let proof = execution_block_number_proof(8_000_000, 19_000_000);
+		assert_ok!(Bridge::prove_execution_block_number(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			8_000_000,
+			proof.clone()
+		));
+
+		let result = Bridge::prove_execution_block_number(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			8_000_000,
+			proof,
+		);
+
+		assert_err!(result, Error::<Test>::ExecutionBlockNumberAlreadyProven);
+	});
+}
+
+#[test]
+fn execute_reuses_account_proven_in_block() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		let message_root = H256(keccak_256(&message.clone().abi_encode()));
+		let first = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message.clone(),
+			account_proof,
+			storage_proof.clone(),
+		);
+		assert_ok!(first);
+		assert_eq!(None, first.unwrap().actual_weight);
+		assert_eq!(1, VerifiedAccountProofs::<Test>::get().len());
+
+		// The account proof is not verified again for the next message of the broadcaster.
+		MessageStatus::<Test>::remove(message_root);
+		let account_proof = BoundedVec::default();
+		let weight = weight_helper::execute::<Test>(&message, &account_proof, &storage_proof);
+		let second = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message,
+			account_proof.clone(),
+			storage_proof,
+		);
+
+		assert_ok!(second);
+		let saved = weight_helper::reused_account_proof::<Test>(&account_proof);
+		assert_eq!(
+			Some(weight.saturating_sub(saved)),
+			second.unwrap().actual_weight
+		);
+	});
+}
+
+#[test]
+fn verified_account_proofs_are_cleared_on_finalize() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		assert_ok!(Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			bridge_dev_message(),
+			account_proof,
+			storage_proof
+		));
+		assert!(!VerifiedAccountProofs::<Test>::get().is_empty());
+
+		Bridge::on_finalize(1);
+
+		assert!(VerifiedAccountProofs::<Test>::get().is_empty());
+	});
+}
+
+/// Queues a message to the EVM contract `contract` following `message` in its ordered channel.
+fn queue_evm_message(message: &AddressedMessage, contract: H160, data: &[u8]) -> AddressedMessage {
+	let next = queue_next_message(message);
+	let mut evm_message = next.clone();
+	evm_message.message = Message::ArbitraryMessage(BoundedVec::truncate_from(data.to_vec()));
+	evm_message.to = H256::from(contract);
+	QueuedMessages::<Test>::insert(
+		(next.origin_domain, next.from),
+		next.id,
+		evm_message.clone(),
+	);
+	EvmContracts::set(vec![contract]);
+	evm_message
+}
+
+#[test]
+fn execute_calls_evm_contract() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		OrderedChannels::<Test>::insert(2, message.from, 0);
+		let contract = H160::repeat_byte(7);
+		let evm_message = queue_evm_message(&message, contract, b"Hello, World!");
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message.clone(),
+			account_proof,
+			storage_proof,
+		);
+
+		assert_ok!(result);
+		let source = H160::from_slice(&message.from.as_bytes()[..20]);
+		assert_eq!(
+			vec![(source, contract, b"Hello, World!".to_vec(), 1_000_000)],
+			EvmCalls::get()
+		);
+		let message_root = H256(keccak_256(&evm_message.clone().abi_encode()));
+		assert_eq!(
+			MessageStatusEnum::ExecutionSucceeded,
+			MessageStatus::<Test>::get(message_root)
+		);
+	});
+}
+
+#[test]
+fn execute_marks_reverted_evm_call_as_failed() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		OrderedChannels::<Test>::insert(2, message.from, 0);
+		let evm_message = queue_evm_message(&message, H160::repeat_byte(7), b"revert");
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message.clone(),
+			account_proof,
+			storage_proof,
+		);
+
+		assert_ok!(result);
+		assert!(EvmCalls::get().is_empty());
+		let message_root = H256(keccak_256(&evm_message.clone().abi_encode()));
+		assert_eq!(
+			MessageStatusEnum::ExecutionFailed,
+			MessageStatus::<Test>::get(message_root)
+		);
+		assert_eq!(
+			Some(FailureCode::HandlerReverted),
+			MessageFailures::<Test>::get(message_root).map(|failure| failure.code)
+		);
+		assert_eq!(Some(2), OrderedChannels::<Test>::get(2, message.from));
+		assert!(OutboundAcknowledgements::<Test>::get().is_empty());
+		assert_bridge_event(Event::MessageExecutionFailed {
+			from: evm_message.from,
+			to: evm_message.to,
+			message_id: evm_message.id,
+			message_root,
+			error: Error::<Test>::EvmCallReverted.into(),
+		});
+	});
+}
+
+#[test]
+fn execute_credits_evm_balance_of_contract() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		OrderedChannels::<Test>::insert(2, message.from, 0);
+		let contract = H160::repeat_byte(7);
+		let mut evm_message = queue_evm_message(&message, contract, b"");
+		evm_message.message = Message::FungibleToken {
+			asset_id: H256::zero(),
+			amount: 1_000_000_000_000_000_000,
+		};
+		QueuedMessages::<Test>::insert(
+			(evm_message.origin_domain, evm_message.from),
+			evm_message.id,
+			evm_message.clone(),
+		);
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message,
+			account_proof,
+			storage_proof,
+		);
+
+		assert_ok!(result);
+		let evm_account = AccountId32::from(H256::from(contract).0);
+		assert_eq!(
+			1_000_000_000_000_000_000,
+			Balances::free_balance(&evm_account)
+		);
+		assert!(EvmCalls::get().is_empty());
+	});
+}
+
+#[test]
+fn quote_message_weight_covers_execution_of_inbound_message() {
+	new_test_ext().execute_with(|| {
+		let token = Bridge::quote_message_weight(MessageDirection::Inbound, 0);
+		let small = Bridge::quote_message_weight(MessageDirection::Inbound, 10);
+		let large = Bridge::quote_message_weight(MessageDirection::Inbound, 1_000);
+
+		assert_eq!(weight_helper::quoted_execute::<Test>(0), token);
+		assert!(large.all_gte(small));
+		assert!(token.all_gte(weight_helper::verified_account_proof::<Test>()));
+	});
+}
+
+#[test]
+fn quote_message_weight_covers_send_message_of_outbound_message() {
+	new_test_ext().execute_with(|| {
+		let message = Message::ArbitraryMessage(BoundedVec::truncate_from(vec![1; 100]));
+		let call = Call::<Test>::send_message {
+			message,
+			to: H256::zero(),
+			domain: 2,
+		};
+
+		let quoted = Bridge::quote_message_weight(MessageDirection::Outbound, 100);
+
+		assert_eq!(call.get_dispatch_info().weight, quoted);
+	});
+}
+
+#[test]
+fn quote_destination_fee_uses_proven_base_fee() {
+	new_test_ext().execute_with(|| {
+		let gas = fees::destination_gas(100);
+
+		assert_eq!(
+			(gas, None),
+			Bridge::quote_destination_fee(MessageDirection::Outbound, 100, 2)
+		);
+
+		SourceBaseFees::<Test>::insert(
+			2,
+			SourceBaseFee {
+				slot: 10,
+				base_fee: U256::from(7),
+			},
+		);
+
+		assert_eq!(
+			(gas, Some(U256::from(7 * gas))),
+			Bridge::quote_destination_fee(MessageDirection::Outbound, 100, 2)
+		);
+		assert_eq!(
+			(0, None),
+			Bridge::quote_destination_fee(MessageDirection::Inbound, 100, 2)
+		);
+	});
+}
+
+#[test]
+fn execute_calls_message_hooks() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message.clone(),
+			account_proof,
+			storage_proof,
+		);
+
+		assert_ok!(result);
+		let message_root = H256(keccak_256(&message.clone().abi_encode()));
+		assert_eq!(vec![message_root], ReceivedMessages::get());
+		assert_eq!(vec![(message_root, true)], ExecutedMessages::get());
+	});
+}
+
+#[test]
+fn execute_reports_failed_message_to_hooks() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		OrderedChannels::<Test>::insert(2, message.from, 0);
+		let evm_message = queue_evm_message(&message, H160::repeat_byte(7), b"revert");
+
+		let result = Bridge::execute(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message.clone(),
+			account_proof,
+			storage_proof,
+		);
+
+		assert_ok!(result);
+		let message_root = H256(keccak_256(&message.clone().abi_encode()));
+		let evm_message_root = H256(keccak_256(&evm_message.clone().abi_encode()));
+		assert_eq!(vec![message_root], ReceivedMessages::get());
+		assert_eq!(
+			vec![(message_root, true), (evm_message_root, false)],
+			ExecutedMessages::get()
+		);
+	});
+}
+
+#[test]
+fn message_hooks_weight_sums_hooks() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			Weight::from_parts(3_000, 0),
+			weight_helper::message_hooks::<Test>()
+		);
+	});
+}
+
+#[test]
+fn register_destination_handler_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let destination = H256(TEST_SENDER_VEC);
+		RuntimeCodeHash::<Test>::put(H256::repeat_byte(1));
+
+		let result = Bridge::register_destination_handler(
+			RuntimeOrigin::root(),
+			destination,
+			TEST_HANDLER_ID,
+			Some(100),
+		);
+
+		assert_ok!(result);
+		let registration = HandlerRegistration {
+			handler: TEST_HANDLER_ID,
+			code_hash: H256::repeat_byte(1),
+			expiry: Some(100),
+		};
+		assert_eq!(
+			Some(registration),
+			DestinationHandlers::<Test>::get(destination)
+		);
+		assert_bridge_event(Event::DestinationHandlerRegistered {
+			destination,
+			handler: TEST_HANDLER_ID,
+			code_hash: H256::repeat_byte(1),
+			expiry: Some(100),
+		});
+	});
+}
+
+#[test]
+fn register_destination_handler_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::register_destination_handler(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256(TEST_SENDER_VEC),
+			TEST_HANDLER_ID,
+			None,
+		);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+#[test]
+fn register_destination_handler_fails_for_past_expiry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+
+		let result = Bridge::register_destination_handler(
+			RuntimeOrigin::root(),
+			H256(TEST_SENDER_VEC),
+			TEST_HANDLER_ID,
+			Some(9),
+		);
+
+		assert_err!(result, Error::<Test>::InvalidHandlerExpiry);
+	});
+}
+
+#[test]
+fn deregister_destination_handler_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let destination = H256(TEST_SENDER_VEC);
+		assert_ok!(Bridge::register_destination_handler(
+			RuntimeOrigin::root(),
+			destination,
+			TEST_HANDLER_ID,
+			None,
+		));
+
+		let result = Bridge::deregister_destination_handler(RuntimeOrigin::root(), destination);
+
+		assert_ok!(result);
+		assert!(!DestinationHandlers::<Test>::contains_key(destination));
+		assert_bridge_event(Event::DestinationHandlerDeregistered { destination });
+	});
+}
+
+#[test]
+fn deregister_destination_handler_fails_when_not_registered() {
+	new_test_ext().execute_with(|| {
+		let result =
+			Bridge::deregister_destination_handler(RuntimeOrigin::root(), H256(TEST_SENDER_VEC));
+
+		assert_err!(result, Error::<Test>::DestinationHandlerNotRegistered);
+	});
+}
+
+/// Executes `bridge_dev_message`, followed by the queued arbitrary message to a destination
+/// registered with `registration`, and returns the latter. Its sender is acknowledged, so its
+/// failure is recorded.
+fn execute_handled_message(registration: HandlerRegistration) -> AddressedMessage {
+	let (account_proof, storage_proof) = setup_bridge_dev_message();
+	let message = bridge_dev_message();
+	OrderedChannels::<Test>::insert(2, message.from, 0);
+	AcknowledgedSenders::<Test>::insert(2, message.from, true);
+	let next = queue_next_message(&message);
+	DestinationHandlers::<Test>::insert(next.to, registration);
+
+	assert_ok!(Bridge::execute(
+		RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+		BRIDGE_DEV_SLOT,
+		message,
+		account_proof,
+		storage_proof,
+	));
+	next
+}
+
+/// Registration of `handler` for the current runtime code, without expiry.
+fn handler_registration(handler: u32) -> HandlerRegistration {
+	HandlerRegistration {
+		handler,
+		code_hash: RuntimeCodeHash::<Test>::get(),
+		expiry: None,
+	}
+}
+
+/// Asserts that the execution of `addr_message` failed with `error`.
+fn assert_execution_failed(addr_message: AddressedMessage, error: Error<Test>) {
+	let message_root = H256(keccak_256(&addr_message.clone().abi_encode()));
+	assert_eq!(
+		MessageStatusEnum::ExecutionFailed,
+		MessageStatus::<Test>::get(message_root)
+	);
+	assert_bridge_event(Event::MessageExecutionFailed {
+		from: addr_message.from,
+		to: addr_message.to,
+		message_id: addr_message.id,
+		message_root,
+		error: error.into(),
+	});
+}
+
+#[test]
+fn execute_hands_message_to_destination_handler() {
+	new_test_ext().execute_with(|| {
+		let next = execute_handled_message(handler_registration(TEST_HANDLER_ID));
+
+		let message_root = H256(keccak_256(&next.clone().abi_encode()));
+		assert_eq!(
+			vec![(TEST_HANDLER_ID, message_root)],
+			HandledMessages::get()
+		);
+		assert_eq!(
+			MessageStatusEnum::ExecutionSucceeded,
+			MessageStatus::<Test>::get(message_root)
+		);
+	});
+}
+
+#[test]
+fn execute_marks_message_failed_by_destination_handler_as_failed() {
+	new_test_ext().execute_with(|| {
+		let next = execute_handled_message(handler_registration(FAILING_HANDLER_ID));
+
+		let message_root = H256(keccak_256(&next.clone().abi_encode()));
+		assert_eq!(
+			MessageStatusEnum::ExecutionFailed,
+			MessageStatus::<Test>::get(message_root)
+		);
+		assert!(HandledMessages::get().is_empty());
+	});
+}
+
+#[test]
+fn execute_fails_for_unknown_destination_handler() {
+	new_test_ext().execute_with(|| {
+		let next = execute_handled_message(handler_registration(TEST_HANDLER_ID + 100));
+
+		assert_execution_failed(next, Error::<Test>::UnknownDestinationHandler);
+	});
+}
+
+#[test]
+fn execute_fails_for_expired_destination_handler() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+		let registration = HandlerRegistration {
+			expiry: Some(9),
+			..handler_registration(TEST_HANDLER_ID)
+		};
+
+		let next = execute_handled_message(registration);
+
+		assert!(HandledMessages::get().is_empty());
+		assert_execution_failed(next, Error::<Test>::DestinationHandlerExpired);
+	});
+}
+
+#[test]
+fn execute_fails_for_destination_handler_of_other_code() {
+	new_test_ext().execute_with(|| {
+		// Registered before a runtime upgrade.
+		let registration = HandlerRegistration {
+			code_hash: H256::repeat_byte(9),
+			..handler_registration(TEST_HANDLER_ID)
+		};
+
+		let next = execute_handled_message(registration);
+
+		assert!(HandledMessages::get().is_empty());
+		assert_execution_failed(next, Error::<Test>::DestinationHandlerCodeChanged);
+	});
+}
+
+#[test]
+fn set_governance_sender_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let sender = Some((2, H256::repeat_byte(1)));
+
+		let result = Bridge::set_governance_sender(RuntimeOrigin::root(), sender);
+
+		assert_ok!(result);
+		assert_eq!(sender, GovernanceSender::<Test>::get());
+		assert_bridge_event(Event::GovernanceSenderUpdated { sender });
+	});
+}
+
+#[test]
+fn set_governance_sender_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::set_governance_sender(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			Some((2, H256::repeat_byte(1))),
+		);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+/// Executes `bridge_dev_message`, followed by the queued governance message with the encoded
+/// `call`, and returns the latter. The governance sender is acknowledged, so the failure of its
+/// message is recorded.
+fn execute_governance_message(call: &Call<Test>) -> AddressedMessage {
+	let (account_proof, storage_proof) = setup_bridge_dev_message();
+	let message = bridge_dev_message();
+	OrderedChannels::<Test>::insert(2, message.from, 0);
+	AcknowledgedSenders::<Test>::insert(2, message.from, true);
+	GovernanceSender::<Test>::put((2, message.from));
+	let mut next = queue_next_message(&message);
+	next.message = Message::ArbitraryMessage(BoundedVec::truncate_from(call.encode()));
+	QueuedMessages::<Test>::insert((next.origin_domain, next.from), next.id, next.clone());
+
+	assert_ok!(Bridge::execute(
+		RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+		BRIDGE_DEV_SLOT,
+		message,
+		account_proof,
+		storage_proof,
+	));
+	next
+}
+
+#[test]
+fn execute_schedules_governance_call() {
+	new_test_ext().execute_with(|| {
+		let call = Call::<Test>::set_proof_retention {
+			retention: Some(10),
+		};
+
+		let next = execute_governance_message(&call);
+
+		let message_root = H256(keccak_256(&next.clone().abi_encode()));
+		let scheduled = GovernanceCall {
+			call_hash: H256(blake2_256(&call.encode())),
+			executable_at: System::block_number() as u32 + 100,
+		};
+		assert_eq!(Some(scheduled), GovernanceCalls::<Test>::get(message_root));
+		assert_eq!(None, ProofRetention::<Test>::get());
+		assert_bridge_event(Event::GovernanceCallScheduled {
+			message_root,
+			call_hash: scheduled.call_hash,
+			executable_at: scheduled.executable_at,
+		});
+	});
+}
+
+#[test]
+fn execute_fails_for_governance_message_not_whitelisted() {
+	new_test_ext().execute_with(|| {
+		let call = Call::<Test>::set_updater {
+			updater: H256::repeat_byte(1),
+		};
+
+		let next = execute_governance_message(&call);
+
+		let message_root = H256(keccak_256(&next.clone().abi_encode()));
+		assert!(!GovernanceCalls::<Test>::contains_key(message_root));
+		assert_execution_failed(next, Error::<Test>::InvalidGovernanceCall);
+	});
+}
+
+#[test]
+fn execute_fails_for_governance_message_changing_trust_root() {
+	new_test_ext().execute_with(|| {
+		let call = Call::<Test>::set_broadcaster {
+			broadcaster_domain: 2,
+			broadcaster: H256::repeat_byte(1),
+		};
+
+		let next = execute_governance_message(&call);
+
+		let message_root = H256(keccak_256(&next.clone().abi_encode()));
+		assert!(!GovernanceCalls::<Test>::contains_key(message_root));
+		assert_execution_failed(next, Error::<Test>::InvalidGovernanceCall);
+	});
+}
+
+#[test]
+fn execute_governance_call_works_after_delay() {
+	new_test_ext().execute_with(|| {
+		let call = Call::<Test>::set_proof_retention {
+			retention: Some(10),
+		};
+		let next = execute_governance_message(&call);
+		let message_root = H256(keccak_256(&next.clone().abi_encode()));
+		System::set_block_number(System::block_number() + 100);
+
+		let result = Bridge::execute_governance_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			message_root,
+			Box::new(call),
+		);
+
+		assert_ok!(result);
+		assert_eq!(Some(10), ProofRetention::<Test>::get());
+		assert!(!GovernanceCalls::<Test>::contains_key(message_root));
+		assert_bridge_event(Event::GovernanceCallExecuted {
+			message_root,
+			result: Ok(()),
+		});
+	});
+}
+
+#[test]
+fn execute_governance_call_fails_before_delay() {
+	new_test_ext().execute_with(|| {
+		let call = Call::<Test>::set_proof_retention {
+			retention: Some(10),
+		};
+		let next = execute_governance_message(&call);
+		let message_root = H256(keccak_256(&next.clone().abi_encode()));
+		System::set_block_number(System::block_number() + 99);
+
+		let result = Bridge::execute_governance_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			message_root,
+			Box::new(call),
+		);
+
+		assert_err!(result, Error::<Test>::GovernanceCallNotExecutable);
+	});
+}
+
+#[test]
+fn execute_governance_call_fails_for_other_call() {
+	new_test_ext().execute_with(|| {
+		let call = Call::<Test>::set_proof_retention {
+			retention: Some(10),
+		};
+		let next = execute_governance_message(&call);
+		let message_root = H256(keccak_256(&next.clone().abi_encode()));
+		System::set_block_number(System::block_number() + 100);
+		let other = Call::<Test>::set_proof_retention {
+			retention: Some(20),
+		};
+
+		let result = Bridge::execute_governance_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			message_root,
+			Box::new(other),
+		);
+
+		assert_err!(result, Error::<Test>::GovernanceCallMismatch);
+	});
+}
+
+#[test]
+fn cancel_governance_call_works_with_root() {
+	new_test_ext().execute_with(|| {
+		let call = Call::<Test>::set_proof_retention {
+			retention: Some(10),
+		};
+		let next = execute_governance_message(&call);
+		let message_root = H256(keccak_256(&next.clone().abi_encode()));
+
+		let result = Bridge::cancel_governance_call(RuntimeOrigin::root(), message_root);
+
+		assert_ok!(result);
+		assert!(!GovernanceCalls::<Test>::contains_key(message_root));
+		assert_bridge_event(Event::GovernanceCallCancelled { message_root });
+	});
+}
+
+#[test]
+fn cancel_governance_call_does_not_work_with_non_root() {
+	new_test_ext().execute_with(|| {
+		let result = Bridge::cancel_governance_call(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			H256::repeat_byte(1),
+		);
+
+		assert_err!(result, BadOrigin);
+	});
+}
+
+/// Compresses the SCALE encoding of `proof` with zstd.
+fn compress_proof(proof: &ValidProof<Test>) -> CompressedProof<Test> {
+	BoundedVec::truncate_from(compression::compress(&proof.encode()))
+}
+
+#[test]
+fn execute_compressed_works() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, storage_proof) = setup_bridge_dev_message();
+		let message = bridge_dev_message();
+		let message_root = H256(keccak_256(&message.clone().abi_encode()));
+		let (compressed_account_proof, compressed_storage_proof) = (
+			compress_proof(&account_proof),
+			compress_proof(&storage_proof),
+		);
+		assert!(compressed_account_proof.len() < account_proof.encoded_size());
+
+		let result = Bridge::execute_compressed(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			message.clone(),
+			compressed_account_proof,
+			compressed_storage_proof,
+		);
+
+		assert_ok!(result);
+		assert_eq!(
+			MessageStatusEnum::ExecutionSucceeded,
+			MessageStatus::<Test>::get(message_root)
+		);
+		// charged for the decompressed proofs instead of the largest ones
+		let actual_weight = result.unwrap().actual_weight.unwrap();
+		assert_eq!(
+			weight_helper::execute::<Test>(&message, &account_proof, &storage_proof)
+				.saturating_add(weight_helper::decompressed_proofs::<Test>(
+					&account_proof,
+					&storage_proof
+				)),
+			actual_weight
+		);
+		assert!(
+			actual_weight.ref_time()
+				< weight_helper::execute_compressed::<Test>(&message).ref_time()
+		);
+	});
+}
+
+#[test]
+fn execute_compressed_fails_for_invalid_proof() {
+	new_test_ext().execute_with(|| {
+		let (_, storage_proof) = setup_bridge_dev_message();
+
+		let result = Bridge::execute_compressed(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			bridge_dev_message(),
+			BoundedVec::truncate_from(vec![1, 2, 3, 4]),
+			compress_proof(&storage_proof),
+		);
+
+		assert_err!(result, Error::<Test>::InvalidCompressedProof);
+	});
+}
+
+#[test]
+fn execute_compressed_fails_for_proof_above_max_size() {
+	new_test_ext().execute_with(|| {
+		let (account_proof, _) = setup_bridge_dev_message();
+		let oversized = vec![0u8; ValidProof::<Test>::max_encoded_len() + 1];
+		let compressed = compression::compress(&oversized);
+
+		let result = Bridge::execute_compressed(
+			RuntimeOrigin::signed(TEST_SENDER_ACCOUNT),
+			BRIDGE_DEV_SLOT,
+			bridge_dev_message(),
+			compress_proof(&account_proof),
+			BoundedVec::truncate_from(compressed),
+		);
+
+		assert_err!(result, Error::<Test>::DecompressedProofTooLarge);
+	});
+}
@@ -25,6 +25,41 @@ pub struct Verifier {
 	pub vk_json: VerifyingKeyJson,
 }
 
+/// Length of an untagged proof, ABI encoded as `(uint256[2], uint256[2][2], uint256[2])`.
+const ABI_PROOF_LENGTH: usize = 256;
+/// Length of an encoded base field element.
+const FQ_LENGTH: usize = 32;
+
+/// Mask of the flag bits of the first byte of a gnark encoded point.
+const GNARK_FLAG_MASK: u8 = 0b11 << 6;
+const GNARK_UNCOMPRESSED: u8 = 0b00 << 6;
+const GNARK_INFINITY: u8 = 0b01 << 6;
+const GNARK_COMPRESSED_LARGEST: u8 = 0b11 << 6;
+
+/// Encodings of a Groth16 proof accepted by `Verifier::verify`, selected by the first byte of
+/// the proof. A proof of exactly `ABI_PROOF_LENGTH` bytes is untagged and ABI encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ProofFormat {
+	/// ABI encoding of the Solidity verifiers, the layout of the untagged proofs.
+	Abi = 0,
+	/// `proof.json` written by snarkjs, with decimal coordinates.
+	SnarkJs = 1,
+	/// Binary encoding written by gnark, with compressed or uncompressed points.
+	Gnark = 2,
+}
+
+impl ProofFormat {
+	pub fn from_tag(tag: u8) -> Option<Self> {
+		match tag {
+			0 => Some(Self::Abi),
+			1 => Some(Self::SnarkJs),
+			2 => Some(Self::Gnark),
+			_ => None,
+		}
+	}
+}
+
 #[derive(Debug)]
 pub enum VKeyDeserializationError {
 	SerdeError,
@@ -163,10 +198,7 @@ impl Verifier {
 		let output_hash_byte_swap = output_hash[0] & bits_mask;
 		output_swap[0] = output_hash_byte_swap;
 
-		let decoded: (Vec<String>, Vec<Vec<String>>, Vec<String>) = decode_proof(proof)?;
-
-		let circom_proof = CircomProof::new(decoded.0, decoded.1, decoded.2);
-		let proof = circom_proof.proof()?;
+		let proof = decode_groth16_proof(&proof)?;
 
 		let mut input = vec!["0".to_string(); 2];
 		input[0] = U256::from_big_endian(output_swap.as_slice()).to_string();
@@ -187,6 +219,123 @@ impl Verifier {
 	}
 }
 
+/// Decodes a Groth16 proof in any of the `ProofFormat`s and checks that its points are on the
+/// curve and in the right subgroup.
+pub fn decode_groth16_proof(proof: &[u8]) -> Result<Proof<Bn254>, VerificationError> {
+	let proof = if proof.len() == ABI_PROOF_LENGTH {
+		decode_abi_proof(proof)?
+	} else {
+		let (tag, encoded) = proof.split_first().ok_or(VerificationError::InvalidProof)?;
+		match ProofFormat::from_tag(*tag).ok_or(VerificationError::InvalidProof)? {
+			ProofFormat::Abi => decode_abi_proof(encoded)?,
+			ProofFormat::SnarkJs => decode_snarkjs_proof(encoded)?,
+			ProofFormat::Gnark => decode_gnark_proof(encoded)?,
+		}
+	};
+
+	let valid = proof.a.is_on_curve()
+		&& proof.c.is_on_curve()
+		&& proof.b.is_on_curve()
+		&& proof.b.is_in_correct_subgroup_assuming_on_curve();
+	if !valid {
+		return Err(VerificationError::InvalidProof);
+	}
+	Ok(proof)
+}
+
+fn decode_abi_proof(proof: &[u8]) -> Result<Proof<Bn254>, VerificationError> {
+	if proof.len() != ABI_PROOF_LENGTH {
+		return Err(VerificationError::InvalidProof);
+	}
+	let (a, b, c) = decode_proof(proof.to_vec())?;
+	CircomProof::new(a, b, c)
+		.proof()
+		.map_err(|_| VerificationError::InvalidProof)
+}
+
+fn decode_snarkjs_proof(json: &[u8]) -> Result<Proof<Bn254>, VerificationError> {
+	let proof: CircomProof =
+		serde_json::from_slice(json).map_err(|_| VerificationError::InvalidProof)?;
+	// the points are projective, the third coordinates of the affine points are ignored
+	let well_formed = proof.protocol == "groth16"
+		&& proof.curve == "bn128"
+		&& proof.pi_a.len() >= 2
+		&& proof.pi_b.len() >= 2
+		&& proof.pi_b[..2]
+			.iter()
+			.all(|coordinate| coordinate.len() == 2)
+		&& proof.pi_c.len() >= 2;
+	if !well_formed {
+		return Err(VerificationError::InvalidProof);
+	}
+	proof.proof().map_err(|_| VerificationError::InvalidProof)
+}
+
+/// Decodes the `Ar`, `Bs` and `Krs` points of a gnark proof. The points are all compressed or
+/// all uncompressed, as written by `WriteTo` and `WriteRawTo`.
+fn decode_gnark_proof(encoded: &[u8]) -> Result<Proof<Bn254>, VerificationError> {
+	let flags = encoded.first().ok_or(VerificationError::InvalidProof)? & GNARK_FLAG_MASK;
+	let g1_length = match flags {
+		GNARK_UNCOMPRESSED => 2 * FQ_LENGTH,
+		_ => FQ_LENGTH,
+	};
+	if encoded.len() < 4 * g1_length {
+		return Err(VerificationError::InvalidProof);
+	}
+	let (a, rest) = encoded.split_at(g1_length);
+	let (b, rest) = rest.split_at(2 * g1_length);
+	let (c, commitments) = rest.split_at(g1_length);
+
+	// proofs of circuits with commitments need the commitment aware verifier; without
+	// commitments, recent gnark versions write an empty list and an unused proof of knowledge
+	let no_commitments = commitments.is_empty()
+		|| (commitments.len() == 4 + g1_length && commitments[..4] == [0u8; 4]);
+	if !no_commitments {
+		return Err(VerificationError::InvalidProof);
+	}
+
+	Ok(Proof {
+		a: gnark_g1(a)?,
+		b: gnark_g2(b)?,
+		c: gnark_g1(c)?,
+	})
+}
+
+fn gnark_g1(point: &[u8]) -> Result<G1Affine, VerificationError> {
+	let x = gnark_fq(point, 0)?;
+	match point[0] & GNARK_FLAG_MASK {
+		GNARK_UNCOMPRESSED => Ok(G1Affine::new(x, gnark_fq(point, 1)?, false)),
+		GNARK_INFINITY => Err(VerificationError::InvalidProof),
+		flags => G1Affine::get_point_from_x(x, flags == GNARK_COMPRESSED_LARGEST)
+			.ok_or(VerificationError::InvalidProof),
+	}
+}
+
+/// gnark writes the coordinates of a G2 point as `(A1, A0)`, like the Solidity verifiers.
+fn gnark_g2(point: &[u8]) -> Result<G2Affine, VerificationError> {
+	let x = Fq2::new(gnark_fq(point, 1)?, gnark_fq(point, 0)?);
+	match point[0] & GNARK_FLAG_MASK {
+		GNARK_UNCOMPRESSED => {
+			let y = Fq2::new(gnark_fq(point, 3)?, gnark_fq(point, 2)?);
+			Ok(G2Affine::new(x, y, false))
+		},
+		GNARK_INFINITY => Err(VerificationError::InvalidProof),
+		flags => G2Affine::get_point_from_x(x, flags == GNARK_COMPRESSED_LARGEST)
+			.ok_or(VerificationError::InvalidProof),
+	}
+}
+
+/// Reads the `index`th base field element of a gnark point, without the flag bits.
+fn gnark_fq(point: &[u8], index: usize) -> Result<Fq, VerificationError> {
+	let mut bytes = [0u8; FQ_LENGTH];
+	bytes.copy_from_slice(&point[index * FQ_LENGTH..(index + 1) * FQ_LENGTH]);
+	if index == 0 {
+		bytes[0] &= !GNARK_FLAG_MASK;
+	}
+	str_to_fq(&U256::from_big_endian(&bytes).to_string())
+		.map_err(|_| VerificationError::InvalidProof)
+}
+
 /// decode_proof decodes proof into points.
 #[allow(clippy::type_complexity)]
 pub fn decode_proof(
@@ -280,7 +429,9 @@ mod tests {
 	use sp_core::{H256, U256};
 	use sp_io::hashing::sha2_256;
 
-	use crate::verifier::{decode_proof, encode_packed, Verifier};
+	use crate::verifier::{
+		decode_groth16_proof, decode_proof, encode_packed, ProofFormat, VerificationError, Verifier,
+	};
 
 	#[test]
 	fn test_zk_step_with_serde() {
@@ -599,4 +750,59 @@ mod tests {
 		let res = encode_packed(stored_poseidon, stored_slot);
 		assert_eq!(requested_input_hash, sha2_256(res.as_slice()))
 	}
+	fn abi_proof() -> [u8; 256] {
+		hex!("1332c772a8f9a02f304b5472d3b6b75f1a494bd9b137fc663fd5b9b475992bc829ba08f7cfa745e340938e356b139224d0288b9511a5cec83235f969f61a94ed16a14579fa0adcc3bf8da36209f64547fd5ff4e1c7e8b5b151335b5b4a471de3115f83b696517ac68ae7620f7d3840e44aff4781c0a4d265a2905ef9bcaa04432a660197790e60d1135946ae0603ef69a5ecb45b6f8046167f902dc6d8a35cf716bce116484dfa4fcd5d8f4c2fda26d68754b56e68f1a877d95dc171accc34d71285068693fe3d8d28e66342c31292ceee5c6d87fcb8ad8c132363565f2aeff905726b2d35def5c9636dd5ec402d8d6f6c9a7be7977e7e5727da327ea5b079ad")
+	}
+
+	#[test]
+	fn test_decode_groth16_proof_formats() {
+		let abi = abi_proof();
+		let expected = decode_groth16_proof(&abi).unwrap();
+
+		let (a, b, c) = decode_proof(abi.to_vec()).unwrap();
+		let snarkjs = format!(
+			r#"{{"pi_a":["{}","{}","1"],"pi_b":[["{}","{}"],["{}","{}"],["1","0"]],"pi_c":["{}","{}","1"],"protocol":"groth16","curve":"bn128"}}"#,
+			a[0], a[1], b[0][0], b[0][1], b[1][0], b[1][1], c[0], c[1]
+		);
+
+		// gnark writes uncompressed points in the ABI layout, compressed points are the x
+		// coordinates flagged with the larger of the two y coordinates
+		let flag = |largest: bool| if largest { 0b11 << 6 } else { 0b10 << 6 };
+		let mut compressed = [&abi[0..32], &abi[64..128], &abi[192..224]].concat();
+		compressed[0] |= flag(expected.a.y > -expected.a.y);
+		compressed[32] |= flag(expected.b.y > -expected.b.y);
+		compressed[96] |= flag(expected.c.y > -expected.c.y);
+
+		for (format, encoded) in [
+			(ProofFormat::Abi, abi.to_vec()),
+			(ProofFormat::SnarkJs, snarkjs.into_bytes()),
+			(ProofFormat::Gnark, abi.to_vec()),
+			(ProofFormat::Gnark, compressed),
+		] {
+			let tagged = [vec![format as u8], encoded].concat();
+			assert_eq!(Ok(expected.clone()), decode_groth16_proof(&tagged));
+		}
+	}
+
+	#[test]
+	fn test_decode_groth16_proof_rejects_unsupported_proofs() {
+		let abi = abi_proof();
+		let unknown_format = [vec![3], abi.to_vec()].concat();
+		let gnark_with_commitment = [
+			vec![ProofFormat::Gnark as u8],
+			abi.to_vec(),
+			1u32.to_be_bytes().to_vec(),
+			abi[..128].to_vec(),
+		]
+		.concat();
+		let mut off_curve = abi;
+		off_curve[63] ^= 1;
+
+		for proof in [unknown_format, gnark_with_commitment, off_curve.to_vec()] {
+			assert_eq!(
+				Err(VerificationError::InvalidProof),
+				decode_groth16_proof(&proof)
+			);
+		}
+	}
 }
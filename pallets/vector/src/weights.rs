@@ -15,13 +15,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Autogenerated weights for `pallet_vector`
+//! Weights for `pallet_vector`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2024-03-26, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! The weights of the calls benchmarked on 2024-03-26 were generated with the Substrate
+//! benchmark CLI version 32.0.0, from the run below.
+//! STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `ip-172-31-12-189`, CPU: `Intel(R) Xeon(R) Platinum 8175M CPU @ 2.50GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! The weights of the calls added since are hand-written estimates, marked as such, until the
+//! file is regenerated with `scripts/run_benchmarks.sh`.
 
 // Executed Command:
 // ./target/release/avail-node
@@ -65,7 +69,54 @@ pub trait WeightInfo {
 	fn failed_tx_index(_l: u32) -> Weight { Weight::zero() }
 	fn set_step_verification_key() -> Weight;
 	fn set_rotate_verification_key() -> Weight;
-	fn set_updater() -> Weight;
+	fn set_source_address_version() -> Weight;
+	fn archive_epoch(s: u32, ) -> Weight;
+	fn verify_archive_proof(d: u32, ) -> Weight;
+	fn add_updater() -> Weight;
+	fn remove_updater() -> Weight;
+	fn fulfill_call_cached() -> Weight;
+	fn set_beacon_root_domain() -> Weight;
+	fn restore_bridge() -> Weight;
+	fn prove_message_absence() -> Weight;
+	fn set_message_schema() -> Weight;
+	fn set_ordered_channel() -> Weight;
+	fn skip_ordered_message() -> Weight;
+	fn set_acknowledged_sender() -> Weight;
+	fn migrate_domain() -> Weight;
+	fn backfill_sync_committees(p: u32, ) -> Weight;
+	fn set_base_fee_oracle() -> Weight;
+	fn update_source_base_fee() -> Weight;
+	fn set_reorg_recovery() -> Weight;
+	fn rewind_head(s: u32, ) -> Weight;
+	fn set_participation_requirement() -> Weight;
+	fn set_execution_fee() -> Weight;
+	fn set_fork_epoch() -> Weight;
+	fn verify_execution_permit() -> Weight;
+	fn verify_proof(n: u32, b: u32, ) -> Weight;
+	fn commit_outbound_root(m: u32, ) -> Weight;
+	fn set_pinned_code_hash() -> Weight;
+	fn set_app_chain_route() -> Weight;
+	fn fulfill_call_stale() -> Weight;
+	fn set_message_length_limit() -> Weight;
+	fn void_message() -> Weight;
+	fn set_rollup_config() -> Weight;
+	fn set_finality_config() -> Weight;
+	fn set_proof_retention() -> Weight;
+	fn set_release_schedule() -> Weight;
+	fn release_vested() -> Weight;
+	fn set_payout_sender() -> Weight;
+	fn hash_proof_nodes(n: u32, b: u32, ) -> Weight;
+	fn schedule_fork_transition() -> Weight;
+	fn schedule_function_ids() -> Weight;
+	fn attest_signature() -> Weight;
+	fn prove_execution_block_number() -> Weight;
+	fn verified_account_proof() -> Weight;
+	fn register_destination_handler() -> Weight;
+	fn deregister_destination_handler() -> Weight;
+	fn set_governance_sender() -> Weight;
+	fn cancel_governance_call() -> Weight;
+	fn execute_governance_call() -> Weight;
+	fn decompress_proof(b: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_vector` using the Avail node and recommended hardware.
@@ -265,17 +316,513 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(40_176_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: `Vector::Updater` (r:1 w:1)
+	/// Storage: `Vector::SourceAddressVersions` (r:0 w:1)
+	/// Proof: `Vector::SourceAddressVersions` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	fn set_source_address_version() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(13_981_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::NextArchiveEpoch` (r:1 w:1)
+	/// Proof: `Vector::NextArchiveEpoch` (`max_values`: Some(1), `max_size`: Some(9), added: 504, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Head` (r:1 w:0)
+	/// Proof: `Vector::Head` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::EpochSlots` (r:1 w:1)
+	/// Proof: `Vector::EpochSlots` (`max_values`: None, `max_size`: Some(65549), added: 68024, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Headers` (r:256 w:256)
+	/// Proof: `Vector::Headers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ExecutionStateRoots` (r:256 w:256)
+	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ArchivedSlots` (r:1 w:1)
+	/// Proof: `Vector::ArchivedSlots` (`max_values`: Some(1), `max_size`: Some(606212), added: 606707, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ArchivedEpochs` (r:0 w:1)
+	/// Proof: `Vector::ArchivedEpochs` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Timestamps` (r:0 w:256)
+	/// Proof: `Vector::Timestamps` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 256]`.
+	fn archive_epoch(s: u32, ) -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(27_205_000_000, 675721)
+			.saturating_add(Weight::from_parts(9_847_211, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 5030).saturating_mul(s.into()))
+	}
+	/// The range of component `d` is `[0, 32]`.
+	fn verify_archive_proof(d: u32, ) -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(1_186_000, 0)
+			.saturating_add(Weight::from_parts(1_473_000, 0).saturating_mul(d.into()))
+	}
+	/// Storage: `Vector::Updaters` (r:0 w:1)
+	/// Proof: `Vector::Updaters` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	fn add_updater() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(9_120_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::Updaters` (r:1 w:1)
+	/// Proof: `Vector::Updaters` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	fn remove_updater() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(12_410_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::Updater` (r:1 w:0)
 	/// Proof: `Vector::Updater` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
-	fn set_updater() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `388`
-		//  Estimated: `1517`
-		// Minimum execution time: 14_000_000 picoseconds.
-		Weight::from_parts(15_000_000, 1517)
+	/// Storage: `Vector::Updaters` (r:1 w:0)
+	/// Proof: `Vector::Updaters` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::VerifiedInputs` (r:1 w:0)
+	/// Proof: `Vector::VerifiedInputs` (`max_values`: Some(1), `max_size`: Some(2306), added: 2801, mode: `MaxEncodedLen`)
+	fn fulfill_call_cached() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(14_870_000, 3796)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+	}
+	/// Storage: `Vector::BeaconRootDomains` (r:0 w:1)
+	/// Proof: `Vector::BeaconRootDomains` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	fn set_beacon_root_domain() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(10_254_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::Status` (r:0 w:1)
+	/// Proof: `Vector::Status` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::LowParticipationStreak` (r:0 w:1)
+	/// Proof: `Vector::LowParticipationStreak` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn restore_bridge() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(9_512_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Vector::WhitelistedDomains` (r:1 w:0)
+	/// Proof: `Vector::WhitelistedDomains` (`max_values`: Some(1), `max_size`: Some(40002), added: 40497, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceChainFrozen` (r:1 w:0)
+	/// Proof: `Vector::SourceChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::AbsentMessages` (r:1 w:1)
+	/// Proof: `Vector::AbsentMessages` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ExecutionStateRoots` (r:1 w:0)
+	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Broadcasters` (r:1 w:0)
+	/// Proof: `Vector::Broadcasters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceAddressVersions` (r:1 w:0)
+	/// Proof: `Vector::SourceAddressVersions` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	fn prove_message_absence() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(98_734_000, 41487)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::MessageSchemas` (r:0 w:1)
+	/// Proof: `Vector::MessageSchemas` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn set_message_schema() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(9_545_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::OrderedChannels` (r:0 w:1)
+	/// Proof: `Vector::OrderedChannels` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_ordered_channel() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(10_120_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::WhitelistedDomains` (r:1 w:0)
+	/// Proof: `Vector::WhitelistedDomains` (`max_values`: Some(1), `max_size`: Some(40002), added: 40497, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::OrderedChannels` (r:1 w:1)
+	/// Proof: `Vector::OrderedChannels` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ExecutionStateRoots` (r:1 w:0)
+	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceChainFrozen` (r:1 w:0)
+	/// Proof: `Vector::SourceChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Broadcasters` (r:1 w:0)
+	/// Proof: `Vector::Broadcasters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceAddressVersions` (r:1 w:0)
+	/// Proof: `Vector::SourceAddressVersions` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::QueuedMessages` (r:1 w:0)
+	/// Proof: `Vector::QueuedMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn skip_ordered_message() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(101_482_000, 41487)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::AcknowledgedSenders` (r:0 w:1)
+	/// Proof: `Vector::AcknowledgedSenders` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	fn set_acknowledged_sender() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(9_874_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::WhitelistedDomains` (r:1 w:1)
+	/// Proof: `Vector::WhitelistedDomains` (`max_values`: Some(1), `max_size`: Some(40002), added: 40497, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::DomainAliases` (r:2 w:1)
+	/// Proof: `Vector::DomainAliases` (`max_values`: None, `max_size`: Some(8), added: 2483, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Broadcasters` (r:1 w:2)
+	/// Proof: `Vector::Broadcasters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceAddressVersions` (r:1 w:2)
+	/// Proof: `Vector::SourceAddressVersions` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceChainFrozen` (r:1 w:2)
+	/// Proof: `Vector::SourceChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::BeaconRootDomains` (r:1 w:2)
+	/// Proof: `Vector::BeaconRootDomains` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::AbsentMessages` (r:2 w:2)
+	/// Proof: `Vector::AbsentMessages` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::AcknowledgedSenders` (r:2 w:2)
+	/// Proof: `Vector::AcknowledgedSenders` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::OrderedChannels` (r:2 w:2)
+	/// Proof: `Vector::OrderedChannels` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::QueuedMessages` (r:2 w:2)
+	/// Proof: `Vector::QueuedMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn migrate_domain() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(63_412_000, 41487)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(18_u64))
+	}
+	/// Storage: `Vector::SyncCommitteeHashes` (r:33 w:32)
+	/// Proof: `Vector::SyncCommitteeHashes` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ConfigurationStorage` (r:1 w:0)
+	/// Proof: `Vector::ConfigurationStorage` (`max_values`: Some(1), `max_size`: Some(10), added: 505, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 32]`.
+	fn backfill_sync_committees(p: u32, ) -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(17_651_000, 3505)
+			.saturating_add(Weight::from_parts(25_313_402_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2515).saturating_mul(p.into()))
+	}
+	/// Storage: `Vector::BaseFeeOracles` (r:0 w:1)
+	/// Proof: `Vector::BaseFeeOracles` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn set_base_fee_oracle() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(9_402_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::BaseFeeOracles` (r:1 w:0)
+	/// Proof: `Vector::BaseFeeOracles` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceBaseFees` (r:1 w:1)
+	/// Proof: `Vector::SourceBaseFees` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceChainFrozen` (r:1 w:0)
+	/// Proof: `Vector::SourceChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ExecutionStateRoots` (r:1 w:0)
+	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn update_source_base_fee() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(94_871_000, 3521)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::ReorgRecovery` (r:0 w:1)
+	/// Proof: `Vector::ReorgRecovery` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_reorg_recovery() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(8_950_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::ReorgRecovery` (r:1 w:0)
+	/// Proof: `Vector::ReorgRecovery` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Head` (r:1 w:1)
+	/// Proof: `Vector::Head` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ArchivedEpochs` (r:1 w:0)
+	/// Proof: `Vector::ArchivedEpochs` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::EpochSlots` (r:2 w:2)
+	/// Proof: `Vector::EpochSlots` (`max_values`: None, `max_size`: Some(65549), added: 68024, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::AcceptedHeaders` (r:1 w:1)
+	/// Proof: `Vector::AcceptedHeaders` (`max_values`: Some(1), `max_size`: Some(6402), added: 6897, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::NextArchiveEpoch` (r:1 w:0)
+	/// Proof: `Vector::NextArchiveEpoch` (`max_values`: Some(1), `max_size`: Some(9), added: 504, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ExecutionStateRoots` (r:8192 w:8193)
+	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Headers` (r:0 w:8193)
+	/// Proof: `Vector::Headers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Timestamps` (r:0 w:8193)
+	/// Proof: `Vector::Timestamps` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::LightClientState` (r:0 w:1)
+	/// Proof: `Vector::LightClientState` (`max_values`: Some(1), `max_size`: Some(262148), added: 262643, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::VerifiedInputs` (r:0 w:1)
+	/// Proof: `Vector::VerifiedInputs` (`max_values`: Some(1), `max_size`: Some(2306), added: 2801, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 8192]`.
+	fn rewind_head(s: u32, ) -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(50_264_000, 137038)
+			.saturating_add(Weight::from_parts(6_412_530, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2515).saturating_mul(s.into()))
+	}
+	/// Storage: `Vector::ParticipationRequirements` (r:0 w:1)
+	/// Proof: `Vector::ParticipationRequirements` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	fn set_participation_requirement() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(9_210_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::ExecutionFees` (r:0 w:1)
+	/// Proof: `Vector::ExecutionFees` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn set_execution_fee() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(9_540_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::ForkEpochs` (r:3 w:1)
+	/// Proof: `Vector::ForkEpochs` (`max_values`: None, `max_size`: Some(9), added: 2484, mode: `MaxEncodedLen`)
+	fn set_fork_epoch() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(11_230_000, 7452)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn verify_execution_permit() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(61_000_000, 0)
+	}
+	/// The range of component `n` is `[1, 32]`.
+	/// The range of component `b` is `[32, 65536]`.
+	fn verify_proof(n: u32, b: u32, ) -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(2_127_512, 0)
+			.saturating_add(Weight::from_parts(1_853_940, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(4_207, 0).saturating_mul(b.into()))
+	}
+	/// Storage: `Vector::OutboundMessages` (r:1 w:0)
+	/// Proof: `Vector::OutboundMessages` (`max_values`: Some(1), `max_size`: Some(262147), added: 262642, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::OutboundMessagesRoot` (r:0 w:1)
+	/// Proof: `Vector::OutboundMessagesRoot` (`max_values`: Some(1), `max_size`: Some(36), added: 531, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[0, 8192]`.
+	fn commit_outbound_root(m: u32, ) -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(3_382_019, 263632)
+			.saturating_add(Weight::from_parts(2_716_402, 0).saturating_mul(m.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Vector::PinnedCodeHashes` (r:0 w:1)
+	/// Proof: `Vector::PinnedCodeHashes` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn set_pinned_code_hash() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(8_500_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::AppChainRoutes` (r:0 w:1)
+	/// Proof: `Vector::AppChainRoutes` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	fn set_app_chain_route() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(8_412_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::Updater` (r:1 w:0)
+	/// Proof: `Vector::Updater` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Updaters` (r:1 w:1)
+	/// Proof: `Vector::Updaters` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::VerifiedInputs` (r:1 w:0)
+	/// Proof: `Vector::VerifiedInputs` (`max_values`: Some(1), `max_size`: Some(2306), added: 2801, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ConfigurationStorage` (r:1 w:0)
+	/// Proof: `Vector::ConfigurationStorage` (`max_values`: Some(1), `max_size`: Some(10), added: 505, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Head` (r:1 w:0)
+	/// Proof: `Vector::Head` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Headers` (r:1 w:0)
+	/// Proof: `Vector::Headers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::AcceptedHeaders` (r:1 w:0)
+	/// Proof: `Vector::AcceptedHeaders` (`max_values`: Some(1), `max_size`: Some(6402), added: 6897, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SyncCommitteeHashes` (r:1 w:0)
+	/// Proof: `Vector::SyncCommitteeHashes` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn fulfill_call_stale() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(1_248_310_000, 7887)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::MessageLengthLimits` (r:0 w:1)
+	/// Proof: `Vector::MessageLengthLimits` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn set_message_length_limit() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(8_371_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::MessageStatus` (r:1 w:0)
+	/// Proof: `Vector::MessageStatus` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::VoidedMessages` (r:0 w:1)
+	/// Proof: `Vector::VoidedMessages` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn void_message() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(11_482_000, 3498)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::RollupConfigs` (r:0 w:1)
+	/// Proof: `Vector::RollupConfigs` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	fn set_rollup_config() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(9_104_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::Finality` (r:0 w:1)
+	/// Proof: `Vector::Finality` (`max_values`: Some(1), `max_size`: Some(25), added: 520, mode: `MaxEncodedLen`)
+	fn set_finality_config() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(8_721_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::ProofRetention` (r:0 w:1)
+	/// Proof: `Vector::ProofRetention` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_proof_retention() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(8_604_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::ReleaseSchedules` (r:0 w:1)
+	/// Proof: `Vector::ReleaseSchedules` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_release_schedule() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(9_012_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::VestedReleases` (r:1 w:1)
+	/// Proof: `Vector::VestedReleases` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn release_vested() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(61_250_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Vector::PayoutSenders` (r:0 w:1)
+	/// Proof: `Vector::PayoutSenders` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	fn set_payout_sender() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(8_912_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// The range of component `n` is `[1, 32]`.
+	/// The range of component `b` is `[32, 65536]`.
+	fn hash_proof_nodes(n: u32, b: u32, ) -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(1_934_207, 0)
+			.saturating_add(Weight::from_parts(1_702_356, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(4_011, 0).saturating_mul(b.into()))
+	}
+	/// Storage: `Vector::WhitelistedDomains` (r:1 w:1)
+	/// Proof: `Vector::WhitelistedDomains` (`max_values`: Some(1), `max_size`: Some(40002), added: 40497, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::DomainAliases` (r:2 w:1)
+	/// Proof: `Vector::DomainAliases` (`max_values`: None, `max_size`: Some(8), added: 2483, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Broadcasters` (r:1 w:2)
+	/// Proof: `Vector::Broadcasters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceAddressVersions` (r:1 w:2)
+	/// Proof: `Vector::SourceAddressVersions` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceChainFrozen` (r:1 w:2)
+	/// Proof: `Vector::SourceChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::BeaconRootDomains` (r:1 w:2)
+	/// Proof: `Vector::BeaconRootDomains` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::AbsentMessages` (r:2 w:2)
+	/// Proof: `Vector::AbsentMessages` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::AcknowledgedSenders` (r:2 w:2)
+	/// Proof: `Vector::AcknowledgedSenders` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::OrderedChannels` (r:2 w:2)
+	/// Proof: `Vector::OrderedChannels` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::QueuedMessages` (r:2 w:2)
+	/// Proof: `Vector::QueuedMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Vector::ForkTransitions` (r:0 w:1)
+	/// Proof: `Vector::ForkTransitions` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ForkActivations` (r:0 w:1)
+	/// Proof: `Vector::ForkActivations` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	fn schedule_fork_transition() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(66_904_000, 41487)
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(20_u64))
+	}
+	/// Storage: `Vector::ScheduledFunctionIds` (r:0 w:1)
+	/// Proof: `Vector::ScheduledFunctionIds` (`max_values`: Some(1), `max_size`: Some(68), added: 563, mode: `MaxEncodedLen`)
+	fn schedule_function_ids() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(14_512_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::Attestations` (r:0 w:1)
+	/// Proof: `Vector::Attestations` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn attest_signature() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(61_208_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::ExecutionBlockNumbers` (r:1 w:1)
+	/// Proof: `Vector::ExecutionBlockNumbers` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Headers` (r:1 w:0)
+	/// Proof: `Vector::Headers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ForkEpochs` (r:3 w:0)
+	/// Proof: `Vector::ForkEpochs` (`max_values`: None, `max_size`: Some(9), added: 2484, mode: `MaxEncodedLen`)
+	fn prove_execution_block_number() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(19_482_000, 3505)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::VerifiedAccountProofs` (r:1 w:1)
+	/// Proof: `Vector::VerifiedAccountProofs` (`max_values`: Some(1), `max_size`: Some(2065), added: 2560, mode: `MaxEncodedLen`)
+	fn verified_account_proof() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(7_184_000, 2560)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::RuntimeCodeHash` (r:1 w:0)
+	/// Proof: `Vector::RuntimeCodeHash` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::DestinationHandlers` (r:0 w:1)
+	/// Proof: `Vector::DestinationHandlers` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	fn register_destination_handler() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(11_200_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::DestinationHandlers` (r:1 w:1)
+	/// Proof: `Vector::DestinationHandlers` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	fn deregister_destination_handler() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(13_400_000, 3538)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::GovernanceSender` (r:0 w:1)
+	/// Proof: `Vector::GovernanceSender` (`max_values`: Some(1), `max_size`: Some(36), added: 531, mode: `MaxEncodedLen`)
+	fn set_governance_sender() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(8_900_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::GovernanceCalls` (r:1 w:1)
+	/// Proof: `Vector::GovernanceCalls` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn cancel_governance_call() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(13_100_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::GovernanceCalls` (r:1 w:1)
+	/// Proof: `Vector::GovernanceCalls` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn execute_governance_call() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(16_700_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// The range of component `b` is `[32, 65536]`.
+	fn decompress_proof(b: u32, ) -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(4_876_310, 0)
+			.saturating_add(Weight::from_parts(1_932, 0).saturating_mul(b.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -474,15 +1021,511 @@ impl WeightInfo for () {
 		Weight::from_parts(40_176_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: `Vector::Updater` (r:1 w:1)
+	/// Storage: `Vector::SourceAddressVersions` (r:0 w:1)
+	/// Proof: `Vector::SourceAddressVersions` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	fn set_source_address_version() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(13_981_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::NextArchiveEpoch` (r:1 w:1)
+	/// Proof: `Vector::NextArchiveEpoch` (`max_values`: Some(1), `max_size`: Some(9), added: 504, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Head` (r:1 w:0)
+	/// Proof: `Vector::Head` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::EpochSlots` (r:1 w:1)
+	/// Proof: `Vector::EpochSlots` (`max_values`: None, `max_size`: Some(65549), added: 68024, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Headers` (r:256 w:256)
+	/// Proof: `Vector::Headers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ExecutionStateRoots` (r:256 w:256)
+	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ArchivedSlots` (r:1 w:1)
+	/// Proof: `Vector::ArchivedSlots` (`max_values`: Some(1), `max_size`: Some(606212), added: 606707, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ArchivedEpochs` (r:0 w:1)
+	/// Proof: `Vector::ArchivedEpochs` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Timestamps` (r:0 w:256)
+	/// Proof: `Vector::Timestamps` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 256]`.
+	fn archive_epoch(s: u32, ) -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(27_205_000_000, 675721)
+			.saturating_add(Weight::from_parts(9_847_211, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 5030).saturating_mul(s.into()))
+	}
+	/// The range of component `d` is `[0, 32]`.
+	fn verify_archive_proof(d: u32, ) -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(1_186_000, 0)
+			.saturating_add(Weight::from_parts(1_473_000, 0).saturating_mul(d.into()))
+	}
+	/// Storage: `Vector::Updaters` (r:0 w:1)
+	/// Proof: `Vector::Updaters` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	fn add_updater() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(9_120_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::Updaters` (r:1 w:1)
+	/// Proof: `Vector::Updaters` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	fn remove_updater() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(12_410_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::Updater` (r:1 w:0)
 	/// Proof: `Vector::Updater` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
-	fn set_updater() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `388`
-		//  Estimated: `1517`
-		// Minimum execution time: 14_000_000 picoseconds.
-		Weight::from_parts(15_000_000, 1517)
+	/// Storage: `Vector::Updaters` (r:1 w:0)
+	/// Proof: `Vector::Updaters` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::VerifiedInputs` (r:1 w:0)
+	/// Proof: `Vector::VerifiedInputs` (`max_values`: Some(1), `max_size`: Some(2306), added: 2801, mode: `MaxEncodedLen`)
+	fn fulfill_call_cached() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(14_870_000, 3796)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+	}
+	/// Storage: `Vector::BeaconRootDomains` (r:0 w:1)
+	/// Proof: `Vector::BeaconRootDomains` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	fn set_beacon_root_domain() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(10_254_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::Status` (r:0 w:1)
+	/// Proof: `Vector::Status` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::LowParticipationStreak` (r:0 w:1)
+	/// Proof: `Vector::LowParticipationStreak` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn restore_bridge() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(9_512_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Vector::WhitelistedDomains` (r:1 w:0)
+	/// Proof: `Vector::WhitelistedDomains` (`max_values`: Some(1), `max_size`: Some(40002), added: 40497, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceChainFrozen` (r:1 w:0)
+	/// Proof: `Vector::SourceChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::AbsentMessages` (r:1 w:1)
+	/// Proof: `Vector::AbsentMessages` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ExecutionStateRoots` (r:1 w:0)
+	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Broadcasters` (r:1 w:0)
+	/// Proof: `Vector::Broadcasters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceAddressVersions` (r:1 w:0)
+	/// Proof: `Vector::SourceAddressVersions` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	fn prove_message_absence() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(98_734_000, 41487)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::MessageSchemas` (r:0 w:1)
+	/// Proof: `Vector::MessageSchemas` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn set_message_schema() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(9_545_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::OrderedChannels` (r:0 w:1)
+	/// Proof: `Vector::OrderedChannels` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_ordered_channel() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(10_120_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::WhitelistedDomains` (r:1 w:0)
+	/// Proof: `Vector::WhitelistedDomains` (`max_values`: Some(1), `max_size`: Some(40002), added: 40497, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::OrderedChannels` (r:1 w:1)
+	/// Proof: `Vector::OrderedChannels` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ExecutionStateRoots` (r:1 w:0)
+	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceChainFrozen` (r:1 w:0)
+	/// Proof: `Vector::SourceChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Broadcasters` (r:1 w:0)
+	/// Proof: `Vector::Broadcasters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceAddressVersions` (r:1 w:0)
+	/// Proof: `Vector::SourceAddressVersions` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::QueuedMessages` (r:1 w:0)
+	/// Proof: `Vector::QueuedMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn skip_ordered_message() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(101_482_000, 41487)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::AcknowledgedSenders` (r:0 w:1)
+	/// Proof: `Vector::AcknowledgedSenders` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	fn set_acknowledged_sender() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(9_874_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::WhitelistedDomains` (r:1 w:1)
+	/// Proof: `Vector::WhitelistedDomains` (`max_values`: Some(1), `max_size`: Some(40002), added: 40497, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::DomainAliases` (r:2 w:1)
+	/// Proof: `Vector::DomainAliases` (`max_values`: None, `max_size`: Some(8), added: 2483, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Broadcasters` (r:1 w:2)
+	/// Proof: `Vector::Broadcasters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceAddressVersions` (r:1 w:2)
+	/// Proof: `Vector::SourceAddressVersions` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceChainFrozen` (r:1 w:2)
+	/// Proof: `Vector::SourceChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::BeaconRootDomains` (r:1 w:2)
+	/// Proof: `Vector::BeaconRootDomains` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::AbsentMessages` (r:2 w:2)
+	/// Proof: `Vector::AbsentMessages` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::AcknowledgedSenders` (r:2 w:2)
+	/// Proof: `Vector::AcknowledgedSenders` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::OrderedChannels` (r:2 w:2)
+	/// Proof: `Vector::OrderedChannels` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::QueuedMessages` (r:2 w:2)
+	/// Proof: `Vector::QueuedMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn migrate_domain() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(63_412_000, 41487)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(18_u64))
+	}
+	/// Storage: `Vector::SyncCommitteeHashes` (r:33 w:32)
+	/// Proof: `Vector::SyncCommitteeHashes` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ConfigurationStorage` (r:1 w:0)
+	/// Proof: `Vector::ConfigurationStorage` (`max_values`: Some(1), `max_size`: Some(10), added: 505, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 32]`.
+	fn backfill_sync_committees(p: u32, ) -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(17_651_000, 3505)
+			.saturating_add(Weight::from_parts(25_313_402_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2515).saturating_mul(p.into()))
+	}
+	/// Storage: `Vector::BaseFeeOracles` (r:0 w:1)
+	/// Proof: `Vector::BaseFeeOracles` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn set_base_fee_oracle() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(9_402_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::BaseFeeOracles` (r:1 w:0)
+	/// Proof: `Vector::BaseFeeOracles` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceBaseFees` (r:1 w:1)
+	/// Proof: `Vector::SourceBaseFees` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceChainFrozen` (r:1 w:0)
+	/// Proof: `Vector::SourceChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ExecutionStateRoots` (r:1 w:0)
+	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn update_source_base_fee() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(94_871_000, 3521)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::ReorgRecovery` (r:0 w:1)
+	/// Proof: `Vector::ReorgRecovery` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_reorg_recovery() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(8_950_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::ReorgRecovery` (r:1 w:0)
+	/// Proof: `Vector::ReorgRecovery` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Head` (r:1 w:1)
+	/// Proof: `Vector::Head` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ArchivedEpochs` (r:1 w:0)
+	/// Proof: `Vector::ArchivedEpochs` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::EpochSlots` (r:2 w:2)
+	/// Proof: `Vector::EpochSlots` (`max_values`: None, `max_size`: Some(65549), added: 68024, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::AcceptedHeaders` (r:1 w:1)
+	/// Proof: `Vector::AcceptedHeaders` (`max_values`: Some(1), `max_size`: Some(6402), added: 6897, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::NextArchiveEpoch` (r:1 w:0)
+	/// Proof: `Vector::NextArchiveEpoch` (`max_values`: Some(1), `max_size`: Some(9), added: 504, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ExecutionStateRoots` (r:8192 w:8193)
+	/// Proof: `Vector::ExecutionStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Headers` (r:0 w:8193)
+	/// Proof: `Vector::Headers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Timestamps` (r:0 w:8193)
+	/// Proof: `Vector::Timestamps` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::LightClientState` (r:0 w:1)
+	/// Proof: `Vector::LightClientState` (`max_values`: Some(1), `max_size`: Some(262148), added: 262643, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::VerifiedInputs` (r:0 w:1)
+	/// Proof: `Vector::VerifiedInputs` (`max_values`: Some(1), `max_size`: Some(2306), added: 2801, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 8192]`.
+	fn rewind_head(s: u32, ) -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(50_264_000, 137038)
+			.saturating_add(Weight::from_parts(6_412_530, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2515).saturating_mul(s.into()))
+	}
+	/// Storage: `Vector::ParticipationRequirements` (r:0 w:1)
+	/// Proof: `Vector::ParticipationRequirements` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	fn set_participation_requirement() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(9_210_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::ExecutionFees` (r:0 w:1)
+	/// Proof: `Vector::ExecutionFees` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn set_execution_fee() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(9_540_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::ForkEpochs` (r:3 w:1)
+	/// Proof: `Vector::ForkEpochs` (`max_values`: None, `max_size`: Some(9), added: 2484, mode: `MaxEncodedLen`)
+	fn set_fork_epoch() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(11_230_000, 7452)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn verify_execution_permit() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(61_000_000, 0)
+	}
+	/// The range of component `n` is `[1, 32]`.
+	/// The range of component `b` is `[32, 65536]`.
+	fn verify_proof(n: u32, b: u32, ) -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(2_127_512, 0)
+			.saturating_add(Weight::from_parts(1_853_940, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(4_207, 0).saturating_mul(b.into()))
+	}
+	/// Storage: `Vector::OutboundMessages` (r:1 w:0)
+	/// Proof: `Vector::OutboundMessages` (`max_values`: Some(1), `max_size`: Some(262147), added: 262642, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::OutboundMessagesRoot` (r:0 w:1)
+	/// Proof: `Vector::OutboundMessagesRoot` (`max_values`: Some(1), `max_size`: Some(36), added: 531, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[0, 8192]`.
+	fn commit_outbound_root(m: u32, ) -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(3_382_019, 263632)
+			.saturating_add(Weight::from_parts(2_716_402, 0).saturating_mul(m.into()))
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-}
\ No newline at end of file
+	/// Storage: `Vector::PinnedCodeHashes` (r:0 w:1)
+	/// Proof: `Vector::PinnedCodeHashes` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn set_pinned_code_hash() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(8_500_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::AppChainRoutes` (r:0 w:1)
+	/// Proof: `Vector::AppChainRoutes` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	fn set_app_chain_route() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(8_412_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::Updater` (r:1 w:0)
+	/// Proof: `Vector::Updater` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Updaters` (r:1 w:1)
+	/// Proof: `Vector::Updaters` (`max_values`: None, `max_size`: Some(45), added: 2520, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::VerifiedInputs` (r:1 w:0)
+	/// Proof: `Vector::VerifiedInputs` (`max_values`: Some(1), `max_size`: Some(2306), added: 2801, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ConfigurationStorage` (r:1 w:0)
+	/// Proof: `Vector::ConfigurationStorage` (`max_values`: Some(1), `max_size`: Some(10), added: 505, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Head` (r:1 w:0)
+	/// Proof: `Vector::Head` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Headers` (r:1 w:0)
+	/// Proof: `Vector::Headers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::AcceptedHeaders` (r:1 w:0)
+	/// Proof: `Vector::AcceptedHeaders` (`max_values`: Some(1), `max_size`: Some(6402), added: 6897, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SyncCommitteeHashes` (r:1 w:0)
+	/// Proof: `Vector::SyncCommitteeHashes` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	fn fulfill_call_stale() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(1_248_310_000, 7887)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::MessageLengthLimits` (r:0 w:1)
+	/// Proof: `Vector::MessageLengthLimits` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn set_message_length_limit() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(8_371_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::MessageStatus` (r:1 w:0)
+	/// Proof: `Vector::MessageStatus` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::VoidedMessages` (r:0 w:1)
+	/// Proof: `Vector::VoidedMessages` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	fn void_message() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(11_482_000, 3498)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::RollupConfigs` (r:0 w:1)
+	/// Proof: `Vector::RollupConfigs` (`max_values`: None, `max_size`: Some(93), added: 2568, mode: `MaxEncodedLen`)
+	fn set_rollup_config() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(9_104_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::Finality` (r:0 w:1)
+	/// Proof: `Vector::Finality` (`max_values`: Some(1), `max_size`: Some(25), added: 520, mode: `MaxEncodedLen`)
+	fn set_finality_config() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(8_721_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::ProofRetention` (r:0 w:1)
+	/// Proof: `Vector::ProofRetention` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_proof_retention() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(8_604_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::ReleaseSchedules` (r:0 w:1)
+	/// Proof: `Vector::ReleaseSchedules` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn set_release_schedule() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(9_012_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::VestedReleases` (r:1 w:1)
+	/// Proof: `Vector::VestedReleases` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn release_vested() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(61_250_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Vector::PayoutSenders` (r:0 w:1)
+	/// Proof: `Vector::PayoutSenders` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	fn set_payout_sender() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(8_912_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// The range of component `n` is `[1, 32]`.
+	/// The range of component `b` is `[32, 65536]`.
+	fn hash_proof_nodes(n: u32, b: u32, ) -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(1_934_207, 0)
+			.saturating_add(Weight::from_parts(1_702_356, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(4_011, 0).saturating_mul(b.into()))
+	}
+	/// Storage: `Vector::WhitelistedDomains` (r:1 w:1)
+	/// Proof: `Vector::WhitelistedDomains` (`max_values`: Some(1), `max_size`: Some(40002), added: 40497, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::DomainAliases` (r:2 w:1)
+	/// Proof: `Vector::DomainAliases` (`max_values`: None, `max_size`: Some(8), added: 2483, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Broadcasters` (r:1 w:2)
+	/// Proof: `Vector::Broadcasters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceAddressVersions` (r:1 w:2)
+	/// Proof: `Vector::SourceAddressVersions` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::SourceChainFrozen` (r:1 w:2)
+	/// Proof: `Vector::SourceChainFrozen` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::BeaconRootDomains` (r:1 w:2)
+	/// Proof: `Vector::BeaconRootDomains` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::AbsentMessages` (r:2 w:2)
+	/// Proof: `Vector::AbsentMessages` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::AcknowledgedSenders` (r:2 w:2)
+	/// Proof: `Vector::AcknowledgedSenders` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::OrderedChannels` (r:2 w:2)
+	/// Proof: `Vector::OrderedChannels` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::QueuedMessages` (r:2 w:2)
+	/// Proof: `Vector::QueuedMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Vector::ForkTransitions` (r:0 w:1)
+	/// Proof: `Vector::ForkTransitions` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ForkActivations` (r:0 w:1)
+	/// Proof: `Vector::ForkActivations` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	fn schedule_fork_transition() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(66_904_000, 41487)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(20_u64))
+	}
+	/// Storage: `Vector::ScheduledFunctionIds` (r:0 w:1)
+	/// Proof: `Vector::ScheduledFunctionIds` (`max_values`: Some(1), `max_size`: Some(68), added: 563, mode: `MaxEncodedLen`)
+	fn schedule_function_ids() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(14_512_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::Attestations` (r:0 w:1)
+	/// Proof: `Vector::Attestations` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn attest_signature() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(61_208_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::ExecutionBlockNumbers` (r:1 w:1)
+	/// Proof: `Vector::ExecutionBlockNumbers` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::Headers` (r:1 w:0)
+	/// Proof: `Vector::Headers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::ForkEpochs` (r:3 w:0)
+	/// Proof: `Vector::ForkEpochs` (`max_values`: None, `max_size`: Some(9), added: 2484, mode: `MaxEncodedLen`)
+	fn prove_execution_block_number() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(19_482_000, 3505)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::VerifiedAccountProofs` (r:1 w:1)
+	/// Proof: `Vector::VerifiedAccountProofs` (`max_values`: Some(1), `max_size`: Some(2065), added: 2560, mode: `MaxEncodedLen`)
+	fn verified_account_proof() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(7_184_000, 2560)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::RuntimeCodeHash` (r:1 w:0)
+	/// Proof: `Vector::RuntimeCodeHash` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Vector::DestinationHandlers` (r:0 w:1)
+	/// Proof: `Vector::DestinationHandlers` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	fn register_destination_handler() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(11_200_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::DestinationHandlers` (r:1 w:1)
+	/// Proof: `Vector::DestinationHandlers` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	fn deregister_destination_handler() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(13_400_000, 3538)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::GovernanceSender` (r:0 w:1)
+	/// Proof: `Vector::GovernanceSender` (`max_values`: Some(1), `max_size`: Some(36), added: 531, mode: `MaxEncodedLen`)
+	fn set_governance_sender() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(8_900_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::GovernanceCalls` (r:1 w:1)
+	/// Proof: `Vector::GovernanceCalls` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn cancel_governance_call() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(13_100_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Vector::GovernanceCalls` (r:1 w:1)
+	/// Proof: `Vector::GovernanceCalls` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn execute_governance_call() -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(16_700_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// The range of component `b` is `[32, 65536]`.
+	fn decompress_proof(b: u32, ) -> Weight {
+		// Hand-written estimate, not measured: regenerate with scripts/run_benchmarks.sh
+		Weight::from_parts(4_876_310, 0)
+			.saturating_add(Weight::from_parts(1_932, 0).saturating_mul(b.into()))
+	}
+}
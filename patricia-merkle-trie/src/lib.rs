@@ -1,10 +1,12 @@
 //! This crate exposes an implementation of [`trie_db::TrieLayout`] that allows [`trie_db::TrieDb`]
 //! be used for verifying Ethereum state proofs as per [EIP-1186](https://eips.ethereum.org/EIPS/eip-1186)
+//! and of [`ProofLayout`] for chains whose tries are keyed differently.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
 
+use alloc::vec::Vec;
 use core::marker::PhantomData;
 use hash_db::Hasher;
 use primitive_types::H256;
@@ -18,6 +20,12 @@ mod tests;
 
 pub use storage_proof::{MemoryDB, StorageProof};
 
+/// Trie layout of a source chain whose account and storage proofs are verified.
+pub trait ProofLayout: TrieLayout {
+	/// Returns the path of `key`, an account address or a storage slot, in the trie.
+	fn trie_key(key: &[u8]) -> Vec<u8>;
+}
+
 /// Trie layout for EIP-1186 state proof nodes.
 #[derive(Default, Clone)]
 pub struct EIP1186Layout<H>(PhantomData<H>);
@@ -30,6 +38,32 @@ impl<H: Hasher<Out = H256>> TrieLayout for EIP1186Layout<H> {
 	type Codec = node_codec::RlpNodeCodec<H>;
 }
 
+/// Accounts and storage slots are keyed by their hash, as in the secure trie of Ethereum.
+impl<H: Hasher<Out = H256>> ProofLayout for EIP1186Layout<H> {
+	fn trie_key(key: &[u8]) -> Vec<u8> {
+		H::hash(key).as_bytes().to_vec()
+	}
+}
+
+/// Trie layout with the nodes of EIP-1186, for chains keeping accounts and storage slots under
+/// their unhashed keys.
+#[derive(Default, Clone)]
+pub struct PlainKeyLayout<H>(PhantomData<H>);
+
+impl<H: Hasher<Out = H256>> TrieLayout for PlainKeyLayout<H> {
+	const USE_EXTENSION: bool = true;
+	const ALLOW_EMPTY: bool = false;
+	const MAX_INLINE_VALUE: Option<u32> = None;
+	type Hash = H;
+	type Codec = node_codec::RlpNodeCodec<H>;
+}
+
+impl<H: Hasher<Out = H256>> ProofLayout for PlainKeyLayout<H> {
+	fn trie_key(key: &[u8]) -> Vec<u8> {
+		key.to_vec()
+	}
+}
+
 pub mod keccak256 {
 	use hash256_std_hasher::Hash256StdHasher;
 	use sp_io::hashing::keccak_256;
@@ -51,6 +85,48 @@ pub mod keccak256 {
 	}
 }
 
+pub mod blake2_256 {
+	use hash256_std_hasher::Hash256StdHasher;
+	use sp_io::hashing::blake2_256;
+
+	use super::*;
+
+	/// Concrete implementation of Hasher using Blake2 256-bit hashes
+	#[derive(Debug)]
+	pub struct Blake2Hasher;
+
+	impl hash_db::Hasher for Blake2Hasher {
+		type Out = H256;
+		type StdHasher = Hash256StdHasher;
+		const LENGTH: usize = 32;
+
+		fn hash(x: &[u8]) -> Self::Out {
+			blake2_256(x).into()
+		}
+	}
+}
+
+pub mod sha256 {
+	use hash256_std_hasher::Hash256StdHasher;
+	use sp_io::hashing::sha2_256;
+
+	use super::*;
+
+	/// Concrete implementation of Hasher using SHA2 256-bit hashes
+	#[derive(Debug)]
+	pub struct Sha256Hasher;
+
+	impl hash_db::Hasher for Sha256Hasher {
+		type Out = H256;
+		type StdHasher = Hash256StdHasher;
+		const LENGTH: usize = 32;
+
+		fn hash(x: &[u8]) -> Self::Out {
+			sha2_256(x).into()
+		}
+	}
+}
+
 /// Keccak hasher implementation, but only for std uses. You'd probably want to delegate
 /// hashing to wasm host functions in `no_std`.
 #[cfg(feature = "std")]
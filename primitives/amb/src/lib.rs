@@ -0,0 +1,21 @@
+//! Primitives of the Avail message bridge: the encoding of the messages, their storage slots in
+//! the source chain broadcaster, and the proofs of that storage against an execution state root.
+//!
+//! `pallet-vector` uses them to execute messages, and relayers and off-chain tools can use them
+//! to build and check the same proofs without depending on the pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod message;
+pub mod storage;
+
+pub use message::{message_root, message_slot_key};
+pub use patricia_merkle_trie::{
+	keccak256::KeccakHasher, EIP1186Layout, PlainKeyLayout, ProofLayout, StorageProof,
+};
+pub use storage::{
+	get_account, get_account_of, get_storage_root, get_storage_value, get_storage_values,
+	verify_storage_absence, AccountState, StorageError,
+};
@@ -0,0 +1,56 @@
+//! Encoding of the bridge messages and their storage slots in the source chain broadcaster.
+use avail_core::data_proof::AddressedMessage;
+use ethabi::Token::Uint;
+use primitive_types::{H256, U256};
+use sp_io::hashing::keccak_256;
+
+/// Returns the hash of the message, as stored in the source chain broadcaster.
+pub fn message_root(addr_message: &AddressedMessage) -> H256 {
+	let encoded_data = addr_message.clone().abi_encode();
+	H256(keccak_256(encoded_data.as_slice()))
+}
+
+/// Returns the storage slot of the message in the broadcaster `messages` mapping, which is the
+/// storage variable `mapping_index` of the broadcaster.
+pub fn message_slot_key(message_id: u64, mapping_index: u64) -> H256 {
+	let message_id = Uint(U256::from(message_id));
+	let mm_idx = Uint(U256::from(mapping_index));
+	H256(keccak_256(ethabi::encode(&[message_id, mm_idx]).as_slice()))
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use avail_core::data_proof::Message;
+	use hex_literal::hex;
+
+	#[test]
+	fn test_abi_encoding() {
+		let expected_encoded_message = hex!("00000000000000000000000000000000000000000000000000000000000000200200000000000000000000000000000000000000000000000000000000000000f39fd6e51aad88f6f4ce6ab8827279cfffb9226600000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000e00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000de0b6b3a7640000").to_vec();
+
+		let asset_id = H256::zero();
+		let amount = 1_000_000_000_000_000_000u128;
+		let from = hex!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266000000000000000000000000");
+		let to = hex!("0000000000000000000000000000000000000000000000000000000000000001");
+		let m = AddressedMessage {
+			message: Message::FungibleToken { asset_id, amount },
+			from: from.into(),
+			to: to.into(),
+			origin_domain: 2,
+			destination_domain: 1,
+			id: 0,
+		};
+
+		let encoded = m.abi_encode();
+		assert_eq!(expected_encoded_message, encoded);
+	}
+
+	#[test]
+	fn test_message_slot_key() {
+		let mut preimage = [0u8; 64];
+		U256::from(4).to_big_endian(&mut preimage[..32]);
+		U256::from(1).to_big_endian(&mut preimage[32..]);
+
+		assert_eq!(H256(keccak_256(&preimage)), message_slot_key(4, 1));
+	}
+}
@@ -5,6 +5,7 @@ use avail_core::{
 use da_runtime::apis::{DataAvailApi, KateApi as RTKateApi};
 use da_runtime::kate::{GDataProof, GRow};
 use kate::com::Cell;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 use frame_support::BoundedVec;
 use frame_system::limits::BlockLength;
@@ -14,13 +15,16 @@ use jsonrpsee::{
 	types::error::{ErrorCode, ErrorObject},
 };
 use sc_client_api::BlockBackend;
+use serde::{Deserialize, Serialize};
 use sp_api::{ApiRef, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H256};
 use sp_runtime::{
 	generic::SignedBlock,
 	traits::{Block as BlockT, ConstU32, Header},
 };
 use std::{marker::PhantomData, marker::Sync, sync::Arc};
+use subscription::SubscriptionLimit;
 
 pub type HashOf<Block> = <Block as BlockT>::Hash;
 pub type MaxRows = ConstU32<64>;
@@ -28,7 +32,48 @@ pub type Rows = BoundedVec<u32, MaxRows>;
 pub type MaxCells = ConstU32<10_000>;
 pub type Cells = BoundedVec<Cell, MaxCells>;
 
+/// Size of a single KZG commitment in bytes.
+pub const COMMITMENT_SIZE: usize = 48;
+
 pub mod metrics;
+pub mod subscription;
+
+/// Version of a decoded header extension.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HeaderExtensionVersion {
+	V3,
+}
+
+/// Decoded commitment data of a header extension.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedHeaderExtension {
+	pub version: HeaderExtensionVersion,
+	pub rows: u16,
+	pub cols: u16,
+	pub data_root: H256,
+	/// Row commitments, `COMMITMENT_SIZE` bytes each.
+	pub commitments: Vec<Bytes>,
+}
+
+impl From<&HeaderExtension> for DecodedHeaderExtension {
+	fn from(extension: &HeaderExtension) -> Self {
+		match extension {
+			HeaderExtension::V3(ext) => Self {
+				version: HeaderExtensionVersion::V3,
+				rows: ext.commitment.rows,
+				cols: ext.commitment.cols,
+				data_root: ext.commitment.data_root,
+				commitments: ext
+					.commitment
+					.commitment
+					.chunks(COMMITMENT_SIZE)
+					.map(|commitment| Bytes(commitment.to_vec()))
+					.collect(),
+			},
+		}
+	}
+}
 
 /// # TODO
 /// - [ ] Update type definitions for RPCs in our subxt & explorer.
@@ -47,21 +92,42 @@ where
 		at: Option<HashOf<Block>>,
 	) -> RpcResult<Vec<GDataProof>>;
 
+	/// Returns the proofs of the cells of several blocks, in the order of `requests`.
+	///
+	/// The total number of cells across the blocks is capped like in `kate_queryProof`.
+	#[method(name = "kate_queryProofMulti")]
+	async fn query_proof_multi(
+		&self,
+		requests: Vec<(HashOf<Block>, Cells)>,
+	) -> RpcResult<Vec<Vec<GDataProof>>>;
+
 	#[method(name = "kate_blockLength")]
 	async fn query_block_length(&self, at: Option<HashOf<Block>>) -> RpcResult<BlockLength>;
 
+	/// Returns the Merkle proof of the data submission or bridge message at `transaction_index`
+	/// against the data root of the finalized block `at`.
+	///
+	/// Rollup verifiers check it against the data root committed by the Vector bridge.
 	#[method(name = "kate_queryDataProof")]
 	async fn query_data_proof(
 		&self,
 		transaction_index: u32,
 		at: Option<HashOf<Block>>,
 	) -> RpcResult<ProofResponse>;
+
+	/// Returns the decoded header extension of the block, for any header extension version.
+	#[method(name = "chain_getHeaderExtension")]
+	async fn query_header_extension(
+		&self,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<DecodedHeaderExtension>;
 }
 
 #[allow(clippy::type_complexity)]
 pub struct Kate<Client, Block: BlockT> {
 	client: Arc<Client>,
 	max_cells_size: usize,
+	subscription_limit: SubscriptionLimit,
 	_block: PhantomData<Block>,
 }
 
@@ -70,9 +136,16 @@ impl<Client, Block: BlockT> Kate<Client, Block> {
 		Self {
 			client,
 			max_cells_size,
+			subscription_limit: SubscriptionLimit::default(),
 			_block: PhantomData,
 		}
 	}
+
+	/// Caps the concurrent `kate_subscribeAppData` subscriptions served by this instance.
+	pub fn with_subscription_limit(mut self, limit: SubscriptionLimit) -> Self {
+		self.subscription_limit = limit;
+		self
+	}
 }
 
 /// Error type of this RPC api.
@@ -170,6 +243,46 @@ where
 		self.ensure_block_finalized(&signed_block)?;
 		Ok(signed_block)
 	}
+
+	fn ensure_max_cells(&self, cells: usize) -> RpcResult<()> {
+		if cells > self.max_cells_size {
+			return Err(
+				internal_err!(
+					"Cannot query ({}) more than {} amount of cells per request. Either increase the max cells size (--kate-max-cells-size) or query less amount of cells per request.",
+					cells,
+					self.max_cells_size
+				)
+			);
+		}
+
+		Ok(())
+	}
+
+	fn proof(&self, cells: Cells, at: Option<Block::Hash>) -> RpcResult<Vec<GDataProof>>
+	where
+		Block: BlockT<Extrinsic = OpaqueExtrinsic>,
+		Client::Api: RTKateApi<Block>,
+	{
+		let (api, at, number, block_len, extrinsics, header) = self.scope(at)?;
+		match header.extension() {
+			HeaderExtension::V3(ext) => {
+				if ext.commitment.commitment.is_empty() {
+					return Err(internal_err!("Requested block {at} has empty commitments"));
+				}
+			},
+		};
+
+		let cells = cells
+			.into_iter()
+			.map(|cell| (cell.row.0, cell.col.0))
+			.collect::<Vec<_>>();
+		let proof = api
+			.proof(at, number, extrinsics, block_len, cells)
+			.map_err(|kate_err| internal_err!("KateApi::proof failed: {kate_err:?}"))?
+			.map_err(|api_err| internal_err!("Failed API: {api_err:?}"))?;
+
+		Ok(proof)
+	}
 }
 
 #[async_trait]
@@ -207,37 +320,26 @@ where
 		cells: Cells,
 		at: Option<HashOf<Block>>,
 	) -> RpcResult<Vec<GDataProof>> {
-		if cells.len() > self.max_cells_size {
-			return Err(
-				internal_err!(
-					"Cannot query ({}) more than {} amount of cells per request. Either increase the max cells size (--kate-max-cells-size) or query less amount of cells per request.",
-					cells.len(),
-					self.max_cells_size
-				)
-			);
-		}
+		self.ensure_max_cells(cells.len())?;
 
 		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryProof);
 
-		let (api, at, number, block_len, extrinsics, header) = self.scope(at)?;
-		match header.extension() {
-			HeaderExtension::V3(ext) => {
-				if ext.commitment.commitment.is_empty() {
-					return Err(internal_err!("Requested block {at} has empty commitments"));
-				}
-			},
-		};
+		self.proof(cells, at)
+	}
 
-		let cells = cells
-			.into_iter()
-			.map(|cell| (cell.row.0, cell.col.0))
-			.collect::<Vec<_>>();
-		let proof = api
-			.proof(at, number, extrinsics, block_len, cells)
-			.map_err(|kate_err| internal_err!("KateApi::proof failed: {kate_err:?}"))?
-			.map_err(|api_err| internal_err!("Failed API: {api_err:?}"))?;
+	async fn query_proof_multi(
+		&self,
+		requests: Vec<(HashOf<Block>, Cells)>,
+	) -> RpcResult<Vec<Vec<GDataProof>>> {
+		self.ensure_max_cells(requests.iter().map(|(_, cells)| cells.len()).sum())?;
 
-		Ok(proof)
+		let _metric_observer = MetricObserver::new(ObserveKind::KateQueryProofMulti);
+
+		// Blocks are independent, so their proofs are computed in parallel.
+		requests
+			.into_par_iter()
+			.map(|(at, cells)| self.proof(cells, Some(at)))
+			.collect()
 	}
 
 	async fn query_block_length(&self, at: Option<HashOf<Block>>) -> RpcResult<BlockLength> {
@@ -270,4 +372,18 @@ where
 
 		Ok(proof)
 	}
+
+	async fn query_header_extension(
+		&self,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<DecodedHeaderExtension> {
+		let at = self.at_or_best(at);
+		let header = self
+			.client
+			.header(at)
+			.map_err(|e| internal_err!("Header of block ({at:?}): {e:?}"))?
+			.ok_or_else(|| internal_err!("Missing block {at}"))?;
+
+		Ok(DecodedHeaderExtension::from(header.extension()))
+	}
 }
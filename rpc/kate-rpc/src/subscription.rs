@@ -0,0 +1,349 @@
+use crate::{HashOf, Kate, RTKateApi};
+
+use avail_core::{
+	data_proof::ProofResponse,
+	header::HeaderExtension,
+	traits::{ExtendedHeader, GetAppId},
+	OpaqueExtrinsic,
+};
+use da_control::Call as DACall;
+use da_runtime::{apis::DataAvailApi, RuntimeCall, UncheckedExtrinsic};
+use futures::StreamExt;
+use jsonrpsee::{
+	core::{async_trait, SubscriptionResult},
+	proc_macros::rpc,
+	types::error::{ErrorObject, SERVER_IS_BUSY_CODE},
+	PendingSubscriptionSink, SubscriptionMessage,
+};
+use sc_client_api::{BlockBackend, BlockchainEvents};
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+use std::sync::{
+	atomic::{AtomicUsize, Ordering},
+	Arc,
+};
+
+/// Content of the data submissions pushed by `kate_subscribeAppData`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AppDataContent {
+	/// The submitted data.
+	#[default]
+	Data,
+	/// The proofs of the submitted data against the data root of the block.
+	Proofs,
+}
+
+/// Data submission of an application in a finalized block.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppDataSubmission {
+	/// Index of the submission among the extrinsics of the block, as taken by
+	/// `kate_queryDataProof`.
+	pub transaction_index: u32,
+	/// Submitted data, with `AppDataContent::Data`.
+	pub data: Option<Bytes>,
+	/// Proof of the submitted data, with `AppDataContent::Proofs`.
+	pub proof: Option<ProofResponse>,
+}
+
+/// Data submissions of an application in a finalized block, pushed for every finalized block
+/// even without submissions, so followers know the block was processed.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppDataNotification<Hash> {
+	pub block_hash: Hash,
+	/// Number of the block, `None` if the block could not be read.
+	pub block_number: Option<u32>,
+	/// Submissions of the application, in the order of the block.
+	pub submissions: Vec<AppDataSubmission>,
+	/// Reason the submissions of the block could not be read, without submissions. The block
+	/// can be queried again with the Kate calls.
+	pub error: Option<String>,
+}
+
+/// Cap of the concurrent subscriptions of a method, shared by all the callers.
+#[derive(Clone, Debug, Default)]
+pub struct SubscriptionLimit {
+	active: Arc<AtomicUsize>,
+	max: Option<usize>,
+}
+
+impl SubscriptionLimit {
+	/// Allows `max` concurrent subscriptions, any number if `None`.
+	pub fn new(max: Option<usize>) -> Self {
+		Self {
+			active: Arc::default(),
+			max,
+		}
+	}
+
+	/// Reserves a subscription, released when the returned slot is dropped, `None` if the
+	/// maximum number of subscriptions is active.
+	fn try_reserve(&self) -> Option<SubscriptionSlot> {
+		self.active
+			.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |active| {
+				self.max
+					.map_or(true, |max| active < max)
+					.then_some(active + 1)
+			})
+			.ok()?;
+		Some(SubscriptionSlot(self.active.clone()))
+	}
+}
+
+/// Active subscription counted by a `SubscriptionLimit`.
+struct SubscriptionSlot(Arc<AtomicUsize>);
+
+impl Drop for SubscriptionSlot {
+	fn drop(&mut self) {
+		self.0.fetch_sub(1, Ordering::SeqCst);
+	}
+}
+
+/// Subscriptions to the finalized data of an application.
+///
+/// Kept apart from `KateApi` as subscriptions are not subject to the per call RPC limits.
+#[rpc(client, server)]
+pub trait KateSubscriptionApi<Block>
+where
+	Block: BlockT,
+{
+	/// Pushes, for each finalized block, the data submissions of `app_id`, with their data or
+	/// with their proofs according to `content`, the data by default.
+	#[subscription(
+		name = "kate_subscribeAppData" => "kate_appData",
+		unsubscribe = "kate_unsubscribeAppData",
+		item = AppDataNotification<HashOf<Block>>
+	)]
+	async fn subscribe_app_data(
+		&self,
+		app_id: u32,
+		content: Option<AppDataContent>,
+	) -> SubscriptionResult;
+}
+
+impl<Client, Block> Kate<Client, Block>
+where
+	Block: BlockT<Extrinsic = OpaqueExtrinsic>,
+	<Block as BlockT>::Header: ExtendedHeader<Extension = HeaderExtension>,
+	Client: Send + Sync + 'static,
+	Client: HeaderBackend<Block> + ProvideRuntimeApi<Block> + BlockBackend<Block>,
+	Client::Api: DataAvailApi<Block> + RTKateApi<Block>,
+{
+	/// Returns the data submissions of `app_id` in the finalized block `at`, or the reason they
+	/// could not be read.
+	fn app_data(
+		&self,
+		at: HashOf<Block>,
+		app_id: u32,
+		content: AppDataContent,
+	) -> AppDataNotification<HashOf<Block>> {
+		match self.app_submissions(at, app_id, content) {
+			Ok((number, submissions)) => AppDataNotification {
+				block_hash: at,
+				block_number: Some(number),
+				submissions,
+				error: None,
+			},
+			Err(error) => AppDataNotification {
+				block_hash: at,
+				block_number: None,
+				submissions: Vec::new(),
+				error: Some(error),
+			},
+		}
+	}
+
+	/// Returns the number of the finalized block `at` and the data submissions of `app_id` in
+	/// it, with their proofs computed by a single runtime call.
+	fn app_submissions(
+		&self,
+		at: HashOf<Block>,
+		app_id: u32,
+		content: AppDataContent,
+	) -> Result<(u32, Vec<AppDataSubmission>), String> {
+		let (api, at, number, _, extrinsics, _) =
+			self.scope(Some(at)).map_err(|e| e.message().to_string())?;
+
+		let (indices, data): (Vec<u32>, Vec<Vec<u8>>) = extrinsics
+			.iter()
+			.enumerate()
+			.filter_map(|(index, opaque)| {
+				let data = app_submitted_data(opaque, app_id)?;
+				Some((u32::try_from(index).ok()?, data))
+			})
+			.unzip();
+
+		let submissions = match content {
+			AppDataContent::Data => indices
+				.into_iter()
+				.zip(data)
+				.map(|(transaction_index, data)| AppDataSubmission {
+					transaction_index,
+					data: Some(Bytes(data)),
+					proof: None,
+				})
+				.collect(),
+			AppDataContent::Proofs => {
+				let version = api
+					.api_version::<dyn RTKateApi<Block>>(at)
+					.map_err(|e| format!("Kate API version at block ({at:?}): {e:?}"))?
+					.unwrap_or_default();
+				if version < 2 {
+					return Err(format!(
+						"Proofs of many submissions are not supported by the runtime at block {at:?}"
+					));
+				}
+
+				let proofs = api
+					.data_proofs(at, number, extrinsics, indices.clone())
+					.map_err(|e| format!("KateApi::data_proofs failed: {e:?}"))?;
+				indices
+					.into_iter()
+					.zip(proofs)
+					.map(|(transaction_index, proof)| {
+						let proof = proof.ok_or_else(|| {
+							format!("Cannot fetch tx data at tx index {transaction_index} at block {at:?}")
+						})?;
+						Ok(AppDataSubmission {
+							transaction_index,
+							data: None,
+							proof: Some(proof),
+						})
+					})
+					.collect::<Result<_, String>>()?
+			},
+		};
+
+		Ok((number, submissions))
+	}
+}
+
+/// Returns the data of `opaque` if it is a non empty `DataAvailability::submit_data` of
+/// `app_id`, the submissions committed to by the data root.
+fn app_submitted_data(opaque: &OpaqueExtrinsic, app_id: u32) -> Option<Vec<u8>> {
+	let xt = UncheckedExtrinsic::try_from(opaque.clone()).ok()?;
+	if xt.app_id().0 != app_id {
+		return None;
+	}
+	let RuntimeCall::DataAvailability(DACall::submit_data { data }) = xt.function else {
+		return None;
+	};
+	(!data.is_empty()).then(|| data.into_inner())
+}
+
+#[async_trait]
+impl<Client, Block> KateSubscriptionApiServer<Block> for Kate<Client, Block>
+where
+	Block: BlockT<Extrinsic = OpaqueExtrinsic>,
+	<Block as BlockT>::Header: ExtendedHeader<Extension = HeaderExtension>,
+	Client: Send + Sync + 'static,
+	Client: HeaderBackend<Block>
+		+ ProvideRuntimeApi<Block>
+		+ BlockBackend<Block>
+		+ BlockchainEvents<Block>,
+	Client::Api: DataAvailApi<Block> + RTKateApi<Block>,
+{
+	async fn subscribe_app_data(
+		&self,
+		pending: PendingSubscriptionSink,
+		app_id: u32,
+		content: Option<AppDataContent>,
+	) -> SubscriptionResult {
+		let content = content.unwrap_or_default();
+		let Some(_slot) = self.subscription_limit.try_reserve() else {
+			let error = ErrorObject::owned(
+				SERVER_IS_BUSY_CODE,
+				"Too many kate_subscribeAppData subscriptions, try again later",
+				None::<()>,
+			);
+			pending.reject(error).await;
+			return Ok(());
+		};
+		let mut finality = self.client.finality_notification_stream();
+		let Ok(sink) = pending.accept().await else {
+			return Ok(());
+		};
+
+		while let Some(notification) = finality.next().await {
+			// Blocks finalized together are notified once, with the earlier ones in the route.
+			let finalized = notification
+				.tree_route
+				.iter()
+				.chain(std::iter::once(&notification.hash));
+			for at in finalized {
+				// A block whose submissions cannot be read is notified with the error, so the
+				// subscription follows the next blocks.
+				let app_data = self.app_data(*at, app_id, content);
+				let message = SubscriptionMessage::from_json(&app_data)?;
+				if sink.send(message).await.is_err() {
+					// The subscriber is gone.
+					return Ok(());
+				}
+			}
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use avail_core::AppId;
+	use da_control::{AppDataFor, CheckAppId};
+	use da_runtime::{Runtime, SignedExtra};
+	use frame_system::{
+		CheckEra, CheckGenesis, CheckNonZeroSender, CheckNonce, CheckSpecVersion, CheckTxVersion,
+		CheckWeight,
+	};
+	use pallet_transaction_payment::ChargeTransactionPayment;
+	use sp_core::sr25519;
+	use sp_runtime::{generic::Era, AccountId32, MultiSignature};
+
+	fn submit_data(app_id: u32, data: &[u8]) -> OpaqueExtrinsic {
+		let extra: SignedExtra = (
+			CheckNonZeroSender::<Runtime>::new(),
+			CheckSpecVersion::<Runtime>::new(),
+			CheckTxVersion::<Runtime>::new(),
+			CheckGenesis::<Runtime>::new(),
+			CheckEra::<Runtime>::from(Era::Immortal),
+			CheckNonce::<Runtime>::from(0),
+			CheckWeight::<Runtime>::new(),
+			ChargeTransactionPayment::<Runtime>::from(0),
+			CheckAppId::<Runtime>::from(AppId(app_id)),
+		);
+		let data = AppDataFor::<Runtime>::truncate_from(data.to_vec());
+		let function = DACall::submit_data { data }.into();
+		// The signature is not checked when filtering the submissions.
+		let signature = MultiSignature::Sr25519(sr25519::Signature::from_raw([0; 64]));
+		let signer = AccountId32::new([1; 32]).into();
+
+		UncheckedExtrinsic::new_signed(function, signer, signature, extra).into()
+	}
+
+	#[test]
+	fn submit_data_is_filtered_by_app_id() {
+		let submission = submit_data(1, b"data");
+
+		assert_eq!(Some(b"data".to_vec()), app_submitted_data(&submission, 1));
+		assert_eq!(None, app_submitted_data(&submission, 2));
+		assert_eq!(None, app_submitted_data(&submit_data(1, b""), 1));
+	}
+
+	#[test]
+	fn subscription_limit_releases_dropped_slots() {
+		let limit = SubscriptionLimit::new(Some(1));
+
+		let slot = limit.try_reserve();
+		assert!(slot.is_some());
+		assert!(limit.try_reserve().is_none());
+
+		drop(slot);
+		assert!(limit.try_reserve().is_some());
+	}
+}
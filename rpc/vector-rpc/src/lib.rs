@@ -0,0 +1,464 @@
+use avail_core::data_proof::AddressedMessage;
+use codec::Decode;
+use da_runtime::apis::VectorApi as RTVectorApi;
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::ErrorObject,
+};
+use pallet_vector::state::{FeePriority, MessageDirection};
+use sc_client_api::ProofProvider;
+use sc_rpc_api::DenyUnsafe;
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::{storage::StorageKey, Bytes, U256};
+use sp_runtime::{
+	traits::{Block as BlockT, Header},
+	PerThing, Perbill,
+};
+use std::{marker::PhantomData, sync::Arc};
+
+pub type HashOf<Block> = <Block as BlockT>::Hash;
+
+/// Avail state proof in the layout expected by the Ethereum verification contract.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EthReadProof<Hash> {
+	/// Block hash the proof was generated at.
+	pub at: Hash,
+	/// Block number the proof was generated at.
+	pub block_number: u32,
+	/// State root of the block, the root of `proof`.
+	pub state_root: Hash,
+	/// Encoded trie nodes, deduplicated and sorted in ascending byte order.
+	pub proof: Vec<Bytes>,
+}
+
+/// Outcome of a dry-run of the execution of a bridge message.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageSimulation<Hash> {
+	/// Block hash the execution was simulated at.
+	pub at: Hash,
+	/// Hash of the message, the key of its status.
+	pub message_root: sp_core::H256,
+	/// Status the message would have after the execution.
+	pub status: String,
+	/// The message would wait for the previous messages of its ordered channel.
+	pub queued: bool,
+	/// Pallet error the execution would fail with, if any.
+	pub error: Option<String>,
+}
+
+/// Outcome of each verification stage of a `fulfill_call` extrinsic.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationTrace<Hash> {
+	/// Block hash the verification was traced at.
+	pub at: Hash,
+	/// Keccak hash of the inputs.
+	pub input_hash: sp_core::H256,
+	/// The same inputs were verified already, their verification would be skipped.
+	pub already_verified: bool,
+	/// Function selected by the function id, `None` if the id is not registered.
+	pub function: Option<String>,
+	/// Decoding of the CBOR inputs.
+	pub decode: String,
+	/// The step is older than the head and of no use, it would be rejected unverified.
+	pub stale: bool,
+	/// Verification of the sync committee updates, in order.
+	pub updates: Vec<String>,
+	/// Verification of the finality update, including the signature of the sync committee.
+	pub finality_update: String,
+	/// Verification of the execution state root against the finalized header.
+	pub execution_state_root: String,
+	/// Slot of the verified finalized header, set if every stage passed.
+	pub finalized_slot: Option<u64>,
+	/// Root of the verified finalized header, set if every stage passed.
+	pub finalized_header_root: Option<sp_core::H256>,
+	/// Sync committee participation of the verified finalized header, set if every stage passed.
+	pub participation: Option<u16>,
+	/// How the verified finalized header would be applied against the head, or the error
+	/// rejecting it, set if every stage passed.
+	pub acceptance: Option<String>,
+}
+
+/// Sync committee participation of the step outputs accepted during a period.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeriodParticipation {
+	/// Sync committee period.
+	pub period: u64,
+	/// Number of accepted step outputs.
+	pub updates: u32,
+	/// Number of them with low participation.
+	pub low_updates: u32,
+	pub min: u16,
+	pub max: u16,
+	pub average: u16,
+	/// Share of the updates without low participation, between 0 and 1.
+	pub liveness: Option<f64>,
+}
+
+/// Participation history of the sync committee, as observed by the light client.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipationHistory<Hash> {
+	/// Block hash the history was read at.
+	pub at: Hash,
+	/// Periods with accepted step outputs, in increasing order.
+	pub periods: Vec<PeriodParticipation>,
+	/// Share of the updates of all the periods without low participation, between 0 and 1.
+	pub liveness_score: Option<f64>,
+}
+
+/// Estimate of the cost of bridging a message.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageFeeQuote<Hash> {
+	/// Block hash the fees were quoted at.
+	pub at: Hash,
+	/// Fee of the weight of the extrinsic carrying the message on Avail.
+	pub execution_fee: u128,
+	/// Tip suggested to the relayer for the priority of the message.
+	pub relayer_tip: u128,
+	/// Sum of the execution fee and of the relayer tip, paid on Avail.
+	pub total_fee: u128,
+	/// Gas used by the delivery of an outbound message on the destination chain.
+	pub destination_gas: u64,
+	/// Cost of the destination gas at the latest base fee proven for the destination domain, in
+	/// the native unit of the destination chain. `None` without a proven base fee.
+	pub destination_fee: Option<U256>,
+}
+
+#[rpc(client, server)]
+pub trait VectorApi<Block>
+where
+	Block: BlockT,
+{
+	/// Returns the storage proof of `keys`, ready to be submitted to the Ethereum contracts.
+	#[method(name = "state_getReadProofEth")]
+	async fn read_proof_eth(
+		&self,
+		keys: Vec<StorageKey>,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<EthReadProof<HashOf<Block>>>;
+
+	/// Runs the verification and the delivery of `message` against the execution state root of
+	/// `slot` without changing the state, as `execute` would if it were submitted at `at`.
+	#[method(name = "succinct_simulateExecuteMessage")]
+	async fn simulate_execute_message(
+		&self,
+		slot: u64,
+		message: AddressedMessage,
+		account_proof: Vec<Bytes>,
+		storage_proof: Vec<Bytes>,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<MessageSimulation<HashOf<Block>>>;
+
+	/// Returns the seconds elapsed since the timestamp of the bridge head on the source chain,
+	/// `None` if it is not known yet.
+	#[method(name = "succinct_headAgeSeconds")]
+	async fn head_age_seconds(&self, at: Option<HashOf<Block>>) -> RpcResult<Option<u64>>;
+
+	/// Runs the verification of the `fulfill_call` in the SCALE encoded `extrinsic` as it would
+	/// be at `at` and reports the outcome of each stage. Unsafe, as the verification is costly.
+	#[method(name = "succinct_traceVerification")]
+	async fn trace_verification(
+		&self,
+		extrinsic: Bytes,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<VerificationTrace<HashOf<Block>>>;
+
+	/// Returns the participation of the step outputs accepted over the last `periods` sync
+	/// committee periods, up to the one of the head, with their liveness score.
+	#[method(name = "succinct_participationHistory")]
+	async fn participation_history(
+		&self,
+		periods: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<ParticipationHistory<HashOf<Block>>>;
+
+	/// Quotes the cost of bridging a message of `payload_size` bytes, an arbitrary message or,
+	/// without payload, a fungible token transfer, in `direction` with `priority`. The gas of
+	/// the delivery of an outbound message is priced at the base fee proven for `domain`.
+	#[method(name = "succinct_quoteMessageFee")]
+	async fn quote_message_fee(
+		&self,
+		direction: MessageDirection,
+		payload_size: u32,
+		priority: FeePriority,
+		domain: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<MessageFeeQuote<HashOf<Block>>>;
+}
+
+pub struct Vector<Client, Block: BlockT> {
+	client: Arc<Client>,
+	deny_unsafe: DenyUnsafe,
+	_block: PhantomData<Block>,
+}
+
+impl<Client, Block: BlockT> Vector<Client, Block> {
+	pub fn new(client: Arc<Client>, deny_unsafe: DenyUnsafe) -> Self {
+		Self {
+			client,
+			deny_unsafe,
+			_block: PhantomData,
+		}
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The proof cannot be generated.
+	VectorRPCError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::VectorRPCError => 1,
+		}
+	}
+}
+
+macro_rules! internal_err {
+	($($arg:tt)*) => {{
+		ErrorObject::owned(
+			Error::VectorRPCError.into(),
+			format!($($arg)*),
+			None::<()>
+		)
+	}}
+}
+
+#[async_trait]
+impl<Client, Block> VectorApiServer<Block> for Vector<Client, Block>
+where
+	Block: BlockT,
+	Client: Send + Sync + 'static,
+	Client: HeaderBackend<Block> + ProofProvider<Block> + ProvideRuntimeApi<Block>,
+	Client::Api: RTVectorApi<Block>,
+{
+	async fn read_proof_eth(
+		&self,
+		keys: Vec<StorageKey>,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<EthReadProof<HashOf<Block>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let header = self
+			.client
+			.header(at)
+			.map_err(|e| internal_err!("Header of block ({at:?}): {e:?}"))?
+			.ok_or_else(|| internal_err!("Missing block {at:?}"))?;
+		let block_number: u32 = (*header.number())
+			.try_into()
+			.map_err(|_| internal_err!("Block number of {at:?} does not fit into u32"))?;
+
+		let proof = self
+			.client
+			.read_proof(at, &mut keys.iter().map(|key| key.0.as_ref()))
+			.map_err(|e| internal_err!("Read proof at block ({at:?}): {e:?}"))?;
+
+		let mut nodes: Vec<Vec<u8>> = proof.into_iter_nodes().collect();
+		nodes.sort();
+		nodes.dedup();
+
+		Ok(EthReadProof {
+			at,
+			block_number,
+			state_root: *header.state_root(),
+			proof: nodes.into_iter().map(Bytes).collect(),
+		})
+	}
+	async fn simulate_execute_message(
+		&self,
+		slot: u64,
+		message: AddressedMessage,
+		account_proof: Vec<Bytes>,
+		storage_proof: Vec<Bytes>,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<MessageSimulation<HashOf<Block>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let api = self.client.runtime_api();
+		let version = api
+			.api_version::<dyn RTVectorApi<Block>>(at)
+			.map_err(|e| internal_err!("Vector API version at block ({at:?}): {e:?}"))?
+			.unwrap_or_default();
+		if version < 2 {
+			return Err(internal_err!(
+				"Message simulation is not supported by the runtime at block {at:?}"
+			));
+		}
+
+		let simulation = api
+			.simulate_execute(
+				at,
+				slot,
+				message,
+				account_proof.into_iter().map(|node| node.0).collect(),
+				storage_proof.into_iter().map(|node| node.0).collect(),
+			)
+			.map_err(|e| internal_err!("Simulate execution at block ({at:?}): {e:?}"))?;
+
+		Ok(MessageSimulation {
+			at,
+			message_root: simulation.message_root,
+			status: format!("{:?}", simulation.status),
+			queued: simulation.queued,
+			error: simulation
+				.error
+				.map(|error| String::from_utf8_lossy(&error).into_owned()),
+		})
+	}
+
+	async fn head_age_seconds(&self, at: Option<HashOf<Block>>) -> RpcResult<Option<u64>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let api = self.client.runtime_api();
+		let version = api
+			.api_version::<dyn RTVectorApi<Block>>(at)
+			.map_err(|e| internal_err!("Vector API version at block ({at:?}): {e:?}"))?
+			.unwrap_or_default();
+		if version < 6 {
+			return Err(internal_err!(
+				"Head age is not supported by the runtime at block {at:?}"
+			));
+		}
+
+		api.head_age_seconds(at)
+			.map_err(|e| internal_err!("Head age at block ({at:?}): {e:?}"))
+	}
+
+	async fn trace_verification(
+		&self,
+		extrinsic: Bytes,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<VerificationTrace<HashOf<Block>>> {
+		self.deny_unsafe.check_if_safe()?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let api = self.client.runtime_api();
+		let version = api
+			.api_version::<dyn RTVectorApi<Block>>(at)
+			.map_err(|e| internal_err!("Vector API version at block ({at:?}): {e:?}"))?
+			.unwrap_or_default();
+		if version < 7 {
+			return Err(internal_err!(
+				"Verification trace is not supported by the runtime at block {at:?}"
+			));
+		}
+
+		let extrinsic = Block::Extrinsic::decode(&mut extrinsic.as_ref())
+			.map_err(|e| internal_err!("Decode extrinsic: {e:?}"))?;
+		let trace = api
+			.trace_verification(at, extrinsic)
+			.map_err(|e| internal_err!("Trace verification at block ({at:?}): {e:?}"))?
+			.ok_or_else(|| internal_err!("The extrinsic is not a Vector fulfill call"))?;
+
+		let output = trace.output;
+		Ok(VerificationTrace {
+			at,
+			input_hash: trace.input_hash,
+			already_verified: trace.already_verified,
+			function: trace.function.map(|function| format!("{function:?}")),
+			decode: format!("{:?}", trace.decode),
+			stale: trace.stale,
+			updates: trace
+				.updates
+				.iter()
+				.map(|update| format!("{update:?}"))
+				.collect(),
+			finality_update: format!("{:?}", trace.finality_update),
+			execution_state_root: format!("{:?}", trace.execution_state_root),
+			finalized_slot: output.map(|output| output.finalized_slot),
+			finalized_header_root: output.map(|output| output.finalized_header_root),
+			participation: output.map(|output| output.participation),
+			acceptance: trace.acceptance.map(|acceptance| match acceptance {
+				Ok(acceptance) => format!("{acceptance:?}"),
+				Err(error) => format!("{error:?}"),
+			}),
+		})
+	}
+
+	async fn participation_history(
+		&self,
+		periods: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<ParticipationHistory<HashOf<Block>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let api = self.client.runtime_api();
+		let version = api
+			.api_version::<dyn RTVectorApi<Block>>(at)
+			.map_err(|e| internal_err!("Vector API version at block ({at:?}): {e:?}"))?
+			.unwrap_or_default();
+		if version < 10 {
+			return Err(internal_err!(
+				"Participation history is not supported by the runtime at block {at:?}"
+			));
+		}
+
+		let history = api
+			.participation_history(at, periods)
+			.map_err(|e| internal_err!("Participation history at block ({at:?}): {e:?}"))?;
+		let liveness_score = api
+			.liveness_score(at, periods)
+			.map_err(|e| internal_err!("Liveness score at block ({at:?}): {e:?}"))?;
+
+		Ok(ParticipationHistory {
+			at,
+			periods: history
+				.into_iter()
+				.map(|(period, participation)| PeriodParticipation {
+					period,
+					updates: participation.updates,
+					low_updates: participation.low_updates,
+					min: participation.min,
+					max: participation.max,
+					average: participation.average(),
+					liveness: participation.liveness().map(share),
+				})
+				.collect(),
+			liveness_score: liveness_score.map(share),
+		})
+	}
+
+	async fn quote_message_fee(
+		&self,
+		direction: MessageDirection,
+		payload_size: u32,
+		priority: FeePriority,
+		domain: u32,
+		at: Option<HashOf<Block>>,
+	) -> RpcResult<MessageFeeQuote<HashOf<Block>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let api = self.client.runtime_api();
+		let version = api
+			.api_version::<dyn RTVectorApi<Block>>(at)
+			.map_err(|e| internal_err!("Vector API version at block ({at:?}): {e:?}"))?
+			.unwrap_or_default();
+		if version < 12 {
+			return Err(internal_err!(
+				"Message fee quotes are not supported by the runtime at block {at:?}"
+			));
+		}
+
+		let quote = api
+			.quote_message_fee(at, direction, payload_size, priority, domain)
+			.map_err(|e| internal_err!("Message fee quote at block ({at:?}): {e:?}"))?;
+
+		Ok(MessageFeeQuote {
+			at,
+			execution_fee: quote.execution_fee,
+			relayer_tip: quote.relayer_tip,
+			total_fee: quote.execution_fee.saturating_add(quote.relayer_tip),
+			destination_gas: quote.destination_gas,
+			destination_fee: quote.destination_fee,
+		})
+	}
+}
+
+/// Returns `ratio` as a share between 0 and 1.
+fn share(ratio: Perbill) -> f64 {
+	f64::from(ratio.deconstruct()) / f64::from(Perbill::ACCURACY)
+}
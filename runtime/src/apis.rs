@@ -8,7 +8,7 @@ use crate::{
 use avail_base::{HeaderExtensionBuilderData, ProvidePostInherent};
 use avail_core::{
 	currency::Balance,
-	data_proof::{DataProof, ProofResponse, SubTrie},
+	data_proof::{AddressedMessage, DataProof, ProofResponse, SubTrie, TxDataRoots},
 	header::HeaderExtension,
 	OpaqueExtrinsic,
 };
@@ -21,6 +21,11 @@ use frame_support::{
 	weights::Weight,
 };
 use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
+use pallet_vector::nonce::MissingNonces;
+use pallet_vector::state::{
+	ExecutionFailure, ExecutionSimulation, FeePriority, MessageDirection, MessageFeeQuote,
+	NamedExecutionSimulation, PeriodParticipation, SourceBaseFee, VerificationTrace,
+};
 use sp_api::{decl_runtime_apis, impl_runtime_apis};
 use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
@@ -28,8 +33,8 @@ use sp_core::{crypto::KeyTypeId, H256, U256};
 use sp_inherents::{CheckInherentsResult, InherentData};
 use sp_runtime::{
 	traits::{Block as BlockT, Extrinsic as ExtrinsicT, NumberFor},
-	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult,
+	transaction_validity::{TransactionSource, TransactionValidity, ValidTransaction},
+	ApplyExtrinsicResult, Perbill,
 };
 use sp_std::{borrow::Cow, vec::Vec};
 use sp_version::RuntimeVersion;
@@ -56,20 +61,93 @@ decl_runtime_apis! {
 		fn check_if_extrinsic_is_post_inherent(uxt: &<Block as BlockT>::Extrinsic) -> bool;
 	}
 
+	#[api_version(13)]
 	pub trait VectorApi {
 		fn sync_committee_poseidons(slot: u64) -> U256;
 		fn sync_committee_hashes(slot: u64) -> U256;
 		fn head() -> u64;
 		fn headers(slot: u64) -> H256;
+		#[api_version(2)]
+		fn simulate_execute(slot: u64, addr_message: AddressedMessage, account_proof: Vec<Vec<u8>>, storage_proof: Vec<Vec<u8>>) -> NamedExecutionSimulation;
+		#[api_version(3)]
+		fn source_base_fee(domain: u32) -> Option<SourceBaseFee>;
+		#[api_version(4)]
+		fn current_period() -> Option<u64>;
+		#[api_version(4)]
+		fn period_start_slot(period: u64) -> Option<u64>;
+		#[api_version(4)]
+		fn rotate_due_by_slot() -> Option<u64>;
+		#[api_version(5)]
+		fn message_failure(message_root: H256) -> Option<ExecutionFailure>;
+		#[api_version(6)]
+		fn head_age_seconds() -> Option<u64>;
+		#[api_version(7)]
+		fn trace_verification(uxt: <Block as BlockT>::Extrinsic) -> Option<VerificationTrace>;
+		#[api_version(8)]
+		fn check_invariants() -> Result<(), Vec<u8>>;
+		#[api_version(9)]
+		fn missing_nonces() -> Vec<MissingNonces>;
+		#[api_version(10)]
+		fn participation_history(periods: u32) -> Vec<(u64, PeriodParticipation)>;
+		#[api_version(10)]
+		fn liveness_score(periods: u32) -> Option<Perbill>;
+		#[api_version(11)]
+		fn block_number_for_slot(slot: u64) -> Option<u64>;
+		#[api_version(12)]
+		fn quote_message_fee(direction: MessageDirection, payload_size: u32, priority: FeePriority, domain: u32) -> MessageFeeQuote;
+		#[api_version(13)]
+		fn dry_run_execute(slot: u64, addr_message: AddressedMessage, account_proof: Vec<Vec<u8>>, storage_proof: Vec<Vec<u8>>) -> ExecutionSimulation;
 	}
 
+	#[api_version(2)]
 	pub trait KateApi {
 		fn data_proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, tx_idx: u32) -> Option<ProofResponse>;
+		/// Proofs of the transactions at `tx_indices`, building the data roots of the block once.
+		#[api_version(2)]
+		fn data_proofs(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, tx_indices: Vec<u32>) -> Vec<Option<ProofResponse>>;
 		fn rows(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, rows: Vec<u32>) -> Result<Vec<GRow>, RTKateError >;
 		fn proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, cells: Vec<(u32,u32)> ) -> Result<Vec<GDataProof>, RTKateError>;
 	}
 }
 
+/// Returns the proof of the transaction at `tx_idx` against the data roots of the block, built
+/// from its sub-trie roots `submitted_root` and `bridged_root`.
+fn data_proof_of(
+	data: &HeaderExtensionBuilderData,
+	submitted_root: H256,
+	bridged_root: H256,
+	tx_idx: u32,
+) -> Option<ProofResponse> {
+	let (leaf_idx, sub_trie) = data.leaf_idx(tx_idx)?;
+	log::trace!(
+		target: LOG_TARGET,
+		"KateApi::data_proof: tx_idx={tx_idx:?} leaf_idx={leaf_idx:?}, sub_trie:{sub_trie:?}");
+
+	let (sub_proof, message) = match sub_trie {
+		SubTrie::DataSubmit => {
+			let proof = data.submitted_proof_of(leaf_idx)?;
+			(proof, None)
+		},
+		SubTrie::Bridge => {
+			let message = data
+				.bridge_messages
+				.get(leaf_idx)
+				.map(|b| b.addr_msg.clone());
+			let proof = data.bridged_proof_of(leaf_idx)?;
+			(proof, message)
+		},
+	};
+
+	let roots = TxDataRoots::new(submitted_root, bridged_root);
+	let data_proof = DataProof::new(sub_trie, roots, sub_proof);
+	let proof = ProofResponse::new(data_proof, message);
+	log::trace!(
+		target: LOG_TARGET,
+		"KateApi::data_proof: proof={proof:#?}");
+
+	Some(proof)
+}
+
 pub(crate) const fn runtime_api_versions() -> Cow<'static, [([u8; 8], u32)]> {
 	RUNTIME_API_VERSIONS
 }
@@ -130,7 +208,12 @@ impl_runtime_apis! {
 			tx: <Block as BlockT>::Extrinsic,
 			block_hash: <Block as BlockT>::Hash,
 		) -> TransactionValidity {
+			let mut vector_validity = ValidTransaction::default();
+			if let RuntimeCall::Vector(call) = &tx.function {
+				vector_validity = pallet_vector::Pallet::<Runtime>::pre_validate(call)?;
+			}
 			Executive::validate_transaction(source, tx, block_hash)
+				.map(|validity| validity.combine_with(vector_validity))
 		}
 	}
 
@@ -403,36 +486,86 @@ impl_runtime_apis! {
 		fn headers(slot: u64) -> H256 {
 			pallet_vector::Pallet::<Runtime>::headers(slot)
 		}
+
+		fn simulate_execute(slot: u64, addr_message: AddressedMessage, account_proof: Vec<Vec<u8>>, storage_proof: Vec<Vec<u8>>) -> NamedExecutionSimulation {
+			pallet_vector::Pallet::<Runtime>::simulate_execute(slot, addr_message, account_proof, storage_proof).into()
+		}
+
+		fn source_base_fee(domain: u32) -> Option<SourceBaseFee> {
+			pallet_vector::Pallet::<Runtime>::source_base_fee(domain)
+		}
+
+		fn current_period() -> Option<u64> {
+			pallet_vector::Pallet::<Runtime>::current_period()
+		}
+
+		fn period_start_slot(period: u64) -> Option<u64> {
+			pallet_vector::Pallet::<Runtime>::period_start_slot(period)
+		}
+
+		fn rotate_due_by_slot() -> Option<u64> {
+			pallet_vector::Pallet::<Runtime>::rotate_due_by_slot()
+		}
+
+		fn message_failure(message_root: H256) -> Option<ExecutionFailure> {
+			pallet_vector::Pallet::<Runtime>::message_failure(message_root)
+		}
+
+		fn head_age_seconds() -> Option<u64> {
+			pallet_vector::Pallet::<Runtime>::head_age_seconds()
+		}
+
+		fn trace_verification(uxt: <Block as BlockT>::Extrinsic) -> Option<VerificationTrace> {
+			match uxt.function {
+				RuntimeCall::Vector(pallet_vector::Call::fulfill_call { function_id, inputs }) => {
+					Some(pallet_vector::Pallet::<Runtime>::trace_verification(function_id, inputs))
+				},
+				_ => None,
+			}
+		}
+
+		fn check_invariants() -> Result<(), Vec<u8>> {
+			pallet_vector::Pallet::<Runtime>::check_invariants().map_err(|violation| violation.as_bytes().to_vec())
+		}
+
+		fn missing_nonces() -> Vec<MissingNonces> {
+			pallet_vector::Pallet::<Runtime>::missing_nonces()
+		}
+
+		fn participation_history(periods: u32) -> Vec<(u64, PeriodParticipation)> {
+			pallet_vector::Pallet::<Runtime>::participation_history(periods)
+		}
+
+		fn liveness_score(periods: u32) -> Option<Perbill> {
+			pallet_vector::Pallet::<Runtime>::liveness_score(periods)
+		}
+
+		fn block_number_for_slot(slot: u64) -> Option<u64> {
+			pallet_vector::Pallet::<Runtime>::block_number_for_slot(slot)
+		}
+
+		fn quote_message_fee(direction: MessageDirection, payload_size: u32, priority: FeePriority, domain: u32) -> MessageFeeQuote {
+			crate::impls::quote_message_fee(direction, payload_size, priority, domain)
+		}
+
+		fn dry_run_execute(slot: u64, addr_message: AddressedMessage, account_proof: Vec<Vec<u8>>, storage_proof: Vec<Vec<u8>>) -> ExecutionSimulation {
+			pallet_vector::Pallet::<Runtime>::simulate_execute(slot, addr_message, account_proof, storage_proof)
+		}
 	}
 
 	impl crate::apis::KateApi<Block> for Runtime {
 		fn data_proof(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, tx_idx: u32) -> Option<ProofResponse> {
 			let data = HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block_number, &extrinsics);
-			let (leaf_idx, sub_trie) = data.leaf_idx(tx_idx)?;
-			log::trace!(
-				target: LOG_TARGET,
-				"KateApi::data_proof: tx_idx={tx_idx:?} leaf_idx={leaf_idx:?}, sub_trie:{sub_trie:?}");
-
-			let (sub_proof, message) = match sub_trie {
-				SubTrie::DataSubmit => {
-					let proof = data.submitted_proof_of(leaf_idx)?;
-					(proof, None)
-				},
-				SubTrie::Bridge => {
-					let message = data.bridge_messages.get(leaf_idx).map(|b| b.addr_msg.clone());
-					let proof = data.bridged_proof_of(leaf_idx)?;
-					(proof, message)
-				},
-			};
-
-			let roots = data.roots();
-			let data_proof = DataProof::new(sub_trie, roots, sub_proof);
-			let proof = ProofResponse::new(data_proof, message);
-			log::trace!(
-				target: LOG_TARGET,
-				"KateApi::data_proof: proof={proof:#?}");
+			data_proof_of(&data, data.submitted_root(), data.bridged_root(), tx_idx)
+		}
 
-			Some(proof)
+		fn data_proofs(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, tx_indices: Vec<u32>) -> Vec<Option<ProofResponse>> {
+			let data = HeaderExtensionBuilderData::from_opaque_extrinsics::<RTExtractor>(block_number, &extrinsics);
+			let (submitted_root, bridged_root) = (data.submitted_root(), data.bridged_root());
+			tx_indices
+				.into_iter()
+				.map(|tx_idx| data_proof_of(&data, submitted_root, bridged_root, tx_idx))
+				.collect()
 		}
 
 		fn rows(block_number: u32, extrinsics: Vec<OpaqueExtrinsic>, block_len: BlockLength, rows: Vec<u32>) -> Result<Vec<GRow>, RTKateError> {
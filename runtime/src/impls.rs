@@ -9,6 +9,7 @@ use crate::{
 };
 use avail_core::{
 	currency::{Balance, AVAIL, CENTS, NANO_AVAIL, PICO_AVAIL},
+	data_proof::BOUNDED_DATA_MAX_LENGTH,
 	AppId, NORMAL_DISPATCH_RATIO,
 };
 
@@ -19,13 +20,14 @@ use frame_election_provider_support::{
 };
 use frame_support::{
 	derive_impl,
+	dispatch::Pays,
 	pallet_prelude::{Get, Weight},
 	parameter_types,
 	traits::{
 		fungible::HoldConsideration,
 		tokens::{pay::PayFromAccount, Imbalance, UnityAssetBalanceConversion},
-		ConstU32, Contains, Currency, EitherOf, EitherOfDiverse, EqualPrivilegeOnly, InsideBoth,
-		InstanceFilter, LinearStoragePrice, OnUnbalanced,
+		ConstBool, ConstU128, ConstU16, ConstU32, Contains, Currency, EitherOf, EitherOfDiverse,
+		EqualPrivilegeOnly, InsideBoth, InstanceFilter, LinearStoragePrice, OnUnbalanced,
 	},
 	weights::{constants::RocksDbWeight, ConstantMultiplier},
 	PalletId,
@@ -33,14 +35,19 @@ use frame_support::{
 use frame_system::{limits::BlockLength, EnsureRoot, EnsureRootWithSuccess, EnsureWithSuccess};
 use pallet_election_provider_multi_phase::{GeometricDepositBase, SolutionAccuracyOf};
 use pallet_identity::legacy::IdentityInfo;
-use pallet_transaction_payment::{CurrencyAdapter, Multiplier, TargetedFeeAdjustment};
+use pallet_transaction_payment::{
+	CurrencyAdapter, Multiplier, OnChargeTransaction, TargetedFeeAdjustment,
+};
 use pallet_tx_pause::RuntimeCallNameOf;
-use sp_core::{ConstU64, RuntimeDebug};
+use pallet_vector::state::{FeePriority, MessageDirection, MessageFeeQuote};
+use sp_core::{ConstU64, RuntimeDebug, H256};
 use sp_runtime::{
 	generic::Era,
 	traits::{self, BlakeTwo256, Bounded, Convert, IdentityLookup, OpaqueKeys},
+	transaction_validity::TransactionValidityError,
 	FixedPointNumber, FixedU128, Perbill, Permill, Perquintill,
 };
+use sp_staking::offence::ReportOffence;
 
 pub type NegativeImbalance<T> = <pallet_balances::Pallet<T> as Currency<
 	<T as frame_system::Config>::AccountId,
@@ -64,6 +71,9 @@ impl pallet_mandate::Config for Runtime {
 
 parameter_types! {
 	pub const BridgePalletId: PalletId = PalletId(*b"avl/brdg");
+	pub BridgeFeeTarget: Weight = Perbill::from_percent(10) * constants::system::RuntimeBlockWeights::get().max_block;
+	pub BridgeFeeAdjustment: Multiplier = Multiplier::saturating_from_rational(1, 1000);
+	pub MaximumBridgeFeeMultiplier: Multiplier = Multiplier::saturating_from_integer(1000);
 }
 
 impl pallet_vector::Config for Runtime {
@@ -75,6 +85,77 @@ impl pallet_vector::Config for Runtime {
 	type MessageMappingStorageIndex = ConstU64<1>;
 	type PalletId = BridgePalletId;
 	type AvailDomain = ConstU32<1>;
+	type SlotRetention = ConstU64<262_144>;
+	type ArchiveEpochSlots = ConstU64<8192>;
+	type CurrentSession = CurrentSessionIndex;
+	type VerifiedInputsExpiry = ConstU32<{ 10 * MINUTES }>;
+	type SourceChainHasher = pallet_vector::KeccakHasher;
+	type SourceChainTrieLayout = pallet_vector::EIP1186Layout<pallet_vector::KeccakHasher>;
+	type LowParticipationMargin = ConstU16<20>;
+	type MaxLowParticipationUpdates = ConstU32<8>;
+	type HighValueAmount = ConstU128<{ 10_000 * AVAIL }>;
+	type DegradedOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 5, 7>,
+	>;
+	type EquivocationReporter = VectorEquivocationReporter;
+	type MaxProofNodes = ConstU32<32>;
+	type MaxProofNodeSize = ConstU32<2048>;
+	type MaxProofDepth = ConstU32<16>;
+	type MaxCompressedProofSize = ConstU32<{ 16 * 1024 }>;
+	type MaxMessageLength = ConstU32<BOUNDED_DATA_MAX_LENGTH>;
+	type MaxBatchMessages = ConstU32<16>;
+	type MaxMultiProofNodes = ConstU32<256>;
+	type DepositOutboundRootLog = ConstBool<true>;
+	type BridgeFeeTarget = BridgeFeeTarget;
+	type BridgeFeeAdjustment = BridgeFeeAdjustment;
+	type MaximumBridgeFeeMultiplier = MaximumBridgeFeeMultiplier;
+	type EvmExecutor = ();
+	type EvmGasLimit = ConstU64<1_000_000>;
+	type OnMessageReceived = ();
+	type OnMessageExecuted = ();
+	type DestinationHandlers = ();
+	type GovernanceDelay = ConstU32<{ 2 * DAYS }>;
+}
+
+/// Reports Vector updater equivocations to the offences pallet, so staked updaters are slashed.
+/// Updaters without a staking exposure cannot be reported and are removed by the pallet.
+pub struct VectorEquivocationReporter;
+impl pallet_vector::equivocation::ReportUpdaterEquivocation for VectorEquivocationReporter {
+	fn report(updater: H256, slot: u64) -> bool {
+		let updater = AccountId::from(updater.0);
+		let Some(exposure) = pallet_staking::ExposureOf::<Runtime>::convert(updater.clone()) else {
+			log::warn!(
+				target: "runtime::vector",
+				"Equivocation of updater {updater:?} is not reported, it is not staked"
+			);
+			return false;
+		};
+
+		let offence = pallet_vector::equivocation::UpdaterEquivocationOffence {
+			session_index: Session::current_index(),
+			validator_set_count: Session::validators().len() as u32,
+			offender: (updater, exposure),
+			slot,
+		};
+		// The equivocation is detected on-chain, so there is no reporter to reward. The only
+		// error is a duplicate report, for which the updater is already slashed.
+		if let Err(e) = Offences::report_offence(Default::default(), offence) {
+			log::warn!(
+				target: "runtime::vector",
+				"Equivocation of updater cannot be reported: {e:?}"
+			);
+		}
+		true
+	}
+}
+
+/// Index of the current session, used by the Vector pallet to reset updater quotas.
+pub struct CurrentSessionIndex;
+impl Get<u32> for CurrentSessionIndex {
+	fn get() -> u32 {
+		Session::current_index()
+	}
 }
 
 parameter_types! {
@@ -173,12 +254,110 @@ impl pallet_transaction_payment::Config for Runtime {
 	// 	MaximumMultiplier,
 	// >;
 	type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
-	type OnChargeTransaction = CurrencyAdapter<Balances, DealWithFees<Runtime>>;
+	type OnChargeTransaction = BridgeFeeAdapter<CurrencyAdapter<Balances, DealWithFees<Runtime>>>;
 	type OperationalFeeMultiplier = OperationalFeeMultiplier;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightToFee = ConstantMultiplier<Balance, WeightFee>; // 1 weight = 10 picoAVAIL -> second_price = 10 AVAIL
 }
 
+/// Charges the weight fee of bridge proof submissions with the multiplier of the bridge fee lane
+/// instead of the one of `TransactionPayment`, and reports their weight to the lane, so that a
+/// surge of data submissions does not price out the light client updates.
+///
+/// Fee estimates of `payment_queryInfo` still use the multiplier of `TransactionPayment`.
+pub struct BridgeFeeAdapter<C>(sp_std::marker::PhantomData<C>);
+
+impl<C> OnChargeTransaction<Runtime> for BridgeFeeAdapter<C>
+where
+	C: OnChargeTransaction<Runtime, Balance = Balance>,
+{
+	type Balance = Balance;
+	/// Liquidity of the wrapped adapter, and whether the call is a bridge proof submission.
+	type LiquidityInfo = (C::LiquidityInfo, bool);
+
+	fn withdraw_fee(
+		who: &AccountId,
+		call: &RuntimeCall,
+		info: &traits::DispatchInfoOf<RuntimeCall>,
+		fee: Balance,
+		tip: Balance,
+	) -> Result<Self::LiquidityInfo, TransactionValidityError> {
+		let is_proof = is_bridge_proof_submission(call);
+		let fee = if is_proof && info.pays_fee == Pays::Yes {
+			bridge_lane_fee(fee, info.weight)
+		} else {
+			fee
+		};
+
+		C::withdraw_fee(who, call, info, fee, tip).map(|liquidity| (liquidity, is_proof))
+	}
+
+	fn correct_and_deposit_fee(
+		who: &AccountId,
+		dispatch_info: &traits::DispatchInfoOf<RuntimeCall>,
+		post_info: &traits::PostDispatchInfoOf<RuntimeCall>,
+		corrected_fee: Balance,
+		tip: Balance,
+		(liquidity, is_proof): Self::LiquidityInfo,
+	) -> Result<(), TransactionValidityError> {
+		let mut corrected_fee = corrected_fee;
+		if is_proof {
+			let weight = post_info.calc_actual_weight(dispatch_info);
+			pallet_vector::Pallet::<Runtime>::note_proof_submission(weight);
+			if post_info.pays_fee(dispatch_info) == Pays::Yes {
+				corrected_fee = bridge_lane_fee(corrected_fee, weight);
+			}
+		}
+
+		C::correct_and_deposit_fee(who, dispatch_info, post_info, corrected_fee, tip, liquidity)
+	}
+}
+
+fn is_bridge_proof_submission(call: &RuntimeCall) -> bool {
+	matches!(
+		call,
+		RuntimeCall::Vector(call) if pallet_vector::Pallet::<Runtime>::is_proof_submission(call)
+	)
+}
+
+/// Replaces the weight fee of `fee`, multiplied by the multiplier of `TransactionPayment`, by the
+/// one multiplied by the multiplier of the bridge fee lane.
+fn bridge_lane_fee(fee: Balance, weight: Weight) -> Balance {
+	let weight_fee = TransactionPayment::weight_to_fee(weight);
+	let multiplier = TransactionPayment::next_fee_multiplier();
+	let bridge_multiplier = pallet_vector::Pallet::<Runtime>::bridge_fee_multiplier();
+
+	fee.saturating_sub(multiplier.saturating_mul_int(weight_fee))
+		.saturating_add(bridge_multiplier.saturating_mul_int(weight_fee))
+}
+
+/// Returns the quote of bridging a message of `payload_size` bytes in `direction`. The proof
+/// submission executing an inbound message is charged with the multiplier of the bridge fee
+/// lane, the `send_message` of an outbound one with the multiplier of `TransactionPayment`.
+pub(crate) fn quote_message_fee(
+	direction: MessageDirection,
+	payload_size: u32,
+	priority: FeePriority,
+	domain: u32,
+) -> MessageFeeQuote {
+	let weight = pallet_vector::Pallet::<Runtime>::quote_message_weight(direction, payload_size);
+	let multiplier = match direction {
+		MessageDirection::Inbound => pallet_vector::Pallet::<Runtime>::bridge_fee_multiplier(),
+		MessageDirection::Outbound => TransactionPayment::next_fee_multiplier(),
+	};
+	let execution_fee = multiplier.saturating_mul_int(TransactionPayment::weight_to_fee(weight));
+	let (destination_gas, destination_fee) =
+		pallet_vector::Pallet::<Runtime>::quote_destination_fee(direction, payload_size, domain);
+
+	MessageFeeQuote {
+		weight,
+		execution_fee,
+		relayer_tip: priority.tip_rate() * execution_fee,
+		destination_gas,
+		destination_fee,
+	}
+}
+
 parameter_types! {
 	pub const MinimumPeriod: u64 = SLOT_DURATION / 2;
 }